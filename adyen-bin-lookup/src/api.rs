@@ -0,0 +1,120 @@
+//! BIN Lookup API client implementation.
+
+use crate::types::{
+    CostEstimateRequest, CostEstimateResponse, ThreeDSAvailabilityRequest,
+    ThreeDSAvailabilityResponse,
+};
+use adyen_core::{Api, Client, Config, Result};
+
+/// Adyen BIN Lookup API client.
+///
+/// Provides access to Adyen's BIN Lookup API v54 for checking 3D Secure
+/// availability and estimating the cost of a card payment before authorising it.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_bin_lookup::BinLookupApi;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let bin_lookup = BinLookupApi::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinLookupApi {
+    client: Client,
+}
+
+impl BinLookupApi {
+    /// Create a new BIN Lookup API client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::BinLookup)?;
+        Ok(Self { client })
+    }
+
+    /// Create a new BIN Lookup API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::BinLookup),
+        }
+    }
+
+    /// Check whether 3D Secure is available for a card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_3ds_availability(
+        &self,
+        request: &ThreeDSAvailabilityRequest,
+    ) -> Result<ThreeDSAvailabilityResponse> {
+        let url = format!(
+            "{}/pal/servlet/BinLookup/v54/get3dsAvailability",
+            self.client.config().environment().classic_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Estimate the interchange and scheme fees of a card payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_bin_lookup::{Amount, BinLookupApi, CostEstimateRequest, ShopperInteraction};
+    ///
+    /// # async fn example(bin_lookup: BinLookupApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = CostEstimateRequest::builder()
+    ///     .amount(Amount::new(1000, "EUR"))
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .card_number("5101180000000007")
+    ///     .shopper_interaction(ShopperInteraction::Ecommerce)
+    ///     .build()
+    ///     .map_err(|e| format!("Builder error: {}", e))?;
+    ///
+    /// let estimate = bin_lookup.get_cost_estimate(&request).await?;
+    /// if estimate.allows_surcharge() {
+    ///     println!("Surcharge: {:?}", estimate.cost_estimate_amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_cost_estimate(
+        &self,
+        request: &CostEstimateRequest,
+    ) -> Result<CostEstimateResponse> {
+        let url = format!(
+            "{}/pal/servlet/BinLookup/v54/getCostEstimate",
+            self.client.config().environment().classic_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl BinLookupApi {
+        fn get_3ds_availability_blocking => get_3ds_availability(request: &ThreeDSAvailabilityRequest) -> Result<ThreeDSAvailabilityResponse>;
+        fn get_cost_estimate_blocking => get_cost_estimate(request: &CostEstimateRequest) -> Result<CostEstimateResponse>;
+    }
+}
@@ -1 +1,41 @@
-//! Placeholder for adyen-bin-lookup crate
+//! # Adyen BIN Lookup API
+//!
+//! This crate provides access to Adyen's BIN Lookup API (v54) for checking a
+//! card before authorising a payment with it.
+//!
+//! ## Features
+//!
+//! - **3D Secure Availability**: Check which 3D Secure versions the issuer supports
+//! - **Cost Estimates**: Estimate interchange and scheme fees, and whether they may be surcharged
+//!
+//! ## Example
+//!
+//! ```rust
+//! use adyen_core::{ConfigBuilder, Environment};
+//! use adyen_bin_lookup::{BinLookupApi, ThreeDSAvailabilityRequest};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let bin_lookup = BinLookupApi::new(config)?;
+//!
+//! let request = ThreeDSAvailabilityRequest::builder()
+//!     .merchant_account("YourMerchantAccount")
+//!     .card_number("4212345678901237")
+//!     .build()
+//!     .map_err(|e| format!("Builder error: {}", e))?;
+//!
+//! let availability = bin_lookup.get_3ds_availability(&request).await?;
+//! println!("3D Secure available: {}", availability.is_3ds_available());
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+pub mod types;
+
+pub use api::BinLookupApi;
+pub use types::*;
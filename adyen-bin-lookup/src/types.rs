@@ -0,0 +1,527 @@
+//! Types for the Adyen BIN Lookup API v54.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Amount in minor units with its currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amount {
+    /// Three-character ISO currency code.
+    pub currency: Box<str>,
+    /// Amount value in minor units.
+    pub value: i64,
+}
+
+impl Amount {
+    /// Create a new amount from minor units and a currency code.
+    #[must_use]
+    pub fn new(value: i64, currency: &str) -> Self {
+        Self {
+            currency: currency.into(),
+            value,
+        }
+    }
+}
+
+impl From<&adyen_core::Amount> for Amount {
+    fn from(amount: &adyen_core::Amount) -> Self {
+        Self {
+            currency: amount.currency().to_string().into(),
+            value: i64::try_from(amount.minor_units()).unwrap_or(i64::MAX),
+        }
+    }
+}
+
+// ============================================================================
+// 3D Secure Availability
+// ============================================================================
+
+/// Request to check whether 3D Secure is available for a card.
+///
+/// Identify the card either by `card_number` or by a stored payment method
+/// (`recurring_detail_reference` together with `shopper_reference`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDSAvailabilityRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card number or BIN to check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_number: Option<Box<str>>,
+
+    /// Card brands to check, e.g. `visa` or `mc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brands: Option<Vec<Box<str>>>,
+
+    /// Reference of a stored payment method to check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_detail_reference: Option<Box<str>>,
+
+    /// Shopper the stored payment method belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<Box<str>>,
+
+    /// Additional data for the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<HashMap<Box<str>, Box<str>>>,
+}
+
+impl ThreeDSAvailabilityRequest {
+    /// Create a new 3D Secure availability request builder.
+    #[must_use]
+    pub fn builder() -> ThreeDSAvailabilityRequestBuilder {
+        ThreeDSAvailabilityRequestBuilder::default()
+    }
+}
+
+/// Builder for 3D Secure availability requests.
+#[derive(Debug, Default)]
+pub struct ThreeDSAvailabilityRequestBuilder {
+    merchant_account: Option<Box<str>>,
+    card_number: Option<Box<str>>,
+    brands: Option<Vec<Box<str>>>,
+    recurring_detail_reference: Option<Box<str>>,
+    shopper_reference: Option<Box<str>>,
+    additional_data: Option<HashMap<Box<str>, Box<str>>>,
+}
+
+impl ThreeDSAvailabilityRequestBuilder {
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the card number or BIN to check.
+    pub fn card_number<S: Into<Box<str>>>(mut self, card_number: S) -> Self {
+        self.card_number = Some(card_number.into());
+        self
+    }
+
+    /// Set the card brands to check.
+    #[must_use]
+    pub fn brands(mut self, brands: Vec<Box<str>>) -> Self {
+        self.brands = Some(brands);
+        self
+    }
+
+    /// Check a stored payment method of the given shopper instead of a card number.
+    pub fn stored_payment_method<S: Into<Box<str>>, T: Into<Box<str>>>(
+        mut self,
+        recurring_detail_reference: S,
+        shopper_reference: T,
+    ) -> Self {
+        self.recurring_detail_reference = Some(recurring_detail_reference.into());
+        self.shopper_reference = Some(shopper_reference.into());
+        self
+    }
+
+    /// Add additional data.
+    #[must_use]
+    pub fn additional_data(mut self, data: HashMap<Box<str>, Box<str>>) -> Self {
+        self.additional_data = Some(data);
+        self
+    }
+
+    /// Build the 3D Secure availability request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the merchant account is missing or no card is given.
+    pub fn build(self) -> Result<ThreeDSAvailabilityRequest, Box<str>> {
+        let merchant_account = self
+            .merchant_account
+            .ok_or("merchant_account is required")?;
+        if self.card_number.is_none() && self.recurring_detail_reference.is_none() {
+            return Err("card_number or stored_payment_method is required".into());
+        }
+
+        Ok(ThreeDSAvailabilityRequest {
+            merchant_account,
+            card_number: self.card_number,
+            brands: self.brands,
+            recurring_detail_reference: self.recurring_detail_reference,
+            shopper_reference: self.shopper_reference,
+            additional_data: self.additional_data,
+        })
+    }
+}
+
+/// Details of the card BIN.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinDetail {
+    /// Country where the card was issued.
+    pub issuer_country: Option<Box<str>>,
+}
+
+/// Public key of a 3D Secure 2 directory server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DSPublicKeyDetail {
+    /// Card brand the directory server belongs to.
+    pub brand: Option<Box<str>>,
+
+    /// Directory server identifier.
+    pub directory_server_id: Option<Box<str>>,
+
+    /// Minimum 3D Secure 2 SDK version supporting this key.
+    #[serde(rename = "fromSDKVersion")]
+    pub from_sdk_version: Option<Box<str>>,
+
+    /// Base64-encoded public key.
+    pub public_key: Option<Box<str>>,
+
+    /// Root certificates of the directory server.
+    pub root_certificates: Option<Box<str>>,
+}
+
+/// 3D Secure 2 support of a card range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDS2CardRangeDetail {
+    /// Features the ACS supports for this range.
+    pub acs_info_ind: Option<Vec<Box<str>>>,
+
+    /// Card brand of the range.
+    pub brand_code: Option<Box<str>>,
+
+    /// First BIN of the range.
+    pub start_range: Option<Box<str>>,
+
+    /// Last BIN of the range.
+    pub end_range: Option<Box<str>>,
+
+    /// 3D Secure 2 protocol versions supported by the range.
+    #[serde(rename = "threeDS2Versions")]
+    pub three_ds2_versions: Option<Vec<Box<str>>>,
+
+    /// URL of the 3D Secure method, if the issuer uses one.
+    #[serde(rename = "threeDSMethodURL")]
+    pub three_ds_method_url: Option<Box<str>>,
+}
+
+/// Response describing the 3D Secure availability of a card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDSAvailabilityResponse {
+    /// Details of the card BIN.
+    pub bin_details: Option<BinDetail>,
+
+    /// Public keys of the directory servers.
+    pub ds_public_keys: Option<Vec<DSPublicKeyDetail>>,
+
+    /// Whether 3D Secure 1 is supported.
+    #[serde(rename = "threeDS1Supported")]
+    pub three_ds1_supported: Option<bool>,
+
+    /// Whether 3D Secure 2 is supported.
+    #[serde(rename = "threeDS2supported")]
+    pub three_ds2_supported: Option<bool>,
+
+    /// 3D Secure 2 support per card range.
+    #[serde(rename = "threeDS2CardRangeDetails")]
+    pub three_ds2_card_range_details: Option<Vec<ThreeDS2CardRangeDetail>>,
+}
+
+impl ThreeDSAvailabilityResponse {
+    /// Check if any version of 3D Secure is available for the card.
+    #[must_use]
+    pub fn is_3ds_available(&self) -> bool {
+        self.three_ds1_supported.unwrap_or(false) || self.three_ds2_supported.unwrap_or(false)
+    }
+}
+
+// ============================================================================
+// Cost Estimate
+// ============================================================================
+
+/// Sales channel of the payment to estimate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShopperInteraction {
+    /// Online payment.
+    Ecommerce,
+    /// Payment with stored details, without the shopper present.
+    ContAuth,
+    /// Mail or telephone order.
+    Moto,
+    /// Point-of-sale payment.
+    #[serde(rename = "POS")]
+    Pos,
+    /// A shopper interaction not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Assumptions used to estimate the cost.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostEstimateAssumptions {
+    /// Assume the payment is authenticated with 3D Secure.
+    #[serde(rename = "assume3DSecureAuthenticated")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assume_3d_secure_authenticated: Option<bool>,
+
+    /// Assume level 3 data is sent with the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assume_level3_data: Option<bool>,
+
+    /// Number of installments of the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments: Option<u32>,
+}
+
+/// Merchant details used to estimate the cost.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantDetails {
+    /// Country of the merchant (ISO 3166-1 alpha-2).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<Box<str>>,
+
+    /// Whether the merchant is enrolled in 3D Secure.
+    #[serde(rename = "enrolledIn3DSecure")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrolled_in_3d_secure: Option<bool>,
+
+    /// Merchant category code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc: Option<Box<str>>,
+}
+
+/// Request to estimate the cost of a card payment.
+///
+/// Identify the card by `card_number`, `encrypted_card_number`, or a stored
+/// payment method (`selected_recurring_detail_reference` together with
+/// `shopper_reference`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostEstimateRequest {
+    /// Amount of the payment to estimate.
+    pub amount: Amount,
+
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card number or BIN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_number: Option<Box<str>>,
+
+    /// Card number encrypted with client-side encryption.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_card_number: Option<Box<str>>,
+
+    /// Reference of a stored payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_recurring_detail_reference: Option<Box<str>>,
+
+    /// Shopper the stored payment method belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<Box<str>>,
+
+    /// Sales channel of the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Assumptions used for the estimate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assumptions: Option<CostEstimateAssumptions>,
+
+    /// Merchant details used for the estimate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_details: Option<MerchantDetails>,
+}
+
+impl CostEstimateRequest {
+    /// Create a new cost estimate request builder.
+    #[must_use]
+    pub fn builder() -> CostEstimateRequestBuilder {
+        CostEstimateRequestBuilder::default()
+    }
+}
+
+/// Builder for cost estimate requests.
+#[derive(Debug, Default)]
+pub struct CostEstimateRequestBuilder {
+    amount: Option<Amount>,
+    merchant_account: Option<Box<str>>,
+    card_number: Option<Box<str>>,
+    encrypted_card_number: Option<Box<str>>,
+    selected_recurring_detail_reference: Option<Box<str>>,
+    shopper_reference: Option<Box<str>>,
+    shopper_interaction: Option<ShopperInteraction>,
+    assumptions: Option<CostEstimateAssumptions>,
+    merchant_details: Option<MerchantDetails>,
+}
+
+impl CostEstimateRequestBuilder {
+    /// Set the amount of the payment.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the card number or BIN.
+    pub fn card_number<S: Into<Box<str>>>(mut self, card_number: S) -> Self {
+        self.card_number = Some(card_number.into());
+        self
+    }
+
+    /// Set the card number encrypted with client-side encryption.
+    pub fn encrypted_card_number<S: Into<Box<str>>>(mut self, encrypted: S) -> Self {
+        self.encrypted_card_number = Some(encrypted.into());
+        self
+    }
+
+    /// Estimate for a stored payment method of the given shopper.
+    pub fn stored_payment_method<S: Into<Box<str>>, T: Into<Box<str>>>(
+        mut self,
+        recurring_detail_reference: S,
+        shopper_reference: T,
+    ) -> Self {
+        self.selected_recurring_detail_reference = Some(recurring_detail_reference.into());
+        self.shopper_reference = Some(shopper_reference.into());
+        self
+    }
+
+    /// Set the sales channel of the payment.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the assumptions used for the estimate.
+    #[must_use]
+    pub fn assumptions(mut self, assumptions: CostEstimateAssumptions) -> Self {
+        self.assumptions = Some(assumptions);
+        self
+    }
+
+    /// Set the merchant details used for the estimate.
+    #[must_use]
+    pub fn merchant_details(mut self, details: MerchantDetails) -> Self {
+        self.merchant_details = Some(details);
+        self
+    }
+
+    /// Build the cost estimate request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing or no card is given.
+    pub fn build(self) -> Result<CostEstimateRequest, Box<str>> {
+        let amount = self.amount.ok_or("amount is required")?;
+        let merchant_account = self
+            .merchant_account
+            .ok_or("merchant_account is required")?;
+        if self.card_number.is_none()
+            && self.encrypted_card_number.is_none()
+            && self.selected_recurring_detail_reference.is_none()
+        {
+            return Err(
+                "card_number, encrypted_card_number or stored_payment_method is required".into(),
+            );
+        }
+
+        Ok(CostEstimateRequest {
+            amount,
+            merchant_account,
+            card_number: self.card_number,
+            encrypted_card_number: self.encrypted_card_number,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            shopper_reference: self.shopper_reference,
+            shopper_interaction: self.shopper_interaction,
+            assumptions: self.assumptions,
+            merchant_details: self.merchant_details,
+        })
+    }
+}
+
+/// How the estimated cost is charged to the shopper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SurchargeType {
+    /// The cost can be passed on to the shopper as a surcharge.
+    Passthrough,
+    /// No surcharge applies.
+    Zero,
+    /// A surcharge type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Details of the card, as known from its BIN.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardBin {
+    /// First digits of the card number.
+    pub bin: Option<Box<str>>,
+
+    /// Whether the card is a commercial card.
+    pub commercial: Option<bool>,
+
+    /// Funding source of the card, e.g. `CREDIT` or `DEBIT`.
+    pub funding_source: Option<Box<str>>,
+
+    /// How quickly funds are available for payouts to the card.
+    pub funds_availability: Option<Box<str>>,
+
+    /// BIN of the issuer.
+    pub issuer_bin: Option<Box<str>>,
+
+    /// Name of the issuing bank.
+    pub issuing_bank: Option<Box<str>>,
+
+    /// Country where the card was issued.
+    pub issuing_country: Option<Box<str>>,
+
+    /// Currency of the card.
+    pub issuing_currency: Option<Box<str>>,
+
+    /// Payment method variant of the card, e.g. `visadebit`.
+    pub payment_method: Option<Box<str>>,
+
+    /// Whether payouts to the card are possible.
+    pub payout_eligible: Option<Box<str>>,
+
+    /// Last four digits of the card number.
+    pub summary: Option<Box<str>>,
+}
+
+/// Response with the estimated cost of a card payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostEstimateResponse {
+    /// Details of the card.
+    pub card_bin: Option<CardBin>,
+
+    /// Estimated cost (interchange and scheme fees) of the payment.
+    pub cost_estimate_amount: Option<Amount>,
+
+    /// Reference of the estimate.
+    pub cost_estimate_reference: Option<Box<str>>,
+
+    /// Result of the estimate, e.g. `Success`.
+    pub result_code: Option<Box<str>>,
+
+    /// How the cost may be charged to the shopper.
+    pub surcharge_type: Option<SurchargeType>,
+}
+
+impl CostEstimateResponse {
+    /// Check if the estimated cost may be passed on to the shopper.
+    #[must_use]
+    pub fn allows_surcharge(&self) -> bool {
+        self.surcharge_type == Some(SurchargeType::Passthrough)
+    }
+}
@@ -0,0 +1,219 @@
+//! Integration tests for the Adyen BIN Lookup API v54.
+
+use adyen_bin_lookup::types::*;
+use adyen_bin_lookup::BinLookupApi;
+use adyen_core::{ConfigBuilder, Currency, Environment};
+
+fn create_test_config() -> adyen_core::Config {
+    ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod request_building_tests {
+    use super::*;
+
+    #[test]
+    fn test_api_creation() {
+        let api = BinLookupApi::new(create_test_config());
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_3ds_availability_request_requires_card() {
+        let result = ThreeDSAvailabilityRequest::builder()
+            .merchant_account("TestMerchant")
+            .build();
+        assert!(result.is_err());
+
+        let request = ThreeDSAvailabilityRequest::builder()
+            .merchant_account("TestMerchant")
+            .stored_payment_method("8415995487234100", "shopper_123")
+            .build()
+            .unwrap();
+        assert_eq!(request.shopper_reference.as_deref(), Some("shopper_123"));
+    }
+
+    #[test]
+    fn test_cost_estimate_request_requires_amount() {
+        let result = CostEstimateRequest::builder()
+            .merchant_account("TestMerchant")
+            .card_number("5101180000000007")
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "amount is required");
+    }
+
+    #[test]
+    fn test_amount_from_core_amount() {
+        let amount = Amount::from(&adyen_core::Amount::from_minor_units(1099, Currency::EUR));
+        assert_eq!(amount, Amount::new(1099, "EUR"));
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_estimate_request_serialization() {
+        let request = CostEstimateRequest::builder()
+            .amount(Amount::new(1000, "EUR"))
+            .merchant_account("TestMerchant")
+            .card_number("5101180000000007")
+            .shopper_interaction(ShopperInteraction::Pos)
+            .assumptions(CostEstimateAssumptions {
+                assume_3d_secure_authenticated: Some(true),
+                ..CostEstimateAssumptions::default()
+            })
+            .merchant_details(MerchantDetails {
+                country_code: Some("NL".into()),
+                mcc: Some("7411".into()),
+                ..MerchantDetails::default()
+            })
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "amount": {"currency": "EUR", "value": 1000},
+                "merchantAccount": "TestMerchant",
+                "cardNumber": "5101180000000007",
+                "shopperInteraction": "POS",
+                "assumptions": {"assume3DSecureAuthenticated": true},
+                "merchantDetails": {"countryCode": "NL", "mcc": "7411"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_3ds_availability_response_deserialization() {
+        let json = r#"{
+            "binDetails": {"issuerCountry": "NL"},
+            "threeDS1Supported": true,
+            "threeDS2CardRangeDetails": [{
+                "acsInfoInd": ["01", "02"],
+                "brandCode": "visa",
+                "endRange": "411111111",
+                "startRange": "411111111",
+                "threeDS2Versions": ["2.1.0", "2.2.0"],
+                "threeDSMethodURL": "https://pal-test.adyen.com/threeds2simulator/acs/startMethod.shtml"
+            }],
+            "threeDS2supported": true
+        }"#;
+
+        let response: ThreeDSAvailabilityResponse = serde_json::from_str(json).unwrap();
+        assert!(response.is_3ds_available());
+        assert_eq!(
+            response.bin_details.unwrap().issuer_country.as_deref(),
+            Some("NL")
+        );
+        let ranges = response.three_ds2_card_range_details.unwrap();
+        assert_eq!(ranges[0].three_ds2_versions.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_cost_estimate_response_deserialization() {
+        let json = r#"{
+            "cardBin": {
+                "bin": "510118",
+                "fundingSource": "CREDIT",
+                "issuingCountry": "NL",
+                "paymentMethod": "mc",
+                "summary": "0007"
+            },
+            "costEstimateAmount": {"currency": "EUR", "value": 12},
+            "resultCode": "Success",
+            "surchargeType": "PASSTHROUGH"
+        }"#;
+
+        let response: CostEstimateResponse = serde_json::from_str(json).unwrap();
+        assert!(response.allows_surcharge());
+        assert_eq!(response.cost_estimate_amount, Some(Amount::new(12, "EUR")));
+        assert_eq!(response.card_bin.unwrap().bin.as_deref(), Some("510118"));
+    }
+
+    #[test]
+    fn test_unknown_surcharge_type() {
+        let response: CostEstimateResponse =
+            serde_json::from_str(r#"{"surchargeType": "SOMETHING_NEW"}"#).unwrap();
+        assert_eq!(response.surcharge_type, Some(SurchargeType::Unknown));
+        assert!(!response.allows_surcharge());
+    }
+}
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+
+    fn mock_api(mock: &MockTransport) -> BinLookupApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        BinLookupApi::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_3ds_availability() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/BinLookup/v54/get3dsAvailability")
+                .body_includes(serde_json::json!({"cardNumber": "4212345678901237"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({"threeDS1Supported": false, "threeDS2supported": true}),
+                )),
+        );
+
+        let api = mock_api(&mock);
+        let request = ThreeDSAvailabilityRequest::builder()
+            .merchant_account("TestMerchant")
+            .card_number("4212345678901237")
+            .build()
+            .unwrap();
+        let response = api.get_3ds_availability(&request).await.unwrap();
+
+        assert_eq!(response.three_ds2_supported, Some(true));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_get_cost_estimate() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/BinLookup/v54/getCostEstimate")
+                .body_includes(serde_json::json!({"amount": {"currency": "EUR", "value": 1000}}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "costEstimateAmount": {"currency": "EUR", "value": 8},
+                        "resultCode": "Success",
+                        "surchargeType": "ZERO"
+                    }),
+                )),
+        );
+
+        let api = mock_api(&mock);
+        let request = CostEstimateRequest::builder()
+            .amount(Amount::new(1000, "EUR"))
+            .merchant_account("TestMerchant")
+            .card_number("5101180000000007")
+            .build()
+            .unwrap();
+        let response = api.get_cost_estimate(&request).await.unwrap();
+
+        assert_eq!(response.surcharge_type, Some(SurchargeType::Zero));
+        assert_eq!(response.cost_estimate_amount, Some(Amount::new(8, "EUR")));
+        mock.verify();
+    }
+}
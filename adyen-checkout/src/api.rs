@@ -9,10 +9,11 @@ use crate::types::{
     DonationRequest, DonationResponse, ListStoredPaymentMethodsResponse, OriginKeysRequest,
     OriginKeysResponse, PayPalUpdateOrderRequest, PayPalUpdateOrderResponse, PaymentDetailsRequest,
     PaymentDetailsResponse, PaymentLinkRequest, PaymentLinkResponse, PaymentMethodsRequest,
-    PaymentMethodsResponse, PaymentRequest, PaymentResponse, RefundRequest, RefundResponse,
-    ReversalRequest, ReversalResponse, SessionResultResponse,
+    PaymentMethodsResponse, PaymentRequest, PaymentResponse, PosSdkSessionRequest,
+    PosSdkSessionResponse, RefundRequest, RefundResponse, ReversalRequest, ReversalResponse,
+    SessionResultResponse, UpdatePaymentLinkRequest,
 };
-use adyen_core::{Client, Config, Result};
+use adyen_core::{Api, Client, Config, PspReference, Result};
 
 /// Adyen Checkout API client.
 ///
@@ -47,10 +48,41 @@ impl CheckoutApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::Checkout)?;
         Ok(Self { client })
     }
 
+    /// Create a new Checkout API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{Client, ConfigBuilder, Environment};
+    /// use adyen_checkout::CheckoutApi;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ConfigBuilder::new()
+    ///     .environment(Environment::test())
+    ///     .api_key("your_api_key")?
+    ///     .build()?;
+    ///
+    /// let client = Client::new(config)?;
+    /// let checkout = CheckoutApi::with_client(client.clone());
+    /// // Other API structs, e.g. `ManagementApi::with_client(client)`, reuse
+    /// // the same connections.
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Checkout),
+        }
+    }
+
     /// Get a list of available payment methods.
     ///
     /// Retrieves the payment methods available for the given merchant account,
@@ -123,7 +155,10 @@ impl CheckoutApi {
             "{}/v71/payments",
             self.client.config().environment().checkout_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -163,6 +198,30 @@ impl CheckoutApi {
             "{}/v71/sessions",
             self.client.config().environment().checkout_api_url()
         );
+        let response = self.client.post_payment(&url, request, None).await?;
+        Ok(response.data)
+    }
+
+    /// Create a POS Mobile session.
+    ///
+    /// Creates the session that Adyen's Tap to Pay SDKs need to accept
+    /// in-person payments on a mobile device. Pass the setup token generated
+    /// by the SDK and return the `sdk_data` of the response to the app.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn pos_sdk_sessions(
+        &self,
+        request: &PosSdkSessionRequest,
+    ) -> Result<PosSdkSessionResponse> {
+        // POS SDK sessions always live under `/checkout`, which the live base URL
+        // already ends in.
+        let base = self.client.config().environment().checkout_api_url();
+        let url = format!(
+            "{}/checkout/possdk/v68/sessions",
+            base.strip_suffix("/checkout").unwrap_or(&base)
+        );
         let response = self.client.post(&url, request).await?;
         Ok(response.data)
     }
@@ -186,26 +245,39 @@ impl CheckoutApi {
 
     /// Get the result of a payment session.
     ///
-    /// Retrieves the result of a payment session that was created earlier.
-    /// Use this to get the final payment result for sessions.
+    /// Use the `sessionResult` returned by Drop-in or Components when the
+    /// session completes to verify its final status server-side.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_checkout::CheckoutApi;
+    ///
+    /// # async fn example(api: CheckoutApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let result = api
+    ///     .get_session_result("CS12345678", "X3XtfGC7!5s7sd...")
+    ///     .await?;
+    /// if result.status.is_final() {
+    ///     println!("Session finished with status {:?}", result.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get_session_result(
         &self,
         session_id: &str,
-        session_result: Option<&str>,
+        session_result: &str,
     ) -> Result<SessionResultResponse> {
-        let mut url = format!(
-            "{}/v71/sessions/{}",
+        let url = format!(
+            "{}/v71/sessions/{}?sessionResult={}",
             self.client.config().environment().checkout_api_url(),
-            session_id
+            urlencoding::encode(session_id),
+            urlencoding::encode(session_result)
         );
-        if let Some(result) = session_result {
-            use std::fmt::Write;
-            let _ = write!(url, "?sessionResult={}", urlencoding::encode(result));
-        }
         let response = self.client.get(&url).await?;
         Ok(response.data)
     }
@@ -213,10 +285,28 @@ impl CheckoutApi {
     /// Get stored payment methods for a shopper.
     ///
     /// Retrieves the stored payment methods for a given shopper reference.
+    /// This replaces `listRecurringDetails` from the legacy Recurring API.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_checkout::CheckoutApi;
+    ///
+    /// # async fn example(api: CheckoutApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let response = api
+    ///     .get_stored_payment_methods("YourMerchantAccount", "shopper-123")
+    ///     .await?;
+    ///
+    /// for method in response.stored_payment_methods.iter().filter(|m| m.is_card()) {
+    ///     println!("{} ending in {:?}", method.id, method.last_four);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get_stored_payment_methods(
         &self,
         merchant_account: &str,
@@ -234,11 +324,12 @@ impl CheckoutApi {
 
     /// Delete a stored payment method.
     ///
-    /// Removes a stored payment method for a shopper.
+    /// Removes a stored payment method for a shopper. This replaces `disable`
+    /// from the legacy Recurring API.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response cannot be parsed.
+    /// Returns an error if the request fails.
     pub async fn delete_stored_payment_method(
         &self,
         stored_payment_method_id: &str,
@@ -287,7 +378,7 @@ impl CheckoutApi {
             "{}/v71/paymentLinks",
             self.client.config().environment().checkout_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -308,6 +399,38 @@ impl CheckoutApi {
         Ok(response.data)
     }
 
+    /// Update a payment link.
+    ///
+    /// Adyen only supports changing the status of a link to expired; see
+    /// [`expire_payment_link`](Self::expire_payment_link).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn update_payment_link(
+        &self,
+        link_id: &str,
+        request: &UpdatePaymentLinkRequest,
+    ) -> Result<PaymentLinkResponse> {
+        let url = format!(
+            "{}/v71/paymentLinks/{}",
+            self.client.config().environment().checkout_api_url(),
+            urlencoding::encode(link_id)
+        );
+        let response = self.client.patch(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Expire a payment link so it can no longer be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn expire_payment_link(&self, link_id: &str) -> Result<PaymentLinkResponse> {
+        self.update_payment_link(link_id, &UpdatePaymentLinkRequest::expire())
+            .await
+    }
+
     /// Get origin keys for client-side encryption.
     ///
     /// Generates origin keys for securing payment data on the client side.
@@ -326,11 +449,32 @@ impl CheckoutApi {
 
     /// Get Apple Pay session.
     ///
-    /// Initiates an Apple Pay session for payment processing.
+    /// Requests an Apple Pay merchant session to complete merchant
+    /// validation, for merchants using Adyen's Apple Pay certificate.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_checkout::types::ApplePaySessionRequest;
+    /// use adyen_checkout::CheckoutApi;
+    ///
+    /// # async fn example(api: CheckoutApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = ApplePaySessionRequest::new(
+    ///     "Your Store",
+    ///     "www.example.com",
+    ///     "merchant.com.example",
+    /// );
+    ///
+    /// let response = api.apple_pay_sessions(&request).await?;
+    /// let merchant_session = response.merchant_session()?;
+    /// println!("{merchant_session}");
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn apple_pay_sessions(
         &self,
         request: &ApplePaySessionRequest,
@@ -345,22 +489,26 @@ impl CheckoutApi {
 
     /// Capture a payment.
     ///
-    /// Captures an authorized payment for the specified amount.
+    /// Captures an authorized payment for the specified amount. Pass line
+    /// items to partially capture an open invoice payment.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn capture_payment(
         &self,
-        payment_psp_reference: &str,
+        payment_psp_reference: &PspReference,
         request: &CaptureRequest,
     ) -> Result<CaptureResponse> {
         let url = format!(
             "{}/v71/payments/{}/captures",
             self.client.config().environment().checkout_api_url(),
-            urlencoding::encode(payment_psp_reference)
+            payment_psp_reference
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -373,15 +521,18 @@ impl CheckoutApi {
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn refund_payment(
         &self,
-        payment_psp_reference: &str,
+        payment_psp_reference: &PspReference,
         request: &RefundRequest,
     ) -> Result<RefundResponse> {
         let url = format!(
             "{}/v71/payments/{}/refunds",
             self.client.config().environment().checkout_api_url(),
-            urlencoding::encode(payment_psp_reference)
+            payment_psp_reference
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -394,15 +545,18 @@ impl CheckoutApi {
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn cancel_payment(
         &self,
-        payment_psp_reference: &str,
+        payment_psp_reference: &PspReference,
         request: &CancelRequest,
     ) -> Result<CancelResponse> {
         let url = format!(
             "{}/v71/payments/{}/cancels",
             self.client.config().environment().checkout_api_url(),
-            urlencoding::encode(payment_psp_reference)
+            payment_psp_reference
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -415,15 +569,18 @@ impl CheckoutApi {
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn reverse_payment(
         &self,
-        payment_psp_reference: &str,
+        payment_psp_reference: &PspReference,
         request: &ReversalRequest,
     ) -> Result<ReversalResponse> {
         let url = format!(
             "{}/v71/payments/{}/reversals",
             self.client.config().environment().checkout_api_url(),
-            urlencoding::encode(payment_psp_reference)
+            payment_psp_reference
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -436,21 +593,25 @@ impl CheckoutApi {
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn update_payment_amount(
         &self,
-        payment_psp_reference: &str,
+        payment_psp_reference: &PspReference,
         request: &AmountUpdateRequest,
     ) -> Result<AmountUpdateResponse> {
         let url = format!(
             "{}/v71/payments/{}/amountUpdates",
             self.client.config().environment().checkout_api_url(),
-            urlencoding::encode(payment_psp_reference)
+            payment_psp_reference
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
-    /// Cancel payment (legacy endpoint).
+    /// Cancel a payment by your reference.
     ///
-    /// Cancels a payment using the legacy cancel endpoint.
+    /// Cancels a payment whose PSP reference you don't know, identified by
+    /// the request's `payment_reference` instead.
     ///
     /// # Errors
     ///
@@ -460,7 +621,10 @@ impl CheckoutApi {
             "{}/v71/cancels",
             self.client.config().environment().checkout_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -498,7 +662,31 @@ impl CheckoutApi {
 
     /// Make a donation.
     ///
-    /// Processes a donation request.
+    /// Makes an Adyen Giving donation after a successful payment, using the
+    /// `donation_token` from the payment response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_checkout::types::{DonationPaymentMethod, DonationRequest};
+    /// use adyen_checkout::{CheckoutApi, PaymentResponse};
+    /// use adyen_core::{Amount, Currency};
+    ///
+    /// # async fn example(api: CheckoutApi, payment: PaymentResponse) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = DonationRequest::new(
+    ///     "YourMerchantAccount",
+    ///     Amount::from_major_units(5, Currency::EUR),
+    ///     "donation-001",
+    ///     DonationPaymentMethod::new("scheme"),
+    /// )
+    /// .with_donation_account("CHARITY_ACCOUNT")
+    /// .with_payment(&payment)?;
+    ///
+    /// let response = api.donations(&request).await?;
+    /// println!("Donation completed: {}", response.is_completed());
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Errors
     ///
@@ -508,7 +696,7 @@ impl CheckoutApi {
             "{}/v71/donations",
             self.client.config().environment().checkout_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -551,9 +739,43 @@ impl CheckoutApi {
     }
 }
 
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl CheckoutApi {
+        fn payment_methods_blocking => payment_methods(request: &PaymentMethodsRequest) -> Result<PaymentMethodsResponse>;
+        fn payments_blocking => payments(request: &PaymentRequest) -> Result<PaymentResponse>;
+        fn payment_details_blocking => payment_details(request: &PaymentDetailsRequest) -> Result<PaymentDetailsResponse>;
+        fn sessions_blocking => sessions(request: &CreateCheckoutSessionRequest) -> Result<CreateCheckoutSessionResponse>;
+        fn pos_sdk_sessions_blocking => pos_sdk_sessions(request: &PosSdkSessionRequest) -> Result<PosSdkSessionResponse>;
+        fn card_details_blocking => card_details(request: &CardDetailsRequest) -> Result<CardDetailsResponse>;
+        fn get_session_result_blocking => get_session_result(session_id: &str, session_result: &str) -> Result<SessionResultResponse>;
+        fn get_stored_payment_methods_blocking => get_stored_payment_methods(merchant_account: &str, shopper_reference: &str) -> Result<ListStoredPaymentMethodsResponse>;
+        fn delete_stored_payment_method_blocking => delete_stored_payment_method(stored_payment_method_id: &str, merchant_account: &str, shopper_reference: &str) -> Result<()>;
+        fn payment_methods_balance_blocking => payment_methods_balance(request: &BalanceCheckRequest) -> Result<BalanceCheckResponse>;
+        fn payment_links_blocking => payment_links(request: &PaymentLinkRequest) -> Result<PaymentLinkResponse>;
+        fn get_payment_link_blocking => get_payment_link(link_id: &str) -> Result<PaymentLinkResponse>;
+        fn update_payment_link_blocking => update_payment_link(link_id: &str, request: &UpdatePaymentLinkRequest) -> Result<PaymentLinkResponse>;
+        fn expire_payment_link_blocking => expire_payment_link(link_id: &str) -> Result<PaymentLinkResponse>;
+        fn origin_keys_blocking => origin_keys(request: &OriginKeysRequest) -> Result<OriginKeysResponse>;
+        fn apple_pay_sessions_blocking => apple_pay_sessions(request: &ApplePaySessionRequest) -> Result<ApplePaySessionResponse>;
+        fn capture_payment_blocking => capture_payment(payment_psp_reference: &PspReference, request: &CaptureRequest) -> Result<CaptureResponse>;
+        fn refund_payment_blocking => refund_payment(payment_psp_reference: &PspReference, request: &RefundRequest) -> Result<RefundResponse>;
+        fn cancel_payment_blocking => cancel_payment(payment_psp_reference: &PspReference, request: &CancelRequest) -> Result<CancelResponse>;
+        fn reverse_payment_blocking => reverse_payment(payment_psp_reference: &PspReference, request: &ReversalRequest) -> Result<ReversalResponse>;
+        fn update_payment_amount_blocking => update_payment_amount(payment_psp_reference: &PspReference, request: &AmountUpdateRequest) -> Result<AmountUpdateResponse>;
+        fn cancel_blocking => cancel(request: &CancelRequest) -> Result<CancelResponse>;
+        fn create_order_blocking => create_order(request: &CreateOrderRequest) -> Result<CreateOrderResponse>;
+        fn cancel_order_blocking => cancel_order(request: &CancelOrderRequest) -> Result<CancelOrderResponse>;
+        fn donations_blocking => donations(request: &DonationRequest) -> Result<DonationResponse>;
+        fn donation_campaigns_blocking => donation_campaigns(request: &DonationCampaignsRequest) -> Result<DonationCampaignsResponse>;
+        fn paypal_update_order_blocking => paypal_update_order(request: &PayPalUpdateOrderRequest) -> Result<PayPalUpdateOrderResponse>;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
     use adyen_core::{ConfigBuilder, Environment};
 
     #[test]
@@ -568,4 +790,76 @@ mod tests {
         let api = CheckoutApi::new(config).unwrap();
         assert!(api.client.config().environment().is_test());
     }
+
+    fn mock_api(mock: &MockTransport, environment: Environment) -> CheckoutApi {
+        let config = ConfigBuilder::new()
+            .environment(environment)
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        CheckoutApi::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_live_checkout_url() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/checkout/v71/cardDetails")
+                .respond_with(MockResponse::json(200, serde_json::json!({}))),
+        );
+
+        let api = mock_api(
+            &mock,
+            Environment::live("1797a841fbb37ca7-AdyenDemo").unwrap(),
+        );
+        let request = CardDetailsRequest::builder()
+            .merchant_account("TestMerchant")
+            .card_number("4111111111111111")
+            .build()
+            .unwrap();
+        api.card_details(&request).await.unwrap();
+
+        assert_eq!(
+            mock.received_requests()[0].url,
+            "https://1797a841fbb37ca7-AdyenDemo-checkout-live.adyenpayments.com/checkout/v71/cardDetails"
+        );
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_pos_sdk_sessions_url() {
+        let response = serde_json::json!({"id": "CS123", "sdkData": "c2RrRGF0YQ=="});
+        let request = PosSdkSessionRequest::new("TestMerchant", "setup-token");
+
+        let live = MockTransport::new();
+        live.expect(
+            Expectation::post("/checkout/possdk/v68/sessions")
+                .respond_with(MockResponse::json(200, response.clone())),
+        );
+        let api = mock_api(
+            &live,
+            Environment::live("1797a841fbb37ca7-AdyenDemo").unwrap(),
+        );
+        api.pos_sdk_sessions(&request).await.unwrap();
+        assert_eq!(
+            live.received_requests()[0].url,
+            "https://1797a841fbb37ca7-AdyenDemo-checkout-live.adyenpayments.com/checkout/possdk/v68/sessions"
+        );
+        live.verify();
+
+        let test = MockTransport::new();
+        test.expect(
+            Expectation::post("/checkout/possdk/v68/sessions")
+                .respond_with(MockResponse::json(200, response)),
+        );
+        let api = mock_api(&test, Environment::test());
+        api.pos_sdk_sessions(&request).await.unwrap();
+        assert_eq!(
+            test.received_requests()[0].url,
+            "https://checkout-test.adyen.com/checkout/possdk/v68/sessions"
+        );
+        test.verify();
+    }
 }
@@ -0,0 +1,336 @@
+//! Client-side encryption (CSE) of card data.
+//!
+//! Integrations that must not send raw card numbers to Adyen can encrypt
+//! them with the merchant's client encryption public key first, the same way
+//! Adyen's web and mobile SDKs do. Adyen decrypts the values when processing
+//! the payment.
+//!
+//! Each value is encrypted with a fresh AES-256 key in CCM mode, and the key
+//! is encrypted with the RSA public key (PKCS #1 v1.5). The result has the
+//! `adyenjs_0_1_25$<encrypted key>$<nonce and ciphertext>` format, both parts
+//! base64-encoded.
+//!
+//! The public key is the "Client Encryption Public Key" of the API
+//! credential in the Customer Area, in `exponent|modulus` hex form.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use adyen_checkout::encryption::{CardData, CardEncrypter};
+//! use adyen_checkout::PaymentRequest;
+//! use adyen_core::{Amount, Currency};
+//!
+//! # fn example(public_key: &str) -> adyen_core::Result<()> {
+//! let encrypter = CardEncrypter::new(public_key)?;
+//! let card = CardData::new("4111111111111111", "03", "2030").with_cvc("737");
+//!
+//! let request = PaymentRequest::builder()
+//!     .amount(Amount::from_major_units(10, Currency::EUR))
+//!     .merchant_account("YourMerchantAccount")
+//!     .reference("Order-12345")
+//!     .return_url("https://example.com/return")
+//!     .payment_method(encrypter.encrypt_card(&card)?.into())
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::payments::PaymentMethodDetails;
+use adyen_core::{AdyenError, Result, SecretString};
+use aes::Aes256;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ccm::aead::{Aead, KeyInit};
+use ccm::consts::{U12, U8};
+use ccm::Ccm;
+use chrono::{DateTime, Utc};
+use rsa::rand_core::OsRng;
+use rsa::{BigUint, Pkcs1v15Encrypt, RsaPublicKey};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Prefix and version of the encrypted data format.
+const PREFIX: &str = "adyenjs_0_1_25$";
+
+/// AES-256 in CCM mode with an 8-byte tag and a 12-byte nonce.
+type Aes256Ccm = Ccm<Aes256, U8, U12>;
+
+/// Card data to encrypt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardData {
+    /// The card number.
+    pub number: SecretString,
+    /// The expiry month (MM).
+    pub expiry_month: String,
+    /// The expiry year (YYYY).
+    pub expiry_year: String,
+    /// The card security code.
+    pub cvc: Option<SecretString>,
+    /// The cardholder name.
+    pub holder_name: Option<String>,
+}
+
+impl CardData {
+    /// Create card data without security code and cardholder name.
+    #[must_use]
+    pub fn new(
+        number: impl Into<SecretString>,
+        expiry_month: impl Into<String>,
+        expiry_year: impl Into<String>,
+    ) -> Self {
+        Self {
+            number: number.into(),
+            expiry_month: expiry_month.into(),
+            expiry_year: expiry_year.into(),
+            cvc: None,
+            holder_name: None,
+        }
+    }
+
+    /// Set the card security code.
+    #[must_use]
+    pub fn with_cvc(mut self, cvc: impl Into<SecretString>) -> Self {
+        self.cvc = Some(cvc.into());
+        self
+    }
+
+    /// Set the cardholder name.
+    #[must_use]
+    pub fn with_holder_name(mut self, holder_name: impl Into<String>) -> Self {
+        self.holder_name = Some(holder_name.into());
+        self
+    }
+}
+
+/// Card fields encrypted one by one, as the Checkout API expects them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedCard {
+    /// The encrypted card number.
+    pub encrypted_card_number: String,
+    /// The encrypted expiry month.
+    pub encrypted_expiry_month: String,
+    /// The encrypted expiry year.
+    pub encrypted_expiry_year: String,
+    /// The encrypted security code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_security_code: Option<String>,
+    /// The cardholder name, which is not encrypted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holder_name: Option<String>,
+}
+
+impl From<EncryptedCard> for PaymentMethodDetails {
+    fn from(card: EncryptedCard) -> Self {
+        let mut details = HashMap::from([("type".to_string(), "scheme".into())]);
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(card) {
+            details.extend(fields);
+        }
+        Self::Other(details)
+    }
+}
+
+/// Encrypts card data with a client encryption public key.
+#[derive(Clone)]
+pub struct CardEncrypter {
+    public_key: RsaPublicKey,
+}
+
+impl CardEncrypter {
+    /// Create an encrypter from a client encryption public key in
+    /// `exponent|modulus` hex form, e.g. `10001|A237...`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not in that form or is not a valid RSA
+    /// public key.
+    pub fn new(public_key: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            AdyenError::config(format!("Invalid client encryption public key: {reason}"))
+        };
+
+        let (exponent, modulus) = public_key
+            .trim()
+            .split_once('|')
+            .ok_or_else(|| invalid("expected exponent|modulus"))?;
+        let parse = |hex: &str| {
+            BigUint::parse_bytes(hex.as_bytes(), 16).ok_or_else(|| invalid("not hexadecimal"))
+        };
+        let public_key = RsaPublicKey::new(parse(modulus)?, parse(exponent)?)
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        Ok(Self { public_key })
+    }
+
+    /// Encrypt the card fields one by one for the `encrypted*` fields of a
+    /// Checkout `scheme` payment method.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encryption fails.
+    pub fn encrypt_card(&self, card: &CardData) -> Result<EncryptedCard> {
+        let now = Utc::now();
+        Ok(EncryptedCard {
+            encrypted_card_number: self
+                .encrypt_fields(&[("number", card.number.expose_secret())], now)?,
+            encrypted_expiry_month: self
+                .encrypt_fields(&[("expiryMonth", card.expiry_month.as_str())], now)?,
+            encrypted_expiry_year: self
+                .encrypt_fields(&[("expiryYear", card.expiry_year.as_str())], now)?,
+            encrypted_security_code: card
+                .cvc
+                .as_ref()
+                .map(|cvc| self.encrypt_fields(&[("cvc", cvc.expose_secret())], now))
+                .transpose()?,
+            holder_name: card.holder_name.clone(),
+        })
+    }
+
+    /// Encrypt the whole card as one value, for the `card.encrypted.json`
+    /// additional data of the Classic Payments API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encryption fails.
+    pub fn encrypt_card_json(&self, card: &CardData) -> Result<String> {
+        let mut fields = vec![
+            ("number", card.number.expose_secret()),
+            ("expiryMonth", card.expiry_month.as_str()),
+            ("expiryYear", card.expiry_year.as_str()),
+        ];
+        if let Some(cvc) = &card.cvc {
+            fields.push(("cvc", cvc.expose_secret()));
+        }
+        if let Some(holder_name) = &card.holder_name {
+            fields.push(("holderName", holder_name));
+        }
+        self.encrypt_fields(&fields, Utc::now())
+    }
+
+    /// Encrypt a JSON object of the given fields and the generation time.
+    fn encrypt_fields(
+        &self,
+        fields: &[(&str, &str)],
+        generation_time: DateTime<Utc>,
+    ) -> Result<String> {
+        let mut object: HashMap<&str, &str> = fields.iter().copied().collect();
+        let generation_time = generation_time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        object.insert("generationtime", &generation_time);
+        let plaintext =
+            SecretString::new(serde_json::to_string(&object).map_err(AdyenError::Serialization)?);
+        self.encrypt(plaintext.expose_secret().as_bytes())
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<String> {
+        let mut key = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        getrandom::getrandom(&mut key)
+            .and_then(|()| getrandom::getrandom(&mut nonce))
+            .map_err(|e| AdyenError::generic(format!("Random number generator failed: {e}")))?;
+
+        let ciphertext = Aes256Ccm::new(&key.into())
+            .encrypt(&nonce.into(), plaintext)
+            .map_err(|_| AdyenError::generic("Card data encryption failed"))?;
+        let encrypted_key = self
+            .public_key
+            .encrypt(&mut OsRng, Pkcs1v15Encrypt, &key)
+            .map_err(|e| AdyenError::generic(format!("Card data encryption failed: {e}")))?;
+        key.fill(0);
+
+        let mut payload = nonce.to_vec();
+        payload.extend(ciphertext);
+        Ok(format!(
+            "{PREFIX}{}${}",
+            BASE64.encode(encrypted_key),
+            BASE64.encode(payload)
+        ))
+    }
+}
+
+impl fmt::Debug for CardEncrypter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CardEncrypter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::traits::PublicKeyParts;
+    use rsa::RsaPrivateKey;
+
+    fn decrypt(private_key: &RsaPrivateKey, encrypted: &str) -> serde_json::Value {
+        let parts: Vec<&str> = encrypted.strip_prefix(PREFIX).unwrap().split('$').collect();
+        let key = private_key
+            .decrypt(Pkcs1v15Encrypt, &BASE64.decode(parts[0]).unwrap())
+            .unwrap();
+        let payload = BASE64.decode(parts[1]).unwrap();
+        let (nonce, ciphertext) = payload.split_at(12);
+        let plaintext = Aes256Ccm::new_from_slice(&key)
+            .unwrap()
+            .decrypt(nonce.into(), ciphertext)
+            .unwrap();
+        serde_json::from_slice(&plaintext).unwrap()
+    }
+
+    fn key_pair() -> (RsaPrivateKey, CardEncrypter) {
+        let private_key = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
+        let public_key = format!("{:X}|{:X}", private_key.e(), private_key.n());
+        (private_key, CardEncrypter::new(&public_key).unwrap())
+    }
+
+    #[test]
+    fn test_encrypt_card() {
+        let (private_key, card_encrypter) = key_pair();
+        let card = CardData::new("4111111111111111", "03", "2030")
+            .with_cvc("737")
+            .with_holder_name("John Smith");
+
+        let encrypted = card_encrypter.encrypt_card(&card).unwrap();
+        let number = decrypt(&private_key, &encrypted.encrypted_card_number);
+        assert_eq!(number["number"], "4111111111111111");
+        assert!(number["generationtime"].as_str().unwrap().ends_with('Z'));
+        assert_eq!(
+            decrypt(&private_key, &encrypted.encrypted_expiry_month)["expiryMonth"],
+            "03"
+        );
+        assert_eq!(
+            decrypt(
+                &private_key,
+                encrypted.encrypted_security_code.as_ref().unwrap()
+            )["cvc"],
+            "737"
+        );
+
+        let details = serde_json::to_value(PaymentMethodDetails::from(encrypted)).unwrap();
+        assert_eq!(details["type"], "scheme");
+        assert_eq!(details["holderName"], "John Smith");
+        assert!(details["encryptedCardNumber"]
+            .as_str()
+            .unwrap()
+            .starts_with(PREFIX));
+        assert!(!details.to_string().contains("4111111111111111"));
+    }
+
+    #[test]
+    fn test_encrypt_card_json() {
+        let (private_key, card_encrypter) = key_pair();
+        let card = CardData::new("4111111111111111", "03", "2030");
+
+        let encrypted = card_encrypter.encrypt_card_json(&card).unwrap();
+        let json = decrypt(&private_key, &encrypted);
+        assert_eq!(json["number"], "4111111111111111");
+        assert_eq!(json["expiryYear"], "2030");
+        assert!(json.get("cvc").is_none());
+
+        // Fresh keys and nonces make every encryption different.
+        assert_ne!(encrypted, card_encrypter.encrypt_card_json(&card).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_public_key() {
+        assert!(CardEncrypter::new("not a key").is_err());
+        assert!(CardEncrypter::new("10001|XYZ").is_err());
+        assert!(CardEncrypter::new("10001|").is_err());
+    }
+}
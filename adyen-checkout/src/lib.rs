@@ -6,10 +6,21 @@
 //! ## Features
 //!
 //! - **Payment Methods**: Retrieve available payment methods for a merchant
-//! - **Payment Sessions**: Create and manage checkout sessions
+//! - **Payment Sessions**: Create checkout sessions and verify their result
 //! - **Payments**: Process payment transactions
 //! - **Payment Details**: Submit additional details for payments (3DS, etc.)
-//! - **Card Details**: Get card brand and validation information
+//! - **Typed Actions**: Redirect, 3DS2, QR code, voucher, await and SDK actions with helpers
+//! - **Card Details**: Detect typed card brands and funding source from a card number
+//! - **POS Mobile**: Create sessions for the Tap to Pay SDKs
+//! - **Pay by Link**: Create, retrieve and expire hosted payment links
+//! - **Donations**: Make Adyen Giving donations with the token from a payment
+//! - **Stored Payment Methods**: List and delete tokens stored for a shopper
+//! - **Split Payments**: Split payments, captures and refunds between accounts
+//! - **Native 3DS2**: Request app-based 3D Secure 2 authentication
+//! - **Recurring**: Mark merchant-initiated payments and set up SEPA, UPI and card mandates
+//! - **Apple Pay**: Request merchant sessions for Apple Pay merchant validation
+//! - **Client-Side Encryption**: Encrypt card data with the client encryption
+//!   public key behind the `encryption` feature
 //!
 //! ## Example
 //!
@@ -42,6 +53,8 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod api;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod types;
 
 // Re-export main API and commonly used types
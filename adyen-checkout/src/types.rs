@@ -1,32 +1,69 @@
 //! Types for Checkout API requests and responses.
 
+pub mod actions;
 pub mod additional;
 pub mod card_details;
+pub mod donations;
+pub mod line_items;
 pub mod modifications;
 pub mod orders;
+pub mod payment_links;
 pub mod payment_methods;
 pub mod payments;
+pub mod recurring;
 pub mod sessions;
+pub mod splits;
+pub mod stored_payment_methods;
+pub mod three_ds;
 
 // Re-export main types
+pub use actions::{
+    AwaitAction, PaymentAction, QrCodeAction, RedirectAction, SdkAction, ThreeDS2Action,
+    VoucherAction,
+};
 pub use additional::{
     ApplePaySessionRequest, ApplePaySessionResponse, BalanceCheckRequest, BalanceCheckResponse,
-    ListStoredPaymentMethodsResponse, OriginKeysRequest, OriginKeysResponse, PaymentLinkRequest,
-    PaymentLinkResponse, SessionResultResponse, StoredPaymentMethodResource,
+    OriginKeysRequest, OriginKeysResponse,
+};
+pub use card_details::{
+    CardBrand, CardBrandDetails, CardDetailsRequest, CardDetailsRequestBuilder,
+    CardDetailsResponse, FundingSource,
 };
-pub use card_details::{CardBrand, CardDetailsRequest, CardDetailsResponse};
+pub use donations::{
+    DonationCampaign, DonationCampaignsRequest, DonationCampaignsResponse, DonationPaymentMethod,
+    DonationRequest, DonationResponse, DonationStatus,
+};
+pub use line_items::LineItem;
 pub use modifications::{
     AmountUpdateRequest, AmountUpdateResponse, CancelRequest, CancelResponse, CaptureRequest,
-    CaptureResponse, RefundRequest, RefundResponse, ReversalRequest, ReversalResponse,
+    CaptureResponse, IndustryUsage, MerchantRefundReason, ModificationStatus, RefundRequest,
+    RefundResponse, ReversalRequest, ReversalResponse,
 };
 pub use orders::{
     CancelOrderRequest, CancelOrderResponse, CreateOrderRequest, CreateOrderResponse,
-    DonationCampaignsRequest, DonationCampaignsResponse, DonationRequest, DonationResponse,
     PayPalUpdateOrderRequest, PayPalUpdateOrderResponse,
 };
+pub use payment_links::{
+    PaymentLinkRequest, PaymentLinkRequestBuilder, PaymentLinkResponse, PaymentLinkStatus,
+    UpdatePaymentLinkRequest,
+};
 pub use payment_methods::{PaymentMethod, PaymentMethodsRequest, PaymentMethodsResponse};
 pub use payments::{
-    PaymentAction, PaymentDetailsRequest, PaymentDetailsResponse, PaymentRequest, PaymentResponse,
-    PaymentResultCode,
+    InstallmentPlan, Installments, PaymentDetailsRequest, PaymentDetailsResponse, PaymentRequest,
+    PaymentResponse, PaymentResultCode, RiskData, Surcharge,
+};
+pub use recurring::{
+    BillingAttemptsRule, Mandate, MandateAmountRule, MandateFrequency, RecurringProcessingModel,
+    ShopperInteraction,
+};
+pub use sessions::{
+    CreateCheckoutSessionRequest, CreateCheckoutSessionResponse, PosSdkSessionRequest,
+    PosSdkSessionResponse, SessionPayment, SessionPaymentMethod, SessionResultResponse,
+    SessionStatus, StorePaymentMethodMode,
+};
+pub use splits::{Split, SplitAmount, SplitType};
+pub use stored_payment_methods::{ListStoredPaymentMethodsResponse, StoredPaymentMethodResource};
+pub use three_ds::{
+    AttemptAuthentication, AuthenticationData, ChallengeIndicator, ChallengeWindowSize,
+    DeviceChannel, NativeThreeDS, ThreeDS2RequestData, ThreeDSRequestData,
 };
-pub use sessions::{CreateCheckoutSessionRequest, CreateCheckoutSessionResponse};
@@ -0,0 +1,432 @@
+//! Actions returned by `/payments` and `/payments/details` to complete a payment.
+
+// rkyv's derive output for tuple variants binds their fields as `_0`.
+#![cfg_attr(feature = "rkyv", allow(clippy::used_underscore_binding))]
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Action required to complete a payment.
+///
+/// Adyen tags actions with a `type` field; 3D Secure 2 actions additionally
+/// carry a `subtype` of `fingerprint` or `challenge`. Action types not known to
+/// this library are kept as [`PaymentAction::Other`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum PaymentAction {
+    /// Redirect the shopper to a URL.
+    Redirect(RedirectAction),
+    /// Collect the 3D Secure 2 device fingerprint.
+    ThreeDS2Fingerprint(ThreeDS2Action),
+    /// Present the 3D Secure 2 challenge to the shopper.
+    ThreeDS2Challenge(ThreeDS2Action),
+    /// Display a QR code to the shopper.
+    QrCode(QrCodeAction),
+    /// Show a voucher the shopper pays offline.
+    Voucher(VoucherAction),
+    /// Wait for the shopper to complete the payment in another app.
+    Await(AwaitAction),
+    /// Hand over to a payment method's native SDK.
+    Sdk(SdkAction),
+    /// Action of a type not known to this library.
+    Other(
+        #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
+        HashMap<String, serde_json::Value>,
+    ),
+}
+
+impl PaymentAction {
+    /// Get the action type as sent by Adyen, e.g. `redirect` or `threeDS2`.
+    #[must_use]
+    pub fn action_type(&self) -> &str {
+        match self {
+            Self::Redirect(_) => "redirect",
+            Self::ThreeDS2Fingerprint(_) | Self::ThreeDS2Challenge(_) => "threeDS2",
+            Self::QrCode(_) => "qrCode",
+            Self::Voucher(_) => "voucher",
+            Self::Await(_) => "await",
+            Self::Sdk(_) => "sdk",
+            Self::Other(fields) => fields
+                .get("type")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Get the URL to redirect the shopper to, for redirect actions.
+    #[must_use]
+    pub fn redirect_url(&self) -> Option<&str> {
+        match self {
+            Self::Redirect(action) => Some(&action.url),
+            _ => None,
+        }
+    }
+
+    /// Get the 3D Secure 2 token, for fingerprint and challenge actions.
+    #[must_use]
+    pub fn three_ds2_token(&self) -> Option<&str> {
+        match self {
+            Self::ThreeDS2Fingerprint(action) | Self::ThreeDS2Challenge(action) => {
+                Some(&action.token)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the QR code data, for QR code actions.
+    #[must_use]
+    pub fn qr_code_data(&self) -> Option<&str> {
+        match self {
+            Self::QrCode(action) => Some(&action.qr_code_data),
+            _ => None,
+        }
+    }
+
+    /// Get the payment data to send back with `/payments/details`.
+    #[must_use]
+    pub fn payment_data(&self) -> Option<&str> {
+        match self {
+            Self::Redirect(action) => action.payment_data.as_deref(),
+            Self::ThreeDS2Fingerprint(action) | Self::ThreeDS2Challenge(action) => {
+                action.payment_data.as_deref()
+            }
+            Self::QrCode(action) => action.payment_data.as_deref(),
+            Self::Voucher(action) => action.payment_data.as_deref(),
+            Self::Await(action) => action.payment_data.as_deref(),
+            Self::Sdk(action) => action.payment_data.as_deref(),
+            Self::Other(fields) => fields
+                .get("paymentData")
+                .and_then(serde_json::Value::as_str),
+        }
+    }
+
+    /// Get the payment method the action belongs to, e.g. `scheme` or `ideal`.
+    #[must_use]
+    pub fn payment_method_type(&self) -> Option<&str> {
+        match self {
+            Self::Redirect(action) => action.payment_method_type.as_deref(),
+            Self::ThreeDS2Fingerprint(action) | Self::ThreeDS2Challenge(action) => {
+                action.payment_method_type.as_deref()
+            }
+            Self::QrCode(action) => action.payment_method_type.as_deref(),
+            Self::Voucher(action) => action.payment_method_type.as_deref(),
+            Self::Await(action) => action.payment_method_type.as_deref(),
+            Self::Sdk(action) => action.payment_method_type.as_deref(),
+            Self::Other(fields) => fields
+                .get("paymentMethodType")
+                .and_then(serde_json::Value::as_str),
+        }
+    }
+}
+
+/// Redirect the shopper to a URL.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RedirectAction {
+    /// The URL to redirect to.
+    pub url: String,
+
+    /// The HTTP method to use, `GET` or `POST`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Form fields to post to the URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<HashMap<String, String>>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+/// 3D Secure 2 fingerprint or challenge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDS2Action {
+    /// Token to pass to the 3D Secure 2 component.
+    pub token: String,
+
+    /// Token authorising the next step of the authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorisation_token: Option<String>,
+
+    /// URL of the 3D Secure 2 endpoint, for native flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+/// Display a QR code to the shopper.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct QrCodeAction {
+    /// The QR code data.
+    pub qr_code_data: String,
+
+    /// The URL encoded in the QR code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// When the QR code expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+/// Show a voucher the shopper pays offline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct VoucherAction {
+    /// The voucher reference the shopper pays with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// URL to download the voucher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+
+    /// URL of the payment instructions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions_url: Option<String>,
+
+    /// When the voucher expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+/// Wait for the shopper to complete the payment in another app.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AwaitAction {
+    /// URL the shopper may be sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+/// Hand over to a payment method's native SDK.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SdkAction {
+    /// Data to pass to the payment method's SDK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sdk_data: Option<HashMap<String, String>>,
+
+    /// URL used by the SDK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Payment data to send back with `/payments/details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<String>,
+
+    /// The payment method the action belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+fn tagged<T: Serialize>(
+    action: &T,
+    action_type: &str,
+    subtype: Option<&str>,
+) -> serde_json::Result<serde_json::Value> {
+    let mut value = serde_json::to_value(action)?;
+    if let serde_json::Value::Object(fields) = &mut value {
+        fields.insert("type".to_string(), action_type.into());
+        if let Some(subtype) = subtype {
+            fields.insert("subtype".to_string(), subtype.into());
+        }
+    }
+    Ok(value)
+}
+
+impl Serialize for PaymentAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Self::Redirect(action) => tagged(action, "redirect", None),
+            Self::ThreeDS2Fingerprint(action) => tagged(action, "threeDS2", Some("fingerprint")),
+            Self::ThreeDS2Challenge(action) => tagged(action, "threeDS2", Some("challenge")),
+            Self::QrCode(action) => tagged(action, "qrCode", None),
+            Self::Voucher(action) => tagged(action, "voucher", None),
+            Self::Await(action) => tagged(action, "await", None),
+            Self::Sdk(action) => tagged(action, "sdk", None),
+            Self::Other(fields) => return fields.serialize(serializer),
+        };
+        value.map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PaymentAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = serde_json::Map::deserialize(deserializer)?;
+        let field = |name: &str| {
+            fields
+                .get(name)
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_owned)
+        };
+        let (action_type, subtype) = (field("type"), field("subtype"));
+        let value = serde_json::Value::Object(fields);
+
+        // Older API versions use dedicated types instead of the `subtype` field.
+        let action = match (action_type.as_deref(), subtype.as_deref()) {
+            (Some("redirect"), _) => serde_json::from_value(value).map(Self::Redirect),
+            (Some("threeDS2"), Some("fingerprint")) | (Some("threeDS2Fingerprint"), _) => {
+                serde_json::from_value(value).map(Self::ThreeDS2Fingerprint)
+            }
+            (Some("threeDS2"), Some("challenge")) | (Some("threeDS2Challenge"), _) => {
+                serde_json::from_value(value).map(Self::ThreeDS2Challenge)
+            }
+            (Some("qrCode"), _) => serde_json::from_value(value).map(Self::QrCode),
+            (Some("voucher"), _) => serde_json::from_value(value).map(Self::Voucher),
+            (Some("await"), _) => serde_json::from_value(value).map(Self::Await),
+            (Some("sdk"), _) => serde_json::from_value(value).map(Self::Sdk),
+            _ => serde_json::from_value(value).map(Self::Other),
+        };
+        action.map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirect_action() {
+        let action: PaymentAction = serde_json::from_str(
+            r#"{
+                "type": "redirect",
+                "method": "GET",
+                "paymentMethodType": "ideal",
+                "url": "https://checkoutshopper-test.adyen.com/checkoutshopper/checkoutPaymentRedirect?redirectData=X6Xtf"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            action.redirect_url(),
+            Some("https://checkoutshopper-test.adyen.com/checkoutshopper/checkoutPaymentRedirect?redirectData=X6Xtf")
+        );
+        assert_eq!(action.payment_method_type(), Some("ideal"));
+        assert!(action.three_ds2_token().is_none());
+    }
+
+    #[test]
+    fn test_three_ds2_subtypes() {
+        let fingerprint: PaymentAction = serde_json::from_str(
+            r#"{"type": "threeDS2", "subtype": "fingerprint", "token": "eyJ0aHJlZURT", "paymentMethodType": "scheme"}"#,
+        )
+        .unwrap();
+        let challenge: PaymentAction = serde_json::from_str(
+            r#"{"type": "threeDS2Challenge", "token": "eyJhY3NSZWZl", "paymentData": "Ab02b4c0"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(fingerprint, PaymentAction::ThreeDS2Fingerprint(_)));
+        assert_eq!(fingerprint.three_ds2_token(), Some("eyJ0aHJlZURT"));
+        assert!(matches!(challenge, PaymentAction::ThreeDS2Challenge(_)));
+        assert_eq!(challenge.payment_data(), Some("Ab02b4c0"));
+
+        let json = serde_json::to_value(&challenge).unwrap();
+        assert_eq!(json["type"], "threeDS2");
+        assert_eq!(json["subtype"], "challenge");
+    }
+
+    #[test]
+    fn test_qr_code_and_sdk_actions() {
+        let qr: PaymentAction = serde_json::from_str(
+            r#"{"type": "qrCode", "qrCodeData": "BQB-Dmg3", "paymentMethodType": "swish"}"#,
+        )
+        .unwrap();
+        assert_eq!(qr.qr_code_data(), Some("BQB-Dmg3"));
+
+        let sdk: PaymentAction = serde_json::from_str(
+            r#"{"type": "sdk", "sdkData": {"appid": "wx123"}, "paymentMethodType": "wechatpaySDK"}"#,
+        )
+        .unwrap();
+        let PaymentAction::Sdk(action) = &sdk else {
+            panic!("expected sdk action");
+        };
+        assert_eq!(action.sdk_data.as_ref().unwrap()["appid"], "wx123");
+        assert_eq!(sdk.action_type(), "sdk");
+    }
+
+    #[test]
+    fn test_unknown_action_round_trip() {
+        let json = serde_json::json!({"type": "bankTransfer", "beneficiary": "Adyen"});
+        let action: PaymentAction = serde_json::from_value(json.clone()).unwrap();
+
+        assert!(matches!(action, PaymentAction::Other(_)));
+        assert_eq!(action.action_type(), "bankTransfer");
+        assert_eq!(serde_json::to_value(&action).unwrap(), json);
+    }
+}
@@ -1,66 +1,32 @@
 //! Additional types for extended Checkout API functionality.
 
-use adyen_core::Amount;
+use adyen_core::{AdyenError, Amount, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use serde::{Deserialize, Serialize};
 
-/// Response from getting session result.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SessionResultResponse {
-    /// A unique identifier of the session.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// The status of the session.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-}
-
-/// Response containing stored payment methods for a shopper.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ListStoredPaymentMethodsResponse {
-    /// Your merchant account.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub merchant_account: Option<String>,
-    /// Your reference to uniquely identify this shopper.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_reference: Option<String>,
-    /// List of all stored payment methods.
-    #[serde(default)]
-    pub stored_payment_methods: Vec<StoredPaymentMethodResource>,
-}
-
-/// Stored payment method resource.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct StoredPaymentMethodResource {
-    /// The unique payment method code.
-    pub type_: String,
-    /// Unique identifier of this stored payment method.
-    pub id: String,
-    /// The display name of the stored payment method.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// A shopper's contact details.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_email: Option<String>,
-    /// Additional stored payment method details.
-    #[serde(flatten)]
-    pub details: serde_json::Value,
-}
-
 /// Request for payment method balance check.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceCheckRequest {
     /// The merchant account identifier.
     pub merchant_account: String,
     /// The payment method for balance check.
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub payment_method: serde_json::Value,
 }
 
 /// Response from balance check request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceCheckResponse {
     /// The balance amount.
@@ -71,70 +37,78 @@ pub struct BalanceCheckResponse {
     pub transaction_limit: Option<Amount>,
 }
 
-/// Request for creating payment links.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PaymentLinkRequest {
-    /// The amount information for the transaction.
-    pub amount: Amount,
-    /// The merchant account identifier.
-    pub merchant_account: String,
-    /// A reference to uniquely identify the payment.
-    pub reference: String,
-    /// The URL to return to after payment completion.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_url: Option<String>,
-    /// Expiry date for the payment link.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
-    /// Description for the payment.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-}
-
-/// Response from creating payment links.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PaymentLinkResponse {
-    /// Unique identifier of the payment link.
-    pub id: String,
-    /// The amount information for the transaction.
-    pub amount: Amount,
-    /// The merchant account identifier.
-    pub merchant_account: String,
-    /// A reference to uniquely identify the payment.
-    pub reference: String,
-    /// Status of the payment link.
-    pub status: String,
-    /// URL of the payment link.
-    pub url: String,
-    /// Expiry date for the payment link.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
-}
-
 /// Request for getting Apple Pay session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Used to complete Apple Pay merchant validation when you use Adyen's Apple
+/// Pay certificate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplePaySessionRequest {
     /// The merchant identifier for Apple Pay.
     pub merchant_identifier: String,
-    /// The display name shown to users.
+    /// The name of your store, shown on the Apple Pay payment sheet.
     pub display_name: String,
-    /// The domain name for validation.
+    /// The domain the Apple Pay button is shown on, without the scheme.
     pub domain_name: String,
 }
 
+impl ApplePaySessionRequest {
+    /// Create a new Apple Pay session request.
+    pub fn new(
+        display_name: impl Into<String>,
+        domain_name: impl Into<String>,
+        merchant_identifier: impl Into<String>,
+    ) -> Self {
+        Self {
+            merchant_identifier: merchant_identifier.into(),
+            display_name: display_name.into(),
+            domain_name: domain_name.into(),
+        }
+    }
+}
+
 /// Response from Apple Pay session request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplePaySessionResponse {
-    /// The Apple Pay session data.
+    /// The base64-encoded Apple Pay merchant session.
     pub data: String,
 }
 
+impl ApplePaySessionResponse {
+    /// Decode the opaque merchant session object.
+    ///
+    /// The result is passed unchanged to `completeMerchantValidation` in
+    /// Apple Pay JS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not base64-encoded JSON.
+    pub fn merchant_session(&self) -> Result<serde_json::Value> {
+        let bytes = BASE64.decode(self.data.as_bytes()).map_err(|e| {
+            AdyenError::generic_with_source("invalid Apple Pay session data", Box::new(e))
+        })?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
 /// Request for getting origin keys.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OriginKeysRequest {
     /// List of origin URLs for which to generate keys.
@@ -143,8 +117,46 @@ pub struct OriginKeysRequest {
 
 /// Response containing origin keys.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OriginKeysResponse {
     /// Origin keys mapped by domain.
     pub origin_keys: std::collections::HashMap<String, String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apple_pay_session_request_serialization() {
+        let request =
+            ApplePaySessionRequest::new("Your Store", "www.example.com", "merchant.com.example");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "merchantIdentifier": "merchant.com.example",
+                "displayName": "Your Store",
+                "domainName": "www.example.com"
+            })
+        );
+    }
+
+    #[test]
+    fn test_apple_pay_merchant_session() {
+        let session = serde_json::json!({"merchantSessionIdentifier": "SSH123", "nonce": "abc"});
+        let response = ApplePaySessionResponse {
+            data: BASE64.encode(session.to_string()),
+        };
+        assert_eq!(response.merchant_session().unwrap(), session);
+
+        let invalid = ApplePaySessionResponse {
+            data: "not base64!".to_string(),
+        };
+        assert!(invalid.merchant_session().is_err());
+    }
+}
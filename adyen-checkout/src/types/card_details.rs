@@ -1,78 +1,244 @@
 //! Card details types for card validation and brand detection.
 
-use adyen_core::{AdyenError, Result};
+// rkyv's derive output for tuple variants binds their fields as `_0`.
+#![cfg_attr(feature = "rkyv", allow(clippy::used_underscore_binding))]
+
+use adyen_core::builder::Missing;
+use adyen_core::{AdyenError, CountryCode, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Request to get card details including brand and validation.
+///
+/// Provide either the first digits of the card number or the encrypted card
+/// number from the Checkout SDK.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CardDetailsRequest {
-    /// The card number to validate.
-    pub card_number: String,
+    /// The card number, or at least its first six digits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_number: Option<String>,
+
+    /// The encrypted card number from the Checkout SDK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_card_number: Option<String>,
 
     /// The merchant account identifier.
     pub merchant_account: String,
 
-    /// Supported brands to check against.
+    /// Brands to check the card against, instead of all brands configured on
+    /// the merchant account.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_brands: Option<Vec<String>>,
+    pub supported_brands: Option<Vec<CardBrand>>,
 
     /// The country code for country-specific validation.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 }
 
 /// Response containing card details and validation information.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CardDetailsResponse {
-    /// The detected card brand.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub brand: Option<CardBrand>,
-
-    /// Whether the card number is valid.
-    pub is_valid: bool,
-
-    /// Supported features for this card.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_features: Option<Vec<String>>,
+    /// The brands detected for the card, with whether each is supported.
+    ///
+    /// Co-badged cards return more than one brand.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub brands: Vec<CardBrandDetails>,
 
     /// Funding source of the card (debit, credit, etc.).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub funding_source: Option<FundingSource>,
 
-    /// Additional details about the card.
+    /// Whether the card is a commercial card.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_data: Option<HashMap<String, String>>,
+    pub is_card_commercial: Option<bool>,
+
+    /// The two-character country code of the card issuer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuing_country_code: Option<String>,
+}
+
+impl CardDetailsResponse {
+    /// Get the first detected brand, if any.
+    #[must_use]
+    pub fn brand(&self) -> Option<&CardBrand> {
+        self.brands.first().map(|details| &details.brand)
+    }
+
+    /// Get the detected brands that are supported.
+    pub fn supported_brands(&self) -> impl Iterator<Item = &CardBrand> {
+        self.brands
+            .iter()
+            .filter(|details| details.supported)
+            .map(|details| &details.brand)
+    }
 }
 
-/// Card brand information.
+/// A brand detected for a card.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
-pub struct CardBrand {
-    /// The brand type (visa, mc, amex, etc.).
+pub struct CardBrandDetails {
+    /// The card brand.
     #[serde(rename = "type")]
-    pub brand_type: String,
+    pub brand: CardBrand,
 
-    /// Whether this brand is supported.
+    /// Whether the brand is supported for the merchant account.
     pub supported: bool,
+}
 
-    /// CVC policy for this brand.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cvc_policy: Option<CvcPolicy>,
+/// Card brands, identified by the brand code used across the Checkout API.
+///
+/// Brands not known to this library deserialize into [`CardBrand::Unknown`],
+/// which keeps the original code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum CardBrand {
+    /// Visa (`visa`).
+    Visa,
+    /// Mastercard (`mc`).
+    Mastercard,
+    /// American Express (`amex`).
+    Amex,
+    /// Maestro (`maestro`).
+    Maestro,
+    /// Diners Club (`diners`).
+    Diners,
+    /// Discover (`discover`).
+    Discover,
+    /// JCB (`jcb`).
+    Jcb,
+    /// `UnionPay` (`cup`).
+    UnionPay,
+    /// Cartes Bancaires (`cartebancaire`).
+    CarteBancaire,
+    /// Bancontact card (`bcmc`).
+    Bancontact,
+    /// Dankort (`dankort`).
+    Dankort,
+    /// eftpos Australia (`eftpos_australia`).
+    EftposAustralia,
+    /// Elo (`elo`).
+    Elo,
+    /// Hipercard (`hipercard`).
+    Hipercard,
+    /// Interac (`interac_card`).
+    Interac,
+    /// A brand not known to this library.
+    Unknown(String),
+}
 
-    /// Whether this brand supports 3D Secure.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_luhn_check: Option<bool>,
+impl CardBrand {
+    /// Get the brand code as used by Adyen, e.g. `mc`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Visa => "visa",
+            Self::Mastercard => "mc",
+            Self::Amex => "amex",
+            Self::Maestro => "maestro",
+            Self::Diners => "diners",
+            Self::Discover => "discover",
+            Self::Jcb => "jcb",
+            Self::UnionPay => "cup",
+            Self::CarteBancaire => "cartebancaire",
+            Self::Bancontact => "bcmc",
+            Self::Dankort => "dankort",
+            Self::EftposAustralia => "eftpos_australia",
+            Self::Elo => "elo",
+            Self::Hipercard => "hipercard",
+            Self::Interac => "interac_card",
+            Self::Unknown(code) => code,
+        }
+    }
 
-    /// Additional brand-specific data.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_data: Option<HashMap<String, String>>,
+    /// Check if this is a brand not known to this library.
+    #[must_use]
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl From<&str> for CardBrand {
+    fn from(code: &str) -> Self {
+        match code {
+            "visa" => Self::Visa,
+            "mc" => Self::Mastercard,
+            "amex" => Self::Amex,
+            "maestro" => Self::Maestro,
+            "diners" => Self::Diners,
+            "discover" => Self::Discover,
+            "jcb" => Self::Jcb,
+            "cup" => Self::UnionPay,
+            "cartebancaire" => Self::CarteBancaire,
+            "bcmc" => Self::Bancontact,
+            "dankort" => Self::Dankort,
+            "eftpos_australia" => Self::EftposAustralia,
+            "elo" => Self::Elo,
+            "hipercard" => Self::Hipercard,
+            "interac_card" => Self::Interac,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::str::FromStr for CardBrand {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl std::fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for CardBrand {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CardBrand {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(code.as_ref()))
+    }
 }
 
 /// CVC policy for a card brand.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum CvcPolicy {
     /// CVC is required.
@@ -81,30 +247,61 @@ pub enum CvcPolicy {
     Optional,
     /// CVC is hidden/not used.
     Hidden,
+    /// A cvc policy not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Funding source of a card.
+///
+/// Adyen reports funding sources in upper case; both cases are accepted.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum FundingSource {
     /// Credit card.
+    #[serde(alias = "CREDIT")]
     Credit,
     /// Debit card.
+    #[serde(alias = "DEBIT")]
     Debit,
     /// Prepaid card.
+    #[serde(alias = "PREPAID")]
     Prepaid,
     /// Charge card.
+    #[serde(alias = "CHARGE")]
     Charge,
     /// Unknown funding source.
+    #[serde(other)]
     Unknown,
 }
 
+impl From<&str> for FundingSource {
+    /// Parse a funding source as reported in additional data, e.g. `CREDIT`.
+    fn from(code: &str) -> Self {
+        match code.to_ascii_lowercase().as_str() {
+            "credit" => Self::Credit,
+            "debit" => Self::Debit,
+            "prepaid" => Self::Prepaid,
+            "charge" => Self::Charge,
+            _ => Self::Unknown,
+        }
+    }
+}
+
 /// Builder for creating card details requests.
+///
+/// `build` is only available once the required merchant account is set.
 #[derive(Debug, Clone, Default)]
-pub struct CardDetailsRequestBuilder {
+pub struct CardDetailsRequestBuilder<M = Missing> {
     card_number: Option<String>,
-    merchant_account: Option<String>,
-    supported_brands: Option<Vec<String>>,
+    encrypted_card_number: Option<String>,
+    merchant_account: M,
+    supported_brands: Option<Vec<CardBrand>>,
     country_code: Option<String>,
 }
 
@@ -114,7 +311,9 @@ impl CardDetailsRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M> CardDetailsRequestBuilder<M> {
     /// Set the card number.
     #[must_use]
     pub fn card_number(mut self, card_number: impl Into<String>) -> Self {
@@ -122,16 +321,31 @@ impl CardDetailsRequestBuilder {
         self
     }
 
-    /// Set the merchant account.
+    /// Set the encrypted card number from the Checkout SDK.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
+    pub fn encrypted_card_number(mut self, encrypted_card_number: impl Into<String>) -> Self {
+        self.encrypted_card_number = Some(encrypted_card_number.into());
         self
     }
 
+    /// Set the merchant account.
+    #[must_use]
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> CardDetailsRequestBuilder<String> {
+        CardDetailsRequestBuilder {
+            card_number: self.card_number,
+            encrypted_card_number: self.encrypted_card_number,
+            merchant_account: merchant_account.into(),
+            supported_brands: self.supported_brands,
+            country_code: self.country_code,
+        }
+    }
+
     /// Add a supported brand.
     #[must_use]
-    pub fn supported_brand(mut self, brand: impl Into<String>) -> Self {
+    pub fn supported_brand(mut self, brand: impl Into<CardBrand>) -> Self {
         self.supported_brands
             .get_or_insert_with(Vec::new)
             .push(brand.into());
@@ -140,36 +354,54 @@ impl CardDetailsRequestBuilder {
 
     /// Set supported brands.
     #[must_use]
-    pub fn supported_brands(mut self, brands: Vec<String>) -> Self {
-        self.supported_brands = Some(brands);
+    pub fn supported_brands(mut self, brands: impl IntoIterator<Item = CardBrand>) -> Self {
+        self.supported_brands = Some(brands.into_iter().collect());
         self
     }
 
     /// Set the country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
     #[must_use]
-    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
-        self.country_code = Some(country_code.into());
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
         self
     }
+}
 
+impl CardDetailsRequestBuilder<String> {
     /// Build the card details request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are not set.
+    /// Returns an error if neither or both of the card number and encrypted
+    /// card number are set, or if the country code is invalid.
     pub fn build(self) -> Result<CardDetailsRequest> {
-        let card_number = self
-            .card_number
-            .ok_or_else(|| AdyenError::config("card_number is required"))?;
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
+        match (&self.card_number, &self.encrypted_card_number) {
+            (None, None) => {
+                return Err(AdyenError::config(
+                    "card_number or encrypted_card_number is required",
+                ))
+            }
+            (Some(_), Some(_)) => {
+                return Err(AdyenError::config(
+                    "card_number and encrypted_card_number are mutually exclusive",
+                ))
+            }
+            _ => {}
+        }
 
         Ok(CardDetailsRequest {
-            card_number,
-            merchant_account,
+            card_number: self.card_number,
+            encrypted_card_number: self.encrypted_card_number,
+            merchant_account: self.merchant_account,
             supported_brands: self.supported_brands,
-            country_code: self.country_code,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
         })
     }
 }
@@ -196,7 +428,8 @@ impl CardDetailsRequest {
         }
 
         Ok(Self {
-            card_number,
+            card_number: Some(card_number),
+            encrypted_card_number: None,
             merchant_account: merchant_account.into(),
             supported_brands: None,
             country_code: None,
@@ -204,7 +437,7 @@ impl CardDetailsRequest {
     }
 }
 
-/// Common card brand constants.
+/// Common card brand codes, convertible into [`CardBrand`].
 pub mod brands {
     /// Visa card brand.
     pub const VISA: &str = "visa";
@@ -239,17 +472,23 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(request.card_number, "4111111111111111");
+        assert_eq!(request.card_number.as_deref(), Some("4111111111111111"));
         assert_eq!(request.merchant_account, "TestMerchant");
-        assert_eq!(request.supported_brands.as_ref().unwrap().len(), 2);
-        assert_eq!(request.country_code, Some("NL".to_string()));
+        assert_eq!(
+            request.supported_brands,
+            Some(vec![CardBrand::Visa, CardBrand::Mastercard])
+        );
+        assert_eq!(
+            request.country_code.as_ref().map(CountryCode::as_str),
+            Some("NL")
+        );
     }
 
     #[test]
     fn test_card_details_request_simple() {
         let request = CardDetailsRequest::simple("4111111111111111", "TestMerchant").unwrap();
 
-        assert_eq!(request.card_number, "4111111111111111");
+        assert_eq!(request.card_number.as_deref(), Some("4111111111111111"));
         assert_eq!(request.merchant_account, "TestMerchant");
         assert!(request.supported_brands.is_none());
         assert!(request.country_code.is_none());
@@ -261,6 +500,83 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_card_details_request_encrypted_card_number() {
+        let request = CardDetailsRequest::builder()
+            .encrypted_card_number("adyenjs_0_1_25$...")
+            .merchant_account("TestMerchant")
+            .supported_brands([CardBrand::CarteBancaire, CardBrand::Visa])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "encryptedCardNumber": "adyenjs_0_1_25$...",
+                "merchantAccount": "TestMerchant",
+                "supportedBrands": ["cartebancaire", "visa"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_card_details_request_requires_one_card_number() {
+        let missing = CardDetailsRequest::builder()
+            .merchant_account("TestMerchant")
+            .build();
+        assert!(missing.is_err());
+
+        let both = CardDetailsRequest::builder()
+            .card_number("411111")
+            .encrypted_card_number("adyenjs_0_1_25$...")
+            .merchant_account("TestMerchant")
+            .build();
+        assert!(both.is_err());
+    }
+
+    #[test]
+    fn test_card_details_response_deserialization() {
+        let json = r#"{
+            "brands": [
+                {"type": "cartebancaire", "supported": true},
+                {"type": "visa", "supported": false},
+                {"type": "newbrand", "supported": true}
+            ],
+            "fundingSource": "DEBIT",
+            "isCardCommercial": false,
+            "issuingCountryCode": "FR"
+        }"#;
+
+        let response: CardDetailsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.brand(), Some(&CardBrand::CarteBancaire));
+        assert_eq!(
+            response.supported_brands().collect::<Vec<_>>(),
+            vec![
+                &CardBrand::CarteBancaire,
+                &CardBrand::Unknown("newbrand".to_string())
+            ]
+        );
+        assert_eq!(response.funding_source, Some(FundingSource::Debit));
+        assert_eq!(response.is_card_commercial, Some(false));
+        assert_eq!(response.issuing_country_code.as_deref(), Some("FR"));
+    }
+
+    #[test]
+    fn test_card_brand_round_trip() {
+        for brand in [
+            CardBrand::Visa,
+            CardBrand::Mastercard,
+            CardBrand::Amex,
+            CardBrand::CarteBancaire,
+            CardBrand::EftposAustralia,
+        ] {
+            let json = serde_json::to_string(&brand).unwrap();
+            assert_eq!(serde_json::from_str::<CardBrand>(&json).unwrap(), brand);
+        }
+        assert_eq!(CardBrand::from(brands::MASTERCARD), CardBrand::Mastercard);
+        assert!(CardBrand::from("newbrand").is_unknown());
+    }
+
     #[test]
     fn test_funding_source_serialization() {
         assert_eq!(
@@ -0,0 +1,328 @@
+//! Adyen Giving donation types.
+
+use crate::types::payments::PaymentResponse;
+use adyen_core::{AdyenError, Amount, PspReference, Result};
+use serde::{Deserialize, Serialize};
+
+/// Payment method used for a donation.
+///
+/// When donating with a donation token only the type is needed, as the
+/// payment details are taken from the original payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationPaymentMethod {
+    /// The payment method type, for example `scheme`.
+    #[serde(rename = "type")]
+    pub payment_method_type: String,
+}
+
+impl DonationPaymentMethod {
+    /// Create a donation payment method of the given type.
+    pub fn new(payment_method_type: impl Into<String>) -> Self {
+        Self {
+            payment_method_type: payment_method_type.into(),
+        }
+    }
+}
+
+/// Request to make a donation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationRequest {
+    /// The donation amount.
+    pub amount: Amount,
+    /// The merchant account identifier.
+    pub merchant_account: String,
+    /// Your reference for the donation.
+    pub reference: String,
+    /// The payment method used for the donation.
+    pub payment_method: DonationPaymentMethod,
+    /// The Adyen account of the charity receiving the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_account: Option<String>,
+    /// The ID of the donation campaign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_campaign_id: Option<String>,
+    /// The donation token returned in the original payment response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_token: Option<String>,
+    /// The PSP reference of the original payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_original_psp_reference: Option<PspReference>,
+    /// The URL to return to after a redirect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+    /// The shopper reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<String>,
+}
+
+impl DonationRequest {
+    /// Create a new donation request.
+    pub fn new(
+        merchant_account: impl Into<String>,
+        amount: Amount,
+        reference: impl Into<String>,
+        payment_method: DonationPaymentMethod,
+    ) -> Self {
+        Self {
+            amount,
+            merchant_account: merchant_account.into(),
+            reference: reference.into(),
+            payment_method,
+            donation_account: None,
+            donation_campaign_id: None,
+            donation_token: None,
+            donation_original_psp_reference: None,
+            return_url: None,
+            shopper_reference: None,
+        }
+    }
+
+    /// Set the charity account receiving the donation.
+    #[must_use]
+    pub fn with_donation_account(mut self, donation_account: impl Into<String>) -> Self {
+        self.donation_account = Some(donation_account.into());
+        self
+    }
+
+    /// Set the donation campaign.
+    #[must_use]
+    pub fn with_donation_campaign_id(mut self, campaign_id: impl Into<String>) -> Self {
+        self.donation_campaign_id = Some(campaign_id.into());
+        self
+    }
+
+    /// Set the donation token and PSP reference of the original payment.
+    #[must_use]
+    pub fn with_donation_token(
+        mut self,
+        donation_token: impl Into<String>,
+        original_psp_reference: PspReference,
+    ) -> Self {
+        self.donation_token = Some(donation_token.into());
+        self.donation_original_psp_reference = Some(original_psp_reference);
+        self
+    }
+
+    /// Take the donation token and PSP reference from the original payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payment response has no donation token or PSP
+    /// reference, for example because the payment was not authorised.
+    pub fn with_payment(self, payment: &PaymentResponse) -> Result<Self> {
+        let token = payment
+            .donation_token
+            .as_deref()
+            .ok_or_else(|| AdyenError::config("payment has no donation token"))?;
+        let psp_reference = payment
+            .psp_reference
+            .clone()
+            .ok_or_else(|| AdyenError::config("payment has no PSP reference"))?;
+        Ok(self.with_donation_token(token, psp_reference))
+    }
+
+    /// Set the return URL.
+    #[must_use]
+    pub fn with_return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.return_url = Some(return_url.into());
+        self
+    }
+
+    /// Set the shopper reference.
+    #[must_use]
+    pub fn with_shopper_reference(mut self, shopper_reference: impl Into<String>) -> Self {
+        self.shopper_reference = Some(shopper_reference.into());
+        self
+    }
+}
+
+/// Status of a donation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DonationStatus {
+    /// The donation was made.
+    Completed,
+    /// The donation is being processed.
+    Pending,
+    /// The donation was refused.
+    Refused,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Donation response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationResponse {
+    /// The unique identifier of the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The donation status.
+    pub status: DonationStatus,
+    /// The donation amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// The Adyen account of the charity receiving the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_account: Option<String>,
+    /// The merchant account identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_account: Option<String>,
+    /// Your reference for the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The payment made for the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<PaymentResponse>,
+}
+
+impl DonationResponse {
+    /// Whether the donation was made.
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.status == DonationStatus::Completed
+    }
+}
+
+/// Request for donation campaigns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationCampaignsRequest {
+    /// The merchant account identifier.
+    pub merchant_account: String,
+}
+
+/// Response with donation campaigns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationCampaignsResponse {
+    /// List of available donation campaigns.
+    #[serde(default)]
+    pub donation_campaigns: Vec<DonationCampaign>,
+}
+
+/// Donation campaign information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationCampaign {
+    /// The campaign identifier.
+    pub id: String,
+    /// The campaign name.
+    pub name: String,
+    /// The campaign description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The campaign URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adyen_core::Currency;
+
+    #[test]
+    fn test_donation_request_from_payment() {
+        let payment: PaymentResponse = serde_json::from_value(serde_json::json!({
+            "resultCode": "Authorised",
+            "pspReference": "991559660454807J",
+            "donationToken": "YOUR_DONATION_TOKEN"
+        }))
+        .unwrap();
+
+        let request = DonationRequest::new(
+            "TestMerchant",
+            Amount::from_major_units(5, Currency::EUR),
+            "donation-001",
+            DonationPaymentMethod::new("scheme"),
+        )
+        .with_donation_account("CHARITY_ACCOUNT")
+        .with_payment(&payment)
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["donationToken"], "YOUR_DONATION_TOKEN");
+        assert_eq!(json["donationOriginalPspReference"], "991559660454807J");
+        assert_eq!(json["paymentMethod"]["type"], "scheme");
+        assert_eq!(json["donationAccount"], "CHARITY_ACCOUNT");
+    }
+
+    #[test]
+    fn test_donation_request_requires_token() {
+        let payment: PaymentResponse = serde_json::from_value(serde_json::json!({
+            "resultCode": "Refused",
+            "pspReference": "991559660454807J"
+        }))
+        .unwrap();
+
+        let result = DonationRequest::new(
+            "TestMerchant",
+            Amount::from_major_units(5, Currency::EUR),
+            "donation-001",
+            DonationPaymentMethod::new("scheme"),
+        )
+        .with_payment(&payment);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_donation_response_deserialization() {
+        let response: DonationResponse = serde_json::from_value(serde_json::json!({
+            "id": "UNIQUE_RESOURCE_ID",
+            "status": "completed",
+            "donationAccount": "CHARITY_ACCOUNT",
+            "merchantAccount": "TestMerchant",
+            "reference": "donation-001",
+            "payment": {
+                "pspReference": "853623361553458C",
+                "resultCode": "Authorised"
+            }
+        }))
+        .unwrap();
+
+        assert!(response.is_completed());
+        assert_eq!(
+            response.payment.unwrap().psp_reference.unwrap(),
+            "853623361553458C"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Line item types for open invoice and itemized payments.
+//!
+//! Line items are required by open invoice payment methods such as Klarna,
+//! Afterpay and Ratepay, and are also used for partial captures and refunds.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A line item for the payment.
+///
+/// All amounts are in minor units of the payment currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    /// The item ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The item description.
+    pub description: String,
+
+    /// The quantity of the item.
+    pub quantity: u32,
+
+    /// The price per item including tax, in minor units.
+    pub amount_including_tax: u64,
+
+    /// The price per item excluding tax, in minor units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_excluding_tax: Option<u64>,
+
+    /// The tax amount per item, in minor units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_amount: Option<u64>,
+
+    /// The tax percentage in basis points, e.g. `2100` for 21%.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_percentage: Option<u32>,
+
+    /// The item category, e.g. `PHYSICAL_GOODS` or `DIGITAL_GOODS`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_category: Option<String>,
+
+    /// The stock keeping unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+
+    /// The brand of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+
+    /// The manufacturer of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
+
+    /// The color of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// The size of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+
+    /// A link to a picture of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+
+    /// A link to the product page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_url: Option<String>,
+
+    /// Additional item data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<HashMap<String, String>>,
+}
+
+impl LineItem {
+    /// Create a new line item with its price per item including tax, in
+    /// minor units.
+    #[must_use]
+    pub fn new(description: impl Into<String>, quantity: u32, amount_including_tax: u64) -> Self {
+        Self {
+            id: None,
+            description: description.into(),
+            quantity,
+            amount_including_tax,
+            amount_excluding_tax: None,
+            tax_amount: None,
+            tax_percentage: None,
+            item_category: None,
+            sku: None,
+            brand: None,
+            manufacturer: None,
+            color: None,
+            size: None,
+            image_url: None,
+            product_url: None,
+            additional_data: None,
+        }
+    }
+
+    /// Set the item ID.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the tax information.
+    ///
+    /// The amount excluding tax is derived from the amount including tax.
+    #[must_use]
+    pub fn with_tax(mut self, tax_amount: u64, tax_percentage: u32) -> Self {
+        self.amount_excluding_tax = Some(self.amount_including_tax.saturating_sub(tax_amount));
+        self.tax_amount = Some(tax_amount);
+        self.tax_percentage = Some(tax_percentage);
+        self
+    }
+
+    /// Set the item category.
+    #[must_use]
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.item_category = Some(category.into());
+        self
+    }
+
+    /// Set the stock keeping unit.
+    #[must_use]
+    pub fn with_sku(mut self, sku: impl Into<String>) -> Self {
+        self.sku = Some(sku.into());
+        self
+    }
+
+    /// Set the brand.
+    #[must_use]
+    pub fn with_brand(mut self, brand: impl Into<String>) -> Self {
+        self.brand = Some(brand.into());
+        self
+    }
+
+    /// Set the image URL.
+    #[must_use]
+    pub fn with_image_url(mut self, image_url: impl Into<String>) -> Self {
+        self.image_url = Some(image_url.into());
+        self
+    }
+
+    /// Set the product page URL.
+    #[must_use]
+    pub fn with_product_url(mut self, product_url: impl Into<String>) -> Self {
+        self.product_url = Some(product_url.into());
+        self
+    }
+
+    /// Get the total for this line including tax, in minor units.
+    #[must_use]
+    pub fn total_including_tax(&self) -> u64 {
+        self.amount_including_tax * u64::from(self.quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_item_creation() {
+        let line_item = LineItem::new("Test Product", 2, 1000)
+            .with_id("item-123")
+            .with_category("electronics");
+
+        assert_eq!(line_item.description, "Test Product");
+        assert_eq!(line_item.quantity, 2);
+        assert_eq!(line_item.amount_including_tax, 1000);
+        assert_eq!(line_item.total_including_tax(), 2000);
+        assert_eq!(line_item.id, Some("item-123".to_string()));
+        assert_eq!(line_item.item_category, Some("electronics".to_string()));
+    }
+
+    #[test]
+    fn test_line_item_serialization() {
+        let line_item = LineItem::new("Shoes", 1, 12100)
+            .with_id("shoes-1")
+            .with_tax(2100, 2100)
+            .with_sku("SKU-42")
+            .with_product_url("https://example.com/shoes");
+
+        assert_eq!(
+            serde_json::to_value(&line_item).unwrap(),
+            serde_json::json!({
+                "id": "shoes-1",
+                "description": "Shoes",
+                "quantity": 1,
+                "amountIncludingTax": 12100,
+                "amountExcludingTax": 10000,
+                "taxAmount": 2100,
+                "taxPercentage": 2100,
+                "sku": "SKU-42",
+                "productUrl": "https://example.com/shoes"
+            })
+        );
+    }
+}
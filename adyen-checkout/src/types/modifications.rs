@@ -1,10 +1,60 @@
 //! Payment modification types for captures, refunds, cancels, etc.
 
-use adyen_core::Amount;
+use crate::types::line_items::LineItem;
+use crate::types::splits::Split;
+use adyen_core::{Amount, PspReference, RequestId};
 use serde::{Deserialize, Serialize};
 
+/// Status of a modification request.
+///
+/// Modifications are processed asynchronously; the outcome is sent in a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ModificationStatus {
+    /// The request was received and will be processed.
+    Received,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Reason for a refund.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum MerchantRefundReason {
+    /// The payment was fraudulent.
+    #[serde(rename = "FRAUD")]
+    Fraud,
+    /// The shopper asked for the refund.
+    #[serde(rename = "CUSTOMER REQUEST")]
+    CustomerRequest,
+    /// The goods were returned.
+    #[serde(rename = "RETURN")]
+    Return,
+    /// The payment was a duplicate.
+    #[serde(rename = "DUPLICATE")]
+    Duplicate,
+    /// Any other reason.
+    #[serde(rename = "OTHER")]
+    Other,
+}
+
 /// Request to capture a payment.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureRequest {
     /// The merchant account identifier.
@@ -14,24 +64,95 @@ pub struct CaptureRequest {
     /// The reference for the capture.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    /// The items captured, required for partial captures of open invoice payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+    /// How to split the captured amount between accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl CaptureRequest {
+    /// Create a request capturing `amount`.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>, amount: Amount) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            amount,
+            reference: None,
+            line_items: None,
+            splits: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference for the capture.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the items captured.
+    #[must_use]
+    pub fn with_line_items(mut self, line_items: Vec<LineItem>) -> Self {
+        self.line_items = Some(line_items);
+        self
+    }
+
+    /// Set how to split the captured amount between accounts.
+    #[must_use]
+    pub fn with_splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this capture.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
 
 /// Response from a capture request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureResponse {
     /// The PSP reference of the capture.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+    /// The PSP reference of the captured payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<PspReference>,
     /// The status of the capture.
-    pub status: String,
+    pub status: ModificationStatus,
     /// The merchant account.
     pub merchant_account: String,
     /// The amount that was captured.
     pub amount: Amount,
+    /// The reference for the capture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The items captured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
 }
 
 /// Request to refund a payment.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RefundRequest {
     /// The merchant account identifier.
@@ -41,24 +162,109 @@ pub struct RefundRequest {
     /// The reference for the refund.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    /// The reason for the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_refund_reason: Option<MerchantRefundReason>,
+    /// The items refunded, required for partial refunds of open invoice payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+    /// How to split the refunded amount between accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl RefundRequest {
+    /// Create a request refunding `amount`.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>, amount: Amount) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            amount,
+            reference: None,
+            merchant_refund_reason: None,
+            line_items: None,
+            splits: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference for the refund.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the reason for the refund.
+    #[must_use]
+    pub fn with_reason(mut self, reason: MerchantRefundReason) -> Self {
+        self.merchant_refund_reason = Some(reason);
+        self
+    }
+
+    /// Set the items refunded.
+    #[must_use]
+    pub fn with_line_items(mut self, line_items: Vec<LineItem>) -> Self {
+        self.line_items = Some(line_items);
+        self
+    }
+
+    /// Set how to split the refundd amount between accounts.
+    #[must_use]
+    pub fn with_splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this refund.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
 
 /// Response from a refund request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RefundResponse {
     /// The PSP reference of the refund.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+    /// The PSP reference of the refunded payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<PspReference>,
     /// The status of the refund.
-    pub status: String,
+    pub status: ModificationStatus,
     /// The merchant account.
     pub merchant_account: String,
     /// The amount that was refunded.
     pub amount: Amount,
+    /// The reference for the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The reason for the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_refund_reason: Option<MerchantRefundReason>,
 }
 
 /// Request to cancel a payment.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Used with `cancel_payment` for a payment identified by its PSP reference,
+/// or with `cancel` for a payment identified by your `payment_reference`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelRequest {
     /// The merchant account identifier.
@@ -66,22 +272,81 @@ pub struct CancelRequest {
     /// The reference for the cancellation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    /// Your reference of the payment to cancel, required by `cancel`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_reference: Option<String>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl CancelRequest {
+    /// Create a cancel request.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            reference: None,
+            payment_reference: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference for the cancellation.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set your reference of the payment to cancel.
+    #[must_use]
+    pub fn with_payment_reference(mut self, payment_reference: impl Into<String>) -> Self {
+        self.payment_reference = Some(payment_reference.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this cancellation.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
 
 /// Response from a cancel request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelResponse {
     /// The PSP reference of the cancellation.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+    /// The PSP reference of the cancelled payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<PspReference>,
+    /// Your reference of the cancelled payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_reference: Option<String>,
     /// The status of the cancellation.
-    pub status: String,
+    pub status: ModificationStatus,
     /// The merchant account.
     pub merchant_account: String,
+    /// The reference for the cancellation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 }
 
 /// Request to reverse a payment.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ReversalRequest {
     /// The merchant account identifier.
@@ -89,22 +354,89 @@ pub struct ReversalRequest {
     /// The reference for the reversal.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl ReversalRequest {
+    /// Create a reversal request.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            reference: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference for the reversal.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this reversal.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
 
 /// Response from a reversal request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ReversalResponse {
     /// The PSP reference of the reversal.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+    /// The PSP reference of the reversed payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<PspReference>,
     /// The status of the reversal.
-    pub status: String,
+    pub status: ModificationStatus,
     /// The merchant account.
     pub merchant_account: String,
+    /// The reference for the reversal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+/// Type of an authorisation amount update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum IndustryUsage {
+    /// The shopper is charged later for a final amount, e.g. hotel stays.
+    #[serde(rename = "delayedCharge")]
+    DelayedCharge,
+    /// The final amount is not known at authorisation, e.g. car rental.
+    #[serde(rename = "installment")]
+    Installment,
+    /// No show charge, e.g. a missed hotel reservation.
+    #[serde(rename = "noShow")]
+    NoShow,
+    /// An industry usage not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Request to update the amount of a payment.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AmountUpdateRequest {
     /// The merchant account identifier.
@@ -114,18 +446,128 @@ pub struct AmountUpdateRequest {
     /// The reference for the amount update.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
+    /// The type of amount update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industry_usage: Option<IndustryUsage>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl AmountUpdateRequest {
+    /// Create a request updating the payment to `amount`.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>, amount: Amount) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            amount,
+            reference: None,
+            industry_usage: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference for the amount update.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the type of amount update.
+    #[must_use]
+    pub fn with_industry_usage(mut self, industry_usage: IndustryUsage) -> Self {
+        self.industry_usage = Some(industry_usage);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this amount update.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
 
 /// Response from an amount update request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AmountUpdateResponse {
     /// The PSP reference of the amount update.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+    /// The PSP reference of the updated payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<PspReference>,
     /// The status of the amount update.
-    pub status: String,
+    pub status: ModificationStatus,
     /// The merchant account.
     pub merchant_account: String,
     /// The updated amount.
     pub amount: Amount,
+    /// The reference for the amount update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adyen_core::Currency;
+
+    #[test]
+    fn test_partial_capture_with_line_items() {
+        let item = LineItem::new("Shoes", 1, 5000);
+        let request = CaptureRequest::new(
+            "TestMerchant",
+            Amount::from_minor_units(5000, Currency::EUR),
+        )
+        .with_reference("capture-1")
+        .with_line_items(vec![item])
+        .with_idempotency_key(RequestId::from_string("capture-1").unwrap());
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["merchantAccount"], "TestMerchant");
+        assert_eq!(json["lineItems"][0]["description"], "Shoes");
+        assert!(json.get("idempotencyKey").is_none());
+    }
+
+    #[test]
+    fn test_refund_with_splits() {
+        let request = RefundRequest::new(
+            "TestMerchant",
+            Amount::from_minor_units(5000, Currency::EUR),
+        )
+        .with_splits(vec![
+            Split::balance_account("BA00000000000000000000001", 4500).with_reference("seller"),
+            Split::commission(500),
+        ]);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["splits"][0]["account"], "BA00000000000000000000001");
+        assert_eq!(json["splits"][1]["amount"]["value"], 500);
+    }
+
+    #[test]
+    fn test_refund_reason_serialization() {
+        let request =
+            RefundRequest::new("TestMerchant", Amount::from_minor_units(500, Currency::EUR))
+                .with_reason(MerchantRefundReason::CustomerRequest);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["merchantRefundReason"], "CUSTOMER REQUEST");
+    }
+
+    #[test]
+    fn test_modification_status_deserialization() {
+        let status: ModificationStatus = serde_json::from_str("\"received\"").unwrap();
+        assert_eq!(status, ModificationStatus::Received);
+
+        let status: ModificationStatus = serde_json::from_str("\"queued\"").unwrap();
+        assert_eq!(status, ModificationStatus::Unknown);
+    }
 }
@@ -0,0 +1,530 @@
+//! Pay by Link types for creating and managing hosted payment links.
+
+use crate::types::line_items::LineItem;
+use crate::types::payments::Address;
+use adyen_core::builder::Missing;
+use adyen_core::validation::RequestValidator;
+use adyen_core::{Amount, CountryCode, Locale, Result};
+use serde::{Deserialize, Serialize};
+
+/// Status of a payment link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PaymentLinkStatus {
+    /// The link can be used to make payments.
+    Active,
+    /// The shopper completed the payment.
+    Completed,
+    /// The link expired or was manually expired.
+    Expired,
+    /// The payment was authorised.
+    Paid,
+    /// The payment is still being processed.
+    PaymentPending,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request to create a payment link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLinkRequest {
+    /// The amount information for the transaction.
+    pub amount: Amount,
+    /// The merchant account identifier.
+    pub merchant_account: String,
+    /// A reference to uniquely identify the payment.
+    pub reference: String,
+    /// The URL to return to after payment completion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+    /// Expiry date for the payment link, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Description for the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the link can be used for multiple payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reusable: Option<bool>,
+    /// ID of the theme used to style the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_id: Option<String>,
+    /// The shopper's country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<CountryCode>,
+    /// The shopper's locale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_locale: Option<Locale>,
+    /// The shopper reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<String>,
+    /// The shopper's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_email: Option<String>,
+    /// Billing address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    /// Delivery address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_address: Option<Address>,
+    /// Line items shown on the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+    /// Payment methods shown on the payment page; all others are hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_payment_methods: Option<Vec<String>>,
+    /// Payment methods hidden from the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_payment_methods: Option<Vec<String>>,
+}
+
+impl PaymentLinkRequest {
+    /// Create a new builder for payment link requests.
+    #[must_use]
+    pub fn builder() -> PaymentLinkRequestBuilder {
+        PaymentLinkRequestBuilder::new()
+    }
+
+    /// Check the formats of the request's fields.
+    ///
+    /// Checks that the reference is at most 80 characters, the currency and
+    /// address countries are ISO codes, the shopper email is valid, and the
+    /// return URL, if set, has a scheme shoppers can be redirected to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdyenError::Validation`](adyen_core::AdyenError::Validation)
+    /// listing every invalid field.
+    pub fn validate(&self) -> Result<()> {
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &self.reference)
+            .currency("amount", &self.amount);
+        if let Some(return_url) = &self.return_url {
+            validator.return_url("return_url", return_url);
+        }
+        if let Some(email) = &self.shopper_email {
+            validator.email("shopper_email", email);
+        }
+        for (field, address) in [
+            ("billing_address.country", &self.billing_address),
+            ("delivery_address.country", &self.delivery_address),
+        ] {
+            if let Some(country) = address.as_ref().and_then(|a| a.country.as_deref()) {
+                validator.country_code(field, country);
+            }
+        }
+        validator.finish()
+    }
+}
+
+/// Builder for creating payment link requests.
+///
+/// `build` is only available once the required amount, merchant account and
+/// reference are set.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentLinkRequestBuilder<A = Missing, M = Missing, R = Missing> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    return_url: Option<String>,
+    expires_at: Option<String>,
+    description: Option<String>,
+    reusable: Option<bool>,
+    theme_id: Option<String>,
+    country_code: Option<String>,
+    shopper_locale: Option<String>,
+    shopper_reference: Option<String>,
+    shopper_email: Option<String>,
+    billing_address: Option<Address>,
+    delivery_address: Option<Address>,
+    line_items: Option<Vec<LineItem>>,
+    allowed_payment_methods: Option<Vec<String>>,
+    blocked_payment_methods: Option<Vec<String>>,
+}
+
+impl PaymentLinkRequestBuilder {
+    /// Create a new builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A, M, R> PaymentLinkRequestBuilder<A, M, R> {
+    /// Set the payment amount.
+    #[must_use]
+    pub fn amount(self, amount: Amount) -> PaymentLinkRequestBuilder<Amount, M, R> {
+        PaymentLinkRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
+            expires_at: self.expires_at,
+            description: self.description,
+            reusable: self.reusable,
+            theme_id: self.theme_id,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+        }
+    }
+
+    /// Set the merchant account.
+    #[must_use]
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentLinkRequestBuilder<A, String, R> {
+        PaymentLinkRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            return_url: self.return_url,
+            expires_at: self.expires_at,
+            description: self.description,
+            reusable: self.reusable,
+            theme_id: self.theme_id,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+        }
+    }
+
+    /// Set the payment reference.
+    #[must_use]
+    pub fn reference(
+        self,
+        reference: impl Into<String>,
+    ) -> PaymentLinkRequestBuilder<A, M, String> {
+        PaymentLinkRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            return_url: self.return_url,
+            expires_at: self.expires_at,
+            description: self.description,
+            reusable: self.reusable,
+            theme_id: self.theme_id,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+        }
+    }
+
+    /// Set the URL the shopper returns to after payment.
+    #[must_use]
+    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
+        self.return_url = Some(return_url.into());
+        self
+    }
+
+    /// Set the link expiry time, in ISO 8601 format.
+    #[must_use]
+    pub fn expires_at(mut self, expires_at: impl Into<String>) -> Self {
+        self.expires_at = Some(expires_at.into());
+        self
+    }
+
+    /// Set the payment description.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set whether the link can be paid more than once.
+    #[must_use]
+    pub fn reusable(mut self, reusable: bool) -> Self {
+        self.reusable = Some(reusable);
+        self
+    }
+
+    /// Set the payment page theme.
+    #[must_use]
+    pub fn theme_id(mut self, theme_id: impl Into<String>) -> Self {
+        self.theme_id = Some(theme_id.into());
+        self
+    }
+
+    /// Set the shopper's country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
+    #[must_use]
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
+        self
+    }
+
+    /// Set the shopper's locale.
+    ///
+    /// Accepts a [`Locale`] or a string such as `nl-NL`, which is validated
+    /// by `build`.
+    #[must_use]
+    pub fn shopper_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.shopper_locale = Some(locale.as_ref().to_owned());
+        self
+    }
+
+    /// Set the shopper reference.
+    #[must_use]
+    pub fn shopper_reference(mut self, reference: impl Into<String>) -> Self {
+        self.shopper_reference = Some(reference.into());
+        self
+    }
+
+    /// Set the shopper's email.
+    #[must_use]
+    pub fn shopper_email(mut self, email: impl Into<String>) -> Self {
+        self.shopper_email = Some(email.into());
+        self
+    }
+
+    /// Set billing address.
+    #[must_use]
+    pub fn billing_address(mut self, address: Address) -> Self {
+        self.billing_address = Some(address);
+        self
+    }
+
+    /// Set delivery address.
+    #[must_use]
+    pub fn delivery_address(mut self, address: Address) -> Self {
+        self.delivery_address = Some(address);
+        self
+    }
+
+    /// Add a line item.
+    #[must_use]
+    pub fn line_item(mut self, item: LineItem) -> Self {
+        self.line_items.get_or_insert_with(Vec::new).push(item);
+        self
+    }
+
+    /// Set line items.
+    #[must_use]
+    pub fn line_items(mut self, items: Vec<LineItem>) -> Self {
+        self.line_items = Some(items);
+        self
+    }
+
+    /// Only show the given payment method types on the payment page.
+    #[must_use]
+    pub fn allowed_payment_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_payment_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Hide the given payment method types from the payment page.
+    #[must_use]
+    pub fn blocked_payment_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.blocked_payment_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl PaymentLinkRequestBuilder<Amount, String, String> {
+    /// Build the payment link request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the country code or shopper locale is invalid.
+    pub fn build(self) -> Result<PaymentLinkRequest> {
+        Ok(PaymentLinkRequest {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
+            expires_at: self.expires_at,
+            description: self.description,
+            reusable: self.reusable,
+            theme_id: self.theme_id,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
+            shopper_locale: self
+                .shopper_locale
+                .as_deref()
+                .map(Locale::new)
+                .transpose()?,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+        })
+    }
+
+    /// Build the payment link request and check the formats of its fields, see
+    /// [`PaymentLinkRequest::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`build`](Self::build), or
+    /// [`AdyenError::Validation`](adyen_core::AdyenError::Validation) listing
+    /// every field with an invalid format.
+    pub fn build_validated(self) -> Result<PaymentLinkRequest> {
+        let request = self.build()?;
+        request.validate()?;
+        Ok(request)
+    }
+}
+
+/// Payment link details returned when creating, retrieving or updating a link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLinkResponse {
+    /// Unique identifier of the payment link.
+    pub id: String,
+    /// The amount information for the transaction.
+    pub amount: Amount,
+    /// The merchant account identifier.
+    pub merchant_account: String,
+    /// A reference to uniquely identify the payment.
+    pub reference: String,
+    /// Status of the payment link.
+    pub status: PaymentLinkStatus,
+    /// URL of the payment link.
+    pub url: String,
+    /// Expiry date for the payment link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Whether the link can be used for multiple payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reusable: Option<bool>,
+    /// Description for the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID of the theme used to style the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_id: Option<String>,
+    /// Line items shown on the payment page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+}
+
+impl PaymentLinkResponse {
+    /// Whether the link can still be used to make a payment.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.status == PaymentLinkStatus::Active
+    }
+}
+
+/// Request to update a payment link.
+///
+/// Adyen only allows changing the status of a link, and only to `expired`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePaymentLinkRequest {
+    /// The new status of the payment link.
+    pub status: PaymentLinkStatus,
+}
+
+impl UpdatePaymentLinkRequest {
+    /// Create a request that expires the payment link.
+    #[must_use]
+    pub fn expire() -> Self {
+        Self {
+            status: PaymentLinkStatus::Expired,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adyen_core::Currency;
+
+    #[test]
+    fn test_payment_link_request_builder() {
+        let amount = Amount::from_major_units(25, Currency::EUR);
+        let request = PaymentLinkRequest::builder()
+            .amount(amount.clone())
+            .merchant_account("TestMerchant")
+            .reference("Link-001")
+            .expires_at("2026-12-31T23:59:59+01:00")
+            .reusable(true)
+            .theme_id("AZ1234567890")
+            .line_item(LineItem::new("Gift", 1, amount.minor_units()).with_id("gift-1"))
+            .allowed_payment_methods(["scheme", "ideal"])
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["reference"], "Link-001");
+        assert_eq!(json["reusable"], true);
+        assert_eq!(json["themeId"], "AZ1234567890");
+        assert_eq!(json["lineItems"][0]["id"], "gift-1");
+        assert_eq!(
+            json["allowedPaymentMethods"],
+            serde_json::json!(["scheme", "ideal"])
+        );
+        assert!(json.get("blockedPaymentMethods").is_none());
+    }
+
+    #[test]
+    fn test_payment_link_status() {
+        assert_eq!(
+            serde_json::to_value(UpdatePaymentLinkRequest::expire()).unwrap(),
+            serde_json::json!({"status": "expired"})
+        );
+        let status: PaymentLinkStatus = serde_json::from_str("\"paymentPending\"").unwrap();
+        assert_eq!(status, PaymentLinkStatus::PaymentPending);
+        let status: PaymentLinkStatus = serde_json::from_str("\"archived\"").unwrap();
+        assert_eq!(status, PaymentLinkStatus::Unknown);
+    }
+}
@@ -1,6 +1,7 @@
 //! Payment methods request and response types.
 
-use adyen_core::{AdyenError, Amount, Result};
+use adyen_core::builder::Missing;
+use adyen_core::{Amount, CountryCode, Locale, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,6 +10,11 @@ use std::collections::HashMap;
 /// This request is used to get the list of payment methods available
 /// for a specific merchant account, amount, country, and shopper.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodsRequest {
     /// The merchant account identifier.
@@ -20,11 +26,11 @@ pub struct PaymentMethodsRequest {
 
     /// The shopper's country code in ISO 3166-1 alpha-2 format.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 
     /// The shopper's locale in BCP 47 format.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_locale: Option<String>,
+    pub shopper_locale: Option<Locale>,
 
     /// The sales channel for the transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -45,6 +51,11 @@ pub struct PaymentMethodsRequest {
 
 /// The sales channel for the transaction.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum Channel {
     /// Online/web channel
@@ -54,10 +65,18 @@ pub enum Channel {
     Ios,
     /// Android application
     Android,
+    /// A channel not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Response containing available payment methods.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodsResponse {
     /// List of available payment methods.
@@ -74,6 +93,11 @@ pub struct PaymentMethodsResponse {
 
 /// A payment method that can be used for transactions.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethod {
     /// The payment method type (e.g., "scheme", "ideal", "paypal").
@@ -102,6 +126,11 @@ pub struct PaymentMethod {
 
 /// Configuration details for a payment method.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodConfiguration {
     /// Available currencies for this payment method.
@@ -110,11 +139,17 @@ pub struct PaymentMethodConfiguration {
 
     /// Additional configuration data.
     #[serde(flatten)]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub additional_data: HashMap<String, serde_json::Value>,
 }
 
 /// A stored payment method for a shopper.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredPaymentMethod {
     /// Unique identifier for the stored payment method.
@@ -154,6 +189,11 @@ pub struct StoredPaymentMethod {
 
 /// A group of related payment methods.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodGroup {
     /// The group name.
@@ -168,9 +208,11 @@ pub struct PaymentMethodGroup {
 }
 
 /// Builder for creating payment methods requests.
+///
+/// `build` is only available once the required merchant account is set.
 #[derive(Debug, Clone, Default)]
-pub struct PaymentMethodsRequestBuilder {
-    merchant_account: Option<String>,
+pub struct PaymentMethodsRequestBuilder<M = Missing> {
+    merchant_account: M,
     amount: Option<Amount>,
     country_code: Option<String>,
     shopper_locale: Option<String>,
@@ -186,12 +228,25 @@ impl PaymentMethodsRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M> PaymentMethodsRequestBuilder<M> {
     /// Set the merchant account identifier.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentMethodsRequestBuilder<String> {
+        PaymentMethodsRequestBuilder {
+            merchant_account: merchant_account.into(),
+            amount: self.amount,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            channel: self.channel,
+            store_payment_method: self.store_payment_method,
+            shopper_reference: self.shopper_reference,
+            additional_data: self.additional_data,
+        }
     }
 
     /// Set the transaction amount and currency.
@@ -202,16 +257,22 @@ impl PaymentMethodsRequestBuilder {
     }
 
     /// Set the shopper's country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
     #[must_use]
-    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
-        self.country_code = Some(country_code.into());
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
         self
     }
 
     /// Set the shopper's locale.
+    ///
+    /// Accepts a [`Locale`] or a string such as `nl-NL`, which is validated
+    /// by `build`.
     #[must_use]
-    pub fn shopper_locale(mut self, locale: impl Into<String>) -> Self {
-        self.shopper_locale = Some(locale.into());
+    pub fn shopper_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.shopper_locale = Some(locale.as_ref().to_owned());
         self
     }
 
@@ -244,22 +305,28 @@ impl PaymentMethodsRequestBuilder {
             .insert(key.into(), value.into());
         self
     }
+}
 
+impl PaymentMethodsRequestBuilder<String> {
     /// Build the payment methods request.
     ///
     /// # Errors
     ///
-    /// Returns an error if the merchant account is not set.
+    /// Returns an error if the country code or shopper locale is invalid.
     pub fn build(self) -> Result<PaymentMethodsRequest> {
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-
         Ok(PaymentMethodsRequest {
-            merchant_account,
+            merchant_account: self.merchant_account,
             amount: self.amount,
-            country_code: self.country_code,
-            shopper_locale: self.shopper_locale,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
+            shopper_locale: self
+                .shopper_locale
+                .as_deref()
+                .map(Locale::new)
+                .transpose()?,
             channel: self.channel,
             store_payment_method: self.store_payment_method,
             shopper_reference: self.shopper_reference,
@@ -299,8 +366,14 @@ mod tests {
 
         assert_eq!(request.merchant_account, "TestMerchant");
         assert_eq!(request.amount, Some(amount));
-        assert_eq!(request.country_code, Some("NL".to_string()));
-        assert_eq!(request.shopper_locale, Some("nl-NL".to_string()));
+        assert_eq!(
+            request.country_code.as_ref().map(CountryCode::as_str),
+            Some("NL")
+        );
+        assert_eq!(
+            request.shopper_locale.as_ref().map(Locale::as_str),
+            Some("nl-NL")
+        );
         assert_eq!(request.channel, Some(Channel::Web));
         assert_eq!(request.store_payment_method, Some(true));
         assert_eq!(request.shopper_reference, Some("test-shopper".to_string()));
@@ -319,12 +392,6 @@ mod tests {
         assert!(request.country_code.is_none());
     }
 
-    #[test]
-    fn test_payment_methods_request_missing_merchant_account() {
-        let result = PaymentMethodsRequest::builder().build();
-        assert!(result.is_err());
-    }
-
     #[test]
     fn test_channel_serialization() {
         assert_eq!(serde_json::to_string(&Channel::Web).unwrap(), "\"Web\"");
@@ -1,11 +1,31 @@
 //! Payment request and response types.
 
-use adyen_core::{AdyenError, Amount, Result};
+// rkyv's derive output for tuple variants binds their fields as `_0`.
+#![cfg_attr(feature = "rkyv", allow(clippy::used_underscore_binding))]
+
+use crate::types::actions::PaymentAction;
+use crate::types::card_details::FundingSource;
+use crate::types::line_items::LineItem;
+use crate::types::recurring::{Mandate, RecurringProcessingModel, ShopperInteraction};
+use crate::types::splits::Split;
+use crate::types::three_ds::{AuthenticationData, ThreeDS2RequestData};
+use adyen_core::additional_data::{AirlineData, EnhancedSchemeData, LodgingData};
+use adyen_core::builder::Missing;
+use adyen_core::validation::RequestValidator;
+use adyen_core::{
+    AdyenError, Amount, CountryCode, Locale, Metadata, PspReference, RequestId, Result,
+    SecretString,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Request to initiate a payment transaction.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentRequest {
     /// The payment amount and currency.
@@ -34,11 +54,11 @@ pub struct PaymentRequest {
 
     /// The shopper's country code.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 
     /// The shopper's locale.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_locale: Option<String>,
+    pub shopper_locale: Option<Locale>,
 
     /// The shopper reference.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,6 +72,26 @@ pub struct PaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub store_payment_method: Option<bool>,
 
+    /// The sales channel; `ContAuth` marks a merchant-initiated payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// The type of recurring payment, when storing or using stored details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_processing_model: Option<RecurringProcessingModel>,
+
+    /// The mandate for recurring charges, e.g. for SEPA or UPI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<Mandate>,
+
+    /// The number of installments and the installment plan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments: Option<Installments>,
+
+    /// The surcharge or convenience fee added to the amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surcharge: Option<Surcharge>,
+
     /// Additional data for the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
@@ -67,23 +107,59 @@ pub struct PaymentRequest {
     /// Delivery address for the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delivery_address: Option<Address>,
+
+    /// Line items, required for open invoice payment methods such as Klarna.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<LineItem>>,
+
+    /// How to split the payment between accounts (Adyen for Platforms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+
+    /// Data for Adyen's risk engine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_data: Option<RiskData>,
+
+    /// Your own key-value data, returned in the webhooks of the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// 3D Secure authentication settings, e.g. to request the native flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_data: Option<AuthenticationData>,
+
+    /// Additional 3D Secure 2 data for the issuer.
+    #[serde(
+        rename = "threeDS2RequestData",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub three_ds2_request_data: Option<ThreeDS2RequestData>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
 }
 
 /// Payment method details for different payment types.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum PaymentMethodDetails {
     /// Credit/debit card payment.
     #[serde(rename = "scheme")]
     Card {
         /// The card number.
-        number: String,
+        number: SecretString,
         /// The expiry month (MM).
         expiry_month: String,
         /// The expiry year (YYYY).
         expiry_year: String,
         /// The card security code.
-        cvc: String,
+        cvc: SecretString,
         /// The cardholder name.
         #[serde(skip_serializing_if = "Option::is_none")]
         holder_name: Option<String>,
@@ -120,11 +196,19 @@ pub enum PaymentMethodDetails {
 
     /// Generic payment method for other types.
     #[serde(untagged)]
-    Other(HashMap<String, serde_json::Value>),
+    Other(
+        #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
+        HashMap<String, serde_json::Value>,
+    ),
 }
 
 /// Browser information for web payments.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserInfo {
     /// The accept header value.
@@ -155,8 +239,191 @@ pub struct BrowserInfo {
     pub user_agent: String,
 }
 
+/// Data for Adyen's risk engine.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskData {
+    /// Device fingerprint collected by the Checkout SDK.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_data: Option<String>,
+
+    /// Values for the custom risk fields configured in your Customer Area.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<HashMap<String, String>>,
+
+    /// Offset added to the fraud score; negative values lower the risk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraud_offset: Option<i32>,
+
+    /// The risk profile to assign to the payment, instead of the merchant
+    /// account's default profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_reference: Option<String>,
+}
+
+impl RiskData {
+    /// Create empty risk data.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the device fingerprint collected by the Checkout SDK.
+    #[must_use]
+    pub fn with_client_data(mut self, client_data: impl Into<String>) -> Self {
+        self.client_data = Some(client_data.into());
+        self
+    }
+
+    /// Add a custom risk field.
+    #[must_use]
+    pub fn with_custom_field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.custom_fields
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the fraud offset.
+    #[must_use]
+    pub fn with_fraud_offset(mut self, fraud_offset: i32) -> Self {
+        self.fraud_offset = Some(fraud_offset);
+        self
+    }
+
+    /// Set the risk profile reference.
+    #[must_use]
+    pub fn with_profile_reference(mut self, profile_reference: impl Into<String>) -> Self {
+        self.profile_reference = Some(profile_reference.into());
+        self
+    }
+}
+
+/// Installment options for a card payment, e.g. in Brazil, Mexico or Japan.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_checkout::types::{InstallmentPlan, Installments};
+///
+/// let installments = Installments::new(3).with_plan(InstallmentPlan::WithInterest);
+/// assert_eq!(installments.value, 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Installments {
+    /// The number of installments.
+    pub value: u32,
+
+    /// The installment plan, required for some plans in Brazil and Mexico.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<InstallmentPlan>,
+
+    /// Extra number of installments or bonus payments, used in Japan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<u32>,
+}
+
+impl Installments {
+    /// Create installments with the given number of installments.
+    #[must_use]
+    pub fn new(value: u32) -> Self {
+        Self {
+            value,
+            plan: None,
+            extra: None,
+        }
+    }
+
+    /// Set the installment plan.
+    #[must_use]
+    pub fn with_plan(mut self, plan: InstallmentPlan) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    /// Set the extra number of installments.
+    #[must_use]
+    pub fn with_extra(mut self, extra: u32) -> Self {
+        self.extra = Some(extra);
+        self
+    }
+}
+
+/// Installment plan type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallmentPlan {
+    /// Fixed installments without interest.
+    Regular,
+    /// Revolving credit: the shopper chooses how much to pay each month.
+    Revolving,
+    /// Installments with interest charged by the issuer (Brazil).
+    WithInterest,
+    /// Buy now, pay later: the first installment is deferred (Mexico).
+    #[serde(rename = "buynow_paylater")]
+    BuyNowPayLater,
+    /// Bonus payments (Japan).
+    Bonus,
+    /// Installments with interest and bonus payments (Mexico).
+    InterestBonus,
+    /// Installments without interest, with bonus payments (Mexico).
+    NointerestBonus,
+    /// Percentage refund on interest-bearing installments (Mexico).
+    #[serde(rename = "interes_refund_prctg")]
+    InterestRefundPercentage,
+    /// Percentage refund on interest-free installments (Mexico).
+    #[serde(rename = "nointeres_refund_prctg")]
+    NointerestRefundPercentage,
+    /// Percentage refund on installments (Mexico).
+    #[serde(rename = "refund_prctg")]
+    RefundPercentage,
+    /// An installment plan not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A surcharge or convenience fee charged to the shopper.
+///
+/// Where surcharging is allowed, the fee usually depends on the card's
+/// funding source and issuing country. Get these before the payment from
+/// [`CheckoutApi::card_details`](crate::CheckoutApi::card_details), or after
+/// it from [`PaymentResponse::funding_source`] and
+/// [`PaymentResponse::issuer_country`]. The payment amount must include the
+/// surcharge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct Surcharge {
+    /// The surcharge, in minor units of the payment currency.
+    pub value: u64,
+}
+
 /// Address information.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// The street address.
@@ -186,6 +453,11 @@ pub struct Address {
 
 /// Response from a payment request.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentResponse {
     /// The result of the payment request.
@@ -193,7 +465,7 @@ pub struct PaymentResponse {
 
     /// Adyen's 16-character string reference associated with the transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub psp_reference: Option<String>,
+    pub psp_reference: Option<PspReference>,
 
     /// Action required to complete the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -214,10 +486,49 @@ pub struct PaymentResponse {
     /// The reason for the payment result.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refusal_reason: Option<String>,
+
+    /// Token for making an Adyen Giving donation with the same payment details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_token: Option<String>,
+}
+
+impl PaymentResponse {
+    /// Get an additional data value by key.
+    #[must_use]
+    pub fn additional_value(&self, key: &str) -> Option<&str> {
+        self.additional_data.as_ref()?.get(key).map(String::as_str)
+    }
+
+    /// Get the card's funding source from the `fundingSource` additional
+    /// data, e.g. to decide on a surcharge.
+    #[must_use]
+    pub fn funding_source(&self) -> Option<FundingSource> {
+        self.additional_value("fundingSource")
+            .map(FundingSource::from)
+    }
+
+    /// Get the country code of the card issuer from the `issuerCountry`
+    /// additional data.
+    #[must_use]
+    pub fn issuer_country(&self) -> Option<&str> {
+        self.additional_value("issuerCountry")
+    }
+
+    /// Get the first six digits of the card number from the `cardBin`
+    /// additional data.
+    #[must_use]
+    pub fn card_bin(&self) -> Option<&str> {
+        self.additional_value("cardBin")
+    }
 }
 
 /// The result code of a payment request.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum PaymentResultCode {
     /// The payment was successful.
@@ -238,51 +549,18 @@ pub enum PaymentResultCode {
     Cancelled,
     /// An error occurred.
     Error,
-}
-
-/// Action required to complete a payment.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "camelCase")]
-pub enum PaymentAction {
-    /// Redirect the shopper to a URL.
-    #[serde(rename = "redirect")]
-    Redirect {
-        /// The URL to redirect to.
-        url: String,
-        /// The HTTP method to use.
-        method: String,
-        /// Additional data to send.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        data: Option<HashMap<String, String>>,
-    },
-
-    /// 3D Secure 2 authentication.
-    #[serde(rename = "threeDS2")]
-    ThreeDS2 {
-        /// The authentication token.
-        token: String,
-        /// Additional authentication data.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        authentication_data: Option<HashMap<String, String>>,
-    },
-
-    /// Display a QR code to the shopper.
-    #[serde(rename = "qrCode")]
-    QrCode {
-        /// The QR code data.
-        qr_code_data: String,
-        /// The URL encoded in the QR code.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        url: Option<String>,
-    },
-
-    /// Generic action for other types.
-    #[serde(untagged)]
-    Other(HashMap<String, serde_json::Value>),
+    /// A payment result code not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Fraud detection results.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct FraudResult {
     /// The fraud score.
@@ -295,6 +573,11 @@ pub struct FraudResult {
 
 /// Individual fraud check result.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct FraudCheckResult {
     /// The name of the fraud check.
@@ -310,6 +593,11 @@ pub struct FraudCheckResult {
 
 /// Request to submit additional payment details.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentDetailsRequest {
     /// The payment details to submit.
@@ -326,6 +614,11 @@ pub struct PaymentDetailsRequest {
 
 /// Response from submitting payment details.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentDetailsResponse {
     /// The result of the payment details submission.
@@ -333,7 +626,7 @@ pub struct PaymentDetailsResponse {
 
     /// Adyen's 16-character string reference.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub psp_reference: Option<String>,
+    pub psp_reference: Option<PspReference>,
 
     /// Additional action required.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -346,15 +639,34 @@ pub struct PaymentDetailsResponse {
     /// The merchant reference.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub merchant_reference: Option<String>,
+
+    /// Token for making an Adyen Giving donation with the same payment details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation_token: Option<String>,
 }
 
 /// Builder for creating payment requests.
+///
+/// `build` is only available once the required amount, merchant account,
+/// reference and return URL are set.
+///
+/// ```compile_fail
+/// use adyen_checkout::PaymentRequest;
+/// use adyen_core::{Amount, Currency};
+///
+/// // No return URL, so there is no `build` method.
+/// let request = PaymentRequest::builder()
+///     .amount(Amount::from_major_units(100, Currency::EUR))
+///     .merchant_account("TestMerchant")
+///     .reference("Order-12345")
+///     .build();
+/// ```
 #[derive(Debug, Clone, Default)]
-pub struct PaymentRequestBuilder {
-    amount: Option<Amount>,
-    merchant_account: Option<String>,
-    reference: Option<String>,
-    return_url: Option<String>,
+pub struct PaymentRequestBuilder<A = Missing, M = Missing, R = Missing, U = Missing> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    return_url: U,
     payment_method: Option<PaymentMethodDetails>,
     channel: Option<String>,
     origin: Option<String>,
@@ -363,10 +675,22 @@ pub struct PaymentRequestBuilder {
     shopper_reference: Option<String>,
     shopper_email: Option<String>,
     store_payment_method: Option<bool>,
+    shopper_interaction: Option<ShopperInteraction>,
+    recurring_processing_model: Option<RecurringProcessingModel>,
+    mandate: Option<Mandate>,
+    installments: Option<Installments>,
+    surcharge: Option<u64>,
     additional_data: Option<HashMap<String, String>>,
     browser_info: Option<BrowserInfo>,
     billing_address: Option<Address>,
     delivery_address: Option<Address>,
+    line_items: Option<Vec<LineItem>>,
+    splits: Option<Vec<Split>>,
+    risk_data: Option<RiskData>,
+    metadata: Option<HashMap<String, String>>,
+    authentication_data: Option<AuthenticationData>,
+    three_ds2_request_data: Option<ThreeDS2RequestData>,
+    idempotency_key: Option<RequestId>,
 }
 
 impl PaymentRequestBuilder {
@@ -375,33 +699,153 @@ impl PaymentRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<A, M, R, U> PaymentRequestBuilder<A, M, R, U> {
     /// Set the payment amount.
     #[must_use]
-    pub fn amount(mut self, amount: Amount) -> Self {
-        self.amount = Some(amount);
-        self
+    pub fn amount(self, amount: Amount) -> PaymentRequestBuilder<Amount, M, R, U> {
+        PaymentRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
+            payment_method: self.payment_method,
+            channel: self.channel,
+            origin: self.origin,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            shopper_interaction: self.shopper_interaction,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            installments: self.installments,
+            surcharge: self.surcharge,
+            additional_data: self.additional_data,
+            browser_info: self.browser_info,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            authentication_data: self.authentication_data,
+            three_ds2_request_data: self.three_ds2_request_data,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the merchant account.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentRequestBuilder<A, String, R, U> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            return_url: self.return_url,
+            payment_method: self.payment_method,
+            channel: self.channel,
+            origin: self.origin,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            shopper_interaction: self.shopper_interaction,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            installments: self.installments,
+            surcharge: self.surcharge,
+            additional_data: self.additional_data,
+            browser_info: self.browser_info,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            authentication_data: self.authentication_data,
+            three_ds2_request_data: self.three_ds2_request_data,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the payment reference.
     #[must_use]
-    pub fn reference(mut self, reference: impl Into<String>) -> Self {
-        self.reference = Some(reference.into());
-        self
+    pub fn reference(self, reference: impl Into<String>) -> PaymentRequestBuilder<A, M, String, U> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            return_url: self.return_url,
+            payment_method: self.payment_method,
+            channel: self.channel,
+            origin: self.origin,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            shopper_interaction: self.shopper_interaction,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            installments: self.installments,
+            surcharge: self.surcharge,
+            additional_data: self.additional_data,
+            browser_info: self.browser_info,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            authentication_data: self.authentication_data,
+            three_ds2_request_data: self.three_ds2_request_data,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the return URL.
     #[must_use]
-    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
-        self.return_url = Some(return_url.into());
-        self
+    pub fn return_url(
+        self,
+        return_url: impl Into<String>,
+    ) -> PaymentRequestBuilder<A, M, R, String> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: return_url.into(),
+            payment_method: self.payment_method,
+            channel: self.channel,
+            origin: self.origin,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            shopper_interaction: self.shopper_interaction,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            installments: self.installments,
+            surcharge: self.surcharge,
+            additional_data: self.additional_data,
+            browser_info: self.browser_info,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            authentication_data: self.authentication_data,
+            three_ds2_request_data: self.three_ds2_request_data,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the payment method details.
@@ -426,16 +870,22 @@ impl PaymentRequestBuilder {
     }
 
     /// Set the shopper's country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
     #[must_use]
-    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
-        self.country_code = Some(country_code.into());
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
         self
     }
 
     /// Set the shopper's locale.
+    ///
+    /// Accepts a [`Locale`] or a string such as `nl-NL`, which is validated
+    /// by `build`.
     #[must_use]
-    pub fn shopper_locale(mut self, locale: impl Into<String>) -> Self {
-        self.shopper_locale = Some(locale.into());
+    pub fn shopper_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.shopper_locale = Some(locale.as_ref().to_owned());
         self
     }
 
@@ -460,6 +910,63 @@ impl PaymentRequestBuilder {
         self
     }
 
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the type of recurring payment.
+    #[must_use]
+    pub fn recurring_processing_model(mut self, model: RecurringProcessingModel) -> Self {
+        self.recurring_processing_model = Some(model);
+        self
+    }
+
+    /// Set the mandate for recurring charges.
+    #[must_use]
+    pub fn mandate(mut self, mandate: Mandate) -> Self {
+        self.mandate = Some(mandate);
+        self
+    }
+
+    /// Set the number of installments and the installment plan.
+    #[must_use]
+    pub fn installments(mut self, installments: Installments) -> Self {
+        self.installments = Some(installments);
+        self
+    }
+
+    /// Set the surcharge or convenience fee, in minor units.
+    ///
+    /// The amount must include the surcharge; `build` fails if the surcharge
+    /// is larger than the amount.
+    #[must_use]
+    pub fn surcharge(mut self, value: u64) -> Self {
+        self.surcharge = Some(value);
+        self
+    }
+
+    /// Mark this as a merchant-initiated payment with stored details.
+    ///
+    /// Sets the shopper interaction to `ContAuth` and the recurring
+    /// processing model to `model`.
+    #[must_use]
+    pub fn merchant_initiated(self, model: RecurringProcessingModel) -> Self {
+        self.shopper_interaction(ShopperInteraction::ContAuth)
+            .recurring_processing_model(model)
+    }
+
+    /// Set the network transaction reference of the initial payment.
+    ///
+    /// Needed for merchant-initiated payments with card details stored
+    /// outside Adyen; sent as `networkTxReference` additional data.
+    #[must_use]
+    pub fn network_tx_reference(self, reference: impl Into<String>) -> Self {
+        self.additional_data("networkTxReference", reference)
+    }
+
     /// Set browser information.
     #[must_use]
     pub fn browser_info(mut self, browser_info: BrowserInfo) -> Self {
@@ -481,6 +988,78 @@ impl PaymentRequestBuilder {
         self
     }
 
+    /// Add a line item.
+    #[must_use]
+    pub fn line_item(mut self, item: LineItem) -> Self {
+        self.line_items.get_or_insert_with(Vec::new).push(item);
+        self
+    }
+
+    /// Set line items.
+    #[must_use]
+    pub fn line_items(mut self, items: Vec<LineItem>) -> Self {
+        self.line_items = Some(items);
+        self
+    }
+
+    /// Add a split.
+    #[must_use]
+    pub fn split(mut self, split: Split) -> Self {
+        self.splits.get_or_insert_with(Vec::new).push(split);
+        self
+    }
+
+    /// Set how to split the payment between accounts.
+    #[must_use]
+    pub fn splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set data for Adyen's risk engine.
+    #[must_use]
+    pub fn risk_data(mut self, risk_data: RiskData) -> Self {
+        self.risk_data = Some(risk_data);
+        self
+    }
+
+    /// Add a metadata entry.
+    ///
+    /// The [`Metadata`] limits are checked by `build`.
+    #[must_use]
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the 3D Secure authentication settings.
+    ///
+    /// Use [`AuthenticationData::native`] to request the native 3D Secure 2
+    /// flow in the iOS and Android Checkout SDKs.
+    #[must_use]
+    pub fn authentication_data(mut self, authentication_data: AuthenticationData) -> Self {
+        self.authentication_data = Some(authentication_data);
+        self
+    }
+
+    /// Set additional 3D Secure 2 data for the issuer.
+    #[must_use]
+    pub fn three_ds2_request_data(mut self, data: ThreeDS2RequestData) -> Self {
+        self.three_ds2_request_data = Some(data);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this payment.
+    ///
+    /// Use [`RequestId::new`] to generate a random UUID v4 key.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
     /// Add additional data.
     #[must_use]
     pub fn additional_data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
@@ -490,44 +1069,111 @@ impl PaymentRequestBuilder {
         self
     }
 
+    /// Add airline passenger data as `airline.*` additional data.
+    #[must_use]
+    pub fn airline_data(mut self, airline: &AirlineData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(airline.to_additional_data());
+        self
+    }
+
+    /// Add lodging data as `lodging.*` additional data.
+    #[must_use]
+    pub fn lodging_data(mut self, lodging: &LodgingData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(lodging.to_additional_data());
+        self
+    }
+
+    /// Add Level 2/3 data as `enhancedSchemeData.*` additional data.
+    #[must_use]
+    pub fn enhanced_scheme_data(mut self, enhanced: &EnhancedSchemeData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(enhanced.to_additional_data());
+        self
+    }
+}
+
+impl PaymentRequestBuilder<Amount, String, String, String> {
     /// Build the payment request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are not set.
+    /// Returns an error if the country code, shopper locale or metadata is
+    /// invalid, a `ContAuth` payment has no recurring processing model, or the
+    /// surcharge is larger than the amount.
     pub fn build(self) -> Result<PaymentRequest> {
-        let amount = self
-            .amount
-            .ok_or_else(|| AdyenError::config("amount is required"))?;
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-        let reference = self
-            .reference
-            .ok_or_else(|| AdyenError::config("reference is required"))?;
-        let return_url = self
-            .return_url
-            .ok_or_else(|| AdyenError::config("return_url is required"))?;
+        if self.shopper_interaction == Some(ShopperInteraction::ContAuth)
+            && self.recurring_processing_model.is_none()
+        {
+            return Err(AdyenError::config(
+                "recurring_processing_model is required for ContAuth payments",
+            ));
+        }
+        if self
+            .surcharge
+            .is_some_and(|surcharge| surcharge > self.amount.minor_units())
+        {
+            return Err(AdyenError::config(
+                "surcharge cannot be larger than the payment amount",
+            ));
+        }
 
         Ok(PaymentRequest {
-            amount,
-            merchant_account,
-            reference,
-            return_url,
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
             payment_method: self.payment_method,
             channel: self.channel,
             origin: self.origin,
-            country_code: self.country_code,
-            shopper_locale: self.shopper_locale,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
+            shopper_locale: self
+                .shopper_locale
+                .as_deref()
+                .map(Locale::new)
+                .transpose()?,
             shopper_reference: self.shopper_reference,
             shopper_email: self.shopper_email,
             store_payment_method: self.store_payment_method,
+            shopper_interaction: self.shopper_interaction,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            installments: self.installments,
+            surcharge: self.surcharge.map(|value| Surcharge { value }),
             additional_data: self.additional_data,
             browser_info: self.browser_info,
             billing_address: self.billing_address,
             delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata.map(Metadata::try_from).transpose()?,
+            authentication_data: self.authentication_data,
+            three_ds2_request_data: self.three_ds2_request_data,
+            idempotency_key: self.idempotency_key,
         })
     }
+
+    /// Build the payment request and check the formats of its fields, see
+    /// [`PaymentRequest::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`build`](Self::build), or
+    /// [`AdyenError::Validation`] listing every field with an invalid format.
+    pub fn build_validated(self) -> Result<PaymentRequest> {
+        let request = self.build()?;
+        request.validate()?;
+        Ok(request)
+    }
 }
 
 impl PaymentRequest {
@@ -536,6 +1182,43 @@ impl PaymentRequest {
     pub fn builder() -> PaymentRequestBuilder {
         PaymentRequestBuilder::new()
     }
+
+    /// Check the formats of the request's fields.
+    ///
+    /// Checks that the reference is at most 80 characters, the currency and
+    /// address countries are ISO codes, split currencies match the amount,
+    /// the shopper email is valid, the card expiry month is from 1 to 12,
+    /// and the return URL has a scheme shoppers can be redirected to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdyenError::Validation`] listing every invalid field.
+    pub fn validate(&self) -> Result<()> {
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &self.reference)
+            .currency("amount", &self.amount)
+            .return_url("return_url", &self.return_url);
+        if let Some(email) = &self.shopper_email {
+            validator.email("shopper_email", email);
+        }
+        if let Some(PaymentMethodDetails::Card { expiry_month, .. }) = &self.payment_method {
+            validator.expiry_month("payment_method.expiry_month", expiry_month);
+        }
+        for (field, address) in [
+            ("billing_address.country", &self.billing_address),
+            ("delivery_address.country", &self.delivery_address),
+        ] {
+            if let Some(country) = address.as_ref().and_then(|a| a.country.as_deref()) {
+                validator.country_code(field, country);
+            }
+        }
+        for split in self.splits.iter().flatten() {
+            let currency = split.amount.as_ref().and_then(|a| a.currency.as_ref());
+            validator.same_currency("splits.amount.currency", &self.amount, currency);
+        }
+        validator.finish()
+    }
 }
 
 #[cfg(test)]
@@ -562,30 +1245,362 @@ mod tests {
         assert_eq!(request.reference, "Order-12345");
         assert_eq!(request.return_url, "https://example.com/return");
         assert_eq!(request.channel, Some("Web".to_string()));
-        assert_eq!(request.country_code, Some("NL".to_string()));
+        assert_eq!(
+            request.country_code.as_ref().map(CountryCode::as_str),
+            Some("NL")
+        );
     }
 
     #[test]
-    fn test_payment_request_missing_required_fields() {
-        assert!(PaymentRequest::builder().build().is_err());
+    fn test_payment_request_build_validated() {
+        let builder = || {
+            PaymentRequest::builder()
+                .amount(Amount::from_major_units(100, Currency::EUR))
+                .merchant_account("TestMerchant")
+                .reference("Order-12345")
+        };
 
-        let amount = Amount::from_major_units(100, Currency::EUR);
-        assert!(PaymentRequest::builder().amount(amount).build().is_err());
+        let request = builder()
+            .return_url("https://example.com/return")
+            .shopper_email("shopper@example.com")
+            .build_validated()
+            .unwrap();
+        assert_eq!(request.reference, "Order-12345");
+
+        let mut split = crate::types::splits::Split::commission(100);
+        if let Some(amount) = split.amount.as_mut() {
+            amount.currency = Some(Currency::USD);
+        }
+        let error = builder()
+            .reference("x".repeat(81))
+            .return_url("javascript:alert(1)")
+            .shopper_email("shopper.example.com")
+            .payment_method(PaymentMethodDetails::Card {
+                number: SecretString::from("4111111111111111"),
+                expiry_month: "13".to_string(),
+                expiry_year: "2030".to_string(),
+                cvc: SecretString::from("737"),
+                holder_name: None,
+            })
+            .billing_address(Address {
+                street: None,
+                house_number_or_name: None,
+                city: None,
+                postal_code: None,
+                state_or_province: None,
+                country: Some("Netherlands".to_string()),
+            })
+            .split(split)
+            .build_validated()
+            .unwrap_err();
+        let AdyenError::Validation(errors) = error else {
+            panic!("expected validation errors, got {error:?}");
+        };
+        let mut fields: Vec<_> = errors.field_errors().into_keys().collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            [
+                "billing_address.country",
+                "payment_method.expiry_month",
+                "reference",
+                "return_url",
+                "shopper_email",
+                "splits.amount.currency"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payment_request_validates_country_code_and_locale() {
+        let builder = || {
+            PaymentRequest::builder()
+                .amount(Amount::from_major_units(100, Currency::EUR))
+                .merchant_account("TestMerchant")
+                .reference("Order-12345")
+                .return_url("https://example.com/return")
+        };
+
+        let country = CountryCode::new("NL").unwrap();
+        let request = builder()
+            .country_code(&country)
+            .shopper_locale("nl_NL")
+            .build()
+            .unwrap();
+        assert_eq!(request.country_code, Some(country));
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["countryCode"], "NL");
+        assert_eq!(json["shopperLocale"], "nl_NL");
+
+        assert!(builder().country_code("Netherlands").build().is_err());
+        assert!(builder().shopper_locale("Dutch").build().is_err());
+    }
+
+    #[test]
+    fn test_payment_request_with_line_items() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(14100, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .return_url("https://example.com/return")
+            .line_item(LineItem::new("Shoes", 1, 12100).with_tax(2100, 2100))
+            .line_item(LineItem::new("Socks", 2, 1000))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["lineItems"][0]["amountExcludingTax"], 10000);
+        assert_eq!(json["lineItems"][1]["quantity"], 2);
+    }
+
+    #[test]
+    fn test_payment_request_with_splits() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(10000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .return_url("https://example.com/return")
+            .split(Split::balance_account("BA00000000000000000000001", 9000))
+            .split(Split::commission(1000))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["splits"][0]["type"], "BalanceAccount");
+        assert_eq!(json["splits"][1]["type"], "Commission");
+        assert_eq!(json["splits"][1]["amount"]["value"], 1000);
+    }
+
+    #[test]
+    fn test_payment_request_metadata_limits() {
+        let builder = || {
+            PaymentRequest::builder()
+                .amount(Amount::from_minor_units(10000, Currency::EUR))
+                .merchant_account("TestMerchant")
+                .reference("Order-12345")
+                .return_url("https://example.com/return")
+        };
+
+        let request = builder().metadata("orderId", "1234").build().unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["metadata"], serde_json::json!({"orderId": "1234"}));
+
+        let error = builder()
+            .metadata("orderId", "x".repeat(Metadata::MAX_VALUE_LENGTH + 1))
+            .build()
+            .unwrap_err();
+        assert!(error.to_string().contains("orderId"));
+    }
+
+    #[test]
+    fn test_payment_request_with_risk_data() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(10000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .return_url("https://example.com/return")
+            .risk_data(
+                RiskData::new()
+                    .with_client_data("DpqwU4zEdN0050000000000000")
+                    .with_custom_field("loyaltyTier", "gold")
+                    .with_fraud_offset(-20)
+                    .with_profile_reference("RiskProfile-1"),
+            )
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["riskData"],
+            serde_json::json!({
+                "clientData": "DpqwU4zEdN0050000000000000",
+                "customFields": {"loyaltyTier": "gold"},
+                "fraudOffset": -20,
+                "profileReference": "RiskProfile-1"
+            })
+        );
+    }
+
+    #[test]
+    fn test_payment_request_with_native_three_ds() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(10000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .return_url("my-app://adyen")
+            .channel("iOS")
+            .authentication_data(AuthenticationData::native())
+            .three_ds2_request_data(ThreeDS2RequestData::app("https://example.com/3ds2"))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["authenticationData"]["threeDSRequestData"]["nativeThreeDS"],
+            "preferred"
+        );
+        assert_eq!(json["threeDS2RequestData"]["deviceChannel"], "app");
+        assert_eq!(
+            json["threeDS2RequestData"]["threeDSRequestorAppURL"],
+            "https://example.com/3ds2"
+        );
+    }
+
+    #[test]
+    fn test_merchant_initiated_payment() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(999, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Subscription-2026-10")
+            .return_url("https://example.com/return")
+            .shopper_reference("Shopper-1")
+            .merchant_initiated(RecurringProcessingModel::Subscription)
+            .network_tx_reference("MCC123456789")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["shopperInteraction"], "ContAuth");
+        assert_eq!(json["recurringProcessingModel"], "Subscription");
+        assert_eq!(json["additionalData"]["networkTxReference"], "MCC123456789");
+
+        let missing_model = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(999, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Subscription-2026-10")
+            .return_url("https://example.com/return")
+            .shopper_interaction(ShopperInteraction::ContAuth)
+            .build();
+        assert!(missing_model.is_err());
+    }
+
+    #[test]
+    fn test_payment_request_with_mandate() {
+        use crate::types::recurring::MandateFrequency;
+
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(1999, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Mandate-1")
+            .return_url("https://example.com/return")
+            .recurring_processing_model(RecurringProcessingModel::Subscription)
+            .mandate(Mandate::new(1999, MandateFrequency::Monthly, "2027-10-31"))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["mandate"]["frequency"], "monthly");
+        assert_eq!(json["mandate"]["amount"], "1999");
+    }
+
+    #[test]
+    fn test_payment_request_with_airline_data() {
+        use adyen_core::additional_data::AirlineLeg;
+
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(45000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Ticket-1")
+            .return_url("https://example.com/return")
+            .airline_data(
+                &AirlineData::new("JOHN/DOE")
+                    .with_ticket_number("0062345678901")
+                    .with_leg(AirlineLeg::new("KL", "AMS", "JFK")),
+            )
+            .enhanced_scheme_data(&EnhancedSchemeData::new().with_customer_reference("PO-42"))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["additionalData"]["airline.passenger_name"], "JOHN/DOE");
+        assert_eq!(
+            json["additionalData"]["airline.ticket_number"],
+            "0062345678901"
+        );
+        assert_eq!(json["additionalData"]["airline.leg1.carrier_code"], "KL");
+        assert_eq!(
+            json["additionalData"]["enhancedSchemeData.customerReference"],
+            "PO-42"
+        );
+    }
+
+    #[test]
+    fn test_payment_request_with_surcharge() {
+        let builder = || {
+            PaymentRequest::builder()
+                .amount(Amount::from_minor_units(10250, Currency::AUD))
+                .merchant_account("TestMerchant")
+                .reference("Order-12345")
+                .return_url("https://example.com/return")
+        };
+
+        let request = builder().surcharge(250).build().unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["surcharge"], serde_json::json!({"value": 250}));
+
+        assert!(builder().surcharge(10251).build().is_err());
+    }
+
+    #[test]
+    fn test_payment_response_surcharging_data() {
+        let response: PaymentResponse = serde_json::from_str(
+            r#"{
+                "resultCode": "Authorised",
+                "pspReference": "8515131751004933",
+                "additionalData": {
+                    "fundingSource": "DEBIT",
+                    "issuerCountry": "AU",
+                    "cardBin": "411111"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.funding_source(), Some(FundingSource::Debit));
+        assert_eq!(response.issuer_country(), Some("AU"));
+        assert_eq!(response.card_bin(), Some("411111"));
+        assert_eq!(response.additional_value("missing"), None);
+    }
+
+    #[test]
+    fn test_payment_request_with_installments() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(30000, Currency::BRL))
+            .merchant_account("TestMerchant")
+            .reference("Installments-1")
+            .return_url("https://example.com/return")
+            .installments(Installments::new(3).with_plan(InstallmentPlan::WithInterest))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["installments"],
+            serde_json::json!({"value": 3, "plan": "with_interest"})
+        );
+
+        let plan: InstallmentPlan = serde_json::from_str(r#""nointeres_refund_prctg""#).unwrap();
+        assert_eq!(plan, InstallmentPlan::NointerestRefundPercentage);
+        let plan: InstallmentPlan = serde_json::from_str(r#""buynow_paylater""#).unwrap();
+        assert_eq!(plan, InstallmentPlan::BuyNowPayLater);
+        let plan: InstallmentPlan = serde_json::from_str(r#""new_plan""#).unwrap();
+        assert_eq!(plan, InstallmentPlan::Unknown);
     }
 
     #[test]
     fn test_payment_method_details_serialization() {
         let card = PaymentMethodDetails::Card {
-            number: "4111111111111111".to_string(),
+            number: "4111111111111111".into(),
             expiry_month: "12".to_string(),
             expiry_year: "2025".to_string(),
-            cvc: "123".to_string(),
+            cvc: "123".into(),
             holder_name: Some("John Doe".to_string()),
         };
 
         let json = serde_json::to_string(&card).unwrap();
         assert!(json.contains("\"type\":\"scheme\""));
         assert!(json.contains("\"number\":\"4111111111111111\""));
+        assert!(!format!("{card:?}").contains("4111111111111111"));
     }
 
     #[test]
@@ -599,4 +1614,10 @@ mod tests {
             "\"Refused\""
         );
     }
+
+    #[test]
+    fn test_payment_result_code_unknown() {
+        let code: PaymentResultCode = serde_json::from_str("\"PartiallyAuthorised\"").unwrap();
+        assert_eq!(code, PaymentResultCode::Unknown);
+    }
 }
@@ -0,0 +1,259 @@
+//! Types for recurring and merchant-initiated payments.
+
+use serde::{Deserialize, Serialize};
+
+/// Sales channel through which the shopper gives their payment details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum ShopperInteraction {
+    /// Online payment by a shopper who is present (customer-initiated).
+    Ecommerce,
+    /// Payment with stored details, without the shopper present
+    /// (merchant-initiated).
+    ContAuth,
+    /// Mail or telephone order.
+    Moto,
+    /// Point-of-sale payment.
+    #[serde(rename = "POS")]
+    Pos,
+    /// A shopper interaction not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Type of recurring payment, required by the card schemes for stored details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum RecurringProcessingModel {
+    /// Payment initiated by the shopper with stored details, e.g. one-click.
+    CardOnFile,
+    /// Payment on a fixed schedule, e.g. a monthly subscription.
+    Subscription,
+    /// Payment initiated by the merchant at no fixed schedule, e.g. a top-up.
+    UnscheduledCardOnFile,
+    /// A recurring processing model not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How often a mandate is charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum MandateFrequency {
+    /// At no fixed interval.
+    Adhoc,
+    /// Every day.
+    Daily,
+    /// Every week.
+    Weekly,
+    /// Every two weeks.
+    BiWeekly,
+    /// Every month.
+    Monthly,
+    /// Every three months.
+    Quarterly,
+    /// Every six months.
+    HalfYearly,
+    /// Every year.
+    Yearly,
+    /// A mandate frequency not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How the mandate amount is applied to each charge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum MandateAmountRule {
+    /// Charges are up to the mandate amount.
+    Max,
+    /// Charges are exactly the mandate amount.
+    Exact,
+    /// A mandate amount rule not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// When charges may happen relative to the billing day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum BillingAttemptsRule {
+    /// On the billing day.
+    On,
+    /// On or before the billing day.
+    Before,
+    /// On or after the billing day.
+    After,
+    /// A billing attempts rule not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A mandate for recurring charges, e.g. a SEPA, UPI or card subscription
+/// mandate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Mandate {
+    /// The maximum or exact amount of each charge, in minor units.
+    pub amount: String,
+
+    /// How often the mandate is charged.
+    pub frequency: MandateFrequency,
+
+    /// The date the mandate ends, in `YYYY-MM-DD` format.
+    pub ends_at: String,
+
+    /// The date the mandate starts, in `YYYY-MM-DD` format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<String>,
+
+    /// How the amount is applied to each charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_rule: Option<MandateAmountRule>,
+
+    /// The day charges happen on: the day of the week for weekly mandates,
+    /// or the day of the month otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_day: Option<String>,
+
+    /// When charges may happen relative to the billing day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_attempts_rule: Option<BillingAttemptsRule>,
+
+    /// The number of charges allowed in each frequency period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<String>,
+
+    /// A description of the mandate, shown to the shopper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+}
+
+impl Mandate {
+    /// Create a mandate for charges of `amount` minor units at `frequency`,
+    /// ending on `ends_at` (`YYYY-MM-DD`).
+    #[must_use]
+    pub fn new(amount: u64, frequency: MandateFrequency, ends_at: impl Into<String>) -> Self {
+        Self {
+            amount: amount.to_string(),
+            frequency,
+            ends_at: ends_at.into(),
+            starts_at: None,
+            amount_rule: None,
+            billing_day: None,
+            billing_attempts_rule: None,
+            count: None,
+            remarks: None,
+        }
+    }
+
+    /// Set the date the mandate starts (`YYYY-MM-DD`).
+    #[must_use]
+    pub fn with_starts_at(mut self, starts_at: impl Into<String>) -> Self {
+        self.starts_at = Some(starts_at.into());
+        self
+    }
+
+    /// Set how the amount is applied to each charge.
+    #[must_use]
+    pub fn with_amount_rule(mut self, amount_rule: MandateAmountRule) -> Self {
+        self.amount_rule = Some(amount_rule);
+        self
+    }
+
+    /// Set the billing day and when charges may happen relative to it.
+    #[must_use]
+    pub fn with_billing_day(mut self, billing_day: u8, rule: BillingAttemptsRule) -> Self {
+        self.billing_day = Some(billing_day.to_string());
+        self.billing_attempts_rule = Some(rule);
+        self
+    }
+
+    /// Set the number of charges allowed in each frequency period.
+    #[must_use]
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    /// Set the description shown to the shopper.
+    #[must_use]
+    pub fn with_remarks(mut self, remarks: impl Into<String>) -> Self {
+        self.remarks = Some(remarks.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ShopperInteraction::ContAuth).unwrap(),
+            "\"ContAuth\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ShopperInteraction::Pos).unwrap(),
+            "\"POS\""
+        );
+        assert_eq!(
+            serde_json::to_string(&RecurringProcessingModel::UnscheduledCardOnFile).unwrap(),
+            "\"UnscheduledCardOnFile\""
+        );
+    }
+
+    #[test]
+    fn test_mandate_serialization() {
+        let mandate = Mandate::new(1999, MandateFrequency::Monthly, "2027-10-31")
+            .with_amount_rule(MandateAmountRule::Max)
+            .with_billing_day(15, BillingAttemptsRule::On)
+            .with_remarks("Monthly plan");
+
+        assert_eq!(
+            serde_json::to_value(&mandate).unwrap(),
+            serde_json::json!({
+                "amount": "1999",
+                "frequency": "monthly",
+                "endsAt": "2027-10-31",
+                "amountRule": "max",
+                "billingDay": "15",
+                "billingAttemptsRule": "on",
+                "remarks": "Monthly plan"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(MandateFrequency::BiWeekly).unwrap(),
+            serde_json::json!("biWeekly")
+        );
+    }
+}
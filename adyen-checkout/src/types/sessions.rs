@@ -1,12 +1,22 @@
 //! Checkout session types for creating and managing sessions.
 
-use crate::types::payments::Address;
-use adyen_core::{AdyenError, Amount, Result};
+use crate::types::line_items::LineItem;
+use crate::types::payments::{Address, RiskData};
+use crate::types::recurring::{Mandate, RecurringProcessingModel};
+use crate::types::splits::Split;
+use adyen_core::builder::Missing;
+use adyen_core::validation::RequestValidator;
+use adyen_core::{Amount, CountryCode, Locale, Metadata, PspReference, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Request to create a checkout session.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCheckoutSessionRequest {
     /// The payment amount and currency.
@@ -27,11 +37,11 @@ pub struct CreateCheckoutSessionRequest {
 
     /// The shopper's country code.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 
     /// The shopper's locale.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_locale: Option<String>,
+    pub shopper_locale: Option<Locale>,
 
     /// The shopper reference.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -45,6 +55,32 @@ pub struct CreateCheckoutSessionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub store_payment_method: Option<bool>,
 
+    /// Whether and how the shopper's payment details are stored. Replaces
+    /// `store_payment_method` for sessions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_payment_method_mode: Option<StorePaymentMethodMode>,
+
+    /// The type of recurring payment the stored details are used for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_processing_model: Option<RecurringProcessingModel>,
+
+    /// The mandate for recurring charges, e.g. for SEPA or UPI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<Mandate>,
+
+    /// Payment methods shown to the shopper; all others are hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_payment_methods: Option<Vec<String>>,
+
+    /// Payment methods hidden from the shopper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_payment_methods: Option<Vec<String>>,
+
+    /// Whether to show separate debit and credit card payment methods, for
+    /// countries where the shopper chooses the funding source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_card_funding_sources: Option<bool>,
+
     /// Billing address for the session.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub billing_address: Option<Address>,
@@ -57,6 +93,22 @@ pub struct CreateCheckoutSessionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_items: Option<Vec<LineItem>>,
 
+    /// How to split the payment between accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+
+    /// Data for Adyen's risk engine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_data: Option<RiskData>,
+
+    /// Your own key-value data, returned in the webhooks of the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The ID of the Hosted Checkout theme to use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_id: Option<String>,
+
     /// Additional data for the session.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
@@ -66,8 +118,30 @@ pub struct CreateCheckoutSessionRequest {
     pub expires_at: Option<String>,
 }
 
+/// Whether a session stores the shopper's payment details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum StorePaymentMethodMode {
+    /// Show a checkbox so the shopper can choose to store their details.
+    AskForConsent,
+    /// Never store the shopper's details.
+    Disabled,
+    /// Always store the shopper's details.
+    Enabled,
+}
+
 /// Response from creating a checkout session.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCheckoutSessionResponse {
     /// The unique session identifier.
@@ -98,67 +172,154 @@ pub struct CreateCheckoutSessionResponse {
 
     /// The country code.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 
     /// The shopper locale.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_locale: Option<String>,
+    pub shopper_locale: Option<Locale>,
 }
 
-/// A line item for the payment.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Status of a checkout session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
-pub struct LineItem {
-    /// The item ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+pub enum SessionStatus {
+    /// The session is active and can be paid.
+    Active,
+    /// The shopper canceled the payment.
+    Canceled,
+    /// The session was paid.
+    Completed,
+    /// The session expired without a payment.
+    Expired,
+    /// A payment was made but its result is not final yet.
+    PaymentPending,
+    /// The payment was refused.
+    Refused,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
 
-    /// The item description.
-    pub description: String,
+impl SessionStatus {
+    /// Whether the session has reached a final status.
+    #[must_use]
+    pub const fn is_final(self) -> bool {
+        matches!(
+            self,
+            Self::Canceled | Self::Completed | Self::Expired | Self::Refused
+        )
+    }
+}
 
-    /// The quantity of the item.
-    pub quantity: u32,
+/// Payment method used for a payment in a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPaymentMethod {
+    /// The payment method type, e.g. `scheme`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub method_type: Option<String>,
 
-    /// The amount per item.
-    pub amount_including_tax: Amount,
+    /// The card brand, for card payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+}
 
-    /// The amount excluding tax.
+/// A payment made in a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPayment {
+    /// The amount of the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount_excluding_tax: Option<Amount>,
+    pub amount: Option<Amount>,
 
-    /// The tax amount.
+    /// The payment method used.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tax_amount: Option<Amount>,
+    pub payment_method: Option<SessionPaymentMethod>,
 
-    /// The tax percentage (in basis points).
+    /// Adyen's reference for the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tax_percentage: Option<u32>,
+    pub psp_reference: Option<PspReference>,
 
-    /// The item category.
+    /// The result of the payment, e.g. `Authorised`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub item_category: Option<String>,
+    pub result_code: Option<String>,
+}
 
-    /// Additional item data.
+/// Result of a checkout session.
+///
+/// Query this with the `sessionResult` returned by Drop-in or Components to
+/// verify the outcome of a session server-side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionResultResponse {
+    /// The session ID.
+    pub id: String,
+
+    /// The status of the session.
+    pub status: SessionStatus,
+
+    /// Your reference for the session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// The payments made in the session.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payments: Vec<SessionPayment>,
+
+    /// Additional data for the session.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
 }
 
 /// Builder for creating checkout session requests.
+///
+/// `build` is only available once the required amount, merchant account,
+/// reference and return URL are set.
 #[derive(Debug, Clone, Default)]
-pub struct CreateCheckoutSessionRequestBuilder {
-    amount: Option<Amount>,
-    merchant_account: Option<String>,
-    reference: Option<String>,
-    return_url: Option<String>,
+pub struct CreateCheckoutSessionRequestBuilder<A = Missing, M = Missing, R = Missing, U = Missing> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    return_url: U,
     channel: Option<String>,
     country_code: Option<String>,
     shopper_locale: Option<String>,
     shopper_reference: Option<String>,
     shopper_email: Option<String>,
     store_payment_method: Option<bool>,
+    store_payment_method_mode: Option<StorePaymentMethodMode>,
+    recurring_processing_model: Option<RecurringProcessingModel>,
+    mandate: Option<Mandate>,
+    allowed_payment_methods: Option<Vec<String>>,
+    blocked_payment_methods: Option<Vec<String>>,
+    split_card_funding_sources: Option<bool>,
     billing_address: Option<Address>,
     delivery_address: Option<Address>,
     line_items: Option<Vec<LineItem>>,
+    splits: Option<Vec<Split>>,
+    risk_data: Option<RiskData>,
+    metadata: Option<HashMap<String, String>>,
+    theme_id: Option<String>,
     additional_data: Option<HashMap<String, String>>,
     expires_at: Option<String>,
 }
@@ -169,33 +330,144 @@ impl CreateCheckoutSessionRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<A, M, R, U> CreateCheckoutSessionRequestBuilder<A, M, R, U> {
     /// Set the payment amount.
     #[must_use]
-    pub fn amount(mut self, amount: Amount) -> Self {
-        self.amount = Some(amount);
-        self
+    pub fn amount(self, amount: Amount) -> CreateCheckoutSessionRequestBuilder<Amount, M, R, U> {
+        CreateCheckoutSessionRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            store_payment_method_mode: self.store_payment_method_mode,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+            split_card_funding_sources: self.split_card_funding_sources,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            theme_id: self.theme_id,
+            additional_data: self.additional_data,
+            expires_at: self.expires_at,
+        }
     }
 
     /// Set the merchant account.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> CreateCheckoutSessionRequestBuilder<A, String, R, U> {
+        CreateCheckoutSessionRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            return_url: self.return_url,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            store_payment_method_mode: self.store_payment_method_mode,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+            split_card_funding_sources: self.split_card_funding_sources,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            theme_id: self.theme_id,
+            additional_data: self.additional_data,
+            expires_at: self.expires_at,
+        }
     }
 
     /// Set the session reference.
     #[must_use]
-    pub fn reference(mut self, reference: impl Into<String>) -> Self {
-        self.reference = Some(reference.into());
-        self
+    pub fn reference(
+        self,
+        reference: impl Into<String>,
+    ) -> CreateCheckoutSessionRequestBuilder<A, M, String, U> {
+        CreateCheckoutSessionRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            return_url: self.return_url,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            store_payment_method_mode: self.store_payment_method_mode,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+            split_card_funding_sources: self.split_card_funding_sources,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            theme_id: self.theme_id,
+            additional_data: self.additional_data,
+            expires_at: self.expires_at,
+        }
     }
 
     /// Set the return URL.
     #[must_use]
-    pub fn return_url(mut self, return_url: impl Into<String>) -> Self {
-        self.return_url = Some(return_url.into());
-        self
+    pub fn return_url(
+        self,
+        return_url: impl Into<String>,
+    ) -> CreateCheckoutSessionRequestBuilder<A, M, R, String> {
+        CreateCheckoutSessionRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: return_url.into(),
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            store_payment_method: self.store_payment_method,
+            store_payment_method_mode: self.store_payment_method_mode,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+            split_card_funding_sources: self.split_card_funding_sources,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata,
+            theme_id: self.theme_id,
+            additional_data: self.additional_data,
+            expires_at: self.expires_at,
+        }
     }
 
     /// Set the sales channel.
@@ -206,16 +478,22 @@ impl CreateCheckoutSessionRequestBuilder {
     }
 
     /// Set the shopper's country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
     #[must_use]
-    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
-        self.country_code = Some(country_code.into());
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
         self
     }
 
     /// Set the shopper's locale.
+    ///
+    /// Accepts a [`Locale`] or a string such as `nl-NL`, which is validated
+    /// by `build`.
     #[must_use]
-    pub fn shopper_locale(mut self, locale: impl Into<String>) -> Self {
-        self.shopper_locale = Some(locale.into());
+    pub fn shopper_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.shopper_locale = Some(locale.as_ref().to_owned());
         self
     }
 
@@ -240,6 +518,57 @@ impl CreateCheckoutSessionRequestBuilder {
         self
     }
 
+    /// Set whether and how to store the shopper's payment details.
+    #[must_use]
+    pub fn store_payment_method_mode(mut self, mode: StorePaymentMethodMode) -> Self {
+        self.store_payment_method_mode = Some(mode);
+        self
+    }
+
+    /// Set the type of recurring payment the stored details are used for.
+    #[must_use]
+    pub fn recurring_processing_model(mut self, model: RecurringProcessingModel) -> Self {
+        self.recurring_processing_model = Some(model);
+        self
+    }
+
+    /// Set the mandate for recurring charges.
+    #[must_use]
+    pub fn mandate(mut self, mandate: Mandate) -> Self {
+        self.mandate = Some(mandate);
+        self
+    }
+
+    /// Only show the given payment method types to the shopper.
+    #[must_use]
+    pub fn allowed_payment_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_payment_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Hide the given payment method types from the shopper.
+    #[must_use]
+    pub fn blocked_payment_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.blocked_payment_methods = Some(methods.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set whether to show debit and credit cards as separate payment
+    /// methods.
+    #[must_use]
+    pub fn split_card_funding_sources(mut self, split: bool) -> Self {
+        self.split_card_funding_sources = Some(split);
+        self
+    }
+
     /// Set billing address.
     #[must_use]
     pub fn billing_address(mut self, address: Address) -> Self {
@@ -268,6 +597,45 @@ impl CreateCheckoutSessionRequestBuilder {
         self
     }
 
+    /// Add a split.
+    #[must_use]
+    pub fn split(mut self, split: Split) -> Self {
+        self.splits.get_or_insert_with(Vec::new).push(split);
+        self
+    }
+
+    /// Set how to split the payment between accounts.
+    #[must_use]
+    pub fn splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set data for Adyen's risk engine.
+    #[must_use]
+    pub fn risk_data(mut self, risk_data: RiskData) -> Self {
+        self.risk_data = Some(risk_data);
+        self
+    }
+
+    /// Add a metadata entry.
+    ///
+    /// The [`Metadata`] limits are checked by `build`.
+    #[must_use]
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the Hosted Checkout theme.
+    #[must_use]
+    pub fn theme_id(mut self, theme_id: impl Into<String>) -> Self {
+        self.theme_id = Some(theme_id.into());
+        self
+    }
+
     /// Set session expiry time.
     #[must_use]
     pub fn expires_at(mut self, expires_at: impl Into<String>) -> Self {
@@ -283,44 +651,66 @@ impl CreateCheckoutSessionRequestBuilder {
             .insert(key.into(), value.into());
         self
     }
+}
 
+impl CreateCheckoutSessionRequestBuilder<Amount, String, String, String> {
     /// Build the session request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are not set.
+    /// Returns an error if the country code, shopper locale or metadata is
+    /// invalid.
     pub fn build(self) -> Result<CreateCheckoutSessionRequest> {
-        let amount = self
-            .amount
-            .ok_or_else(|| AdyenError::config("amount is required"))?;
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-        let reference = self
-            .reference
-            .ok_or_else(|| AdyenError::config("reference is required"))?;
-        let return_url = self
-            .return_url
-            .ok_or_else(|| AdyenError::config("return_url is required"))?;
-
         Ok(CreateCheckoutSessionRequest {
-            amount,
-            merchant_account,
-            reference,
-            return_url,
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            return_url: self.return_url,
             channel: self.channel,
-            country_code: self.country_code,
-            shopper_locale: self.shopper_locale,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
+            shopper_locale: self
+                .shopper_locale
+                .as_deref()
+                .map(Locale::new)
+                .transpose()?,
             shopper_reference: self.shopper_reference,
             shopper_email: self.shopper_email,
             store_payment_method: self.store_payment_method,
+            store_payment_method_mode: self.store_payment_method_mode,
+            recurring_processing_model: self.recurring_processing_model,
+            mandate: self.mandate,
+            allowed_payment_methods: self.allowed_payment_methods,
+            blocked_payment_methods: self.blocked_payment_methods,
+            split_card_funding_sources: self.split_card_funding_sources,
             billing_address: self.billing_address,
             delivery_address: self.delivery_address,
             line_items: self.line_items,
+            splits: self.splits,
+            risk_data: self.risk_data,
+            metadata: self.metadata.map(Metadata::try_from).transpose()?,
+            theme_id: self.theme_id,
             additional_data: self.additional_data,
             expires_at: self.expires_at,
         })
     }
+
+    /// Build the session request and check the formats of its fields, see
+    /// [`CreateCheckoutSessionRequest::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`build`](Self::build), or
+    /// [`AdyenError::Validation`](adyen_core::AdyenError::Validation) listing
+    /// every field with an invalid format.
+    pub fn build_validated(self) -> Result<CreateCheckoutSessionRequest> {
+        let request = self.build()?;
+        request.validate()?;
+        Ok(request)
+    }
 }
 
 impl CreateCheckoutSessionRequest {
@@ -329,56 +719,108 @@ impl CreateCheckoutSessionRequest {
     pub fn builder() -> CreateCheckoutSessionRequestBuilder {
         CreateCheckoutSessionRequestBuilder::new()
     }
+
+    /// Check the formats of the request's fields.
+    ///
+    /// Checks that the reference is at most 80 characters, the currency and
+    /// address countries are ISO codes, splits are in the amount's currency,
+    /// the shopper email is valid, and the return URL has a scheme shoppers
+    /// can be redirected to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdyenError::Validation`](adyen_core::AdyenError::Validation)
+    /// listing every invalid field.
+    pub fn validate(&self) -> Result<()> {
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &self.reference)
+            .currency("amount", &self.amount)
+            .return_url("return_url", &self.return_url);
+        if let Some(email) = &self.shopper_email {
+            validator.email("shopper_email", email);
+        }
+        for (field, address) in [
+            ("billing_address.country", &self.billing_address),
+            ("delivery_address.country", &self.delivery_address),
+        ] {
+            if let Some(country) = address.as_ref().and_then(|a| a.country.as_deref()) {
+                validator.country_code(field, country);
+            }
+        }
+        for split in self.splits.iter().flatten() {
+            let currency = split.amount.as_ref().and_then(|a| a.currency.as_ref());
+            validator.same_currency("splits.amount.currency", &self.amount, currency);
+        }
+        validator.finish()
+    }
+}
+
+/// Request to create a POS Mobile session for Adyen's Tap to Pay SDKs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PosSdkSessionRequest {
+    /// The merchant account identifier.
+    pub merchant_account: String,
+
+    /// The setup token generated by the SDK on the mobile device.
+    pub setup_token: String,
+
+    /// The store the mobile device is used in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<String>,
 }
 
-impl LineItem {
-    /// Create a new line item.
+impl PosSdkSessionRequest {
+    /// Create a session request for the setup token provided by the SDK.
     #[must_use]
-    pub fn new(
-        description: impl Into<String>,
-        quantity: u32,
-        amount_including_tax: Amount,
-    ) -> Self {
+    pub fn new(merchant_account: impl Into<String>, setup_token: impl Into<String>) -> Self {
         Self {
-            id: None,
-            description: description.into(),
-            quantity,
-            amount_including_tax,
-            amount_excluding_tax: None,
-            tax_amount: None,
-            tax_percentage: None,
-            item_category: None,
-            additional_data: None,
+            merchant_account: merchant_account.into(),
+            setup_token: setup_token.into(),
+            store: None,
         }
     }
 
-    /// Set the item ID.
+    /// Set the store the mobile device is used in.
     #[must_use]
-    pub fn with_id(mut self, id: impl Into<String>) -> Self {
-        self.id = Some(id.into());
+    pub fn with_store(mut self, store: impl Into<String>) -> Self {
+        self.store = Some(store.into());
         self
     }
+}
 
-    /// Set the tax information.
-    #[must_use]
-    pub fn with_tax(
-        mut self,
-        amount_excluding_tax: Amount,
-        tax_amount: Amount,
-        tax_percentage: u32,
-    ) -> Self {
-        self.amount_excluding_tax = Some(amount_excluding_tax);
-        self.tax_amount = Some(tax_amount);
-        self.tax_percentage = Some(tax_percentage);
-        self
-    }
+/// Response from creating a POS Mobile session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PosSdkSessionResponse {
+    /// The unique session identifier.
+    pub id: String,
 
-    /// Set the item category.
-    #[must_use]
-    pub fn with_category(mut self, category: impl Into<String>) -> Self {
-        self.item_category = Some(category.into());
-        self
-    }
+    /// The session data to pass back to the SDK.
+    pub sdk_data: String,
+
+    /// The identifier of the SDK installation on the mobile device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installation_id: Option<String>,
+
+    /// The merchant account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_account: Option<String>,
+
+    /// The store the mobile device is used in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<String>,
 }
 
 #[cfg(test)]
@@ -404,31 +846,21 @@ mod tests {
         assert_eq!(request.merchant_account, "TestMerchant");
         assert_eq!(request.reference, "Session-12345");
         assert_eq!(request.return_url, "https://example.com/return");
-        assert_eq!(request.country_code, Some("NL".to_string()));
-        assert_eq!(request.shopper_locale, Some("nl-NL".to_string()));
-    }
-
-    #[test]
-    fn test_line_item_creation() {
-        let amount = Amount::from_major_units(10, Currency::EUR);
-        let line_item = LineItem::new("Test Product", 2, amount.clone())
-            .with_id("item-123")
-            .with_category("electronics");
-
-        assert_eq!(line_item.description, "Test Product");
-        assert_eq!(line_item.quantity, 2);
-        assert_eq!(line_item.amount_including_tax, amount);
-        assert_eq!(line_item.id, Some("item-123".to_string()));
-        assert_eq!(line_item.item_category, Some("electronics".to_string()));
+        assert_eq!(
+            request.country_code.as_ref().map(CountryCode::as_str),
+            Some("NL")
+        );
+        assert_eq!(
+            request.shopper_locale.as_ref().map(Locale::as_str),
+            Some("nl-NL")
+        );
     }
 
     #[test]
     fn test_session_request_with_line_items() {
         let amount = Amount::from_major_units(100, Currency::EUR);
-        let item_amount = Amount::from_major_units(50, Currency::EUR);
-
-        let line_item1 = LineItem::new("Product 1", 1, item_amount.clone());
-        let line_item2 = LineItem::new("Product 2", 1, item_amount);
+        let line_item1 = LineItem::new("Product 1", 1, 5000);
+        let line_item2 = LineItem::new("Product 2", 1, 5000);
 
         let request = CreateCheckoutSessionRequest::builder()
             .amount(amount)
@@ -442,4 +874,114 @@ mod tests {
 
         assert_eq!(request.line_items.as_ref().unwrap().len(), 2);
     }
+
+    #[test]
+    fn test_session_request_options_serialization() {
+        let request = CreateCheckoutSessionRequest::builder()
+            .amount(Amount::from_minor_units(10_000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Session-12345")
+            .return_url("https://example.com/return")
+            .shopper_reference("shopper-1")
+            .store_payment_method_mode(StorePaymentMethodMode::AskForConsent)
+            .recurring_processing_model(RecurringProcessingModel::CardOnFile)
+            .allowed_payment_methods(["scheme", "ideal"])
+            .blocked_payment_methods(["paypal"])
+            .split_card_funding_sources(true)
+            .split(Split::commission(100))
+            .risk_data(RiskData::new())
+            .metadata("orderId", "1234")
+            .theme_id("AZ1234567890")
+            .build_validated()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["storePaymentMethodMode"], "askForConsent");
+        assert_eq!(json["recurringProcessingModel"], "CardOnFile");
+        assert_eq!(
+            json["allowedPaymentMethods"],
+            serde_json::json!(["scheme", "ideal"])
+        );
+        assert_eq!(json["blockedPaymentMethods"], serde_json::json!(["paypal"]));
+        assert_eq!(json["splitCardFundingSources"], true);
+        assert_eq!(json["splits"][0]["type"], "Commission");
+        assert_eq!(json["riskData"], serde_json::json!({}));
+        assert_eq!(json["metadata"]["orderId"], "1234");
+        assert_eq!(
+            request.metadata.as_ref().and_then(|m| m.get("orderId")),
+            Some("1234")
+        );
+        assert_eq!(json["themeId"], "AZ1234567890");
+
+        let minimal = CreateCheckoutSessionRequest::builder()
+            .amount(Amount::from_minor_units(10_000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Session-12345")
+            .return_url("https://example.com/return")
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&minimal).unwrap();
+        assert!(json.get("storePaymentMethodMode").is_none());
+        assert!(json.get("metadata").is_none());
+    }
+
+    #[test]
+    fn test_session_result_deserialization() {
+        let response: SessionResultResponse = serde_json::from_str(
+            r#"{
+                "id": "CS12345678",
+                "status": "completed",
+                "reference": "Session-12345",
+                "payments": [
+                    {"pspReference": "8825408195409505", "resultCode": "Authorised", "paymentMethod": {"type": "scheme", "brand": "visa"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.status, SessionStatus::Completed);
+        assert!(response.status.is_final());
+        assert_eq!(
+            response.payments[0].psp_reference.as_ref().unwrap(),
+            "8825408195409505"
+        );
+        assert_eq!(
+            response.payments[0]
+                .payment_method
+                .as_ref()
+                .and_then(|method| method.brand.as_deref()),
+            Some("visa")
+        );
+
+        let pending: SessionResultResponse =
+            serde_json::from_str(r#"{"id": "CS1", "status": "paymentPending"}"#).unwrap();
+        assert!(!pending.status.is_final());
+        let unknown: SessionResultResponse =
+            serde_json::from_str(r#"{"id": "CS1", "status": "somethingNew"}"#).unwrap();
+        assert_eq!(unknown.status, SessionStatus::Unknown);
+    }
+
+    #[test]
+    fn test_pos_sdk_session_serialization() {
+        let request =
+            PosSdkSessionRequest::new("TestMerchant", "setup-token").with_store("Store-1");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "merchantAccount": "TestMerchant",
+                "setupToken": "setup-token",
+                "store": "Store-1"
+            })
+        );
+
+        let response: PosSdkSessionResponse = serde_json::from_str(
+            r#"{"id": "CS123", "installationId": "INST1", "merchantAccount": "TestMerchant", "sdkData": "c2RrRGF0YQ=="}"#,
+        )
+        .unwrap();
+        assert_eq!(response.sdk_data, "c2RrRGF0YQ==");
+        assert_eq!(response.installation_id.as_deref(), Some("INST1"));
+        assert!(response.store.is_none());
+    }
 }
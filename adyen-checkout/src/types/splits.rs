@@ -0,0 +1,160 @@
+//! Split types for distributing funds between accounts (Adyen for Platforms).
+
+use adyen_core::Currency;
+use serde::{Deserialize, Serialize};
+
+/// How a split is booked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum SplitType {
+    /// Books the amount to a balance account.
+    BalanceAccount,
+    /// Books the amount to your platform's liable balance account as commission.
+    Commission,
+    /// Books the amount to the default account.
+    Default,
+    /// Books the amount to a marketplace sub-merchant account (classic platforms).
+    MarketPlace,
+    /// Books the payment fee to the specified balance account.
+    PaymentFee,
+    /// Books any remaining amount to the specified balance account.
+    Remainder,
+    /// Books a surcharge to the specified balance account.
+    Surcharge,
+    /// Books a tip to the specified balance account.
+    Tip,
+    /// Books the VAT to the specified balance account.
+    #[serde(rename = "VAT")]
+    Vat,
+    /// Books the amount for a top-up to the specified balance account.
+    TopUp,
+    /// A split type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The amount of a split.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitAmount {
+    /// The amount in minor units.
+    pub value: u64,
+
+    /// The currency; defaults to the currency of the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+}
+
+/// An instruction for booking part of a payment, capture or refund.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Split {
+    /// How the split is booked.
+    #[serde(rename = "type")]
+    pub split_type: SplitType,
+
+    /// The amount of the split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<SplitAmount>,
+
+    /// The balance account or sub-merchant account the split is booked to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+
+    /// Your reference for the split, shown in reports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// A description of the split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Split {
+    /// Create a split of `value` minor units.
+    #[must_use]
+    pub fn new(split_type: SplitType, value: u64) -> Self {
+        Self {
+            split_type,
+            amount: Some(SplitAmount {
+                value,
+                currency: None,
+            }),
+            account: None,
+            reference: None,
+            description: None,
+        }
+    }
+
+    /// Create a split booking `value` minor units to a balance account.
+    #[must_use]
+    pub fn balance_account(account: impl Into<String>, value: u64) -> Self {
+        Self::new(SplitType::BalanceAccount, value).with_account(account)
+    }
+
+    /// Create a split booking `value` minor units as your platform's commission.
+    #[must_use]
+    pub fn commission(value: u64) -> Self {
+        Self::new(SplitType::Commission, value)
+    }
+
+    /// Set the account the split is booked to.
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Set the reference for the split.
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the description of the split.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_serialization() {
+        let split = Split::balance_account("BA00000000000000000000001", 9000)
+            .with_reference("split-seller");
+
+        assert_eq!(
+            serde_json::to_value(&split).unwrap(),
+            serde_json::json!({
+                "type": "BalanceAccount",
+                "amount": {"value": 9000},
+                "account": "BA00000000000000000000001",
+                "reference": "split-seller"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(SplitType::Vat).unwrap(),
+            serde_json::json!("VAT")
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Types for managing stored payment methods (tokens).
+
+use serde::{Deserialize, Serialize};
+
+/// Response containing stored payment methods for a shopper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ListStoredPaymentMethodsResponse {
+    /// Your merchant account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_account: Option<String>,
+    /// Your reference to uniquely identify this shopper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<String>,
+    /// List of all stored payment methods.
+    #[serde(default)]
+    pub stored_payment_methods: Vec<StoredPaymentMethodResource>,
+}
+
+impl ListStoredPaymentMethodsResponse {
+    /// Find a stored payment method by its ID.
+    #[must_use]
+    pub fn find(&self, id: &str) -> Option<&StoredPaymentMethodResource> {
+        self.stored_payment_methods
+            .iter()
+            .find(|method| method.id == id)
+    }
+}
+
+/// Stored payment method resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredPaymentMethodResource {
+    /// Unique identifier of this stored payment method, used as the token
+    /// in later payments.
+    pub id: String,
+    /// The payment method type, for example `scheme` or `sepadirectdebit`.
+    #[serde(rename = "type")]
+    pub payment_method_type: String,
+    /// The display name of the stored payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The card brand, for example `visa`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<String>,
+    /// The last four digits of the card number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_four: Option<String>,
+    /// The card expiry month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_month: Option<String>,
+    /// The card expiry year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_year: Option<String>,
+    /// The name of the cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holder_name: Option<String>,
+    /// The IBAN of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<String>,
+    /// The name of the bank account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_name: Option<String>,
+    /// The name of the issuer of the token or card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer_name: Option<String>,
+    /// A shopper's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_email: Option<String>,
+    /// The reference the shopper was stored under.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<String>,
+    /// The recurring processing models this token can be used with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_recurring_processing_models: Vec<String>,
+    /// The transaction reference assigned by the card network.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_tx_reference: Option<String>,
+    /// The response code returned by an external system, such as an issuer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_response_code: Option<String>,
+    /// The token reference assigned by an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_token_reference: Option<String>,
+}
+
+impl StoredPaymentMethodResource {
+    /// Whether this stored payment method is a card.
+    #[must_use]
+    pub fn is_card(&self) -> bool {
+        self.payment_method_type == "scheme"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_stored_payment_methods_deserialization() {
+        let response: ListStoredPaymentMethodsResponse =
+            serde_json::from_value(serde_json::json!({
+                "merchantAccount": "TestMerchant",
+                "shopperReference": "shopper-123",
+                "storedPaymentMethods": [
+                    {
+                        "brand": "visa",
+                        "expiryMonth": "03",
+                        "expiryYear": "30",
+                        "holderName": "John Smith",
+                        "id": "M5N7TQ4TG5PFWR50",
+                        "lastFour": "1111",
+                        "name": "VISA",
+                        "supportedRecurringProcessingModels": ["CardOnFile", "Subscription"],
+                        "type": "scheme"
+                    },
+                    {
+                        "iban": "NL13TEST0123456789",
+                        "id": "K6ZMCQGDRMNKGK82",
+                        "ownerName": "John Smith",
+                        "type": "sepadirectdebit"
+                    }
+                ]
+            }))
+            .unwrap();
+
+        let card = response.find("M5N7TQ4TG5PFWR50").unwrap();
+        assert!(card.is_card());
+        assert_eq!(card.last_four.as_deref(), Some("1111"));
+        assert_eq!(card.supported_recurring_processing_models.len(), 2);
+
+        let sepa = response.find("K6ZMCQGDRMNKGK82").unwrap();
+        assert!(!sepa.is_card());
+        assert_eq!(sepa.iban.as_deref(), Some("NL13TEST0123456789"));
+        assert!(response.find("unknown").is_none());
+    }
+}
@@ -0,0 +1,294 @@
+//! 3D Secure 2 request data for Checkout payments.
+
+use serde::{Deserialize, Serialize};
+
+/// Whether to attempt 3D Secure authentication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum AttemptAuthentication {
+    /// Always attempt authentication, even if not required by regulation.
+    Always,
+    /// Never attempt authentication.
+    Never,
+    /// An authentication attempt setting not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether to use the native 3D Secure 2 flow in the Checkout SDKs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum NativeThreeDS {
+    /// Use the native flow if the issuer supports it.
+    Preferred,
+    /// Always use the redirect flow.
+    Disabled,
+    /// A native 3D Secure setting not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Size of the challenge window shown to the shopper in browser flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum ChallengeWindowSize {
+    /// 250 x 400 pixels.
+    #[serde(rename = "01")]
+    Size250x400,
+    /// 390 x 400 pixels.
+    #[serde(rename = "02")]
+    Size390x400,
+    /// 500 x 600 pixels.
+    #[serde(rename = "03")]
+    Size500x600,
+    /// 600 x 400 pixels.
+    #[serde(rename = "04")]
+    Size600x400,
+    /// Full screen.
+    #[serde(rename = "05")]
+    FullScreen,
+    /// A challenge window size not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Channel the shopper authenticates on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviceChannel {
+    /// Authentication in a mobile app with the 3D Secure 2 SDK.
+    App,
+    /// Authentication in a browser.
+    Browser,
+    /// A device channel not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Preference for a challenge, sent to the issuer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum ChallengeIndicator {
+    /// No preference.
+    #[serde(rename = "01")]
+    NoPreference,
+    /// No challenge requested.
+    #[serde(rename = "02")]
+    NoChallengeRequested,
+    /// Challenge requested by the merchant.
+    #[serde(rename = "03")]
+    ChallengeRequested,
+    /// Challenge requested as mandated by regulation.
+    #[serde(rename = "04")]
+    ChallengeMandated,
+    /// A challenge indicator not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Settings for the 3D Secure 2 authentication request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDSRequestData {
+    /// Whether to use the native 3D Secure 2 flow.
+    #[serde(rename = "nativeThreeDS", skip_serializing_if = "Option::is_none")]
+    pub native_three_ds: Option<NativeThreeDS>,
+
+    /// Size of the challenge window in browser flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_window_size: Option<ChallengeWindowSize>,
+
+    /// The 3D Secure version to use, e.g. `2.2.0`.
+    #[serde(rename = "threeDSVersion", skip_serializing_if = "Option::is_none")]
+    pub three_ds_version: Option<String>,
+}
+
+/// Authentication settings for a payment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticationData {
+    /// Whether to attempt 3D Secure authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_authentication: Option<AttemptAuthentication>,
+
+    /// Whether to only authenticate, without authorising the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authentication_only: Option<bool>,
+
+    /// Settings for the 3D Secure 2 authentication request.
+    #[serde(rename = "threeDSRequestData", skip_serializing_if = "Option::is_none")]
+    pub three_ds_request_data: Option<ThreeDSRequestData>,
+}
+
+impl AuthenticationData {
+    /// Create authentication data requesting the native 3D Secure 2 flow,
+    /// used by the iOS and Android Checkout SDKs.
+    #[must_use]
+    pub fn native() -> Self {
+        Self {
+            three_ds_request_data: Some(ThreeDSRequestData {
+                native_three_ds: Some(NativeThreeDS::Preferred),
+                ..ThreeDSRequestData::default()
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Set whether to attempt 3D Secure authentication.
+    #[must_use]
+    pub fn with_attempt_authentication(mut self, attempt: AttemptAuthentication) -> Self {
+        self.attempt_authentication = Some(attempt);
+        self
+    }
+
+    /// Set whether to only authenticate, without authorising the payment.
+    #[must_use]
+    pub fn with_authentication_only(mut self, authentication_only: bool) -> Self {
+        self.authentication_only = Some(authentication_only);
+        self
+    }
+
+    /// Set the size of the challenge window in browser flows.
+    #[must_use]
+    pub fn with_challenge_window_size(mut self, size: ChallengeWindowSize) -> Self {
+        self.three_ds_request_data
+            .get_or_insert_with(ThreeDSRequestData::default)
+            .challenge_window_size = Some(size);
+        self
+    }
+}
+
+/// Additional 3D Secure 2 data for the issuer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreeDS2RequestData {
+    /// Channel the shopper authenticates on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_channel: Option<DeviceChannel>,
+
+    /// Preference for a challenge.
+    #[serde(
+        rename = "threeDSRequestorChallengeInd",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub challenge_indicator: Option<ChallengeIndicator>,
+
+    /// URL of your app, used by the issuer's app to return the shopper after
+    /// out-of-band authentication.
+    #[serde(
+        rename = "threeDSRequestorAppURL",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub requestor_app_url: Option<String>,
+
+    /// URL of your website, for the issuer's reference.
+    #[serde(
+        rename = "threeDSRequestorURL",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub requestor_url: Option<String>,
+}
+
+impl ThreeDS2RequestData {
+    /// Create 3D Secure 2 data for authentication in a mobile app.
+    #[must_use]
+    pub fn app(requestor_app_url: impl Into<String>) -> Self {
+        Self {
+            device_channel: Some(DeviceChannel::App),
+            requestor_app_url: Some(requestor_app_url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create 3D Secure 2 data for authentication in a browser.
+    #[must_use]
+    pub fn browser() -> Self {
+        Self {
+            device_channel: Some(DeviceChannel::Browser),
+            ..Self::default()
+        }
+    }
+
+    /// Set the preference for a challenge.
+    #[must_use]
+    pub fn with_challenge_indicator(mut self, indicator: ChallengeIndicator) -> Self {
+        self.challenge_indicator = Some(indicator);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_authentication_data_serialization() {
+        let data = AuthenticationData::native()
+            .with_attempt_authentication(AttemptAuthentication::Always)
+            .with_challenge_window_size(ChallengeWindowSize::FullScreen);
+
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            serde_json::json!({
+                "attemptAuthentication": "always",
+                "threeDSRequestData": {
+                    "nativeThreeDS": "preferred",
+                    "challengeWindowSize": "05"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_app_three_ds2_request_data_serialization() {
+        let data = ThreeDS2RequestData::app("https://example.com/3ds2")
+            .with_challenge_indicator(ChallengeIndicator::NoPreference);
+
+        assert_eq!(
+            serde_json::to_value(&data).unwrap(),
+            serde_json::json!({
+                "deviceChannel": "app",
+                "threeDSRequestorChallengeInd": "01",
+                "threeDSRequestorAppURL": "https://example.com/3ds2"
+            })
+        );
+    }
+}
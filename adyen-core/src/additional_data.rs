@@ -0,0 +1,948 @@
+//! Typed additional data for airline, lodging and Level 2/3 card payments.
+//!
+//! Adyen reads industry-specific and enhanced scheme data from dotted
+//! `additionalData` keys such as `airline.passenger_name` or
+//! `enhancedSchemeData.itemDetailLine1.unitPrice`. The types in this module
+//! hold that data in a structured form and flatten it into the expected keys
+//! with `to_additional_data`. The Checkout and Classic Payments request
+//! builders accept them directly.
+//!
+//! All amounts are in minor units of the payment currency.
+
+use std::collections::HashMap;
+
+/// Airline passenger data, used for lower interchange and better
+/// authorisation rates on airline ticket payments.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_core::additional_data::{AirlineData, AirlineLeg};
+///
+/// let airline = AirlineData::new("JOHN/DOE")
+///     .with_ticket_number("0062345678901")
+///     .with_leg(AirlineLeg::new("KL", "AMS", "JFK").with_flight_number("641"));
+///
+/// let data = airline.to_additional_data();
+/// assert_eq!(data["airline.passenger_name"], "JOHN/DOE");
+/// assert_eq!(data["airline.leg1.depart_airport"], "AMS");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AirlineData {
+    /// The passenger name as printed on the ticket, e.g. `JOHN/DOE`.
+    pub passenger_name: String,
+
+    /// The IATA 3-digit accounting code of the airline.
+    pub airline_code: Option<String>,
+
+    /// The IATA 2-letter code of the airline.
+    pub airline_designator_code: Option<String>,
+
+    /// The ticket number.
+    pub ticket_number: Option<String>,
+
+    /// The date of the first flight, in `yyyy-MM-dd` format.
+    pub flight_date: Option<String>,
+
+    /// The code of the reservation system used to book the ticket.
+    pub computerized_reservation_system: Option<String>,
+
+    /// Your reference for the customer, e.g. a frequent flyer number.
+    pub customer_reference_number: Option<String>,
+
+    /// The IATA code of the travel agency that issued the ticket.
+    pub travel_agency_code: Option<String>,
+
+    /// The name of the travel agency that issued the ticket.
+    pub travel_agency_name: Option<String>,
+
+    /// The boarding fee, in minor units.
+    pub boarding_fee: Option<u64>,
+
+    /// The flight legs of the trip.
+    pub legs: Vec<AirlineLeg>,
+
+    /// The passengers on the ticket.
+    pub passengers: Vec<AirlinePassenger>,
+}
+
+impl AirlineData {
+    /// Create airline data for the passenger named on the ticket.
+    #[must_use]
+    pub fn new(passenger_name: impl Into<String>) -> Self {
+        Self {
+            passenger_name: passenger_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the IATA 3-digit accounting code of the airline.
+    #[must_use]
+    pub fn with_airline_code(mut self, code: impl Into<String>) -> Self {
+        self.airline_code = Some(code.into());
+        self
+    }
+
+    /// Set the IATA 2-letter code of the airline.
+    #[must_use]
+    pub fn with_airline_designator_code(mut self, code: impl Into<String>) -> Self {
+        self.airline_designator_code = Some(code.into());
+        self
+    }
+
+    /// Set the ticket number.
+    #[must_use]
+    pub fn with_ticket_number(mut self, ticket_number: impl Into<String>) -> Self {
+        self.ticket_number = Some(ticket_number.into());
+        self
+    }
+
+    /// Set the date of the first flight, in `yyyy-MM-dd` format.
+    #[must_use]
+    pub fn with_flight_date(mut self, date: impl Into<String>) -> Self {
+        self.flight_date = Some(date.into());
+        self
+    }
+
+    /// Set your reference for the customer.
+    #[must_use]
+    pub fn with_customer_reference_number(mut self, reference: impl Into<String>) -> Self {
+        self.customer_reference_number = Some(reference.into());
+        self
+    }
+
+    /// Set the travel agency that issued the ticket.
+    #[must_use]
+    pub fn with_travel_agency(mut self, code: impl Into<String>, name: impl Into<String>) -> Self {
+        self.travel_agency_code = Some(code.into());
+        self.travel_agency_name = Some(name.into());
+        self
+    }
+
+    /// Set the boarding fee, in minor units.
+    #[must_use]
+    pub fn with_boarding_fee(mut self, fee: u64) -> Self {
+        self.boarding_fee = Some(fee);
+        self
+    }
+
+    /// Add a flight leg.
+    #[must_use]
+    pub fn with_leg(mut self, leg: AirlineLeg) -> Self {
+        self.legs.push(leg);
+        self
+    }
+
+    /// Add a passenger.
+    #[must_use]
+    pub fn with_passenger(mut self, passenger: AirlinePassenger) -> Self {
+        self.passengers.push(passenger);
+        self
+    }
+
+    /// Get the `airline.*` additional data entries for this data.
+    ///
+    /// Legs and passengers are numbered from 1, as Adyen expects.
+    #[must_use]
+    pub fn to_additional_data(&self) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        data.insert(
+            "airline.passenger_name".to_string(),
+            self.passenger_name.clone(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.airline_code",
+            self.airline_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.airline_designator_code",
+            self.airline_designator_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.ticket_number",
+            self.ticket_number.as_ref(),
+        );
+        insert_opt(&mut data, "airline.flight_date", self.flight_date.as_ref());
+        insert_opt(
+            &mut data,
+            "airline.computerized_reservation_system",
+            self.computerized_reservation_system.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.customer_reference_number",
+            self.customer_reference_number.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.travel_agency_code",
+            self.travel_agency_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.travel_agency_name",
+            self.travel_agency_name.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "airline.boarding_fee",
+            self.boarding_fee.as_ref(),
+        );
+
+        for (index, leg) in self.legs.iter().enumerate() {
+            leg.insert_into(&mut data, &format!("airline.leg{}", index + 1));
+        }
+
+        for (index, passenger) in self.passengers.iter().enumerate() {
+            passenger.insert_into(&mut data, &format!("airline.passenger{}", index + 1));
+        }
+
+        data
+    }
+}
+
+/// A flight leg of an airline trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirlineLeg {
+    /// The IATA 2-letter code of the carrier.
+    pub carrier_code: String,
+
+    /// The IATA 3-letter code of the departure airport.
+    pub depart_airport: String,
+
+    /// The IATA 3-letter code of the destination airport.
+    pub destination_code: String,
+
+    /// The flight number.
+    pub flight_number: Option<String>,
+
+    /// The departure date and time, in `yyyy-MM-dd HH:mm` format.
+    pub date_of_travel: Option<String>,
+
+    /// The fare class, e.g. `F` for first class.
+    pub class_of_travel: Option<String>,
+
+    /// The fare basis code.
+    pub fare_base_code: Option<String>,
+
+    /// `O` if a stopover is allowed on this leg, `X` if not.
+    pub stop_over_code: Option<String>,
+
+    /// The departure tax, in minor units.
+    pub depart_tax: Option<u64>,
+}
+
+impl AirlineLeg {
+    /// Create a flight leg with the carrier and the departure and destination
+    /// airports.
+    #[must_use]
+    pub fn new(
+        carrier_code: impl Into<String>,
+        depart_airport: impl Into<String>,
+        destination_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            carrier_code: carrier_code.into(),
+            depart_airport: depart_airport.into(),
+            destination_code: destination_code.into(),
+            flight_number: None,
+            date_of_travel: None,
+            class_of_travel: None,
+            fare_base_code: None,
+            stop_over_code: None,
+            depart_tax: None,
+        }
+    }
+
+    /// Set the flight number.
+    #[must_use]
+    pub fn with_flight_number(mut self, flight_number: impl Into<String>) -> Self {
+        self.flight_number = Some(flight_number.into());
+        self
+    }
+
+    /// Set the departure date and time, in `yyyy-MM-dd HH:mm` format.
+    #[must_use]
+    pub fn with_date_of_travel(mut self, date: impl Into<String>) -> Self {
+        self.date_of_travel = Some(date.into());
+        self
+    }
+
+    /// Set the fare class.
+    #[must_use]
+    pub fn with_class_of_travel(mut self, class: impl Into<String>) -> Self {
+        self.class_of_travel = Some(class.into());
+        self
+    }
+
+    /// Set the fare basis code.
+    #[must_use]
+    pub fn with_fare_base_code(mut self, code: impl Into<String>) -> Self {
+        self.fare_base_code = Some(code.into());
+        self
+    }
+
+    /// Set whether a stopover is allowed on this leg.
+    #[must_use]
+    pub fn with_stop_over(mut self, allowed: bool) -> Self {
+        self.stop_over_code = Some(if allowed { "O" } else { "X" }.to_string());
+        self
+    }
+
+    /// Set the departure tax, in minor units.
+    #[must_use]
+    pub fn with_depart_tax(mut self, tax: u64) -> Self {
+        self.depart_tax = Some(tax);
+        self
+    }
+    fn insert_into(&self, data: &mut HashMap<String, String>, prefix: &str) {
+        data.insert(format!("{prefix}.carrier_code"), self.carrier_code.clone());
+        data.insert(
+            format!("{prefix}.depart_airport"),
+            self.depart_airport.clone(),
+        );
+        data.insert(
+            format!("{prefix}.destination_code"),
+            self.destination_code.clone(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.flight_number"),
+            self.flight_number.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.date_of_travel"),
+            self.date_of_travel.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.class_of_travel"),
+            self.class_of_travel.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.fare_base_code"),
+            self.fare_base_code.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.stop_over_code"),
+            self.stop_over_code.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.depart_tax"),
+            self.depart_tax.as_ref(),
+        );
+    }
+}
+
+/// A passenger on an airline ticket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirlinePassenger {
+    /// The passenger's first name.
+    pub first_name: String,
+
+    /// The passenger's last name.
+    pub last_name: String,
+
+    /// The passenger's date of birth, in `yyyy-MM-dd` format.
+    pub date_of_birth: Option<String>,
+
+    /// The IATA passenger type code, e.g. `ADT` for adult or `CHD` for child.
+    pub traveller_type: Option<String>,
+
+    /// The passenger's phone number.
+    pub phone_number: Option<String>,
+}
+
+impl AirlinePassenger {
+    /// Create a passenger.
+    #[must_use]
+    pub fn new(first_name: impl Into<String>, last_name: impl Into<String>) -> Self {
+        Self {
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            date_of_birth: None,
+            traveller_type: None,
+            phone_number: None,
+        }
+    }
+
+    /// Set the passenger's date of birth, in `yyyy-MM-dd` format.
+    #[must_use]
+    pub fn with_date_of_birth(mut self, date: impl Into<String>) -> Self {
+        self.date_of_birth = Some(date.into());
+        self
+    }
+
+    /// Set the IATA passenger type code.
+    #[must_use]
+    pub fn with_traveller_type(mut self, traveller_type: impl Into<String>) -> Self {
+        self.traveller_type = Some(traveller_type.into());
+        self
+    }
+
+    /// Set the passenger's phone number.
+    #[must_use]
+    pub fn with_phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.phone_number = Some(phone_number.into());
+        self
+    }
+    fn insert_into(&self, data: &mut HashMap<String, String>, prefix: &str) {
+        data.insert(format!("{prefix}.first_name"), self.first_name.clone());
+        data.insert(format!("{prefix}.last_name"), self.last_name.clone());
+        insert_opt(
+            data,
+            &format!("{prefix}.date_of_birth"),
+            self.date_of_birth.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.traveller_type"),
+            self.traveller_type.as_ref(),
+        );
+        insert_opt(
+            data,
+            &format!("{prefix}.phone_number"),
+            self.phone_number.as_ref(),
+        );
+    }
+}
+
+/// Lodging data for hotel and accommodation payments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LodgingData {
+    /// The arrival date, in `yyyy-MM-dd` format.
+    pub check_in_date: Option<String>,
+
+    /// The departure date, in `yyyy-MM-dd` format.
+    pub check_out_date: Option<String>,
+
+    /// The folio (invoice) number.
+    pub folio_number: Option<String>,
+
+    /// The phone number of the property.
+    pub property_phone_number: Option<String>,
+
+    /// The toll-free customer service phone number of the property.
+    pub customer_service_toll_free_number: Option<String>,
+
+    /// Whether the property complies with the US Hotel and Motel Fire Safety
+    /// Act of 1990.
+    pub fire_safety_act_indicator: Option<bool>,
+
+    /// Whether the guest did not check in.
+    pub no_show_indicator: Option<bool>,
+
+    /// The booked rooms.
+    pub rooms: Vec<LodgingRoom>,
+
+    /// The total tax on the room charges, in minor units.
+    pub total_room_tax: Option<u64>,
+
+    /// The total tax on the stay, in minor units.
+    pub total_tax: Option<u64>,
+
+    /// The food and beverage charges, in minor units.
+    pub food_beverage_charges: Option<u64>,
+
+    /// The prepaid expenses, in minor units.
+    pub prepaid_expenses: Option<u64>,
+
+    /// The cash advances on the folio, in minor units.
+    pub folio_cash_advances: Option<u64>,
+}
+
+impl LodgingData {
+    /// Create empty lodging data.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the arrival and departure dates, in `yyyy-MM-dd` format.
+    #[must_use]
+    pub fn with_stay(mut self, check_in: impl Into<String>, check_out: impl Into<String>) -> Self {
+        self.check_in_date = Some(check_in.into());
+        self.check_out_date = Some(check_out.into());
+        self
+    }
+
+    /// Set the folio number.
+    #[must_use]
+    pub fn with_folio_number(mut self, folio_number: impl Into<String>) -> Self {
+        self.folio_number = Some(folio_number.into());
+        self
+    }
+
+    /// Set the phone number of the property.
+    #[must_use]
+    pub fn with_property_phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.property_phone_number = Some(phone_number.into());
+        self
+    }
+
+    /// Set whether the guest did not check in.
+    #[must_use]
+    pub fn with_no_show(mut self, no_show: bool) -> Self {
+        self.no_show_indicator = Some(no_show);
+        self
+    }
+
+    /// Add a booked room.
+    #[must_use]
+    pub fn with_room(mut self, room: LodgingRoom) -> Self {
+        self.rooms.push(room);
+        self
+    }
+
+    /// Set the total tax on the stay, in minor units.
+    #[must_use]
+    pub fn with_total_tax(mut self, total_tax: u64) -> Self {
+        self.total_tax = Some(total_tax);
+        self
+    }
+
+    /// Get the `lodging.*` additional data entries for this data.
+    ///
+    /// Rooms are numbered from 1, as Adyen expects.
+    #[must_use]
+    pub fn to_additional_data(&self) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        insert_opt(
+            &mut data,
+            "lodging.checkInDate",
+            self.check_in_date.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "lodging.checkOutDate",
+            self.check_out_date.as_ref(),
+        );
+        insert_opt(&mut data, "lodging.folioNumber", self.folio_number.as_ref());
+        insert_opt(
+            &mut data,
+            "lodging.propertyPhoneNumber",
+            self.property_phone_number.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "lodging.customerServiceTollFreeNumber",
+            self.customer_service_toll_free_number.as_ref(),
+        );
+        if let Some(indicator) = self.fire_safety_act_indicator {
+            data.insert(
+                "lodging.fireSafetyActIndicator".to_string(),
+                yes_no(indicator),
+            );
+        }
+        if let Some(indicator) = self.no_show_indicator {
+            data.insert("lodging.noShowIndicator".to_string(), yes_no(indicator));
+        }
+        insert_opt(
+            &mut data,
+            "lodging.totalRoomTax",
+            self.total_room_tax.as_ref(),
+        );
+        insert_opt(&mut data, "lodging.totalTax", self.total_tax.as_ref());
+        insert_opt(
+            &mut data,
+            "lodging.foodBeverageCharges",
+            self.food_beverage_charges.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "lodging.prepaidExpenses",
+            self.prepaid_expenses.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "lodging.folioCashAdvances",
+            self.folio_cash_advances.as_ref(),
+        );
+
+        for (index, room) in self.rooms.iter().enumerate() {
+            let prefix = format!("lodging.room{}", index + 1);
+            data.insert(format!("{prefix}.rate"), room.rate.to_string());
+            data.insert(
+                format!("{prefix}.numberOfNights"),
+                room.number_of_nights.to_string(),
+            );
+        }
+
+        data
+    }
+}
+
+/// A booked room of a lodging stay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LodgingRoom {
+    /// The nightly rate, in minor units.
+    pub rate: u64,
+
+    /// The number of nights.
+    pub number_of_nights: u32,
+}
+
+impl LodgingRoom {
+    /// Create a room with its nightly rate, in minor units.
+    #[must_use]
+    pub fn new(rate: u64, number_of_nights: u32) -> Self {
+        Self {
+            rate,
+            number_of_nights,
+        }
+    }
+}
+
+/// Level 2/3 enhanced scheme data for commercial card payments.
+///
+/// Level 2 data covers the tax and customer reference; Level 3 data adds
+/// shipping details and the individual items.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnhancedSchemeData {
+    /// The customer code or purchase order number.
+    pub customer_reference: Option<String>,
+
+    /// The total tax amount, in minor units.
+    pub total_tax_amount: Option<u64>,
+
+    /// The shipping amount, in minor units.
+    pub freight_amount: Option<u64>,
+
+    /// The duty amount, in minor units.
+    pub duty_amount: Option<u64>,
+
+    /// The postal code of the destination address.
+    pub destination_postal_code: Option<String>,
+
+    /// The state or province code of the destination address.
+    pub destination_state_province_code: Option<String>,
+
+    /// The ISO 3166-1 alpha-3 country code of the destination address.
+    pub destination_country_code: Option<String>,
+
+    /// The postal code of the address the goods ship from.
+    pub ship_from_postal_code: Option<String>,
+
+    /// The order date, in `ddMMyy` format.
+    pub order_date: Option<String>,
+
+    /// The purchased items.
+    pub items: Vec<EnhancedSchemeItem>,
+}
+
+impl EnhancedSchemeData {
+    /// Create empty enhanced scheme data.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the customer code or purchase order number.
+    #[must_use]
+    pub fn with_customer_reference(mut self, reference: impl Into<String>) -> Self {
+        self.customer_reference = Some(reference.into());
+        self
+    }
+
+    /// Set the total tax amount, in minor units.
+    #[must_use]
+    pub fn with_total_tax_amount(mut self, amount: u64) -> Self {
+        self.total_tax_amount = Some(amount);
+        self
+    }
+
+    /// Set the shipping amount, in minor units.
+    #[must_use]
+    pub fn with_freight_amount(mut self, amount: u64) -> Self {
+        self.freight_amount = Some(amount);
+        self
+    }
+
+    /// Set the destination postal code and ISO 3166-1 alpha-3 country code.
+    #[must_use]
+    pub fn with_destination(
+        mut self,
+        postal_code: impl Into<String>,
+        country_code: impl Into<String>,
+    ) -> Self {
+        self.destination_postal_code = Some(postal_code.into());
+        self.destination_country_code = Some(country_code.into());
+        self
+    }
+
+    /// Set the order date, in `ddMMyy` format.
+    #[must_use]
+    pub fn with_order_date(mut self, date: impl Into<String>) -> Self {
+        self.order_date = Some(date.into());
+        self
+    }
+
+    /// Add a purchased item.
+    #[must_use]
+    pub fn with_item(mut self, item: EnhancedSchemeItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Get the `enhancedSchemeData.*` additional data entries for this data.
+    ///
+    /// Items are numbered from 1, as Adyen expects.
+    #[must_use]
+    pub fn to_additional_data(&self) -> HashMap<String, String> {
+        let mut data = HashMap::new();
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.customerReference",
+            self.customer_reference.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.totalTaxAmount",
+            self.total_tax_amount.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.freightAmount",
+            self.freight_amount.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.dutyAmount",
+            self.duty_amount.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.destinationPostalCode",
+            self.destination_postal_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.destinationStateProvinceCode",
+            self.destination_state_province_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.destinationCountryCode",
+            self.destination_country_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.shipFromPostalCode",
+            self.ship_from_postal_code.as_ref(),
+        );
+        insert_opt(
+            &mut data,
+            "enhancedSchemeData.orderDate",
+            self.order_date.as_ref(),
+        );
+
+        for (index, item) in self.items.iter().enumerate() {
+            let prefix = format!("enhancedSchemeData.itemDetailLine{}", index + 1);
+            data.insert(format!("{prefix}.description"), item.description.clone());
+            data.insert(format!("{prefix}.quantity"), item.quantity.to_string());
+            data.insert(format!("{prefix}.unitPrice"), item.unit_price.to_string());
+            data.insert(
+                format!("{prefix}.totalAmount"),
+                item.total_amount().to_string(),
+            );
+            insert_opt(
+                &mut data,
+                &format!("{prefix}.productCode"),
+                item.product_code.as_ref(),
+            );
+            insert_opt(
+                &mut data,
+                &format!("{prefix}.commodityCode"),
+                item.commodity_code.as_ref(),
+            );
+            insert_opt(
+                &mut data,
+                &format!("{prefix}.unitOfMeasure"),
+                item.unit_of_measure.as_ref(),
+            );
+            insert_opt(
+                &mut data,
+                &format!("{prefix}.discountAmount"),
+                item.discount_amount.as_ref(),
+            );
+        }
+
+        data
+    }
+}
+
+/// A purchased item in Level 3 enhanced scheme data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnhancedSchemeItem {
+    /// The item description.
+    pub description: String,
+
+    /// The quantity of the item.
+    pub quantity: u32,
+
+    /// The price per unit, in minor units.
+    pub unit_price: u64,
+
+    /// Your product code for the item.
+    pub product_code: Option<String>,
+
+    /// The UNSPSC commodity code of the item.
+    pub commodity_code: Option<String>,
+
+    /// The unit of measure, e.g. `EAC` for each.
+    pub unit_of_measure: Option<String>,
+
+    /// The discount on the item, in minor units.
+    pub discount_amount: Option<u64>,
+}
+
+impl EnhancedSchemeItem {
+    /// Create an item with its price per unit, in minor units.
+    #[must_use]
+    pub fn new(description: impl Into<String>, quantity: u32, unit_price: u64) -> Self {
+        Self {
+            description: description.into(),
+            quantity,
+            unit_price,
+            product_code: None,
+            commodity_code: None,
+            unit_of_measure: None,
+            discount_amount: None,
+        }
+    }
+
+    /// Set your product code for the item.
+    #[must_use]
+    pub fn with_product_code(mut self, code: impl Into<String>) -> Self {
+        self.product_code = Some(code.into());
+        self
+    }
+
+    /// Set the UNSPSC commodity code of the item.
+    #[must_use]
+    pub fn with_commodity_code(mut self, code: impl Into<String>) -> Self {
+        self.commodity_code = Some(code.into());
+        self
+    }
+
+    /// Set the unit of measure.
+    #[must_use]
+    pub fn with_unit_of_measure(mut self, unit: impl Into<String>) -> Self {
+        self.unit_of_measure = Some(unit.into());
+        self
+    }
+
+    /// Set the discount on the item, in minor units.
+    #[must_use]
+    pub fn with_discount_amount(mut self, amount: u64) -> Self {
+        self.discount_amount = Some(amount);
+        self
+    }
+
+    /// Get the total amount for the item after the discount, in minor units.
+    #[must_use]
+    pub fn total_amount(&self) -> u64 {
+        self.unit_price
+            .saturating_mul(u64::from(self.quantity))
+            .saturating_sub(self.discount_amount.unwrap_or(0))
+    }
+}
+
+fn insert_opt<T: ToString>(data: &mut HashMap<String, String>, key: &str, value: Option<&T>) {
+    if let Some(value) = value {
+        data.insert(key.to_string(), value.to_string());
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "Y" } else { "N" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_airline_data() {
+        let airline = AirlineData::new("JOHN/DOE")
+            .with_airline_designator_code("KL")
+            .with_ticket_number("0062345678901")
+            .with_boarding_fee(500)
+            .with_leg(
+                AirlineLeg::new("KL", "AMS", "JFK")
+                    .with_flight_number("641")
+                    .with_stop_over(false),
+            )
+            .with_leg(AirlineLeg::new("KL", "JFK", "AMS"))
+            .with_passenger(AirlinePassenger::new("John", "Doe").with_traveller_type("ADT"));
+
+        let data = airline.to_additional_data();
+        assert_eq!(data["airline.passenger_name"], "JOHN/DOE");
+        assert_eq!(data["airline.airline_designator_code"], "KL");
+        assert_eq!(data["airline.boarding_fee"], "500");
+        assert_eq!(data["airline.leg1.flight_number"], "641");
+        assert_eq!(data["airline.leg1.stop_over_code"], "X");
+        assert_eq!(data["airline.leg2.depart_airport"], "JFK");
+        assert_eq!(data["airline.passenger1.last_name"], "Doe");
+        assert_eq!(data["airline.passenger1.traveller_type"], "ADT");
+        assert!(!data.contains_key("airline.airline_code"));
+        assert!(!data.contains_key("airline.leg2.flight_number"));
+    }
+
+    #[test]
+    fn test_lodging_data() {
+        let lodging = LodgingData::new()
+            .with_stay("2026-10-16", "2026-10-18")
+            .with_folio_number("F-123")
+            .with_no_show(false)
+            .with_room(LodgingRoom::new(12000, 2))
+            .with_total_tax(2400);
+
+        let data = lodging.to_additional_data();
+        assert_eq!(data["lodging.checkInDate"], "2026-10-16");
+        assert_eq!(data["lodging.checkOutDate"], "2026-10-18");
+        assert_eq!(data["lodging.noShowIndicator"], "N");
+        assert_eq!(data["lodging.room1.rate"], "12000");
+        assert_eq!(data["lodging.room1.numberOfNights"], "2");
+        assert_eq!(data["lodging.totalTax"], "2400");
+        assert!(LodgingData::new().to_additional_data().is_empty());
+    }
+
+    #[test]
+    fn test_enhanced_scheme_data() {
+        let enhanced = EnhancedSchemeData::new()
+            .with_customer_reference("PO-42")
+            .with_total_tax_amount(210)
+            .with_destination("1011 DJ", "NLD")
+            .with_item(
+                EnhancedSchemeItem::new("Printer paper", 4, 500)
+                    .with_unit_of_measure("EAC")
+                    .with_discount_amount(100),
+            );
+
+        let data = enhanced.to_additional_data();
+        assert_eq!(data["enhancedSchemeData.customerReference"], "PO-42");
+        assert_eq!(data["enhancedSchemeData.totalTaxAmount"], "210");
+        assert_eq!(data["enhancedSchemeData.destinationCountryCode"], "NLD");
+        assert_eq!(
+            data["enhancedSchemeData.itemDetailLine1.description"],
+            "Printer paper"
+        );
+        assert_eq!(data["enhancedSchemeData.itemDetailLine1.quantity"], "4");
+        assert_eq!(
+            data["enhancedSchemeData.itemDetailLine1.totalAmount"],
+            "1900"
+        );
+        assert_eq!(
+            data["enhancedSchemeData.itemDetailLine1.discountAmount"],
+            "100"
+        );
+    }
+}
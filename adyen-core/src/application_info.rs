@@ -0,0 +1,163 @@
+//! Information about the integration sending requests.
+//!
+//! Adyen support uses `applicationInfo` to identify the platform and
+//! application behind a payment. Set it once with
+//! [`ConfigBuilder::application_info`](crate::ConfigBuilder::application_info)
+//! and the client adds it to every payment, session, payment link, donation
+//! and modification request.
+
+/// Application information.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ApplicationInfo {
+    /// Information about the merchant application.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub merchant_application: Option<MerchantApplication>,
+
+    /// Information about the external platform.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub external_platform: Option<ExternalPlatform>,
+}
+
+/// Merchant application information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct MerchantApplication {
+    /// The application name.
+    pub name: String,
+
+    /// The application version.
+    pub version: String,
+}
+
+/// External platform information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExternalPlatform {
+    /// The platform name.
+    pub name: String,
+
+    /// The platform version.
+    pub version: String,
+
+    /// The platform integrator.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub integrator: Option<String>,
+}
+
+impl ApplicationInfo {
+    /// Set the merchant application.
+    #[must_use]
+    pub fn with_merchant_application(
+        mut self,
+        name: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        self.merchant_application = Some(MerchantApplication {
+            name: name.into(),
+            version: version.into(),
+        });
+        self
+    }
+
+    /// Set the external platform, with an optional integrator.
+    #[must_use]
+    pub fn with_external_platform(
+        mut self,
+        name: impl Into<String>,
+        version: impl Into<String>,
+        integrator: Option<&str>,
+    ) -> Self {
+        self.external_platform = Some(ExternalPlatform {
+            name: name.into(),
+            version: version.into(),
+            integrator: integrator.map(String::from),
+        });
+        self
+    }
+
+    /// Add this information to the `applicationInfo` of a JSON request body.
+    ///
+    /// Fields already present in the body are kept, so information set on a
+    /// single request wins over the configured defaults.
+    #[cfg(feature = "serde")]
+    pub(crate) fn merge_into(&self, body: &mut serde_json::Value) -> crate::Result<()> {
+        let serde_json::Value::Object(ours) = serde_json::to_value(self)? else {
+            return Ok(());
+        };
+        let Some(body) = body.as_object_mut() else {
+            return Ok(());
+        };
+
+        let entry = body
+            .entry("applicationInfo")
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if entry.is_null() {
+            *entry = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let Some(theirs) = entry.as_object_mut() {
+            for (key, value) in ours {
+                theirs.entry(key).or_insert(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_application_info() {
+        let info = ApplicationInfo::default()
+            .with_merchant_application("shop", "2.1.0")
+            .with_external_platform("Magento", "2.4.6", Some("Acme Integrations"));
+
+        let mut body = json!({"merchantAccount": "TestMerchant"});
+        info.merge_into(&mut body).unwrap();
+        assert_eq!(
+            body["applicationInfo"],
+            json!({
+                "merchantApplication": {"name": "shop", "version": "2.1.0"},
+                "externalPlatform": {
+                    "name": "Magento",
+                    "version": "2.4.6",
+                    "integrator": "Acme Integrations"
+                }
+            })
+        );
+
+        let mut body = json!({
+            "applicationInfo": {"merchantApplication": {"name": "pos", "version": "1.0"}}
+        });
+        info.merge_into(&mut body).unwrap();
+        assert_eq!(
+            body["applicationInfo"]["merchantApplication"]["name"],
+            "pos"
+        );
+        assert_eq!(
+            body["applicationInfo"]["externalPlatform"]["name"],
+            "Magento"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! rkyv support for fields without an rkyv representation.
+//!
+//! Request and response types keep free-form data, such as additional data
+//! and raw actions, as [`serde_json::Value`]. Such fields are archived as
+//! their JSON text with [`AsJson`]:
+//!
+//! ```rust
+//! # #[cfg(all(feature = "rkyv", feature = "serde"))]
+//! # {
+//! use adyen_core::archive::AsJson;
+//! use std::collections::HashMap;
+//!
+//! #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+//! struct Item {
+//!     #[with(AsJson)]
+//!     additional_data: Option<HashMap<String, serde_json::Value>>,
+//! }
+//! # }
+//! ```
+
+use rkyv::ser::Serializer;
+use rkyv::string::{ArchivedString, StringResolver};
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::Fallible;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Archives a field as its JSON text.
+///
+/// The archived field is an [`ArchivedString`], which can be read without
+/// deserializing the rest of the archive and parsed on demand.
+///
+/// A value that cannot be written as JSON is archived as `null`, and archived
+/// text that is not valid JSON for the field type deserializes as the type's
+/// default. Neither happens for JSON values and maps of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsJson;
+
+fn to_json<T: Serialize>(field: &T) -> String {
+    serde_json::to_string(field).unwrap_or_else(|_| "null".to_string())
+}
+
+impl<T: Serialize> ArchiveWith<T> for AsJson {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    unsafe fn resolve_with(
+        field: &T,
+        pos: usize,
+        resolver: StringResolver,
+        out: *mut ArchivedString,
+    ) {
+        // SAFETY: serializing the same value yields the same text that
+        // `serialize_with` archived, which `resolver` refers to.
+        unsafe { ArchivedString::resolve_from_str(&to_json(field), pos, resolver, out) }
+    }
+}
+
+impl<T: Serialize, S: Fallible + Serializer + ?Sized> SerializeWith<T, S> for AsJson {
+    fn serialize_with(field: &T, serializer: &mut S) -> Result<StringResolver, S::Error> {
+        ArchivedString::serialize_from_str(&to_json(field), serializer)
+    }
+}
+
+impl<T: DeserializeOwned + Default, D: Fallible + ?Sized> DeserializeWith<ArchivedString, T, D>
+    for AsJson
+{
+    fn deserialize_with(field: &ArchivedString, _: &mut D) -> Result<T, D::Error> {
+        Ok(serde_json::from_str(field.as_str()).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+    #[archive(check_bytes)]
+    struct Item {
+        #[with(AsJson)]
+        action: serde_json::Value,
+        #[with(AsJson)]
+        additional_data: Option<HashMap<String, serde_json::Value>>,
+    }
+
+    #[test]
+    fn test_as_json_round_trip() {
+        let item = Item {
+            action: json!({"type": "redirect", "url": "https://example.com"}),
+            additional_data: Some(HashMap::from([(
+                "fundingSource".to_string(),
+                json!("DEBIT"),
+            )])),
+        };
+
+        let bytes = rkyv::to_bytes::<_, 256>(&item).unwrap();
+        let archived = rkyv::check_archived_root::<Item>(&bytes).unwrap();
+        assert_eq!(
+            archived.additional_data.as_str(),
+            r#"{"fundingSource":"DEBIT"}"#
+        );
+
+        let item: Item = rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).unwrap();
+        assert_eq!(item.action["type"], "redirect");
+        assert_eq!(item.additional_data.unwrap()["fundingSource"], "DEBIT");
+    }
+}
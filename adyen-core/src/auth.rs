@@ -1,6 +1,7 @@
 //! Authentication mechanisms for Adyen APIs.
 
-use crate::{AdyenError, Result};
+use crate::oauth::OAuthCredentials;
+use crate::{AdyenError, Result, SecretString};
 use base64::prelude::*;
 use std::fmt;
 
@@ -11,15 +12,18 @@ pub enum Credentials {
     ApiKey(ApiKey),
     /// Basic authentication (for Legal Entity Management API)
     Basic(BasicAuth),
+    /// OAuth access tokens (for partner applications)
+    OAuth(OAuthCredentials),
 }
 
 /// API Key for authentication with Adyen APIs.
 ///
 /// This is the most common authentication method for Adyen APIs.
-/// The API key should be kept secure and never logged or exposed.
+/// The API key should be kept secure and never logged or exposed; it is
+/// zeroized when dropped.
 #[derive(Clone, PartialEq, Eq)]
 pub struct ApiKey {
-    key: String,
+    key: SecretString,
 }
 
 impl ApiKey {
@@ -48,7 +52,7 @@ impl ApiKey {
             return Err(AdyenError::auth("API key cannot contain whitespace"));
         }
 
-        Ok(Self { key })
+        Ok(Self { key: key.into() })
     }
 
     /// Get the API key as a string slice.
@@ -57,7 +61,7 @@ impl ApiKey {
     /// Avoid logging or displaying this value.
     #[must_use]
     pub fn as_str(&self) -> &str {
-        &self.key
+        self.key.expose_secret()
     }
 }
 
@@ -76,10 +80,11 @@ impl fmt::Display for ApiKey {
 /// Basic authentication credentials.
 ///
 /// Used for some Adyen APIs, particularly the Legal Entity Management API.
+/// The password is zeroized when dropped.
 #[derive(Clone, PartialEq, Eq)]
 pub struct BasicAuth {
     username: String,
-    password: String,
+    password: SecretString,
 }
 
 impl BasicAuth {
@@ -100,7 +105,10 @@ impl BasicAuth {
             return Err(AdyenError::auth("Password cannot be empty"));
         }
 
-        Ok(Self { username, password })
+        Ok(Self {
+            username,
+            password: password.into(),
+        })
     }
 
     /// Get the username.
@@ -115,14 +123,18 @@ impl BasicAuth {
     /// Avoid logging or displaying this value.
     #[must_use]
     pub fn password(&self) -> &str {
-        &self.password
+        self.password.expose_secret()
     }
 
     /// Generate the Authorization header value for basic authentication.
     #[must_use]
     pub fn authorization_header(&self) -> String {
-        let credentials = format!("{}:{}", self.username, self.password);
-        let encoded = base64::prelude::BASE64_STANDARD.encode(credentials);
+        let credentials = zeroize::Zeroizing::new(format!(
+            "{}:{}",
+            self.username,
+            self.password.expose_secret()
+        ));
+        let encoded = base64::prelude::BASE64_STANDARD.encode(credentials.as_bytes());
         format!("Basic {encoded}")
     }
 }
@@ -165,6 +177,12 @@ impl Credentials {
         Ok(Self::Basic(BasicAuth::new(username, password)?))
     }
 
+    /// Create OAuth credentials.
+    #[must_use]
+    pub const fn oauth(credentials: OAuthCredentials) -> Self {
+        Self::OAuth(credentials)
+    }
+
     /// Check if these are API key credentials.
     #[must_use]
     pub const fn is_api_key(&self) -> bool {
@@ -177,12 +195,18 @@ impl Credentials {
         matches!(self, Self::Basic(_))
     }
 
+    /// Check if these are OAuth credentials.
+    #[must_use]
+    pub const fn is_oauth(&self) -> bool {
+        matches!(self, Self::OAuth(_))
+    }
+
     /// Get the API key if these are API key credentials.
     #[must_use]
     pub const fn as_api_key(&self) -> Option<&ApiKey> {
         match self {
             Self::ApiKey(key) => Some(key),
-            Self::Basic(_) => None,
+            Self::Basic(_) | Self::OAuth(_) => None,
         }
     }
 
@@ -190,8 +214,17 @@ impl Credentials {
     #[must_use]
     pub const fn basic_auth(&self) -> Option<&BasicAuth> {
         match self {
-            Self::ApiKey(_) => None,
             Self::Basic(auth) => Some(auth),
+            Self::ApiKey(_) | Self::OAuth(_) => None,
+        }
+    }
+
+    /// Get the OAuth credentials if these are OAuth credentials.
+    #[must_use]
+    pub const fn as_oauth(&self) -> Option<&OAuthCredentials> {
+        match self {
+            Self::OAuth(oauth) => Some(oauth),
+            Self::ApiKey(_) | Self::Basic(_) => None,
         }
     }
 }
@@ -201,6 +234,7 @@ impl fmt::Display for Credentials {
         match self {
             Self::ApiKey(_) => write!(f, "ApiKey([REDACTED])"),
             Self::Basic(auth) => write!(f, "{auth}"),
+            Self::OAuth(oauth) => write!(f, "{oauth}"),
         }
     }
 }
@@ -273,5 +307,12 @@ mod tests {
         assert!(basic_creds.is_basic());
         assert!(basic_creds.as_api_key().is_none());
         assert!(basic_creds.basic_auth().is_some());
+
+        let oauth_creds = Credentials::oauth(
+            OAuthCredentials::from_refresh_token("client", "secret", "refresh").unwrap(),
+        );
+        assert!(oauth_creds.is_oauth());
+        assert!(oauth_creds.as_api_key().is_none());
+        assert_eq!(oauth_creds.as_oauth().unwrap().client_id(), "client");
     }
 }
@@ -0,0 +1,268 @@
+//! Blocking API for code without an async runtime.
+//!
+//! Like reqwest's `blocking` module, the blocking API runs the async client
+//! on a runtime owned by the library, so callers need neither tokio nor
+//! `.await`. Every API crate has a `blocking` feature adding a `_blocking`
+//! variant of each API method, e.g. `PaymentsApi::authorise_blocking`.
+//!
+//! Blocking calls must not be made from within an async runtime; they panic
+//! there, as blocking an executor thread would stall other tasks. Use the
+//! async methods instead.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::blocking::BlockingClient;
+//! use adyen_core::{ConfigBuilder, Environment};
+//!
+//! # fn example() -> adyen_core::Result<()> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let client = BlockingClient::new(config)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Api, ApiResponse, Client, Config, Request, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Runtime};
+
+/// Run a future to completion on the library's runtime.
+///
+/// The runtime is created on first use and shared by all blocking calls, so
+/// connections are pooled across them.
+///
+/// # Panics
+///
+/// Panics if called from within an async runtime, or if the runtime cannot
+/// be created.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    assert!(
+        tokio::runtime::Handle::try_current().is_err(),
+        "blocking Adyen API calls cannot be made from within an async runtime; use the async methods instead"
+    );
+    runtime().block_on(future)
+}
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("adyen-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to create the runtime for blocking Adyen API calls")
+    })
+}
+
+/// Blocking variant of [`Client`].
+#[derive(Debug, Clone)]
+pub struct BlockingClient {
+    client: Client,
+}
+
+impl BlockingClient {
+    /// Create a new blocking client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        Ok(Self {
+            client: Client::new(config)?,
+        })
+    }
+
+    /// Create a new blocking client for requests to a specific API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn for_api(config: Config, api: Api) -> Result<Self> {
+        Ok(Self {
+            client: Client::for_api(config, api)?,
+        })
+    }
+
+    /// Get the async client.
+    #[must_use]
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub fn config(&self) -> &Config {
+        self.client.config()
+    }
+
+    /// Blocking variant of [`Client::execute`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub fn execute<T>(&self, request: Request) -> Result<ApiResponse<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        block_on(self.client.execute(request))
+    }
+
+    /// Blocking variant of [`Client::execute_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn execute_raw(&self, request: Request) -> Result<ApiResponse<Vec<u8>>> {
+        block_on(self.client.execute_raw(request))
+    }
+
+    /// Blocking variant of [`Client::post`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub fn post<T, R>(&self, url: &str, body: &T) -> Result<ApiResponse<R>>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        block_on(self.client.post(url, body))
+    }
+
+    /// Blocking variant of [`Client::get`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub fn get<R>(&self, url: &str) -> Result<ApiResponse<R>>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        block_on(self.client.get(url))
+    }
+
+    /// Blocking variant of [`Client::patch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub fn patch<T, R>(&self, url: &str, body: &T) -> Result<ApiResponse<R>>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        block_on(self.client.patch(url, body))
+    }
+
+    /// Blocking variant of [`Client::delete`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn delete(&self, url: &str) -> Result<()> {
+        block_on(self.client.delete(url))
+    }
+}
+
+impl From<Client> for BlockingClient {
+    fn from(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+/// Generate `_blocking` variants of async API methods.
+///
+/// Each entry names the blocking method, the async method it calls and its
+/// arguments. Used by the API crates behind their `blocking` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # struct PaymentsApi;
+/// # impl PaymentsApi {
+/// #     async fn authorise(&self, reference: &str) -> adyen_core::Result<String> {
+/// #         Ok(reference.to_string())
+/// #     }
+/// # }
+/// adyen_core::blocking_api! {
+///     impl PaymentsApi {
+///         fn authorise_blocking => authorise(reference: &str) -> adyen_core::Result<String>;
+///     }
+/// }
+///
+/// assert_eq!(PaymentsApi.authorise_blocking("Order-1").unwrap(), "Order-1");
+/// ```
+#[macro_export]
+macro_rules! blocking_api {
+    (impl $ty:ty { $( fn $name:ident => $async_name:ident ( $( $arg:ident : $arg_ty:ty ),* $(,)? ) -> $ret:ty ; )* }) => {
+        impl $ty {
+            $(
+                #[doc = concat!(
+                    "Blocking variant of [`", stringify!($async_name), "`](Self::", stringify!($async_name), ")."
+                )]
+                ///
+                /// # Panics
+                ///
+                /// Panics if called from within an async runtime.
+                pub fn $name(&self $(, $arg: $arg_ty )*) -> $ret {
+                    $crate::blocking::block_on(self.$async_name($( $arg ),*))
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{BoxFuture, HttpRequest, HttpResponse, HttpTransport, TransportError};
+    use crate::ConfigBuilder;
+
+    #[derive(Debug)]
+    struct StaticTransport;
+
+    impl HttpTransport for StaticTransport {
+        fn send(
+            &self,
+            _request: HttpRequest,
+        ) -> BoxFuture<'_, std::result::Result<HttpResponse, TransportError>> {
+            Box::pin(async {
+                // Timers need the library's runtime.
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                Ok(HttpResponse {
+                    status: 200,
+                    headers: crate::http::HeaderMap::new(),
+                    body: br#"{"resultCode":"Authorised"}"#.to_vec(),
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn test_blocking_client() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .transport(StaticTransport)
+            .build()
+            .unwrap();
+        let client = BlockingClient::new(config).unwrap();
+
+        let response: ApiResponse<serde_json::Value> =
+            client.get("https://example.com/payments").unwrap();
+        assert_eq!(response.data["resultCode"], "Authorised");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "within an async runtime")]
+    async fn test_block_on_panics_in_async_context() {
+        block_on(async {});
+    }
+}
@@ -0,0 +1,15 @@
+//! Type state marker for request builders.
+//!
+//! Request builders store their required fields in type parameters, one per
+//! field, which start as [`Missing`] and become the field's type once the
+//! field's setter is called. `build` is only available when every required
+//! field is set, so forgetting one is a compile error rather than a runtime
+//! failure.
+//!
+//! Builders are still plain values, so a builder whose required fields are
+//! set in different branches needs a type per branch; set required fields
+//! first and optional ones conditionally afterwards.
+
+/// Marker for a required builder field that has not been set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Missing;
@@ -1,18 +1,44 @@
 //! HTTP client implementation for Adyen APIs.
 
-use crate::{auth::Credentials, AdyenError, Config, Result};
-use reqwest::{header::HeaderMap, RequestBuilder, Response};
+use crate::runtime::Instant;
+use crate::{
+    auth::Credentials,
+    http::{
+        BoxFuture, Bytes, HeaderMap, HeaderName, HeaderValue, HttpRequest, HttpResponse,
+        HttpTransport, MaybeSend, StreamingHttpResponse, TransportError, TransportErrorKind,
+    },
+    observability::{self, RequestMetrics},
+    retry::RetryPolicy,
+    AdyenError, Api, Config, RequestId, Result,
+};
+use futures::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Name of the header Adyen uses to de-duplicate requests.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
 /// HTTP client for making requests to Adyen APIs.
 ///
 /// This client handles authentication, request/response serialization,
 /// error handling, retry logic, and provides a consistent interface for all Adyen APIs.
+///
+/// Cloning is cheap: clones share the configuration and the transport, and
+/// with it the connection pool. Pass clones to the `with_client`
+/// constructors of the API structs to use one pool for several APIs.
 #[derive(Debug, Clone)]
 pub struct Client {
+    inner: Arc<ClientInner>,
+    api: Option<Api>,
+}
+
+/// State shared by the clones of a [`Client`].
+#[derive(Debug)]
+struct ClientInner {
     config: Config,
-    http_client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
+    default_headers: HeaderMap,
 }
 
 /// Request configuration for API calls.
@@ -26,10 +52,107 @@ pub struct Request {
     pub body: Option<serde_json::Value>,
     /// Additional headers
     pub headers: HeaderMap,
-    /// Request timeout override
+    /// Request timeout override, applied to every attempt
     pub timeout: Option<Duration>,
+    /// Deadline for the request including all retries
+    pub deadline: Option<Instant>,
     /// Enable retry on failure
     pub retry: bool,
+    /// Retry policy override; falls back to the client configuration
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+impl Request {
+    /// Create a new request with retries enabled and no body.
+    #[must_use]
+    pub fn new(method: crate::http::Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            body: None,
+            headers: HeaderMap::new(),
+            timeout: None,
+            deadline: None,
+            retry: true,
+            retry_policy: None,
+        }
+    }
+
+    /// Set the JSON request body.
+    #[must_use]
+    pub fn with_body(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Add a header to the request.
+    #[must_use]
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Set the `Idempotency-Key` header so Adyen can safely de-duplicate
+    /// retries of this request.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: &RequestId) -> Self {
+        // Request IDs are validated to be visible ASCII, so this cannot fail.
+        if let Ok(value) = HeaderValue::from_str(key.as_str()) {
+            self.headers.insert(IDEMPOTENCY_KEY_HEADER, value);
+        }
+        self
+    }
+
+    /// Override the client timeout for this request.
+    ///
+    /// The timeout applies to each attempt; use
+    /// [`with_deadline`](Self::with_deadline) to bound the request including
+    /// retries.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail the request if it has not completed by `deadline`, including all
+    /// retries.
+    ///
+    /// Attempts are cut short when the deadline passes, and retries whose
+    /// backoff would end after the deadline are not made.
+    #[must_use]
+    pub const fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Override the client retry policy for this request.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Disable retries for this request.
+    #[must_use]
+    pub const fn without_retry(mut self) -> Self {
+        self.retry = false;
+        self
+    }
+
+    /// Check if the request carries an `Idempotency-Key` header.
+    #[must_use]
+    pub fn has_idempotency_key(&self) -> bool {
+        self.headers.contains_key(IDEMPOTENCY_KEY_HEADER)
+    }
+
+    /// Resolve the retry policy for this request against the client default.
+    fn effective_retry_policy(&self, default: RetryPolicy) -> RetryPolicy {
+        if self.retry {
+            self.retry_policy.unwrap_or(default)
+        } else {
+            RetryPolicy::none()
+        }
+    }
 }
 
 /// Response from an API call.
@@ -48,57 +171,127 @@ pub struct ApiResponse<T> {
 impl Client {
     /// Create a new client with the given configuration.
     ///
+    /// Requests are sent through the transport set with
+    /// [`ConfigBuilder::transport`](crate::ConfigBuilder::transport), or the
+    /// default reqwest transport otherwise, created with the configured proxy
+    /// and TLS settings.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the HTTP client cannot be created.
+    /// Returns an error if the HTTP client cannot be created, e.g. because of
+    /// an invalid certificate, or if no transport is configured and the crate
+    /// was built without the `reqwest` feature.
     pub fn new(config: Config) -> Result<Self> {
-        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(transport) = config.transport().cloned() {
+            return Self::from_parts(config, transport);
+        }
+
+        #[cfg(feature = "reqwest")]
+        {
+            let transport = crate::http::ReqwestTransport::from_config(&config)?;
+            Self::with_transport(config, transport)
+        }
+        #[cfg(not(feature = "reqwest"))]
+        {
+            Err(AdyenError::config(
+                "No default HTTP transport available; enable the `reqwest` feature or configure a transport",
+            ))
+        }
+    }
+
+    /// Create a new client that sends requests through a custom transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured default headers or user agent are
+    /// not valid header values.
+    pub fn with_transport(config: Config, transport: impl HttpTransport + 'static) -> Result<Self> {
+        Self::from_parts(config, Arc::new(transport))
+    }
+
+    fn from_parts(config: Config, transport: Arc<dyn HttpTransport>) -> Result<Self> {
+        let mut headers = HeaderMap::new();
 
         // Add default headers
         for (name, value) in config.default_headers() {
-            let header_name =
-                reqwest::header::HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
-                    crate::AdyenError::config(format!("Invalid header name '{name}': {e}"))
-                })?;
-            let header_value = reqwest::header::HeaderValue::from_str(value).map_err(|e| {
-                crate::AdyenError::config(format!("Invalid header value '{value}': {e}"))
-            })?;
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| AdyenError::config(format!("Invalid header name '{name}': {e}")))?;
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|e| AdyenError::config(format!("Invalid header value '{value}': {e}")))?;
             headers.insert(header_name, header_value);
         }
 
         // Add User-Agent
         headers.insert(
-            reqwest::header::USER_AGENT,
-            reqwest::header::HeaderValue::from_str(config.user_agent())
-                .map_err(|e| crate::AdyenError::config(format!("Invalid user agent: {e}")))?,
+            ::http::header::USER_AGENT,
+            HeaderValue::from_str(config.user_agent())
+                .map_err(|e| AdyenError::config(format!("Invalid user agent: {e}")))?,
         );
 
         // Add Content-Type
         headers.insert(
-            reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            ::http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
         );
         headers.insert(
-            reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            ::http::header::ACCEPT,
+            HeaderValue::from_static("application/json"),
         );
 
-        // Build HTTP client
-        let http_client = reqwest::ClientBuilder::new()
-            .timeout(config.timeout())
-            .default_headers(headers)
-            .https_only(true)
-            .build()
-            .map_err(|e| crate::AdyenError::config(format!("Failed to create HTTP client: {e}")))?;
+        let transport = crate::middleware::wrap(transport, config.layers());
 
         Ok(Self {
-            config,
-            http_client,
+            inner: Arc::new(ClientInner {
+                config,
+                transport,
+                default_headers: headers,
+            }),
+            api: None,
         })
     }
 
+    /// Create a new client for the given API.
+    ///
+    /// Requests use the timeout configured for the API with
+    /// [`ConfigBuilder::api_timeout`](crate::ConfigBuilder::api_timeout),
+    /// falling back to the default timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be created, see
+    /// [`new`](Self::new).
+    pub fn for_api(config: Config, api: Api) -> Result<Self> {
+        let mut client = Self::new(config)?;
+        client.api = Some(api);
+        Ok(client)
+    }
+
+    /// Scope this client to the given API, keeping its transport.
+    ///
+    /// Unlike [`for_api`](Self::for_api), no new HTTP client is created, so
+    /// clones scoped to different APIs send requests through one connection
+    /// pool.
+    #[must_use]
+    pub fn with_api(mut self, api: Api) -> Self {
+        self.api = Some(api);
+        self
+    }
+
     /// Execute a request with automatic retry and error handling.
     ///
+    /// Transient failures (HTTP 429, 5xx and connection errors) are retried
+    /// according to the request's retry policy, or the client's policy if the
+    /// request does not override it. POST and PATCH requests are only retried
+    /// after a response was received if they carry an `Idempotency-Key` header.
+    /// A `Retry-After` header on the failed response overrides the backoff
+    /// delay. With a [rate limit](crate::ConfigBuilder::rate_limit) configured,
+    /// every attempt waits for the limiter first.
+    ///
+    /// Each attempt uses the request's timeout, or the client's
+    /// [default timeout](Self::default_timeout), and is cut short at the
+    /// request's deadline. Dropping the returned future cancels the request,
+    /// including any pending retry.
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails after all retry attempts.
@@ -106,37 +299,85 @@ impl Client {
     where
         T: for<'de> Deserialize<'de>,
     {
-        let max_retries = if request.retry { 3 } else { 1 };
-        let mut last_error = None;
+        let response = self.execute_raw(request).await?;
 
-        for attempt in 0..max_retries {
-            match self.try_request(&request).await {
-                Ok(response) => {
-                    return self.handle_response::<T>(response).await;
-                }
-                Err(e) => {
-                    last_error = Some(e);
-
-                    if attempt < max_retries - 1 {
-                        // Exponential backoff: 100ms, 200ms, 400ms
-                        let delay = Duration::from_millis(100 * (1 << attempt));
-                        tokio::time::sleep(delay).await;
-
-                        if self.config.is_logging_enabled() {
-                            #[cfg(feature = "tracing")]
-                            tracing::warn!(
-                                "Request failed, retrying in {:?} (attempt {})",
-                                delay,
-                                attempt + 1
-                            );
-                        }
-                    }
-                }
-            }
+        // Parse successful response
+        let data: T = parse_json(&response.data)?;
+
+        Ok(ApiResponse {
+            data,
+            status: response.status,
+            headers: response.headers,
+            psp_reference: response.psp_reference,
+        })
+    }
+
+    /// Execute a request like [`execute`](Self::execute), but return the raw
+    /// response body instead of parsing it as JSON.
+    ///
+    /// Use this for endpoints that answer successful requests with a
+    /// non-JSON body. Error responses are still turned into
+    /// [`AdyenError::Api`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retry attempts.
+    pub async fn execute_raw(&self, mut request: Request) -> Result<ApiResponse<Vec<u8>>> {
+        if self.inner.config.is_auto_idempotency_key_enabled()
+            && request.method == crate::http::Method::Post
+            && !request.has_idempotency_key()
+        {
+            // Generated once so every retry of this request reuses the same key.
+            request = request.with_idempotency_key(&RequestId::new());
+        }
+
+        let response = self
+            .send_with_retry(&request, |http_request| {
+                self.inner.transport.send(http_request)
+            })
+            .await?;
+        self.handle_response(response)
+    }
+
+    /// Execute a request like [`execute_raw`](Self::execute_raw), but stream
+    /// the response body instead of reading it into memory.
+    ///
+    /// Retries only cover failures before the response arrives; errors while
+    /// reading the body are returned by the stream. The request timeout also
+    /// covers reading the body, so raise it with
+    /// [`Request::with_timeout`] for large downloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retry attempts, or
+    /// [`AdyenError::Api`] for error responses.
+    pub async fn execute_stream(
+        &self,
+        request: Request,
+    ) -> Result<ApiResponse<impl Stream<Item = Result<Bytes>> + MaybeSend + Unpin>> {
+        let response = self
+            .send_with_retry(&request, |http_request| {
+                self.inner.transport.send_streaming(http_request)
+            })
+            .await?;
+
+        if response.status >= 400 {
+            // Error bodies are small JSON documents, so they can be buffered.
+            let response = response.buffer().await?;
+            return Err(self.parse_api_error(
+                &String::from_utf8_lossy(&response.body),
+                response.status,
+                psp_reference(&response.headers),
+            ));
         }
 
-        Err(last_error
-            .unwrap_or_else(|| AdyenError::generic("Request failed with no error details")))
+        let psp_reference = psp_reference(&response.headers);
+        Ok(ApiResponse {
+            data: response.body.map_err(AdyenError::from),
+            status: response.status,
+            headers: response.headers,
+            psp_reference,
+        })
     }
 
     /// Send a POST request with JSON body.
@@ -149,14 +390,65 @@ impl Client {
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        let request = Request {
-            method: crate::http::Method::Post,
-            url: url.to_string(),
-            body: Some(serde_json::to_value(body)?),
-            headers: HeaderMap::new(),
-            timeout: None,
-            retry: true,
-        };
+        let request =
+            Request::new(crate::http::Method::Post, url).with_body(serde_json::to_value(body)?);
+
+        self.execute(request).await
+    }
+
+    /// Send a payment-like POST request, adding the configured
+    /// [application information](crate::ConfigBuilder::application_info) to
+    /// the body, with an optional `Idempotency-Key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or response cannot be parsed.
+    pub async fn post_payment<T, R>(
+        &self,
+        url: &str,
+        body: &T,
+        idempotency_key: Option<&RequestId>,
+    ) -> Result<ApiResponse<R>>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        let mut body = serde_json::to_value(body)?;
+        if let Some(application_info) = self.inner.config.application_info() {
+            application_info.merge_into(&mut body)?;
+        }
+        let mut request = Request::new(crate::http::Method::Post, url).with_body(body);
+        if let Some(key) = idempotency_key {
+            request = request.with_idempotency_key(key);
+        }
+
+        self.execute(request).await
+    }
+
+    /// Send a POST request with JSON body and an optional `Idempotency-Key`.
+    ///
+    /// With a key set, Adyen returns the original response for repeated
+    /// requests instead of processing them again, which also makes the
+    /// request eligible for automatic retries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or response cannot be parsed.
+    pub async fn post_idempotent<T, R>(
+        &self,
+        url: &str,
+        body: &T,
+        idempotency_key: Option<&RequestId>,
+    ) -> Result<ApiResponse<R>>
+    where
+        T: Serialize,
+        R: for<'de> Deserialize<'de>,
+    {
+        let mut request =
+            Request::new(crate::http::Method::Post, url).with_body(serde_json::to_value(body)?);
+        if let Some(key) = idempotency_key {
+            request = request.with_idempotency_key(key);
+        }
 
         self.execute(request).await
     }
@@ -170,18 +462,30 @@ impl Client {
     where
         R: for<'de> Deserialize<'de>,
     {
-        let request = Request {
-            method: crate::http::Method::Get,
-            url: url.to_string(),
-            body: None,
-            headers: HeaderMap::new(),
-            timeout: None,
-            retry: true,
-        };
+        let request = Request::new(crate::http::Method::Get, url);
 
         self.execute(request).await
     }
 
+    /// Send a GET request and stream the response body.
+    ///
+    /// Use this for large downloads such as reports, which would otherwise be
+    /// held in memory in full. See [`execute_stream`](Self::execute_stream).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or Adyen returns an error
+    /// response. Failures while reading the body are returned by the stream.
+    pub async fn get_stream(
+        &self,
+        url: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + MaybeSend + Unpin> {
+        let request = Request::new(crate::http::Method::Get, url)
+            .with_header(::http::header::ACCEPT, HeaderValue::from_static("*/*"));
+
+        Ok(self.execute_stream(request).await?.data)
+    }
+
     /// Send a PATCH request.
     ///
     /// # Errors
@@ -192,116 +496,329 @@ impl Client {
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        let request = Request {
-            method: crate::http::Method::Patch,
-            url: url.to_string(),
-            body: Some(serde_json::to_value(body)?),
-            headers: HeaderMap::new(),
-            timeout: None,
-            retry: true,
-        };
+        let request =
+            Request::new(crate::http::Method::Patch, url).with_body(serde_json::to_value(body)?);
 
         self.execute(request).await
     }
 
     /// Send a DELETE request.
     ///
+    /// The response body is ignored, so `204 No Content` responses succeed.
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails.
     pub async fn delete(&self, url: &str) -> Result<()> {
-        let request = Request {
-            method: crate::http::Method::Delete,
-            url: url.to_string(),
-            body: None,
-            headers: HeaderMap::new(),
-            timeout: None,
-            retry: false, // Don't retry delete operations
-        };
+        // Don't retry delete operations
+        let request = Request::new(crate::http::Method::Delete, url).without_retry();
 
-        let _response: ApiResponse<serde_json::Value> = self.execute(request).await?;
+        self.execute_raw(request).await?;
         Ok(())
     }
 
     /// Get the client configuration.
     #[must_use]
-    pub const fn config(&self) -> &Config {
-        &self.config
+    pub fn config(&self) -> &Config {
+        &self.inner.config
     }
 
-    /// Get the underlying HTTP client.
+    /// Get the API this client was created for, if any.
     #[must_use]
-    pub const fn http_client(&self) -> &reqwest::Client {
-        &self.http_client
-    }
-
-    /// Try to execute a single request attempt.
-    async fn try_request(&self, request: &Request) -> Result<Response> {
-        let mut req_builder = match request.method {
-            crate::http::Method::Get => self.http_client.get(&request.url),
-            crate::http::Method::Post => self.http_client.post(&request.url),
-            crate::http::Method::Put => self.http_client.put(&request.url),
-            crate::http::Method::Patch => self.http_client.patch(&request.url),
-            crate::http::Method::Delete => self.http_client.delete(&request.url),
-        };
+    pub const fn api(&self) -> Option<Api> {
+        self.api
+    }
+
+    /// Get the timeout applied to requests without their own timeout.
+    #[must_use]
+    pub fn default_timeout(&self) -> Duration {
+        self.api.map_or_else(
+            || self.inner.config.timeout(),
+            |api| self.inner.config.timeout_for(api),
+        )
+    }
+
+    /// Get the transport used to send requests, wrapped in the configured
+    /// middleware.
+    #[must_use]
+    pub fn transport(&self) -> &dyn HttpTransport {
+        self.inner.transport.as_ref()
+    }
+
+    /// Send a request, retrying transient failures according to its retry
+    /// policy.
+    ///
+    /// The last response is returned once it is not retried, whatever its
+    /// status.
+    async fn send_with_retry<'a, R, F>(&'a self, request: &Request, send: F) -> Result<R>
+    where
+        R: TransportResponse,
+        F: Fn(HttpRequest) -> BoxFuture<'a, std::result::Result<R, TransportError>>,
+    {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            let span = self.request_span(request);
+            self.send_attempts(request, send).instrument(span).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            self.send_attempts(request, send).await
+        }
+    }
+
+    async fn send_attempts<'a, R, F>(&'a self, request: &Request, send: F) -> Result<R>
+    where
+        R: TransportResponse,
+        F: Fn(HttpRequest) -> BoxFuture<'a, std::result::Result<R, TransportError>>,
+    {
+        self.log_request(request);
+
+        let policy = request.effective_retry_policy(*self.inner.config.retry_policy());
+        let idempotency_key = request.has_idempotency_key();
+        let mut attempt = 0;
+
+        loop {
+            if let Some(limiter) = self.inner.config.rate_limiter() {
+                limiter.acquire().await;
+            }
+
+            let result = match self.prepare_request(request).await {
+                Ok(http_request) => {
+                    let started = Instant::now();
+                    let result = send(http_request).await.map_err(AdyenError::from);
+                    self.record_attempt(request, result.as_ref().ok(), started.elapsed(), attempt);
+                    result
+                }
+                Err(e) => Err(e),
+            };
+
+            // Only retry if the backoff ends before the request's deadline.
+            let in_time = |delay: Duration| {
+                request
+                    .deadline
+                    .is_none_or(|deadline| Instant::now() + delay < deadline)
+            };
+
+            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+            let (reason, delay) = match result {
+                Ok(response) => {
+                    let status = response.status();
+                    if status == 401 {
+                        // A revoked access token must not be reused for the next request.
+                        if let Credentials::OAuth(oauth) = self.credentials() {
+                            oauth.invalidate().await;
+                        }
+                    }
+                    let mut retry_after = None;
+                    if RetryPolicy::is_retryable_status(status) {
+                        retry_after = crate::rate_limit::retry_after(response.headers());
+                        // Hold back every request sharing the limiter, not just this one.
+                        if let (Some(limiter), Some(wait)) =
+                            (self.inner.config.rate_limiter(), retry_after)
+                        {
+                            limiter.pause_for(wait);
+                        }
+                    }
+                    let delay = retry_after.unwrap_or_else(|| policy.delay(attempt));
+                    if !policy.should_retry_status(request.method, idempotency_key, status, attempt)
+                        || !in_time(delay)
+                    {
+                        return Ok(response);
+                    }
+                    (format!("HTTP {status}"), delay)
+                }
+                Err(e) => {
+                    let (transient, connect_error) = match &e {
+                        AdyenError::Http(err) => (err.is_transient(), err.is_connect()),
+                        _ => (false, false),
+                    };
+                    let delay = policy.delay(attempt);
+                    if !transient
+                        || !policy.should_retry_error(
+                            request.method,
+                            idempotency_key,
+                            connect_error,
+                            attempt,
+                        )
+                        || !in_time(delay)
+                    {
+                        return Err(e);
+                    }
+                    (e.to_string(), delay)
+                }
+            };
+
+            if self.inner.config.is_logging_enabled() {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    "Request failed ({}), retrying in {:?} (attempt {})",
+                    reason,
+                    delay,
+                    attempt + 1
+                );
+            }
+            crate::runtime::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Span covering all attempts of a request.
+    #[cfg(feature = "tracing")]
+    fn request_span(&self, request: &Request) -> tracing::Span {
+        let merchant_account = request
+            .body
+            .as_ref()
+            .and_then(|body| body.get("merchantAccount"))
+            .and_then(serde_json::Value::as_str);
+        tracing::info_span!(
+            "adyen.request",
+            api = %observability::api_label(self.api),
+            method = %request.method,
+            endpoint = %observability::endpoint_label(&request.url),
+            merchant_account,
+            psp_reference = tracing::field::Empty,
+            status = tracing::field::Empty,
+        )
+    }
+
+    /// Report the outcome of an attempt to the span and the metrics recorder.
+    fn record_attempt<R: TransportResponse>(
+        &self,
+        request: &Request,
+        response: Option<&R>,
+        latency: Duration,
+        attempt: u32,
+    ) {
+        #[cfg(feature = "tracing")]
+        if let Some(response) = response {
+            let span = tracing::Span::current();
+            span.record("status", response.status());
+            if let Some(psp_reference) = psp_reference(response.headers()) {
+                span.record("psp_reference", psp_reference.as_str());
+            }
+        }
+
+        if let Some(recorder) = self.inner.config.metrics_recorder() {
+            recorder.record(&RequestMetrics {
+                api: self.api,
+                method: request.method,
+                endpoint: observability::endpoint_label(&request.url),
+                status: response.map(TransportResponse::status),
+                latency,
+                attempt,
+            });
+        }
+    }
+
+    /// Log an outgoing request with sensitive body fields redacted.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn log_request(&self, request: &Request) {
+        if self.inner.config.is_logging_enabled() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                "Request {} {}, body: {}",
+                request.method,
+                request.url,
+                request.body.as_ref().map_or_else(String::new, |body| {
+                    self.inner.config.redactor().redact(body)
+                })
+            );
+        }
+    }
+
+    /// Build the HTTP request for a single attempt.
+    async fn prepare_request(&self, request: &Request) -> Result<HttpRequest> {
+        let mut headers = self.inner.default_headers.clone();
+
+        // Cut the attempt short at the deadline.
+        let mut timeout = request.timeout.unwrap_or_else(|| self.default_timeout());
+        if let Some(deadline) = request.deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(TransportError::new(
+                    TransportErrorKind::Timeout,
+                    "request deadline exceeded",
+                )
+                .into());
+            }
+            timeout = timeout.min(remaining);
+        }
 
         // Add authentication
-        req_builder = self.add_authentication(req_builder);
+        self.add_authentication(&mut headers, timeout).await?;
 
         // Add custom headers
         for (name, value) in &request.headers {
-            req_builder = req_builder.header(name, value);
+            headers.insert(name, value.clone());
         }
 
-        // Add body if present
-        if let Some(body) = &request.body {
-            req_builder = req_builder.json(body);
-        }
+        // Serialize body if present
+        let body = request.body.as_ref().map(serde_json::to_vec).transpose()?;
 
-        // Set timeout if specified
-        if let Some(timeout) = request.timeout {
-            req_builder = req_builder.timeout(timeout);
-        }
+        Ok(HttpRequest {
+            method: request.method,
+            url: request.url.clone(),
+            headers,
+            body,
+            timeout,
+        })
+    }
 
-        // Execute request
-        let response = req_builder.send().await?;
-        Ok(response)
+    /// Credentials of the API this client is scoped to.
+    fn credentials(&self) -> &Credentials {
+        let config = &self.inner.config;
+        self.api
+            .map_or_else(|| config.credentials(), |api| config.credentials_for(api))
     }
 
     /// Add authentication headers to the request.
-    fn add_authentication(&self, mut req_builder: RequestBuilder) -> RequestBuilder {
-        match self.config.credentials() {
-            Credentials::ApiKey(api_key) => {
-                req_builder = req_builder.header("X-API-Key", api_key.as_str());
-            }
-            Credentials::Basic(basic_auth) => {
-                req_builder =
-                    req_builder.header("Authorization", basic_auth.authorization_header());
+    ///
+    /// With OAuth credentials this requests a new access token if the cached
+    /// one is missing or about to expire, within `timeout`.
+    async fn add_authentication(&self, headers: &mut HeaderMap, timeout: Duration) -> Result<()> {
+        let config = &self.inner.config;
+        let (name, value) = match self.credentials() {
+            Credentials::ApiKey(api_key) => ("X-API-Key", api_key.as_str().to_string()),
+            Credentials::Basic(basic_auth) => ("Authorization", basic_auth.authorization_header()),
+            Credentials::OAuth(oauth) => {
+                let token = oauth
+                    .access_token(
+                        self.transport(),
+                        &config.environment().oauth_token_url(),
+                        timeout,
+                    )
+                    .await?;
+                ("Authorization", format!("Bearer {}", token.expose_secret()))
             }
-        }
-        req_builder
+        };
+        let mut value = HeaderValue::from_str(&value)
+            .map_err(|e| AdyenError::auth(format!("Invalid credentials header: {e}")))?;
+        value.set_sensitive(true);
+        headers.insert(name, value);
+        Ok(())
     }
 
     /// Handle the HTTP response and convert to `ApiResponse`.
-    async fn handle_response<T>(&self, response: Response) -> Result<ApiResponse<T>>
-    where
-        T: for<'de> Deserialize<'de>,
-    {
-        let status = response.status().as_u16();
-        let headers = response.headers().clone();
+    fn handle_response(&self, response: HttpResponse) -> Result<ApiResponse<Vec<u8>>> {
+        let HttpResponse {
+            status,
+            headers,
+            body,
+        } = response;
 
-        // Extract PSP reference from headers
-        let psp_reference = headers
-            .get("psp-reference")
-            .and_then(|v| v.to_str().ok())
-            .map(std::string::ToString::to_string);
+        let psp_reference = psp_reference(&headers);
 
-        let response_text = response.text().await?;
+        let response_text = String::from_utf8_lossy(&body);
 
-        // Log response if enabled
-        if self.config.is_logging_enabled() {
+        // Log response if enabled, never with card data or credentials.
+        if self.inner.config.is_logging_enabled() {
             #[cfg(feature = "tracing")]
-            tracing::debug!("Response status: {}, body: {}", status, response_text);
+            tracing::debug!(
+                "Response status: {}, body: {}",
+                status,
+                self.inner.config.redactor().redact_str(&response_text)
+            );
         }
 
         // Handle error responses
@@ -310,16 +827,8 @@ impl Client {
             return Err(api_error);
         }
 
-        // Parse successful response
-        let data: T = serde_json::from_str(&response_text).map_err(|e| {
-            AdyenError::generic_with_source(
-                format!("Failed to parse response: {response_text}"),
-                Box::new(e),
-            )
-        })?;
-
         Ok(ApiResponse {
-            data,
+            data: body,
             status,
             headers,
             psp_reference,
@@ -373,6 +882,65 @@ impl Client {
     }
 }
 
+/// Extract the PSP reference Adyen returns in the response headers.
+fn psp_reference(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("psp-reference")
+        .and_then(|v| v.to_str().ok())
+        .map(std::string::ToString::to_string)
+}
+
+/// Parse a JSON response body.
+///
+/// With the `simd-json` feature, the body is parsed by simd-json, which
+/// works on its own copy of the body because it rewrites its input.
+fn parse_json<T>(body: &[u8]) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    #[cfg(feature = "simd-json")]
+    let parsed = simd_json::serde::from_slice(&mut body.to_vec());
+    #[cfg(not(feature = "simd-json"))]
+    let parsed = serde_json::from_slice(body);
+
+    parsed.map_err(|e| {
+        AdyenError::generic_with_source(
+            format!(
+                "Failed to parse response: {}",
+                String::from_utf8_lossy(body)
+            ),
+            Box::new(e),
+        )
+    })
+}
+
+/// Transport responses whose status and headers decide whether a request is
+/// retried.
+trait TransportResponse {
+    fn status(&self) -> u16;
+    fn headers(&self) -> &HeaderMap;
+}
+
+impl TransportResponse for HttpResponse {
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
+impl TransportResponse for StreamingHttpResponse {
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,9 +965,11 @@ mod tests {
             method: crate::http::Method::Post,
             url: "https://checkout-test.adyen.com/v71/payments".to_string(),
             body: Some(serde_json::json!({"amount": {"value": 1000, "currency": "EUR"}})),
-            headers: reqwest::header::HeaderMap::new(),
+            headers: HeaderMap::new(),
             timeout: Some(std::time::Duration::from_secs(30)),
+            deadline: None,
             retry: true,
+            retry_policy: None,
         };
 
         assert_eq!(request.url, "https://checkout-test.adyen.com/v71/payments");
@@ -407,12 +977,536 @@ mod tests {
         assert!(request.body.is_some());
     }
 
+    #[test]
+    fn test_request_retry_policy_override() {
+        let default = RetryPolicy::new();
+        let request = Request::new(crate::http::Method::Get, "https://example.com");
+        assert_eq!(request.effective_retry_policy(default), default);
+
+        let custom = RetryPolicy::new().max_attempts(7);
+        let request = request.with_retry_policy(custom);
+        assert_eq!(request.effective_retry_policy(default), custom);
+
+        let request = request.without_retry();
+        assert!(!request.effective_retry_policy(default).is_enabled());
+    }
+
+    #[test]
+    fn test_request_idempotency_key_detection() {
+        let request = Request::new(crate::http::Method::Post, "https://example.com");
+        assert!(!request.has_idempotency_key());
+
+        let key = RequestId::from_string("key-1").unwrap();
+        let request = request.with_idempotency_key(&key);
+        assert!(request.has_idempotency_key());
+        assert_eq!(
+            request.headers.get(IDEMPOTENCY_KEY_HEADER).unwrap(),
+            "key-1"
+        );
+    }
+
+    /// Transport returning a fixed sequence of status codes.
+    #[derive(Debug)]
+    struct ScriptedTransport {
+        statuses: std::sync::Mutex<Vec<u16>>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ScriptedTransport {
+        fn new(statuses: &[u16]) -> Arc<Self> {
+            Arc::new(Self {
+                statuses: std::sync::Mutex::new(statuses.iter().rev().copied().collect()),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    impl HttpTransport for Arc<ScriptedTransport> {
+        fn send(
+            &self,
+            request: HttpRequest,
+        ) -> crate::http::BoxFuture<
+            '_,
+            std::result::Result<HttpResponse, crate::http::TransportError>,
+        > {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let status = self.statuses.lock().unwrap().pop().unwrap_or(200);
+            assert!(request.headers.contains_key("X-API-Key"));
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status,
+                    headers: HeaderMap::new(),
+                    body: b"{}".to_vec(),
+                })
+            })
+        }
+    }
+
+    fn scripted_client(transport: &Arc<ScriptedTransport>) -> Client {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .retry_policy(
+                RetryPolicy::new().backoff(crate::retry::Backoff::constant(Duration::ZERO)),
+            )
+            .build()
+            .unwrap();
+        Client::with_transport(config, Arc::clone(transport)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_retries_idempotent_requests() {
+        let transport = ScriptedTransport::new(&[503, 429, 200]);
+        let client = scripted_client(&transport);
+
+        let response: ApiResponse<serde_json::Value> =
+            client.get("https://example.com").await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(transport.calls(), 3);
+    }
+
+    #[derive(Debug, Default)]
+    struct CollectingRecorder(std::sync::Mutex<Vec<RequestMetrics>>);
+
+    impl crate::observability::MetricsRecorder for Arc<CollectingRecorder> {
+        fn record(&self, metrics: &RequestMetrics) {
+            self.0.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_recorded_per_attempt() {
+        let recorder = Arc::new(CollectingRecorder::default());
+        let transport = ScriptedTransport::new(&[503, 200]);
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .retry_policy(
+                RetryPolicy::new().backoff(crate::retry::Backoff::constant(Duration::ZERO)),
+            )
+            .metrics_recorder(Arc::clone(&recorder))
+            .transport(Arc::clone(&transport))
+            .build()
+            .unwrap();
+        let client = Client::for_api(config, Api::Checkout).unwrap();
+
+        let _: ApiResponse<serde_json::Value> = client
+            .get("https://example.com/v71/payments/8815329842815468")
+            .await
+            .unwrap();
+
+        let attempts = recorder.0.lock().unwrap();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].status, Some(503));
+        assert_eq!(attempts[0].attempt, 0);
+        assert_eq!(attempts[1].status, Some(200));
+        assert_eq!(attempts[1].attempt, 1);
+        assert_eq!(attempts[1].api, Some(Api::Checkout));
+        assert_eq!(attempts[1].endpoint, "/v71/payments/{id}");
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingMiddleware(std::sync::atomic::AtomicUsize);
+
+    impl crate::middleware::Middleware for Arc<CountingMiddleware> {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: crate::middleware::Next<'a>,
+        ) -> BoxFuture<'a, std::result::Result<HttpResponse, TransportError>> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            next.run(request)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_pass_through_middleware() {
+        let middleware = Arc::new(CountingMiddleware::default());
+        let transport = ScriptedTransport::new(&[503, 200]);
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .retry_policy(
+                RetryPolicy::new().backoff(crate::retry::Backoff::constant(Duration::ZERO)),
+            )
+            .build()
+            .unwrap()
+            .with_layer(Arc::clone(&middleware));
+        let client = Client::with_transport(config, Arc::clone(&transport)).unwrap();
+
+        let _: ApiResponse<serde_json::Value> = client.get("https://example.com").await.unwrap();
+        assert_eq!(middleware.0.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(transport.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_post_without_idempotency_key_not_retried() {
+        let transport = ScriptedTransport::new(&[503, 503, 200]);
+        let client = scripted_client(&transport);
+
+        let result: Result<ApiResponse<serde_json::Value>> = client
+            .post("https://example.com", &serde_json::json!({}))
+            .await;
+        assert_eq!(result.unwrap_err().status_code(), Some(503));
+        assert_eq!(transport.calls(), 1);
+
+        let key = RequestId::new();
+        let response: ApiResponse<serde_json::Value> = client
+            .post_idempotent("https://example.com", &serde_json::json!({}), Some(&key))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(transport.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_execute_raw_returns_unparsed_body() {
+        let transport = ScriptedTransport::new(&[200, 422]);
+        let client = scripted_client(&transport);
+
+        let request = Request::new(crate::http::Method::Post, "https://example.com");
+        let response = client.execute_raw(request.clone()).await.unwrap();
+        assert_eq!(response.data, b"{}");
+
+        let result = client.execute_raw(request).await;
+        assert_eq!(result.unwrap_err().status_code(), Some(422));
+    }
+
+    /// Transport acting as both the OAuth token endpoint and an API.
+    #[derive(Debug, Default)]
+    struct OAuthTransport {
+        tokens_issued: std::sync::atomic::AtomicUsize,
+        api_authorizations: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl HttpTransport for Arc<OAuthTransport> {
+        fn send(
+            &self,
+            request: HttpRequest,
+        ) -> crate::http::BoxFuture<
+            '_,
+            std::result::Result<HttpResponse, crate::http::TransportError>,
+        > {
+            let authorization = request.headers[::http::header::AUTHORIZATION]
+                .to_str()
+                .unwrap()
+                .to_string();
+            let (status, body) = if request.url == "https://oauth-test.adyen.com/v1/token" {
+                let body = String::from_utf8(request.body.unwrap()).unwrap();
+                let n = self
+                    .tokens_issued
+                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+                    + 1;
+                assert!(authorization.starts_with("Basic "));
+                assert!(body.contains(&format!("refresh_token=refresh_{n}")));
+                (
+                    200,
+                    format!(
+                        r#"{{"access_token":"token_{n}","token_type":"bearer","expires_in":3600,"refresh_token":"refresh_{}"}}"#,
+                        n + 1
+                    ),
+                )
+            } else {
+                let mut authorizations = self.api_authorizations.lock().unwrap();
+                authorizations.push(authorization);
+                let status = if authorizations.len() == 2 { 401 } else { 200 };
+                (status, "{}".to_string())
+            };
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status,
+                    headers: HeaderMap::new(),
+                    body: body.into_bytes(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oauth_token_cached_refreshed_and_rotated() {
+        let transport = Arc::new(OAuthTransport::default());
+        let oauth =
+            crate::oauth::OAuthCredentials::from_refresh_token("client", "secret", "refresh_1")
+                .unwrap();
+        let config = ConfigBuilder::new()
+            .oauth(oauth)
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        let client = Client::with_transport(config, Arc::clone(&transport)).unwrap();
+        let request = Request::new(crate::http::Method::Get, "https://example.com");
+
+        // The first token is cached, the second request is rejected, and the
+        // rotated refresh token is used for the next one.
+        client.execute_raw(request.clone()).await.unwrap();
+        assert!(client.execute_raw(request.clone()).await.is_err());
+        client.execute_raw(request).await.unwrap();
+
+        assert_eq!(
+            transport
+                .tokens_issued
+                .load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+        assert_eq!(
+            *transport.api_authorizations.lock().unwrap(),
+            ["Bearer token_1", "Bearer token_1", "Bearer token_2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oauth_token_refreshed_before_expiry() {
+        let transport = Arc::new(OAuthTransport::default());
+        let oauth =
+            crate::oauth::OAuthCredentials::from_refresh_token("client", "secret", "refresh_1")
+                .unwrap()
+                .with_access_token("token_0", Duration::from_secs(30));
+        let config = ConfigBuilder::new().oauth(oauth).build().unwrap();
+        let client = Client::with_transport(config, Arc::clone(&transport)).unwrap();
+
+        let request = Request::new(crate::http::Method::Get, "https://example.com");
+        client.execute_raw(request).await.unwrap();
+        assert_eq!(
+            *transport.api_authorizations.lock().unwrap(),
+            ["Bearer token_1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deadline_stops_retries() {
+        let transport = ScriptedTransport::new(&[503, 503, 200]);
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .retry_policy(
+                RetryPolicy::new()
+                    .backoff(crate::retry::Backoff::constant(Duration::from_secs(3600))),
+            )
+            .build()
+            .unwrap();
+        let client = Client::with_transport(config, Arc::clone(&transport)).unwrap();
+
+        // The backoff would end after the deadline, so the 503 is returned.
+        let request = Request::new(crate::http::Method::Get, "https://example.com")
+            .with_deadline(Instant::now() + Duration::from_secs(1));
+        let result = client.execute_raw(request).await;
+        assert_eq!(result.unwrap_err().status_code(), Some(503));
+        assert_eq!(transport.calls(), 1);
+
+        // Requests past their deadline are not sent.
+        let request = Request::new(crate::http::Method::Get, "https://example.com")
+            .with_deadline(Instant::now());
+        let error = client.execute_raw(request).await.unwrap_err();
+        assert!(matches!(error, AdyenError::Http(ref e) if e.is_timeout()));
+        assert_eq!(transport.calls(), 1);
+    }
+
+    /// Transport recording the timeout of every request.
+    #[derive(Debug, Default)]
+    struct TimeoutTransport {
+        timeouts: std::sync::Mutex<Vec<Duration>>,
+    }
+
+    impl HttpTransport for Arc<TimeoutTransport> {
+        fn send(
+            &self,
+            request: HttpRequest,
+        ) -> crate::http::BoxFuture<
+            '_,
+            std::result::Result<HttpResponse, crate::http::TransportError>,
+        > {
+            self.timeouts.lock().unwrap().push(request.timeout);
+            Box::pin(async {
+                Ok(HttpResponse {
+                    status: 200,
+                    headers: HeaderMap::new(),
+                    body: b"{}".to_vec(),
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn test_with_api_shares_transport() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .api_timeout(Api::LegalEntity, Duration::from_secs(120))
+            .transport(Arc::new(TimeoutTransport::default()))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        let legal_entity = client.clone().with_api(Api::LegalEntity);
+
+        assert_eq!(legal_entity.api(), Some(Api::LegalEntity));
+        assert_eq!(legal_entity.default_timeout(), Duration::from_secs(120));
+        assert!(client.api().is_none());
+        assert!(Arc::ptr_eq(&client.inner, &legal_entity.inner));
+    }
+
+    #[tokio::test]
+    async fn test_api_and_request_timeouts() {
+        let transport = Arc::new(TimeoutTransport::default());
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .timeout(Duration::from_secs(10))
+            .api_timeout(Api::LegalEntity, Duration::from_secs(120))
+            .transport(Arc::clone(&transport))
+            .build()
+            .unwrap();
+        let default_client = Client::new(config.clone()).unwrap();
+        let legal_entity = Client::for_api(config, Api::LegalEntity).unwrap();
+        assert_eq!(legal_entity.api(), Some(Api::LegalEntity));
+
+        let request = Request::new(crate::http::Method::Get, "https://example.com");
+        default_client.execute_raw(request.clone()).await.unwrap();
+        legal_entity.execute_raw(request.clone()).await.unwrap();
+        legal_entity
+            .execute_raw(request.clone().with_timeout(Duration::from_secs(5)))
+            .await
+            .unwrap();
+        legal_entity
+            .execute_raw(request.with_deadline(Instant::now() + Duration::from_secs(2)))
+            .await
+            .unwrap();
+
+        let timeouts = transport.timeouts.lock().unwrap().clone();
+        assert_eq!(timeouts[0], Duration::from_secs(10));
+        assert_eq!(timeouts[1], Duration::from_secs(120));
+        assert_eq!(timeouts[2], Duration::from_secs(5));
+        assert!(timeouts[3] <= Duration::from_secs(2));
+    }
+
+    /// Transport answering the first request with 429 and `Retry-After`.
+    #[derive(Debug, Default)]
+    struct RateLimitedTransport {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl HttpTransport for Arc<RateLimitedTransport> {
+        fn send(
+            &self,
+            _request: HttpRequest,
+        ) -> crate::http::BoxFuture<
+            '_,
+            std::result::Result<HttpResponse, crate::http::TransportError>,
+        > {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async move {
+                let mut headers = HeaderMap::new();
+                let status = if call == 0 {
+                    headers.insert("Retry-After", HeaderValue::from_static("0"));
+                    429
+                } else {
+                    200
+                };
+                Ok(HttpResponse {
+                    status,
+                    headers,
+                    body: b"{}".to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_overrides_backoff() {
+        let transport = Arc::new(RateLimitedTransport::default());
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            // Without the header the retry would wait an hour.
+            .retry_policy(
+                RetryPolicy::new()
+                    .backoff(crate::retry::Backoff::constant(Duration::from_secs(3600))),
+            )
+            .rate_limit(crate::RateLimit::per_second(100))
+            .build()
+            .unwrap();
+        let client = Client::with_transport(config, Arc::clone(&transport)).unwrap();
+
+        let response: ApiResponse<serde_json::Value> =
+            client.get("https://example.com").await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(transport.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_retries_and_reports_errors() {
+        let transport = ScriptedTransport::new(&[503, 200, 404]);
+        let client = scripted_client(&transport);
+
+        let stream = client.get_stream("https://example.com").await.unwrap();
+        let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"{}");
+        assert_eq!(transport.calls(), 2);
+
+        let result = client.get_stream("https://example.com").await;
+        assert_eq!(result.err().unwrap().status_code(), Some(404));
+    }
+
+    /// Transport streaming a fixed body in chunks.
+    #[derive(Debug)]
+    struct ChunkedTransport;
+
+    impl HttpTransport for ChunkedTransport {
+        fn send(
+            &self,
+            _request: HttpRequest,
+        ) -> crate::http::BoxFuture<
+            '_,
+            std::result::Result<HttpResponse, crate::http::TransportError>,
+        > {
+            unreachable!("downloads are streamed")
+        }
+
+        fn send_streaming(
+            &self,
+            _request: HttpRequest,
+        ) -> crate::http::BoxFuture<'_, std::result::Result<StreamingHttpResponse, TransportError>>
+        {
+            let chunks = ["a,b\n", "1,2\n", "3,4\n"].map(|chunk| Ok(Bytes::from(chunk)));
+            Box::pin(async move {
+                Ok(StreamingHttpResponse {
+                    status: 200,
+                    headers: HeaderMap::new(),
+                    body: Box::pin(futures::stream::iter(chunks)),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_yields_chunks() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .build()
+            .unwrap();
+        let client = Client::with_transport(config, ChunkedTransport).unwrap();
+
+        let chunks: Vec<Bytes> = client
+            .get_stream("https://example.com/report.csv")
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), b"a,b\n1,2\n3,4\n");
+    }
+
     #[test]
     fn test_api_response() {
         let response = ApiResponse {
             data: serde_json::json!({"status": "success"}),
             status: 200,
-            headers: reqwest::header::HeaderMap::new(),
+            headers: HeaderMap::new(),
             psp_reference: Some("12345678901234567890".to_string()),
         };
 
@@ -1,8 +1,160 @@
 //! Configuration management for Adyen clients.
 
-use crate::{auth::Credentials, environment::Environment, AdyenError, Result};
+use crate::{
+    application_info::ApplicationInfo,
+    auth::Credentials,
+    environment::Environment,
+    http::{HttpTransport, PoolConfig, ProxyConfig, TlsConfig},
+    middleware::Middleware,
+    oauth::OAuthCredentials,
+    observability::MetricsRecorder,
+    rate_limit::{RateLimit, RateLimiter},
+    redact::Redactor,
+    retry::RetryPolicy,
+    AdyenError, Result, SecretString,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Adyen APIs, used to configure settings for a single API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Api {
+    /// Checkout API
+    Checkout,
+    /// Classic Payments API
+    Payments,
+    /// Recurring API
+    Recurring,
+    /// Payout API
+    Payout,
+    /// BIN Lookup API
+    BinLookup,
+    /// Stored Value API
+    StoredValue,
+    /// Management API
+    Management,
+    /// Balance Platform Configuration API
+    BalancePlatform,
+    /// Transfers API
+    Transfers,
+    /// Legal Entity Management API
+    LegalEntity,
+    /// Disputes API
+    Disputes,
+    /// Data Protection API
+    DataProtection,
+    /// Terminal API
+    Terminal,
+    /// Report downloads
+    Reports,
+}
+
+impl Api {
+    /// All APIs.
+    pub const ALL: [Self; 14] = [
+        Self::Checkout,
+        Self::Payments,
+        Self::Recurring,
+        Self::Payout,
+        Self::BinLookup,
+        Self::StoredValue,
+        Self::Management,
+        Self::BalancePlatform,
+        Self::Transfers,
+        Self::LegalEntity,
+        Self::Disputes,
+        Self::DataProtection,
+        Self::Terminal,
+        Self::Reports,
+    ];
+
+    /// Get the name of the API as used in configuration files, e.g.
+    /// `balance_platform`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Checkout => "checkout",
+            Self::Payments => "payments",
+            Self::Recurring => "recurring",
+            Self::Payout => "payout",
+            Self::BinLookup => "bin_lookup",
+            Self::StoredValue => "stored_value",
+            Self::Management => "management",
+            Self::BalancePlatform => "balance_platform",
+            Self::Transfers => "transfers",
+            Self::LegalEntity => "legal_entity",
+            Self::Disputes => "disputes",
+            Self::DataProtection => "data_protection",
+            Self::Terminal => "terminal",
+            Self::Reports => "reports",
+        }
+    }
+
+    /// Find an API by its [`name`](Self::name).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|api| api.name() == name)
+    }
+}
+
+/// HMAC key for validating webhooks, as configured for a deployment.
+///
+/// The key is kept hex-encoded; `adyen-webhooks` decodes it when creating a
+/// validator with `HmacValidator::from_config`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookHmacKey {
+    id: Option<Box<str>>,
+    key: SecretString,
+}
+
+impl WebhookHmacKey {
+    /// Create a key with an optional identifier, such as its creation date.
+    #[must_use]
+    pub fn new(id: Option<&str>, key: impl Into<SecretString>) -> Self {
+        Self {
+            id: id.map(Into::into),
+            key: key.into(),
+        }
+    }
+
+    /// Parse a comma-separated list of hex-encoded keys, each optionally
+    /// prefixed with an identifier and `=`, e.g.
+    /// `2024-06=44782DEF...,2024-01=A1B2C3...`.
+    #[must_use]
+    pub fn parse_list(value: &str) -> Vec<Self> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    /// Parse a hex-encoded key, optionally prefixed with an identifier and
+    /// `=`.
+    #[must_use]
+    pub fn parse(entry: &str) -> Self {
+        match entry.split_once('=') {
+            Some((id, key)) => Self::new(Some(id.trim()), key.trim()),
+            None => Self::new(None, entry.trim()),
+        }
+    }
+
+    /// Get the identifier of this key, if it has one.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Get the hex-encoded key.
+    #[must_use]
+    pub const fn key(&self) -> &SecretString {
+        &self.key
+    }
+}
+
 /// Configuration for Adyen API clients.
 ///
 /// Contains all necessary settings for connecting to Adyen APIs including
@@ -11,16 +163,44 @@ use std::time::Duration;
 pub struct Config {
     /// Authentication credentials
     credentials: Credentials,
+    /// Credentials overriding the default credentials for single APIs
+    api_credentials: HashMap<Api, Credentials>,
+    /// HMAC keys for validating webhooks, current key first
+    hmac_keys: Vec<WebhookHmacKey>,
     /// API environment (test or live)
     environment: Environment,
     /// HTTP request timeout
     timeout: Duration,
+    /// Timeouts overriding the default for single APIs
+    api_timeouts: HashMap<Api, Duration>,
     /// User agent string for requests
     user_agent: String,
     /// Additional default headers
     default_headers: std::collections::HashMap<String, String>,
     /// Enable request/response logging
     enable_logging: bool,
+    /// Redaction applied to logged request and response bodies
+    redactor: Arc<Redactor>,
+    /// Retry policy for transient failures
+    retry_policy: RetryPolicy,
+    /// Generate an `Idempotency-Key` for POST requests without one
+    auto_idempotency_key: bool,
+    /// Application information added to payment requests
+    application_info: Option<ApplicationInfo>,
+    /// Proxy used by the default transport
+    proxy: Option<ProxyConfig>,
+    /// TLS settings used by the default transport
+    tls: TlsConfig,
+    /// Connection pool settings used by the default transport
+    pool: PoolConfig,
+    /// Transport used instead of the default reqwest transport
+    transport: Option<Arc<dyn HttpTransport>>,
+    /// Client-side rate limiter shared by all clients using this configuration
+    rate_limiter: Option<RateLimiter>,
+    /// Receiver for request metrics
+    metrics_recorder: Option<Arc<dyn MetricsRecorder>>,
+    /// Middleware wrapped around the transport, outermost first
+    layers: Vec<Arc<dyn Middleware>>,
 }
 
 /// Builder for creating Adyen client configuration.
@@ -29,11 +209,25 @@ pub struct Config {
 #[derive(Debug, Default)]
 pub struct ConfigBuilder {
     credentials: Option<Credentials>,
+    api_credentials: HashMap<Api, Credentials>,
+    hmac_keys: Vec<WebhookHmacKey>,
     environment: Option<Environment>,
     timeout: Option<Duration>,
+    api_timeouts: HashMap<Api, Duration>,
     user_agent: Option<String>,
     default_headers: std::collections::HashMap<String, String>,
     enable_logging: bool,
+    redactor: Option<Redactor>,
+    retry_policy: Option<RetryPolicy>,
+    auto_idempotency_key: bool,
+    application_info: Option<ApplicationInfo>,
+    proxy: Option<ProxyConfig>,
+    tls: Option<TlsConfig>,
+    pool: Option<PoolConfig>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    rate_limit: Option<RateLimit>,
+    metrics_recorder: Option<Arc<dyn MetricsRecorder>>,
+    layers: Vec<Arc<dyn Middleware>>,
 }
 
 impl ConfigBuilder {
@@ -74,6 +268,67 @@ impl ConfigBuilder {
         Ok(self)
     }
 
+    /// Authenticate with OAuth access tokens, as partner applications do.
+    ///
+    /// Tokens are requested from the environment's
+    /// [token endpoint](Environment::oauth_token_url) on first use and
+    /// refreshed shortly before they expire.
+    #[must_use]
+    pub fn oauth(mut self, credentials: OAuthCredentials) -> Self {
+        self.credentials = Some(Credentials::OAuth(credentials));
+        self
+    }
+
+    /// Set credentials used for a single API instead of the default
+    /// credentials.
+    ///
+    /// Adyen recommends a separate API credential per integration, e.g. one
+    /// with Management API roles only.
+    #[must_use]
+    pub fn api_credentials(mut self, api: Api, credentials: Credentials) -> Self {
+        self.api_credentials.insert(api, credentials);
+        self
+    }
+
+    /// Set the API key used for a single API instead of the default
+    /// credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API key is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{Api, ConfigBuilder};
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = ConfigBuilder::new()
+    ///     .api_key("your_payments_api_key")?
+    ///     .api_key_for(Api::Management, "your_management_api_key")?
+    ///     .build()?;
+    ///
+    /// let management = config.credentials_for(Api::Management).as_api_key().unwrap();
+    /// assert_eq!(management.as_str(), "your_management_api_key");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn api_key_for(mut self, api: Api, api_key: impl Into<String>) -> Result<Self> {
+        self.api_credentials
+            .insert(api, Credentials::api_key(api_key)?);
+        Ok(self)
+    }
+
+    /// Add an HMAC key for validating webhooks.
+    ///
+    /// Add the current key first; keys added later are still accepted, e.g.
+    /// the previous key during a rotation.
+    #[must_use]
+    pub fn hmac_key(mut self, key: WebhookHmacKey) -> Self {
+        self.hmac_keys.push(key);
+        self
+    }
+
     /// Set the environment.
     #[must_use]
     pub fn environment(mut self, environment: Environment) -> Self {
@@ -88,6 +343,36 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the request timeout for a single API, overriding the default
+    /// timeout.
+    ///
+    /// Useful for slow operations such as document uploads, which need more
+    /// time than authorisations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{Api, ConfigBuilder};
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = ConfigBuilder::new()
+    ///     .api_key("your_api_key")?
+    ///     .timeout(Duration::from_secs(15))
+    ///     .api_timeout(Api::LegalEntity, Duration::from_secs(120))
+    ///     .build()?;
+    ///
+    /// assert_eq!(config.timeout_for(Api::Checkout), Duration::from_secs(15));
+    /// assert_eq!(config.timeout_for(Api::LegalEntity), Duration::from_secs(120));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn api_timeout(mut self, api: Api, timeout: Duration) -> Self {
+        self.api_timeouts.insert(api, timeout);
+        self
+    }
+
     /// Set the user agent string.
     #[must_use]
     pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
@@ -103,12 +388,182 @@ impl ConfigBuilder {
     }
 
     /// Enable or disable request/response logging.
+    ///
+    /// With the `tracing` feature, requests and responses are logged at debug
+    /// level with sensitive fields redacted; see [`redact`](crate::redact).
     #[must_use]
     pub fn enable_logging(mut self, enable: bool) -> Self {
         self.enable_logging = enable;
         self
     }
 
+    /// Set the redaction rules for logged request and response bodies.
+    ///
+    /// Defaults to [`Redactor::default`]; extend it with merchant-specific
+    /// fields rather than starting from [`Redactor::empty`].
+    #[must_use]
+    pub fn redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Set the retry policy used for transient failures.
+    ///
+    /// Defaults to [`RetryPolicy::new`].
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Automatically attach a random `Idempotency-Key` (UUID v4) to every POST
+    /// request that does not already carry one.
+    ///
+    /// This makes all POST requests safe to retry. Keys are generated per
+    /// call, so repeating a call yourself still creates a new operation; pass
+    /// an explicit key on the request to de-duplicate across calls.
+    #[must_use]
+    pub fn auto_idempotency_key(mut self, enable: bool) -> Self {
+        self.auto_idempotency_key = enable;
+        self
+    }
+
+    /// Add application information to every payment-like request.
+    ///
+    /// The information is sent as `applicationInfo` on payments, sessions,
+    /// payment links, donations and modifications of the Checkout and
+    /// classic Payments APIs, so Adyen support can identify the integration.
+    /// Fields set on a request take precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{ApplicationInfo, ConfigBuilder};
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = ConfigBuilder::new()
+    ///     .api_key("your_api_key")?
+    ///     .application_info(
+    ///         ApplicationInfo::default()
+    ///             .with_merchant_application("my-shop", "2.1.0")
+    ///             .with_external_platform("Magento", "2.4.6", Some("Acme Integrations")),
+    ///     )
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn application_info(mut self, application_info: ApplicationInfo) -> Self {
+        self.application_info = Some(application_info);
+        self
+    }
+
+    /// Send requests through a proxy.
+    ///
+    /// Applies to the default reqwest transport; a transport set with
+    /// [`transport`](Self::transport) must be configured itself. Without a
+    /// proxy the default transport uses the proxy from the `HTTPS_PROXY`
+    /// environment variable, if set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::http::ProxyConfig;
+    /// use adyen_core::ConfigBuilder;
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = ConfigBuilder::new()
+    ///     .api_key("your_api_key")?
+    ///     .proxy(ProxyConfig::new("http://proxy.internal:3128")?.with_basic_auth("egress", "secret"))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Set custom root certificates and a client certificate for TLS.
+    ///
+    /// Applies to the default reqwest transport, like [`proxy`](Self::proxy).
+    #[must_use]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Tune the connection pool for the expected traffic.
+    ///
+    /// Applies to the default reqwest transport, like [`proxy`](Self::proxy).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::http::PoolConfig;
+    /// use adyen_core::ConfigBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = ConfigBuilder::new()
+    ///     .api_key("your_api_key")?
+    ///     .connection_pool(
+    ///         PoolConfig::new()
+    ///             .max_idle_per_host(64)
+    ///             .tcp_keepalive(Some(Duration::from_secs(60))),
+    ///     )
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn connection_pool(mut self, pool: PoolConfig) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Set the HTTP transport used by clients created from this configuration.
+    ///
+    /// Every API client built from the configuration sends its requests
+    /// through this transport, which makes it the hook for custom HTTP stacks
+    /// and for mock transports in tests.
+    #[must_use]
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Limit how many requests are sent.
+    ///
+    /// Requests wait for the limiter before being sent. The limiter is shared
+    /// by every client created from the configuration, including clones, so
+    /// build the configuration once and clone it for each API client.
+    #[must_use]
+    pub const fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Add a middleware around the transport.
+    ///
+    /// Middleware runs in the order it was added; see
+    /// [`middleware`](crate::middleware).
+    #[must_use]
+    pub fn layer(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.layers.push(Arc::new(middleware));
+        self
+    }
+
+    /// Report latency and status of every request attempt to a recorder.
+    ///
+    /// See [`observability`](crate::observability) for the recorded metrics.
+    #[must_use]
+    pub fn metrics_recorder(mut self, recorder: impl MetricsRecorder + 'static) -> Self {
+        self.metrics_recorder = Some(Arc::new(recorder));
+        self
+    }
+
     /// Build the configuration.
     ///
     /// # Errors
@@ -129,11 +584,25 @@ impl ConfigBuilder {
 
         Ok(Config {
             credentials,
+            api_credentials: self.api_credentials,
+            hmac_keys: self.hmac_keys,
             environment,
             timeout,
+            api_timeouts: self.api_timeouts,
             user_agent,
             default_headers: self.default_headers,
             enable_logging: self.enable_logging,
+            redactor: Arc::new(self.redactor.unwrap_or_default()),
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            auto_idempotency_key: self.auto_idempotency_key,
+            application_info: self.application_info,
+            proxy: self.proxy,
+            tls: self.tls.unwrap_or_default(),
+            pool: self.pool.unwrap_or_default(),
+            transport: self.transport,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            metrics_recorder: self.metrics_recorder,
+            layers: self.layers,
         })
     }
 }
@@ -151,6 +620,19 @@ impl Config {
         &self.credentials
     }
 
+    /// Get the credentials for an API, falling back to the default
+    /// credentials.
+    #[must_use]
+    pub fn credentials_for(&self, api: Api) -> &Credentials {
+        self.api_credentials.get(&api).unwrap_or(&self.credentials)
+    }
+
+    /// Get the HMAC keys for validating webhooks, current key first.
+    #[must_use]
+    pub fn hmac_keys(&self) -> &[WebhookHmacKey] {
+        &self.hmac_keys
+    }
+
     /// Get the environment.
     #[must_use]
     pub const fn environment(&self) -> &Environment {
@@ -163,6 +645,12 @@ impl Config {
         self.timeout
     }
 
+    /// Get the timeout for an API, falling back to the default timeout.
+    #[must_use]
+    pub fn timeout_for(&self, api: Api) -> Duration {
+        self.api_timeouts.get(&api).copied().unwrap_or(self.timeout)
+    }
+
     /// Get the user agent.
     #[must_use]
     pub fn user_agent(&self) -> &str {
@@ -180,6 +668,82 @@ impl Config {
     pub const fn is_logging_enabled(&self) -> bool {
         self.enable_logging
     }
+
+    /// Get the redaction rules for logged bodies.
+    #[must_use]
+    pub fn redactor(&self) -> &Redactor {
+        &self.redactor
+    }
+
+    /// Check if idempotency keys are generated automatically.
+    #[must_use]
+    pub const fn is_auto_idempotency_key_enabled(&self) -> bool {
+        self.auto_idempotency_key
+    }
+
+    /// Get the application information added to payment requests, if set.
+    #[must_use]
+    pub const fn application_info(&self) -> Option<&ApplicationInfo> {
+        self.application_info.as_ref()
+    }
+
+    /// Get the proxy, if one was configured.
+    #[must_use]
+    pub const fn proxy(&self) -> Option<&ProxyConfig> {
+        self.proxy.as_ref()
+    }
+
+    /// Get the TLS settings.
+    #[must_use]
+    pub const fn tls(&self) -> &TlsConfig {
+        &self.tls
+    }
+
+    /// Get the connection pool settings.
+    #[must_use]
+    pub const fn connection_pool(&self) -> PoolConfig {
+        self.pool
+    }
+
+    /// Get the custom HTTP transport, if one was configured.
+    #[must_use]
+    pub fn transport(&self) -> Option<&Arc<dyn HttpTransport>> {
+        self.transport.as_ref()
+    }
+
+    /// Get the retry policy.
+    #[must_use]
+    pub const fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Add a middleware around the transport of clients created from this
+    /// configuration, after any middleware already added.
+    ///
+    /// Clients created before are not affected.
+    #[must_use]
+    pub fn with_layer(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.layers.push(Arc::new(middleware));
+        self
+    }
+
+    /// Get the middleware wrapped around the transport, outermost first.
+    #[must_use]
+    pub fn layers(&self) -> &[Arc<dyn Middleware>] {
+        &self.layers
+    }
+
+    /// Get the metrics recorder, if one was configured.
+    #[must_use]
+    pub fn metrics_recorder(&self) -> Option<&Arc<dyn MetricsRecorder>> {
+        self.metrics_recorder.as_ref()
+    }
+
+    /// Get the rate limiter, if a rate limit was configured.
+    #[must_use]
+    pub const fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +787,52 @@ mod tests {
         assert_eq!(config.timeout(), Duration::from_secs(60));
         assert_eq!(config.user_agent(), crate::USER_AGENT);
         assert!(!config.is_logging_enabled());
+        assert_eq!(config.retry_policy(), &RetryPolicy::default());
+        assert!(!config.is_auto_idempotency_key_enabled());
+    }
+
+    #[test]
+    fn test_config_builder_retry_policy() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .retry_policy(RetryPolicy::new().max_attempts(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.retry_policy().total_attempts(), 5);
+    }
+
+    #[test]
+    fn test_api_credentials_and_hmac_keys() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .api_key_for(Api::Management, "management_key_12345")
+            .unwrap()
+            .hmac_key(WebhookHmacKey::parse("2024-06=44782DEF547AAA06"))
+            .build()
+            .unwrap();
+
+        let key_for = |api| {
+            config
+                .credentials_for(api)
+                .as_api_key()
+                .unwrap()
+                .as_str()
+                .to_string()
+        };
+        assert_eq!(key_for(Api::Management), "management_key_12345");
+        assert_eq!(key_for(Api::Checkout), "test_key_12345");
+        assert_eq!(config.hmac_keys()[0].id(), Some("2024-06"));
+        assert_eq!(
+            config.hmac_keys()[0].key().expose_secret(),
+            "44782DEF547AAA06"
+        );
+        assert_eq!(
+            Api::from_name("balance_platform"),
+            Some(Api::BalancePlatform)
+        );
     }
 
     #[test]
@@ -230,4 +840,76 @@ mod tests {
         let result = ConfigBuilder::new().build();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_rate_limiter_shared_by_clones() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .rate_limit(RateLimit::per_second(1))
+            .build()
+            .unwrap();
+        let clone = config.clone();
+
+        assert!(config.rate_limiter().unwrap().try_acquire().is_ok());
+        assert!(clone.rate_limiter().unwrap().try_acquire().is_err());
+
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(config.rate_limiter().is_none());
+    }
+
+    #[test]
+    fn test_custom_redactor() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .redactor(Redactor::default().with_rule("shopperEmail"))
+            .build()
+            .unwrap();
+
+        let logged = config
+            .redactor()
+            .redact_str(r#"{"shopperEmail":"shopper@example.com","cvc":"737"}"#);
+        assert!(!logged.contains("shopper@example.com"));
+        assert!(!logged.contains("737"));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_proxy_and_tls_settings() {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .proxy(ProxyConfig::new("http://proxy.internal:3128").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(config.proxy().unwrap().url(), "http://proxy.internal:3128/");
+        assert!(config.tls().uses_built_in_roots());
+        assert!(config.connection_pool().is_http2_enabled());
+        assert!(crate::Client::new(config).is_ok());
+
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .connection_pool(PoolConfig::new().max_idle_per_host(8).http2(false))
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.connection_pool().max_idle_connections_per_host(),
+            Some(8)
+        );
+        assert!(crate::Client::new(config).is_ok());
+
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .tls(TlsConfig::new().add_root_certificate_pem("not a certificate"))
+            .build()
+            .unwrap();
+        assert!(crate::Client::new(config).is_err());
+    }
 }
@@ -2,178 +2,287 @@
 
 use std::fmt;
 
-/// ISO 4217 currency codes supported by Adyen.
-///
-/// This enum represents the most commonly used currencies in the Adyen platform.
-/// For a complete list of supported currencies, refer to the Adyen documentation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(
-    feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
-)]
-#[repr(u16)]
-pub enum Currency {
-    /// Euro
-    EUR = 978,
-    /// US Dollar
-    USD = 840,
-    /// British Pound Sterling
-    GBP = 826,
-    /// Japanese Yen
-    JPY = 392,
-    /// Swiss Franc
-    CHF = 756,
-    /// Canadian Dollar
-    CAD = 124,
-    /// Australian Dollar
-    AUD = 36,
-    /// Norwegian Krone
-    NOK = 578,
-    /// Swedish Krona
-    SEK = 752,
-    /// Danish Krone
-    DKK = 208,
-    /// Polish Zloty
-    PLN = 985,
-    /// Czech Koruna
-    CZK = 203,
-    /// Hungarian Forint
-    HUF = 348,
-    /// Brazilian Real
-    BRL = 986,
-    /// Mexican Peso
-    MXN = 484,
-    /// Singapore Dollar
-    SGD = 702,
-    /// Hong Kong Dollar
-    HKD = 344,
-    /// New Zealand Dollar
-    NZD = 554,
-    /// South African Rand
-    ZAR = 710,
-    /// Chinese Yuan Renminbi
-    CNY = 156,
-    /// Indian Rupee
-    INR = 356,
-    /// South Korean Won
-    KRW = 410,
-    /// Russian Ruble
-    RUB = 643,
-    /// Turkish Lira
-    TRY = 949,
-    /// Thai Baht
-    THB = 764,
-    /// Malaysian Ringgit
-    MYR = 458,
-    /// Indonesian Rupiah
-    IDR = 360,
-    /// Philippine Peso
-    PHP = 608,
-    /// Vietnamese Dong
-    VND = 704,
-    /// Icelandic Krona
-    ISK = 352,
-}
-
-impl Currency {
-    /// Get the number of decimal places for this currency.
-    ///
-    /// Most currencies use 2 decimal places, but some exceptions exist:
-    /// - JPY, KRW, VND, ISK use 0 decimal places
-    /// - Some Middle Eastern currencies use 3 decimal places (not included in this enum)
-    #[must_use]
-    pub const fn decimal_places(self) -> u8 {
-        match self {
-            Self::JPY | Self::KRW | Self::VND | Self::ISK => 0,
-            _ => 2,
+/// Define [`Currency`] and its metadata from a table of ISO 4217 entries:
+/// alphabetic code, numeric code, exponent (minor unit digits) and name.
+macro_rules! currencies {
+    ($( $code:ident = $numeric:literal, $exponent:literal, $name:literal; )*) => {
+        /// ISO 4217 currency codes.
+        ///
+        /// Covers all active ISO 4217 currencies and funds with minor units.
+        /// Codes without a variant, such as currencies introduced after this
+        /// release, deserialize to [`Currency::Unknown`] so that responses
+        /// containing them can still be read.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+            archive(check_bytes)
+        )]
+        #[non_exhaustive]
+        pub enum Currency {
+            $(
+                #[doc = $name]
+                $code,
+            )*
+            /// Currency code not known to this library.
+            Unknown(Box<str>),
         }
-    }
 
-    /// Get the currency code as a string.
-    #[must_use]
-    pub const fn code(self) -> &'static str {
-        match self {
-            Self::EUR => "EUR",
-            Self::USD => "USD",
-            Self::GBP => "GBP",
-            Self::JPY => "JPY",
-            Self::CHF => "CHF",
-            Self::CAD => "CAD",
-            Self::AUD => "AUD",
-            Self::NOK => "NOK",
-            Self::SEK => "SEK",
-            Self::DKK => "DKK",
-            Self::PLN => "PLN",
-            Self::CZK => "CZK",
-            Self::HUF => "HUF",
-            Self::BRL => "BRL",
-            Self::MXN => "MXN",
-            Self::SGD => "SGD",
-            Self::HKD => "HKD",
-            Self::NZD => "NZD",
-            Self::ZAR => "ZAR",
-            Self::CNY => "CNY",
-            Self::INR => "INR",
-            Self::KRW => "KRW",
-            Self::RUB => "RUB",
-            Self::TRY => "TRY",
-            Self::THB => "THB",
-            Self::MYR => "MYR",
-            Self::IDR => "IDR",
-            Self::PHP => "PHP",
-            Self::VND => "VND",
-            Self::ISK => "ISK",
+        impl Currency {
+            /// All currencies known to this library, in alphabetical order.
+            pub const KNOWN: &'static [Self] = &[$(Self::$code),*];
+
+            /// Get the currency code as a string.
+            #[must_use]
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Self::$code => stringify!($code),)*
+                    Self::Unknown(code) => code,
+                }
+            }
+
+            /// Get the ISO 4217 numeric code, e.g. `978` for EUR.
+            ///
+            /// Returns `None` for unknown currencies.
+            #[must_use]
+            pub const fn numeric_code(&self) -> Option<u16> {
+                match self {
+                    $(Self::$code => Some($numeric),)*
+                    Self::Unknown(_) => None,
+                }
+            }
+
+            /// Get the English name of the currency.
+            ///
+            /// Returns `None` for unknown currencies.
+            #[must_use]
+            pub const fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$code => Some($name),)*
+                    Self::Unknown(_) => None,
+                }
+            }
+
+            /// Get the number of decimal places for this currency, i.e. its
+            /// ISO 4217 exponent.
+            ///
+            /// Most currencies use 2 decimal places; JPY and KRW use 0 and
+            /// BHD and KWD use 3, for example. Unknown currencies are assumed
+            /// to use 2.
+            #[must_use]
+            pub const fn decimal_places(&self) -> u8 {
+                match self {
+                    $(Self::$code => $exponent,)*
+                    Self::Unknown(_) => 2,
+                }
+            }
+
+            /// Parse a currency from its string code.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the currency code is not recognized.
+            pub fn from_code(code: &str) -> Result<Self, crate::error::AdyenError> {
+                match code {
+                    $(stringify!($code) => Ok(Self::$code),)*
+                    _ => Err(crate::error::AdyenError::config(format!(
+                        "Unsupported currency code: {code}"
+                    ))),
+                }
+            }
+
+            /// Look up a currency by its ISO 4217 numeric code.
+            #[must_use]
+            pub const fn from_numeric_code(numeric_code: u16) -> Option<Self> {
+                match numeric_code {
+                    $($numeric => Some(Self::$code),)*
+                    _ => None,
+                }
+            }
         }
-    }
+    };
+}
+
+currencies! {
+    AED = 784, 2, "UAE Dirham";
+    AFN = 971, 2, "Afghani";
+    ALL = 8, 2, "Albanian Lek";
+    AMD = 51, 2, "Armenian Dram";
+    ANG = 532, 2, "Netherlands Antillean Guilder";
+    AOA = 973, 2, "Angolan Kwanza";
+    ARS = 32, 2, "Argentine Peso";
+    AUD = 36, 2, "Australian Dollar";
+    AWG = 533, 2, "Aruban Florin";
+    AZN = 944, 2, "Azerbaijan Manat";
+    BAM = 977, 2, "Convertible Mark";
+    BBD = 52, 2, "Barbados Dollar";
+    BDT = 50, 2, "Bangladeshi Taka";
+    BGN = 975, 2, "Bulgarian Lev";
+    BHD = 48, 3, "Bahraini Dinar";
+    BIF = 108, 0, "Burundi Franc";
+    BMD = 60, 2, "Bermudian Dollar";
+    BND = 96, 2, "Brunei Dollar";
+    BOB = 68, 2, "Boliviano";
+    BOV = 984, 2, "Mvdol";
+    BRL = 986, 2, "Brazilian Real";
+    BSD = 44, 2, "Bahamian Dollar";
+    BTN = 64, 2, "Ngultrum";
+    BWP = 72, 2, "Pula";
+    BYN = 933, 2, "Belarusian Ruble";
+    BZD = 84, 2, "Belize Dollar";
+    CAD = 124, 2, "Canadian Dollar";
+    CDF = 976, 2, "Congolese Franc";
+    CHE = 947, 2, "WIR Euro";
+    CHF = 756, 2, "Swiss Franc";
+    CHW = 948, 2, "WIR Franc";
+    CLF = 990, 4, "Unidad de Fomento";
+    CLP = 152, 0, "Chilean Peso";
+    CNY = 156, 2, "Chinese Yuan Renminbi";
+    COP = 170, 2, "Colombian Peso";
+    COU = 970, 2, "Unidad de Valor Real";
+    CRC = 188, 2, "Costa Rican Colon";
+    CUP = 192, 2, "Cuban Peso";
+    CVE = 132, 2, "Cabo Verde Escudo";
+    CZK = 203, 2, "Czech Koruna";
+    DJF = 262, 0, "Djibouti Franc";
+    DKK = 208, 2, "Danish Krone";
+    DOP = 214, 2, "Dominican Peso";
+    DZD = 12, 2, "Algerian Dinar";
+    EGP = 818, 2, "Egyptian Pound";
+    ERN = 232, 2, "Nakfa";
+    ETB = 230, 2, "Ethiopian Birr";
+    EUR = 978, 2, "Euro";
+    FJD = 242, 2, "Fiji Dollar";
+    FKP = 238, 2, "Falkland Islands Pound";
+    GBP = 826, 2, "British Pound Sterling";
+    GEL = 981, 2, "Georgian Lari";
+    GHS = 936, 2, "Ghana Cedi";
+    GIP = 292, 2, "Gibraltar Pound";
+    GMD = 270, 2, "Dalasi";
+    GNF = 324, 0, "Guinean Franc";
+    GTQ = 320, 2, "Quetzal";
+    GYD = 328, 2, "Guyana Dollar";
+    HKD = 344, 2, "Hong Kong Dollar";
+    HNL = 340, 2, "Lempira";
+    HTG = 332, 2, "Gourde";
+    HUF = 348, 2, "Hungarian Forint";
+    IDR = 360, 2, "Indonesian Rupiah";
+    ILS = 376, 2, "New Israeli Sheqel";
+    INR = 356, 2, "Indian Rupee";
+    IQD = 368, 3, "Iraqi Dinar";
+    IRR = 364, 2, "Iranian Rial";
+    ISK = 352, 0, "Icelandic Krona";
+    JMD = 388, 2, "Jamaican Dollar";
+    JOD = 400, 3, "Jordanian Dinar";
+    JPY = 392, 0, "Japanese Yen";
+    KES = 404, 2, "Kenyan Shilling";
+    KGS = 417, 2, "Som";
+    KHR = 116, 2, "Riel";
+    KMF = 174, 0, "Comorian Franc";
+    KPW = 408, 2, "North Korean Won";
+    KRW = 410, 0, "South Korean Won";
+    KWD = 414, 3, "Kuwaiti Dinar";
+    KYD = 136, 2, "Cayman Islands Dollar";
+    KZT = 398, 2, "Tenge";
+    LAK = 418, 2, "Lao Kip";
+    LBP = 422, 2, "Lebanese Pound";
+    LKR = 144, 2, "Sri Lanka Rupee";
+    LRD = 430, 2, "Liberian Dollar";
+    LSL = 426, 2, "Loti";
+    LYD = 434, 3, "Libyan Dinar";
+    MAD = 504, 2, "Moroccan Dirham";
+    MDL = 498, 2, "Moldovan Leu";
+    MGA = 969, 2, "Malagasy Ariary";
+    MKD = 807, 2, "Denar";
+    MMK = 104, 2, "Kyat";
+    MNT = 496, 2, "Tugrik";
+    MOP = 446, 2, "Pataca";
+    MRU = 929, 2, "Ouguiya";
+    MUR = 480, 2, "Mauritius Rupee";
+    MVR = 462, 2, "Rufiyaa";
+    MWK = 454, 2, "Malawi Kwacha";
+    MXN = 484, 2, "Mexican Peso";
+    MXV = 979, 2, "Mexican Unidad de Inversion";
+    MYR = 458, 2, "Malaysian Ringgit";
+    MZN = 943, 2, "Mozambique Metical";
+    NAD = 516, 2, "Namibia Dollar";
+    NGN = 566, 2, "Naira";
+    NIO = 558, 2, "Cordoba Oro";
+    NOK = 578, 2, "Norwegian Krone";
+    NPR = 524, 2, "Nepalese Rupee";
+    NZD = 554, 2, "New Zealand Dollar";
+    OMR = 512, 3, "Rial Omani";
+    PAB = 590, 2, "Balboa";
+    PEN = 604, 2, "Sol";
+    PGK = 598, 2, "Kina";
+    PHP = 608, 2, "Philippine Peso";
+    PKR = 586, 2, "Pakistan Rupee";
+    PLN = 985, 2, "Polish Zloty";
+    PYG = 600, 0, "Guarani";
+    QAR = 634, 2, "Qatari Rial";
+    RON = 946, 2, "Romanian Leu";
+    RSD = 941, 2, "Serbian Dinar";
+    RUB = 643, 2, "Russian Ruble";
+    RWF = 646, 0, "Rwanda Franc";
+    SAR = 682, 2, "Saudi Riyal";
+    SBD = 90, 2, "Solomon Islands Dollar";
+    SCR = 690, 2, "Seychelles Rupee";
+    SDG = 938, 2, "Sudanese Pound";
+    SEK = 752, 2, "Swedish Krona";
+    SGD = 702, 2, "Singapore Dollar";
+    SHP = 654, 2, "Saint Helena Pound";
+    SLE = 925, 2, "Leone";
+    SOS = 706, 2, "Somali Shilling";
+    SRD = 968, 2, "Surinam Dollar";
+    SSP = 728, 2, "South Sudanese Pound";
+    STN = 930, 2, "Dobra";
+    SVC = 222, 2, "El Salvador Colon";
+    SYP = 760, 2, "Syrian Pound";
+    SZL = 748, 2, "Lilangeni";
+    THB = 764, 2, "Thai Baht";
+    TJS = 972, 2, "Somoni";
+    TMT = 934, 2, "Turkmenistan New Manat";
+    TND = 788, 3, "Tunisian Dinar";
+    TOP = 776, 2, "Pa'anga";
+    TRY = 949, 2, "Turkish Lira";
+    TTD = 780, 2, "Trinidad and Tobago Dollar";
+    TWD = 901, 2, "New Taiwan Dollar";
+    TZS = 834, 2, "Tanzanian Shilling";
+    UAH = 980, 2, "Hryvnia";
+    UGX = 800, 0, "Uganda Shilling";
+    USD = 840, 2, "US Dollar";
+    USN = 997, 2, "US Dollar (Next day)";
+    UYI = 940, 0, "Uruguay Peso en Unidades Indexadas";
+    UYU = 858, 2, "Peso Uruguayo";
+    UYW = 927, 4, "Unidad Previsional";
+    UZS = 860, 2, "Uzbekistan Sum";
+    VED = 926, 2, "Bolivar Digital";
+    VES = 928, 2, "Bolivar Soberano";
+    VND = 704, 0, "Vietnamese Dong";
+    VUV = 548, 0, "Vatu";
+    WST = 882, 2, "Tala";
+    XAF = 950, 0, "CFA Franc BEAC";
+    XCD = 951, 2, "East Caribbean Dollar";
+    XOF = 952, 0, "CFA Franc BCEAO";
+    XPF = 953, 0, "CFP Franc";
+    YER = 886, 2, "Yemeni Rial";
+    ZAR = 710, 2, "South African Rand";
+    ZMW = 967, 2, "Zambian Kwacha";
+    ZWG = 924, 2, "Zimbabwe Gold";
+}
 
-    /// Get the numeric code for this currency (ISO 4217).
+impl Currency {
+    /// Parse a currency from its string code, keeping codes not known to
+    /// this library as [`Currency::Unknown`].
     #[must_use]
-    pub const fn numeric_code(self) -> u16 {
-        self as u16
+    pub fn from_code_or_unknown(code: &str) -> Self {
+        Self::from_code(code).unwrap_or_else(|_| Self::Unknown(code.into()))
     }
 
-    /// Parse a currency from its string code.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the currency code is not recognized.
-    pub fn from_code(code: &str) -> Result<Self, crate::error::AdyenError> {
-        match code {
-            "EUR" => Ok(Self::EUR),
-            "USD" => Ok(Self::USD),
-            "GBP" => Ok(Self::GBP),
-            "JPY" => Ok(Self::JPY),
-            "CHF" => Ok(Self::CHF),
-            "CAD" => Ok(Self::CAD),
-            "AUD" => Ok(Self::AUD),
-            "NOK" => Ok(Self::NOK),
-            "SEK" => Ok(Self::SEK),
-            "DKK" => Ok(Self::DKK),
-            "PLN" => Ok(Self::PLN),
-            "CZK" => Ok(Self::CZK),
-            "HUF" => Ok(Self::HUF),
-            "BRL" => Ok(Self::BRL),
-            "MXN" => Ok(Self::MXN),
-            "SGD" => Ok(Self::SGD),
-            "HKD" => Ok(Self::HKD),
-            "NZD" => Ok(Self::NZD),
-            "ZAR" => Ok(Self::ZAR),
-            "CNY" => Ok(Self::CNY),
-            "INR" => Ok(Self::INR),
-            "KRW" => Ok(Self::KRW),
-            "RUB" => Ok(Self::RUB),
-            "TRY" => Ok(Self::TRY),
-            "THB" => Ok(Self::THB),
-            "MYR" => Ok(Self::MYR),
-            "IDR" => Ok(Self::IDR),
-            "PHP" => Ok(Self::PHP),
-            "VND" => Ok(Self::VND),
-            "ISK" => Ok(Self::ISK),
-            _ => Err(crate::error::AdyenError::config(format!(
-                "Unsupported currency code: {code}"
-            ))),
-        }
+    /// Check if the currency is known to this library.
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
     }
 
     /// Get the minor unit multiplier for this currency.
@@ -181,19 +290,14 @@ impl Currency {
     /// This is `10^decimal_places` and is used to convert between
     /// major units (e.g., dollars) and minor units (e.g., cents).
     #[must_use]
-    pub const fn minor_unit_multiplier(self) -> u64 {
-        match self.decimal_places() {
-            0 => 1,
-            1 => 10,
-            2 => 100,
-            _ => 1000, // Fallback for any unexpected values (including 3 and higher)
-        }
+    pub const fn minor_unit_multiplier(&self) -> u64 {
+        10u64.pow(self.decimal_places() as u32)
     }
 }
 
 impl fmt::Display for Currency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.code())
+        f.write_str(self.code())
     }
 }
 
@@ -205,6 +309,21 @@ impl std::str::FromStr for Currency {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Self::from_code_or_unknown(&code))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,6 +365,55 @@ mod tests {
         assert_eq!(format!("{}", Currency::USD), "USD");
     }
 
+    #[test]
+    fn test_currency_metadata() {
+        assert_eq!(Currency::EUR.numeric_code(), Some(978));
+        assert_eq!(Currency::ALL.numeric_code(), Some(8));
+        assert_eq!(Currency::EUR.name(), Some("Euro"));
+        assert_eq!(Currency::BHD.decimal_places(), 3);
+        assert_eq!(Currency::BHD.minor_unit_multiplier(), 1000);
+        assert_eq!(Currency::CLF.minor_unit_multiplier(), 10_000);
+        assert_eq!(Currency::from_numeric_code(840), Some(Currency::USD));
+        assert_eq!(Currency::from_numeric_code(1), None);
+    }
+
+    #[test]
+    fn test_known_currencies_are_consistent() {
+        let mut numeric_codes = std::collections::HashSet::new();
+        for currency in Currency::KNOWN {
+            assert!(currency.is_known());
+            assert_eq!(Currency::from_code(currency.code()).unwrap(), *currency);
+            assert!(numeric_codes.insert(currency.numeric_code().unwrap()));
+            assert_eq!(
+                Currency::from_numeric_code(currency.numeric_code().unwrap()).as_ref(),
+                Some(currency)
+            );
+        }
+        assert!(Currency::KNOWN
+            .windows(2)
+            .all(|w| w[0].code() < w[1].code()));
+    }
+
+    #[test]
+    fn test_unknown_currency() {
+        let currency = Currency::from_code_or_unknown("XCG");
+        assert_eq!(currency, Currency::Unknown("XCG".into()));
+        assert!(!currency.is_known());
+        assert_eq!(currency.to_string(), "XCG");
+        assert_eq!(currency.numeric_code(), None);
+        assert_eq!(currency.decimal_places(), 2);
+        assert_eq!(Currency::from_code_or_unknown("EUR"), Currency::EUR);
+    }
+
+    #[test]
+    fn test_currency_serde() {
+        assert_eq!(serde_json::to_string(&Currency::EUR).unwrap(), r#""EUR""#);
+        let currency: Currency = serde_json::from_str(r#""KWD""#).unwrap();
+        assert_eq!(currency, Currency::KWD);
+        let currency: Currency = serde_json::from_str(r#""XCG""#).unwrap();
+        assert_eq!(serde_json::to_string(&currency).unwrap(), r#""XCG""#);
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!("EUR".parse::<Currency>().unwrap(), Currency::EUR);
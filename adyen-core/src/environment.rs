@@ -11,7 +11,8 @@ use std::fmt;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
 pub enum Environment {
     /// Test environment for development and testing
@@ -30,7 +31,8 @@ pub enum Environment {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
 pub struct UrlPrefix(Box<str>);
 
@@ -96,6 +98,10 @@ impl Environment {
 
     /// Create a live environment with the given URL prefix.
     ///
+    /// The prefix is shown in the Customer Area under **Developers** >
+    /// **API URLs**, e.g. `1797a841fbb37ca7-AdyenDemo`. It is used for the
+    /// Checkout and classic API URLs; the other APIs have fixed live URLs.
+    ///
     /// # Errors
     ///
     /// Returns an error if the URL prefix is invalid.
@@ -105,6 +111,29 @@ impl Environment {
         })
     }
 
+    /// Create a live environment with the given URL prefix.
+    ///
+    /// Same as [`live`](Self::live).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::Environment;
+    ///
+    /// let env = Environment::live_with_prefix("1797a841fbb37ca7-AdyenDemo").unwrap();
+    /// assert_eq!(
+    ///     env.checkout_api_url(),
+    ///     "https://1797a841fbb37ca7-AdyenDemo-checkout-live.adyenpayments.com/checkout"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL prefix is invalid.
+    pub fn live_with_prefix(url_prefix: impl Into<String>) -> Result<Self> {
+        Self::live(url_prefix)
+    }
+
     /// Check if this is the test environment.
     #[must_use]
     pub const fn is_test(&self) -> bool {
@@ -140,13 +169,16 @@ impl Environment {
     }
 
     /// Get the base URL for Checkout API.
+    ///
+    /// Live Checkout endpoints are served under an additional `/checkout`
+    /// path, e.g. `https://{prefix}-checkout-live.adyenpayments.com/checkout/v71/payments`.
     #[must_use]
     pub fn checkout_api_url(&self) -> String {
         match self {
             Self::Test => "https://checkout-test.adyen.com".to_string(),
             Self::Live { url_prefix } => {
                 format!(
-                    "https://{}-checkout-live.adyenpayments.com",
+                    "https://{}-checkout-live.adyenpayments.com/checkout",
                     url_prefix.as_str()
                 )
             }
@@ -215,6 +247,15 @@ impl Environment {
             Self::Live { .. } => "https://terminal-api-live.adyen.com".to_string(),
         }
     }
+
+    /// Get the URL of the OAuth token endpoint.
+    #[must_use]
+    pub fn oauth_token_url(&self) -> String {
+        match self {
+            Self::Test => "https://oauth-test.adyen.com/v1/token".to_string(),
+            Self::Live { .. } => "https://oauth.adyen.com/v1/token".to_string(),
+        }
+    }
 }
 
 impl fmt::Display for Environment {
@@ -280,7 +321,7 @@ mod tests {
         );
         assert_eq!(
             live_env.checkout_api_url(),
-            "https://test-prefix-checkout-live.adyenpayments.com"
+            "https://test-prefix-checkout-live.adyenpayments.com/checkout"
         );
         assert_eq!(
             live_env.management_api_url(),
@@ -288,6 +329,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_live_with_prefix_urls() {
+        let env = Environment::live_with_prefix("1797a841fbb37ca7-AdyenDemo").unwrap();
+        assert_eq!(
+            env,
+            Environment::live("1797a841fbb37ca7-AdyenDemo").unwrap()
+        );
+        assert_eq!(
+            env.classic_api_url(),
+            "https://1797a841fbb37ca7-AdyenDemo-pal-live.adyenpayments.com"
+        );
+        assert_eq!(
+            env.checkout_api_url(),
+            "https://1797a841fbb37ca7-AdyenDemo-checkout-live.adyenpayments.com/checkout"
+        );
+        assert_eq!(
+            env.balance_platform_api_url(),
+            "https://balanceplatform-api-live.adyen.com"
+        );
+        assert_eq!(env.legal_entity_api_url(), "https://kyc-live.adyen.com");
+        assert_eq!(env.disputes_api_url(), "https://ca-live.adyen.com");
+        assert_eq!(
+            env.terminal_api_url(),
+            "https://terminal-api-live.adyen.com"
+        );
+        assert!(Environment::live_with_prefix("https://example.com").is_err());
+    }
+
     #[test]
     fn test_environment_display() {
         let test_env = Environment::test();
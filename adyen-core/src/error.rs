@@ -6,9 +6,9 @@ pub type Result<T> = std::result::Result<T, AdyenError>;
 /// Main error type for all Adyen operations.
 #[derive(Debug, thiserror::Error)]
 pub enum AdyenError {
-    /// HTTP request/response errors
+    /// HTTP transport errors
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[from] crate::http::TransportError),
 
     /// JSON serialization/deserialization errors
     #[error("Serialization error: {0}")]
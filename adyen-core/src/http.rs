@@ -1,4 +1,31 @@
 //! HTTP utilities and abstractions.
+//!
+//! [`Client`](crate::Client) sends requests through an [`HttpTransport`], so
+//! the underlying HTTP stack can be swapped out. With the `reqwest` feature
+//! (enabled by default) [`ReqwestTransport`] is used unless another transport
+//! is supplied via [`Client::with_transport`](crate::Client::with_transport).
+//! Runtimes without sockets, such as edge runtimes, can plug in their own
+//! HTTP client with [`FetchTransport`].
+
+use futures::{Stream, TryStreamExt};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+pub use ::http::header::{HeaderMap, HeaderName, HeaderValue};
+pub use bytes::Bytes;
+
+mod connection;
+mod fetch;
+#[cfg(feature = "reqwest")]
+mod reqwest_transport;
+
+pub use connection::{ClientIdentity, PoolConfig, ProxyConfig, TlsConfig};
+pub use fetch::FetchTransport;
+
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::ReqwestTransport;
 
 /// HTTP status codes commonly used in Adyen APIs.
 pub mod status {
@@ -55,6 +82,7 @@ pub enum Method {
 
 impl Method {
     /// Convert to reqwest Method.
+    #[cfg(feature = "reqwest")]
     #[must_use]
     pub const fn as_reqwest(&self) -> reqwest::Method {
         match self {
@@ -65,6 +93,13 @@ impl Method {
             Self::Delete => reqwest::Method::DELETE,
         }
     }
+
+    /// Check if the method is idempotent, i.e. repeating it has no
+    /// additional effect.
+    #[must_use]
+    pub const fn is_idempotent(&self) -> bool {
+        matches!(self, Self::Get | Self::Put | Self::Delete)
+    }
 }
 
 impl std::fmt::Display for Method {
@@ -78,3 +113,262 @@ impl std::fmt::Display for Method {
         }
     }
 }
+
+/// `Send` everywhere except on wasm32.
+///
+/// Futures of the JavaScript fetch API are not `Send`, so futures and streams
+/// coming from a transport only need to be `Send` where threads exist.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// `Send` everywhere except on wasm32.
+///
+/// Futures of the JavaScript fetch API are not `Send`, so futures and streams
+/// coming from a transport only need to be `Send` where threads exist.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// A boxed, sendable future as returned by [`HttpTransport::send`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A boxed future as returned by [`HttpTransport::send`], not `Send` on
+/// wasm32 (see [`MaybeSend`]).
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A fully prepared HTTP request handed to an [`HttpTransport`].
+///
+/// Authentication, default headers and the serialized body have already been
+/// applied by the client.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Absolute request URL
+    pub url: String,
+    /// Request headers
+    pub headers: HeaderMap,
+    /// Serialized request body
+    pub body: Option<Vec<u8>>,
+    /// Timeout for the whole request
+    pub timeout: Duration,
+}
+
+/// A raw HTTP response returned by an [`HttpTransport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+/// A response body delivered in chunks as returned by
+/// [`HttpTransport::send_streaming`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, TransportError>> + Send>>;
+
+/// A response body delivered in chunks as returned by
+/// [`HttpTransport::send_streaming`].
+#[cfg(target_arch = "wasm32")]
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes, TransportError>>>>;
+
+/// A raw HTTP response whose body is streamed instead of buffered.
+pub struct StreamingHttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: ByteStream,
+}
+
+impl StreamingHttpResponse {
+    /// Read the whole body into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the body fails.
+    pub async fn buffer(self) -> Result<HttpResponse, TransportError> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: chunks.concat(),
+        })
+    }
+}
+
+impl From<HttpResponse> for StreamingHttpResponse {
+    fn from(response: HttpResponse) -> Self {
+        let body = Bytes::from(response.body);
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body: Box::pin(futures::stream::once(async move { Ok(body) })),
+        }
+    }
+}
+
+impl fmt::Debug for StreamingHttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingHttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Transport used by [`Client`](crate::Client) to send HTTP requests.
+///
+/// Implement this trait to use an HTTP stack other than reqwest. Transports
+/// must only report failures to exchange a request; non-2xx responses are
+/// returned as [`HttpResponse`] values and handled by the client.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::http::{BoxFuture, HttpRequest, HttpResponse, HttpTransport, TransportError};
+///
+/// #[derive(Debug)]
+/// struct StaticTransport;
+///
+/// impl HttpTransport for StaticTransport {
+///     fn send(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+///         Box::pin(async {
+///             Ok(HttpResponse {
+///                 status: 200,
+///                 headers: Default::default(),
+///                 body: b"{}".to_vec(),
+///             })
+///         })
+///     }
+/// }
+/// ```
+pub trait HttpTransport: Send + Sync + fmt::Debug {
+    /// Send a request and return the response.
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>>;
+
+    /// Send a request and return the response without reading its body.
+    ///
+    /// Used for large downloads. The default implementation buffers the body
+    /// with [`send`](Self::send) and returns it as a single chunk.
+    fn send_streaming(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<StreamingHttpResponse, TransportError>> {
+        Box::pin(async move { Ok(self.send(request).await?.into()) })
+    }
+}
+
+/// Classification of transport failures, used to decide whether a request
+/// may be retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The connection could not be established; the request was not sent.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// The request failed while being sent or its response while being read.
+    Request,
+    /// Any other failure.
+    Other,
+}
+
+impl fmt::Display for TransportErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connect => write!(f, "connection error"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::Request => write!(f, "request error"),
+            Self::Other => write!(f, "transport error"),
+        }
+    }
+}
+
+/// Error returned by an [`HttpTransport`].
+#[derive(Debug, thiserror::Error)]
+#[error("{kind}: {message}")]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    message: String,
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl TransportError {
+    /// Create a new transport error.
+    #[must_use]
+    pub fn new(kind: TransportErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Attach the underlying error.
+    #[must_use]
+    pub fn with_source(mut self, source: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Get the error kind.
+    #[must_use]
+    pub const fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    /// Check if the connection could not be established.
+    #[must_use]
+    pub const fn is_connect(&self) -> bool {
+        matches!(self.kind, TransportErrorKind::Connect)
+    }
+
+    /// Check if the request timed out.
+    #[must_use]
+    pub const fn is_timeout(&self) -> bool {
+        matches!(self.kind, TransportErrorKind::Timeout)
+    }
+
+    /// Check if the failure is transient and the request may be retried.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        !matches!(self.kind, TransportErrorKind::Other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_idempotency() {
+        assert!(Method::Get.is_idempotent());
+        assert!(Method::Put.is_idempotent());
+        assert!(Method::Delete.is_idempotent());
+        assert!(!Method::Post.is_idempotent());
+        assert!(!Method::Patch.is_idempotent());
+    }
+
+    #[test]
+    fn test_transport_error_classification() {
+        let error = TransportError::new(TransportErrorKind::Connect, "connection refused");
+        assert!(error.is_connect());
+        assert!(error.is_transient());
+        assert_eq!(error.to_string(), "connection error: connection refused");
+
+        let error = TransportError::new(TransportErrorKind::Other, "invalid URL");
+        assert!(!error.is_transient());
+    }
+}
@@ -0,0 +1,109 @@
+//! [`HttpTransport`] backed by an async fetch function.
+
+use super::{BoxFuture, HttpRequest, HttpResponse, HttpTransport, MaybeSend, TransportError};
+use std::fmt;
+use std::future::Future;
+
+/// [`HttpTransport`] that sends requests through an async function.
+///
+/// Use this on runtimes that come with their own HTTP client instead of
+/// sockets, e.g. edge runtimes exposing a `fetch` API, by wrapping that
+/// client in a closure. Build the client without the `reqwest` and `tokio`
+/// features on such runtimes. On wasm32 the returned futures need not be
+/// `Send`, so JavaScript fetch futures can be used directly.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::http::{FetchTransport, HeaderMap, HttpResponse};
+/// use adyen_core::ConfigBuilder;
+///
+/// # fn example() -> adyen_core::Result<()> {
+/// let transport = FetchTransport::new(|request| async move {
+///     // Call the runtime's fetch API with `request` here.
+///     let _ = request;
+///     Ok(HttpResponse {
+///         status: 200,
+///         headers: HeaderMap::new(),
+///         body: b"{}".to_vec(),
+///     })
+/// });
+///
+/// let config = ConfigBuilder::new()
+///     .api_key("your_api_key")?
+///     .transport(transport)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FetchTransport<F> {
+    fetch: F,
+}
+
+impl<F, Fut> FetchTransport<F>
+where
+    F: Fn(HttpRequest) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<HttpResponse, TransportError>> + MaybeSend + 'static,
+{
+    /// Create a transport calling `fetch` for every request attempt.
+    #[must_use]
+    pub const fn new(fetch: F) -> Self {
+        Self { fetch }
+    }
+}
+
+impl<F> fmt::Debug for FetchTransport<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FetchTransport").finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut> HttpTransport for FetchTransport<F>
+where
+    F: Fn(HttpRequest) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<HttpResponse, TransportError>> + MaybeSend + 'static,
+{
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        Box::pin((self.fetch)(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{HeaderMap, TransportErrorKind};
+    use crate::{ApiResponse, Client, ConfigBuilder};
+
+    #[tokio::test]
+    async fn test_fetch_transport() {
+        let transport = FetchTransport::new(|request: HttpRequest| async move {
+            if request.url.ends_with("/unreachable") {
+                return Err(TransportError::new(
+                    TransportErrorKind::Connect,
+                    "fetch failed",
+                ));
+            }
+            Ok(HttpResponse {
+                status: 200,
+                headers: HeaderMap::new(),
+                body: format!(r#"{{"url":"{}"}}"#, request.url).into_bytes(),
+            })
+        });
+        let config = ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .transport(transport)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let response: ApiResponse<serde_json::Value> =
+            client.get("https://example.com/sessions").await.unwrap();
+        assert_eq!(response.data["url"], "https://example.com/sessions");
+
+        let request =
+            crate::Request::new(crate::http::Method::Get, "https://example.com/unreachable")
+                .without_retry();
+        assert!(client.execute_raw(request).await.is_err());
+    }
+}
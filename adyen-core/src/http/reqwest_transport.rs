@@ -0,0 +1,191 @@
+//! Default [`HttpTransport`] implementation based on reqwest.
+
+use super::{
+    BoxFuture, ClientIdentity, HttpRequest, HttpResponse, HttpTransport, PoolConfig, ProxyConfig,
+    StreamingHttpResponse, TlsConfig, TransportError, TransportErrorKind,
+};
+use crate::{AdyenError, Config, Result};
+
+/// [`HttpTransport`] backed by a [`reqwest::Client`].
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport with a new HTTPS-only reqwest client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created.
+    pub fn new() -> Result<Self> {
+        let client = reqwest::ClientBuilder::new()
+            .https_only(true)
+            .build()
+            .map_err(|e| AdyenError::config(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self { client })
+    }
+
+    /// Create a transport with the proxy, TLS and connection pool settings of
+    /// a configuration.
+    ///
+    /// SOCKS proxies require reqwest's `socks` feature to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a certificate or client identity cannot be parsed,
+    /// or the HTTP client cannot be created.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let mut builder = reqwest::ClientBuilder::new().https_only(true);
+        if let Some(proxy) = config.proxy() {
+            builder = builder.proxy(proxy_from_config(proxy)?);
+        }
+        builder = apply_tls(builder, config.tls())?;
+        builder = apply_pool(builder, config.connection_pool());
+
+        let client = builder
+            .build()
+            .map_err(|e| AdyenError::config(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self { client })
+    }
+
+    /// Create a transport from an existing reqwest client.
+    #[must_use]
+    pub const fn from_client(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    /// Get the underlying reqwest client.
+    #[must_use]
+    pub const fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    fn request(&self, request: HttpRequest) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .client
+            .request(request.method.as_reqwest(), &request.url)
+            .headers(request.headers)
+            .timeout(request.timeout);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        builder
+    }
+}
+
+fn proxy_from_config(config: &ProxyConfig) -> Result<reqwest::Proxy> {
+    let mut proxy = reqwest::Proxy::all(config.url())
+        .map_err(|e| AdyenError::config(format!("Invalid proxy: {e}")))?;
+    if let Some((username, password)) = config.credentials() {
+        proxy = proxy.basic_auth(username, password);
+    }
+    Ok(proxy)
+}
+
+fn apply_tls(
+    mut builder: reqwest::ClientBuilder,
+    config: &TlsConfig,
+) -> Result<reqwest::ClientBuilder> {
+    for pem in config.root_certificates() {
+        let certificates = reqwest::Certificate::from_pem_bundle(pem)
+            .map_err(|e| AdyenError::config(format!("Invalid root certificate: {e}")))?;
+        if certificates.is_empty() {
+            return Err(AdyenError::config(
+                "Invalid root certificate: no PEM certificate found",
+            ));
+        }
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+    builder = builder.tls_built_in_root_certs(config.uses_built_in_roots());
+
+    if let Some(identity) = config.client_identity() {
+        let identity = match identity {
+            ClientIdentity::Pkcs12 { der, password } => {
+                reqwest::Identity::from_pkcs12_der(der, password)
+            }
+            ClientIdentity::Pem {
+                certificate,
+                private_key,
+            } => reqwest::Identity::from_pkcs8_pem(certificate, private_key),
+        }
+        .map_err(|e| AdyenError::config(format!("Invalid client identity: {e}")))?;
+        builder = builder.identity(identity);
+    }
+    Ok(builder)
+}
+
+fn apply_pool(mut builder: reqwest::ClientBuilder, config: PoolConfig) -> reqwest::ClientBuilder {
+    if let Some(max) = config.max_idle_connections_per_host() {
+        builder = builder.pool_max_idle_per_host(max);
+    }
+    builder = builder
+        .pool_idle_timeout(config.idle_connection_timeout())
+        .tcp_keepalive(config.tcp_keepalive_interval());
+    if !config.is_http2_enabled() {
+        builder = builder.http1_only();
+    }
+    builder
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, std::result::Result<HttpResponse, TransportError>> {
+        Box::pin(async move {
+            let response = self.request(request).send().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+
+    fn send_streaming(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, std::result::Result<StreamingHttpResponse, TransportError>> {
+        Box::pin(async move {
+            let response = self.request(request).send().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = futures::stream::try_unfold(response, |mut response| async move {
+                let chunk = response.chunk().await?;
+                Ok(chunk.map(|chunk| (chunk, response)))
+            });
+
+            Ok(StreamingHttpResponse {
+                status,
+                headers,
+                body: Box::pin(body),
+            })
+        })
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        let kind = if error.is_connect() {
+            TransportErrorKind::Connect
+        } else if error.is_timeout() {
+            TransportErrorKind::Timeout
+        } else if error.is_request() || error.is_body() {
+            TransportErrorKind::Request
+        } else {
+            TransportErrorKind::Other
+        };
+
+        Self::new(kind, error.to_string()).with_source(Box::new(error))
+    }
+}
@@ -3,17 +3,31 @@
 //! Core types, utilities, and abstractions for the Adyen Rust library.
 //!
 //! This crate provides the foundational components used across all Adyen API modules:
-//! - Common data types (Amount, Currency, etc.)
-//! - HTTP client abstractions
-//! - Authentication mechanisms
+//! - Common data types (Amount, Currency, country codes, locales, etc.)
+//! - Typed airline, lodging and Level 2/3 additional data
+//! - HTTP client and pluggable transport abstractions
+//! - Middleware around the transport
+//! - Authentication mechanisms, including OAuth for partner applications
 //! - Error handling types
 //! - Configuration management
+//! - Retry policies for transient failures
+//! - Client-side rate limiting
+//! - Redaction of card data and credentials for logging
+//! - Pagination streams for list endpoints
 //!
 //! ## Features
 //!
 //! - `serde` (default): Enable serde serialization support
+//! - `reqwest` (default): Use reqwest as the default HTTP transport
+//! - `tokio` (default): Use tokio timers for retry backoff and rate limiting; disable on
+//!   wasm32, where a timer backed by the JavaScript event loop is used instead
 //! - `rkyv`: Enable zero-copy rkyv serialization support
-//! - `observability`: Enable tracing and metrics collection
+//! - `testing`: Enable mock and record/replay transports for tests
+//! - `observability`: Enable tracing spans and the `metrics` facade recorder
+//! - `blocking`: Enable the blocking client for code without an async runtime
+//! - `decimal` (default): Enable `rust_decimal` conversions for `Amount`, including
+//!   rounding and string parsing constructors
+//! - `config-file`: Enable loading configuration profiles from TOML files
 //!
 //! ## Example
 //!
@@ -36,23 +50,49 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod additional_data;
+pub mod application_info;
+#[cfg(all(feature = "rkyv", feature = "serde"))]
+pub mod archive;
 pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
 pub mod client;
 pub mod config;
 pub mod currency;
 pub mod environment;
 pub mod error;
 pub mod http;
+pub mod middleware;
+pub mod oauth;
+pub mod observability;
+pub mod pagination;
+pub mod profile;
+pub mod rate_limit;
+pub mod redact;
+pub mod retry;
+pub mod runtime;
+pub mod secret;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
+pub mod validation;
 
 // Re-export commonly used types
+pub use application_info::{ApplicationInfo, ExternalPlatform, MerchantApplication};
 pub use auth::{ApiKey, BasicAuth, Credentials};
-pub use client::{ApiResponse, Client, Request};
-pub use config::{Config, ConfigBuilder};
+pub use client::{ApiResponse, Client, Request, IDEMPOTENCY_KEY_HEADER};
+pub use config::{Api, Config, ConfigBuilder, WebhookHmacKey};
 pub use currency::Currency;
 pub use environment::Environment;
 pub use error::{AdyenError, Result};
-pub use types::{Amount, RequestId};
+pub use pagination::{Page, PageIterator, PageLink, PaginatedRequest, PaginationLinks};
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use retry::{Backoff, RetryPolicy};
+pub use secret::SecretString;
+pub use types::{Amount, CountryCode, Locale, Metadata, PspReference, RequestId};
+pub use validation::ValidationErrors;
 
 /// Current version of the Adyen Core library
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
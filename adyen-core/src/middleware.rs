@@ -0,0 +1,258 @@
+//! Middleware wrapping the HTTP transport.
+//!
+//! A [`Middleware`] sees every request attempt after the client prepared it,
+//! authentication included, and decides how to pass it on through [`Next`]:
+//! it can modify the request, inspect or replace the response, or fail the
+//! attempt without sending it. This is the place for audit logging, extra
+//! headers or circuit breaking without forking the client.
+//!
+//! Middleware is added with [`ConfigBuilder::layer`](crate::ConfigBuilder::layer)
+//! or [`Config::with_layer`](crate::Config::with_layer) and runs in the order
+//! it was added, the first middleware being the outermost. The client's own
+//! retries and rate limiting run outside of the stack, so a retried request
+//! passes through every middleware again.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::http::{BoxFuture, HttpRequest, HttpResponse, TransportError};
+//! use adyen_core::middleware::{Middleware, Next};
+//! use adyen_core::ConfigBuilder;
+//!
+//! #[derive(Debug)]
+//! struct AuditLog;
+//!
+//! impl Middleware for AuditLog {
+//!     fn handle<'a>(
+//!         &'a self,
+//!         request: HttpRequest,
+//!         next: Next<'a>,
+//!     ) -> BoxFuture<'a, Result<HttpResponse, TransportError>> {
+//!         Box::pin(async move {
+//!             let url = request.url.clone();
+//!             let response = next.run(request).await;
+//!             if let Ok(response) = &response {
+//!                 println!("{url} -> {}", response.status);
+//!             }
+//!             response
+//!         })
+//!     }
+//! }
+//!
+//! # fn example() -> adyen_core::Result<()> {
+//! let config = ConfigBuilder::new()
+//!     .api_key("your_api_key")?
+//!     .layer(AuditLog)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::http::{
+    BoxFuture, HttpRequest, HttpResponse, HttpTransport, StreamingHttpResponse, TransportError,
+};
+use std::fmt;
+use std::sync::Arc;
+
+/// Behavior injected around every request attempt.
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// Handle a request, usually by passing it on with [`Next::run`].
+    fn handle<'a>(
+        &'a self,
+        request: HttpRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<HttpResponse, TransportError>>;
+
+    /// Handle a request whose response body is streamed, e.g. a report
+    /// download.
+    ///
+    /// The default implementation passes the request on unchanged; override
+    /// it for middleware that must see every request.
+    fn handle_streaming<'a>(
+        &'a self,
+        request: HttpRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<StreamingHttpResponse, TransportError>> {
+        next.run_streaming(request)
+    }
+}
+
+/// The rest of the middleware stack, ending with the transport.
+#[derive(Debug, Clone, Copy)]
+pub struct Next<'a> {
+    inner: &'a dyn HttpTransport,
+}
+
+impl<'a> Next<'a> {
+    /// Pass the request on to the next middleware or the transport.
+    #[must_use]
+    pub fn run(self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse, TransportError>> {
+        self.inner.send(request)
+    }
+
+    /// Pass a streaming request on to the next middleware or the transport.
+    #[must_use]
+    pub fn run_streaming(
+        self,
+        request: HttpRequest,
+    ) -> BoxFuture<'a, Result<StreamingHttpResponse, TransportError>> {
+        self.inner.send_streaming(request)
+    }
+}
+
+/// Wrap a transport in middleware, the first middleware being the outermost.
+pub(crate) fn wrap(
+    transport: Arc<dyn HttpTransport>,
+    layers: &[Arc<dyn Middleware>],
+) -> Arc<dyn HttpTransport> {
+    layers.iter().rev().fold(transport, |inner, middleware| {
+        Arc::new(Layered {
+            middleware: Arc::clone(middleware),
+            inner,
+        })
+    })
+}
+
+/// Transport running a middleware in front of an inner transport.
+#[derive(Debug)]
+struct Layered {
+    middleware: Arc<dyn Middleware>,
+    inner: Arc<dyn HttpTransport>,
+}
+
+impl HttpTransport for Layered {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+        self.middleware.handle(
+            request,
+            Next {
+                inner: self.inner.as_ref(),
+            },
+        )
+    }
+
+    fn send_streaming(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, Result<StreamingHttpResponse, TransportError>> {
+        self.middleware.handle_streaming(
+            request,
+            Next {
+                inner: self.inner.as_ref(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{HeaderMap, HeaderValue, Method, TransportErrorKind};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// Transport answering with the request's headers as the body.
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    impl HttpTransport for EchoTransport {
+        fn send(
+            &self,
+            request: HttpRequest,
+        ) -> BoxFuture<'_, Result<HttpResponse, TransportError>> {
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: 200,
+                    headers: HeaderMap::new(),
+                    body: format!("{:?}", request.headers.get("x-trace")).into_bytes(),
+                })
+            })
+        }
+    }
+
+    type Calls = Arc<Mutex<Vec<&'static str>>>;
+
+    #[derive(Debug)]
+    struct AddHeader(&'static str, Calls);
+
+    impl Middleware for AddHeader {
+        fn handle<'a>(
+            &'a self,
+            mut request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<HttpResponse, TransportError>> {
+            Box::pin(async move {
+                self.1.lock().unwrap().push(self.0);
+                request
+                    .headers
+                    .insert("x-trace", HeaderValue::from_static(self.0));
+                next.run(request).await
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CircuitBreaker {
+        open: AtomicBool,
+    }
+
+    impl Middleware for CircuitBreaker {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<HttpResponse, TransportError>> {
+            Box::pin(async move {
+                if self.open.load(Ordering::SeqCst) {
+                    return Err(TransportError::new(
+                        TransportErrorKind::Connect,
+                        "circuit open",
+                    ));
+                }
+                next.run(request).await
+            })
+        }
+    }
+
+    fn request() -> HttpRequest {
+        HttpRequest {
+            method: Method::Get,
+            url: "https://example.com".to_string(),
+            headers: HeaderMap::new(),
+            body: None,
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let layers: Vec<Arc<dyn Middleware>> = vec![
+            Arc::new(AddHeader("outer", Arc::clone(&calls))),
+            Arc::new(AddHeader("inner", Arc::clone(&calls))),
+        ];
+        let transport = wrap(Arc::new(EchoTransport), &layers);
+
+        let response = transport.send(request()).await.unwrap();
+        assert_eq!(*calls.lock().unwrap(), ["outer", "inner"]);
+        // The innermost middleware has the last word on the request.
+        assert_eq!(response.body, br#"Some("inner")"#);
+
+        // Streaming requests pass through middleware without an override.
+        let response = transport.send_streaming(request()).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(calls.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_can_short_circuit() {
+        let breaker = Arc::new(CircuitBreaker::default());
+        let layers: Vec<Arc<dyn Middleware>> = vec![breaker.clone()];
+        let transport = wrap(Arc::new(EchoTransport), &layers);
+
+        assert!(transport.send(request()).await.is_ok());
+        breaker.open.store(true, Ordering::SeqCst);
+        let error = transport.send(request()).await.unwrap_err();
+        assert!(error.is_connect());
+    }
+}
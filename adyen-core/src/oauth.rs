@@ -0,0 +1,385 @@
+//! OAuth 2.0 authentication for Adyen partner applications.
+//!
+//! Partners acting on behalf of merchants authenticate with access tokens
+//! issued by Adyen's OAuth server instead of API keys. [`OAuthCredentials`]
+//! exchanges an authorization code or refresh token for an access token,
+//! caches it, and refreshes it shortly before it expires. Requests carry the
+//! token in an `Authorization: Bearer` header.
+
+use crate::http::{HttpRequest, HttpTransport, Method};
+use crate::runtime::Instant;
+use crate::{AdyenError, Result, SecretString};
+use base64::prelude::*;
+use futures::lock::Mutex;
+use http::{HeaderMap, HeaderValue};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long before expiry a cached access token is refreshed by default.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// OAuth client credentials and the tokens issued for them.
+///
+/// Clones share the token cache, so clients created from one configuration
+/// refresh the access token once rather than each on their own. The client
+/// secret and tokens are never shown by `Debug` and are zeroized when
+/// dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{oauth::OAuthCredentials, ConfigBuilder, Environment};
+///
+/// # fn example() -> adyen_core::Result<()> {
+/// let oauth = OAuthCredentials::from_refresh_token(
+///     "YOUR_CLIENT_ID",
+///     "YOUR_CLIENT_SECRET",
+///     "YOUR_REFRESH_TOKEN",
+/// )?;
+///
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .oauth(oauth)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OAuthCredentials {
+    client_id: String,
+    client_secret: SecretString,
+    token_url: Option<String>,
+    refresh_margin: Duration,
+    state: Arc<Mutex<TokenState>>,
+}
+
+/// Grant used to obtain the next access token.
+enum Grant {
+    AuthorizationCode {
+        code: SecretString,
+        redirect_uri: String,
+        code_verifier: SecretString,
+    },
+    RefreshToken(SecretString),
+}
+
+struct TokenState {
+    grant: Grant,
+    access_token: Option<SecretString>,
+    expires_at: Option<Instant>,
+}
+
+/// Token endpoint response.
+#[derive(serde::Deserialize)]
+struct TokenResponse {
+    access_token: SecretString,
+    expires_in: Option<u64>,
+    refresh_token: Option<SecretString>,
+}
+
+/// Token endpoint error response.
+#[derive(serde::Deserialize)]
+struct TokenError {
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+impl OAuthCredentials {
+    /// Create credentials that obtain access tokens with a refresh token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values is empty.
+    pub fn from_refresh_token(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Result<Self> {
+        let refresh_token = non_empty(refresh_token.into(), "Refresh token")?;
+        Self::with_grant(
+            client_id.into(),
+            client_secret.into(),
+            Grant::RefreshToken(refresh_token.into()),
+        )
+    }
+
+    /// Create credentials that exchange an authorization code for the first
+    /// access token.
+    ///
+    /// `redirect_uri` and `code_verifier` must be those of the authorization
+    /// request that returned the code. Later tokens are obtained with the
+    /// refresh token returned alongside the first access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the values is empty.
+    pub fn from_authorization_code(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+        code_verifier: impl Into<String>,
+    ) -> Result<Self> {
+        let grant = Grant::AuthorizationCode {
+            code: non_empty(code.into(), "Authorization code")?.into(),
+            redirect_uri: non_empty(redirect_uri.into(), "Redirect URI")?,
+            code_verifier: non_empty(code_verifier.into(), "Code verifier")?.into(),
+        };
+        Self::with_grant(client_id.into(), client_secret.into(), grant)
+    }
+
+    fn with_grant(client_id: String, client_secret: String, grant: Grant) -> Result<Self> {
+        Ok(Self {
+            client_id: non_empty(client_id, "Client ID")?,
+            client_secret: non_empty(client_secret, "Client secret")?.into(),
+            token_url: None,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            state: Arc::new(Mutex::new(TokenState {
+                grant,
+                access_token: None,
+                expires_at: None,
+            })),
+        })
+    }
+
+    /// Use an access token obtained earlier until it expires.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token cache is in use, i.e. if called after the
+    /// credentials were used for a request.
+    #[must_use]
+    pub fn with_access_token(self, access_token: impl Into<String>, expires_in: Duration) -> Self {
+        {
+            let mut state = self
+                .state
+                .try_lock()
+                .expect("access token set while the token cache is in use");
+            state.access_token = Some(SecretString::new(access_token));
+            state.expires_at = Some(Instant::now() + expires_in);
+        }
+        self
+    }
+
+    /// Override the token endpoint, which defaults to the one of the
+    /// configured [environment](crate::Environment::oauth_token_url).
+    #[must_use]
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+
+    /// Set how long before expiry the access token is refreshed, by default
+    /// [`DEFAULT_REFRESH_MARGIN`].
+    #[must_use]
+    pub const fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Get the client ID.
+    #[must_use]
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Get the token endpoint override, if set.
+    #[must_use]
+    pub fn token_url(&self) -> Option<&str> {
+        self.token_url.as_deref()
+    }
+
+    /// Get a valid access token, requesting a new one from the token
+    /// endpoint if none is cached or the cached one is about to expire.
+    ///
+    /// Concurrent callers wait for a single token request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token request fails or is rejected.
+    pub async fn access_token(
+        &self,
+        transport: &dyn HttpTransport,
+        default_token_url: &str,
+        timeout: Duration,
+    ) -> Result<SecretString> {
+        let mut state = self.state.lock().await;
+        if let Some(token) = &state.access_token {
+            let fresh = state
+                .expires_at
+                .is_none_or(|expires_at| Instant::now() + self.refresh_margin < expires_at);
+            if fresh {
+                return Ok(token.clone());
+            }
+        }
+
+        let url = self.token_url.as_deref().unwrap_or(default_token_url);
+        let request = self.token_request(&state.grant, url, timeout)?;
+        let requested_at = Instant::now();
+        let response = transport.send(request).await?;
+
+        if !(200..300).contains(&response.status) {
+            let message = serde_json::from_slice::<TokenError>(&response.body)
+                .ok()
+                .and_then(|e| e.error_description.or(e.error))
+                .unwrap_or_else(|| format!("HTTP {}", response.status));
+            return Err(AdyenError::auth(format!(
+                "OAuth token request failed: {message}"
+            )));
+        }
+        let token: TokenResponse = serde_json::from_slice(&response.body)
+            .map_err(|e| AdyenError::auth(format!("Invalid OAuth token response: {e}")))?;
+
+        if let Some(refresh_token) = token.refresh_token {
+            state.grant = Grant::RefreshToken(refresh_token);
+        }
+        state.expires_at = token
+            .expires_in
+            .map(|seconds| requested_at + Duration::from_secs(seconds));
+        state.access_token = Some(token.access_token.clone());
+        Ok(token.access_token)
+    }
+
+    /// Drop the cached access token, e.g. after it was rejected, so the
+    /// next request obtains a new one.
+    pub async fn invalidate(&self) {
+        let mut state = self.state.lock().await;
+        state.access_token = None;
+        state.expires_at = None;
+    }
+
+    fn token_request(&self, grant: &Grant, url: &str, timeout: Duration) -> Result<HttpRequest> {
+        let mut form = url::form_urlencoded::Serializer::new(String::new());
+        match grant {
+            Grant::AuthorizationCode {
+                code,
+                redirect_uri,
+                code_verifier,
+            } => {
+                form.append_pair("grant_type", "authorization_code")
+                    .append_pair("code", code.expose_secret())
+                    .append_pair("redirect_uri", redirect_uri)
+                    .append_pair("code_verifier", code_verifier.expose_secret());
+            }
+            Grant::RefreshToken(refresh_token) => {
+                form.append_pair("grant_type", "refresh_token")
+                    .append_pair("refresh_token", refresh_token.expose_secret());
+            }
+        }
+        let body = zeroize::Zeroizing::new(form.finish());
+
+        let credentials = zeroize::Zeroizing::new(format!(
+            "{}:{}",
+            self.client_id,
+            self.client_secret.expose_secret()
+        ));
+        let mut authorization = HeaderValue::from_str(&format!(
+            "Basic {}",
+            BASE64_STANDARD.encode(credentials.as_bytes())
+        ))
+        .map_err(|e| AdyenError::auth(format!("Invalid OAuth client credentials: {e}")))?;
+        authorization.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::AUTHORIZATION, authorization);
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        headers.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/json"),
+        );
+
+        Ok(HttpRequest {
+            method: Method::Post,
+            url: url.to_string(),
+            headers,
+            body: Some(body.as_bytes().to_vec()),
+            timeout,
+        })
+    }
+}
+
+impl fmt::Debug for OAuthCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthCredentials")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[REDACTED]")
+            .field("token_url", &self.token_url)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for OAuthCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OAuth(client_id: {}, client_secret: [REDACTED])",
+            self.client_id
+        )
+    }
+}
+
+fn non_empty(value: String, name: &str) -> Result<String> {
+    if value.is_empty() {
+        return Err(AdyenError::auth(format!("{name} cannot be empty")));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oauth_credentials_validation() {
+        assert!(OAuthCredentials::from_refresh_token("", "secret", "refresh").is_err());
+        assert!(OAuthCredentials::from_refresh_token("client", "", "refresh").is_err());
+        assert!(OAuthCredentials::from_refresh_token("client", "secret", "").is_err());
+        assert!(OAuthCredentials::from_authorization_code(
+            "client", "secret", "code", "", "verifier"
+        )
+        .is_err());
+
+        let oauth = OAuthCredentials::from_refresh_token("client", "top_secret", "refresh")
+            .unwrap()
+            .with_token_url("https://oauth.example.com/token");
+        assert_eq!(oauth.client_id(), "client");
+        assert_eq!(oauth.token_url(), Some("https://oauth.example.com/token"));
+        assert!(!format!("{oauth:?}").contains("top_secret"));
+        assert!(!oauth.to_string().contains("top_secret"));
+    }
+
+    #[test]
+    fn test_token_request_form() {
+        let oauth = OAuthCredentials::from_authorization_code(
+            "client",
+            "secret",
+            "code123",
+            "https://example.com/callback",
+            "verifier",
+        )
+        .unwrap();
+        let state = oauth.state.try_lock().unwrap();
+        let request = oauth
+            .token_request(
+                &state.grant,
+                "https://oauth-test.adyen.com/v1/token",
+                Duration::from_secs(10),
+            )
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(request.body.unwrap()).unwrap(),
+            "grant_type=authorization_code&code=code123\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&code_verifier=verifier"
+        );
+        assert_eq!(
+            request.headers.get(http::header::AUTHORIZATION).unwrap(),
+            "Basic Y2xpZW50OnNlY3JldA=="
+        );
+    }
+}
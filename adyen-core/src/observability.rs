@@ -0,0 +1,159 @@
+//! Tracing spans and metrics for API calls.
+//!
+//! With the `tracing` feature (part of `observability`), every API call runs
+//! in an `adyen.request` span with the API, HTTP method, endpoint, merchant
+//! account and, once the response arrives, the PSP reference and status.
+//!
+//! Metrics are reported for every attempt, including retries, to the
+//! [`MetricsRecorder`] set with
+//! [`ConfigBuilder::metrics_recorder`](crate::ConfigBuilder::metrics_recorder).
+//! With the `metrics` feature, [`MetricsFacadeRecorder`] forwards them to the
+//! [`metrics`](https://docs.rs/metrics) facade, e.g. for a Prometheus exporter.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::observability::{MetricsRecorder, RequestMetrics};
+//! use adyen_core::ConfigBuilder;
+//!
+//! #[derive(Debug)]
+//! struct LogRecorder;
+//!
+//! impl MetricsRecorder for LogRecorder {
+//!     fn record(&self, metrics: &RequestMetrics) {
+//!         println!(
+//!             "{} {} -> {:?} in {:?}",
+//!             metrics.method, metrics.endpoint, metrics.status, metrics.latency
+//!         );
+//!     }
+//! }
+//!
+//! # fn example() -> adyen_core::Result<()> {
+//! let config = ConfigBuilder::new()
+//!     .api_key("your_api_key")?
+//!     .metrics_recorder(LogRecorder)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config::Api;
+use crate::http::Method;
+use std::fmt;
+use std::time::Duration;
+
+/// Outcome of a single request attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestMetrics {
+    /// API the request was sent to, if known.
+    pub api: Option<Api>,
+    /// HTTP method.
+    pub method: Method,
+    /// Request path with IDs replaced by `{id}`, e.g.
+    /// `/checkout/v71/payments/{id}/captures`.
+    pub endpoint: String,
+    /// Response status, or `None` if no response was received.
+    pub status: Option<u16>,
+    /// Time until the response headers arrived or the attempt failed.
+    pub latency: Duration,
+    /// Zero-based attempt number; retries have an attempt above zero.
+    pub attempt: u32,
+}
+
+/// Receiver for request metrics.
+///
+/// Called once per attempt, so implementations should be cheap and must not
+/// block.
+pub trait MetricsRecorder: fmt::Debug + Send + Sync {
+    /// Record the outcome of a request attempt.
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+/// [`MetricsRecorder`] reporting to the [`metrics`](https://docs.rs/metrics)
+/// facade.
+///
+/// Records the `adyen_request_duration_seconds` histogram and the
+/// `adyen_requests_total` counter, labelled with `api`, `method`, `endpoint`
+/// and `status` (`error` if no response was received).
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsFacadeRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsFacadeRecorder {
+    fn record(&self, request: &RequestMetrics) {
+        let labels = [
+            ("api", api_label(request.api)),
+            ("method", request.method.to_string()),
+            ("endpoint", request.endpoint.clone()),
+            (
+                "status",
+                request
+                    .status
+                    .map_or_else(|| "error".to_string(), |status| status.to_string()),
+            ),
+        ];
+        metrics::histogram!("adyen_request_duration_seconds", request.latency, &labels);
+        metrics::increment_counter!("adyen_requests_total", &labels);
+    }
+}
+
+/// Label for an API, e.g. `Checkout`, or `unknown` for generic clients.
+#[must_use]
+pub fn api_label(api: Option<Api>) -> String {
+    api.map_or_else(|| "unknown".to_string(), |api| format!("{api:?}"))
+}
+
+/// Path of a URL with IDs replaced by `{id}`, to keep the number of distinct
+/// endpoints low.
+///
+/// Path segments of 12 or more characters containing a digit, such as PSP
+/// references and balance account IDs, count as IDs.
+#[must_use]
+pub fn endpoint_label(url: &str) -> String {
+    let path = url::Url::parse(url).map_or_else(
+        |_| url.split('?').next().unwrap_or_default().to_string(),
+        |url| url.path().to_string(),
+    );
+    path.split('/')
+        .map(|segment| {
+            if segment.len() >= 12 && segment.bytes().any(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_label() {
+        assert_eq!(
+            endpoint_label(
+                "https://checkout-test.adyen.com/v71/payments/8815329842815468/captures"
+            ),
+            "/v71/payments/{id}/captures"
+        );
+        assert_eq!(
+            endpoint_label("https://balanceplatform-api-test.adyen.com/bcl/v2/balanceAccounts/BA3227C223222B5FTD2KR6TJ4?limit=10"),
+            "/bcl/v2/balanceAccounts/{id}"
+        );
+        assert_eq!(
+            endpoint_label("https://checkout-test.adyen.com/v71/paymentMethods"),
+            "/v71/paymentMethods"
+        );
+        assert_eq!(endpoint_label("/v71/payments?x=1"), "/v71/payments");
+    }
+
+    #[test]
+    fn test_api_label() {
+        assert_eq!(api_label(Some(Api::Checkout)), "Checkout");
+        assert_eq!(api_label(None), "unknown");
+    }
+}
@@ -0,0 +1,360 @@
+//! Pagination for list endpoints that take `pageNumber` and `pageSize`.
+//!
+//! Adyen's Management and Balance Platform list endpoints return one page of
+//! results at a time. [`PageIterator`] fetches the pages in order, and
+//! [`PageIterator::into_stream`] turns them into a [`Stream`] of items so
+//! callers do not have to track page numbers themselves. When a response
+//! includes `_links`, the `next` link decides whether another page follows.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::{Client, PageIterator, PaginatedRequest};
+//! use futures::TryStreamExt;
+//!
+//! # async fn example(client: Client) -> adyen_core::Result<()> {
+//! let pages = PageIterator::<serde_json::Value>::new(
+//!     client,
+//!     "https://management-test.adyen.com/v3/companies/COMPANY_ID/merchants",
+//!     PaginatedRequest::new(100),
+//! );
+//! let merchants: Vec<serde_json::Value> = pages.into_stream().try_collect().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{AdyenError, Client, Result};
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Page selection for a paginated list request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginatedRequest {
+    page_number: u32,
+    page_size: u32,
+}
+
+impl PaginatedRequest {
+    /// Page size used by Adyen when none is given.
+    pub const DEFAULT_PAGE_SIZE: u32 = 10;
+
+    /// Largest page size Adyen accepts.
+    pub const MAX_PAGE_SIZE: u32 = 100;
+
+    /// Request the first page with the given page size.
+    ///
+    /// The page size is clamped to `1..=MAX_PAGE_SIZE`.
+    #[must_use]
+    pub fn new(page_size: u32) -> Self {
+        Self {
+            page_number: 1,
+            page_size: page_size.clamp(1, Self::MAX_PAGE_SIZE),
+        }
+    }
+
+    /// Start at the given page number, counting from 1.
+    #[must_use]
+    pub fn page(mut self, page_number: u32) -> Self {
+        self.page_number = page_number.max(1);
+        self
+    }
+
+    /// The page number, counting from 1.
+    #[must_use]
+    pub const fn page_number(&self) -> u32 {
+        self.page_number
+    }
+
+    /// The number of items per page.
+    #[must_use]
+    pub const fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// The request for the page after this one.
+    #[must_use]
+    pub const fn next_page(&self) -> Self {
+        Self {
+            page_number: self.page_number + 1,
+            page_size: self.page_size,
+        }
+    }
+
+    /// Append the `pageNumber` and `pageSize` query parameters to a URL.
+    #[must_use]
+    pub fn apply(&self, url: &str) -> String {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        format!(
+            "{url}{separator}pageNumber={}&pageSize={}",
+            self.page_number, self.page_size
+        )
+    }
+}
+
+impl Default for PaginatedRequest {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// One page of results from a paginated list endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    /// The items on this page.
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+
+    /// The total number of items across all pages, if reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items_total: Option<u32>,
+
+    /// The total number of pages, if reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages_total: Option<u32>,
+
+    /// Links to this page and the first, last, previous and next pages, if
+    /// reported.
+    #[serde(rename = "_links", default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PaginationLinks>,
+}
+
+impl<T> Page<T> {
+    /// Whether there is a page after this one, given the request that
+    /// returned it.
+    ///
+    /// Uses the `next` link when `_links` are reported, then `pagesTotal`,
+    /// and otherwise assumes more pages follow a full page.
+    #[must_use]
+    pub fn has_next(&self, request: &PaginatedRequest) -> bool {
+        if let Some(links) = &self.links {
+            return links.next.is_some();
+        }
+        if self.data.is_empty() {
+            return false;
+        }
+        match self.pages_total {
+            Some(pages_total) => request.page_number() < pages_total,
+            None => self.data.len() >= request.page_size() as usize,
+        }
+    }
+
+    /// Get the request for the page after this one, or `None` on the last
+    /// page, given the request that returned it.
+    ///
+    /// Follows the `next` link when it has page parameters, and otherwise
+    /// requests the following page number.
+    #[must_use]
+    pub fn next_page(&self, request: &PaginatedRequest) -> Option<PaginatedRequest> {
+        if !self.has_next(request) {
+            return None;
+        }
+        let next_link = self.links.as_ref().and_then(|links| links.next.as_ref());
+        Some(
+            next_link
+                .and_then(PageLink::page_request)
+                .unwrap_or_else(|| request.next_page()),
+        )
+    }
+}
+
+/// Links to the pages of a list result.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct PaginationLinks {
+    /// Link to the first page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first: Option<PageLink>,
+    /// Link to the last page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last: Option<PageLink>,
+    /// Link to the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<PageLink>,
+    /// Link to the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<PageLink>,
+    /// Link to this page.
+    #[serde(rename = "self", skip_serializing_if = "Option::is_none")]
+    pub self_link: Option<PageLink>,
+}
+
+/// A link to a page of a list result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct PageLink {
+    /// URL of the page.
+    pub href: Box<str>,
+}
+
+impl PageLink {
+    /// Get the page request from the `pageNumber` and `pageSize` parameters
+    /// of the link, or `None` if the link has no valid page number.
+    #[must_use]
+    pub fn page_request(&self) -> Option<PaginatedRequest> {
+        let url = url::Url::parse(&self.href).ok()?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse::<u32>().ok())
+        };
+        let page_size = param("pageSize").unwrap_or(PaginatedRequest::DEFAULT_PAGE_SIZE);
+        Some(PaginatedRequest::new(page_size).page(param("pageNumber")?))
+    }
+}
+
+/// Fetches the pages of a list endpoint in order.
+#[derive(Debug)]
+pub struct PageIterator<T> {
+    client: Client,
+    url: String,
+    next: Option<PaginatedRequest>,
+    last_page_number: Option<u32>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> PageIterator<T> {
+    /// Iterate over the pages of `url`, starting at `request`.
+    ///
+    /// `url` must not contain `pageNumber` or `pageSize` parameters; other
+    /// query parameters are kept.
+    pub fn new(client: Client, url: impl Into<String>, request: PaginatedRequest) -> Self {
+        Self {
+            client,
+            url: url.into(),
+            next: Some(request),
+            last_page_number: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Fetch the next page, or `None` once all pages were fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the response cannot be parsed,
+    /// or the `next` link of the previous page does not move forward, which
+    /// would otherwise fetch the same pages forever.
+    pub async fn next_page(&mut self) -> Result<Option<Page<T>>> {
+        let Some(request) = self.next.take() else {
+            return Ok(None);
+        };
+        if let Some(last) = self.last_page_number {
+            if request.page_number() <= last {
+                return Err(AdyenError::generic(format!(
+                    "Next page link of page {last} points to page {}",
+                    request.page_number()
+                )));
+            }
+        }
+        self.last_page_number = Some(request.page_number());
+
+        let response = self
+            .client
+            .get::<Page<T>>(&request.apply(&self.url))
+            .await?;
+        let page = response.data;
+        self.next = page.next_page(&request);
+        Ok(Some(page))
+    }
+
+    /// Turn the pages into a stream of items.
+    ///
+    /// The stream fetches each page when the items of the previous one are
+    /// consumed, and ends after the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<T>> {
+        stream::try_unfold(self, |mut pages| async move {
+            let page = pages.next_page().await?;
+            Ok::<_, AdyenError>(page.map(|page| {
+                let items = page.data.into_iter().map(Ok::<T, AdyenError>);
+                (stream::iter(items), pages)
+            }))
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginated_request() {
+        let request = PaginatedRequest::new(500);
+        assert_eq!(request.page_size(), PaginatedRequest::MAX_PAGE_SIZE);
+        assert_eq!(request.page_number(), 1);
+        assert_eq!(
+            request
+                .next_page()
+                .apply("https://example.com/v3/merchants"),
+            "https://example.com/v3/merchants?pageNumber=2&pageSize=100"
+        );
+        assert_eq!(
+            PaginatedRequest::default().apply("https://example.com/v3/stores?merchantId=M1"),
+            "https://example.com/v3/stores?merchantId=M1&pageNumber=1&pageSize=10"
+        );
+    }
+
+    #[test]
+    fn test_page_has_next() {
+        let request = PaginatedRequest::new(2);
+        let page: Page<u32> =
+            serde_json::from_str(r#"{"data": [1, 2], "itemsTotal": 3, "pagesTotal": 2}"#).unwrap();
+        assert!(page.has_next(&request));
+        assert!(!page.has_next(&request.next_page()));
+
+        let unknown_total: Page<u32> = serde_json::from_str(r#"{"data": [1, 2]}"#).unwrap();
+        assert!(unknown_total.has_next(&request));
+
+        let empty: Page<u32> = serde_json::from_str("{}").unwrap();
+        assert!(!empty.has_next(&request));
+    }
+
+    #[test]
+    fn test_page_follows_links() {
+        let request = PaginatedRequest::new(2);
+        let page: Page<u32> = serde_json::from_str(
+            r#"{
+                "data": [1, 2],
+                "pagesTotal": 5,
+                "_links": {
+                    "self": {"href": "https://example.com/v3/stores?pageNumber=1&pageSize=2"},
+                    "next": {"href": "https://example.com/v3/stores?merchantId=M%261&pageSize=2&pageNumber=2"}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(page.has_next(&request));
+        assert_eq!(page.next_page(&request), Some(request.next_page()));
+
+        let last: Page<u32> = serde_json::from_str(
+            r#"{"data": [1, 2], "_links": {"self": {"href": "https://example.com/v3/stores"}}}"#,
+        )
+        .unwrap();
+        assert!(!last.has_next(&request));
+        assert_eq!(last.next_page(&request), None);
+
+        let link = PageLink {
+            href: "https://example.com/v3/stores?pageNumber=3".into(),
+        };
+        assert_eq!(
+            link.page_request(),
+            Some(PaginatedRequest::default().page(3))
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Configuration from environment variables and profile files.
+//!
+//! Deployments can configure the client without building a [`Config`] in
+//! code: [`Config::from_env`] reads `ADYEN_*` environment variables, and
+//! with the `config-file` feature [`Config::from_file`] reads a named
+//! profile from a TOML file.
+//!
+//! | Setting | Environment variable | File key |
+//! |---------|----------------------|----------|
+//! | Environment (`test` or `live`) | `ADYEN_ENVIRONMENT` | `environment` |
+//! | Live URL prefix | `ADYEN_LIVE_URL_PREFIX` | `live_url_prefix` |
+//! | API key | `ADYEN_API_KEY` | `api_key` |
+//! | Basic auth | `ADYEN_USERNAME`, `ADYEN_PASSWORD` | `username`, `password` |
+//! | API key for one API | `ADYEN_<API>_API_KEY`, e.g. `ADYEN_MANAGEMENT_API_KEY` | `[<profile>.api_keys]` table, e.g. `management = "..."` |
+//! | Webhook HMAC keys | `ADYEN_HMAC_KEYS`, comma-separated | `hmac_keys` list |
+//! | Timeout in seconds | `ADYEN_TIMEOUT_SECS` | `timeout_secs` |
+//!
+//! API names are those of [`Api::name`]. HMAC keys are hex-encoded and may
+//! be prefixed with an identifier and `=`, e.g. `2024-06=44782DEF...`.
+//!
+//! A file holds one table per profile. Profiles named `live` use the live
+//! environment unless `environment` says otherwise:
+//!
+//! ```toml
+//! [test]
+//! api_key = "AQEyhmfxK..."
+//! hmac_keys = ["44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056"]
+//!
+//! [live]
+//! live_url_prefix = "1797a841fbb37ca7-AdyenDemo"
+//! api_key = "AQEyhmfxL..."
+//! hmac_keys = ["2024-06=44782DEF...", "2024-01=A1B2C3..."]
+//!
+//! [live.api_keys]
+//! management = "AQEthmfxM..."
+//! ```
+
+use crate::{AdyenError, Api, Config, ConfigBuilder, Environment, Result, WebhookHmacKey};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Environment variable selecting the profile read by [`Config::from_file`].
+pub const PROFILE_ENV_VAR: &str = "ADYEN_PROFILE";
+
+/// Profile used by [`Config::from_file`] if [`PROFILE_ENV_VAR`] is not set.
+pub const DEFAULT_PROFILE: &str = "test";
+
+/// Settings of a profile, from environment variables or a file.
+#[derive(Default)]
+#[cfg_attr(
+    feature = "config-file",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+struct Profile {
+    environment: Option<String>,
+    live_url_prefix: Option<String>,
+    api_key: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    #[cfg_attr(feature = "config-file", serde(default))]
+    api_keys: HashMap<String, String>,
+    #[cfg_attr(feature = "config-file", serde(default))]
+    hmac_keys: Vec<String>,
+    timeout_secs: Option<u64>,
+}
+
+impl Profile {
+    /// Read the settings from `ADYEN_*` variables through `lookup`.
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let timeout_secs = lookup("ADYEN_TIMEOUT_SECS")
+            .map(|value| {
+                value.trim().parse().map_err(|_| {
+                    AdyenError::config("ADYEN_TIMEOUT_SECS must be a number of seconds")
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            environment: lookup("ADYEN_ENVIRONMENT"),
+            live_url_prefix: lookup("ADYEN_LIVE_URL_PREFIX"),
+            api_key: lookup("ADYEN_API_KEY"),
+            username: lookup("ADYEN_USERNAME"),
+            password: lookup("ADYEN_PASSWORD"),
+            api_keys: Api::ALL
+                .into_iter()
+                .filter_map(|api| {
+                    let var = format!("ADYEN_{}_API_KEY", api.name().to_ascii_uppercase());
+                    lookup(&var).map(|key| (api.name().to_string(), key))
+                })
+                .collect(),
+            hmac_keys: lookup("ADYEN_HMAC_KEYS")
+                .map(|keys| keys.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
+            timeout_secs,
+        })
+    }
+
+    /// Create a configuration builder from the settings of the profile `name`.
+    fn into_builder(self, name: &str) -> Result<ConfigBuilder> {
+        let live = match self.environment.as_deref().map(str::trim) {
+            Some("live") => true,
+            Some("test") => false,
+            Some(other) => {
+                return Err(AdyenError::config(format!(
+                    "Unknown environment '{other}', expected 'test' or 'live'"
+                )))
+            }
+            None => name == "live",
+        };
+        let environment = if live {
+            let prefix = self.live_url_prefix.ok_or_else(|| {
+                AdyenError::config("A live URL prefix is required for the live environment")
+            })?;
+            Environment::live(prefix)?
+        } else {
+            Environment::test()
+        };
+
+        let mut builder = ConfigBuilder::new().environment(environment);
+        match (self.api_key, self.username, self.password) {
+            (Some(api_key), _, _) => builder = builder.api_key(api_key)?,
+            (None, Some(username), Some(password)) => {
+                builder = builder.basic_auth(username, password)?;
+            }
+            (None, Some(_), None) | (None, None, Some(_)) => {
+                return Err(AdyenError::config(
+                    "Basic authentication needs both a username and a password",
+                ))
+            }
+            (None, None, None) => {}
+        }
+        for (name, api_key) in self.api_keys {
+            let api = Api::from_name(&name)
+                .ok_or_else(|| AdyenError::config(format!("Unknown API '{name}'")))?;
+            builder = builder.api_key_for(api, api_key)?;
+        }
+        for entry in &self.hmac_keys {
+            for key in WebhookHmacKey::parse_list(entry) {
+                builder = builder.hmac_key(key);
+            }
+        }
+        if let Some(seconds) = self.timeout_secs {
+            builder = builder.timeout(Duration::from_secs(seconds));
+        }
+        Ok(builder)
+    }
+}
+
+impl ConfigBuilder {
+    /// Create a builder from `ADYEN_*` environment variables.
+    ///
+    /// See the [module documentation](crate::profile) for the variables.
+    /// Settings can be changed on the returned builder, e.g. to add
+    /// middleware.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable holds an invalid value.
+    pub fn from_env() -> Result<Self> {
+        Profile::from_lookup(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))?
+            .into_builder("")
+    }
+
+    /// Create a builder from a profile file, using the profile named by
+    /// `ADYEN_PROFILE` or `test` if it is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not valid, or has no
+    /// such profile.
+    #[cfg(feature = "config-file")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let profile = std::env::var(PROFILE_ENV_VAR)
+            .ok()
+            .filter(|profile| !profile.is_empty());
+        Self::from_file_profile(path, profile.as_deref().unwrap_or(DEFAULT_PROFILE))
+    }
+
+    /// Create a builder from the named profile of a profile file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not valid, or has no
+    /// such profile.
+    #[cfg(feature = "config-file")]
+    pub fn from_file_profile(path: impl AsRef<std::path::Path>, profile: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| AdyenError::config(format!("Cannot read {}: {e}", path.display())))?;
+        Self::from_toml_profile(&contents, profile)
+            .map_err(|e| AdyenError::config(format!("{}: {e}", path.display())))
+    }
+
+    #[cfg(feature = "config-file")]
+    fn from_toml_profile(contents: &str, profile: &str) -> Result<Self> {
+        let mut profiles: HashMap<String, Profile> = toml::from_str(contents)
+            .map_err(|e| AdyenError::config(format!("Invalid profile file: {e}")))?;
+        profiles
+            .remove(profile)
+            .ok_or_else(|| AdyenError::config(format!("Profile '{profile}' not found")))?
+            .into_builder(profile)
+    }
+}
+
+impl Config {
+    /// Create a configuration from `ADYEN_*` environment variables.
+    ///
+    /// See the [module documentation](crate::profile) for the variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable holds an invalid value or no
+    /// credentials are set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use adyen_core::Config;
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// // ADYEN_ENVIRONMENT=live
+    /// // ADYEN_LIVE_URL_PREFIX=1797a841fbb37ca7-AdyenDemo
+    /// // ADYEN_API_KEY=AQEyhmfxK...
+    /// let config = Config::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        ConfigBuilder::from_env()?.build()
+    }
+
+    /// Create a configuration from a profile file, using the profile named
+    /// by `ADYEN_PROFILE` or `test` if it is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not valid, has no
+    /// such profile, or the profile has no credentials.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use adyen_core::Config;
+    ///
+    /// # fn example() -> adyen_core::Result<()> {
+    /// let config = Config::from_file("adyen.toml")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "config-file")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        ConfigBuilder::from_file(path)?.build()
+    }
+
+    /// Create a configuration from the named profile of a profile file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not valid, has no
+    /// such profile, or the profile has no credentials.
+    #[cfg(feature = "config-file")]
+    pub fn from_file_profile(path: impl AsRef<std::path::Path>, profile: &str) -> Result<Self> {
+        ConfigBuilder::from_file_profile(path, profile)?.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_vars(vars: &[(&str, &str)]) -> Result<Config> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect();
+        Profile::from_lookup(|name| vars.get(name).cloned())?
+            .into_builder("")?
+            .build()
+    }
+
+    #[test]
+    fn test_config_from_env_vars() {
+        let config = from_vars(&[
+            ("ADYEN_ENVIRONMENT", "live"),
+            ("ADYEN_LIVE_URL_PREFIX", "1797a841fbb37ca7-AdyenDemo"),
+            ("ADYEN_API_KEY", "default_key_12345"),
+            ("ADYEN_MANAGEMENT_API_KEY", "management_key_12345"),
+            ("ADYEN_HMAC_KEYS", "2024-06=44782DEF,2024-01=A1B2C3D4"),
+            ("ADYEN_TIMEOUT_SECS", "30"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            config.environment(),
+            &Environment::live("1797a841fbb37ca7-AdyenDemo").unwrap()
+        );
+        let key_for = |api| config.credentials_for(api).as_api_key().unwrap().as_str();
+        assert_eq!(key_for(Api::Management), "management_key_12345");
+        assert_eq!(key_for(Api::Checkout), "default_key_12345");
+        assert_eq!(config.hmac_keys().len(), 2);
+        assert_eq!(config.hmac_keys()[1].id(), Some("2024-01"));
+        assert_eq!(config.timeout(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_config_from_env_vars_errors() {
+        assert!(from_vars(&[]).is_err());
+        assert!(from_vars(&[
+            ("ADYEN_ENVIRONMENT", "live"),
+            ("ADYEN_API_KEY", "default_key_12345")
+        ])
+        .is_err());
+        assert!(from_vars(&[
+            ("ADYEN_ENVIRONMENT", "staging"),
+            ("ADYEN_API_KEY", "default_key_12345")
+        ])
+        .is_err());
+        assert!(from_vars(&[("ADYEN_USERNAME", "ws@Company.Test")]).is_err());
+
+        let config = from_vars(&[
+            ("ADYEN_USERNAME", "ws@Company.Test"),
+            ("ADYEN_PASSWORD", "password"),
+        ])
+        .unwrap();
+        assert!(config.credentials().is_basic());
+        assert!(config.environment().is_test());
+    }
+
+    #[cfg(feature = "config-file")]
+    #[test]
+    fn test_config_from_file_profiles() {
+        let contents = r#"
+            [test]
+            api_key = "test_key_12345"
+
+            [live]
+            live_url_prefix = "1797a841fbb37ca7-AdyenDemo"
+            api_key = "live_key_12345"
+            hmac_keys = ["2024-06=44782DEF", "A1B2C3D4"]
+
+            [live.api_keys]
+            balance_platform = "platform_key_12345"
+        "#;
+
+        let test = ConfigBuilder::from_toml_profile(contents, "test")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(test.environment().is_test());
+        assert!(test.hmac_keys().is_empty());
+
+        let live = ConfigBuilder::from_toml_profile(contents, "live")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(live.environment().is_live());
+        assert_eq!(
+            live.credentials_for(Api::BalancePlatform)
+                .as_api_key()
+                .unwrap()
+                .as_str(),
+            "platform_key_12345"
+        );
+        assert_eq!(live.hmac_keys()[1].id(), None);
+
+        assert!(ConfigBuilder::from_toml_profile(contents, "staging").is_err());
+        assert!(ConfigBuilder::from_toml_profile("[test]\napi_kee = \"x\"", "test").is_err());
+        assert!(ConfigBuilder::from_file_profile("/nonexistent/adyen.toml", "test").is_err());
+    }
+}
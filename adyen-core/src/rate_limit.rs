@@ -0,0 +1,278 @@
+//! Client-side rate limiting.
+//!
+//! Adyen enforces rate limits per endpoint and answers requests over the limit
+//! with HTTP 429. A [`RateLimit`] set with
+//! [`ConfigBuilder::rate_limit`](crate::ConfigBuilder::rate_limit) spaces
+//! requests out before they are sent, using a token bucket shared by every
+//! client created from the configuration and by their clones.
+//!
+//! When Adyen answers with a `Retry-After` header, retries wait for the given
+//! time, and with a rate limit configured all other requests wait as well.
+
+use crate::http::HeaderMap;
+use crate::runtime::Instant;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Number of requests allowed per period.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{rate_limit::RateLimit, ConfigBuilder};
+///
+/// # fn example() -> adyen_core::Result<()> {
+/// // Send at most 10 requests per second, in bursts of up to 20.
+/// let config = ConfigBuilder::new()
+///     .api_key("your_api_key")?
+///     .rate_limit(RateLimit::per_second(10).burst(20))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    requests: u32,
+    period: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Allow `requests` requests per `period`, in bursts of up to `requests`.
+    ///
+    /// Zero requests or a zero period are treated as one request per second.
+    #[must_use]
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let (requests, period) = if requests == 0 || period.is_zero() {
+            (1, Duration::from_secs(1))
+        } else {
+            (requests, period)
+        };
+        Self {
+            requests,
+            period,
+            burst: requests,
+        }
+    }
+
+    /// Allow `requests` requests per second.
+    #[must_use]
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Set how many requests may be sent at once after the limiter was idle.
+    ///
+    /// A value of zero is treated as one.
+    #[must_use]
+    pub const fn burst(mut self, burst: u32) -> Self {
+        self.burst = if burst == 0 { 1 } else { burst };
+        self
+    }
+
+    /// Get the number of requests allowed per period.
+    #[must_use]
+    pub const fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    /// Get the period.
+    #[must_use]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Get the burst size.
+    #[must_use]
+    pub const fn burst_size(&self) -> u32 {
+        self.burst
+    }
+
+    fn tokens_per_second(self) -> f64 {
+        f64::from(self.requests) / self.period.as_secs_f64()
+    }
+}
+
+/// Token bucket enforcing a [`RateLimit`].
+///
+/// Clones share the same bucket.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    state: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Create a limiter that starts with a full bucket.
+    #[must_use]
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            state: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                refilled_at: Instant::now(),
+                paused_until: None,
+            })),
+        }
+    }
+
+    /// Get the enforced rate limit.
+    #[must_use]
+    pub const fn limit(&self) -> &RateLimit {
+        &self.limit
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            crate::runtime::sleep(wait).await;
+        }
+    }
+
+    /// Take a token if one is available, or return how long to wait for one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the time until a token is available if none is available now.
+    pub fn try_acquire(&self) -> std::result::Result<(), Duration> {
+        self.try_acquire_at(Instant::now())
+    }
+
+    /// Hold back all requests for `duration`, e.g. as asked by a `Retry-After`
+    /// header.
+    ///
+    /// An existing longer pause is kept.
+    pub fn pause_for(&self, duration: Duration) {
+        self.pause_at(Instant::now(), duration);
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> std::result::Result<(), Duration> {
+        let mut bucket = self.lock();
+
+        if let Some(paused_until) = bucket.paused_until {
+            if paused_until > now {
+                return Err(paused_until - now);
+            }
+            bucket.paused_until = None;
+        }
+
+        let rate = self.limit.tokens_per_second();
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * rate).min(f64::from(self.limit.burst));
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+
+    fn pause_at(&self, now: Instant, duration: Duration) {
+        let until = now + duration;
+        let mut bucket = self.lock();
+        if bucket
+            .paused_until
+            .is_none_or(|paused_until| paused_until < until)
+        {
+            bucket.paused_until = Some(until);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Bucket> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Parse the `Retry-After` header, given either in seconds or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get("retry-after")?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.signed_duration_since(chrono::Utc::now());
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::HeaderValue;
+
+    #[test]
+    fn test_token_bucket() {
+        let limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(1)));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at(start).is_ok());
+        assert!(limiter.try_acquire_at(start).is_ok());
+        assert_eq!(
+            limiter.try_acquire_at(start),
+            Err(Duration::from_millis(500))
+        );
+
+        // Tokens refill at two per second, up to the burst size.
+        assert!(limiter
+            .try_acquire_at(start + Duration::from_millis(500))
+            .is_ok());
+        let later = start + Duration::from_secs(10);
+        assert!(limiter.try_acquire_at(later).is_ok());
+        assert!(limiter.try_acquire_at(later).is_ok());
+        assert!(limiter.try_acquire_at(later).is_err());
+    }
+
+    #[test]
+    fn test_pause_is_shared_by_clones() {
+        let limiter = RateLimiter::new(RateLimit::per_second(100).burst(5));
+        let clone = limiter.clone();
+        let start = Instant::now();
+
+        clone.pause_at(start, Duration::from_secs(2));
+        clone.pause_at(start, Duration::from_secs(1));
+        assert_eq!(
+            limiter.try_acquire_at(start + Duration::from_secs(1)),
+            Err(Duration::from_secs(1))
+        );
+        assert!(limiter
+            .try_acquire_at(start + Duration::from_secs(2))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_defaults() {
+        let limit = RateLimit::new(0, Duration::ZERO);
+        assert_eq!(limit.requests(), 1);
+        assert_eq!(limit.period(), Duration::from_secs(1));
+        assert_eq!(RateLimit::per_second(10).burst_size(), 10);
+        assert_eq!(RateLimit::per_second(10).burst(0).burst_size(), 1);
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert("Retry-After", HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            "Retry-After",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert("Retry-After", HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+}
@@ -0,0 +1,268 @@
+//! Redaction of sensitive fields for logging.
+//!
+//! With logging enabled ([`ConfigBuilder::enable_logging`](crate::ConfigBuilder::enable_logging))
+//! and the `tracing` feature (part of `observability`), the client logs every
+//! request and response body at debug level. Bodies are logged as JSON with
+//! card numbers, security codes, IBANs, credentials and similar fields
+//! replaced by `[REDACTED]`, so logs stay out of PCI scope.
+//!
+//! Fields are matched by path: a rule such as `paymentMethod.number` matches a
+//! `number` field directly inside a `paymentMethod` object at any depth, and
+//! `*` matches any single field. Arrays are transparent, so `splits.account`
+//! also matches the accounts of every split.
+//!
+//! The same engine redacts any serializable type through [`Redact`], so all
+//! API crates' request and response types can be logged safely.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::redact::{Redact, Redactor};
+//! use serde_json::json;
+//!
+//! let request = json!({
+//!     "merchantAccount": "TestMerchant",
+//!     "paymentMethod": {
+//!         "type": "scheme",
+//!         "number": "4111111111111111",
+//!         "cvc": "737"
+//!     }
+//! });
+//!
+//! let logged = request.redacted();
+//! assert!(logged.contains("TestMerchant"));
+//! assert!(!logged.contains("4111111111111111"));
+//! assert!(!logged.contains("737"));
+//!
+//! // Merchant-specific fields can be added to the default rules.
+//! let redactor = Redactor::default().with_rule("metadata.customerSsn");
+//! assert!(!redactor.redact_str(r#"{"metadata":{"customerSsn":"123"}}"#).contains("123"));
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Replacement for redacted values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Fields redacted by [`Redactor::default`].
+pub const DEFAULT_RULES: &[&str] = &[
+    // Card data
+    "paymentMethod.number",
+    "card.number",
+    "cardNumber",
+    "cvc",
+    "expiryMonth",
+    "expiryYear",
+    "encryptedCardNumber",
+    "encryptedSecurityCode",
+    "encryptedExpiryMonth",
+    "encryptedExpiryYear",
+    "encryptedPassword",
+    "threeDS2Token",
+    // Bank accounts
+    "iban",
+    "bankAccountNumber",
+    "accountNumber",
+    "bankAccount.number",
+    "bankAccountDetails.iban",
+    // Credentials
+    "apiKey",
+    "password",
+    "hmacKey",
+    "hmacSignature",
+    "clientKey",
+    "secret",
+    "token",
+];
+
+/// Field-path based redaction engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redactor {
+    rules: Vec<Vec<String>>,
+}
+
+impl Redactor {
+    /// Create a redactor without any rules.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule redacting the fields matching a dotted path.
+    ///
+    /// Field names are compared case-insensitively.
+    #[must_use]
+    pub fn with_rule(mut self, path: &str) -> Self {
+        self.rules.push(
+            path.split('.')
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_ascii_lowercase)
+                .collect(),
+        );
+        self
+    }
+
+    /// Redact matching fields of a JSON value in place.
+    pub fn redact_value(&self, value: &mut Value) {
+        self.redact_at(value, &mut Vec::new());
+    }
+
+    /// Redact a JSON document, returning the redacted JSON.
+    ///
+    /// Text that is not JSON is replaced entirely, as it cannot be inspected.
+    #[must_use]
+    pub fn redact_str(&self, text: &str) -> String {
+        if text.trim().is_empty() {
+            return String::new();
+        }
+        match serde_json::from_str::<Value>(text) {
+            Ok(mut value) => {
+                self.redact_value(&mut value);
+                value.to_string()
+            }
+            Err(_) => format!("{REDACTED} ({} bytes of non-JSON content)", text.len()),
+        }
+    }
+
+    /// Serialize a value to JSON with matching fields redacted.
+    #[must_use]
+    pub fn redact<T: Serialize + ?Sized>(&self, value: &T) -> String {
+        match serde_json::to_value(value) {
+            Ok(mut value) => {
+                self.redact_value(&mut value);
+                value.to_string()
+            }
+            Err(_) => REDACTED.to_string(),
+        }
+    }
+
+    fn redact_at(&self, value: &mut Value, path: &mut Vec<String>) {
+        match value {
+            Value::Object(fields) => {
+                for (name, field) in fields.iter_mut() {
+                    path.push(name.to_ascii_lowercase());
+                    if self.matches(path) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact_at(field, path);
+                    }
+                    path.pop();
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.redact_at(item, path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn matches(&self, path: &[String]) -> bool {
+        self.rules.iter().any(|rule| {
+            rule.len() <= path.len()
+                && rule
+                    .iter()
+                    .zip(&path[path.len() - rule.len()..])
+                    .all(|(expected, name)| expected == "*" || expected == name)
+        })
+    }
+}
+
+impl Default for Redactor {
+    /// Redactor with the [`DEFAULT_RULES`].
+    fn default() -> Self {
+        DEFAULT_RULES
+            .iter()
+            .fold(Self::empty(), |redactor, rule| redactor.with_rule(rule))
+    }
+}
+
+/// Shared redactor with the default rules.
+pub(crate) fn default_redactor() -> &'static Redactor {
+    static REDACTOR: OnceLock<Redactor> = OnceLock::new();
+    REDACTOR.get_or_init(Redactor::default)
+}
+
+/// Redacted JSON representation for logging, available for every
+/// serializable type.
+pub trait Redact {
+    /// Serialize to JSON with the default sensitive fields redacted.
+    fn redacted(&self) -> String;
+}
+
+impl<T: Serialize + ?Sized> Redact for T {
+    fn redacted(&self) -> String {
+        default_redactor().redact(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_rules() {
+        let mut value = json!({
+            "amount": {"currency": "EUR", "value": 1000},
+            "paymentMethod": {
+                "type": "scheme",
+                "number": "4111111111111111",
+                "expiryMonth": "03",
+                "expiryYear": "2030",
+                "cvc": "737",
+                "holderName": "John Smith"
+            },
+            "bankAccount": {"iban": "NL13TEST0123456789", "ownerName": "John Smith"},
+            "additionalData": {"cardNumber": "4111111111111111", "cardSummary": "1111"},
+            "telephoneNumber": {"number": "+31 20 123 4567"}
+        });
+        default_redactor().redact_value(&mut value);
+
+        assert_eq!(value["paymentMethod"]["number"], REDACTED);
+        assert_eq!(value["paymentMethod"]["cvc"], REDACTED);
+        assert_eq!(value["paymentMethod"]["expiryYear"], REDACTED);
+        assert_eq!(value["paymentMethod"]["type"], "scheme");
+        assert_eq!(value["bankAccount"]["iban"], REDACTED);
+        assert_eq!(value["additionalData"]["cardNumber"], REDACTED);
+        assert_eq!(value["additionalData"]["cardSummary"], "1111");
+        assert_eq!(value["telephoneNumber"]["number"], "+31 20 123 4567");
+        assert_eq!(value["amount"]["value"], 1000);
+    }
+
+    #[test]
+    fn test_custom_rules_and_arrays() {
+        let redactor = Redactor::empty()
+            .with_rule("splits.account")
+            .with_rule("metadata.*");
+        let mut value = json!({
+            "splits": [{"account": "BA1", "type": "BalanceAccount"}, {"account": "BA2"}],
+            "metadata": {"a": "1", "b": {"c": 2}},
+            "account": "kept"
+        });
+        redactor.redact_value(&mut value);
+
+        assert_eq!(value["splits"][0]["account"], REDACTED);
+        assert_eq!(value["splits"][1]["account"], REDACTED);
+        assert_eq!(value["splits"][0]["type"], "BalanceAccount");
+        assert_eq!(value["metadata"]["a"], REDACTED);
+        assert_eq!(value["metadata"]["b"], REDACTED);
+        assert_eq!(value["account"], "kept");
+    }
+
+    #[test]
+    fn test_redact_str() {
+        let redactor = Redactor::default();
+        assert_eq!(
+            redactor.redact_str(r#"{"IBAN":"NL13TEST0123456789"}"#),
+            r#"{"IBAN":"[REDACTED]"}"#
+        );
+        assert_eq!(redactor.redact_str(""), "");
+        assert!(!redactor
+            .redact_str("number=4111111111111111")
+            .contains("4111"));
+    }
+}
@@ -0,0 +1,339 @@
+//! Retry policies for transient API failures.
+//!
+//! A [`RetryPolicy`] decides whether a failed request should be attempted again
+//! and how long to wait before doing so. Transient failures are HTTP 429
+//! (rate limited), HTTP 5xx responses and connection-level errors.
+//!
+//! Requests that are not idempotent (POST and PATCH) are only retried when it
+//! is safe to do so: either the request never reached Adyen (connection
+//! failure), or the request carries an `Idempotency-Key` header so Adyen can
+//! de-duplicate it.
+
+use crate::http::Method;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// Backoff strategy used between retry attempts.
+///
+/// The delay before retry `n` (starting at zero) is
+/// `initial_delay * multiplier^n`, capped at `max_delay`. When jitter is
+/// enabled a random delay between zero and the computed value is used instead
+/// ("full jitter"), which spreads out retries from concurrent clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    jitter: bool,
+}
+
+impl Backoff {
+    /// Create an exponential backoff starting at `initial_delay`.
+    ///
+    /// The delay doubles after every attempt, is capped at 10 seconds and uses
+    /// jitter by default.
+    #[must_use]
+    pub const fn exponential(initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay: Duration::from_secs(10),
+            multiplier: 2,
+            jitter: true,
+        }
+    }
+
+    /// Create a constant backoff that always waits `delay` between attempts.
+    #[must_use]
+    pub const fn constant(delay: Duration) -> Self {
+        Self {
+            initial_delay: delay,
+            max_delay: delay,
+            multiplier: 1,
+            jitter: false,
+        }
+    }
+
+    /// Set the upper bound for a single delay.
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the factor the delay grows by after every attempt.
+    #[must_use]
+    pub const fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Enable or disable random jitter.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Get the initial delay.
+    #[must_use]
+    pub const fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    /// Get the maximum delay.
+    #[must_use]
+    pub const fn maximum_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Check if jitter is enabled.
+    #[must_use]
+    pub const fn has_jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Compute the delay before the retry following attempt number `attempt`
+    /// (zero-based), without jitter applied.
+    #[must_use]
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.checked_pow(attempt).unwrap_or(u32::MAX);
+        self.initial_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// Compute the delay before the retry following attempt number `attempt`
+    /// (zero-based), with jitter applied if enabled.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let base = self.base_delay(attempt);
+        if !self.jitter || base.is_zero() {
+            return base;
+        }
+
+        // A randomly seeded hasher is a cheap source of randomness that
+        // avoids pulling in an RNG dependency just for jitter.
+        let random = std::collections::hash_map::RandomState::new().hash_one(attempt);
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = (random >> 11) as f64 / (1u64 << 53) as f64;
+        base.mul_f64(fraction)
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::exponential(Duration::from_millis(100))
+    }
+}
+
+/// Policy controlling automatic retries of failed requests.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::retry::{Backoff, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .backoff(Backoff::exponential(Duration::from_millis(200)).max_delay(Duration::from_secs(5)));
+///
+/// assert_eq!(policy.total_attempts(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Backoff,
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Create the default retry policy: 3 attempts with exponential backoff
+    /// starting at 100ms.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a policy that never retries.
+    #[must_use]
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Set the total number of attempts, including the first one.
+    ///
+    /// A value of zero is treated as one.
+    #[must_use]
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = if max_attempts == 0 { 1 } else { max_attempts };
+        self
+    }
+
+    /// Set the backoff strategy.
+    #[must_use]
+    pub const fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Allow retrying POST and PATCH requests after a response was received,
+    /// even when they carry no `Idempotency-Key` header.
+    ///
+    /// This can result in duplicate operations and is disabled by default.
+    #[must_use]
+    pub const fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Get the total number of attempts.
+    #[must_use]
+    pub const fn total_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Get the backoff strategy.
+    #[must_use]
+    pub const fn backoff_strategy(&self) -> &Backoff {
+        &self.backoff
+    }
+
+    /// Check if the policy permits any retries.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_attempts > 1
+    }
+
+    /// Check if an HTTP status code indicates a transient failure.
+    #[must_use]
+    pub const fn is_retryable_status(status: u16) -> bool {
+        status == 429 || (status >= 500 && status <= 599)
+    }
+
+    /// Decide whether a request that received `status` may be retried.
+    ///
+    /// `attempt` is the zero-based number of the attempt that just failed and
+    /// `idempotency_key` indicates that the request carries an
+    /// `Idempotency-Key` header.
+    #[must_use]
+    pub fn should_retry_status(
+        &self,
+        method: Method,
+        idempotency_key: bool,
+        status: u16,
+        attempt: u32,
+    ) -> bool {
+        self.has_attempts_left(attempt)
+            && Self::is_retryable_status(status)
+            && (method.is_idempotent() || idempotency_key || self.retry_non_idempotent)
+    }
+
+    /// Decide whether a request that failed at the transport level may be
+    /// retried.
+    ///
+    /// Connection failures are always safe to retry because the request never
+    /// reached the server. Other transport errors (for example timeouts) may
+    /// have been processed, so they follow the same idempotency rules as
+    /// [`should_retry_status`](Self::should_retry_status).
+    #[must_use]
+    pub fn should_retry_error(
+        &self,
+        method: Method,
+        idempotency_key: bool,
+        connect_error: bool,
+        attempt: u32,
+    ) -> bool {
+        self.has_attempts_left(attempt)
+            && (connect_error
+                || method.is_idempotent()
+                || idempotency_key
+                || self.retry_non_idempotent)
+    }
+
+    /// Get the delay to wait after attempt number `attempt` (zero-based).
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.delay(attempt)
+    }
+
+    const fn has_attempts_left(self, attempt: u32) -> bool {
+        attempt + 1 < self.max_attempts
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Backoff::default(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff() {
+        let backoff = Backoff::exponential(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500))
+            .jitter(false);
+
+        assert_eq!(backoff.delay(0), Duration::from_millis(100));
+        assert_eq!(backoff.delay(1), Duration::from_millis(200));
+        assert_eq!(backoff.delay(2), Duration::from_millis(400));
+        assert_eq!(backoff.delay(3), Duration::from_millis(500));
+        assert_eq!(backoff.delay(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let backoff = Backoff::exponential(Duration::from_millis(100));
+        for attempt in 0..5 {
+            assert!(backoff.delay(attempt) <= backoff.base_delay(attempt));
+        }
+    }
+
+    #[test]
+    fn test_constant_backoff() {
+        let backoff = Backoff::constant(Duration::from_millis(250));
+        assert_eq!(backoff.delay(0), Duration::from_millis(250));
+        assert_eq!(backoff.delay(5), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(RetryPolicy::is_retryable_status(429));
+        assert!(RetryPolicy::is_retryable_status(500));
+        assert!(RetryPolicy::is_retryable_status(503));
+        assert!(!RetryPolicy::is_retryable_status(400));
+        assert!(!RetryPolicy::is_retryable_status(422));
+    }
+
+    #[test]
+    fn test_post_only_retried_when_safe() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy.should_retry_status(Method::Get, false, 503, 0));
+        assert!(!policy.should_retry_status(Method::Post, false, 503, 0));
+        assert!(policy.should_retry_status(Method::Post, true, 503, 0));
+        assert!(policy.should_retry_error(Method::Post, false, true, 0));
+        assert!(!policy.should_retry_error(Method::Post, false, false, 0));
+
+        let permissive = RetryPolicy::new().retry_non_idempotent(true);
+        assert!(permissive.should_retry_status(Method::Post, false, 503, 0));
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        assert!(policy.should_retry_status(Method::Get, false, 500, 0));
+        assert!(!policy.should_retry_status(Method::Get, false, 500, 1));
+
+        assert!(!RetryPolicy::none().is_enabled());
+        assert_eq!(RetryPolicy::new().max_attempts(0).total_attempts(), 1);
+    }
+}
@@ -0,0 +1,21 @@
+//! Timers and clocks that work with or without tokio.
+//!
+//! With the `tokio` feature, waits use tokio's timer. Without it they use a
+//! runtime-independent timer, which on wasm32 is backed by the JavaScript
+//! event loop. `std::time::Instant` panics on wasm32-unknown-unknown, so
+//! [`Instant`] comes from `web-time` there.
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub use web_time::Instant;
+
+/// Wait for `duration` on the timer of the enabled runtime.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio")]
+    tokio::time::sleep(duration).await;
+    #[cfg(not(feature = "tokio"))]
+    futures_timer::Delay::new(duration).await;
+}
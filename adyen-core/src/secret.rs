@@ -0,0 +1,114 @@
+//! Wrapper for secrets such as card numbers and API keys.
+
+use std::fmt;
+use zeroize::Zeroize;
+
+/// String holding sensitive data, such as a card number, security code or
+/// API key.
+///
+/// The value is never shown by `Debug`, there is no `Display`
+/// implementation, and the memory is overwritten with zeros when the value
+/// is dropped. Read the value with [`expose_secret`](Self::expose_secret)
+/// only where it is needed, e.g. when sending it to Adyen.
+///
+/// With the `serde` feature the value is serialized as a plain string, as
+/// request bodies must contain it. With the `rkyv` feature it is archived as
+/// a plain string too.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::SecretString;
+///
+/// let cvc = SecretString::from("737");
+/// assert_eq!(cvc.expose_secret(), "737");
+/// assert_eq!(format!("{cvc:?}"), "SecretString([REDACTED])");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wrap a secret value.
+    #[must_use]
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Get the secret value.
+    ///
+    /// Avoid logging or displaying this value.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Check if the secret is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretString([REDACTED])")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_string() {
+        let secret = SecretString::new("4111111111111111");
+        assert_eq!(secret.expose_secret(), "4111111111111111");
+        assert!(!format!("{secret:?}").contains("4111"));
+        assert!(!secret.is_empty());
+        assert_eq!(secret, SecretString::from("4111111111111111"));
+    }
+
+    #[test]
+    fn test_secret_string_serde() {
+        let secret: SecretString = serde_json::from_str(r#""737""#).unwrap();
+        assert_eq!(secret.expose_secret(), "737");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), r#""737""#);
+    }
+}
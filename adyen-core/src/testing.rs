@@ -0,0 +1,708 @@
+//! Test utilities for exercising API clients without contacting Adyen.
+//!
+//! This module is available with the `testing` feature. It provides:
+//!
+//! - [`MockTransport`]: matches outgoing requests against registered
+//!   [`Expectation`]s and answers with canned [`MockResponse`]s.
+//! - [`RecordReplayTransport`]: records real interactions to a cassette file
+//!   once, then replays them in later test runs.
+//!
+//! Both implement [`HttpTransport`] and are installed through
+//! [`ConfigBuilder::transport`](crate::ConfigBuilder::transport), so every API
+//! client built from that configuration is covered.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+//! use adyen_core::{Client, ConfigBuilder};
+//!
+//! # tokio_test::block_on(async {
+//! let mock = MockTransport::new();
+//! mock.expect(
+//!     Expectation::post("/v71/payments")
+//!         .body_includes(serde_json::json!({"reference": "order-1"}))
+//!         .respond_with(MockResponse::json(200, serde_json::json!({"resultCode": "Authorised"}))),
+//! );
+//!
+//! let config = ConfigBuilder::new()
+//!     .api_key("test_key_1234567890123456")
+//!     .unwrap()
+//!     .transport(mock.clone())
+//!     .build()
+//!     .unwrap();
+//! let client = Client::new(config).unwrap();
+//!
+//! let response: adyen_core::ApiResponse<serde_json::Value> = client
+//!     .post(
+//!         "https://checkout-test.adyen.com/v71/payments",
+//!         &serde_json::json!({"reference": "order-1"}),
+//!     )
+//!     .await
+//!     .unwrap();
+//! assert_eq!(response.data["resultCode"], "Authorised");
+//! mock.verify();
+//! # });
+//! ```
+
+use crate::http::{
+    BoxFuture, HeaderMap, HeaderName, HeaderValue, HttpRequest, HttpResponse, HttpTransport,
+    Method, TransportError, TransportErrorKind,
+};
+use crate::{AdyenError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Canned response returned by a [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Create a JSON response.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)] // taking ownership keeps `json!` call sites terse
+    pub fn json(status: u16, body: serde_json::Value) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// Create a response with a raw body.
+    #[must_use]
+    pub fn raw(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Create a response whose body is read from a fixture file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read.
+    pub fn from_fixture(status: u16, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let body = std::fs::read(path).map_err(|e| {
+            AdyenError::generic_with_source(
+                format!("Failed to read fixture {}", path.display()),
+                Box::new(e),
+            )
+        })?;
+        Ok(Self::raw(status, body))
+    }
+
+    /// Add a response header.
+    ///
+    /// Invalid header names or values are ignored.
+    #[must_use]
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            self.headers.insert(name, value);
+        }
+        self
+    }
+
+    fn to_http_response(&self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+/// A request expectation registered with a [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct Expectation {
+    method: Method,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Option<serde_json::Value>,
+    response: MockResponse,
+    times: Option<usize>,
+    matched: usize,
+}
+
+impl Expectation {
+    /// Expect a request with the given method and URL path.
+    ///
+    /// The path is compared against the path of the request URL, e.g.
+    /// `/v71/payments`. Without a response the expectation answers with an
+    /// empty JSON object.
+    #[must_use]
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            headers: Vec::new(),
+            body: None,
+            response: MockResponse::json(200, serde_json::json!({})),
+            times: None,
+            matched: 0,
+        }
+    }
+
+    /// Expect a GET request.
+    #[must_use]
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::Get, path)
+    }
+
+    /// Expect a POST request.
+    #[must_use]
+    pub fn post(path: impl Into<String>) -> Self {
+        Self::new(Method::Post, path)
+    }
+
+    /// Expect a PATCH request.
+    #[must_use]
+    pub fn patch(path: impl Into<String>) -> Self {
+        Self::new(Method::Patch, path)
+    }
+
+    /// Expect a DELETE request.
+    #[must_use]
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(Method::Delete, path)
+    }
+
+    /// Require a header with the given value.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Require the JSON body to contain `body`.
+    ///
+    /// Objects match if every expected field is present and matches; other
+    /// values must be equal.
+    #[must_use]
+    pub fn body_includes(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Set the response returned for matching requests.
+    #[must_use]
+    pub fn respond_with(mut self, response: MockResponse) -> Self {
+        self.response = response;
+        self
+    }
+
+    /// Limit how many requests this expectation answers.
+    ///
+    /// [`MockTransport::verify`] also checks that it was matched exactly this
+    /// many times. Without a limit the expectation must match at least once.
+    #[must_use]
+    pub const fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    fn matches(&self, request: &HttpRequest) -> bool {
+        if self.method != request.method || request_path(&request.url) != self.path {
+            return false;
+        }
+        if self.times.is_some_and(|times| self.matched >= times) {
+            return false;
+        }
+
+        let headers_match = self.headers.iter().all(|(name, value)| {
+            request
+                .headers
+                .get(name.as_str())
+                .is_some_and(|actual| actual == value.as_str())
+        });
+        if !headers_match {
+            return false;
+        }
+
+        match &self.body {
+            None => true,
+            Some(expected) => request
+                .body
+                .as_deref()
+                .and_then(|body| serde_json::from_slice::<serde_json::Value>(body).ok())
+                .is_some_and(|actual| json_includes(&actual, expected)),
+        }
+    }
+
+    fn is_satisfied(&self) -> bool {
+        self.times
+            .map_or(self.matched > 0, |times| self.matched == times)
+    }
+}
+
+/// A request received by a [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Request URL
+    pub url: String,
+    /// Request headers
+    pub headers: HeaderMap,
+    /// Parsed JSON body, if any
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    expectations: Vec<Expectation>,
+    received: Vec<ReceivedRequest>,
+}
+
+/// [`HttpTransport`] that answers requests from registered expectations.
+///
+/// Cloning the transport yields a handle to the same expectations, so one
+/// clone can be given to the configuration while the test keeps another to
+/// register expectations and verify them.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// Create a mock transport without expectations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an expectation.
+    ///
+    /// Expectations are tried in registration order; the first match answers
+    /// the request. Requests without a matching expectation fail with a
+    /// transport error.
+    pub fn expect(&self, expectation: Expectation) {
+        self.lock().expectations.push(expectation);
+    }
+
+    /// Get all requests received so far.
+    #[must_use]
+    pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+        self.lock().received.clone()
+    }
+
+    /// Check that every expectation was matched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an expectation was not matched, or not matched the required
+    /// number of times.
+    pub fn verify(&self) {
+        let state = self.lock();
+        let unmet: Vec<String> = state
+            .expectations
+            .iter()
+            .filter(|e| !e.is_satisfied())
+            .map(|e| {
+                format!(
+                    "{} {} (matched {} times, expected {})",
+                    e.method,
+                    e.path,
+                    e.matched,
+                    e.times
+                        .map_or_else(|| "at least 1".to_string(), |t| t.to_string())
+                )
+            })
+            .collect();
+
+        assert!(
+            unmet.is_empty(),
+            "Unmet expectations:\n{}",
+            unmet.join("\n")
+        );
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // A panic in another test thread must not hide this test's result.
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn respond(&self, request: &HttpRequest) -> std::result::Result<HttpResponse, TransportError> {
+        let mut state = self.lock();
+        state.received.push(ReceivedRequest {
+            method: request.method,
+            url: request.url.clone(),
+            headers: request.headers.clone(),
+            body: request
+                .body
+                .as_deref()
+                .and_then(|body| serde_json::from_slice(body).ok()),
+        });
+
+        let expectation = state
+            .expectations
+            .iter_mut()
+            .find(|e| e.matches(request))
+            .ok_or_else(|| {
+                TransportError::new(
+                    TransportErrorKind::Other,
+                    format!("No expectation matched {} {}", request.method, request.url),
+                )
+            })?;
+        expectation.matched += 1;
+        Ok(expectation.response.to_http_response())
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, std::result::Result<HttpResponse, TransportError>> {
+        let response = self.respond(&request);
+        Box::pin(async move { response })
+    }
+}
+
+/// A recorded request/response pair stored in a cassette file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interaction {
+    /// HTTP method
+    pub method: String,
+    /// Request URL
+    pub url: String,
+    /// Request body, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+    /// Response status code
+    pub status: u16,
+    /// Response body
+    pub response_body: String,
+}
+
+/// Whether a [`RecordReplayTransport`] records or replays interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordMode {
+    /// Forward requests to a real transport and record the interactions.
+    Record,
+    /// Answer requests from previously recorded interactions.
+    Replay,
+}
+
+/// [`HttpTransport`] that records interactions to, or replays them from, a
+/// JSON cassette file.
+///
+/// Only the method, URL, request body, response status and response body are
+/// recorded; request headers, including credentials, are never written.
+#[derive(Debug, Clone)]
+pub struct RecordReplayTransport {
+    mode: RecordMode,
+    path: PathBuf,
+    inner: Option<Arc<dyn HttpTransport>>,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+    position: Arc<Mutex<usize>>,
+}
+
+impl RecordReplayTransport {
+    /// Create a transport that forwards to `inner` and records interactions.
+    ///
+    /// Call [`save`](Self::save) to write the cassette to `path`.
+    #[must_use]
+    pub fn record(inner: impl HttpTransport + 'static, path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: RecordMode::Record,
+            path: path.into(),
+            inner: Some(Arc::new(inner)),
+            interactions: Arc::new(Mutex::new(Vec::new())),
+            position: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// Create a transport that replays interactions from the cassette at
+    /// `path`.
+    ///
+    /// Requests must be made in the recorded order and match the recorded
+    /// method and URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cassette cannot be read or parsed.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let contents = std::fs::read_to_string(&path).map_err(|e| {
+            AdyenError::generic_with_source(
+                format!("Failed to read cassette {}", path.display()),
+                Box::new(e),
+            )
+        })?;
+        let interactions: Vec<Interaction> = serde_json::from_str(&contents)?;
+
+        Ok(Self {
+            mode: RecordMode::Replay,
+            path,
+            inner: None,
+            interactions: Arc::new(Mutex::new(interactions)),
+            position: Arc::new(Mutex::new(0)),
+        })
+    }
+
+    /// Get the mode of this transport.
+    #[must_use]
+    pub const fn mode(&self) -> RecordMode {
+        self.mode
+    }
+
+    /// Get the recorded interactions.
+    #[must_use]
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Write the recorded interactions to the cassette file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        let contents = serde_json::to_string_pretty(&self.interactions())?;
+        std::fs::write(&self.path, contents).map_err(|e| {
+            AdyenError::generic_with_source(
+                format!("Failed to write cassette {}", self.path.display()),
+                Box::new(e),
+            )
+        })
+    }
+
+    fn replay_next(
+        &self,
+        request: &HttpRequest,
+    ) -> std::result::Result<HttpResponse, TransportError> {
+        let interactions = self
+            .interactions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut position = self
+            .position
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let interaction = interactions.get(*position).ok_or_else(|| {
+            TransportError::new(
+                TransportErrorKind::Other,
+                format!("Cassette exhausted at {} {}", request.method, request.url),
+            )
+        })?;
+        if interaction.method != request.method.to_string() || interaction.url != request.url {
+            return Err(TransportError::new(
+                TransportErrorKind::Other,
+                format!(
+                    "Expected {} {} but cassette has {} {}",
+                    request.method, request.url, interaction.method, interaction.url
+                ),
+            ));
+        }
+        *position += 1;
+
+        Ok(HttpResponse {
+            status: interaction.status,
+            headers: HeaderMap::new(),
+            body: interaction.response_body.clone().into_bytes(),
+        })
+    }
+}
+
+impl HttpTransport for RecordReplayTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'_, std::result::Result<HttpResponse, TransportError>> {
+        Box::pin(async move {
+            let Some(inner) = &self.inner else {
+                return self.replay_next(&request);
+            };
+
+            let method = request.method.to_string();
+            let url = request.url.clone();
+            let request_body = request
+                .body
+                .as_deref()
+                .and_then(|body| serde_json::from_slice(body).ok());
+
+            let response = inner.send(request).await?;
+            self.interactions
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(Interaction {
+                    method,
+                    url,
+                    request_body,
+                    status: response.status,
+                    response_body: String::from_utf8_lossy(&response.body).into_owned(),
+                });
+            Ok(response)
+        })
+    }
+}
+
+/// Extract the path component of a URL, falling back to the whole string.
+fn request_path(url: &str) -> String {
+    url::Url::parse(url).map_or_else(|_| url.to_string(), |url| url.path().to_string())
+}
+
+/// Check whether `actual` contains everything in `expected`.
+fn json_includes(actual: &serde_json::Value, expected: &serde_json::Value) -> bool {
+    match (actual, expected) {
+        (serde_json::Value::Object(actual), serde_json::Value::Object(expected)) => {
+            expected.iter().all(|(key, value)| {
+                actual
+                    .get(key)
+                    .is_some_and(|actual| json_includes(actual, value))
+            })
+        }
+        _ => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApiResponse, Client, ConfigBuilder};
+    use std::time::Duration;
+
+    fn client_with(transport: impl HttpTransport + 'static) -> Client {
+        let config = ConfigBuilder::new()
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(transport)
+            .build()
+            .unwrap();
+        Client::new(config).unwrap()
+    }
+
+    fn request(method: Method, url: &str, body: Option<serde_json::Value>) -> HttpRequest {
+        HttpRequest {
+            method,
+            url: url.to_string(),
+            headers: HeaderMap::new(),
+            body: body.map(|b| b.to_string().into_bytes()),
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_matches_expectation() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v71/paymentLinks/PL123")
+                .header("X-API-Key", "test_key_1234567890123456")
+                .respond_with(MockResponse::json(200, serde_json::json!({"id": "PL123"})))
+                .times(1),
+        );
+
+        let client = client_with(mock.clone());
+        let response: ApiResponse<serde_json::Value> = client
+            .get("https://checkout-test.adyen.com/v71/paymentLinks/PL123")
+            .await
+            .unwrap();
+
+        assert_eq!(response.data["id"], "PL123");
+        assert_eq!(mock.received_requests().len(), 1);
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_unmatched_request_fails() {
+        let mock = MockTransport::new();
+        mock.expect(Expectation::post("/v71/payments"));
+
+        let client = client_with(mock.clone());
+        let result: Result<ApiResponse<serde_json::Value>> = client
+            .get("https://checkout-test.adyen.com/v71/sessions/abc")
+            .await;
+
+        assert!(matches!(result, Err(AdyenError::Http(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unmet expectations")]
+    fn test_verify_reports_unmet_expectations() {
+        let mock = MockTransport::new();
+        mock.expect(Expectation::post("/v71/payments"));
+        mock.verify();
+    }
+
+    #[test]
+    fn test_body_includes_partial_match() {
+        let expectation = Expectation::post("/v71/payments")
+            .body_includes(serde_json::json!({"amount": {"currency": "EUR"}}));
+
+        let matching = request(
+            Method::Post,
+            "https://checkout-test.adyen.com/v71/payments",
+            Some(
+                serde_json::json!({"amount": {"currency": "EUR", "value": 1000}, "reference": "r"}),
+            ),
+        );
+        let other = request(
+            Method::Post,
+            "https://checkout-test.adyen.com/v71/payments",
+            Some(serde_json::json!({"amount": {"currency": "USD", "value": 1000}})),
+        );
+
+        assert!(expectation.matches(&matching));
+        assert!(!expectation.matches(&other));
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/v71/payments").respond_with(MockResponse::json(
+                200,
+                serde_json::json!({"pspReference": "ABC"}),
+            )),
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("adyen-cassette-{}.json", uuid::Uuid::new_v4()));
+        let url = "https://checkout-test.adyen.com/v71/payments";
+
+        let recorder = RecordReplayTransport::record(mock, &path);
+        let response = recorder
+            .send(request(
+                Method::Post,
+                url,
+                Some(serde_json::json!({"reference": "r"})),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        recorder.save().unwrap();
+
+        let replayer = RecordReplayTransport::replay(&path).unwrap();
+        assert_eq!(replayer.mode(), RecordMode::Replay);
+        let replay_response = replayer
+            .send(request(Method::Post, url, None))
+            .await
+            .unwrap();
+        assert_eq!(replay_response.body, response.body);
+        assert!(replayer
+            .send(request(Method::Post, url, None))
+            .await
+            .is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,7 +1,9 @@
 //! Common types used across the Adyen library.
 
 use crate::{AdyenError, Currency, Result};
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+#[cfg(feature = "decimal")]
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use std::collections::HashMap;
 use std::fmt;
 
 /// Represents a monetary amount with currency.
@@ -32,7 +34,8 @@ use std::fmt;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
 pub struct Amount {
     /// Amount in minor units (e.g., cents)
@@ -50,6 +53,7 @@ impl Amount {
     ///
     /// Returns an error if the decimal cannot be converted to minor units
     /// (e.g., too many decimal places or negative value).
+    #[cfg(feature = "decimal")]
     pub fn new(amount: Decimal, currency: Currency) -> Result<Self> {
         let multiplier = Decimal::from(currency.minor_unit_multiplier());
         let minor_units = amount * multiplier;
@@ -68,6 +72,84 @@ impl Amount {
         })
     }
 
+    /// Create an amount from a decimal in major units, rounding it to the
+    /// currency's minor units.
+    ///
+    /// Midpoints are rounded away from zero, so `10.005` EUR becomes
+    /// `10.01` EUR and `10.004` EUR becomes `10.00` EUR. Use [`Amount::new`]
+    /// to reject amounts with too many decimal places instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use adyen_core::{Amount, Currency};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let amount = Amount::from_decimal(Decimal::new(10005, 3), Currency::EUR).unwrap();
+    /// assert_eq!(amount.minor_units(), 1001);
+    ///
+    /// let amount = Amount::from_decimal(Decimal::new(1250, 2), Currency::JPY).unwrap();
+    /// assert_eq!(amount.minor_units(), 13);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the amount is negative or too large.
+    #[cfg(feature = "decimal")]
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> Result<Self> {
+        let rounded = amount.round_dp_with_strategy(
+            u32::from(currency.decimal_places()),
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        Self::new(rounded, currency)
+    }
+
+    /// Parse an amount in major units followed by its currency code, e.g.
+    /// `"12.34 EUR"`.
+    ///
+    /// Unlike [`Amount::from_decimal`], nothing is rounded: the amount must
+    /// not have more decimal places than the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use adyen_core::{Amount, Currency};
+    ///
+    /// let amount = Amount::parse("12.34 EUR").unwrap();
+    /// assert_eq!(amount, Amount::from_minor_units(1234, Currency::EUR));
+    ///
+    /// assert!(Amount::parse("12.345 EUR").is_err());
+    /// assert!(Amount::parse("12.5 JPY").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a non-negative decimal followed by
+    /// a supported currency code, or has too many decimal places.
+    #[cfg(feature = "decimal")]
+    pub fn parse(s: &str) -> Result<Self> {
+        let invalid = || {
+            AdyenError::config(format!(
+                "Invalid amount \"{s}\", expected e.g. \"12.34 EUR\""
+            ))
+        };
+
+        let (value, code) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(invalid)?;
+        let currency = Currency::from_code(code.trim_start())?;
+        let value = Decimal::from_str_exact(value).map_err(|_| invalid())?;
+        let decimal_places = currency.decimal_places();
+        if value.round_dp(u32::from(decimal_places)) != value {
+            return Err(AdyenError::config(format!(
+                "Amount {value} has more than {decimal_places} decimal places for {currency}"
+            )));
+        }
+
+        Self::new(value, currency)
+    }
+
     /// Create an amount from major units (e.g., dollars).
     ///
     /// # Examples
@@ -112,17 +194,38 @@ impl Amount {
 
     /// Get the currency.
     #[must_use]
-    pub const fn currency(&self) -> Currency {
-        self.currency
+    pub const fn currency(&self) -> &Currency {
+        &self.currency
     }
 
     /// Get the amount in major units as a decimal.
+    #[cfg(feature = "decimal")]
     #[must_use]
     pub fn major_units(&self) -> Decimal {
         let divisor = Decimal::from(self.currency.minor_unit_multiplier());
         Decimal::from(self.minor_units) / divisor
     }
 
+    /// Get the amount in major units with exactly the currency's decimal
+    /// places, e.g. `12.30` rather than `12.3`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use adyen_core::{Amount, Currency};
+    ///
+    /// let amount = Amount::from_minor_units(1230, Currency::EUR);
+    /// assert_eq!(amount.to_major_decimal().to_string(), "12.30");
+    /// ```
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn to_major_decimal(&self) -> Decimal {
+        Decimal::from_i128_with_scale(
+            i128::from(self.minor_units),
+            u32::from(self.currency.decimal_places()),
+        )
+    }
+
     /// Check if this amount is zero.
     #[must_use]
     pub const fn is_zero(&self) -> bool {
@@ -149,7 +252,7 @@ impl Amount {
 
         Ok(Self {
             minor_units: result,
-            currency: self.currency,
+            currency: self.currency.clone(),
         })
     }
 
@@ -173,14 +276,35 @@ impl Amount {
 
         Ok(Self {
             minor_units: result,
-            currency: self.currency,
+            currency: self.currency.clone(),
         })
     }
 }
 
 impl fmt::Display for Amount {
+    /// Format the amount in major units with the currency's decimal places,
+    /// e.g. `12.30 EUR`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.major_units(), self.currency)
+        let multiplier = self.currency.minor_unit_multiplier();
+        let major = self.minor_units / multiplier;
+        match usize::from(self.currency.decimal_places()) {
+            0 => write!(f, "{major} {}", self.currency),
+            places => write!(
+                f,
+                "{major}.{:0places$} {}",
+                self.minor_units % multiplier,
+                self.currency
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl std::str::FromStr for Amount {
+    type Err = AdyenError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
     }
 }
 
@@ -193,7 +317,8 @@ impl fmt::Display for Amount {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
 pub struct RequestId(Box<str>);
 
@@ -208,7 +333,8 @@ impl RequestId {
     ///
     /// # Errors
     ///
-    /// Returns an error if the string is empty or too long.
+    /// Returns an error if the string is empty, too long, or contains
+    /// characters other than visible ASCII.
     pub fn from_string(id: impl Into<String>) -> Result<Self> {
         let id = id.into();
         if id.is_empty() {
@@ -219,6 +345,11 @@ impl RequestId {
                 "Request ID cannot be longer than 64 characters",
             ));
         }
+        if !id.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(AdyenError::config(
+                "Request ID can only contain visible ASCII characters",
+            ));
+        }
         Ok(Self(id.into_boxed_str()))
     }
 
@@ -255,19 +386,458 @@ impl std::str::FromStr for RequestId {
     }
 }
 
+/// An ISO 3166-1 alpha-2 country code, such as `NL` or `US`.
+///
+/// Codes are validated to be two ASCII letters and stored in upper case;
+/// whether the country exists is left to Adyen.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_core::CountryCode;
+///
+/// let country: CountryCode = "nl".parse().unwrap();
+/// assert_eq!(country.as_str(), "NL");
+/// assert!(CountryCode::new("NLD").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String")
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct CountryCode(Box<str>);
+
+impl CountryCode {
+    /// Create a country code from its two-letter code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code is not two ASCII letters.
+    pub fn new(code: &str) -> Result<Self> {
+        if code.len() != 2 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(AdyenError::config(format!(
+                "Invalid country code \"{code}\": expected two letters, e.g. \"NL\""
+            )));
+        }
+        Ok(Self(code.to_ascii_uppercase().into_boxed_str()))
+    }
+
+    /// Get the country code as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A shopper locale, such as `nl-NL` or `en_US`.
+///
+/// Locales are validated to be a language code of two or three letters,
+/// optionally followed by a four-letter script and a region of two letters
+/// or three digits, separated by `-` or `_`. They are kept as given.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_core::Locale;
+///
+/// let locale: Locale = "nl-NL".parse().unwrap();
+/// assert_eq!(locale.language(), "nl");
+/// assert_eq!(locale.region(), Some("NL"));
+/// assert!(Locale::new("Dutch").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String")
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct Locale(Box<str>);
+
+impl Locale {
+    /// Create a locale from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locale is not a language code optionally
+    /// followed by a script and a region.
+    pub fn new(locale: &str) -> Result<Self> {
+        let mut subtags = locale.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        let mut valid =
+            matches!(language.len(), 2 | 3) && language.bytes().all(|b| b.is_ascii_alphabetic());
+
+        let mut subtag = subtags.next();
+        if subtag.is_some_and(|script| {
+            script.len() == 4 && script.bytes().all(|b| b.is_ascii_alphabetic())
+        }) {
+            subtag = subtags.next();
+        }
+        if let Some(region) = subtag {
+            valid &= (region.len() == 2 && region.bytes().all(|b| b.is_ascii_alphabetic()))
+                || (region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit()));
+        }
+
+        if !valid || subtags.next().is_some() {
+            return Err(AdyenError::config(format!(
+                "Invalid locale \"{locale}\": expected e.g. \"nl-NL\""
+            )));
+        }
+        Ok(Self(locale.into()))
+    }
+
+    /// Get the locale as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the language code, e.g. `nl` for `nl-NL`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.0.split(['-', '_']).next().unwrap_or_default()
+    }
+
+    /// Get the region, e.g. `NL` for `nl-NL`, if the locale has one.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        self.0
+            .split(['-', '_'])
+            .skip(1)
+            .find(|subtag| subtag.len() != 4)
+    }
+}
+
+/// A PSP reference: Adyen's unique identifier of a payment or modification.
+///
+/// PSP references are 16 alphanumeric characters, such as
+/// `8815329842815468`. The type keeps PSP references from being mixed up with
+/// merchant references.
+///
+/// [`new`](Self::new) and parsing check the format, so an invalid reference is
+/// caught before it is sent. Deserializing accepts any string, so a response
+/// for a payment that went through is never rejected over the format of its
+/// reference.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_core::PspReference;
+///
+/// let psp_reference: PspReference = "8815329842815468".parse().unwrap();
+/// assert_eq!(psp_reference, "8815329842815468");
+/// assert!(PspReference::new("Order-12345").is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct PspReference(Box<str>);
+
+impl PspReference {
+    /// Create a PSP reference from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reference is not 16 ASCII letters or digits.
+    pub fn new(reference: &str) -> Result<Self> {
+        if reference.len() != 16 || !reference.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(AdyenError::config(format!(
+                "Invalid PSP reference \"{reference}\": expected 16 letters or digits"
+            )));
+        }
+        Ok(Self(reference.into()))
+    }
+
+    /// Get the PSP reference as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PspReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PspReference").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for PspReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for PspReference {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::str::FromStr for PspReference {
+    type Err = AdyenError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for PspReference {
+    type Error = AdyenError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl PartialEq<str> for PspReference {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PspReference {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PspReference {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PspReference {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        // Not validated: a successful response must parse even if Adyen
+        // changes the reference format.
+        <Box<str>>::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Your own key-value data attached to a request, such as an order ID.
+///
+/// Adyen limits metadata to [`MAX_ENTRIES`](Self::MAX_ENTRIES) entries, with
+/// keys of at most [`MAX_KEY_LENGTH`](Self::MAX_KEY_LENGTH) characters and
+/// values of at most [`MAX_VALUE_LENGTH`](Self::MAX_VALUE_LENGTH)
+/// characters. [`insert`](Self::insert) enforces the limits, so requests with
+/// too much metadata are rejected before they are sent.
+///
+/// Metadata serializes as a plain JSON object. Metadata received from Adyen
+/// is not checked against the limits.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_core::Metadata;
+///
+/// let mut metadata = Metadata::new();
+/// metadata.insert("orderId", "1234").unwrap();
+/// assert_eq!(metadata.get("orderId"), Some("1234"));
+/// assert!(metadata.insert("a-key-longer-than-twenty", "value").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct Metadata(HashMap<String, String>);
+
+impl Metadata {
+    /// Maximum number of entries.
+    pub const MAX_ENTRIES: usize = 20;
+
+    /// Maximum length of a key, in characters.
+    pub const MAX_KEY_LENGTH: usize = 20;
+
+    /// Maximum length of a value, in characters.
+    pub const MAX_VALUE_LENGTH: usize = 80;
+
+    /// Create empty metadata.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an entry, returning the previous value of the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is empty or too long, the value is too
+    /// long, or the key is new and the metadata already has
+    /// [`MAX_ENTRIES`](Self::MAX_ENTRIES) entries.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Option<String>> {
+        let (key, value) = (key.into(), value.into());
+        Self::check_entry(&key, &value)?;
+        if self.0.len() >= Self::MAX_ENTRIES && !self.0.contains_key(&key) {
+            return Err(AdyenError::config(format!(
+                "Metadata cannot have more than {} entries",
+                Self::MAX_ENTRIES
+            )));
+        }
+        Ok(self.0.insert(key, value))
+    }
+
+    /// Get the value of a key.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Remove a key, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    /// Get the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if there are no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the entries in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Get the entries as a map.
+    #[must_use]
+    pub fn as_map(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+
+    /// Convert into a map of the entries.
+    #[must_use]
+    pub fn into_map(self) -> HashMap<String, String> {
+        self.0
+    }
+
+    fn check_entry(key: &str, value: &str) -> Result<()> {
+        let key_length = key.chars().count();
+        if key_length == 0 || key_length > Self::MAX_KEY_LENGTH {
+            return Err(AdyenError::config(format!(
+                "Invalid metadata key \"{key}\": expected 1 to {} characters",
+                Self::MAX_KEY_LENGTH
+            )));
+        }
+        if value.chars().count() > Self::MAX_VALUE_LENGTH {
+            return Err(AdyenError::config(format!(
+                "Metadata value of \"{key}\" is longer than {} characters",
+                Self::MAX_VALUE_LENGTH
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for Metadata {
+    type Error = AdyenError;
+
+    fn try_from(map: HashMap<String, String>) -> Result<Self> {
+        map.into_iter()
+            .try_fold(Self::new(), |mut metadata, (key, value)| {
+                metadata.insert(key, value)?;
+                Ok(metadata)
+            })
+    }
+}
+
+macro_rules! impl_string_newtype {
+    ($($ty:ident),*) => {$(
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $ty {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = AdyenError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<&str> for $ty {
+            type Error = AdyenError;
+
+            fn try_from(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $ty {
+            type Error = AdyenError;
+
+            fn try_from(s: String) -> Result<Self> {
+                Self::new(&s)
+            }
+        }
+    )*};
+}
+
+impl_string_newtype!(CountryCode, Locale);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "decimal")]
     use rust_decimal::Decimal;
 
+    #[cfg(feature = "decimal")]
     #[test]
     fn test_amount_from_major_units() {
         let amount = Amount::from_major_units(100, Currency::USD);
         assert_eq!(amount.minor_units(), 10000);
-        assert_eq!(amount.currency(), Currency::USD);
+        assert_eq!(amount.currency(), &Currency::USD);
         assert_eq!(amount.major_units(), Decimal::from(100));
     }
 
+    #[cfg(feature = "decimal")]
     #[test]
     fn test_amount_from_minor_units() {
         let amount = Amount::from_minor_units(10050, Currency::USD);
@@ -275,12 +845,71 @@ mod tests {
         assert_eq!(amount.major_units(), Decimal::new(10050, 2));
     }
 
+    #[cfg(feature = "decimal")]
     #[test]
     fn test_amount_new() {
         let amount = Amount::new(Decimal::new(10050, 2), Currency::USD).unwrap();
         assert_eq!(amount.minor_units(), 10050);
     }
 
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_amount_from_decimal_rounding() {
+        let cases = [
+            (Decimal::new(10_005, 3), Currency::EUR, 1001),
+            (Decimal::new(10_004, 3), Currency::EUR, 1000),
+            (Decimal::new(10_015, 3), Currency::EUR, 1002),
+            (Decimal::new(25, 1), Currency::JPY, 3),
+            (Decimal::new(24, 1), Currency::JPY, 2),
+            (Decimal::new(-4, 3), Currency::EUR, 0),
+        ];
+        for (value, currency, minor_units) in cases {
+            let amount = Amount::from_decimal(value, currency.clone()).unwrap();
+            assert_eq!(amount.minor_units(), minor_units, "{value} {currency}");
+        }
+        assert!(Amount::from_decimal(Decimal::new(-5, 3), Currency::EUR).is_err());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_amount_parse() {
+        let amount: Amount = "12.34 EUR".parse().unwrap();
+        assert_eq!(amount, Amount::from_minor_units(1234, Currency::EUR));
+        assert_eq!(
+            Amount::parse(" 1000 JPY ").unwrap(),
+            Amount::from_minor_units(1000, Currency::JPY)
+        );
+        assert_eq!(Amount::parse("0.5 USD").unwrap().minor_units(), 50);
+
+        for invalid in [
+            "12.34",
+            "EUR 12.34",
+            "12.34 XYZ",
+            "-1.00 EUR",
+            "1,00 EUR",
+            "12.345 EUR",
+            "",
+        ] {
+            assert!(Amount::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_amount_to_major_decimal() {
+        let amount = Amount::from_minor_units(1230, Currency::EUR);
+        assert_eq!(amount.to_major_decimal(), Decimal::new(123, 1));
+        assert_eq!(amount.to_major_decimal().to_string(), "12.30");
+        assert_eq!(
+            Amount::parse(&format!("{} EUR", amount.to_major_decimal())).unwrap(),
+            amount
+        );
+
+        let amount = Amount::from_minor_units(u64::MAX, Currency::JPY);
+        assert_eq!(amount.to_major_decimal(), Decimal::from(u64::MAX));
+    }
+
+    #[cfg(feature = "decimal")]
     #[test]
     fn test_amount_zero_decimal_currency() {
         let amount = Amount::from_major_units(100, Currency::JPY);
@@ -288,6 +917,23 @@ mod tests {
         assert_eq!(amount.major_units(), Decimal::from(100));
     }
 
+    #[test]
+    fn test_amount_display() {
+        let cases = [
+            (1230, Currency::EUR, "12.30 EUR"),
+            (1200, Currency::EUR, "12.00 EUR"),
+            (5, Currency::EUR, "0.05 EUR"),
+            (1000, Currency::JPY, "1000 JPY"),
+            (12_345, Currency::BHD, "12.345 BHD"),
+        ];
+        for (minor_units, currency, display) in cases {
+            assert_eq!(
+                Amount::from_minor_units(minor_units, currency).to_string(),
+                display
+            );
+        }
+    }
+
     #[test]
     fn test_amount_add() {
         let amount1 = Amount::from_minor_units(1000, Currency::USD);
@@ -352,10 +998,116 @@ mod tests {
         assert!(RequestId::from_string(long_string).is_err());
     }
 
+    #[test]
+    fn test_request_id_rejects_non_header_safe_characters() {
+        assert!(RequestId::from_string("with space").is_err());
+        assert!(RequestId::from_string("naïve").is_err());
+    }
+
+    #[test]
+    fn test_country_code() {
+        assert_eq!(CountryCode::new("nl").unwrap().as_str(), "NL");
+        assert_eq!(CountryCode::new("US").unwrap().to_string(), "US");
+        for invalid in ["", "N", "NLD", "N1", "ÑL"] {
+            assert!(CountryCode::new(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_locale() {
+        let locale = Locale::new("en_US").unwrap();
+        assert_eq!(locale.as_str(), "en_US");
+        assert_eq!((locale.language(), locale.region()), ("en", Some("US")));
+
+        let locale = Locale::new("zh-Hant-TW").unwrap();
+        assert_eq!((locale.language(), locale.region()), ("zh", Some("TW")));
+        let locale = Locale::new("es-419").unwrap();
+        assert_eq!(locale.region(), Some("419"));
+        assert_eq!(Locale::new("fr").unwrap().region(), None);
+
+        for invalid in ["", "n", "Dutch", "nl-", "nl-NLD", "nl-NL-x", "nl NL"] {
+            assert!(Locale::new(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_country_code_and_locale_serde() {
+        let country: CountryCode = serde_json::from_str(r#""nl""#).unwrap();
+        assert_eq!(serde_json::to_string(&country).unwrap(), r#""NL""#);
+        assert!(serde_json::from_str::<CountryCode>(r#""Netherlands""#).is_err());
+
+        let locale: Locale = serde_json::from_str(r#""nl-NL""#).unwrap();
+        assert_eq!(serde_json::to_string(&locale).unwrap(), r#""nl-NL""#);
+        assert!(serde_json::from_str::<Locale>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_psp_reference() {
+        let reference = PspReference::new("991559660454807J").unwrap();
+        assert_eq!(reference.as_str(), "991559660454807J");
+        assert_eq!(reference.to_string(), "991559660454807J");
+        assert_eq!(
+            format!("{reference:?}"),
+            r#"PspReference("991559660454807J")"#
+        );
+
+        for invalid in [
+            "",
+            "Order-12345",
+            "99155966045480",
+            "991559660454807J1",
+            "991559660454807-",
+        ] {
+            assert!(PspReference::new(invalid).is_err(), "{invalid}");
+        }
+
+        let reference: PspReference = serde_json::from_str(r#""8815329842815468""#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&reference).unwrap(),
+            r#""8815329842815468""#
+        );
+        let unexpected: PspReference = serde_json::from_str(r#""PSP-2024-0001""#).unwrap();
+        assert_eq!(unexpected, "PSP-2024-0001");
+    }
+
     #[test]
     fn test_request_id_from_uuid() {
         let uuid = uuid::Uuid::new_v4();
         let id = RequestId::from(uuid);
         assert_eq!(id.as_str(), uuid.to_string());
     }
+
+    #[test]
+    fn test_metadata_limits() {
+        let mut metadata = Metadata::new();
+        for i in 0..Metadata::MAX_ENTRIES {
+            metadata.insert(format!("key{i}"), "value").unwrap();
+        }
+        assert!(metadata.insert("key20", "value").is_err());
+        // Replacing a value does not add an entry.
+        assert_eq!(
+            metadata
+                .insert("key0", "x".repeat(Metadata::MAX_VALUE_LENGTH))
+                .unwrap(),
+            Some("value".to_string())
+        );
+        assert!(metadata
+            .insert("key1", "x".repeat(Metadata::MAX_VALUE_LENGTH + 1))
+            .is_err());
+        assert!(metadata.insert("", "value").is_err());
+        assert!(metadata
+            .insert("k".repeat(Metadata::MAX_KEY_LENGTH + 1), "value")
+            .is_err());
+        assert_eq!(metadata.len(), Metadata::MAX_ENTRIES);
+
+        let map = HashMap::from([("orderId".to_string(), "1234".to_string())]);
+        let metadata = Metadata::try_from(map.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::to_value(&map).unwrap()
+        );
+        let parsed: Metadata = serde_json::from_str(r#"{"orderId": "1234"}"#).unwrap();
+        assert_eq!(parsed, metadata);
+        assert_eq!(parsed.into_map(), map);
+    }
 }
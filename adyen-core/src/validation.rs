@@ -0,0 +1,240 @@
+//! Field format checks for request builders.
+//!
+//! Builders enforce their required fields at compile time, see
+//! [`builder`](crate::builder). Their opt-in `build_validated` methods
+//! additionally check field formats with a [`RequestValidator`] and report
+//! every failure at once as [`ValidationErrors`], wrapped in
+//! [`AdyenError::Validation`].
+//!
+//! Errors are keyed by the Rust field path, e.g. `billing_address.country`.
+
+use crate::{AdyenError, Amount, Currency, Result};
+use std::borrow::Cow;
+use validator::{ValidateEmail, ValidateUrl};
+
+pub use validator::{ValidationError, ValidationErrors};
+
+/// Maximum length of a merchant reference.
+pub const MAX_REFERENCE_LENGTH: usize = 80;
+
+/// Collects format errors of a request's fields.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::validation::RequestValidator;
+///
+/// let mut validator = RequestValidator::new();
+/// validator
+///     .reference("reference", "Order-1")
+///     .email("shopper_email", "shopper@example")
+///     .expiry_month("card.expiry_month", "13");
+///
+/// let errors = validator.into_errors();
+/// assert!(errors.field_errors().contains_key("card.expiry_month"));
+/// assert!(!errors.field_errors().contains_key("reference"));
+/// ```
+#[derive(Debug, Default)]
+pub struct RequestValidator {
+    errors: ValidationErrors,
+}
+
+impl RequestValidator {
+    /// Create a validator without errors.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that a merchant reference is at most [`MAX_REFERENCE_LENGTH`]
+    /// characters long.
+    pub fn reference(&mut self, field: &'static str, value: &str) -> &mut Self {
+        let length = value.chars().count();
+        if length > MAX_REFERENCE_LENGTH {
+            let mut error = ValidationError::new("length").with_message(Cow::Owned(format!(
+                "must be at most {MAX_REFERENCE_LENGTH} characters, got {length}"
+            )));
+            error.add_param(Cow::Borrowed("max"), &MAX_REFERENCE_LENGTH);
+            self.errors.add(field, error);
+        }
+        self
+    }
+
+    /// Check that an amount's currency is a three-letter ISO 4217 code.
+    pub fn currency(&mut self, field: &'static str, amount: &Amount) -> &mut Self {
+        let code = amount.currency().code();
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            self.add(
+                field,
+                "currency",
+                format!("\"{code}\" is not an ISO 4217 currency code"),
+            );
+        }
+        self
+    }
+
+    /// Check that a currency, if given, is the currency of the request's
+    /// amount.
+    pub fn same_currency(
+        &mut self,
+        field: &'static str,
+        amount: &Amount,
+        currency: Option<&Currency>,
+    ) -> &mut Self {
+        if let Some(currency) = currency {
+            if currency != amount.currency() {
+                self.add(
+                    field,
+                    "currency_mismatch",
+                    format!(
+                        "currency {} does not match the amount's currency {}",
+                        currency.code(),
+                        amount.currency().code()
+                    ),
+                );
+            }
+        }
+        self
+    }
+
+    /// Check that a country code is two uppercase ISO 3166-1 alpha-2 letters.
+    pub fn country_code(&mut self, field: &'static str, value: &str) -> &mut Self {
+        if value.len() != 2 || !value.bytes().all(|b| b.is_ascii_uppercase()) {
+            self.add(
+                field,
+                "country_code",
+                format!("\"{value}\" is not an ISO 3166-1 alpha-2 country code"),
+            );
+        }
+        self
+    }
+
+    /// Check that an email address is syntactically valid.
+    pub fn email(&mut self, field: &'static str, value: &str) -> &mut Self {
+        if !value.validate_email() {
+            self.add(field, "email", "is not a valid email address".to_string());
+        }
+        self
+    }
+
+    /// Check that a card expiry month is a number from 1 to 12.
+    pub fn expiry_month(&mut self, field: &'static str, value: &str) -> &mut Self {
+        let valid = value.len() <= 2
+            && value
+                .parse::<u8>()
+                .is_ok_and(|month| (1..=12).contains(&month));
+        if !valid {
+            self.add(
+                field,
+                "expiry_month",
+                format!("\"{value}\" is not a month from 1 to 12"),
+            );
+        }
+        self
+    }
+
+    /// Check that a return URL is an absolute URL with a scheme the shopper
+    /// can be redirected to.
+    ///
+    /// Web shops use `https`, or `http` for local testing; apps use a custom
+    /// scheme such as `my-app://`. Schemes that run or embed content, such as
+    /// `javascript` and `data`, are rejected.
+    pub fn return_url(&mut self, field: &'static str, value: &str) -> &mut Self {
+        let scheme = value
+            .validate_url()
+            .then(|| value.split_once(':').map(|(scheme, _)| scheme))
+            .flatten()
+            .map(str::to_ascii_lowercase);
+        match scheme.as_deref() {
+            None => self.add(field, "url", "is not an absolute URL".to_string()),
+            Some("javascript" | "data" | "file" | "vbscript" | "blob") => self.add(
+                field,
+                "url_scheme",
+                format!("the {} scheme is not allowed", scheme.unwrap_or_default()),
+            ),
+            Some(_) => {}
+        }
+        self
+    }
+
+    /// Add an error for a field.
+    pub fn add(&mut self, field: &'static str, code: &'static str, message: String) {
+        self.errors.add(
+            field,
+            ValidationError::new(code).with_message(message.into()),
+        );
+    }
+
+    /// Get the collected errors.
+    #[must_use]
+    pub fn into_errors(self) -> ValidationErrors {
+        self.errors
+    }
+
+    /// Finish validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdyenError::Validation`] with all collected errors if any
+    /// check failed.
+    pub fn finish(self) -> Result<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AdyenError::Validation(self.errors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_validator_checks() {
+        let amount = Amount::from_minor_units(1000, Currency::EUR);
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &"x".repeat(MAX_REFERENCE_LENGTH))
+            .currency("amount", &amount)
+            .same_currency("splits.amount", &amount, Some(&Currency::EUR))
+            .country_code("country_code", "NL")
+            .email("shopper_email", "shopper@example.com")
+            .expiry_month("expiry_month", "03")
+            .return_url("return_url", "https://your-company.com/checkout")
+            .return_url("app_return_url", "my-app://checkout");
+        assert!(validator.finish().is_ok());
+
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &"x".repeat(MAX_REFERENCE_LENGTH + 1))
+            .same_currency("splits.amount", &amount, Some(&Currency::USD))
+            .country_code("country_code", "NLD")
+            .email("shopper_email", "not an email")
+            .expiry_month("expiry_month", "0")
+            .expiry_month("expiry_month", "13")
+            .return_url("return_url", "javascript:alert(1)")
+            .return_url("relative_url", "/checkout");
+        let errors = validator.into_errors();
+        let fields = errors.field_errors();
+        for field in [
+            "reference",
+            "splits.amount",
+            "country_code",
+            "shopper_email",
+            "return_url",
+            "relative_url",
+        ] {
+            assert_eq!(fields[field].len(), 1, "{field}");
+        }
+        assert_eq!(fields["expiry_month"].len(), 2);
+        assert_eq!(fields["return_url"][0].code, "url_scheme");
+
+        let mut validator = RequestValidator::new();
+        validator.currency(
+            "amount",
+            &Amount::from_minor_units(1000, Currency::from_code_or_unknown("eur1")),
+        );
+        assert!(matches!(validator.finish(), Err(AdyenError::Validation(_))));
+    }
+}
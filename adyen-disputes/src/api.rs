@@ -0,0 +1,161 @@
+//! Disputes API client implementation.
+
+use crate::types::*;
+use adyen_core::{Api, Client, Config, Result};
+
+/// Adyen Disputes API client.
+///
+/// Provides access to Adyen's Disputes API v30 for handling chargebacks:
+/// retrieving applicable defense reasons, supplying and deleting defense
+/// documents, and defending or accepting disputes.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_disputes::DisputesApi;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let disputes_api = DisputesApi::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisputesApi {
+    client: Client,
+}
+
+impl DisputesApi {
+    /// Create a new Disputes API client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::Disputes)?;
+        Ok(Self { client })
+    }
+
+    /// Create a new Disputes API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Disputes),
+        }
+    }
+
+    fn endpoint(&self, operation: &str) -> String {
+        format!(
+            "{}/ca/services/DisputeService/v30/{}",
+            self.client.config().environment().disputes_api_url(),
+            operation
+        )
+    }
+
+    /// Retrieve the defense reasons applicable to a dispute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn retrieve_applicable_defense_reasons(
+        &self,
+        request: &DisputeRequest,
+    ) -> Result<DefenseReasonsResponse> {
+        let url = self.endpoint("retrieveApplicableDefenseReasons");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Supply documents to defend a dispute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_disputes::{DefenseDocument, DisputesApi, SupplyDefenseDocumentRequest};
+    ///
+    /// # async fn example(api: DisputesApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let receipt = std::fs::read("receipt.pdf")?;
+    /// let request = SupplyDefenseDocumentRequest::new(
+    ///     "DZ4DPSHB4WD2WN82",
+    ///     "YOUR_MERCHANT_ACCOUNT",
+    ///     vec![DefenseDocument::new("DefenseMaterial", "application/pdf", &receipt)],
+    /// );
+    ///
+    /// let response = api.supply_defense_document(&request).await?;
+    /// assert!(response.is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn supply_defense_document(
+        &self,
+        request: &SupplyDefenseDocumentRequest,
+    ) -> Result<DisputeServiceResponse> {
+        let url = self.endpoint("supplyDefenseDocument");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Delete a previously supplied defense document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn delete_dispute_defense_document(
+        &self,
+        request: &DeleteDefenseDocumentRequest,
+    ) -> Result<DisputeServiceResponse> {
+        let url = self.endpoint("deleteDisputeDefenseDocument");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Defend a dispute with the given defense reason.
+    ///
+    /// Supply the documents required by the defense reason first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn defend_dispute(
+        &self,
+        request: &DefendDisputeRequest,
+    ) -> Result<DisputeServiceResponse> {
+        let url = self.endpoint("defendDispute");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Accept a dispute.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn accept_dispute(&self, request: &DisputeRequest) -> Result<DisputeServiceResponse> {
+        let url = self.endpoint("acceptDispute");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl DisputesApi {
+        fn retrieve_applicable_defense_reasons_blocking => retrieve_applicable_defense_reasons(request: &DisputeRequest) -> Result<DefenseReasonsResponse>;
+        fn supply_defense_document_blocking => supply_defense_document(request: &SupplyDefenseDocumentRequest) -> Result<DisputeServiceResponse>;
+        fn delete_dispute_defense_document_blocking => delete_dispute_defense_document(request: &DeleteDefenseDocumentRequest) -> Result<DisputeServiceResponse>;
+        fn defend_dispute_blocking => defend_dispute(request: &DefendDisputeRequest) -> Result<DisputeServiceResponse>;
+        fn accept_dispute_blocking => accept_dispute(request: &DisputeRequest) -> Result<DisputeServiceResponse>;
+    }
+}
@@ -1 +1,45 @@
-//! Placeholder for adyen-disputes crate
+//! Adyen Disputes API v30 for defending and accepting chargebacks.
+//!
+//! This crate provides access to Adyen's Disputes API v30, enabling merchants
+//! to respond to chargebacks programmatically.
+//!
+//! # Features
+//!
+//! - **Defense Reasons**: Typed defense reason codes with required document types
+//! - **Defense Documents**: Supply and delete documents with base64 encoding handled for you
+//! - **Defend or Accept**: Defend a dispute with a reason, or accept it
+//!
+//! # Quick Start
+//!
+//! ```rust
+//! use adyen_core::{ConfigBuilder, Environment};
+//! use adyen_disputes::{DefendDisputeRequest, DisputeRequest, DisputesApi};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let api = DisputesApi::new(config)?;
+//!
+//! let dispute = DisputeRequest::new("DZ4DPSHB4WD2WN82", "YOUR_MERCHANT_ACCOUNT");
+//! let reasons = api.retrieve_applicable_defense_reasons(&dispute).await?;
+//!
+//! if let Some(reason) = reasons.defense_reasons.iter().find(|reason| reason.satisfied) {
+//!     let request = DefendDisputeRequest::new(
+//!         "DZ4DPSHB4WD2WN82",
+//!         "YOUR_MERCHANT_ACCOUNT",
+//!         reason.defense_reason_code.clone(),
+//!     );
+//!     api.defend_dispute(&request).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+pub mod types;
+
+pub use api::DisputesApi;
+pub use types::*;
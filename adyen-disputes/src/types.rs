@@ -0,0 +1,359 @@
+//! Types for the Adyen Disputes API v30.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// ============================================================================
+// Defense Reasons
+// ============================================================================
+
+/// Reason given to defend a dispute.
+///
+/// The applicable reasons depend on the dispute; retrieve them with
+/// `retrieve_applicable_defense_reasons` before defending. Codes not known to
+/// this library are kept as [`DefenseReasonCode::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DefenseReasonCode {
+    /// Additional information supports the transaction.
+    AdditionalInformation,
+    /// Compelling evidence that the cardholder made the transaction.
+    CompellingEvidence,
+    /// Cancellation or refund policy was properly disclosed.
+    CreditOrCancellationPolicyProperlyDisclosed,
+    /// Goods were not returned by the shopper.
+    GoodsNotReturned,
+    /// Goods or services were provided.
+    GoodsOrServicesProvided,
+    /// Goods were repaired or replaced.
+    GoodsRepairedOrReplaced,
+    /// Goods were as described.
+    GoodsWereAsDescribed,
+    /// The chargeback is invalid.
+    InvalidChargeback,
+    /// Merchandise was received by the shopper.
+    MerchandiseReceived,
+    /// The purchase was properly authorised.
+    PurchaseProperlyAuthorised,
+    /// Services were provided after the cancellation date.
+    ServicesProvidedAfterCancellation,
+    /// Supply defense material.
+    SupplyDefenseMaterial,
+    /// Terminal ID or invoice proves the transaction.
+    TidOrInvoice,
+    /// A reason code not known to this library.
+    Unknown(String),
+}
+
+impl DefenseReasonCode {
+    /// Get the reason code as sent by Adyen, e.g. `GoodsOrServicesProvided`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AdditionalInformation => "AdditionalInformation",
+            Self::CompellingEvidence => "CompellingEvidence",
+            Self::CreditOrCancellationPolicyProperlyDisclosed => {
+                "CreditOrCancellationPolicyProperlyDisclosed"
+            }
+            Self::GoodsNotReturned => "GoodsNotReturned",
+            Self::GoodsOrServicesProvided => "GoodsOrServicesProvided",
+            Self::GoodsRepairedOrReplaced => "GoodsRepairedOrReplaced",
+            Self::GoodsWereAsDescribed => "GoodsWereAsDescribed",
+            Self::InvalidChargeback => "InvalidChargeback",
+            Self::MerchandiseReceived => "MerchandiseReceived",
+            Self::PurchaseProperlyAuthorised => "PurchaseProperlyAuthorised",
+            Self::ServicesProvidedAfterCancellation => "ServicesProvidedAfterCancellation",
+            Self::SupplyDefenseMaterial => "SupplyDefenseMaterial",
+            Self::TidOrInvoice => "TIDorInvoice",
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Check if this is a reason code not known to this library.
+    #[must_use]
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl From<&str> for DefenseReasonCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "AdditionalInformation" => Self::AdditionalInformation,
+            "CompellingEvidence" => Self::CompellingEvidence,
+            "CreditOrCancellationPolicyProperlyDisclosed" => {
+                Self::CreditOrCancellationPolicyProperlyDisclosed
+            }
+            "GoodsNotReturned" => Self::GoodsNotReturned,
+            "GoodsOrServicesProvided" => Self::GoodsOrServicesProvided,
+            "GoodsRepairedOrReplaced" => Self::GoodsRepairedOrReplaced,
+            "GoodsWereAsDescribed" => Self::GoodsWereAsDescribed,
+            "InvalidChargeback" => Self::InvalidChargeback,
+            "MerchandiseReceived" => Self::MerchandiseReceived,
+            "PurchaseProperlyAuthorised" => Self::PurchaseProperlyAuthorised,
+            "ServicesProvidedAfterCancellation" => Self::ServicesProvidedAfterCancellation,
+            "SupplyDefenseMaterial" => Self::SupplyDefenseMaterial,
+            "TIDorInvoice" => Self::TidOrInvoice,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for DefenseReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for DefenseReasonCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DefenseReasonCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(code.as_ref()))
+    }
+}
+
+/// How strongly a document type is required for a defense reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequirementLevel {
+    /// The document must be supplied.
+    Required,
+    /// At least one document of this group must be supplied.
+    OneOrMore,
+    /// The document is optional.
+    Optional,
+    /// Either this or another document must be supplied.
+    AlternativeRequired,
+    /// A requirement level not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Document type accepted for a defense reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenseDocumentType {
+    /// Whether a document of this type was already supplied.
+    pub available: bool,
+    /// Code of the document type, e.g. `DefenseMaterial`.
+    pub defense_document_type_code: Box<str>,
+    /// How strongly the document is required.
+    pub requirement_level: RequirementLevel,
+}
+
+/// Defense reason applicable to a dispute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenseReason {
+    /// Code of the defense reason.
+    pub defense_reason_code: DefenseReasonCode,
+    /// Whether all required documents for this reason were supplied.
+    pub satisfied: bool,
+    /// Document types accepted for this reason.
+    #[serde(default)]
+    pub defense_document_types: Vec<DefenseDocumentType>,
+}
+
+impl DefenseReason {
+    /// Get the document types that must still be supplied.
+    #[must_use]
+    pub fn missing_required_documents(&self) -> Vec<&DefenseDocumentType> {
+        self.defense_document_types
+            .iter()
+            .filter(|document| {
+                !document.available && document.requirement_level == RequirementLevel::Required
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Common Types
+// ============================================================================
+
+/// Outcome of a Disputes API call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeServiceResult {
+    /// Whether the call succeeded.
+    pub success: bool,
+    /// Reason the call failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<Box<str>>,
+}
+
+/// Response of the Disputes API endpoints that only report an outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeServiceResponse {
+    /// Outcome of the call.
+    pub dispute_service_result: DisputeServiceResult,
+}
+
+impl DisputeServiceResponse {
+    /// Check if the call succeeded.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.dispute_service_result.success
+    }
+}
+
+/// Request identifying a dispute.
+///
+/// Used to accept a dispute and to retrieve its applicable defense reasons.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeRequest {
+    /// PSP reference of the dispute.
+    pub dispute_psp_reference: Box<str>,
+    /// Merchant account of the disputed payment.
+    pub merchant_account_code: Box<str>,
+}
+
+impl DisputeRequest {
+    /// Create a request for the given dispute.
+    #[must_use]
+    pub fn new(dispute_psp_reference: &str, merchant_account_code: &str) -> Self {
+        Self {
+            dispute_psp_reference: dispute_psp_reference.into(),
+            merchant_account_code: merchant_account_code.into(),
+        }
+    }
+}
+
+/// Response listing the defense reasons applicable to a dispute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenseReasonsResponse {
+    /// Applicable defense reasons.
+    #[serde(default)]
+    pub defense_reasons: Vec<DefenseReason>,
+    /// Outcome of the call.
+    pub dispute_service_result: DisputeServiceResult,
+}
+
+// ============================================================================
+// Defense
+// ============================================================================
+
+/// Request to defend a dispute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefendDisputeRequest {
+    /// Reason for defending the dispute.
+    pub defense_reason_code: DefenseReasonCode,
+    /// PSP reference of the dispute.
+    pub dispute_psp_reference: Box<str>,
+    /// Merchant account of the disputed payment.
+    pub merchant_account_code: Box<str>,
+}
+
+impl DefendDisputeRequest {
+    /// Create a request to defend the given dispute.
+    #[must_use]
+    pub fn new(
+        dispute_psp_reference: &str,
+        merchant_account_code: &str,
+        defense_reason_code: DefenseReasonCode,
+    ) -> Self {
+        Self {
+            defense_reason_code,
+            dispute_psp_reference: dispute_psp_reference.into(),
+            merchant_account_code: merchant_account_code.into(),
+        }
+    }
+}
+
+/// Document supplied to defend a dispute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenseDocument {
+    /// Base64-encoded document content.
+    pub content: Box<str>,
+    /// MIME type of the document, e.g. `application/pdf` or `image/jpeg`.
+    pub content_type: Box<str>,
+    /// Code of the document type, e.g. `DefenseMaterial`.
+    pub defense_document_type_code: Box<str>,
+}
+
+impl DefenseDocument {
+    /// Create a document from its raw bytes, encoding them as base64.
+    #[must_use]
+    pub fn new(defense_document_type_code: &str, content_type: &str, content: &[u8]) -> Self {
+        Self {
+            content: BASE64.encode(content).into(),
+            content_type: content_type.into(),
+            defense_document_type_code: defense_document_type_code.into(),
+        }
+    }
+
+    /// Decode the document content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid base64.
+    pub fn decode_content(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        BASE64.decode(self.content.as_bytes())
+    }
+}
+
+/// Request to supply defense documents for a dispute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplyDefenseDocumentRequest {
+    /// Documents to supply.
+    pub defense_documents: Vec<DefenseDocument>,
+    /// PSP reference of the dispute.
+    pub dispute_psp_reference: Box<str>,
+    /// Merchant account of the disputed payment.
+    pub merchant_account_code: Box<str>,
+}
+
+impl SupplyDefenseDocumentRequest {
+    /// Create a request supplying `defense_documents` for the given dispute.
+    #[must_use]
+    pub fn new(
+        dispute_psp_reference: &str,
+        merchant_account_code: &str,
+        defense_documents: Vec<DefenseDocument>,
+    ) -> Self {
+        Self {
+            defense_documents,
+            dispute_psp_reference: dispute_psp_reference.into(),
+            merchant_account_code: merchant_account_code.into(),
+        }
+    }
+}
+
+/// Request to delete a supplied defense document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDefenseDocumentRequest {
+    /// Code of the document type to delete.
+    pub defense_document_type: Box<str>,
+    /// PSP reference of the dispute.
+    pub dispute_psp_reference: Box<str>,
+    /// Merchant account of the disputed payment.
+    pub merchant_account_code: Box<str>,
+}
+
+impl DeleteDefenseDocumentRequest {
+    /// Create a request deleting the document of the given type.
+    #[must_use]
+    pub fn new(
+        dispute_psp_reference: &str,
+        merchant_account_code: &str,
+        defense_document_type: &str,
+    ) -> Self {
+        Self {
+            defense_document_type: defense_document_type.into(),
+            dispute_psp_reference: dispute_psp_reference.into(),
+            merchant_account_code: merchant_account_code.into(),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Integration tests for the Adyen Disputes API v30.
+
+use adyen_core::{ConfigBuilder, Environment};
+use adyen_disputes::types::*;
+use adyen_disputes::DisputesApi;
+
+fn create_test_config() -> adyen_core::Config {
+    ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod request_building_tests {
+    use super::*;
+
+    #[test]
+    fn test_api_creation() {
+        let api = DisputesApi::new(create_test_config());
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_defense_document_base64_round_trip() {
+        let document = DefenseDocument::new("DefenseMaterial", "application/pdf", b"%PDF-1.4");
+
+        assert_eq!(&*document.content, "JVBERi0xLjQ=");
+        assert_eq!(document.decode_content().unwrap(), b"%PDF-1.4");
+    }
+
+    #[test]
+    fn test_defense_document_invalid_base64() {
+        let mut document = DefenseDocument::new("DefenseMaterial", "image/jpeg", b"");
+        document.content = "not base64!".into();
+
+        assert!(document.decode_content().is_err());
+    }
+
+    #[test]
+    fn test_defense_reason_code_round_trip() {
+        assert_eq!(
+            DefenseReasonCode::from("TIDorInvoice"),
+            DefenseReasonCode::TidOrInvoice
+        );
+        assert_eq!(DefenseReasonCode::TidOrInvoice.as_str(), "TIDorInvoice");
+
+        let unknown = DefenseReasonCode::from("NewReasonCode");
+        assert!(unknown.is_unknown());
+        assert_eq!(unknown.to_string(), "NewReasonCode");
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_supply_defense_document_request_serialization() {
+        let request = SupplyDefenseDocumentRequest::new(
+            "DZ4DPSHB4WD2WN82",
+            "YOUR_MERCHANT_ACCOUNT",
+            vec![DefenseDocument::new(
+                "DefenseMaterial",
+                "application/pdf",
+                b"%PDF-1.4",
+            )],
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "defenseDocuments": [{
+                    "content": "JVBERi0xLjQ=",
+                    "contentType": "application/pdf",
+                    "defenseDocumentTypeCode": "DefenseMaterial"
+                }],
+                "disputePspReference": "DZ4DPSHB4WD2WN82",
+                "merchantAccountCode": "YOUR_MERCHANT_ACCOUNT"
+            })
+        );
+    }
+
+    #[test]
+    fn test_defend_dispute_request_serialization() {
+        let request = DefendDisputeRequest::new(
+            "DZ4DPSHB4WD2WN82",
+            "YOUR_MERCHANT_ACCOUNT",
+            DefenseReasonCode::GoodsOrServicesProvided,
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["defenseReasonCode"], "GoodsOrServicesProvided");
+        assert_eq!(json["disputePspReference"], "DZ4DPSHB4WD2WN82");
+    }
+
+    #[test]
+    fn test_defense_reasons_response_deserialization() {
+        let json = r#"{
+            "defenseReasons": [
+                {
+                    "defenseDocumentTypes": [
+                        {
+                            "available": false,
+                            "defenseDocumentTypeCode": "TIDorInvoice",
+                            "requirementLevel": "Optional"
+                        },
+                        {
+                            "available": false,
+                            "defenseDocumentTypeCode": "GoodsOrServicesProvided",
+                            "requirementLevel": "Required"
+                        }
+                    ],
+                    "defenseReasonCode": "GoodsOrServicesProvided",
+                    "satisfied": false
+                },
+                {
+                    "defenseReasonCode": "BrandNewReason",
+                    "satisfied": true
+                }
+            ],
+            "disputeServiceResult": {"success": true}
+        }"#;
+
+        let response: DefenseReasonsResponse = serde_json::from_str(json).unwrap();
+        assert!(response.dispute_service_result.success);
+        assert_eq!(response.defense_reasons.len(), 2);
+
+        let reason = &response.defense_reasons[0];
+        assert_eq!(
+            reason.defense_reason_code,
+            DefenseReasonCode::GoodsOrServicesProvided
+        );
+        let missing = reason.missing_required_documents();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(
+            &*missing[0].defense_document_type_code,
+            "GoodsOrServicesProvided"
+        );
+
+        let unknown = &response.defense_reasons[1];
+        assert!(unknown.defense_reason_code.is_unknown());
+        assert!(unknown.defense_document_types.is_empty());
+    }
+
+    #[test]
+    fn test_failed_service_result_deserialization() {
+        let json = r#"{
+            "disputeServiceResult": {
+                "errorMessage": "Dispute not found",
+                "success": false
+            }
+        }"#;
+
+        let response: DisputeServiceResponse = serde_json::from_str(json).unwrap();
+        assert!(!response.is_success());
+        assert_eq!(
+            response.dispute_service_result.error_message.as_deref(),
+            Some("Dispute not found")
+        );
+    }
+}
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+
+    const SERVICE_PATH: &str = "/ca/services/DisputeService/v30";
+
+    fn mock_api(mock: &MockTransport) -> DisputesApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        DisputesApi::new(config).unwrap()
+    }
+
+    fn success() -> MockResponse {
+        MockResponse::json(
+            200,
+            serde_json::json!({"disputeServiceResult": {"success": true}}),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_supply_document_and_defend() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post(format!("{SERVICE_PATH}/supplyDefenseDocument"))
+                .body_includes(serde_json::json!({"merchantAccountCode": "YOUR_MERCHANT_ACCOUNT"}))
+                .respond_with(success()),
+        );
+        mock.expect(
+            Expectation::post(format!("{SERVICE_PATH}/defendDispute"))
+                .body_includes(serde_json::json!({"defenseReasonCode": "SupplyDefenseMaterial"}))
+                .respond_with(success()),
+        );
+
+        let api = mock_api(&mock);
+        let supply = SupplyDefenseDocumentRequest::new(
+            "DZ4DPSHB4WD2WN82",
+            "YOUR_MERCHANT_ACCOUNT",
+            vec![DefenseDocument::new(
+                "DefenseMaterial",
+                "application/pdf",
+                b"%PDF-1.4",
+            )],
+        );
+        assert!(api
+            .supply_defense_document(&supply)
+            .await
+            .unwrap()
+            .is_success());
+
+        let defend = DefendDisputeRequest::new(
+            "DZ4DPSHB4WD2WN82",
+            "YOUR_MERCHANT_ACCOUNT",
+            DefenseReasonCode::SupplyDefenseMaterial,
+        );
+        assert!(api.defend_dispute(&defend).await.unwrap().is_success());
+
+        let body = mock.received_requests()[0].body.clone().unwrap();
+        assert_eq!(body["defenseDocuments"][0]["content"], "JVBERi0xLjQ=");
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_reasons_and_accept() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post(format!("{SERVICE_PATH}/retrieveApplicableDefenseReasons"))
+                .body_includes(serde_json::json!({"disputePspReference": "DZ4DPSHB4WD2WN82"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "defenseReasons": [],
+                        "disputeServiceResult": {"success": true}
+                    }),
+                )),
+        );
+        mock.expect(
+            Expectation::post(format!("{SERVICE_PATH}/deleteDisputeDefenseDocument"))
+                .body_includes(serde_json::json!({"defenseDocumentType": "DefenseMaterial"}))
+                .respond_with(success()),
+        );
+        mock.expect(
+            Expectation::post(format!("{SERVICE_PATH}/acceptDispute")).respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "disputeServiceResult": {
+                            "errorMessage": "Dispute already accepted",
+                            "success": false
+                        }
+                    }),
+                ),
+            ),
+        );
+
+        let api = mock_api(&mock);
+        let dispute = DisputeRequest::new("DZ4DPSHB4WD2WN82", "YOUR_MERCHANT_ACCOUNT");
+
+        let reasons = api
+            .retrieve_applicable_defense_reasons(&dispute)
+            .await
+            .unwrap();
+        assert!(reasons.defense_reasons.is_empty());
+
+        let delete = DeleteDefenseDocumentRequest::new(
+            "DZ4DPSHB4WD2WN82",
+            "YOUR_MERCHANT_ACCOUNT",
+            "DefenseMaterial",
+        );
+        assert!(api
+            .delete_dispute_defense_document(&delete)
+            .await
+            .unwrap()
+            .is_success());
+
+        let accepted = api.accept_dispute(&dispute).await.unwrap();
+        assert!(!accepted.is_success());
+        mock.verify();
+    }
+}
@@ -1,7 +1,7 @@
 //! Legal Entity API client implementation.
 
 use crate::types::*;
-use adyen_core::{Client, Config, Result};
+use adyen_core::{http::Method, Api, Client, Config, Request, Result};
 
 /// Adyen Legal Entity API client.
 ///
@@ -36,10 +36,21 @@ impl LegalEntityApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::LegalEntity)?;
         Ok(Self { client })
     }
 
+    /// Create a new Legal Entity API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::LegalEntity),
+        }
+    }
+
     // ============================================================================
     // Legal Entities Management
     // ============================================================================
@@ -131,6 +142,62 @@ impl LegalEntityApi {
         Ok(response.data)
     }
 
+    /// Check a legal entity for verification errors.
+    ///
+    /// Runs verification checks for the legal entity and returns the problems
+    /// found, with the actions that resolve them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_legal_entity::LegalEntityApi;
+    ///
+    /// # async fn example(api: LegalEntityApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let errors = api.check_verification_errors("LE_ORG").await?;
+    /// for action in errors.remediating_actions() {
+    ///     println!("{:?}: {:?}", action.code, action.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_verification_errors(
+        &self,
+        legal_entity_id: &str,
+    ) -> Result<VerificationErrorsResponse> {
+        let url = format!(
+            "{}/v3/legalEntities/{}/checkVerificationErrors",
+            self.client.config().environment().legal_entity_api_url(),
+            legal_entity_id
+        );
+        let response = self.client.post(&url, &serde_json::json!({})).await?;
+        Ok(response.data)
+    }
+
+    /// Confirm the data of a legal entity is up to date.
+    ///
+    /// Records that the user reviewed the legal entity's data, as required
+    /// periodically for some capabilities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn confirm_data_review(
+        &self,
+        legal_entity_id: &str,
+    ) -> Result<DataReviewConfirmation> {
+        let url = format!(
+            "{}/v3/legalEntities/{}/confirmDataReview",
+            self.client.config().environment().legal_entity_api_url(),
+            legal_entity_id
+        );
+        let response = self.client.post(&url, &serde_json::json!({})).await?;
+        Ok(response.data)
+    }
+
     // ============================================================================
     // Business Lines Management
     // ============================================================================
@@ -226,6 +293,40 @@ impl LegalEntityApi {
         Ok(response.data)
     }
 
+    /// Upload a single-page document for verification from a file.
+    ///
+    /// Reads the file, infers its content type from the extension (PDF, JPEG
+    /// or PNG), and uploads it as a document owned by the legal entity. The
+    /// file is read synchronously, so keep uploads to document-sized files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or has an unsupported
+    /// extension, the request fails, or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_legal_entity::{DocumentType, LegalEntityApi};
+    ///
+    /// # async fn example(api: LegalEntityApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let document = api
+    ///     .upload_document_file(DocumentType::ProofOfAddress, "LE12345", "utility_bill.pdf")
+    ///     .await?;
+    /// println!("Uploaded document {}", document.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_document_file(
+        &self,
+        r#type: DocumentType,
+        legal_entity_id: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Document> {
+        let document = Document::from_file(r#type, legal_entity_id, path)?;
+        self.upload_document(&document).await
+    }
+
     /// Get a document by ID.
     ///
     /// Returns the document details for the specified ID.
@@ -364,38 +465,81 @@ impl LegalEntityApi {
 
     /// Create a hosted onboarding link.
     ///
-    /// Creates a link for user self-service onboarding.
+    /// Creates a link for user self-service onboarding of the legal entity.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_legal_entity::{LegalEntityApi, OnboardingLinkInfo};
+    ///
+    /// # async fn example(api: LegalEntityApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = OnboardingLinkInfo::default()
+    ///     .redirect_url("https://example.com/onboarding/done")
+    ///     .locale("en-US");
+    /// let link = api.create_onboarding_link("LE_ORG", &request).await?;
+    /// println!("Send the user to {}", link.url);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn create_onboarding_link(
         &self,
+        legal_entity_id: &str,
         request: &OnboardingLinkInfo,
     ) -> Result<OnboardingLink> {
         let url = format!(
-            "{}/v3/hostedOnboarding/links",
-            self.client.config().environment().legal_entity_api_url()
+            "{}/v3/legalEntities/{}/onboardingLinks",
+            self.client.config().environment().legal_entity_api_url(),
+            legal_entity_id
         );
         let response = self.client.post(&url, request).await?;
         Ok(response.data)
     }
 
-    /// Get hosted onboarding themes.
+    /// List hosted onboarding themes.
     ///
     /// Returns available themes for hosted onboarding customization.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn get_onboarding_themes(&self) -> Result<Vec<OnboardingTheme>> {
+    pub async fn list_hosted_onboarding_themes(&self) -> Result<OnboardingThemes> {
         let url = format!(
-            "{}/v3/hostedOnboarding/themes",
+            "{}/v3/themes",
             self.client.config().environment().legal_entity_api_url()
         );
-        let response: adyen_core::ApiResponse<PaginatedResponse<OnboardingTheme>> =
-            self.client.get(&url).await?;
-        Ok(response.data.data)
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Get hosted onboarding themes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    #[deprecated(
+        note = "use `list_hosted_onboarding_themes`, which also returns the paging cursors"
+    )]
+    pub async fn get_onboarding_themes(&self) -> Result<Vec<OnboardingTheme>> {
+        Ok(self.list_hosted_onboarding_themes().await?.themes)
+    }
+
+    /// Get a hosted onboarding theme.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_theme(&self, theme_id: &str) -> Result<OnboardingTheme> {
+        let url = format!(
+            "{}/v3/themes/{}",
+            self.client.config().environment().legal_entity_api_url(),
+            theme_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
     }
 
     // ============================================================================
@@ -494,68 +638,112 @@ impl LegalEntityApi {
     // Terms of Service
     // ============================================================================
 
-    /// Get terms of service acceptance information.
+    /// Get the Terms of Service document to show to a legal entity.
     ///
-    /// Returns information about terms of service acceptance status.
+    /// Returns the document in the requested type, language and format. The
+    /// returned document ID is used to accept it.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn get_terms_of_service_acceptance_infos(
+    pub async fn get_terms_of_service_document(
         &self,
         legal_entity_id: &str,
-    ) -> Result<serde_json::Value> {
+        request: &GetTermsOfServiceDocumentRequest,
+    ) -> Result<TermsOfServiceDocument> {
         let url = format!(
             "{}/v3/legalEntities/{}/termsOfService",
             self.client.config().environment().legal_entity_api_url(),
             legal_entity_id
         );
-        let response = self.client.get(&url).await?;
+        let response = self.client.post(&url, request).await?;
         Ok(response.data)
     }
 
-    /// Get terms of service document.
+    /// Accept a Terms of Service document.
     ///
-    /// Returns the terms of service document for the legal entity.
+    /// Records that the individual in the request accepted the document for
+    /// the legal entity.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn get_terms_of_service_document(
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_legal_entity::{
+    ///     AcceptTermsOfServiceRequest, GetTermsOfServiceDocumentRequest, LegalEntityApi,
+    /// };
+    ///
+    /// # async fn example(api: LegalEntityApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let status = api.get_terms_of_service_status("LE_ORG").await?;
+    /// for tos_type in status.terms_of_service_types {
+    ///     let request = GetTermsOfServiceDocumentRequest::new(tos_type, "en");
+    ///     let document = api.get_terms_of_service_document("LE_ORG", &request).await?;
+    ///     // Show the document to the signatory, then record their acceptance.
+    ///     let acceptance = AcceptTermsOfServiceRequest {
+    ///         accepted_by: "LE_SIGNATORY".into(),
+    ///         ip_address: Some("203.0.113.10".into()),
+    ///     };
+    ///     api.accept_terms_of_service("LE_ORG", &document.terms_of_service_document_id, &acceptance)
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_terms_of_service(
         &self,
         legal_entity_id: &str,
-        tos_id: &str,
-    ) -> Result<serde_json::Value> {
+        terms_of_service_document_id: &str,
+        request: &AcceptTermsOfServiceRequest,
+    ) -> Result<AcceptTermsOfServiceResponse> {
         let url = format!(
             "{}/v3/legalEntities/{}/termsOfService/{}",
             self.client.config().environment().legal_entity_api_url(),
             legal_entity_id,
-            tos_id
+            terms_of_service_document_id
         );
-        let response = self.client.get(&url).await?;
+        let response = self.client.patch(&url, request).await?;
         Ok(response.data)
     }
 
-    /// Accept terms of service.
+    /// Get the Terms of Service accepted for a legal entity.
     ///
-    /// Accepts the terms of service for the legal entity.
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_terms_of_service_acceptance_infos(
+        &self,
+        legal_entity_id: &str,
+    ) -> Result<Vec<TermsOfServiceAcceptanceInfo>> {
+        let url = format!(
+            "{}/v3/legalEntities/{}/termsOfServiceAcceptanceInfos",
+            self.client.config().environment().legal_entity_api_url(),
+            legal_entity_id
+        );
+        let response: adyen_core::ApiResponse<PaginatedResponse<TermsOfServiceAcceptanceInfo>> =
+            self.client.get(&url).await?;
+        Ok(response.data.data)
+    }
+
+    /// Get the Terms of Service a legal entity still has to accept.
+    ///
+    /// Onboarding cannot complete until the returned list is empty.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn accept_terms_of_service(
+    pub async fn get_terms_of_service_status(
         &self,
         legal_entity_id: &str,
-        tos_id: &str,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<TermsOfServiceStatus> {
         let url = format!(
-            "{}/v3/legalEntities/{}/termsOfService/{}/acceptTermsOfService",
+            "{}/v3/legalEntities/{}/termsOfServiceStatus",
             self.client.config().environment().legal_entity_api_url(),
-            legal_entity_id,
-            tos_id
+            legal_entity_id
         );
-        let response = self.client.patch(&url, request).await?;
+        let response = self.client.get(&url).await?;
         Ok(response.data)
     }
 
@@ -565,43 +753,99 @@ impl LegalEntityApi {
 
     /// Check tax electronic delivery consent.
     ///
-    /// Checks the status of tax document electronic delivery consent.
+    /// Returns whether the legal entity agreed to receive US Form 1099-K
+    /// electronically.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn check_tax_e_delivery_consent(
+    pub async fn check_tax_electronic_delivery_consent(
         &self,
         legal_entity_id: &str,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<TaxElectronicDeliveryConsent> {
         let url = format!(
-            "{}/v3/legalEntities/{}/taxElectronicDeliveryConsent",
+            "{}/v3/legalEntities/{}/checkTaxElectronicDeliveryConsent",
             self.client.config().environment().legal_entity_api_url(),
             legal_entity_id
         );
-        let response = self.client.get(&url).await?;
+        let response = self.client.post(&url, &serde_json::json!({})).await?;
         Ok(response.data)
     }
 
     /// Set tax electronic delivery consent.
     ///
-    /// Sets the consent for electronic delivery of tax documents.
+    /// Records the legal entity's consent to receive US Form 1099-K
+    /// electronically.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn set_tax_e_delivery_consent(
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_legal_entity::{LegalEntityApi, TaxElectronicDeliveryConsent};
+    ///
+    /// # async fn example(api: LegalEntityApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// api.set_tax_electronic_delivery_consent(
+    ///     "LE_ORG",
+    ///     &TaxElectronicDeliveryConsent::us_1099k(true),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_tax_electronic_delivery_consent(
         &self,
         legal_entity_id: &str,
-        request: &serde_json::Value,
-    ) -> Result<serde_json::Value> {
+        request: &TaxElectronicDeliveryConsent,
+    ) -> Result<()> {
         let url = format!(
-            "{}/v3/legalEntities/{}/taxElectronicDeliveryConsent",
+            "{}/v3/legalEntities/{}/setTaxElectronicDeliveryConsent",
             self.client.config().environment().legal_entity_api_url(),
             legal_entity_id
         );
-        let response = self.client.patch(&url, request).await?;
-        Ok(response.data)
+        // Adyen accepts the consent with an empty 204 response.
+        let request = Request::new(Method::Post, url).with_body(serde_json::to_value(request)?);
+        self.client.execute_raw(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl LegalEntityApi {
+        fn create_legal_entity_blocking => create_legal_entity(request: &LegalEntityInfo) -> Result<LegalEntity>;
+        fn get_legal_entity_blocking => get_legal_entity(legal_entity_id: &str) -> Result<LegalEntity>;
+        fn update_legal_entity_blocking => update_legal_entity(legal_entity_id: &str, request: &LegalEntityInfo) -> Result<LegalEntity>;
+        fn check_verification_errors_blocking => check_verification_errors(legal_entity_id: &str) -> Result<VerificationErrorsResponse>;
+        fn confirm_data_review_blocking => confirm_data_review(legal_entity_id: &str) -> Result<DataReviewConfirmation>;
+        fn create_business_line_blocking => create_business_line(request: &BusinessLineInfo) -> Result<BusinessLine>;
+        fn get_business_line_blocking => get_business_line(business_line_id: &str) -> Result<BusinessLine>;
+        fn update_business_line_blocking => update_business_line(business_line_id: &str, request: &BusinessLineInfo) -> Result<BusinessLine>;
+        fn delete_business_line_blocking => delete_business_line(business_line_id: &str) -> Result<()>;
+        fn upload_document_blocking => upload_document(request: &Document) -> Result<Document>;
+        fn upload_document_file_blocking => upload_document_file(r#type: DocumentType, legal_entity_id: &str, path: impl AsRef<std::path::Path>) -> Result<Document>;
+        fn get_document_blocking => get_document(document_id: &str) -> Result<Document>;
+        fn update_document_blocking => update_document(document_id: &str, request: &Document) -> Result<Document>;
+        fn delete_document_blocking => delete_document(document_id: &str) -> Result<()>;
+        fn create_transfer_instrument_blocking => create_transfer_instrument(request: &TransferInstrumentInfo) -> Result<TransferInstrument>;
+        fn get_transfer_instrument_blocking => get_transfer_instrument(transfer_instrument_id: &str) -> Result<TransferInstrument>;
+        fn update_transfer_instrument_blocking => update_transfer_instrument(transfer_instrument_id: &str, request: &TransferInstrumentInfo) -> Result<TransferInstrument>;
+        fn delete_transfer_instrument_blocking => delete_transfer_instrument(transfer_instrument_id: &str) -> Result<()>;
+        fn create_onboarding_link_blocking => create_onboarding_link(legal_entity_id: &str, request: &OnboardingLinkInfo) -> Result<OnboardingLink>;
+        fn list_hosted_onboarding_themes_blocking => list_hosted_onboarding_themes() -> Result<OnboardingThemes>;
+        fn get_theme_blocking => get_theme(theme_id: &str) -> Result<OnboardingTheme>;
+        fn get_pci_questionnaire_infos_blocking => get_pci_questionnaire_infos(legal_entity_id: &str) -> Result<serde_json::Value>;
+        fn get_pci_questionnaire_blocking => get_pci_questionnaire(legal_entity_id: &str, pci_id: &str) -> Result<serde_json::Value>;
+        fn generate_pci_description_blocking => generate_pci_description(legal_entity_id: &str, pci_id: &str, request: &serde_json::Value) -> Result<serde_json::Value>;
+        fn sign_pci_questionnaire_blocking => sign_pci_questionnaire(legal_entity_id: &str, pci_id: &str, request: &serde_json::Value) -> Result<serde_json::Value>;
+        fn get_terms_of_service_document_blocking => get_terms_of_service_document(legal_entity_id: &str, request: &GetTermsOfServiceDocumentRequest) -> Result<TermsOfServiceDocument>;
+        fn accept_terms_of_service_blocking => accept_terms_of_service(legal_entity_id: &str, terms_of_service_document_id: &str, request: &AcceptTermsOfServiceRequest) -> Result<AcceptTermsOfServiceResponse>;
+        fn get_terms_of_service_acceptance_infos_blocking => get_terms_of_service_acceptance_infos(legal_entity_id: &str) -> Result<Vec<TermsOfServiceAcceptanceInfo>>;
+        fn get_terms_of_service_status_blocking => get_terms_of_service_status(legal_entity_id: &str) -> Result<TermsOfServiceStatus>;
+        fn check_tax_electronic_delivery_consent_blocking => check_tax_electronic_delivery_consent(legal_entity_id: &str) -> Result<TaxElectronicDeliveryConsent>;
+        fn set_tax_electronic_delivery_consent_blocking => set_tax_electronic_delivery_consent(legal_entity_id: &str, request: &TaxElectronicDeliveryConsent) -> Result<()>;
     }
 }
 
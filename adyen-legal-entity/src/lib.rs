@@ -7,13 +7,13 @@
 //! # Features
 //!
 //! - **Legal Entity Management**: Create and manage individuals, organizations, trusts, etc.
-//! - **KYC Verification**: Document upload and verification workflows
+//! - **KYC Verification**: Document upload from bytes or files, and verification workflows
 //! - **Business Lines**: Define operational details and industry classifications
 //! - **Transfer Instruments**: Configure bank accounts for fund transfers
 //! - **Hosted Onboarding**: Self-service onboarding links with customization
 //! - **PCI Compliance**: PCI DSS questionnaire management
 //! - **Terms of Service**: Legal agreement acceptance workflows
-//! - **Tax Compliance**: Electronic delivery consent for tax documents
+//! - **Tax Compliance**: Electronic delivery consent for US Form 1099-K
 //! - **Comprehensive Type Safety**: Full type definitions for all 91 models
 //! - **Builder Patterns**: Ergonomic request builders with validation
 //!
@@ -1,7 +1,10 @@
 //! Types for the Adyen Legal Entity API v3.
 
+use adyen_core::AdyenError;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 // ============================================================================
 // Legal Entity Management
@@ -9,6 +12,11 @@ use std::collections::HashMap;
 
 /// Legal entity representing individuals or organizations for KYC purposes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct LegalEntity {
     /// Unique identifier of the legal entity.
@@ -35,10 +43,17 @@ pub struct LegalEntity {
     pub reference: Option<Box<str>>,
     /// Problems that need to be resolved.
     pub problems: Option<Vec<CapabilityProblem>>,
+    /// Transfer instruments owned by the legal entity.
+    pub transfer_instruments: Option<Vec<TransferInstrumentReference>>,
 }
 
 /// Request to create or update a legal entity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct LegalEntityInfo {
     /// Type of legal entity.
@@ -59,6 +74,11 @@ pub struct LegalEntityInfo {
 
 /// Type of legal entity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum LegalEntityType {
     Individual,
@@ -66,6 +86,9 @@ pub enum LegalEntityType {
     SoleProprietorship,
     Trust,
     UnincorporatedPartnership,
+    /// A legal entity type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -74,6 +97,11 @@ pub enum LegalEntityType {
 
 /// Individual person details for KYC.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Individual {
     /// Full name of the individual.
@@ -96,6 +124,11 @@ pub struct Individual {
 
 /// Full name details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Name {
     /// First name.
@@ -108,6 +141,11 @@ pub struct Name {
 
 /// Phone number information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PhoneNumber {
     /// Phone number including country code.
@@ -118,15 +156,28 @@ pub struct PhoneNumber {
 
 /// Type of phone number.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PhoneType {
     Landline,
     Mobile,
     Fax,
+    /// A phone type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Birth data including date and place.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BirthData {
     /// Date of birth (YYYY-MM-DD format).
@@ -141,6 +192,11 @@ pub struct BirthData {
 
 /// Identification document data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct IdentificationData {
     /// Type of identification document.
@@ -157,6 +213,11 @@ pub struct IdentificationData {
 
 /// Type of identification document.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum IdentificationType {
     DriversLicense,
@@ -165,6 +226,9 @@ pub enum IdentificationType {
     Passport,
     SocialSecurityNumber,
     TaxId,
+    /// An identification type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -173,6 +237,11 @@ pub enum IdentificationType {
 
 /// Organization details for business entities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Organization {
     /// Legal business name.
@@ -209,6 +278,11 @@ pub struct Organization {
 
 /// Type of organization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum OrganizationType {
     AssociationIncorporated,
@@ -219,10 +293,18 @@ pub enum OrganizationType {
     ProfessionalCorporation,
     PublicLimitedCompany,
     RegisteredCharity,
+    /// An organization type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// VAT exemption information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct VatExemption {
     /// Reason for VAT exemption.
@@ -233,6 +315,11 @@ pub struct VatExemption {
 
 /// Website and online presence information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct WebData {
     /// Website URL.
@@ -243,6 +330,11 @@ pub struct WebData {
 
 /// Exemption from providing web data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct WebDataExemption {
     /// Reason for exemption.
@@ -251,6 +343,11 @@ pub struct WebDataExemption {
 
 /// Stock exchange information for public companies.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct StockData {
     /// Market where the stock is traded.
@@ -269,6 +366,11 @@ pub struct StockData {
 
 /// Sole proprietorship business details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct SoleProprietorship {
     /// Principal business address.
@@ -293,6 +395,11 @@ pub struct SoleProprietorship {
 
 /// Trust entity details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Trust {
     /// Name of the trust.
@@ -315,15 +422,28 @@ pub struct Trust {
 
 /// Type of trust structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TrustType {
     RevocableTrust,
     IrrevocableTrust,
     TrustCorporation,
+    /// A trust type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Source of funds information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceOfFunds {
     /// Type of funds source.
@@ -334,6 +454,11 @@ pub struct SourceOfFunds {
 
 /// Type of funds source.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum FundsSourceType {
     Business,
@@ -348,6 +473,11 @@ pub enum FundsSourceType {
 
 /// Undefined beneficiary information for trusts.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UndefinedBeneficiary {
     /// Description of undefined beneficiaries.
@@ -360,6 +490,11 @@ pub struct UndefinedBeneficiary {
 
 /// Unincorporated partnership entity details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UnincorporatedPartnership {
     /// Partnership name.
@@ -392,6 +527,11 @@ pub struct UnincorporatedPartnership {
 
 /// Address information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// Country code (ISO 3166-1 alpha-2).
@@ -414,6 +554,11 @@ pub struct Address {
 
 /// Tax classification and reporting information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TaxInformation {
     /// Country for tax purposes.
@@ -428,6 +573,11 @@ pub struct TaxInformation {
 
 /// Type of tax identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TaxIdType {
     AusTfn,
@@ -445,10 +595,18 @@ pub enum TaxIdType {
     UsSsn,
     UsEin,
     UsTin,
+    /// A tax ID type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Tax reporting classification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TaxReportingClassification {
     /// Business type for tax purposes.
@@ -461,6 +619,11 @@ pub struct TaxReportingClassification {
 
 /// Business type for tax classification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TaxBusinessType {
     Corporation,
@@ -470,10 +633,18 @@ pub enum TaxBusinessType {
     LimitedLiability,
     Partnership,
     Trust,
+    /// A tax business type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Commercial type for tax classification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TaxCommercialType {
     ApplicantExempt,
@@ -489,6 +660,9 @@ pub enum TaxCommercialType {
     RegisteredSecuritiesDealer,
     RetailForexDealer,
     SwapDealer,
+    /// A tax commercial type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -497,6 +671,11 @@ pub enum TaxCommercialType {
 
 /// Legal entity capability configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct LegalEntityCapability {
     /// Whether the capability is allowed.
@@ -523,26 +702,47 @@ pub struct LegalEntityCapability {
 
 /// Allowed level for a capability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CapabilityAllowedLevel {
     High,
     Low,
     Medium,
     NotApplicable,
+    /// A capability allowed level not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Requested level for a capability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CapabilityRequestedLevel {
     High,
     Low,
     Medium,
     NotApplicable,
+    /// A capability requested level not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Settings for a capability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CapabilitySettings {
     /// Amount limits.
@@ -559,23 +759,44 @@ pub struct CapabilitySettings {
 
 /// Funding source for capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum FundingSource {
     Credit,
     Debit,
+    /// A funding source not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Interval for capability settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CapabilitySettingsInterval {
     Daily,
     Monthly,
     Weekly,
+    /// A capability settings interval not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Amount with currency.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Amount {
     /// Three-character ISO currency code.
@@ -586,6 +807,11 @@ pub struct Amount {
 
 /// Problem that needs to be resolved for a capability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CapabilityProblem {
     /// Problem entity details.
@@ -598,6 +824,11 @@ pub struct CapabilityProblem {
 
 /// Entity associated with a capability problem.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CapabilityProblemEntity {
     /// Associated documents.
@@ -612,6 +843,11 @@ pub struct CapabilityProblemEntity {
 
 /// Owner entity reference.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OwnerEntity {
     /// Owner entity ID.
@@ -622,15 +858,28 @@ pub struct OwnerEntity {
 
 /// Type of capability problem.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CapabilityProblemType {
     DataMissing,
     InvalidData,
     VerificationFailed,
+    /// A capability problem type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Supporting entity capability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct SupportingEntityCapability {
     /// Allowed level.
@@ -647,16 +896,29 @@ pub struct SupportingEntityCapability {
 
 /// Verification status for entities and capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum VerificationStatus {
     Pending,
     Valid,
     Invalid,
     Rejected,
+    /// A verification status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Verification errors collection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationErrors {
     /// List of verification errors.
@@ -665,6 +927,19 @@ pub struct VerificationErrors {
 
 /// Individual verification error.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+// `sub_errors` makes the type recursive, so its bounds are spelled out
+// instead of inferred from the fields.
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(
+        check_bytes,
+        bound(serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer")
+    ),
+    archive_attr(check_bytes(
+        bound = "__C: rkyv::validation::ArchiveContext, <__C as rkyv::Fallible>::Error: rkyv::bytecheck::Error"
+    ))
+)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationError {
     /// Error code.
@@ -675,19 +950,97 @@ pub struct VerificationError {
     pub r#type: Option<VerificationErrorType>,
     /// Remediating actions to resolve the error.
     pub remediating_actions: Option<Vec<RemediatingAction>>,
+    /// More specific errors that make up this error.
+    #[cfg_attr(feature = "rkyv", omit_bounds, archive_attr(omit_bounds))]
+    pub sub_errors: Option<Vec<VerificationError>>,
 }
 
 /// Type of verification error.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum VerificationErrorType {
     DataMissing,
+    DataReview,
     InvalidData,
+    InvalidInput,
     PendingReview,
+    PendingStatus,
+    Rejected,
+    /// An error type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Result of checking a legal entity for verification errors.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationErrorsResponse {
+    /// Problems found, grouped by the entity they apply to.
+    #[serde(default)]
+    pub problems: Vec<CapabilityProblem>,
+}
+
+impl VerificationErrorsResponse {
+    /// Returns `true` if no problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// All remediating actions across problems and nested errors, without
+    /// duplicates, in the order they first appear.
+    pub fn remediating_actions(&self) -> Vec<&RemediatingAction> {
+        fn collect<'a>(errors: &'a [VerificationError], actions: &mut Vec<&'a RemediatingAction>) {
+            for error in errors {
+                for action in error.remediating_actions.iter().flatten() {
+                    if !actions.iter().any(|a| a.code == action.code) {
+                        actions.push(action);
+                    }
+                }
+                collect(error.sub_errors.as_deref().unwrap_or_default(), actions);
+            }
+        }
+
+        let mut actions = Vec::new();
+        for problem in &self.problems {
+            collect(
+                problem.verification_errors.as_deref().unwrap_or_default(),
+                &mut actions,
+            );
+        }
+        actions
+    }
+}
+
+/// Confirmation that the legal entity's data was reviewed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DataReviewConfirmation {
+    /// When the data was confirmed, in ISO 8601 format.
+    pub data_reviewed_at: Option<Box<str>>,
 }
 
 /// Action to remediate a verification error.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RemediatingAction {
     /// Action code.
@@ -702,6 +1055,11 @@ pub struct RemediatingAction {
 
 /// Association between legal entities (ownership, representation, etc.).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct LegalEntityAssociation {
     /// Associated entity ID.
@@ -718,6 +1076,11 @@ pub struct LegalEntityAssociation {
 
 /// Type of association between entities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum AssociationType {
     BeneficialOwner,
@@ -728,6 +1091,9 @@ pub enum AssociationType {
     Signatory,
     TrusteeBeneficiary,
     UndefinedBeneficiary,
+    /// An association type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -736,9 +1102,15 @@ pub enum AssociationType {
 
 /// Document for verification purposes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Document {
-    /// Document ID.
+    /// Document ID. Empty for documents that are not uploaded yet.
+    #[serde(default, skip_serializing_if = "str::is_empty")]
     pub id: Box<str>,
     /// Type of document.
     pub r#type: DocumentType,
@@ -760,8 +1132,67 @@ pub struct Document {
     pub file_name: Option<Box<str>>,
 }
 
+impl Document {
+    /// Create a document of the given type for a legal entity, without pages.
+    pub fn new(r#type: DocumentType, owner_id: impl Into<Box<str>>) -> Self {
+        Self {
+            id: "".into(),
+            r#type,
+            pages: None,
+            owner: Some(EntityReference {
+                id: owner_id.into(),
+                r#type: None,
+            }),
+            creation_date: None,
+            modification_date: None,
+            expiry_date: None,
+            number: None,
+            description: None,
+            file_name: None,
+        }
+    }
+
+    /// Create a single-page document for a legal entity from a file.
+    ///
+    /// The content type is inferred from the file extension with
+    /// [`DocumentPage::content_type_for_path`], and the file name is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or its extension is not
+    /// a supported document format.
+    pub fn from_file(
+        r#type: DocumentType,
+        owner_id: impl Into<Box<str>>,
+        path: impl AsRef<Path>,
+    ) -> adyen_core::Result<Self> {
+        let path = path.as_ref();
+        let mut document = Self::new(r#type, owner_id).with_page(DocumentPage::from_file(path)?);
+        document.file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(Into::into);
+        Ok(document)
+    }
+
+    /// Append a page, numbering it after the existing pages.
+    pub fn with_page(mut self, mut page: DocumentPage) -> Self {
+        let pages = self.pages.get_or_insert_with(Vec::new);
+        if page.page_number.is_none() {
+            page.page_number = i32::try_from(pages.len() + 1).ok();
+        }
+        pages.push(page);
+        self
+    }
+}
+
 /// Type of document for verification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum DocumentType {
     AdditionalRequirement,
@@ -783,10 +1214,18 @@ pub enum DocumentType {
     UboDeclaration,
     UtilityBill,
     VoterIdCard,
+    /// A document type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Individual page of a document.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct DocumentPage {
     /// Page content (base64 encoded).
@@ -797,8 +1236,72 @@ pub struct DocumentPage {
     pub page_number: Option<i32>,
 }
 
+impl DocumentPage {
+    /// Create a page from raw file contents, e.g. a scanned PDF or JPEG.
+    pub fn from_bytes(bytes: &[u8], content_type: impl Into<Box<str>>) -> Self {
+        Self {
+            content: BASE64.encode(bytes).into(),
+            content_type: Some(content_type.into()),
+            page_number: None,
+        }
+    }
+
+    /// Create a page from a file, inferring the content type from its
+    /// extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or its extension is not
+    /// a supported document format.
+    pub fn from_file(path: impl AsRef<Path>) -> adyen_core::Result<Self> {
+        let path = path.as_ref();
+        let content_type = Self::content_type_for_path(path).ok_or_else(|| {
+            AdyenError::generic(format!(
+                "unsupported document format: {}, expected PDF, JPEG or PNG",
+                path.display()
+            ))
+        })?;
+        let bytes = std::fs::read(path).map_err(|e| {
+            AdyenError::generic_with_source(
+                format!("failed to read document file {}", path.display()),
+                Box::new(e),
+            )
+        })?;
+        Ok(Self::from_bytes(&bytes, content_type))
+    }
+
+    /// MIME type of a document file, based on its extension.
+    ///
+    /// Returns `None` for formats Adyen does not accept.
+    pub fn content_type_for_path(path: impl AsRef<Path>) -> Option<&'static str> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "pdf" => Some("application/pdf"),
+            "jpg" | "jpeg" => Some("image/jpeg"),
+            "png" => Some("image/png"),
+            _ => None,
+        }
+    }
+
+    /// Decode the page content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid base64.
+    pub fn to_bytes(&self) -> adyen_core::Result<Vec<u8>> {
+        BASE64.decode(self.content.as_bytes()).map_err(|e| {
+            AdyenError::generic_with_source("invalid document page content", Box::new(e))
+        })
+    }
+}
+
 /// Reference to an entity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityReference {
     /// Entity ID.
@@ -813,6 +1316,11 @@ pub struct EntityReference {
 
 /// Transfer instrument for moving funds.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TransferInstrument {
     /// Transfer instrument ID.
@@ -823,10 +1331,38 @@ pub struct TransferInstrument {
     pub r#type: TransferInstrumentType,
     /// Bank account information.
     pub bank_account: Option<BankAccountInfo>,
+    /// Capabilities the instrument supports, keyed by capability name.
+    pub capabilities: Option<HashMap<String, SupportingEntityCapability>>,
+    /// Problems that need to be resolved before the instrument can be used.
+    pub problems: Option<Vec<CapabilityProblem>>,
+}
+
+/// Summary of a transfer instrument listed on its legal entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferInstrumentReference {
+    /// Transfer instrument ID.
+    pub id: Box<str>,
+    /// Masked account number or IBAN.
+    pub account_identifier: Option<Box<str>>,
+    /// Last four digits of the account number.
+    pub real_last_four: Option<Box<str>>,
+    /// Whether the instrument was verified by a trusted source.
+    pub trusted_source: Option<bool>,
 }
 
 /// Request to create transfer instrument.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TransferInstrumentInfo {
     /// Legal entity ID.
@@ -839,13 +1375,27 @@ pub struct TransferInstrumentInfo {
 
 /// Type of transfer instrument.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TransferInstrumentType {
     BankAccount,
+    RecurringDetail,
+    /// A transfer instrument type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Bank account information for transfers.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BankAccountInfo {
     /// Account holder name.
@@ -856,6 +1406,11 @@ pub struct BankAccountInfo {
 
 /// Bank account identification methods.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 #[serde(untagged)]
 pub enum BankAccountIdentification {
@@ -877,6 +1432,11 @@ pub enum BankAccountIdentification {
 
 /// IBAN account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct IbanAccountIdentification {
     /// International Bank Account Number.
@@ -887,6 +1447,11 @@ pub struct IbanAccountIdentification {
 
 /// US local account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UsLocalAccountIdentification {
     /// Account number.
@@ -901,14 +1466,27 @@ pub struct UsLocalAccountIdentification {
 
 /// US bank account type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum UsAccountType {
     Checking,
     Savings,
+    /// A US account type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// UK local account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UkLocalAccountIdentification {
     /// Account number.
@@ -921,6 +1499,11 @@ pub struct UkLocalAccountIdentification {
 
 /// Canadian local account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CaLocalAccountIdentification {
     /// Account number.
@@ -937,14 +1520,27 @@ pub struct CaLocalAccountIdentification {
 
 /// Canadian bank account type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CaAccountType {
     Checking,
     Savings,
+    /// A Canadian account type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Australian local account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AuLocalAccountIdentification {
     /// Account number.
@@ -957,6 +1553,11 @@ pub struct AuLocalAccountIdentification {
 
 /// Singapore local account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct SgLocalAccountIdentification {
     /// Account number.
@@ -969,6 +1570,11 @@ pub struct SgLocalAccountIdentification {
 
 /// Number and BIC account identification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct NumberAndBicAccountIdentification {
     /// Account number.
@@ -985,6 +1591,11 @@ pub struct NumberAndBicAccountIdentification {
 
 /// Business line for legal entity operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BusinessLine {
     /// Business line ID.
@@ -1007,6 +1618,11 @@ pub struct BusinessLine {
 
 /// Request to create or update business line.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BusinessLineInfo {
     /// Legal entity ID.
@@ -1025,12 +1641,20 @@ pub struct BusinessLineInfo {
 
 /// Sales channel for business operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum SalesChannel {
     Ecommerce,
     Pos,
     ContAuth,
     Moto,
+    /// A sales channel not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -1039,30 +1663,77 @@ pub enum SalesChannel {
 
 /// Hosted onboarding link for user self-service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OnboardingLink {
-    /// Unique link ID.
-    pub id: Box<str>,
-    /// Onboarding URL for the user.
+    /// Onboarding URL for the user. The link expires after a short time.
     pub url: Box<str>,
-    /// Link expiration time.
-    pub expires_at: Option<Box<str>>,
 }
 
 /// Request to create onboarding link.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OnboardingLinkInfo {
-    /// Legal entity ID.
-    pub legal_entity_id: Box<str>,
     /// Onboarding settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub settings: Option<OnboardingLinkSettings>,
     /// Theme customization.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub theme_id: Option<Box<str>>,
+    /// URL the user is redirected to after completing onboarding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<Box<str>>,
+    /// Language of the onboarding pages, e.g. `en-US`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Box<str>>,
+}
+
+impl OnboardingLinkInfo {
+    /// Set the theme applied to the onboarding pages.
+    #[must_use]
+    pub fn theme_id(mut self, theme_id: impl Into<Box<str>>) -> Self {
+        self.theme_id = Some(theme_id.into());
+        self
+    }
+
+    /// Set the URL the user returns to after onboarding.
+    #[must_use]
+    pub fn redirect_url(mut self, redirect_url: impl Into<Box<str>>) -> Self {
+        self.redirect_url = Some(redirect_url.into());
+        self
+    }
+
+    /// Set the language of the onboarding pages.
+    #[must_use]
+    pub fn locale(mut self, locale: impl Into<Box<str>>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Set the onboarding settings.
+    #[must_use]
+    pub fn settings(mut self, settings: OnboardingLinkSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
 }
 
 /// Settings for onboarding links.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OnboardingLinkSettings {
     /// Target entity types to collect.
@@ -1075,26 +1746,289 @@ pub struct OnboardingLinkSettings {
 
 /// Type of verification check.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum VerificationCheckType {
     CompanyVerification,
     IdentityVerification,
     PassportVerification,
     VisaVerification,
+    /// A verification check type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Onboarding theme customization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct OnboardingTheme {
     /// Theme ID.
     pub id: Box<str>,
-    /// Primary color.
-    pub primary_color: Option<Box<str>>,
-    /// Secondary color.
-    pub secondary_color: Option<Box<str>>,
-    /// Logo URL.
-    pub logo_url: Option<Box<str>>,
+    /// Theme type, e.g. `onboarding`.
+    pub r#type: Option<Box<str>>,
+    /// Theme description.
+    pub description: Option<Box<str>>,
+    /// Theme properties such as colors and logo URLs, keyed by property name.
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    /// Creation timestamp.
+    pub created_at: Option<Box<str>>,
+    /// Last update timestamp.
+    pub updated_at: Option<Box<str>>,
+}
+
+/// Page of hosted onboarding themes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingThemes {
+    /// Themes on this page.
+    #[serde(default)]
+    pub themes: Vec<OnboardingTheme>,
+    /// Cursor for the next page.
+    pub next: Option<Box<str>>,
+    /// Cursor for the previous page.
+    pub previous: Option<Box<str>>,
+}
+
+// ============================================================================
+// Terms of Service
+// ============================================================================
+
+/// Type of Terms of Service document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TermsOfServiceType {
+    AdyenAccount,
+    AdyenCapital,
+    AdyenCard,
+    AdyenChargeCard,
+    AdyenForPlatformsAdvanced,
+    AdyenForPlatformsManage,
+    AdyenFranchisee,
+    AdyenIssuing,
+    AdyenPccr,
+    CapitalAtWill,
+    KycOnInvite,
+    /// A Terms of Service type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Format of a Terms of Service document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TermsOfServiceDocumentFormat {
+    Json,
+    Pdf,
+    Txt,
+    /// A terms of service document format not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request for a Terms of Service document to show to the legal entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTermsOfServiceDocumentRequest {
+    /// Type of Terms of Service.
+    pub r#type: TermsOfServiceType,
+    /// Language of the document, as a two-letter ISO 639-1 code, e.g. `en`.
+    pub language: Box<str>,
+    /// Format of the document. Defaults to JSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_document_format: Option<TermsOfServiceDocumentFormat>,
+}
+
+impl GetTermsOfServiceDocumentRequest {
+    /// Request the document of the given type in `language`.
+    pub fn new(r#type: TermsOfServiceType, language: impl Into<Box<str>>) -> Self {
+        Self {
+            r#type,
+            language: language.into(),
+            terms_of_service_document_format: None,
+        }
+    }
+
+    /// Set the format of the document.
+    pub fn format(mut self, format: TermsOfServiceDocumentFormat) -> Self {
+        self.terms_of_service_document_format = Some(format);
+        self
+    }
+}
+
+/// Terms of Service document for a legal entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TermsOfServiceDocument {
+    /// Legal entity ID.
+    pub id: Box<str>,
+    /// Document content (base64 encoded).
+    pub document: Box<str>,
+    /// Language of the document.
+    pub language: Box<str>,
+    /// ID of the document, used to accept it.
+    pub terms_of_service_document_id: Box<str>,
+    /// Type of Terms of Service.
+    pub r#type: TermsOfServiceType,
+    /// Format of the document.
+    pub terms_of_service_document_format: Option<TermsOfServiceDocumentFormat>,
+}
+
+impl TermsOfServiceDocument {
+    /// Decode the document content, e.g. the bytes of a PDF.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid base64.
+    pub fn to_bytes(&self) -> adyen_core::Result<Vec<u8>> {
+        BASE64.decode(self.document.as_bytes()).map_err(|e| {
+            AdyenError::generic_with_source("invalid Terms of Service document", Box::new(e))
+        })
+    }
+}
+
+/// Request to accept a Terms of Service document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptTermsOfServiceRequest {
+    /// Legal entity ID of the individual who accepts, e.g. a signatory of
+    /// the organization.
+    pub accepted_by: Box<str>,
+    /// IP address of the individual who accepts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<Box<str>>,
+}
+
+/// Result of accepting a Terms of Service document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptTermsOfServiceResponse {
+    /// Acceptance reference.
+    pub id: Box<str>,
+    /// Legal entity ID of the individual who accepted.
+    pub accepted_by: Box<str>,
+    /// IP address of the individual who accepted.
+    pub ip_address: Option<Box<str>>,
+    /// Language of the accepted document.
+    pub language: Option<Box<str>>,
+    /// ID of the accepted document.
+    pub terms_of_service_document_id: Option<Box<str>>,
+    /// Type of Terms of Service.
+    pub r#type: TermsOfServiceType,
+}
+
+/// Record of an accepted Terms of Service document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TermsOfServiceAcceptanceInfo {
+    /// Acceptance reference.
+    pub id: Box<str>,
+    /// Legal entity ID of the individual who accepted.
+    pub accepted_by: Box<str>,
+    /// Legal entity ID the Terms of Service were accepted for.
+    pub accepted_for: Box<str>,
+    /// Acceptance time.
+    pub created_at: Option<Box<str>>,
+    /// Type of Terms of Service.
+    pub r#type: TermsOfServiceType,
+    /// Time until which the acceptance is valid.
+    pub valid_to: Option<Box<str>>,
+}
+
+/// Terms of Service a legal entity still has to accept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TermsOfServiceStatus {
+    /// Types of Terms of Service to accept.
+    #[serde(default)]
+    pub terms_of_service_types: Vec<TermsOfServiceType>,
+}
+
+impl TermsOfServiceStatus {
+    /// Whether all required Terms of Service are accepted.
+    pub fn is_complete(&self) -> bool {
+        self.terms_of_service_types.is_empty()
+    }
+}
+
+// ============================================================================
+// Tax Electronic Delivery Consent
+// ============================================================================
+
+/// Consent to receive tax forms electronically instead of by post.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct TaxElectronicDeliveryConsent {
+    /// Consent to receive US Form 1099-K electronically.
+    #[serde(rename = "US1099k", skip_serializing_if = "Option::is_none")]
+    pub us_1099k: Option<bool>,
+}
+
+impl TaxElectronicDeliveryConsent {
+    /// Consent, or withdraw consent, to electronic delivery of Form 1099-K.
+    pub fn us_1099k(consent: bool) -> Self {
+        Self {
+            us_1099k: Some(consent),
+        }
+    }
 }
 
 // ============================================================================
@@ -1103,6 +2037,11 @@ pub struct OnboardingTheme {
 
 /// Response wrapper for paginated results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginatedResponse<T> {
     /// Array of result items.
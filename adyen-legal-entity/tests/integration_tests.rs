@@ -214,15 +214,19 @@ mod request_building_tests {
             ]),
         };
 
-        let request = OnboardingLinkInfo {
-            legal_entity_id: "LE12345".into(),
-            settings: Some(settings),
-            theme_id: Some("theme_123".into()),
-        };
+        let request = OnboardingLinkInfo::default()
+            .settings(settings)
+            .theme_id("theme_123")
+            .redirect_url("https://example.com/done")
+            .locale("en-US");
 
-        assert_eq!(request.legal_entity_id.as_ref(), "LE12345");
         assert!(request.settings.is_some());
         assert_eq!(request.theme_id.as_ref().unwrap().as_ref(), "theme_123");
+        assert_eq!(
+            request.redirect_url.as_ref().unwrap().as_ref(),
+            "https://example.com/done"
+        );
+        assert_eq!(request.locale.as_ref().unwrap().as_ref(), "en-US");
     }
 }
 
@@ -303,6 +307,115 @@ mod serialization_tests {
         assert!(json.contains("\"accountType\":\"checking\""));
     }
 
+    #[test]
+    fn test_document_upload_serialization() {
+        let path = std::env::temp_dir().join("adyen_legal_entity_test_document.PDF");
+        std::fs::write(&path, b"%PDF-1.4 test").unwrap();
+        let document = Document::from_file(DocumentType::BankStatement, "LE12345", &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let json = serde_json::to_value(&document).unwrap();
+        assert!(json.get("id").is_none());
+        assert_eq!(json["type"], "bankStatement");
+        assert_eq!(json["owner"]["id"], "LE12345");
+        assert_eq!(json["fileName"], "adyen_legal_entity_test_document.PDF");
+        assert_eq!(
+            json["pages"],
+            serde_json::json!([{
+                "content": "JVBERi0xLjQgdGVzdA==",
+                "contentType": "application/pdf",
+                "pageNumber": 1
+            }])
+        );
+
+        let pages = document.pages.unwrap();
+        assert_eq!(pages[0].to_bytes().unwrap(), b"%PDF-1.4 test");
+        assert_eq!(
+            DocumentPage::content_type_for_path("passport.jpeg"),
+            Some("image/jpeg")
+        );
+        assert!(Document::from_file(DocumentType::PassportPage, "LE12345", "scan.gif").is_err());
+    }
+
+    #[test]
+    fn test_onboarding_serialization() {
+        let request = OnboardingLinkInfo::default().redirect_url("https://example.com/done");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"redirectUrl": "https://example.com/done"})
+        );
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("settings").is_none());
+        assert!(json.get("themeId").is_none());
+
+        let themes: OnboardingThemes = serde_json::from_str(
+            r##"{
+                "themes": [{
+                    "id": "ONBT00000000000000000000001",
+                    "type": "onboarding",
+                    "description": "Default theme",
+                    "properties": {"pageBackgroundColor": "#ffffff"},
+                    "createdAt": "2024-01-01T12:00:00+01:00"
+                }],
+                "next": "cursor_2"
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(themes.themes.len(), 1);
+        assert_eq!(
+            themes.themes[0].properties["pageBackgroundColor"],
+            "#ffffff"
+        );
+        assert_eq!(themes.next.as_deref(), Some("cursor_2"));
+        assert!(themes.previous.is_none());
+    }
+
+    #[test]
+    fn test_terms_of_service_serialization() {
+        let request = GetTermsOfServiceDocumentRequest::new(
+            TermsOfServiceType::AdyenForPlatformsManage,
+            "en",
+        )
+        .format(TermsOfServiceDocumentFormat::Pdf);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "type": "adyenForPlatformsManage",
+                "language": "en",
+                "termsOfServiceDocumentFormat": "PDF"
+            })
+        );
+
+        let document: TermsOfServiceDocument = serde_json::from_str(
+            r#"{
+                "id": "LE12345",
+                "document": "JVBERi0xLjQ=",
+                "language": "en",
+                "termsOfServiceDocumentId": "abc123",
+                "type": "adyenForPlatformsManage",
+                "termsOfServiceDocumentFormat": "PDF"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(document.to_bytes().unwrap(), b"%PDF-1.4");
+
+        let status: TermsOfServiceStatus = serde_json::from_str(
+            r#"{"termsOfServiceTypes": ["adyenIssuing", "capitalAtWill", "someNewType"]}"#,
+        )
+        .unwrap();
+        assert!(!status.is_complete());
+        assert_eq!(
+            status.terms_of_service_types,
+            [
+                TermsOfServiceType::AdyenIssuing,
+                TermsOfServiceType::CapitalAtWill,
+                TermsOfServiceType::Unknown
+            ]
+        );
+        let status: TermsOfServiceStatus = serde_json::from_str("{}").unwrap();
+        assert!(status.is_complete());
+    }
+
     #[test]
     fn test_enum_serialization() {
         assert_eq!(
@@ -599,7 +712,6 @@ mod workflow_tests {
 
         // 4. Hosted Onboarding for Sub-entities
         let onboarding_link = OnboardingLinkInfo {
-            legal_entity_id: "LE_ORG_001".into(),
             settings: Some(OnboardingLinkSettings {
                 collect_entity_types: Some(vec![LegalEntityType::Individual]),
                 enable_manual_review: Some(true),
@@ -609,6 +721,7 @@ mod workflow_tests {
                 ]),
             }),
             theme_id: Some("techcorp_theme".into()),
+            ..OnboardingLinkInfo::default()
         };
 
         // Verify organization onboarding workflow
@@ -694,6 +807,197 @@ mod workflow_tests {
     }
 }
 
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+
+    fn mock_api(mock: &MockTransport) -> LegalEntityApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        LegalEntityApi::new(config).unwrap()
+    }
+
+    fn transfer_instrument_json() -> serde_json::Value {
+        serde_json::json!({
+            "id": "SE322KH223222F5GXZFNM3BGP",
+            "legalEntityId": "LE322JV223222D5GG42KN6869",
+            "type": "bankAccount",
+            "bankAccount": {
+                "accountHolder": "Example Corp",
+                "accountIdentification": {
+                    "type": "iban",
+                    "iban": "NL62ABNA0000000123"
+                }
+            },
+            "capabilities": {
+                "sendToTransferInstrument": {
+                    "allowedLevel": "high",
+                    "enabled": true,
+                    "requestedLevel": "high",
+                    "verificationStatus": "pending"
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_transfer_instrument_lifecycle() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/v3/transferInstruments")
+                .body_includes(serde_json::json!({
+                    "legalEntityId": "LE322JV223222D5GG42KN6869",
+                    "type": "bankAccount"
+                }))
+                .respond_with(MockResponse::json(200, transfer_instrument_json())),
+        );
+        mock.expect(
+            Expectation::get("/v3/transferInstruments/SE322KH223222F5GXZFNM3BGP")
+                .respond_with(MockResponse::json(200, transfer_instrument_json())),
+        );
+        mock.expect(
+            Expectation::patch("/v3/transferInstruments/SE322KH223222F5GXZFNM3BGP")
+                .respond_with(MockResponse::json(200, transfer_instrument_json())),
+        );
+        mock.expect(
+            Expectation::delete("/v3/transferInstruments/SE322KH223222F5GXZFNM3BGP")
+                .respond_with(MockResponse::raw(204, Vec::new())),
+        );
+
+        let api = mock_api(&mock);
+        let request = TransferInstrumentInfo {
+            legal_entity_id: "LE322JV223222D5GG42KN6869".into(),
+            r#type: TransferInstrumentType::BankAccount,
+            bank_account: Some(BankAccountInfo {
+                account_holder: "Example Corp".into(),
+                account_identification: BankAccountIdentification::Iban(
+                    IbanAccountIdentification {
+                        iban: "NL62ABNA0000000123".into(),
+                        r#type: "iban".into(),
+                    },
+                ),
+            }),
+        };
+
+        let created = api.create_transfer_instrument(&request).await.unwrap();
+        let capability = &created.capabilities.as_ref().unwrap()["sendToTransferInstrument"];
+        assert_eq!(capability.enabled, Some(true));
+
+        let fetched = api.get_transfer_instrument(&created.id).await.unwrap();
+        assert!(matches!(
+            fetched.bank_account.unwrap().account_identification,
+            BankAccountIdentification::Iban(_)
+        ));
+
+        api.update_transfer_instrument(&created.id, &request)
+            .await
+            .unwrap();
+        api.delete_transfer_instrument(&created.id).await.unwrap();
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_check_verification_errors() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/v3/legalEntities/LE_ORG/checkVerificationErrors").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "problems": [{
+                            "entity": {"id": "LE_ORG", "type": "organization"},
+                            "verificationErrors": [{
+                                "code": "2_8036",
+                                "message": "Bank account details are missing",
+                                "type": "dataMissing",
+                                "remediatingActions": [
+                                    {"code": "2_138", "message": "Add bank account details"}
+                                ],
+                                "subErrors": [{
+                                    "code": "2_8037",
+                                    "type": "someNewType",
+                                    "remediatingActions": [
+                                        {"code": "2_138", "message": "Add bank account details"},
+                                        {"code": "2_151", "message": "Upload a bank statement"}
+                                    ]
+                                }]
+                            }]
+                        }]
+                    }),
+                ),
+            ),
+        );
+        mock.expect(
+            Expectation::post("/v3/legalEntities/LE_ORG/confirmDataReview").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({"dataReviewedAt": "2024-05-01T12:00:00Z"}),
+                ),
+            ),
+        );
+
+        let api = mock_api(&mock);
+        let errors = api.check_verification_errors("LE_ORG").await.unwrap();
+        assert!(!errors.is_empty());
+        let codes: Vec<_> = errors
+            .remediating_actions()
+            .iter()
+            .map(|a| a.code.as_deref().unwrap())
+            .collect();
+        assert_eq!(codes, ["2_138", "2_151"]);
+        let error = &errors.problems[0].verification_errors.as_ref().unwrap()[0];
+        assert_eq!(error.r#type, Some(VerificationErrorType::DataMissing));
+        assert_eq!(
+            error.sub_errors.as_ref().unwrap()[0].r#type,
+            Some(VerificationErrorType::Unknown)
+        );
+
+        let confirmation = api.confirm_data_review("LE_ORG").await.unwrap();
+        assert_eq!(
+            confirmation.data_reviewed_at.as_deref(),
+            Some("2024-05-01T12:00:00Z")
+        );
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_tax_electronic_delivery_consent() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/v3/legalEntities/LE_ORG/setTaxElectronicDeliveryConsent")
+                .body_includes(serde_json::json!({"US1099k": true}))
+                .respond_with(MockResponse::raw(204, Vec::new())),
+        );
+        mock.expect(
+            Expectation::post("/v3/legalEntities/LE_ORG/checkTaxElectronicDeliveryConsent")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({"US1099k": true}),
+                )),
+        );
+
+        let api = mock_api(&mock);
+        api.set_tax_electronic_delivery_consent(
+            "LE_ORG",
+            &TaxElectronicDeliveryConsent::us_1099k(true),
+        )
+        .await
+        .unwrap();
+        let consent = api
+            .check_tax_electronic_delivery_consent("LE_ORG")
+            .await
+            .unwrap();
+        assert_eq!(consent.us_1099k, Some(true));
+        mock.verify();
+    }
+}
+
 #[cfg(test)]
 mod api_tests {
     use super::*;
@@ -705,3 +1009,30 @@ mod api_tests {
         // API created successfully indicates proper configuration
     }
 }
+
+#[cfg(feature = "rkyv")]
+mod rkyv_tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_verification_errors_archive() {
+        let error: VerificationError = serde_json::from_value(serde_json::json!({
+            "code": "2_8036",
+            "type": "dataMissing",
+            "subErrors": [{"code": "2_8037", "message": "Bank statement missing"}]
+        }))
+        .unwrap();
+
+        let bytes = rkyv::to_bytes::<_, 1024>(&error).unwrap();
+        let archived = rkyv::check_archived_root::<VerificationError>(&bytes).unwrap();
+        let sub_errors = archived.sub_errors.as_ref().unwrap();
+        assert_eq!(sub_errors[0].code.as_deref(), Some("2_8037"));
+
+        let restored: VerificationError =
+            rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).unwrap();
+        assert_eq!(
+            restored.sub_errors.unwrap()[0].message.as_deref(),
+            Some("Bank statement missing")
+        );
+    }
+}
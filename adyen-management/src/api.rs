@@ -1,7 +1,10 @@
 //! Management API client implementation.
 
 use crate::types::*;
-use adyen_core::{Client, Config, Result};
+use adyen_core::{
+    http::Method, Api, Client, Config, Page, PageIterator, PaginatedRequest, Request, Result,
+};
+use futures::Stream;
 
 /// Adyen Management API client.
 ///
@@ -36,10 +39,21 @@ impl ManagementApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::Management)?;
         Ok(Self { client })
     }
 
+    /// Create a new Management API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Management),
+        }
+    }
+
     // Company Management
 
     /// Get a company account.
@@ -77,6 +91,62 @@ impl ManagementApi {
         Ok(response.data.data)
     }
 
+    /// Stream all merchant accounts for a company.
+    ///
+    /// Fetches the pages of [`list_merchants`](Self::list_merchants) as the
+    /// stream is consumed, starting at `page`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::PaginatedRequest;
+    /// use adyen_management::ManagementApi;
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let merchants = management.list_merchants_stream("company_123", PaginatedRequest::new(100));
+    /// futures::pin_mut!(merchants);
+    /// while let Some(merchant) = merchants.try_next().await? {
+    ///     println!("{}: {}", merchant.id, merchant.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_merchants_stream(
+        &self,
+        company_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<MerchantAccount>> {
+        let url = format!(
+            "{}/v3/companies/{}/merchants",
+            self.client.config().environment().management_api_url(),
+            company_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    /// Get one page of merchant accounts for a company.
+    ///
+    /// Unlike [`list_merchants`](Self::list_merchants), the returned page
+    /// keeps the totals and `_links` to the other pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_merchants_page(
+        &self,
+        company_id: &str,
+        page: PaginatedRequest,
+    ) -> Result<Page<MerchantAccount>> {
+        let url = format!(
+            "{}/v3/companies/{}/merchants",
+            self.client.config().environment().management_api_url(),
+            company_id
+        );
+        let response = self.client.get(&page.apply(&url)).await?;
+        Ok(response.data)
+    }
+
     /// Create a new merchant account.
     ///
     /// Creates a new merchant account under the specified company.
@@ -112,7 +182,7 @@ impl ManagementApi {
     ///     .merchant_account("ExampleMerchant")
     ///     .business_details(business_details)
     ///     .primary_contact(contact)
-    ///     .build()?;
+    ///     .build();
     ///
     /// let merchant = management.create_merchant(&request).await?;
     /// # Ok(())
@@ -167,6 +237,110 @@ impl ManagementApi {
         Ok(response.data.data)
     }
 
+    /// Stream all stores for a merchant.
+    ///
+    /// Fetches the pages of [`list_stores`](Self::list_stores) as the stream
+    /// is consumed, starting at `page`.
+    pub fn list_stores_stream(
+        &self,
+        merchant_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<Store>> {
+        let url = format!(
+            "{}/v3/merchants/{}/stores",
+            self.client.config().environment().management_api_url(),
+            merchant_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    /// Get one page of stores for a merchant.
+    ///
+    /// Unlike [`list_stores`](Self::list_stores), the returned page keeps the
+    /// totals and `_links` to the other pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_stores_page(
+        &self,
+        merchant_id: &str,
+        page: PaginatedRequest,
+    ) -> Result<Page<Store>> {
+        let url = format!(
+            "{}/v3/merchants/{}/stores",
+            self.client.config().environment().management_api_url(),
+            merchant_id
+        );
+        let response = self.client.get(&page.apply(&url)).await?;
+        Ok(response.data)
+    }
+
+    /// Search the stores of the company.
+    ///
+    /// Returns one page of the stores matching `query`, which can filter by
+    /// merchant account, store reference and status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::PaginatedRequest;
+    /// use adyen_management::ManagementApi;
+    /// use adyen_management::types::StoreListQuery;
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let query = StoreListQuery::new().merchant_id("merchant_123");
+    /// let mut page = Some(PaginatedRequest::new(50));
+    /// while let Some(request) = page {
+    ///     let stores = management.search_stores(&query, request).await?;
+    ///     for store in &stores.data {
+    ///         println!("{}", store.id);
+    ///     }
+    ///     page = stores.next_page(&request);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_stores(
+        &self,
+        query: &StoreListQuery,
+        page: PaginatedRequest,
+    ) -> Result<Page<Store>> {
+        let url = self.stores_search_url(query);
+        let response = self.client.get(&page.apply(&url)).await?;
+        Ok(response.data)
+    }
+
+    /// Stream the stores of the company matching `query`.
+    ///
+    /// Fetches the pages of [`search_stores`](Self::search_stores) as the
+    /// stream is consumed, starting at `page`.
+    pub fn search_stores_stream(
+        &self,
+        query: &StoreListQuery,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<Store>> {
+        let url = self.stores_search_url(query);
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    fn stores_search_url(&self, query: &StoreListQuery) -> String {
+        let url = format!(
+            "{}/v3/stores",
+            self.client.config().environment().management_api_url()
+        );
+        let query = query.to_query_string();
+        if query.is_empty() {
+            url
+        } else {
+            format!("{url}?{query}")
+        }
+    }
+
     /// Create a new store.
     ///
     /// Creates a new store under the specified merchant account.
@@ -195,7 +369,7 @@ impl ManagementApi {
     ///     .description("Main Store")
     ///     .address(address)
     ///     .phone_number("+1234567890")
-    ///     .build()?;
+    ///     .build();
     ///
     /// let store = management.create_store("merchant_123", &request).await?;
     /// # Ok(())
@@ -303,15 +477,34 @@ impl ManagementApi {
 
     /// Request a new payment method for a merchant.
     ///
-    /// Requests a new payment method to be enabled for the merchant.
+    /// Requests a new payment method to be enabled for the merchant. Use the
+    /// type-specific constructors of [`PaymentMethodSetupRequest`] to send
+    /// the settings the payment method requires.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use adyen_management::types::{PayPalInfo, PaymentMethodSetupRequest};
+    /// use adyen_management::ManagementApi;
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request =
+    ///     PaymentMethodSetupRequest::paypal(PayPalInfo::new("ABCDEFGHIJKL", "paypal@example.com"))
+    ///         .currencies(["EUR"]);
+    /// let payment_method = management
+    ///     .request_payment_method("merchant_123", &request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn request_payment_method(
         &self,
         merchant_id: &str,
-        request: &UpdatePaymentMethodRequest,
+        request: &PaymentMethodSetupRequest,
     ) -> Result<PaymentMethod> {
         let url = format!(
             "{}/v3/merchants/{}/paymentMethodSettings",
@@ -322,6 +515,53 @@ impl ManagementApi {
         Ok(response.data)
     }
 
+    /// Get the Apple Pay domains of a payment method.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_apple_pay_domains(
+        &self,
+        merchant_id: &str,
+        payment_method_id: &str,
+    ) -> Result<ApplePayInfo> {
+        let url = format!(
+            "{}/v3/merchants/{}/paymentMethodSettings/{}/getApplePayDomains",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            payment_method_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Add Apple Pay domains to a payment method.
+    ///
+    /// Host the Apple Pay domain association file from your Customer Area at
+    /// `/.well-known/apple-developer-merchantid-domain-association` on each
+    /// domain first; Adyen verifies it when the domains are added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn add_apple_pay_domains(
+        &self,
+        merchant_id: &str,
+        payment_method_id: &str,
+        domains: &ApplePayInfo,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/v3/merchants/{}/paymentMethodSettings/{}/addApplePayDomains",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            payment_method_id
+        );
+        // Adyen answers with an empty body once the domains are added.
+        let request = Request::new(Method::Post, url).with_body(serde_json::to_value(domains)?);
+        self.client.execute_raw(request).await?;
+        Ok(())
+    }
+
     // Webhook Management
 
     /// List all webhooks for a merchant.
@@ -341,6 +581,23 @@ impl ManagementApi {
         Ok(response.data.data)
     }
 
+    /// Stream all webhooks for a merchant.
+    ///
+    /// Fetches the pages of [`list_webhooks`](Self::list_webhooks) as the
+    /// stream is consumed, starting at `page`.
+    pub fn list_webhooks_stream(
+        &self,
+        merchant_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<Webhook>> {
+        let url = format!(
+            "{}/v3/merchants/{}/webhooks",
+            self.client.config().environment().management_api_url(),
+            merchant_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
     /// Create a new webhook.
     ///
     /// Creates a new webhook configuration for the specified merchant.
@@ -441,91 +698,681 @@ impl ManagementApi {
         Ok(())
     }
 
-    // Terminal Management
+    /// Generate a new HMAC key for a webhook.
+    ///
+    /// Replaces any existing HMAC key; update your notification handler with
+    /// the new key before relying on signature validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn generate_hmac_key(
+        &self,
+        merchant_id: &str,
+        webhook_id: &str,
+    ) -> Result<GenerateHmacKeyResponse> {
+        let url = format!(
+            "{}/v3/merchants/{}/webhooks/{}/generateHmac",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            webhook_id
+        );
+        let response = self.client.post(&url, &serde_json::json!({})).await?;
+        Ok(response.data)
+    }
 
-    /// List terminal models available to a merchant.
+    /// Send test notifications to a webhook.
     ///
-    /// Returns a list of terminal models that the merchant can order.
+    /// Sends a test notification for each of the given event types and
+    /// returns how the webhook endpoint responded.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn list_terminal_models(&self, merchant_id: &str) -> Result<Vec<TerminalModel>> {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{ManagementApi, CreateWebhookRequest};
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = CreateWebhookRequest {
+    ///     url: "https://example.com/webhook".into(),
+    ///     description: None,
+    ///     active: true,
+    ///     communication_format: "json".into(),
+    ///     filter_merchant_accounts: None,
+    ///     additional_settings: None,
+    /// };
+    ///
+    /// let webhook = management.create_webhook("merchant_123", &request).await?;
+    /// let hmac = management.generate_hmac_key("merchant_123", &webhook.id).await?;
+    /// println!("Store this HMAC key: {}", hmac.hmac_key.expose_secret());
+    ///
+    /// let results = management
+    ///     .test_webhook("merchant_123", &webhook.id, ["AUTHORISATION"])
+    ///     .await?;
+    ///
+    /// assert!(results.iter().all(|result| result.is_success()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_webhook<I, T>(
+        &self,
+        merchant_id: &str,
+        webhook_id: &str,
+        types: I,
+    ) -> Result<Vec<TestWebhookResult>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Box<str>>,
+    {
         let url = format!(
-            "{}/v3/merchants/{}/terminalModels",
+            "{}/v3/merchants/{}/webhooks/{}/test",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            webhook_id
+        );
+        let request = TestWebhookRequest {
+            types: types.into_iter().map(Into::into).collect(),
+        };
+        let response: adyen_core::ApiResponse<TestWebhookResponse> =
+            self.client.post(&url, &request).await?;
+        Ok(response.data.data)
+    }
+
+    // API Credential Management
+
+    /// List all API credentials for a merchant.
+    ///
+    /// Returns the API credentials of the specified merchant account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_api_credentials(&self, merchant_id: &str) -> Result<Vec<ApiCredential>> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials",
             self.client.config().environment().management_api_url(),
             merchant_id
         );
-        let response: adyen_core::ApiResponse<ListTerminalModelsResponse> =
+        let response: adyen_core::ApiResponse<ListApiCredentialsResponse> =
             self.client.get(&url).await?;
         Ok(response.data.data)
     }
 
-    /// Get terminal settings for a merchant.
+    /// Stream all API credentials for a merchant.
     ///
-    /// Returns the terminal settings configuration for the specified merchant.
+    /// Fetches the pages of [`list_api_credentials`](Self::list_api_credentials)
+    /// as the stream is consumed, starting at `page`.
+    pub fn list_api_credentials_stream(
+        &self,
+        merchant_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<ApiCredential>> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials",
+            self.client.config().environment().management_api_url(),
+            merchant_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    /// Create a new API credential.
+    ///
+    /// Creates an API credential for the specified merchant. The API key and
+    /// password are only returned in this response.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn get_terminal_settings(&self, merchant_id: &str) -> Result<TerminalSettings> {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{ManagementApi, CreateApiCredentialRequest};
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = CreateApiCredentialRequest {
+    ///     roles: Some(vec!["Checkout webservice role".into()]),
+    ///     allowed_origins: Some(vec!["https://www.example.com".into()]),
+    ///     description: Some("Web shop".into()),
+    /// };
+    ///
+    /// let created = management.create_api_credential("merchant_123", &request).await?;
+    /// println!(
+    ///     "API key for {}: {}",
+    ///     created.credential.username,
+    ///     created.api_key.expose_secret()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_api_credential(
+        &self,
+        merchant_id: &str,
+        request: &CreateApiCredentialRequest,
+    ) -> Result<CreateApiCredentialResponse> {
         let url = format!(
-            "{}/v3/merchants/{}/terminalSettings",
+            "{}/v3/merchants/{}/apiCredentials",
             self.client.config().environment().management_api_url(),
             merchant_id
         );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Get an API credential.
+    ///
+    /// Returns the API credential for the specified credential ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_api_credential(
+        &self,
+        merchant_id: &str,
+        credential_id: &str,
+    ) -> Result<ApiCredential> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials/{}",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            credential_id
+        );
         let response = self.client.get(&url).await?;
         Ok(response.data)
     }
 
-    /// Update terminal settings for a merchant.
+    /// Update an API credential.
     ///
-    /// Updates the terminal settings configuration for the specified merchant.
+    /// Updates the roles, allowed origins, description or active state of the
+    /// specified API credential.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn update_terminal_settings(
+    pub async fn update_api_credential(
         &self,
         merchant_id: &str,
-        request: &TerminalSettings,
-    ) -> Result<TerminalSettings> {
+        credential_id: &str,
+        request: &UpdateApiCredentialRequest,
+    ) -> Result<ApiCredential> {
         let url = format!(
-            "{}/v3/merchants/{}/terminalSettings",
+            "{}/v3/merchants/{}/apiCredentials/{}",
             self.client.config().environment().management_api_url(),
-            merchant_id
+            merchant_id,
+            credential_id
         );
         let response = self.client.patch(&url, request).await?;
         Ok(response.data)
     }
 
-    /// List terminals for a store.
+    /// Generate a new client key.
     ///
-    /// Returns a list of terminals assigned to the specified store.
+    /// Rotates the client key of the specified API credential. The previous
+    /// client key stops working immediately.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn list_store_terminals(
+    pub async fn generate_client_key(
         &self,
         merchant_id: &str,
-        store_id: &str,
-    ) -> Result<Vec<Terminal>> {
+        credential_id: &str,
+    ) -> Result<GenerateClientKeyResponse> {
         let url = format!(
-            "{}/v3/merchants/{}/stores/{}/terminals",
+            "{}/v3/merchants/{}/apiCredentials/{}/generateClientKey",
             self.client.config().environment().management_api_url(),
             merchant_id,
-            store_id
+            credential_id
         );
-        let response: adyen_core::ApiResponse<ListTerminalsResponse> =
-            self.client.get(&url).await?;
-        Ok(response.data.data)
+        let response = self.client.post(&url, &serde_json::json!({})).await?;
+        Ok(response.data)
     }
-}
 
-// Response wrapper types for list endpoints
-#[derive(Debug, Clone, serde::Deserialize)]
-struct ListMerchantsResponse {
+    /// List allowed origins for an API credential.
+    ///
+    /// Returns the origins allowed to make client-side requests with the
+    /// credential's client key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_allowed_origins(
+        &self,
+        merchant_id: &str,
+        credential_id: &str,
+    ) -> Result<Vec<AllowedOrigin>> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials/{}/allowedOrigins",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            credential_id
+        );
+        let response: adyen_core::ApiResponse<ListAllowedOriginsResponse> =
+            self.client.get(&url).await?;
+        Ok(response.data.data)
+    }
+
+    /// Add an allowed origin to an API credential.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn create_allowed_origin(
+        &self,
+        merchant_id: &str,
+        credential_id: &str,
+        origin: &AllowedOrigin,
+    ) -> Result<AllowedOrigin> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials/{}/allowedOrigins",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            credential_id
+        );
+        let response = self.client.post(&url, origin).await?;
+        Ok(response.data)
+    }
+
+    /// Get an allowed origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_allowed_origin(
+        &self,
+        merchant_id: &str,
+        credential_id: &str,
+        origin_id: &str,
+    ) -> Result<AllowedOrigin> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials/{}/allowedOrigins/{}",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            credential_id,
+            origin_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Delete an allowed origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn delete_allowed_origin(
+        &self,
+        merchant_id: &str,
+        credential_id: &str,
+        origin_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/v3/merchants/{}/apiCredentials/{}/allowedOrigins/{}",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            credential_id,
+            origin_id
+        );
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+
+    // Terminal Management
+
+    /// List terminal models available to a merchant.
+    ///
+    /// Returns a list of terminal models that the merchant can order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_terminal_models(&self, merchant_id: &str) -> Result<Vec<TerminalModel>> {
+        let url = format!(
+            "{}/v3/merchants/{}/terminalModels",
+            self.client.config().environment().management_api_url(),
+            merchant_id
+        );
+        let response: adyen_core::ApiResponse<ListTerminalModelsResponse> =
+            self.client.get(&url).await?;
+        Ok(response.data.data)
+    }
+
+    /// Get terminal settings for a merchant.
+    ///
+    /// Returns the terminal settings configuration for the specified merchant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_terminal_settings(&self, merchant_id: &str) -> Result<TerminalSettings> {
+        self.get_terminal_settings_at(&TerminalSettingsLevel::merchant(merchant_id))
+            .await
+    }
+
+    /// Update terminal settings for a merchant.
+    ///
+    /// Updates the terminal settings configuration for the specified merchant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn update_terminal_settings(
+        &self,
+        merchant_id: &str,
+        request: &TerminalSettings,
+    ) -> Result<TerminalSettings> {
+        self.update_terminal_settings_at(&TerminalSettingsLevel::merchant(merchant_id), request)
+            .await
+    }
+
+    /// Get terminal settings at a level of the account hierarchy.
+    ///
+    /// Returns the settings that apply at the given company, merchant, store
+    /// or terminal level, including those inherited from the levels above.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{ManagementApi, TerminalSettingsLevel};
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let store = TerminalSettingsLevel::store("merchant_123", "store_456");
+    /// let settings = management.get_terminal_settings_at(&store).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_terminal_settings_at(
+        &self,
+        level: &TerminalSettingsLevel,
+    ) -> Result<TerminalSettings> {
+        let url = format!(
+            "{}/v3/{}/terminalSettings",
+            self.client.config().environment().management_api_url(),
+            level.path()
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Update terminal settings at a level of the account hierarchy.
+    ///
+    /// Settings updated at a level override those inherited from the levels
+    /// above, for all terminals below it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn update_terminal_settings_at(
+        &self,
+        level: &TerminalSettingsLevel,
+        request: &TerminalSettings,
+    ) -> Result<TerminalSettings> {
+        let url = format!(
+            "{}/v3/{}/terminalSettings",
+            self.client.config().environment().management_api_url(),
+            level.path()
+        );
+        let response = self.client.patch(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Get the terminal logo at a level of the account hierarchy.
+    ///
+    /// The terminal `model` (e.g. `V400m`) is required at company, merchant
+    /// and store level, and ignored at terminal level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_terminal_logo(
+        &self,
+        level: &TerminalSettingsLevel,
+        model: Option<&str>,
+    ) -> Result<TerminalLogo> {
+        let url = self.terminal_logo_url(level, model);
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Update the terminal logo at a level of the account hierarchy.
+    ///
+    /// The terminal `model` (e.g. `V400m`) is required at company, merchant
+    /// and store level, and ignored at terminal level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{ManagementApi, TerminalLogo, TerminalSettingsLevel};
+    ///
+    /// # async fn example(management: ManagementApi, png: Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let logo = TerminalLogo::from_bytes(&png);
+    /// management
+    ///     .update_terminal_logo(&TerminalSettingsLevel::merchant("merchant_123"), Some("V400m"), &logo)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_terminal_logo(
+        &self,
+        level: &TerminalSettingsLevel,
+        model: Option<&str>,
+        logo: &TerminalLogo,
+    ) -> Result<TerminalLogo> {
+        let url = self.terminal_logo_url(level, model);
+        let response = self.client.patch(&url, logo).await?;
+        Ok(response.data)
+    }
+
+    fn terminal_logo_url(&self, level: &TerminalSettingsLevel, model: Option<&str>) -> String {
+        let mut url = format!(
+            "{}/v3/{}/terminalLogos",
+            self.client.config().environment().management_api_url(),
+            level.path()
+        );
+        // Terminal-level logos apply to the terminal's own model.
+        let model = model.filter(|_| !matches!(level, TerminalSettingsLevel::Terminal(_)));
+        if let Some(model) = model {
+            url.push_str("?model=");
+            url.push_str(&urlencoding::encode(model));
+        }
+        url
+    }
+
+    /// List terminals for a store.
+    ///
+    /// Returns a list of terminals assigned to the specified store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_store_terminals(
+        &self,
+        merchant_id: &str,
+        store_id: &str,
+    ) -> Result<Vec<Terminal>> {
+        let url = format!(
+            "{}/v3/merchants/{}/stores/{}/terminals",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            store_id
+        );
+        let response: adyen_core::ApiResponse<ListTerminalsResponse> =
+            self.client.get(&url).await?;
+        Ok(response.data.data)
+    }
+
+    /// Stream all terminals for a store.
+    ///
+    /// Fetches the pages of [`list_store_terminals`](Self::list_store_terminals)
+    /// as the stream is consumed, starting at `page`.
+    pub fn list_store_terminals_stream(
+        &self,
+        merchant_id: &str,
+        store_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<Terminal>> {
+        let url = format!(
+            "{}/v3/merchants/{}/stores/{}/terminals",
+            self.client.config().environment().management_api_url(),
+            merchant_id,
+            store_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    /// Reassign a terminal.
+    ///
+    /// Moves the terminal to another company or merchant account inventory,
+    /// or to a store. The terminal picks up the new assignment at its next
+    /// maintenance call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{ManagementApi, TerminalReassignmentRequest};
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = TerminalReassignmentRequest::to_store("store_456");
+    /// management.reassign_terminal("V400m-123456789", &request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reassign_terminal(
+        &self,
+        terminal_id: &str,
+        request: &TerminalReassignmentRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/v3/terminals/{}/reassign",
+            self.client.config().environment().management_api_url(),
+            terminal_id
+        );
+        // Adyen answers a successful reassignment with an empty body.
+        let request = Request::new(Method::Post, url).with_body(serde_json::to_value(request)?);
+        self.client.execute_raw(request).await?;
+        Ok(())
+    }
+
+    /// Schedule an action on payment terminals.
+    ///
+    /// Returns the scheduled actions, and the terminals the action could not
+    /// be scheduled on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_management::{
+    ///     ManagementApi, ScheduleTerminalActionsRequest, TerminalActionDetails,
+    /// };
+    ///
+    /// # async fn example(management: ManagementApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = ScheduleTerminalActionsRequest::new(
+    ///     TerminalActionDetails::ReleaseUpdate {
+    ///         update_at_first_maintenance_call: Some(true),
+    ///     },
+    ///     ["V400m-123456789"],
+    /// )
+    /// .scheduled_at("2026-10-20T02:00:00");
+    ///
+    /// let response = management.schedule_terminal_actions(&request).await?;
+    /// for (terminal_id, errors) in &response.terminals_with_errors {
+    ///     println!("Could not schedule on {terminal_id}: {errors:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn schedule_terminal_actions(
+        &self,
+        request: &ScheduleTerminalActionsRequest,
+    ) -> Result<ScheduleTerminalActionsResponse> {
+        let url = format!(
+            "{}/v3/terminals/scheduleActions",
+            self.client.config().environment().management_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// List terminal actions for a company.
+    ///
+    /// Returns the actions scheduled on the company's terminals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_terminal_actions(&self, company_id: &str) -> Result<Vec<TerminalAction>> {
+        let url = format!(
+            "{}/v3/companies/{}/terminalActions",
+            self.client.config().environment().management_api_url(),
+            company_id
+        );
+        let response: adyen_core::ApiResponse<ListTerminalActionsResponse> =
+            self.client.get(&url).await?;
+        Ok(response.data.data)
+    }
+
+    /// Stream all terminal actions for a company.
+    ///
+    /// Fetches the pages of [`list_terminal_actions`](Self::list_terminal_actions)
+    /// as the stream is consumed, starting at `page`.
+    pub fn list_terminal_actions_stream(
+        &self,
+        company_id: &str,
+        page: PaginatedRequest,
+    ) -> impl Stream<Item = Result<TerminalAction>> {
+        let url = format!(
+            "{}/v3/companies/{}/terminalActions",
+            self.client.config().environment().management_api_url(),
+            company_id
+        );
+        PageIterator::new(self.client.clone(), url, page).into_stream()
+    }
+
+    /// Get a terminal action.
+    ///
+    /// Returns the status and result of the specified action.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_terminal_action(
+        &self,
+        company_id: &str,
+        action_id: &str,
+    ) -> Result<TerminalAction> {
+        let url = format!(
+            "{}/v3/companies/{}/terminalActions/{}",
+            self.client.config().environment().management_api_url(),
+            company_id,
+            action_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+}
+
+// Response wrapper types for list endpoints
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ListMerchantsResponse {
     data: Vec<MerchantAccount>,
 }
 
@@ -539,6 +1386,21 @@ struct ListWebhooksResponse {
     data: Vec<Webhook>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+struct TestWebhookResponse {
+    data: Vec<TestWebhookResult>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ListApiCredentialsResponse {
+    data: Vec<ApiCredential>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ListAllowedOriginsResponse {
+    data: Vec<AllowedOrigin>,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 struct ListTerminalModelsResponse {
     data: Vec<TerminalModel>,
@@ -549,6 +1411,81 @@ struct ListTerminalsResponse {
     data: Vec<Terminal>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ListTerminalActionsResponse {
+    data: Vec<TerminalAction>,
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl ManagementApi {
+        fn get_company_blocking => get_company(company_id: &str) -> Result<Company>;
+        fn list_merchants_blocking => list_merchants(company_id: &str) -> Result<Vec<MerchantAccount>>;
+        fn list_merchants_page_blocking => list_merchants_page(company_id: &str, page: PaginatedRequest) -> Result<Page<MerchantAccount>>;
+        fn create_merchant_blocking => create_merchant(request: &CreateMerchantRequest) -> Result<MerchantAccount>;
+        fn get_merchant_blocking => get_merchant(merchant_id: &str) -> Result<MerchantAccount>;
+        fn list_stores_blocking => list_stores(merchant_id: &str) -> Result<Vec<Store>>;
+        fn list_stores_page_blocking => list_stores_page(merchant_id: &str, page: PaginatedRequest) -> Result<Page<Store>>;
+        fn search_stores_blocking => search_stores(query: &StoreListQuery, page: PaginatedRequest) -> Result<Page<Store>>;
+        fn create_store_blocking => create_store(merchant_id: &str, request: &CreateStoreRequest) -> Result<Store>;
+        fn get_store_blocking => get_store(merchant_id: &str, store_id: &str) -> Result<Store>;
+        fn update_store_blocking => update_store(merchant_id: &str, store_id: &str, request: &CreateStoreRequest) -> Result<Store>;
+        fn get_payment_method_settings_blocking => get_payment_method_settings(merchant_id: &str) -> Result<PaymentMethodSettings>;
+        fn update_payment_method_blocking => update_payment_method(merchant_id: &str, payment_method_id: &str, request: &UpdatePaymentMethodRequest) -> Result<PaymentMethod>;
+        fn request_payment_method_blocking => request_payment_method(merchant_id: &str, request: &PaymentMethodSetupRequest) -> Result<PaymentMethod>;
+        fn get_apple_pay_domains_blocking => get_apple_pay_domains(merchant_id: &str, payment_method_id: &str) -> Result<ApplePayInfo>;
+        fn add_apple_pay_domains_blocking => add_apple_pay_domains(merchant_id: &str, payment_method_id: &str, domains: &ApplePayInfo) -> Result<()>;
+        fn list_webhooks_blocking => list_webhooks(merchant_id: &str) -> Result<Vec<Webhook>>;
+        fn create_webhook_blocking => create_webhook(merchant_id: &str, request: &CreateWebhookRequest) -> Result<Webhook>;
+        fn get_webhook_blocking => get_webhook(merchant_id: &str, webhook_id: &str) -> Result<Webhook>;
+        fn update_webhook_blocking => update_webhook(merchant_id: &str, webhook_id: &str, request: &UpdateWebhookRequest) -> Result<Webhook>;
+        fn delete_webhook_blocking => delete_webhook(merchant_id: &str, webhook_id: &str) -> Result<()>;
+        fn generate_hmac_key_blocking => generate_hmac_key(merchant_id: &str, webhook_id: &str) -> Result<GenerateHmacKeyResponse>;
+        fn list_api_credentials_blocking => list_api_credentials(merchant_id: &str) -> Result<Vec<ApiCredential>>;
+        fn create_api_credential_blocking => create_api_credential(merchant_id: &str, request: &CreateApiCredentialRequest) -> Result<CreateApiCredentialResponse>;
+        fn get_api_credential_blocking => get_api_credential(merchant_id: &str, credential_id: &str) -> Result<ApiCredential>;
+        fn update_api_credential_blocking => update_api_credential(merchant_id: &str, credential_id: &str, request: &UpdateApiCredentialRequest) -> Result<ApiCredential>;
+        fn generate_client_key_blocking => generate_client_key(merchant_id: &str, credential_id: &str) -> Result<GenerateClientKeyResponse>;
+        fn list_allowed_origins_blocking => list_allowed_origins(merchant_id: &str, credential_id: &str) -> Result<Vec<AllowedOrigin>>;
+        fn create_allowed_origin_blocking => create_allowed_origin(merchant_id: &str, credential_id: &str, origin: &AllowedOrigin) -> Result<AllowedOrigin>;
+        fn get_allowed_origin_blocking => get_allowed_origin(merchant_id: &str, credential_id: &str, origin_id: &str) -> Result<AllowedOrigin>;
+        fn delete_allowed_origin_blocking => delete_allowed_origin(merchant_id: &str, credential_id: &str, origin_id: &str) -> Result<()>;
+        fn list_terminal_models_blocking => list_terminal_models(merchant_id: &str) -> Result<Vec<TerminalModel>>;
+        fn get_terminal_settings_blocking => get_terminal_settings(merchant_id: &str) -> Result<TerminalSettings>;
+        fn update_terminal_settings_blocking => update_terminal_settings(merchant_id: &str, request: &TerminalSettings) -> Result<TerminalSettings>;
+        fn get_terminal_settings_at_blocking => get_terminal_settings_at(level: &TerminalSettingsLevel) -> Result<TerminalSettings>;
+        fn update_terminal_settings_at_blocking => update_terminal_settings_at(level: &TerminalSettingsLevel, request: &TerminalSettings) -> Result<TerminalSettings>;
+        fn get_terminal_logo_blocking => get_terminal_logo(level: &TerminalSettingsLevel, model: Option<&str>) -> Result<TerminalLogo>;
+        fn update_terminal_logo_blocking => update_terminal_logo(level: &TerminalSettingsLevel, model: Option<&str>, logo: &TerminalLogo) -> Result<TerminalLogo>;
+        fn list_store_terminals_blocking => list_store_terminals(merchant_id: &str, store_id: &str) -> Result<Vec<Terminal>>;
+        fn reassign_terminal_blocking => reassign_terminal(terminal_id: &str, request: &TerminalReassignmentRequest) -> Result<()>;
+        fn schedule_terminal_actions_blocking => schedule_terminal_actions(request: &ScheduleTerminalActionsRequest) -> Result<ScheduleTerminalActionsResponse>;
+        fn list_terminal_actions_blocking => list_terminal_actions(company_id: &str) -> Result<Vec<TerminalAction>>;
+        fn get_terminal_action_blocking => get_terminal_action(company_id: &str, action_id: &str) -> Result<TerminalAction>;
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl ManagementApi {
+    /// Blocking variant of [`test_webhook`](Self::test_webhook).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an async runtime.
+    pub fn test_webhook_blocking<I, T>(
+        &self,
+        merchant_id: &str,
+        webhook_id: &str,
+        types: I,
+    ) -> Result<Vec<TestWebhookResult>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Box<str>>,
+    {
+        adyen_core::blocking::block_on(self.test_webhook(merchant_id, webhook_id, types))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -10,10 +10,14 @@
 //! - **Company Management**: Manage your Adyen company account and settings
 //! - **Merchant Accounts**: Create and manage merchant accounts
 //! - **Store Management**: Add and configure stores under merchant accounts
-//! - **Payment Methods**: Configure payment methods for stores and accounts
-//! - **Terminal Management**: Manage payment terminals and their assignments
-//! - **Webhook Configuration**: Set up and manage webhook endpoints
+//! - **Payment Methods**: Configure payment methods with typed per-method settings, including Apple Pay domains
+//! - **Terminal Management**: Manage payment terminals, reassign them, and schedule remote actions
+//! - **Terminal Settings**: Configure terminal settings and logos at company, merchant, store, or terminal level
+//! - **Webhook Configuration**: Set up webhook endpoints, generate HMAC keys, and send test notifications
+//! - **API Credentials**: Manage API credentials, allowed origins, and client keys
 //! - **User Management**: Manage users and their permissions
+//! - **Pagination**: Stream all pages of list endpoints, or fetch single pages with typed `_links`
+//! - **Store Search**: Filter stores by merchant account and reference without assembling URLs
 //! - **Type Safety**: Full Rust type safety with builder patterns
 //! - **Modern Patterns**: Async/await support with comprehensive error handling
 //!
@@ -42,24 +46,47 @@ pub use api::ManagementApi;
 pub use types::{
     // Common types
     Address,
+    // API credentials
+    AllowedOrigin,
+    ApiCredential,
     // Company and Merchant types
     Company,
     Contact,
+    CreateApiCredentialRequest,
+    CreateApiCredentialResponse,
     CreateMerchantRequest,
     CreateStoreRequest,
     CreateWebhookRequest,
+    GenerateClientKeyResponse,
+    GenerateHmacKeyResponse,
     Links,
     MerchantAccount,
     // Payment methods
     PaymentMethod,
     PaymentMethodSettings,
+    PaymentMethodSetupRequest,
+    PaymentMethodTypeSettings,
+    ScheduleTerminalActionsRequest,
+    ScheduleTerminalActionsResponse,
+    ScheduledTerminalAction,
     // Store management
     Store,
     StoreCreationWithMerchantCodeRequest,
+    StoreListQuery,
     Terminal,
+    TerminalAction,
+    TerminalActionDetails,
+    TerminalActionStatus,
+    TerminalLogo,
     // Terminal management
     TerminalModel,
+    TerminalReassignmentRequest,
     TerminalSettings,
+    TerminalSettingsLevel,
+    TestWebhookRequest,
+    TestWebhookResult,
+    TestWebhookStatus,
+    UpdateApiCredentialRequest,
     UpdatePaymentMethodRequest,
     UpdateWebhookRequest,
     // Webhooks
@@ -3,12 +3,19 @@
 //! This module contains all request and response types for managing company and merchant
 //! accounts, stores, payment methods, terminals, and webhooks.
 
-use adyen_core::{AdyenError, Result};
+use adyen_core::builder::Missing;
+use adyen_core::{AdyenError, Result, SecretString};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Company account information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Company {
     /// The unique identifier of the company account.
@@ -26,11 +33,17 @@ pub struct Company {
     pub data_processing: Option<DataProcessing>,
     /// Additional properties.
     #[serde(flatten)]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub additional_data: HashMap<String, serde_json::Value>,
 }
 
 /// Company registration details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CompanyRegistration {
     /// The company's registration number.
@@ -44,6 +57,11 @@ pub struct CompanyRegistration {
 
 /// Data processing specifications for compliance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct DataProcessing {
     /// The data processing region.
@@ -55,6 +73,11 @@ pub struct DataProcessing {
 
 /// Merchant account information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct MerchantAccount {
     /// The unique identifier of the merchant account.
@@ -84,6 +107,11 @@ pub struct MerchantAccount {
 
 /// Request to create a new merchant account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateMerchantRequest {
     /// The company ID to create the merchant under.
@@ -101,6 +129,11 @@ pub struct CreateMerchantRequest {
 
 /// Business details for a merchant.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BusinessDetails {
     /// The legal name of the business.
@@ -121,16 +154,29 @@ pub struct BusinessDetails {
 
 /// Merchant account status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum MerchantStatus {
     Active,
     Inactive,
     Suspended,
     Closed,
+    /// A merchant status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Store information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Store {
     /// The unique identifier of the store.
@@ -160,6 +206,11 @@ pub struct Store {
 
 /// Request to create a new store.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateStoreRequest {
     /// The store reference code.
@@ -178,6 +229,11 @@ pub struct CreateStoreRequest {
 
 /// Request to create a store with merchant code.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct StoreCreationWithMerchantCodeRequest {
     /// The merchant account code.
@@ -188,18 +244,34 @@ pub struct StoreCreationWithMerchantCodeRequest {
 }
 
 /// Store status.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum StoreStatus {
     Active,
     Inactive,
     Closed,
+    /// A store status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Payment method configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethod {
+    /// The ID of the payment method, used to update it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<str>>,
     /// The payment method type (e.g., "scheme", "klarna").
     pub r#type: Box<str>,
     /// Whether this payment method is enabled.
@@ -213,10 +285,18 @@ pub struct PaymentMethod {
     /// Supported currencies for this payment method.
     #[serde(default)]
     pub currencies: Vec<Box<str>>,
+    /// Settings specific to the payment method type.
+    #[serde(flatten)]
+    pub settings: PaymentMethodTypeSettings,
 }
 
 /// Payment method configuration details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodConfiguration {
     /// Merchant identifier for this payment method.
@@ -227,11 +307,17 @@ pub struct PaymentMethodConfiguration {
     pub api_credentials: Option<HashMap<String, Box<str>>>,
     /// Payment method-specific properties.
     #[serde(flatten)]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub properties: HashMap<String, serde_json::Value>,
 }
 
 /// Payment method settings for a store or merchant.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentMethodSettings {
     /// The store or merchant ID these settings apply to.
@@ -242,7 +328,12 @@ pub struct PaymentMethodSettings {
 }
 
 /// Request to update payment method settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePaymentMethodRequest {
     /// The payment method type to update.
@@ -258,10 +349,518 @@ pub struct UpdatePaymentMethodRequest {
     /// Currencies this payment method should support.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currencies: Option<Vec<Box<str>>>,
+    /// Updated settings specific to the payment method type.
+    #[serde(flatten)]
+    pub settings: PaymentMethodTypeSettings,
+}
+
+impl UpdatePaymentMethodRequest {
+    /// Create a request to enable or disable a payment method.
+    pub fn new(r#type: impl Into<Box<str>>, enabled: bool) -> Self {
+        Self {
+            r#type: r#type.into(),
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    /// Set the settings specific to the payment method type.
+    #[must_use]
+    pub fn settings(mut self, settings: impl Into<PaymentMethodTypeSettings>) -> Self {
+        self.settings = settings.into();
+        self
+    }
+}
+
+/// Request to add a payment method to a merchant account.
+///
+/// The type-specific constructors set the payment method type together with
+/// its required settings.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_management::types::{KlarnaInfo, KlarnaRegion, PaymentMethodSetupRequest};
+///
+/// let request = PaymentMethodSetupRequest::klarna(KlarnaInfo::new(
+///     KlarnaRegion::Europe,
+///     "support@example.com",
+///     "disputes@example.com",
+/// ))
+/// .countries(["NL", "DE"])
+/// .currencies(["EUR"]);
+/// assert_eq!(&*request.r#type, "klarna");
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodSetupRequest {
+    /// The payment method type to add.
+    pub r#type: Box<str>,
+    /// Countries where the payment method should be available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countries: Option<Vec<Box<str>>>,
+    /// Currencies the payment method should support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currencies: Option<Vec<Box<str>>>,
+    /// The stores the payment method applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_ids: Option<Vec<Box<str>>>,
+    /// The business line the payment method applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_line_id: Option<Box<str>>,
+    /// Your reference for the payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+    /// Settings specific to the payment method type.
+    #[serde(flatten)]
+    pub settings: PaymentMethodTypeSettings,
+}
+
+impl PaymentMethodSetupRequest {
+    /// Create a request to add a payment method of the given type.
+    pub fn new(r#type: impl Into<Box<str>>) -> Self {
+        Self {
+            r#type: r#type.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add Apple Pay.
+    pub fn apple_pay(info: ApplePayInfo) -> Self {
+        Self::new("applepay").settings(info)
+    }
+
+    /// Add Google Pay.
+    pub fn google_pay(info: GooglePayInfo) -> Self {
+        Self::new("googlepay").settings(info)
+    }
+
+    /// Add Klarna.
+    pub fn klarna(info: KlarnaInfo) -> Self {
+        Self::new("klarna").settings(info)
+    }
+
+    /// Add PayPal.
+    pub fn paypal(info: PayPalInfo) -> Self {
+        Self::new("paypal").settings(info)
+    }
+
+    /// Add giropay.
+    pub fn giropay(info: GiroPayInfo) -> Self {
+        Self::new("giropay").settings(info)
+    }
+
+    /// Add Sofort.
+    pub fn sofort(info: SofortInfo) -> Self {
+        Self::new("directEbanking").settings(info)
+    }
+
+    /// Add Swish.
+    pub fn swish(info: SwishInfo) -> Self {
+        Self::new("swish").settings(info)
+    }
+
+    /// Add Bancontact.
+    pub fn bcmc(info: BcmcInfo) -> Self {
+        Self::new("bcmc").settings(info)
+    }
+
+    /// Add Cartes Bancaires.
+    pub fn cartes_bancaires(info: CartesBancairesInfo) -> Self {
+        Self::new("cartebancaire").settings(info)
+    }
+
+    /// Set the countries where the payment method should be available.
+    #[must_use]
+    pub fn countries<I, S>(mut self, countries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Box<str>>,
+    {
+        self.countries = Some(countries.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the currencies the payment method should support.
+    #[must_use]
+    pub fn currencies<I, S>(mut self, currencies: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Box<str>>,
+    {
+        self.currencies = Some(currencies.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the stores the payment method applies to.
+    #[must_use]
+    pub fn store_ids<I, S>(mut self, store_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Box<str>>,
+    {
+        self.store_ids = Some(store_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the business line the payment method applies to.
+    #[must_use]
+    pub fn business_line_id(mut self, business_line_id: impl Into<Box<str>>) -> Self {
+        self.business_line_id = Some(business_line_id.into());
+        self
+    }
+
+    /// Set your reference for the payment method.
+    #[must_use]
+    pub fn reference(mut self, reference: impl Into<Box<str>>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the settings specific to the payment method type.
+    #[must_use]
+    pub fn settings(mut self, settings: impl Into<PaymentMethodTypeSettings>) -> Self {
+        self.settings = settings.into();
+        self
+    }
+}
+
+/// Settings specific to a payment method type.
+///
+/// Adyen sends these as a field named after the payment method, e.g.
+/// `applePay` or `klarna`; at most one is set for a payment method.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodTypeSettings {
+    /// Apple Pay settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apple_pay: Option<ApplePayInfo>,
+    /// Google Pay settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_pay: Option<GooglePayInfo>,
+    /// Klarna settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<KlarnaInfo>,
+    /// PayPal settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paypal: Option<PayPalInfo>,
+    /// giropay settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giro_pay: Option<GiroPayInfo>,
+    /// Sofort settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<SofortInfo>,
+    /// Swish settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swish: Option<SwishInfo>,
+    /// Bancontact settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bcmc: Option<BcmcInfo>,
+    /// Cartes Bancaires settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cartes_bancaires: Option<CartesBancairesInfo>,
+}
+
+macro_rules! impl_type_settings_from {
+    ($($info:ident => $field:ident),* $(,)?) => {$(
+        impl From<$info> for PaymentMethodTypeSettings {
+            fn from(info: $info) -> Self {
+                Self {
+                    $field: Some(info),
+                    ..Self::default()
+                }
+            }
+        }
+    )*};
+}
+
+impl_type_settings_from!(
+    ApplePayInfo => apple_pay,
+    GooglePayInfo => google_pay,
+    KlarnaInfo => klarna,
+    PayPalInfo => paypal,
+    GiroPayInfo => giro_pay,
+    SofortInfo => sofort,
+    SwishInfo => swish,
+    BcmcInfo => bcmc,
+    CartesBancairesInfo => cartes_bancaires,
+);
+
+/// Apple Pay settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayInfo {
+    /// The domains the Apple Pay button is shown on, e.g. `www.example.com`.
+    #[serde(default)]
+    pub domains: Vec<Box<str>>,
+}
+
+impl ApplePayInfo {
+    /// Create Apple Pay settings for the given domains.
+    pub fn new<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Box<str>>,
+    {
+        Self {
+            domains: domains.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Google Pay settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayInfo {
+    /// Your Google Pay merchant ID.
+    pub merchant_id: Box<str>,
+    /// Whether to reuse a merchant ID already used by another payment method
+    /// configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reuse_merchant_id: Option<bool>,
+}
+
+impl GooglePayInfo {
+    /// Create Google Pay settings with your Google Pay merchant ID.
+    pub fn new(merchant_id: impl Into<Box<str>>) -> Self {
+        Self {
+            merchant_id: merchant_id.into(),
+            reuse_merchant_id: None,
+        }
+    }
+}
+
+/// Klarna settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct KlarnaInfo {
+    /// The Klarna region of the merchant.
+    pub region: KlarnaRegion,
+    /// The email address shoppers can contact for support.
+    pub support_email: Box<str>,
+    /// The email address Klarna sends disputes to.
+    pub dispute_email: Box<str>,
+    /// Whether Klarna payments are captured automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_capture: Option<bool>,
+}
+
+impl KlarnaInfo {
+    /// Create Klarna settings.
+    pub fn new(
+        region: KlarnaRegion,
+        support_email: impl Into<Box<str>>,
+        dispute_email: impl Into<Box<str>>,
+    ) -> Self {
+        Self {
+            region,
+            support_email: support_email.into(),
+            dispute_email: dispute_email.into(),
+            auto_capture: None,
+        }
+    }
+}
+
+/// Klarna region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum KlarnaRegion {
+    /// North America.
+    #[serde(rename = "NA")]
+    NorthAmerica,
+    /// Europe.
+    #[serde(rename = "EU")]
+    Europe,
+    /// Switzerland.
+    #[serde(rename = "CH")]
+    Switzerland,
+    /// Australia.
+    #[serde(rename = "AU")]
+    Australia,
+    /// A Klarna region not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// PayPal settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PayPalInfo {
+    /// Your PayPal merchant (payer) ID.
+    pub payer_id: Box<str>,
+    /// The email address of your PayPal account.
+    pub subject: Box<str>,
+    /// Whether PayPal payments are captured immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_capture: Option<bool>,
+}
+
+impl PayPalInfo {
+    /// Create PayPal settings with your PayPal merchant ID and account email.
+    pub fn new(payer_id: impl Into<Box<str>>, subject: impl Into<Box<str>>) -> Self {
+        Self {
+            payer_id: payer_id.into(),
+            subject: subject.into(),
+            direct_capture: None,
+        }
+    }
+}
+
+/// giropay settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GiroPayInfo {
+    /// The email address shoppers can contact for support.
+    pub support_email: Box<str>,
+}
+
+impl GiroPayInfo {
+    /// Create giropay settings.
+    pub fn new(support_email: impl Into<Box<str>>) -> Self {
+        Self {
+            support_email: support_email.into(),
+        }
+    }
+}
+
+/// Sofort settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SofortInfo {
+    /// The currency of the payments.
+    pub currency_code: Box<str>,
+    /// The URL of your logo, shown on the Sofort payment page.
+    pub logo: Box<str>,
+}
+
+impl SofortInfo {
+    /// Create Sofort settings.
+    pub fn new(currency_code: impl Into<Box<str>>, logo: impl Into<Box<str>>) -> Self {
+        Self {
+            currency_code: currency_code.into(),
+            logo: logo.into(),
+        }
+    }
+}
+
+/// Swish settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SwishInfo {
+    /// Your Swish number.
+    pub swish_number: Box<str>,
+}
+
+impl SwishInfo {
+    /// Create Swish settings.
+    pub fn new(swish_number: impl Into<Box<str>>) -> Self {
+        Self {
+            swish_number: swish_number.into(),
+        }
+    }
+}
+
+/// Bancontact settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BcmcInfo {
+    /// Whether to also enable the Bancontact mobile app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_bcmc_mobile: Option<bool>,
+}
+
+impl BcmcInfo {
+    /// Create Bancontact settings.
+    pub fn new(enable_bcmc_mobile: bool) -> Self {
+        Self {
+            enable_bcmc_mobile: Some(enable_bcmc_mobile),
+        }
+    }
+}
+
+/// Cartes Bancaires settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CartesBancairesInfo {
+    /// The SIRET number of your business in France.
+    pub siret: Box<str>,
+}
+
+impl CartesBancairesInfo {
+    /// Create Cartes Bancaires settings.
+    pub fn new(siret: impl Into<Box<str>>) -> Self {
+        Self {
+            siret: siret.into(),
+        }
+    }
 }
 
 /// Webhook configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Webhook {
     /// The unique identifier of the webhook.
@@ -288,6 +887,11 @@ pub struct Webhook {
 
 /// Request to create a new webhook.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateWebhookRequest {
     /// The webhook URL that will receive notifications.
@@ -309,6 +913,11 @@ pub struct CreateWebhookRequest {
 
 /// Request to update an existing webhook.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateWebhookRequest {
     /// The webhook URL that will receive notifications.
@@ -333,6 +942,11 @@ pub struct UpdateWebhookRequest {
 
 /// Additional settings for webhooks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct WebhookAdditionalSettings {
     /// Additional HTTP headers to include in webhook requests.
@@ -346,8 +960,231 @@ pub struct WebhookAdditionalSettings {
     pub exclude_event_codes: Option<Vec<Box<str>>>,
 }
 
+/// Response containing a newly generated webhook HMAC key.
+///
+/// The key is used to verify the HMAC signature of incoming notifications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateHmacKeyResponse {
+    /// The new HMAC key, hex-encoded.
+    pub hmac_key: SecretString,
+}
+
+/// Request to send a test notification to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TestWebhookRequest {
+    /// The event types to send test notifications for, e.g. `AUTHORISATION`.
+    pub types: Vec<Box<str>>,
+}
+
+/// Outcome of a test notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TestWebhookStatus {
+    /// The webhook endpoint accepted the notification.
+    Success,
+    /// The webhook endpoint did not accept the notification.
+    Failed,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Result of a test notification sent to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TestWebhookResult {
+    /// The merchant account the notification was sent for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<Box<str>>,
+    /// The outcome of the test.
+    pub status: TestWebhookStatus,
+    /// A description of the outcome.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Box<str>>,
+    /// The notification body that was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_sent: Option<Box<str>>,
+    /// The HTTP status code returned by the webhook endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_code: Option<Box<str>>,
+    /// The time the webhook endpoint took to respond, e.g. `657 ms`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_time: Option<Box<str>>,
+}
+
+impl TestWebhookResult {
+    /// Whether the webhook endpoint accepted the notification.
+    pub fn is_success(&self) -> bool {
+        self.status == TestWebhookStatus::Success
+    }
+}
+
+/// API credential for a merchant account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiCredential {
+    /// The unique identifier of the API credential.
+    pub id: Box<str>,
+    /// The username of the API credential, e.g. `ws_123456@Company.Example`.
+    pub username: Box<str>,
+    /// Whether the API credential is active.
+    pub active: bool,
+    /// The client key, used for client-side integrations.
+    pub client_key: Box<str>,
+    /// The roles assigned to the API credential.
+    #[serde(default)]
+    pub roles: Vec<Box<str>>,
+    /// The IP addresses allowed to use the API credential.
+    #[serde(default)]
+    pub allowed_ip_addresses: Vec<Box<str>>,
+    /// The origins allowed to make client-side requests with the client key.
+    #[serde(default)]
+    pub allowed_origins: Vec<AllowedOrigin>,
+    /// The description of the API credential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+}
+
+/// API credential returned on creation, including its secrets.
+///
+/// The API key and password are only returned once; store them securely.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateApiCredentialResponse {
+    /// The API key for the new credential.
+    pub api_key: SecretString,
+    /// The password for basic authentication with the new credential.
+    pub password: SecretString,
+    /// The created API credential.
+    #[serde(flatten)]
+    pub credential: ApiCredential,
+}
+
+/// Request to create a new API credential.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateApiCredentialRequest {
+    /// The roles to assign to the API credential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Box<str>>>,
+    /// The origins allowed to make client-side requests with the client key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<Box<str>>>,
+    /// The description of the API credential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+}
+
+/// Request to update an existing API credential.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateApiCredentialRequest {
+    /// Whether the API credential should be active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The roles to assign to the API credential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Box<str>>>,
+    /// The origins allowed to make client-side requests with the client key.
+    ///
+    /// Replaces the existing list of allowed origins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Vec<Box<str>>>,
+    /// The description of the API credential.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+}
+
+/// Origin allowed to make client-side requests with a client key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedOrigin {
+    /// The unique identifier of the allowed origin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<str>>,
+    /// The origin, e.g. `https://www.example.com`.
+    pub domain: Box<str>,
+}
+
+impl AllowedOrigin {
+    /// Create an allowed origin for the given domain.
+    pub fn new(domain: impl Into<Box<str>>) -> Self {
+        Self {
+            id: None,
+            domain: domain.into(),
+        }
+    }
+}
+
+/// Response containing a newly generated client key.
+///
+/// The previous client key stops working once a new one is generated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateClientKeyResponse {
+    /// The new client key.
+    pub client_key: Box<str>,
+}
+
 /// Terminal model information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminalModel {
     /// The terminal model identifier.
@@ -367,6 +1204,11 @@ pub struct TerminalModel {
 
 /// Terminal settings and configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminalSettings {
     /// Card acquisition settings.
@@ -383,8 +1225,133 @@ pub struct TerminalSettings {
     pub gratuity: Option<GratuitySettings>,
 }
 
+impl TerminalSettings {
+    /// Fill in the settings not configured at this level from the level above.
+    ///
+    /// Terminal settings are inherited from company to merchant account to
+    /// store to terminal; a setting configured at a lower level overrides the
+    /// one above it.
+    #[must_use]
+    pub fn inherit_from(self, parent: &TerminalSettings) -> Self {
+        Self {
+            card_acquisition: self
+                .card_acquisition
+                .or_else(|| parent.card_acquisition.clone()),
+            connectivity: self.connectivity.or_else(|| parent.connectivity.clone()),
+            receipt_options: self
+                .receipt_options
+                .or_else(|| parent.receipt_options.clone()),
+            gratuity: self.gratuity.or_else(|| parent.gratuity.clone()),
+        }
+    }
+}
+
+/// Level at which terminal settings and logos are configured.
+///
+/// Settings configured at a level apply to all terminals below it, unless
+/// overridden at a lower level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalSettingsLevel {
+    /// All terminals of a company account.
+    Company(Box<str>),
+    /// All terminals of a merchant account.
+    Merchant(Box<str>),
+    /// All terminals of a store.
+    Store {
+        /// The merchant account the store belongs to.
+        merchant_id: Box<str>,
+        /// The store ID.
+        store_id: Box<str>,
+    },
+    /// A single terminal.
+    Terminal(Box<str>),
+}
+
+impl TerminalSettingsLevel {
+    /// Create a company-level target.
+    pub fn company(company_id: impl Into<Box<str>>) -> Self {
+        Self::Company(company_id.into())
+    }
+
+    /// Create a merchant-level target.
+    pub fn merchant(merchant_id: impl Into<Box<str>>) -> Self {
+        Self::Merchant(merchant_id.into())
+    }
+
+    /// Create a store-level target.
+    pub fn store(merchant_id: impl Into<Box<str>>, store_id: impl Into<Box<str>>) -> Self {
+        Self::Store {
+            merchant_id: merchant_id.into(),
+            store_id: store_id.into(),
+        }
+    }
+
+    /// Create a terminal-level target.
+    pub fn terminal(terminal_id: impl Into<Box<str>>) -> Self {
+        Self::Terminal(terminal_id.into())
+    }
+
+    /// The API path of this level, relative to the API version.
+    pub fn path(&self) -> String {
+        match self {
+            Self::Company(company_id) => format!("companies/{company_id}"),
+            Self::Merchant(merchant_id) => format!("merchants/{merchant_id}"),
+            Self::Store {
+                merchant_id,
+                store_id,
+            } => format!("merchants/{merchant_id}/stores/{store_id}"),
+            Self::Terminal(terminal_id) => format!("terminals/{terminal_id}"),
+        }
+    }
+}
+
+/// Logo shown on the display of payment terminals.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminalLogo {
+    /// The logo image, base64-encoded. Send `None` to remove the logo and
+    /// inherit it from the level above.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Box<str>>,
+}
+
+impl TerminalLogo {
+    /// Create a logo from image bytes, e.g. the contents of a PNG file.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            data: Some(BASE64.encode(bytes).into()),
+        }
+    }
+
+    /// Decode the logo image bytes, if a logo is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the logo data is not valid base64.
+    pub fn to_bytes(&self) -> Result<Option<Vec<u8>>> {
+        self.data
+            .as_deref()
+            .map(|data| {
+                BASE64.decode(data).map_err(|e| {
+                    AdyenError::generic_with_source("invalid terminal logo data", Box::new(e))
+                })
+            })
+            .transpose()
+    }
+}
+
 /// Card acquisition settings for terminals.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CardAcquisitionSettings {
     /// Operation mode for card acquisition.
@@ -397,6 +1364,11 @@ pub struct CardAcquisitionSettings {
 
 /// Connectivity settings for terminals.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectivitySettings {
     /// Ethernet settings.
@@ -409,6 +1381,11 @@ pub struct ConnectivitySettings {
 
 /// Ethernet connectivity settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct EthernetSettings {
     /// Whether DHCP is enabled.
@@ -421,6 +1398,11 @@ pub struct EthernetSettings {
 
 /// WiFi connectivity settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct WiFiSettings {
     /// WiFi network SSID.
@@ -433,6 +1415,11 @@ pub struct WiFiSettings {
 
 /// Receipt printing options.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ReceiptOptions {
     /// Whether to print merchant receipts.
@@ -445,6 +1432,11 @@ pub struct ReceiptOptions {
 
 /// Gratuity/tip settings for terminals.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct GratuitySettings {
     /// Whether gratuity is enabled.
@@ -457,6 +1449,11 @@ pub struct GratuitySettings {
 
 /// Terminal information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Terminal {
     /// The terminal ID.
@@ -478,6 +1475,11 @@ pub struct Terminal {
 
 /// Terminal assignment information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminalAssignment {
     /// The company ID.
@@ -496,6 +1498,11 @@ pub struct TerminalAssignment {
 
 /// Terminal status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum TerminalStatus {
     Active,
@@ -504,10 +1511,276 @@ pub enum TerminalStatus {
     DeliveryPending,
     Delivered,
     Assigned,
+    /// A terminal status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request to reassign a terminal to another account or store.
+///
+/// Set exactly one target: a company or merchant account inventory, or a
+/// store.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminalReassignmentRequest {
+    /// The company account to reassign the terminal to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_id: Option<Box<str>>,
+    /// The merchant account to reassign the terminal to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_id: Option<Box<str>>,
+    /// The store to reassign the terminal to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<Box<str>>,
+    /// Whether to put the terminal in the merchant account's inventory
+    /// instead of assigning it for use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<bool>,
+}
+
+impl TerminalReassignmentRequest {
+    /// Reassign the terminal to a company account inventory.
+    pub fn to_company(company_id: impl Into<Box<str>>) -> Self {
+        Self {
+            company_id: Some(company_id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Reassign the terminal to a merchant account, for use or in its
+    /// inventory.
+    pub fn to_merchant(merchant_id: impl Into<Box<str>>, inventory: bool) -> Self {
+        Self {
+            merchant_id: Some(merchant_id.into()),
+            inventory: Some(inventory),
+            ..Self::default()
+        }
+    }
+
+    /// Reassign the terminal to a store.
+    pub fn to_store(store_id: impl Into<Box<str>>) -> Self {
+        Self {
+            store_id: Some(store_id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Action to run on payment terminals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(tag = "type")]
+pub enum TerminalActionDetails {
+    /// Install an Android app.
+    #[serde(rename_all = "camelCase")]
+    InstallAndroidApp {
+        /// The ID of the app, as uploaded to the Customer Area.
+        app_id: Box<str>,
+    },
+    /// Uninstall an Android app.
+    #[serde(rename_all = "camelCase")]
+    UninstallAndroidApp {
+        /// The ID of the app.
+        app_id: Box<str>,
+    },
+    /// Install an Android certificate.
+    #[serde(rename_all = "camelCase")]
+    InstallAndroidCertificate {
+        /// The ID of the certificate, as uploaded to the Customer Area.
+        certificate_id: Box<str>,
+    },
+    /// Uninstall an Android certificate.
+    #[serde(rename_all = "camelCase")]
+    UninstallAndroidCertificate {
+        /// The ID of the certificate.
+        certificate_id: Box<str>,
+    },
+    /// Release pending configuration and firmware updates to the terminal.
+    ///
+    /// The terminal restarts to apply the updates.
+    #[serde(rename_all = "camelCase")]
+    ReleaseUpdate {
+        /// Whether to apply the updates at the terminal's first maintenance
+        /// call instead of its next scheduled one.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        update_at_first_maintenance_call: Option<bool>,
+    },
+}
+
+/// Request to schedule an action on payment terminals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleTerminalActionsRequest {
+    /// The action to run.
+    pub action_details: TerminalActionDetails,
+    /// The terminals to run the action on.
+    pub terminal_ids: Vec<Box<str>>,
+    /// When to run the action, as an ISO 8601 date-time, e.g.
+    /// `2026-10-20T02:00:00`. Interpreted in the time zone of the terminals'
+    /// store. Leave empty to run at the next maintenance call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<Box<str>>,
+    /// The store the terminals belong to, if all are in one store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<Box<str>>,
+}
+
+impl ScheduleTerminalActionsRequest {
+    /// Create a request to run an action on the given terminals at their next
+    /// maintenance call.
+    pub fn new<I, T>(action_details: TerminalActionDetails, terminal_ids: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Box<str>>,
+    {
+        Self {
+            action_details,
+            terminal_ids: terminal_ids.into_iter().map(Into::into).collect(),
+            scheduled_at: None,
+            store_id: None,
+        }
+    }
+
+    /// Set when to run the action.
+    #[must_use]
+    pub fn scheduled_at(mut self, scheduled_at: impl Into<Box<str>>) -> Self {
+        self.scheduled_at = Some(scheduled_at.into());
+        self
+    }
+
+    /// Set the store the terminals belong to.
+    #[must_use]
+    pub fn store_id(mut self, store_id: impl Into<Box<str>>) -> Self {
+        self.store_id = Some(store_id.into());
+        self
+    }
+}
+
+/// A terminal action scheduled for a single terminal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTerminalAction {
+    /// The ID of the action.
+    pub id: Box<str>,
+    /// The terminal the action is scheduled on.
+    pub terminal_id: Box<str>,
+}
+
+/// Response to scheduling terminal actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleTerminalActionsResponse {
+    /// The scheduled action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_details: Option<TerminalActionDetails>,
+    /// The actions scheduled, one per terminal.
+    #[serde(default)]
+    pub items: Vec<ScheduledTerminalAction>,
+    /// When the action is scheduled to run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<Box<str>>,
+    /// The store the terminals belong to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<Box<str>>,
+    /// The terminals the action could not be scheduled on, with the reasons.
+    #[serde(default)]
+    pub terminals_with_errors: HashMap<String, Vec<Box<str>>>,
+    /// The number of terminals the action could not be scheduled on.
+    #[serde(default)]
+    pub total_errors: u32,
+    /// The number of terminals the action was scheduled on.
+    #[serde(default)]
+    pub total_scheduled: u32,
+}
+
+/// Status of a terminal action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TerminalActionStatus {
+    /// The action is waiting to run.
+    Pending,
+    /// The action ran successfully.
+    Successful,
+    /// The action failed.
+    Failed,
+    /// The action was cancelled.
+    Cancelled,
+    /// The action could not run and will be retried.
+    TryLater,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A terminal action, as listed for a company account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TerminalAction {
+    /// The ID of the action.
+    pub id: Box<str>,
+    /// The type of action, e.g. `InstallAndroidApp`.
+    pub action_type: Box<str>,
+    /// The terminal the action is scheduled on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terminal_id: Option<Box<str>>,
+    /// The status of the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TerminalActionStatus>,
+    /// The action's configuration, e.g. the app or certificate ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<Box<str>>,
+    /// The result message of the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Box<str>>,
+    /// When the action is scheduled to run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<Box<str>>,
+    /// When the terminal confirmed the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmed_at: Option<Box<str>>,
 }
 
 /// Contact information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Contact {
     /// The contact's email address.
@@ -525,6 +1798,11 @@ pub struct Contact {
 
 /// Address information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// Street address line 1.
@@ -545,6 +1823,11 @@ pub struct Address {
 
 /// Links to related resources.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Links {
     /// Link to the resource itself.
@@ -558,6 +1841,85 @@ pub struct Links {
     pub additional_links: HashMap<String, Box<str>>,
 }
 
+/// Filters for listing stores.
+///
+/// # Examples
+///
+/// ```rust
+/// use adyen_management::types::StoreListQuery;
+///
+/// use adyen_management::types::StoreStatus;
+///
+/// let query = StoreListQuery::new()
+///     .merchant_id("merchant_123")
+///     .reference("Amsterdam 1")
+///     .status(StoreStatus::Active);
+/// assert_eq!(
+///     query.to_query_string(),
+///     "merchantId=merchant_123&reference=Amsterdam%201&status=Active"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreListQuery {
+    /// Only include stores of this merchant account.
+    pub merchant_id: Option<Box<str>>,
+    /// Only include stores with this reference.
+    pub reference: Option<Box<str>>,
+    /// Only include stores with this status.
+    pub status: Option<StoreStatus>,
+}
+
+impl StoreListQuery {
+    /// Create a query without filters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by merchant account.
+    #[must_use]
+    pub fn merchant_id(mut self, merchant_id: impl Into<Box<str>>) -> Self {
+        self.merchant_id = Some(merchant_id.into());
+        self
+    }
+
+    /// Filter by store reference.
+    #[must_use]
+    pub fn reference(mut self, reference: impl Into<Box<str>>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Filter by store status.
+    #[must_use]
+    pub fn status(mut self, status: StoreStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Encode the filters as a URL query string, without the leading `?`.
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        let status = self.status.as_ref().map(|status| match status {
+            StoreStatus::Active => "Active",
+            StoreStatus::Inactive => "Inactive",
+            StoreStatus::Closed => "Closed",
+            StoreStatus::Unknown => "Unknown",
+        });
+        [
+            ("merchantId", self.merchant_id.as_deref()),
+            ("reference", self.reference.as_deref()),
+            ("status", status),
+        ]
+        .iter()
+        .filter_map(|(name, value)| {
+            value.map(|value| format!("{name}={}", urlencoding::encode(value)))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+    }
+}
+
 // Builder implementations for easier API usage
 impl CreateMerchantRequest {
     /// Create a new builder for CreateMerchantRequest.
@@ -567,38 +1929,73 @@ impl CreateMerchantRequest {
 }
 
 /// Builder for CreateMerchantRequest.
+///
+/// `build` is only available once the required company ID, merchant account,
+/// business details and primary contact are set.
 #[derive(Default)]
-pub struct CreateMerchantRequestBuilder {
-    company_id: Option<Box<str>>,
-    merchant_account: Option<Box<str>>,
-    business_details: Option<BusinessDetails>,
-    primary_contact: Option<Contact>,
+pub struct CreateMerchantRequestBuilder<C = Missing, M = Missing, B = Missing, P = Missing> {
+    company_id: C,
+    merchant_account: M,
+    business_details: B,
+    primary_contact: P,
     billing_address: Option<Address>,
 }
 
-impl CreateMerchantRequestBuilder {
+impl<C, M, B, P> CreateMerchantRequestBuilder<C, M, B, P> {
     /// Set the company ID.
-    pub fn company_id(mut self, company_id: impl Into<Box<str>>) -> Self {
-        self.company_id = Some(company_id.into());
-        self
+    pub fn company_id(
+        self,
+        company_id: impl Into<Box<str>>,
+    ) -> CreateMerchantRequestBuilder<Box<str>, M, B, P> {
+        CreateMerchantRequestBuilder {
+            company_id: company_id.into(),
+            merchant_account: self.merchant_account,
+            business_details: self.business_details,
+            primary_contact: self.primary_contact,
+            billing_address: self.billing_address,
+        }
     }
 
     /// Set the merchant account name.
-    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> CreateMerchantRequestBuilder<C, Box<str>, B, P> {
+        CreateMerchantRequestBuilder {
+            company_id: self.company_id,
+            merchant_account: merchant_account.into(),
+            business_details: self.business_details,
+            primary_contact: self.primary_contact,
+            billing_address: self.billing_address,
+        }
     }
 
     /// Set the business details.
-    pub fn business_details(mut self, business_details: BusinessDetails) -> Self {
-        self.business_details = Some(business_details);
-        self
+    pub fn business_details(
+        self,
+        business_details: BusinessDetails,
+    ) -> CreateMerchantRequestBuilder<C, M, BusinessDetails, P> {
+        CreateMerchantRequestBuilder {
+            company_id: self.company_id,
+            merchant_account: self.merchant_account,
+            business_details,
+            primary_contact: self.primary_contact,
+            billing_address: self.billing_address,
+        }
     }
 
     /// Set the primary contact.
-    pub fn primary_contact(mut self, primary_contact: Contact) -> Self {
-        self.primary_contact = Some(primary_contact);
-        self
+    pub fn primary_contact(
+        self,
+        primary_contact: Contact,
+    ) -> CreateMerchantRequestBuilder<C, M, B, Contact> {
+        CreateMerchantRequestBuilder {
+            company_id: self.company_id,
+            merchant_account: self.merchant_account,
+            business_details: self.business_details,
+            primary_contact,
+            billing_address: self.billing_address,
+        }
     }
 
     /// Set the billing address.
@@ -606,29 +2003,18 @@ impl CreateMerchantRequestBuilder {
         self.billing_address = Some(billing_address);
         self
     }
+}
 
+impl CreateMerchantRequestBuilder<Box<str>, Box<str>, BusinessDetails, Contact> {
     /// Build the CreateMerchantRequest.
-    pub fn build(self) -> Result<CreateMerchantRequest> {
-        let company_id = self
-            .company_id
-            .ok_or_else(|| AdyenError::generic("company_id is required"))?;
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::generic("merchant_account is required"))?;
-        let business_details = self
-            .business_details
-            .ok_or_else(|| AdyenError::generic("business_details is required"))?;
-        let primary_contact = self
-            .primary_contact
-            .ok_or_else(|| AdyenError::generic("primary_contact is required"))?;
-
-        Ok(CreateMerchantRequest {
-            company_id,
-            merchant_account,
-            business_details,
-            primary_contact,
+    pub fn build(self) -> CreateMerchantRequest {
+        CreateMerchantRequest {
+            company_id: self.company_id,
+            merchant_account: self.merchant_account,
+            business_details: self.business_details,
+            primary_contact: self.primary_contact,
             billing_address: self.billing_address,
-        })
+        }
     }
 }
 
@@ -640,32 +2026,66 @@ impl CreateStoreRequest {
 }
 
 /// Builder for CreateStoreRequest.
+///
+/// `build` is only available once the required store reference, description and
+/// address are set.
+///
+/// ```compile_fail
+/// use adyen_management::types::CreateStoreRequest;
+///
+/// // No address, so there is no `build` method.
+/// let request = CreateStoreRequest::builder()
+///     .store_reference("store-1")
+///     .description("Amsterdam store")
+///     .build();
+/// ```
 #[derive(Default)]
-pub struct CreateStoreRequestBuilder {
-    store_reference: Option<Box<str>>,
-    description: Option<Box<str>>,
-    address: Option<Address>,
+pub struct CreateStoreRequestBuilder<R = Missing, D = Missing, A = Missing> {
+    store_reference: R,
+    description: D,
+    address: A,
     phone_number: Option<Box<str>>,
     business_line_id: Option<Box<str>>,
 }
 
-impl CreateStoreRequestBuilder {
+impl<R, D, A> CreateStoreRequestBuilder<R, D, A> {
     /// Set the store reference.
-    pub fn store_reference(mut self, store_reference: impl Into<Box<str>>) -> Self {
-        self.store_reference = Some(store_reference.into());
-        self
+    pub fn store_reference(
+        self,
+        store_reference: impl Into<Box<str>>,
+    ) -> CreateStoreRequestBuilder<Box<str>, D, A> {
+        CreateStoreRequestBuilder {
+            store_reference: store_reference.into(),
+            description: self.description,
+            address: self.address,
+            phone_number: self.phone_number,
+            business_line_id: self.business_line_id,
+        }
     }
 
     /// Set the store description.
-    pub fn description(mut self, description: impl Into<Box<str>>) -> Self {
-        self.description = Some(description.into());
-        self
+    pub fn description(
+        self,
+        description: impl Into<Box<str>>,
+    ) -> CreateStoreRequestBuilder<R, Box<str>, A> {
+        CreateStoreRequestBuilder {
+            store_reference: self.store_reference,
+            description: description.into(),
+            address: self.address,
+            phone_number: self.phone_number,
+            business_line_id: self.business_line_id,
+        }
     }
 
     /// Set the store address.
-    pub fn address(mut self, address: Address) -> Self {
-        self.address = Some(address);
-        self
+    pub fn address(self, address: Address) -> CreateStoreRequestBuilder<R, D, Address> {
+        CreateStoreRequestBuilder {
+            store_reference: self.store_reference,
+            description: self.description,
+            address,
+            phone_number: self.phone_number,
+            business_line_id: self.business_line_id,
+        }
     }
 
     /// Set the store phone number.
@@ -679,25 +2099,17 @@ impl CreateStoreRequestBuilder {
         self.business_line_id = Some(business_line_id.into());
         self
     }
+}
 
+impl CreateStoreRequestBuilder<Box<str>, Box<str>, Address> {
     /// Build the CreateStoreRequest.
-    pub fn build(self) -> Result<CreateStoreRequest> {
-        let store_reference = self
-            .store_reference
-            .ok_or_else(|| AdyenError::generic("store_reference is required"))?;
-        let description = self
-            .description
-            .ok_or_else(|| AdyenError::generic("description is required"))?;
-        let address = self
-            .address
-            .ok_or_else(|| AdyenError::generic("address is required"))?;
-
-        Ok(CreateStoreRequest {
-            store_reference,
-            description,
-            address,
+    pub fn build(self) -> CreateStoreRequest {
+        CreateStoreRequest {
+            store_reference: self.store_reference,
+            description: self.description,
+            address: self.address,
             phone_number: self.phone_number,
             business_line_id: self.business_line_id,
-        })
+        }
     }
 }
@@ -2,11 +2,16 @@
 
 use adyen_core::{ConfigBuilder, Environment};
 use adyen_management::types::{
-    Address, BusinessDetails, Contact, MerchantStatus, StoreStatus, TerminalStatus,
+    Address, ApplePayInfo, BusinessDetails, Contact, KlarnaInfo, KlarnaRegion, MerchantStatus,
+    PayPalInfo, StoreListQuery, StoreStatus, TerminalStatus,
 };
 use adyen_management::{
-    CreateMerchantRequest, CreateStoreRequest, CreateWebhookRequest, ManagementApi,
-    UpdatePaymentMethodRequest,
+    CreateApiCredentialResponse, CreateMerchantRequest, CreateStoreRequest, CreateWebhookRequest,
+    GenerateClientKeyResponse, ManagementApi, PaymentMethod, PaymentMethodSetupRequest,
+    PaymentMethodTypeSettings, ScheduleTerminalActionsRequest, ScheduleTerminalActionsResponse,
+    TerminalActionDetails, TerminalLogo, TerminalReassignmentRequest, TerminalSettings,
+    TerminalSettingsLevel, TestWebhookRequest, TestWebhookResult, TestWebhookStatus,
+    UpdateApiCredentialRequest, UpdatePaymentMethodRequest,
 };
 
 fn create_test_config() -> adyen_core::Config {
@@ -54,8 +59,7 @@ mod request_building_tests {
             .business_details(business_details)
             .primary_contact(contact)
             .billing_address(address)
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(&*request.company_id, "company_123");
         assert_eq!(&*request.merchant_account, "ExampleMerchant");
@@ -84,8 +88,7 @@ mod request_building_tests {
             .address(address)
             .phone_number("+1987654321")
             .business_line_id("business_line_123")
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(&*request.store_reference, "store_001");
         assert_eq!(&*request.description, "Main Store Location");
@@ -126,6 +129,7 @@ mod request_building_tests {
             configuration: None,
             countries: Some(vec!["US".into(), "CA".into()]),
             currencies: Some(vec!["USD".into(), "CAD".into()]),
+            settings: PaymentMethodTypeSettings::default(),
         };
 
         assert_eq!(&*request.r#type, "scheme");
@@ -133,6 +137,28 @@ mod request_building_tests {
         assert_eq!(request.countries.as_ref().unwrap().len(), 2);
         assert_eq!(request.currencies.as_ref().unwrap().len(), 2);
     }
+
+    #[test]
+    fn test_store_list_query() {
+        assert_eq!(StoreListQuery::new().to_query_string(), "");
+        assert_eq!(
+            StoreListQuery::new()
+                .merchant_id("merchant_123")
+                .reference("Store #1")
+                .to_query_string(),
+            "merchantId=merchant_123&reference=Store%20%231"
+        );
+        assert_eq!(
+            StoreListQuery::new().reference("main").to_query_string(),
+            "reference=main"
+        );
+        assert_eq!(
+            StoreListQuery::new()
+                .status(StoreStatus::Closed)
+                .to_query_string(),
+            "status=Closed"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -185,8 +211,7 @@ mod serialization_tests {
             .merchant_account("TestMerchant")
             .business_details(business_details)
             .primary_contact(contact)
-            .build()
-            .unwrap();
+            .build();
 
         let json = serde_json::to_string(&request).unwrap();
         let deserialized: CreateMerchantRequest = serde_json::from_str(&json).unwrap();
@@ -214,8 +239,7 @@ mod serialization_tests {
             .store_reference("test_store")
             .description("Test Store")
             .address(address)
-            .build()
-            .unwrap();
+            .build();
 
         let json = serde_json::to_string(&request).unwrap();
         let deserialized: CreateStoreRequest = serde_json::from_str(&json).unwrap();
@@ -248,6 +272,253 @@ mod serialization_tests {
         );
     }
 
+    #[test]
+    fn test_update_api_credential_request_serialization() {
+        let request = UpdateApiCredentialRequest {
+            active: Some(false),
+            allowed_origins: Some(vec!["https://www.example.com".into()]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "active": false,
+                "allowedOrigins": ["https://www.example.com"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_api_credential_response_deserialization() {
+        let json = r#"{
+            "id": "S2-6262224667",
+            "username": "ws_123456@Company.Example",
+            "active": true,
+            "clientKey": "test_Q2PFT5AOBXSQKV2PWZ4SKTRMMY",
+            "roles": ["Checkout webservice role"],
+            "allowedIpAddresses": [],
+            "allowedOrigins": [{"id": "S2-123123", "domain": "https://www.example.com"}],
+            "apiKey": "AQEyhmfxK4",
+            "password": "secret"
+        }"#;
+
+        let response: CreateApiCredentialResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.api_key.expose_secret(), "AQEyhmfxK4");
+        assert_eq!(&*response.credential.username, "ws_123456@Company.Example");
+        assert_eq!(
+            &*response.credential.allowed_origins[0].domain,
+            "https://www.example.com"
+        );
+
+        let key: GenerateClientKeyResponse =
+            serde_json::from_str(r#"{"clientKey": "test_NEWKEY"}"#).unwrap();
+        assert_eq!(&*key.client_key, "test_NEWKEY");
+    }
+
+    #[test]
+    fn test_webhook_test_results_deserialization() {
+        let json = r#"[
+            {
+                "merchantId": "merchant_123",
+                "output": "[accepted]",
+                "requestSent": "{}",
+                "responseCode": "200",
+                "responseTime": "657 ms",
+                "status": "success"
+            },
+            {"merchantId": "merchant_456", "status": "failed", "responseCode": "500"},
+            {"status": "pending"}
+        ]"#;
+
+        let results: Vec<TestWebhookResult> = serde_json::from_str(json).unwrap();
+        assert!(results[0].is_success());
+        assert_eq!(results[0].response_code.as_deref(), Some("200"));
+        assert_eq!(results[1].status, TestWebhookStatus::Failed);
+        assert_eq!(results[2].status, TestWebhookStatus::Unknown);
+
+        let request = TestWebhookRequest {
+            types: vec!["AUTHORISATION".into()],
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"types": ["AUTHORISATION"]})
+        );
+    }
+
+    #[test]
+    fn test_payment_method_setup_serialization() {
+        let request = PaymentMethodSetupRequest::klarna(KlarnaInfo::new(
+            KlarnaRegion::Europe,
+            "support@example.com",
+            "disputes@example.com",
+        ))
+        .countries(["NL"])
+        .currencies(["EUR"])
+        .business_line_id("BL001");
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "type": "klarna",
+                "countries": ["NL"],
+                "currencies": ["EUR"],
+                "businessLineId": "BL001",
+                "klarna": {
+                    "region": "EU",
+                    "supportEmail": "support@example.com",
+                    "disputeEmail": "disputes@example.com"
+                }
+            })
+        );
+
+        let update = UpdatePaymentMethodRequest::new("paypal", true)
+            .settings(PayPalInfo::new("ABCDEFGHIJKL", "paypal@example.com"));
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            serde_json::json!({
+                "type": "paypal",
+                "enabled": true,
+                "paypal": {"payerId": "ABCDEFGHIJKL", "subject": "paypal@example.com"}
+            })
+        );
+
+        let payment_method: PaymentMethod = serde_json::from_str(
+            r#"{
+                "id": "PM3224R223224K5FH4M2K9B86",
+                "type": "applepay",
+                "enabled": true,
+                "applePay": {"domains": ["www.example.com"]}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            payment_method.id.as_deref(),
+            Some("PM3224R223224K5FH4M2K9B86")
+        );
+        assert_eq!(
+            payment_method.settings.apple_pay,
+            Some(ApplePayInfo::new(["www.example.com"]))
+        );
+        assert!(payment_method.settings.klarna.is_none());
+    }
+
+    #[test]
+    fn test_terminal_settings_inheritance() {
+        use adyen_management::types::{GratuitySettings, ReceiptOptions};
+
+        let merchant = TerminalSettings {
+            card_acquisition: None,
+            connectivity: None,
+            receipt_options: Some(ReceiptOptions {
+                merchant_receipt: Some(true),
+                shopper_receipt: Some(true),
+            }),
+            gratuity: Some(GratuitySettings {
+                enabled: Some(false),
+                predefined_tip_entries: None,
+            }),
+        };
+        let store = TerminalSettings {
+            card_acquisition: None,
+            connectivity: None,
+            receipt_options: None,
+            gratuity: Some(GratuitySettings {
+                enabled: Some(true),
+                predefined_tip_entries: Some(vec![100, 200]),
+            }),
+        };
+
+        let effective = store.inherit_from(&merchant);
+        assert_eq!(effective.gratuity.unwrap().enabled, Some(true));
+        assert_eq!(
+            effective.receipt_options.unwrap().merchant_receipt,
+            Some(true)
+        );
+        assert!(effective.card_acquisition.is_none());
+
+        assert_eq!(
+            TerminalSettingsLevel::company("company_1").path(),
+            "companies/company_1"
+        );
+        assert_eq!(
+            TerminalSettingsLevel::store("merchant_1", "store_1").path(),
+            "merchants/merchant_1/stores/store_1"
+        );
+        assert_eq!(
+            TerminalSettingsLevel::terminal("V400m-123456789").path(),
+            "terminals/V400m-123456789"
+        );
+    }
+
+    #[test]
+    fn test_terminal_logo_round_trip() {
+        let logo = TerminalLogo::from_bytes(b"\x89PNG");
+        let json = serde_json::to_string(&logo).unwrap();
+        let deserialized: TerminalLogo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.to_bytes().unwrap().unwrap(), b"\x89PNG");
+        assert!(TerminalLogo::default().to_bytes().unwrap().is_none());
+        assert!(TerminalLogo {
+            data: Some("not base64!".into())
+        }
+        .to_bytes()
+        .is_err());
+    }
+
+    #[test]
+    fn test_terminal_actions_serialization() {
+        let request = ScheduleTerminalActionsRequest::new(
+            TerminalActionDetails::InstallAndroidApp {
+                app_id: "ANDA422LZ223223K5F694GCCF732K8".into(),
+            },
+            ["V400m-123456789"],
+        )
+        .scheduled_at("2026-10-20T02:00:00")
+        .store_id("store_456");
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "actionDetails": {
+                    "type": "InstallAndroidApp",
+                    "appId": "ANDA422LZ223223K5F694GCCF732K8"
+                },
+                "terminalIds": ["V400m-123456789"],
+                "scheduledAt": "2026-10-20T02:00:00",
+                "storeId": "store_456"
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(TerminalReassignmentRequest::to_merchant(
+                "merchant_123",
+                true
+            ))
+            .unwrap(),
+            serde_json::json!({"merchantId": "merchant_123", "inventory": true})
+        );
+
+        let response: ScheduleTerminalActionsResponse = serde_json::from_str(
+            r#"{
+                "actionDetails": {"type": "ReleaseUpdate", "updateAtFirstMaintenanceCall": false},
+                "items": [{"id": "TRAC422T2223223K5GFMQHM6WQ4KB6", "terminalId": "V400m-123456789"}],
+                "terminalsWithErrors": {"P400Plus-987654321": ["Terminal not found"]},
+                "totalErrors": 1,
+                "totalScheduled": 1
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.action_details,
+            Some(TerminalActionDetails::ReleaseUpdate {
+                update_at_first_maintenance_call: Some(false)
+            })
+        );
+        assert_eq!(&*response.items[0].terminal_id, "V400m-123456789");
+        assert_eq!(response.terminals_with_errors.len(), 1);
+    }
+
     #[test]
     fn test_enum_serialization() {
         // Test MerchantStatus enum
@@ -280,85 +551,17 @@ mod serialization_tests {
         assert_eq!(terminal_active_json, "\"Active\"");
         assert_eq!(terminal_boarded_json, "\"Boarded\"");
     }
-}
-
-#[cfg(test)]
-mod validation_tests {
-    use super::*;
 
     #[test]
-    fn test_create_merchant_request_validation() {
-        // Test missing company ID
-        let business_details = BusinessDetails {
-            legal_business_name: "Test Corp".into(),
-            trading_name: None,
-            mcc: None,
-            registration: None,
-            website_url: None,
-        };
-
-        let contact = Contact {
-            email: "test@example.com".into(),
-            first_name: None,
-            last_name: None,
-            phone_number: None,
-        };
-
-        let result = CreateMerchantRequest::builder()
-            .merchant_account("TestMerchant")
-            .business_details(business_details.clone())
-            .primary_contact(contact.clone())
-            .build();
-        assert!(result.is_err());
-
-        // Test missing merchant account
-        let result = CreateMerchantRequest::builder()
-            .company_id("company_123")
-            .business_details(business_details.clone())
-            .primary_contact(contact.clone())
-            .build();
-        assert!(result.is_err());
-
-        // Test missing business details
-        let result = CreateMerchantRequest::builder()
-            .company_id("company_123")
-            .merchant_account("TestMerchant")
-            .primary_contact(contact)
-            .build();
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_create_store_request_validation() {
-        let address = Address {
-            street_address: "123 Test St".into(),
-            street_address2: None,
-            city: "Test City".into(),
-            state_or_province: None,
-            postal_code: "12345".into(),
-            country: "US".into(),
-        };
-
-        // Test missing store reference
-        let result = CreateStoreRequest::builder()
-            .description("Test Store")
-            .address(address.clone())
-            .build();
-        assert!(result.is_err());
+    fn test_unknown_enum_values() {
+        let merchant: MerchantStatus = serde_json::from_str("\"PendingReview\"").unwrap();
+        assert!(matches!(merchant, MerchantStatus::Unknown));
 
-        // Test missing description
-        let result = CreateStoreRequest::builder()
-            .store_reference("test_store")
-            .address(address.clone())
-            .build();
-        assert!(result.is_err());
+        let store: StoreStatus = serde_json::from_str("\"Migrating\"").unwrap();
+        assert!(matches!(store, StoreStatus::Unknown));
 
-        // Test missing address
-        let result = CreateStoreRequest::builder()
-            .store_reference("test_store")
-            .description("Test Store")
-            .build();
-        assert!(result.is_err());
+        let terminal: TerminalStatus = serde_json::from_str("\"Decommissioned\"").unwrap();
+        assert!(matches!(terminal, TerminalStatus::Unknown));
     }
 }
 
@@ -404,8 +607,7 @@ mod workflow_tests {
             .business_details(business_details)
             .primary_contact(primary_contact)
             .billing_address(billing_address)
-            .build()
-            .unwrap();
+            .build();
 
         // Step 2: Create store under merchant
         let store_address = Address {
@@ -423,8 +625,7 @@ mod workflow_tests {
             .address(store_address)
             .phone_number("+1555987654")
             .business_line_id("retail_line_001")
-            .build()
-            .unwrap();
+            .build();
 
         // Step 3: Configure payment methods
         let payment_method_request = UpdatePaymentMethodRequest {
@@ -433,6 +634,7 @@ mod workflow_tests {
             configuration: None,
             countries: Some(vec!["US".into(), "CA".into(), "MX".into()]),
             currencies: Some(vec!["USD".into(), "CAD".into(), "MXN".into()]),
+            settings: PaymentMethodTypeSettings::default(),
         };
 
         // Step 4: Set up webhooks
@@ -645,3 +847,268 @@ mod workflow_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_core::PaginatedRequest;
+    use futures::TryStreamExt;
+
+    fn mock_api(mock: &MockTransport) -> ManagementApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        ManagementApi::new(config).unwrap()
+    }
+
+    fn merchant(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "name": id,
+            "merchantCode": id,
+            "companyId": "company_123"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_merchants_stream_fetches_all_pages() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v3/companies/company_123/merchants")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [merchant("merchant_1"), merchant("merchant_2")],
+                        "itemsTotal": 3,
+                        "pagesTotal": 2
+                    }),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::get("/v3/companies/company_123/merchants")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [merchant("merchant_3")],
+                        "itemsTotal": 3,
+                        "pagesTotal": 2
+                    }),
+                ))
+                .times(1),
+        );
+
+        let api = mock_api(&mock);
+        let merchants: Vec<_> = api
+            .list_merchants_stream("company_123", PaginatedRequest::new(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = merchants.iter().map(|m| &*m.id).collect();
+        assert_eq!(ids, ["merchant_1", "merchant_2", "merchant_3"]);
+
+        let urls: Vec<String> = mock
+            .received_requests()
+            .into_iter()
+            .map(|request| request.url)
+            .collect();
+        assert!(urls[0].ends_with("?pageNumber=1&pageSize=2"));
+        assert!(urls[1].ends_with("?pageNumber=2&pageSize=2"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_list_api_credentials_stream_follows_next_link() {
+        let credential = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "username": format!("ws_{id}@Company.Test"),
+                "active": true,
+                "clientKey": "test_CLIENTKEY"
+            })
+        };
+        let href = |page: u32| {
+            serde_json::json!({
+                "href": format!(
+                    "https://management-test.adyen.com/v3/merchants/merchant_123/apiCredentials?pageNumber={page}&pageSize=1"
+                )
+            })
+        };
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v3/merchants/merchant_123/apiCredentials")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [credential("S2-1")],
+                        "_links": {"self": href(1), "next": href(2)}
+                    }),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::get("/v3/merchants/merchant_123/apiCredentials")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [credential("S2-2")],
+                        "_links": {"self": href(2), "prev": href(1)}
+                    }),
+                ))
+                .times(1),
+        );
+
+        let api = mock_api(&mock);
+        let credentials: Vec<_> = api
+            .list_api_credentials_stream("merchant_123", PaginatedRequest::new(1))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = credentials.iter().map(|c| &*c.id).collect();
+        assert_eq!(ids, ["S2-1", "S2-2"]);
+        let url = &mock.received_requests()[1].url;
+        assert!(url.ends_with("/apiCredentials?pageNumber=2&pageSize=1"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_list_terminal_actions_stream_fetches_all_pages() {
+        let action = |id: &str| serde_json::json!({"id": id, "actionType": "InstallAndroidApp"});
+        let mock = MockTransport::new();
+        for data in [
+            vec![action("action_1"), action("action_2")],
+            vec![action("action_3")],
+        ] {
+            mock.expect(
+                Expectation::get("/v3/companies/company_123/terminalActions")
+                    .respond_with(MockResponse::json(
+                        200,
+                        serde_json::json!({"data": data, "itemsTotal": 3, "pagesTotal": 2}),
+                    ))
+                    .times(1),
+            );
+        }
+
+        let api = mock_api(&mock);
+        let actions: Vec<_> = api
+            .list_terminal_actions_stream("company_123", PaginatedRequest::new(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = actions.iter().map(|a| &*a.id).collect();
+        assert_eq!(ids, ["action_1", "action_2", "action_3"]);
+        assert_eq!(mock.received_requests().len(), 2);
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_stream_fails_on_next_link_that_does_not_advance() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v3/companies/company_123/merchants").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [merchant("merchant_1")],
+                        "_links": {
+                            "next": {"href": "https://management-test.adyen.com/v3/companies/company_123/merchants?pageNumber=1&pageSize=1"}
+                        }
+                    }),
+                ),
+            ),
+        );
+
+        let api = mock_api(&mock);
+        let stream = api.list_merchants_stream("company_123", PaginatedRequest::new(1));
+        futures::pin_mut!(stream);
+        assert_eq!(&*stream.try_next().await.unwrap().unwrap().id, "merchant_1");
+        assert!(stream.try_next().await.is_err());
+        assert_eq!(mock.received_requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_stores_returns_links() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v3/stores").respond_with(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "data": [{
+                        "id": "ST123",
+                        "merchantId": "merchant_123",
+                        "storeReference": "main",
+                        "description": "Main store"
+                    }],
+                    "itemsTotal": 3,
+                    "pagesTotal": 3,
+                    "_links": {
+                        "first": {"href": "https://management-test.adyen.com/v3/stores?pageNumber=1&pageSize=1"},
+                        "last": {"href": "https://management-test.adyen.com/v3/stores?pageNumber=3&pageSize=1"},
+                        "next": {"href": "https://management-test.adyen.com/v3/stores?merchantId=merchant_123&pageNumber=2&pageSize=1"},
+                        "self": {"href": "https://management-test.adyen.com/v3/stores?pageNumber=1&pageSize=1"}
+                    }
+                }),
+            )),
+        );
+
+        let api = mock_api(&mock);
+        let query = StoreListQuery::new().merchant_id("merchant_123");
+        let request = PaginatedRequest::new(1);
+        let page = api.search_stores(&query, request).await.unwrap();
+
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(&*page.data[0].id, "ST123");
+        assert_eq!(page.pages_total, Some(3));
+        assert!(page.has_next(&request));
+        assert!(page.links.as_ref().unwrap().prev.is_none());
+        assert_eq!(page.next_page(&request), Some(request.page(2)));
+
+        let url = &mock.received_requests()[0].url;
+        assert!(url.ends_with("/v3/stores?merchantId=merchant_123&pageNumber=1&pageSize=1"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_apple_pay_domains() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post(
+                "/v3/merchants/merchant_123/paymentMethodSettings/PM123/addApplePayDomains",
+            )
+            .body_includes(serde_json::json!({"domains": ["shop.example.com"]}))
+            .respond_with(MockResponse::raw(204, "")),
+        );
+        mock.expect(
+            Expectation::get(
+                "/v3/merchants/merchant_123/paymentMethodSettings/PM123/getApplePayDomains",
+            )
+            .respond_with(MockResponse::json(
+                200,
+                serde_json::json!({"domains": ["shop.example.com"]}),
+            )),
+        );
+
+        let api = mock_api(&mock);
+        api.add_apple_pay_domains(
+            "merchant_123",
+            "PM123",
+            &ApplePayInfo::new(["shop.example.com"]),
+        )
+        .await
+        .unwrap();
+        let domains = api
+            .get_apple_pay_domains("merchant_123", "PM123")
+            .await
+            .unwrap();
+        assert_eq!(&*domains.domains[0], "shop.example.com");
+        mock.verify();
+    }
+}
@@ -2,11 +2,12 @@
 
 use crate::types::{
     AdjustAuthorisationRequest, AuthenticationResultRequest, AuthenticationResultResponse,
-    CancelOrRefundRequest, CancelRequest, CaptureRequest, DonateRequest, ModificationResult,
-    PaymentRequest, PaymentRequest3d, PaymentRequest3ds2, PaymentResult, RefundRequest,
-    TechnicalCancelRequest, ThreeDSResultRequest, ThreeDSResultResponse, VoidPendingRefundRequest,
+    BalanceCheckRequest, BalanceCheckResult, CancelOrRefundRequest, CancelRequest, CaptureRequest,
+    DonateRequest, ModificationResult, PaymentRequest, PaymentRequest3d, PaymentRequest3ds2,
+    PaymentResult, RefundRequest, TechnicalCancelRequest, ThreeDSResultRequest,
+    ThreeDSResultResponse, VoidPendingRefundRequest,
 };
-use adyen_core::{Client, Config, Result};
+use adyen_core::{Api, Client, Config, Result};
 
 /// Adyen Classic Payments API client.
 ///
@@ -41,10 +42,21 @@ impl PaymentsApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::Payments)?;
         Ok(Self { client })
     }
 
+    /// Create a new Classic Payments API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Payments),
+        }
+    }
+
     /// Create an authorization for a payment.
     ///
     /// Creates a payment with a unique reference (pspReference) and attempts to obtain
@@ -78,7 +90,10 @@ impl PaymentsApi {
             "{}/pal/servlet/Payment/v68/authorise",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -90,12 +105,31 @@ impl PaymentsApi {
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_payments::{PaymentRequest3d, PaymentsApi};
+    ///
+    /// # async fn example(payments: PaymentsApi, md: String, pa_res: String) -> Result<(), Box<dyn std::error::Error>> {
+    /// // `MD` and `PaRes` are posted back to your return URL by the issuer.
+    /// let request = PaymentRequest3d::builder()
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .md(md)
+    ///     .pa_response(pa_res)
+    ///     .build();
+    ///
+    /// let result = payments.authorise_3d(&request).await?;
+    /// println!("Authorization result: {:?}", result.result_code);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn authorise_3d(&self, request: &PaymentRequest3d) -> Result<PaymentResult> {
         let url = format!(
             "{}/pal/servlet/Payment/v68/authorise3d",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -107,22 +141,61 @@ impl PaymentsApi {
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_payments::{PaymentRequest3ds2, PaymentsApi, ThreeDS2Result};
+    ///
+    /// # async fn example(payments: PaymentsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = PaymentRequest3ds2::builder()
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .three_ds2_result(ThreeDS2Result {
+    ///         trans_status: Some("Y".to_string()),
+    ///         ..ThreeDS2Result::default()
+    ///     })
+    ///     .build();
+    ///
+    /// let result = payments.authorise_3ds2(&request).await?;
+    /// println!("Authorization result: {:?}", result.result_code);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn authorise_3ds2(&self, request: &PaymentRequest3ds2) -> Result<PaymentResult> {
         let url = format!(
             "{}/pal/servlet/Payment/v68/authorise3ds2",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
     /// Get the authentication result for a 3D Secure transaction.
     ///
-    /// Retrieves the 3D Secure authentication result for a specific transaction.
+    /// Retrieves the 3D Secure authentication result for a specific transaction,
+    /// e.g. to keep the CAVV and ECI of an authenticated payment for audits.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::PspReference;
+    /// use adyen_payments::{AuthenticationResultRequest, PaymentsApi};
+    ///
+    /// # async fn example(payments: PaymentsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = AuthenticationResultRequest::new(
+    ///     "YourMerchantAccount",
+    ///     PspReference::new("8515131751004933")?,
+    /// );
+    ///
+    /// let result = payments.get_authentication_result(&request).await?;
+    /// println!("CAVV: {:?}, ECI: {:?}", result.cavv(), result.eci());
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get_authentication_result(
         &self,
         request: &AuthenticationResultRequest,
@@ -142,6 +215,24 @@ impl PaymentsApi {
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::PspReference;
+    /// use adyen_payments::{PaymentsApi, ThreeDSResultRequest};
+    ///
+    /// # async fn example(payments: PaymentsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = ThreeDSResultRequest::new(
+    ///     "YourMerchantAccount",
+    ///     PspReference::new("8515131751004933")?,
+    /// );
+    ///
+    /// let result = payments.retrieve_3ds2_result(&request).await?;
+    /// println!("3DS2 result: {:?}", result.three_ds2_result);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn retrieve_3ds2_result(
         &self,
         request: &ThreeDSResultRequest,
@@ -153,6 +244,44 @@ impl PaymentsApi {
         let response = self.client.post(&url, request).await?;
         Ok(response.data)
     }
+
+    /// Check the balance of a gift card.
+    ///
+    /// Classic integrations check gift card balances through the Stored
+    /// Value API's `/checkBalance`, which this calls with the Payments API
+    /// credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_payments::{BalanceCheckRequest, GiftCard, PaymentsApi};
+    ///
+    /// # async fn example(payments: PaymentsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = BalanceCheckRequest::new(
+    ///     "YourMerchantAccount",
+    ///     GiftCard::new("givex", "6036280000000000000").with_security_code("123"),
+    ///     "Balance-123",
+    /// );
+    ///
+    /// let result = payments.check_balance(&request).await?;
+    /// if result.is_success() {
+    ///     println!("Balance: {:?}", result.current_balance);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_balance(&self, request: &BalanceCheckRequest) -> Result<BalanceCheckResult> {
+        let url = format!(
+            "{}/pal/servlet/StoredValue/v46/checkBalance",
+            self.client.config().environment().classic_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
 }
 
 /// Adyen Payment Modifications API client.
@@ -171,10 +300,21 @@ impl ModificationsApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::Payments)?;
         Ok(Self { client })
     }
 
+    /// Create a new Payment Modifications API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Payments),
+        }
+    }
+
     /// Capture an authorized payment.
     ///
     /// Captures the authorized amount (or a lower amount) for a payment.
@@ -207,7 +347,10 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/capture",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_payment(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -224,7 +367,7 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/cancel",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -241,7 +384,7 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/refund",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -261,7 +404,7 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/cancelOrRefund",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -281,7 +424,7 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/adjustAuthorisation",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
@@ -292,19 +435,42 @@ impl ModificationsApi {
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{Amount, Currency};
+    /// use adyen_payments::{DonateRequest, ModificationsApi};
+    ///
+    /// # async fn example(modifications: ModificationsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = DonateRequest::builder()
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .modification_amount(Amount::from_minor_units(500, Currency::EUR))
+    ///     .original_reference("8515131751004933")
+    ///     .donation_account("CharityAccount")
+    ///     .reference("Donation-123")
+    ///     .build()
+    ///     .map_err(String::from)?;
+    ///
+    /// let result = modifications.donate(&request).await?;
+    /// println!("Donation: {}", result.psp_reference);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn donate(&self, request: &DonateRequest) -> Result<ModificationResult> {
         let url = format!(
             "{}/pal/servlet/Payment/v68/donate",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
     /// Perform a technical cancel.
     ///
-    /// Cancels a payment for technical reasons. This is typically used
-    /// when there are technical issues that prevent normal processing.
+    /// Cancels a payment by its merchant reference, for when the PSP reference
+    /// is unknown because the authorisation response was not received, e.g.
+    /// after a POS terminal timeout.
     ///
     /// # Errors
     ///
@@ -317,14 +483,33 @@ impl ModificationsApi {
             "{}/pal/servlet/Payment/v68/technicalCancel",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.client.post_payment(&url, request, None).await?;
         Ok(response.data)
     }
 
     /// Void a pending refund.
     ///
     /// Cancels a refund that is still pending. This can be used to stop
-    /// a refund before it has been processed.
+    /// a refund before it has been processed. POS refunds without a PSP
+    /// reference are identified by terminal and tender reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_payments::{ModificationsApi, VoidPendingRefundRequest};
+    ///
+    /// # async fn example(modifications: ModificationsApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = VoidPendingRefundRequest::builder()
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .terminal_transaction("V400m-324688179", "SGOO001643019870001")
+    ///     .build()
+    ///     .map_err(String::from)?;
+    ///
+    /// let result = modifications.void_pending_refund(&request).await?;
+    /// println!("Void requested: {}", result.psp_reference);
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Errors
     ///
@@ -342,6 +527,32 @@ impl ModificationsApi {
     }
 }
 
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl PaymentsApi {
+        fn authorise_blocking => authorise(request: &PaymentRequest) -> Result<PaymentResult>;
+        fn authorise_3d_blocking => authorise_3d(request: &PaymentRequest3d) -> Result<PaymentResult>;
+        fn authorise_3ds2_blocking => authorise_3ds2(request: &PaymentRequest3ds2) -> Result<PaymentResult>;
+        fn get_authentication_result_blocking => get_authentication_result(request: &AuthenticationResultRequest) -> Result<AuthenticationResultResponse>;
+        fn retrieve_3ds2_result_blocking => retrieve_3ds2_result(request: &ThreeDSResultRequest) -> Result<ThreeDSResultResponse>;
+        fn check_balance_blocking => check_balance(request: &BalanceCheckRequest) -> Result<BalanceCheckResult>;
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl ModificationsApi {
+        fn capture_blocking => capture(request: &CaptureRequest) -> Result<ModificationResult>;
+        fn cancel_blocking => cancel(request: &CancelRequest) -> Result<ModificationResult>;
+        fn refund_blocking => refund(request: &RefundRequest) -> Result<ModificationResult>;
+        fn cancel_or_refund_blocking => cancel_or_refund(request: &CancelOrRefundRequest) -> Result<ModificationResult>;
+        fn adjust_authorisation_blocking => adjust_authorisation(request: &AdjustAuthorisationRequest) -> Result<ModificationResult>;
+        fn donate_blocking => donate(request: &DonateRequest) -> Result<ModificationResult>;
+        fn technical_cancel_blocking => technical_cancel(request: &TechnicalCancelRequest) -> Result<ModificationResult>;
+        fn void_pending_refund_blocking => void_pending_refund(request: &VoidPendingRefundRequest) -> Result<ModificationResult>;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
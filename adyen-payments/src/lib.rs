@@ -16,6 +16,8 @@
 //! - **Payment Modifications**: Capture, cancel, refund, and adjust authorizations
 //! - **Fraud Detection**: Comprehensive fraud scoring and risk management
 //! - **Recurring Payments**: Support for stored payment methods and subscriptions
+//! - **Open Invoice**: Send line items for Klarna, Afterpay and Ratepay
+//! - **Split Payments**: Split payments, captures and refunds between accounts
 //!
 //! ## Example
 //!
@@ -54,6 +56,9 @@ pub mod types;
 // Re-export main API and commonly used types
 pub use api::{ModificationsApi, PaymentsApi};
 pub use types::{
-    CancelOrRefundRequest, CancelRequest, CaptureRequest, Card, ModificationResult, PaymentRequest,
-    PaymentRequest3d, PaymentRequest3ds2, PaymentResult, PaymentResultCode, RefundRequest,
+    AuthenticationResultRequest, AuthenticationResultResponse, CancelOrRefundRequest,
+    CancelRequest, CaptureRequest, Card, ModificationResult, PaymentRequest, PaymentRequest3d,
+    PaymentRequest3ds2, PaymentResult, PaymentResultCode, RefundRequest, TechnicalCancelRequest,
+    ThreeDS2Result, ThreeDSResultRequest, ThreeDSResultResponse, VoidPendingRefundRequest,
 };
+pub use types::{BalanceCheckRequest, BalanceCheckResult, DonateRequest, GiftCard};
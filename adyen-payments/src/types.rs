@@ -2,24 +2,32 @@
 
 pub mod additional;
 pub mod fraud;
+pub mod line_items;
 pub mod modifications;
 pub mod payment;
+pub mod splits;
 pub mod three_d_secure;
 
 // Re-export main types
 pub use additional::{
-    AdjustAuthorisationRequest, DonateRequest, TechnicalCancelRequest, ThreeDSResultRequest,
-    ThreeDSResultResponse, VoidPendingRefundRequest,
+    AdjustAuthorisationRequest, BalanceCheckRequest, BalanceCheckResult, DonateRequest,
+    DonateRequestBuilder, GiftCard, TechnicalCancelRequest, TechnicalCancelRequestBuilder,
+    ThreeDSResultRequest, ThreeDSResultResponse, VoidPendingRefundRequest,
+    VoidPendingRefundRequestBuilder,
 };
-pub use fraud::{FraudCheckResult, FraudCheckResultWrapper};
+pub use fraud::{
+    FraudCheckName, FraudCheckResult, FraudCheckResultWrapper, RiskData, RiskDataBuilder,
+};
+pub use line_items::{open_invoice_data, LineItem};
 pub use modifications::{
     CancelOrRefundRequest, CancelRequest, CaptureRequest, ModificationResponse, ModificationResult,
     RefundRequest,
 };
 pub use payment::{
-    ApplicationInfo, BrowserInfo, Card, FraudResult, PaymentMethod, PaymentRequest, PaymentResult,
-    PaymentResultCode, RecurringType,
+    ApplicationInfo, BrowserInfo, Card, FraudResult, InstallmentPlan, Installments, PaymentMethod,
+    PaymentRequest, PaymentResult, PaymentResultCode, RecurringType,
 };
+pub use splits::{Split, SplitAmount, SplitType};
 pub use three_d_secure::{
     AuthenticationResultRequest, AuthenticationResultResponse, PaymentRequest3d,
     PaymentRequest3ds2, ThreeDS2RequestData, ThreeDS2Result, ThreeDSecureData,
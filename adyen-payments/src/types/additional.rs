@@ -1,11 +1,17 @@
 //! Additional types for extended Classic Payments API functionality.
 
-use adyen_core::Amount;
+use adyen_core::builder::Missing;
+use adyen_core::{Amount, PspReference, SecretString};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Request to adjust an authorization amount.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AdjustAuthorisationRequest {
     /// The merchant account identifier.
@@ -13,20 +19,29 @@ pub struct AdjustAuthorisationRequest {
     /// The amount to adjust the authorization to.
     pub modification_amount: Amount,
     /// The original PSP reference of the payment to modify.
-    pub original_reference: String,
+    pub original_reference: PspReference,
     /// Additional data for the adjustment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
     /// 3D Secure data for the adjustment.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub mpi_data: Option<serde_json::Value>,
     /// The original merchant reference.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub original_merchant_reference: Option<String>,
 }
 
-/// Request to make a donation.
+/// Request to make a donation (`/donate`).
+///
+/// Donates an amount to a charity, charged to the payment method of an
+/// earlier payment of the shopper.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct DonateRequest {
     /// The merchant account identifier.
@@ -34,63 +49,693 @@ pub struct DonateRequest {
     /// The donation amount.
     pub modification_amount: Amount,
     /// The original PSP reference of the payment.
-    pub original_reference: String,
-    /// The donation account.
+    pub original_reference: PspReference,
+    /// The Adyen account of the charity receiving the donation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub donation_account: Option<String>,
+    /// Your reference for the donation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
     /// Additional data for the donation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
 }
 
+impl DonateRequest {
+    /// Create a new donate request builder.
+    #[must_use]
+    pub fn builder() -> DonateRequestBuilder {
+        DonateRequestBuilder::default()
+    }
+}
+
+/// Builder for donate requests.
+///
+/// `build` is only available once the required merchant account, modification
+/// amount, original reference and donation account are set.
+#[derive(Debug, Default)]
+pub struct DonateRequestBuilder<M = Missing, A = Missing, R = Missing, D = Missing> {
+    merchant_account: M,
+    modification_amount: A,
+    original_reference: R,
+    donation_account: D,
+    reference: Option<String>,
+    additional_data: Option<HashMap<String, String>>,
+}
+
+impl<M, A, R, D> DonateRequestBuilder<M, A, R, D> {
+    /// Set the merchant account.
+    #[must_use]
+    pub fn merchant_account<S: Into<String>>(
+        self,
+        account: S,
+    ) -> DonateRequestBuilder<String, A, R, D> {
+        DonateRequestBuilder {
+            merchant_account: account.into(),
+            modification_amount: self.modification_amount,
+            original_reference: self.original_reference,
+            donation_account: self.donation_account,
+            reference: self.reference,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the donation amount.
+    #[must_use]
+    pub fn modification_amount(self, amount: Amount) -> DonateRequestBuilder<M, Amount, R, D> {
+        DonateRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: amount,
+            original_reference: self.original_reference,
+            donation_account: self.donation_account,
+            reference: self.reference,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the PSP reference of the shopper's payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    #[must_use]
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> DonateRequestBuilder<M, A, String, D> {
+        DonateRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: reference.as_ref().into(),
+            donation_account: self.donation_account,
+            reference: self.reference,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the Adyen account of the charity.
+    #[must_use]
+    pub fn donation_account<S: Into<String>>(
+        self,
+        account: S,
+    ) -> DonateRequestBuilder<M, A, R, String> {
+        DonateRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: self.original_reference,
+            donation_account: account.into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the donation reference.
+    #[must_use]
+    pub fn reference<S: Into<String>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Add additional data.
+    #[must_use]
+    pub fn additional_data(mut self, data: HashMap<String, String>) -> Self {
+        self.additional_data = Some(data);
+        self
+    }
+}
+
+impl DonateRequestBuilder<String, Amount, String, String> {
+    /// Build the donate request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the original reference is not a valid PSP reference.
+    pub fn build(self) -> Result<DonateRequest, Box<str>> {
+        Ok(DonateRequest {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
+            donation_account: Some(self.donation_account),
+            reference: self.reference,
+            additional_data: self.additional_data,
+        })
+    }
+}
+
+/// Gift card to check the balance of.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GiftCard {
+    /// The gift card brand, e.g. `givex` or `svs`.
+    #[serde(rename = "type")]
+    pub brand: String,
+    /// The gift card number.
+    pub number: SecretString,
+    /// The gift card security code or PIN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_code: Option<SecretString>,
+}
+
+impl GiftCard {
+    /// Create a gift card of the given brand.
+    #[must_use]
+    pub fn new(brand: impl Into<String>, number: impl Into<SecretString>) -> Self {
+        Self {
+            brand: brand.into(),
+            number: number.into(),
+            security_code: None,
+        }
+    }
+
+    /// Set the security code or PIN.
+    #[must_use]
+    pub fn with_security_code(mut self, security_code: impl Into<SecretString>) -> Self {
+        self.security_code = Some(security_code.into());
+        self
+    }
+}
+
+/// Request to check the balance of a gift card (`/checkBalance`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceCheckRequest {
+    /// The merchant account identifier.
+    pub merchant_account: String,
+    /// The gift card to check.
+    pub payment_method: GiftCard,
+    /// Your reference for the balance check.
+    pub reference: String,
+    /// The store the balance check is made for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<String>,
+}
+
+impl BalanceCheckRequest {
+    /// Create a balance check for the given gift card.
+    #[must_use]
+    pub fn new(
+        merchant_account: impl Into<String>,
+        payment_method: GiftCard,
+        reference: impl Into<String>,
+    ) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            payment_method,
+            reference: reference.into(),
+            store: None,
+        }
+    }
+
+    /// Set the store.
+    #[must_use]
+    pub fn with_store(mut self, store: impl Into<String>) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+}
+
+/// Result of a gift card balance check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceCheckResult {
+    /// The PSP reference of the balance check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub psp_reference: Option<PspReference>,
+    /// The result, e.g. `Success`, `Refused` or `NotEnoughBalance`.
+    pub result_code: String,
+    /// The balance of the gift card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_balance: Option<Amount>,
+    /// The reason the balance check was refused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason: Option<String>,
+}
+
+impl BalanceCheckResult {
+    /// Check if the balance was retrieved.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.result_code == "Success"
+    }
+}
+
 // AuthenticationResultRequest and AuthenticationResultResponse are already defined in three_d_secure.rs
 
 /// Request to retrieve 3DS2 result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDSResultRequest {
     /// The merchant account identifier.
     pub merchant_account: String,
     /// The PSP reference of the authentication.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+}
+
+impl ThreeDSResultRequest {
+    /// Create a request for the 3DS2 result of a payment.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>, psp_reference: PspReference) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            psp_reference,
+        }
+    }
 }
 
 /// Response from 3DS2 result request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDSResultResponse {
     /// The PSP reference.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
     /// The 3DS2 result.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "threeDS2Result", skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub three_ds2_result: Option<serde_json::Value>,
     /// The authentication value.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authentication_value: Option<String>,
 }
 
-/// Request to perform technical cancel.
+/// Request to perform a technical cancel (`/technicalCancel`).
+///
+/// Cancels a payment by your merchant reference, for when the authorisation
+/// response was not received, e.g. after a timeout at a POS terminal, and
+/// the PSP reference is therefore unknown.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TechnicalCancelRequest {
     /// The merchant account identifier.
     pub merchant_account: String,
-    /// The original PSP reference of the payment to cancel.
-    pub original_reference: String,
+    /// The merchant reference of the payment to cancel.
+    pub original_merchant_reference: String,
+    /// Your reference for this cancel request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The amount of the payment to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modification_amount: Option<Amount>,
+    /// The transaction reference of the POS terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tender_reference: Option<String>,
+    /// The unique ID of the POS terminal, e.g. `V400m-324688179`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_terminal_id: Option<String>,
     /// Additional data for the cancellation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
 }
 
-/// Request to void a pending refund.
+impl TechnicalCancelRequest {
+    /// Create a new technical cancel request builder.
+    #[must_use]
+    pub fn builder() -> TechnicalCancelRequestBuilder {
+        TechnicalCancelRequestBuilder::default()
+    }
+}
+
+/// Builder for technical cancel requests.
+///
+/// `build` is only available once the required merchant account and original
+/// merchant reference are set.
+#[derive(Debug, Default)]
+pub struct TechnicalCancelRequestBuilder<M = Missing, R = Missing> {
+    merchant_account: M,
+    original_merchant_reference: R,
+    reference: Option<String>,
+    modification_amount: Option<Amount>,
+    tender_reference: Option<String>,
+    unique_terminal_id: Option<String>,
+    additional_data: Option<HashMap<String, String>>,
+}
+
+impl<M, R> TechnicalCancelRequestBuilder<M, R> {
+    /// Set the merchant account.
+    #[must_use]
+    pub fn merchant_account<S: Into<String>>(
+        self,
+        account: S,
+    ) -> TechnicalCancelRequestBuilder<String, R> {
+        TechnicalCancelRequestBuilder {
+            merchant_account: account.into(),
+            original_merchant_reference: self.original_merchant_reference,
+            reference: self.reference,
+            modification_amount: self.modification_amount,
+            tender_reference: self.tender_reference,
+            unique_terminal_id: self.unique_terminal_id,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the merchant reference of the payment to cancel.
+    #[must_use]
+    pub fn original_merchant_reference<S: Into<String>>(
+        self,
+        reference: S,
+    ) -> TechnicalCancelRequestBuilder<M, String> {
+        TechnicalCancelRequestBuilder {
+            merchant_account: self.merchant_account,
+            original_merchant_reference: reference.into(),
+            reference: self.reference,
+            modification_amount: self.modification_amount,
+            tender_reference: self.tender_reference,
+            unique_terminal_id: self.unique_terminal_id,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the cancel reference.
+    #[must_use]
+    pub fn reference<S: Into<String>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the amount of the payment to cancel.
+    #[must_use]
+    pub fn modification_amount(mut self, amount: Amount) -> Self {
+        self.modification_amount = Some(amount);
+        self
+    }
+
+    /// Identify the payment by the POS terminal and its transaction
+    /// reference.
+    #[must_use]
+    pub fn terminal_transaction<S: Into<String>, T: Into<String>>(
+        mut self,
+        unique_terminal_id: S,
+        tender_reference: T,
+    ) -> Self {
+        self.unique_terminal_id = Some(unique_terminal_id.into());
+        self.tender_reference = Some(tender_reference.into());
+        self
+    }
+
+    /// Add additional data.
+    #[must_use]
+    pub fn additional_data(mut self, data: HashMap<String, String>) -> Self {
+        self.additional_data = Some(data);
+        self
+    }
+}
+
+impl TechnicalCancelRequestBuilder<String, String> {
+    /// Build the technical cancel request.
+    #[must_use]
+    pub fn build(self) -> TechnicalCancelRequest {
+        TechnicalCancelRequest {
+            merchant_account: self.merchant_account,
+            original_merchant_reference: self.original_merchant_reference,
+            reference: self.reference,
+            modification_amount: self.modification_amount,
+            tender_reference: self.tender_reference,
+            unique_terminal_id: self.unique_terminal_id,
+            additional_data: self.additional_data,
+        }
+    }
+}
+
+/// Request to void a pending refund (`/voidPendingRefund`).
+///
+/// Identify the refund by the PSP reference of the payment, or for refunds
+/// made at a POS terminal by the terminal and its transaction reference.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct VoidPendingRefundRequest {
     /// The merchant account identifier.
     pub merchant_account: String,
-    /// The original PSP reference of the refund to void.
-    pub original_reference: String,
+    /// The PSP reference of the payment whose refund to void.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_reference: Option<PspReference>,
+    /// The merchant reference of the payment whose refund to void.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_merchant_reference: Option<String>,
+    /// Your reference for this void request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// The amount of the refund to void.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modification_amount: Option<Amount>,
+    /// The transaction reference of the POS terminal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tender_reference: Option<String>,
+    /// The unique ID of the POS terminal, e.g. `V400m-324688179`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_terminal_id: Option<String>,
     /// Additional data for the void operation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_data: Option<HashMap<String, String>>,
 }
+
+impl VoidPendingRefundRequest {
+    /// Create a new void pending refund request builder.
+    #[must_use]
+    pub fn builder() -> VoidPendingRefundRequestBuilder {
+        VoidPendingRefundRequestBuilder::default()
+    }
+}
+
+/// Builder for void pending refund requests.
+///
+/// `build` is only available once the required merchant account is set.
+#[derive(Debug, Default)]
+pub struct VoidPendingRefundRequestBuilder<M = Missing> {
+    merchant_account: M,
+    original_reference: Option<String>,
+    original_merchant_reference: Option<String>,
+    reference: Option<String>,
+    modification_amount: Option<Amount>,
+    tender_reference: Option<String>,
+    unique_terminal_id: Option<String>,
+    additional_data: Option<HashMap<String, String>>,
+}
+
+impl<M> VoidPendingRefundRequestBuilder<M> {
+    /// Set the merchant account.
+    #[must_use]
+    pub fn merchant_account<S: Into<String>>(
+        self,
+        account: S,
+    ) -> VoidPendingRefundRequestBuilder<String> {
+        VoidPendingRefundRequestBuilder {
+            merchant_account: account.into(),
+            original_reference: self.original_reference,
+            original_merchant_reference: self.original_merchant_reference,
+            reference: self.reference,
+            modification_amount: self.modification_amount,
+            tender_reference: self.tender_reference,
+            unique_terminal_id: self.unique_terminal_id,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the PSP reference of the payment whose refund to void.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    #[must_use]
+    pub fn original_reference<S: AsRef<str>>(mut self, reference: S) -> Self {
+        self.original_reference = Some(reference.as_ref().into());
+        self
+    }
+
+    /// Set the merchant reference of the payment whose refund to void.
+    #[must_use]
+    pub fn original_merchant_reference<S: Into<String>>(mut self, reference: S) -> Self {
+        self.original_merchant_reference = Some(reference.into());
+        self
+    }
+
+    /// Set the void reference.
+    #[must_use]
+    pub fn reference<S: Into<String>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the amount of the refund to void.
+    #[must_use]
+    pub fn modification_amount(mut self, amount: Amount) -> Self {
+        self.modification_amount = Some(amount);
+        self
+    }
+
+    /// Identify the refund by the POS terminal and its transaction
+    /// reference.
+    #[must_use]
+    pub fn terminal_transaction<S: Into<String>, T: Into<String>>(
+        mut self,
+        unique_terminal_id: S,
+        tender_reference: T,
+    ) -> Self {
+        self.unique_terminal_id = Some(unique_terminal_id.into());
+        self.tender_reference = Some(tender_reference.into());
+        self
+    }
+
+    /// Add additional data.
+    #[must_use]
+    pub fn additional_data(mut self, data: HashMap<String, String>) -> Self {
+        self.additional_data = Some(data);
+        self
+    }
+}
+
+impl VoidPendingRefundRequestBuilder<String> {
+    /// Build the void pending refund request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither an original reference nor a terminal
+    /// transaction is set, or if the original reference is not a valid PSP
+    /// reference.
+    pub fn build(self) -> Result<VoidPendingRefundRequest, Box<str>> {
+        if self.original_reference.is_none() && self.tender_reference.is_none() {
+            return Err("original_reference or terminal_transaction is required".into());
+        }
+
+        Ok(VoidPendingRefundRequest {
+            merchant_account: self.merchant_account,
+            original_reference: self
+                .original_reference
+                .map(|reference| {
+                    PspReference::new(&reference)
+                        .map_err(|_| "original_reference is not a valid PSP reference")
+                })
+                .transpose()?,
+            original_merchant_reference: self.original_merchant_reference,
+            reference: self.reference,
+            modification_amount: self.modification_amount,
+            tender_reference: self.tender_reference,
+            unique_terminal_id: self.unique_terminal_id,
+            additional_data: self.additional_data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adyen_core::Currency;
+
+    #[test]
+    fn test_donate_request() {
+        let request = DonateRequest::builder()
+            .merchant_account("TestMerchant")
+            .modification_amount(Amount::from_minor_units(500, Currency::EUR))
+            .original_reference("8815131768219992")
+            .donation_account("CharityAccount")
+            .reference("Donation-123")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["originalReference"], "8815131768219992");
+        assert_eq!(json["donationAccount"], "CharityAccount");
+        assert_eq!(json["reference"], "Donation-123");
+    }
+
+    #[test]
+    fn test_balance_check() {
+        let request = BalanceCheckRequest::new(
+            "TestMerchant",
+            GiftCard::new("givex", "6036280000000000000").with_security_code("123"),
+            "Balance-123",
+        )
+        .with_store("Store-1");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["paymentMethod"]["type"], "givex");
+        assert_eq!(json["paymentMethod"]["number"], "6036280000000000000");
+        assert_eq!(json["paymentMethod"]["securityCode"], "123");
+        assert_eq!(json["store"], "Store-1");
+        assert!(!format!("{request:?}").contains("6036280000000000000"));
+
+        let result: BalanceCheckResult = serde_json::from_str(
+            r#"{"pspReference":"8815131768219992","resultCode":"Success","currentBalance":{"minor_units":5000,"currency":"EUR"}}"#,
+        )
+        .unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.current_balance.unwrap().minor_units(), 5000);
+    }
+
+    #[test]
+    fn test_technical_cancel_request() {
+        let request = TechnicalCancelRequest::builder()
+            .merchant_account("TestMerchant")
+            .original_merchant_reference("POS-Order-123")
+            .terminal_transaction("V400m-324688179", "SGOO001643019870001")
+            .modification_amount(Amount::from_minor_units(1000, Currency::EUR))
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["originalMerchantReference"], "POS-Order-123");
+        assert_eq!(json["uniqueTerminalId"], "V400m-324688179");
+        assert_eq!(json["tenderReference"], "SGOO001643019870001");
+        assert!(json.get("originalReference").is_none());
+    }
+
+    #[test]
+    fn test_void_pending_refund_request() {
+        let request = VoidPendingRefundRequest::builder()
+            .merchant_account("TestMerchant")
+            .terminal_transaction("V400m-324688179", "SGOO001643019870001")
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["tenderReference"], "SGOO001643019870001");
+        assert!(json.get("originalReference").is_none());
+
+        let request = VoidPendingRefundRequest::builder()
+            .merchant_account("TestMerchant")
+            .original_reference("8815131768219992")
+            .build()
+            .unwrap();
+        assert_eq!(request.original_reference.unwrap(), "8815131768219992");
+
+        assert!(VoidPendingRefundRequest::builder()
+            .merchant_account("TestMerchant")
+            .build()
+            .is_err());
+        assert!(VoidPendingRefundRequest::builder()
+            .merchant_account("TestMerchant")
+            .original_reference("not-a-reference")
+            .build()
+            .is_err());
+    }
+}
@@ -2,12 +2,19 @@
 
 #![allow(clippy::type_complexity)]
 #![allow(clippy::return_self_not_must_use)]
+// rkyv's derive output for tuple variants binds their fields as `_0`.
+#![cfg_attr(feature = "rkyv", allow(clippy::used_underscore_binding))]
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Risk score threshold levels for fraud detection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum RiskLevel {
     /// Low risk transaction
@@ -18,10 +25,18 @@ pub enum RiskLevel {
     High,
     /// Critical risk transaction
     Critical,
+    /// A risk level not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Fraud check recommendation actions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum FraudAction {
     /// Allow the transaction to proceed
@@ -32,14 +47,22 @@ pub enum FraudAction {
     Challenge,
     /// Require additional authentication
     Review,
+    /// A fraud action not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Comprehensive fraud detection result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct FraudResult {
-    /// Account score indicating fraud likelihood (0-100, higher = more risky)
-    pub account_score: Option<u8>,
+    /// Account score indicating fraud likelihood (higher = more risky)
+    pub account_score: Option<i32>,
 
     /// Check ID for tracking fraud assessments
     pub check_id: Option<i32>,
@@ -63,31 +86,267 @@ pub struct FraudResult {
     pub reason_codes: Option<Vec<Box<str>>>,
 }
 
-/// Individual fraud check result from a specific provider.
+impl FraudResult {
+    /// Get the account score of each check, summed per check name.
+    #[must_use]
+    pub fn scores_by_check(&self) -> HashMap<FraudCheckName, i32> {
+        scores_by_check(self.results.as_deref().unwrap_or_default())
+    }
+}
+
+/// Sum the account scores of fraud check results per check name.
+pub(crate) fn scores_by_check(results: &[FraudCheckResult]) -> HashMap<FraudCheckName, i32> {
+    let mut scores = HashMap::new();
+    for result in results {
+        *scores.entry(result.name.clone()).or_insert(0) += result.account_score;
+    }
+    scores
+}
+
+/// Name of an Adyen risk check.
+///
+/// Checks not known to this library deserialize into
+/// [`FraudCheckName::Unknown`], which keeps the original name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub enum FraudCheckName {
+    /// The card number is on a referral or block list.
+    PaymentDetailRefCheck,
+    /// The card number is on a trust list.
+    PaymentDetailNonFraudRefCheck,
+    /// The card number was used too often in a period.
+    PaymentDetailUsage,
+    /// Card numbers close to this one were used too often in a period.
+    CardChunkUsage,
+    /// The cardholder name was used too often in a period.
+    HolderNameUsage,
+    /// The cardholder name contains a number.
+    HolderNameContainsNumber,
+    /// The cardholder name is a single character.
+    HolderNameIsOneCharacter,
+    /// The shopper email was used too often in a period.
+    ShopperEmailUsage,
+    /// The shopper email is on a referral or block list.
+    ShopperEmailRefCheck,
+    /// The shopper IP address was used too often in a period.
+    ShopperIpUsage,
+    /// The shopper reference is on a referral or block list.
+    ShopperReferenceCheck,
+    /// The payment method owner is on a referral or block list.
+    PmOwnerRefCheck,
+    /// The issuing bank is on a referral or block list.
+    IssuerRefCheck,
+    /// The issuing country is on a referral list.
+    IssuingCountryReferral,
+    /// The result of the address verification (AVS).
+    AvsAuthResultCheck,
+    /// The result of the card security code check.
+    CvcAuthResultCheck,
+    /// The payment was authenticated with 3D Secure.
+    ThreeDAuthenticated,
+    /// The result of the 3D Secure authentication.
+    ThreeDAuthenticatedResponse,
+    /// A custom risk rule on a `riskdata` field.
+    CustomFieldCheck,
+    /// A check not known to this library.
+    Unknown(Box<str>),
+}
+
+impl FraudCheckName {
+    /// Get the check name as sent by Adyen.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PaymentDetailRefCheck => "PaymentDetailRefCheck",
+            Self::PaymentDetailNonFraudRefCheck => "PaymentDetailNonFraudRefCheck",
+            Self::PaymentDetailUsage => "PaymentDetailUsage",
+            Self::CardChunkUsage => "CardChunkUsage",
+            Self::HolderNameUsage => "HolderNameUsage",
+            Self::HolderNameContainsNumber => "HolderNameContainsNumber",
+            Self::HolderNameIsOneCharacter => "HolderNameIsOneCharacter",
+            Self::ShopperEmailUsage => "ShopperEmailUsage",
+            Self::ShopperEmailRefCheck => "ShopperEmailRefCheck",
+            Self::ShopperIpUsage => "ShopperIpUsage",
+            Self::ShopperReferenceCheck => "ShopperReferenceCheck",
+            Self::PmOwnerRefCheck => "PmOwnerRefCheck",
+            Self::IssuerRefCheck => "IssuerRefCheck",
+            Self::IssuingCountryReferral => "IssuingCountryReferral",
+            Self::AvsAuthResultCheck => "AVSAuthResultCheck",
+            Self::CvcAuthResultCheck => "CVCAuthResultCheck",
+            Self::ThreeDAuthenticated => "3DAuthenticated",
+            Self::ThreeDAuthenticatedResponse => "3DAuthenticatedResponse",
+            Self::CustomFieldCheck => "CustomFieldCheck",
+            Self::Unknown(name) => name,
+        }
+    }
+}
+
+impl From<&str> for FraudCheckName {
+    fn from(name: &str) -> Self {
+        match name {
+            "PaymentDetailRefCheck" => Self::PaymentDetailRefCheck,
+            "PaymentDetailNonFraudRefCheck" => Self::PaymentDetailNonFraudRefCheck,
+            "PaymentDetailUsage" => Self::PaymentDetailUsage,
+            "CardChunkUsage" => Self::CardChunkUsage,
+            "HolderNameUsage" => Self::HolderNameUsage,
+            "HolderNameContainsNumber" => Self::HolderNameContainsNumber,
+            "HolderNameIsOneCharacter" => Self::HolderNameIsOneCharacter,
+            "ShopperEmailUsage" => Self::ShopperEmailUsage,
+            "ShopperEmailRefCheck" => Self::ShopperEmailRefCheck,
+            "ShopperIpUsage" => Self::ShopperIpUsage,
+            "ShopperReferenceCheck" => Self::ShopperReferenceCheck,
+            "PmOwnerRefCheck" => Self::PmOwnerRefCheck,
+            "IssuerRefCheck" => Self::IssuerRefCheck,
+            "IssuingCountryReferral" => Self::IssuingCountryReferral,
+            "AVSAuthResultCheck" => Self::AvsAuthResultCheck,
+            "CVCAuthResultCheck" => Self::CvcAuthResultCheck,
+            "3DAuthenticated" => Self::ThreeDAuthenticated,
+            "3DAuthenticatedResponse" => Self::ThreeDAuthenticatedResponse,
+            "CustomFieldCheck" => Self::CustomFieldCheck,
+            other => Self::Unknown(other.into()),
+        }
+    }
+}
+
+impl AsRef<str> for FraudCheckName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FraudCheckName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for FraudCheckName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FraudCheckName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Self::from(name.as_ref()))
+    }
+}
+
+/// Result of an individual risk check.
+///
+/// Like in API responses, the serialized form is wrapped in a
+/// `FraudCheckResult` object; unwrapped entries are accepted as well.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(from = "FraudCheckResultEntry", into = "FraudCheckResultEntry")]
 pub struct FraudCheckResult {
-    /// Name of the fraud check provider
-    pub name: Box<str>,
+    /// Name of the risk check
+    pub name: FraudCheckName,
 
     /// Check ID for this specific fraud check
     pub check_id: i32,
 
-    /// Account score from this provider (0-100)
-    pub account_score: u8,
+    /// Score this check added to the account score; negative for trust
+    /// checks
+    pub account_score: i32,
 
-    /// Risk level from this provider
-    pub risk_level: RiskLevel,
+    /// Risk level from this check, if reported
+    pub risk_level: Option<RiskLevel>,
 
-    /// Recommended action from this provider
-    pub action: FraudAction,
+    /// Recommended action from this check, if reported
+    pub action: Option<FraudAction>,
 
     /// Additional metadata from the fraud check
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
     pub metadata: Option<HashMap<Box<str>, serde_json::Value>>,
 }
 
+/// Fields of a [`FraudCheckResult`] as sent by Adyen.
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+struct FraudCheckResultData {
+    name: FraudCheckName,
+    check_id: i32,
+    account_score: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    risk_level: Option<RiskLevel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    action: Option<FraudAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
+    metadata: Option<HashMap<Box<str>, serde_json::Value>>,
+}
+
+/// Entry of the `results` list of a fraud result, which Adyen wraps in a
+/// `FraudCheckResult` object.
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(untagged)]
+enum FraudCheckResultEntry {
+    Wrapped {
+        #[serde(rename = "FraudCheckResult")]
+        result: FraudCheckResultData,
+    },
+    Plain(FraudCheckResultData),
+}
+
+impl From<FraudCheckResultEntry> for FraudCheckResult {
+    fn from(entry: FraudCheckResultEntry) -> Self {
+        match entry {
+            FraudCheckResultEntry::Wrapped { result } | FraudCheckResultEntry::Plain(result) => {
+                Self {
+                    name: result.name,
+                    check_id: result.check_id,
+                    account_score: result.account_score,
+                    risk_level: result.risk_level,
+                    action: result.action,
+                    metadata: result.metadata,
+                }
+            }
+        }
+    }
+}
+
+impl From<FraudCheckResult> for FraudCheckResultEntry {
+    fn from(result: FraudCheckResult) -> Self {
+        Self::Wrapped {
+            result: FraudCheckResultData {
+                name: result.name,
+                check_id: result.check_id,
+                account_score: result.account_score,
+                risk_level: result.risk_level,
+                action: result.action,
+                metadata: result.metadata,
+            },
+        }
+    }
+}
+
 /// Wrapper for fraud check results in payment responses.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct FraudCheckResultWrapper {
     /// Fraud check results
@@ -99,6 +358,11 @@ pub struct FraudCheckResultWrapper {
 
 /// Device fingerprinting data for fraud detection.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceFingerprint {
     /// Unique device identifier
@@ -240,6 +504,11 @@ impl DeviceFingerprintBuilder {
 
 /// Risk assessment data for merchants.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskData {
     /// Client data for fraud detection
@@ -264,6 +533,30 @@ impl RiskData {
     pub fn builder() -> RiskDataBuilder {
         RiskDataBuilder::default()
     }
+
+    /// Get the `riskdata.*` additional data entries for this risk data.
+    ///
+    /// The Classic Payments API reads custom risk fields, the risk profile
+    /// and the skip flag from additional data rather than a `riskData` object.
+    #[must_use]
+    pub fn to_additional_data(&self) -> HashMap<String, String> {
+        let mut data: HashMap<String, String> = self
+            .custom_fields
+            .iter()
+            .flatten()
+            .map(|(key, value)| (format!("riskdata.{key}"), value.to_string()))
+            .collect();
+        if let Some(reference) = &self.profile_reference {
+            data.insert(
+                "riskdata.riskProfileReferenceId".to_string(),
+                reference.to_string(),
+            );
+        }
+        if let Some(skip) = self.skip_fraud {
+            data.insert("riskdata.skipRisk".to_string(), skip.to_string());
+        }
+        data
+    }
 }
 
 /// Builder for risk assessment data.
@@ -340,6 +633,21 @@ mod tests {
     use super::*;
     use serde_json;
 
+    #[test]
+    fn test_risk_data_to_additional_data() {
+        let risk_data = RiskData::builder()
+            .custom_field("accountAgeDays", "120")
+            .profile_reference("RiskProfile-1")
+            .skip_fraud(false)
+            .build();
+
+        let data = risk_data.to_additional_data();
+        assert_eq!(data["riskdata.accountAgeDays"], "120");
+        assert_eq!(data["riskdata.riskProfileReferenceId"], "RiskProfile-1");
+        assert_eq!(data["riskdata.skipRisk"], "false");
+        assert_eq!(data.len(), 3);
+    }
+
     #[test]
     fn test_fraud_result_serialization() {
         let fraud_result = FraudResult {
@@ -374,8 +682,8 @@ mod tests {
             name: "TestProvider".into(),
             check_id: 67890,
             account_score: 75,
-            risk_level: RiskLevel::High,
-            action: FraudAction::Block,
+            risk_level: Some(RiskLevel::High),
+            action: Some(FraudAction::Block),
             metadata: Some(metadata),
         };
 
@@ -384,6 +692,41 @@ mod tests {
         assert_eq!(check_result, deserialized);
     }
 
+    #[test]
+    fn test_fraud_check_results_from_api_response() {
+        let fraud_result: FraudResult = serde_json::from_str(
+            r#"{
+                "accountScore": 45,
+                "results": [
+                    {"FraudCheckResult": {"accountScore": 0, "checkId": 2, "name": "CardChunkUsage"}},
+                    {"FraudCheckResult": {"accountScore": 50, "checkId": 25, "name": "CVCAuthResultCheck"}},
+                    {"FraudCheckResult": {"accountScore": -5, "checkId": 26, "name": "ShopperEmailRefCheck"}},
+                    {"FraudCheckResult": {"accountScore": 0, "checkId": 99, "name": "NewRiskCheck"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let results = fraud_result.results.as_ref().unwrap();
+        assert_eq!(results[0].name, FraudCheckName::CardChunkUsage);
+        assert_eq!(results[1].name, FraudCheckName::CvcAuthResultCheck);
+        assert_eq!(
+            results[3].name,
+            FraudCheckName::Unknown("NewRiskCheck".into())
+        );
+        assert!(results[0].risk_level.is_none());
+
+        let scores = fraud_result.scores_by_check();
+        assert_eq!(scores[&FraudCheckName::CvcAuthResultCheck], 50);
+        assert_eq!(scores[&FraudCheckName::ShopperEmailRefCheck], -5);
+
+        let json = serde_json::to_value(&fraud_result).unwrap();
+        assert_eq!(
+            json["results"][1]["FraudCheckResult"]["name"],
+            "CVCAuthResultCheck"
+        );
+    }
+
     #[test]
     fn test_device_fingerprint_builder() {
         let fingerprint = DeviceFingerprint::builder()
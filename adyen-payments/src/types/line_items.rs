@@ -0,0 +1,156 @@
+//! Line item types for open invoice payments.
+//!
+//! The Classic Payments API has no `lineItems` field; open invoice payment
+//! methods such as Klarna, Afterpay and Ratepay read the items from
+//! `openinvoicedata.*` keys in the additional data instead.
+
+use adyen_core::Currency;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A line item for an open invoice payment.
+///
+/// All amounts are in minor units of the payment currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    /// The item ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The item description.
+    pub description: String,
+
+    /// The quantity of the item.
+    pub quantity: u32,
+
+    /// The price per item including tax, in minor units.
+    pub amount_including_tax: u64,
+
+    /// The price per item excluding tax, in minor units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_excluding_tax: Option<u64>,
+
+    /// The tax amount per item, in minor units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_amount: Option<u64>,
+
+    /// The tax percentage in basis points, e.g. `2100` for 21%.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_percentage: Option<u32>,
+}
+
+impl LineItem {
+    /// Create a new line item with its price per item including tax, in
+    /// minor units.
+    #[must_use]
+    pub fn new(description: impl Into<String>, quantity: u32, amount_including_tax: u64) -> Self {
+        Self {
+            id: None,
+            description: description.into(),
+            quantity,
+            amount_including_tax,
+            amount_excluding_tax: None,
+            tax_amount: None,
+            tax_percentage: None,
+        }
+    }
+
+    /// Set the item ID.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the tax information.
+    ///
+    /// The amount excluding tax is derived from the amount including tax.
+    #[must_use]
+    pub fn with_tax(mut self, tax_amount: u64, tax_percentage: u32) -> Self {
+        self.amount_excluding_tax = Some(self.amount_including_tax.saturating_sub(tax_amount));
+        self.tax_amount = Some(tax_amount);
+        self.tax_percentage = Some(tax_percentage);
+        self
+    }
+
+    /// Get the price per item excluding tax, in minor units.
+    #[must_use]
+    pub fn item_amount(&self) -> u64 {
+        self.amount_excluding_tax.unwrap_or_else(|| {
+            self.amount_including_tax
+                .saturating_sub(self.tax_amount.unwrap_or(0))
+        })
+    }
+}
+
+/// Convert line items into `openinvoicedata.*` additional data entries.
+///
+/// Lines are numbered from 1, as Adyen expects.
+#[must_use]
+pub fn open_invoice_data(items: &[LineItem], currency: &Currency) -> HashMap<String, String> {
+    let mut data = HashMap::new();
+    data.insert(
+        "openinvoicedata.numberOfLines".to_string(),
+        items.len().to_string(),
+    );
+
+    for (index, item) in items.iter().enumerate() {
+        let prefix = format!("openinvoicedata.line{}", index + 1);
+        data.insert(
+            format!("{prefix}.currencyCode"),
+            currency.code().to_string(),
+        );
+        data.insert(format!("{prefix}.description"), item.description.clone());
+        data.insert(
+            format!("{prefix}.itemAmount"),
+            item.item_amount().to_string(),
+        );
+        data.insert(
+            format!("{prefix}.itemVatAmount"),
+            item.tax_amount.unwrap_or(0).to_string(),
+        );
+        data.insert(
+            format!("{prefix}.itemVatPercentage"),
+            item.tax_percentage.unwrap_or(0).to_string(),
+        );
+        data.insert(format!("{prefix}.numberOfItems"), item.quantity.to_string());
+        if let Some(id) = &item.id {
+            data.insert(format!("{prefix}.itemId"), id.clone());
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_invoice_data() {
+        let items = vec![
+            LineItem::new("Shoes", 1, 12100)
+                .with_id("shoes-1")
+                .with_tax(2100, 2100),
+            LineItem::new("Socks", 2, 1000),
+        ];
+
+        let data = open_invoice_data(&items, &Currency::EUR);
+        assert_eq!(data["openinvoicedata.numberOfLines"], "2");
+        assert_eq!(data["openinvoicedata.line1.currencyCode"], "EUR");
+        assert_eq!(data["openinvoicedata.line1.itemId"], "shoes-1");
+        assert_eq!(data["openinvoicedata.line1.itemAmount"], "10000");
+        assert_eq!(data["openinvoicedata.line1.itemVatAmount"], "2100");
+        assert_eq!(data["openinvoicedata.line1.itemVatPercentage"], "2100");
+        assert_eq!(data["openinvoicedata.line2.description"], "Socks");
+        assert_eq!(data["openinvoicedata.line2.itemAmount"], "1000");
+        assert_eq!(data["openinvoicedata.line2.numberOfItems"], "2");
+        assert!(!data.contains_key("openinvoicedata.line2.itemId"));
+    }
+}
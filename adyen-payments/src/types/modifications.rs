@@ -3,8 +3,10 @@
 #![allow(clippy::type_complexity)]
 #![allow(clippy::return_self_not_must_use)]
 
+use crate::types::splits::Split;
 use crate::types::ApplicationInfo;
-use adyen_core::Amount;
+use adyen_core::builder::Missing;
+use adyen_core::{Amount, PspReference, RequestId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -13,6 +15,11 @@ pub type AdditionalData = HashMap<Box<str>, Box<str>>;
 
 /// Request to capture an authorized payment.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CaptureRequest {
     /// The merchant account identifier.
@@ -22,7 +29,7 @@ pub struct CaptureRequest {
     pub modification_amount: Amount,
 
     /// The PSP reference of the payment to capture.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 
     /// Your reference for this capture request.
     pub reference: Option<Box<str>>,
@@ -32,6 +39,14 @@ pub struct CaptureRequest {
 
     /// Application information.
     pub application_info: Option<ApplicationInfo>,
+
+    /// How to split the captured amount between accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
 }
 
 impl CaptureRequest {
@@ -43,34 +58,83 @@ impl CaptureRequest {
 }
 
 /// Builder for capture requests.
+///
+/// `build` is only available once the required merchant account, modification
+/// amount and original reference are set.
+///
+/// ```compile_fail
+/// use adyen_core::{Amount, Currency};
+/// use adyen_payments::types::modifications::CaptureRequest;
+///
+/// // No original reference, so there is no `build` method.
+/// let request = CaptureRequest::builder()
+///     .merchant_account("TestMerchant")
+///     .modification_amount(Amount::from_major_units(100, Currency::EUR))
+///     .build();
+/// ```
 #[derive(Debug, Default)]
-pub struct CaptureRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    modification_amount: Option<Amount>,
-    original_reference: Option<Box<str>>,
+pub struct CaptureRequestBuilder<M = Missing, A = Missing, R = Missing> {
+    merchant_account: M,
+    modification_amount: A,
+    original_reference: R,
     reference: Option<Box<str>>,
     additional_data: Option<HashMap<Box<str>, Box<str>>>,
     application_info: Option<ApplicationInfo>,
+    splits: Option<Vec<Split>>,
+    idempotency_key: Option<RequestId>,
 }
 
-impl CaptureRequestBuilder {
+impl<M, A, R> CaptureRequestBuilder<M, A, R> {
     /// Set the merchant account.
-    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
-        self.merchant_account = Some(account.into());
-        self
+    pub fn merchant_account<S: Into<Box<str>>>(
+        self,
+        account: S,
+    ) -> CaptureRequestBuilder<Box<str>, A, R> {
+        CaptureRequestBuilder {
+            merchant_account: account.into(),
+            modification_amount: self.modification_amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the modification amount.
     #[must_use]
-    pub fn modification_amount(mut self, amount: Amount) -> Self {
-        self.modification_amount = Some(amount);
-        self
+    pub fn modification_amount(self, amount: Amount) -> CaptureRequestBuilder<M, Amount, R> {
+        CaptureRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
-    /// Set the original payment reference.
-    pub fn original_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the original payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> CaptureRequestBuilder<M, A, Box<str>> {
+        CaptureRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: reference.as_ref().into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the capture reference.
@@ -93,38 +157,56 @@ impl CaptureRequestBuilder {
         self
     }
 
+    /// Set how to split the captured amount between accounts.
+    #[must_use]
+    pub fn splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this capture.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
+
+impl CaptureRequestBuilder<Box<str>, Amount, Box<str>> {
     /// Build the capture request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are missing.
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<CaptureRequest, Box<str>> {
         Ok(CaptureRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or("merchant_account is required")?,
-            modification_amount: self
-                .modification_amount
-                .ok_or("modification_amount is required")?,
-            original_reference: self
-                .original_reference
-                .ok_or("original_reference is required")?,
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
             reference: self.reference,
             additional_data: self.additional_data,
             application_info: self.application_info,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
         })
     }
 }
 
 /// Request to cancel an authorized payment.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelRequest {
     /// The merchant account identifier.
     pub merchant_account: Box<str>,
 
     /// The PSP reference of the payment to cancel.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 
     /// Your reference for this cancel request.
     pub reference: Option<Box<str>>,
@@ -145,26 +227,48 @@ impl CancelRequest {
 }
 
 /// Builder for cancel requests.
+///
+/// `build` is only available once the required merchant account and original
+/// reference are set.
 #[derive(Debug, Default)]
-pub struct CancelRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    original_reference: Option<Box<str>>,
+pub struct CancelRequestBuilder<M = Missing, R = Missing> {
+    merchant_account: M,
+    original_reference: R,
     reference: Option<Box<str>>,
     additional_data: Option<HashMap<Box<str>, Box<str>>>,
     application_info: Option<ApplicationInfo>,
 }
 
-impl CancelRequestBuilder {
+impl<M, R> CancelRequestBuilder<M, R> {
     /// Set the merchant account.
-    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
-        self.merchant_account = Some(account.into());
-        self
+    pub fn merchant_account<S: Into<Box<str>>>(
+        self,
+        account: S,
+    ) -> CancelRequestBuilder<Box<str>, R> {
+        CancelRequestBuilder {
+            merchant_account: account.into(),
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
-    /// Set the original payment reference.
-    pub fn original_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the original payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> CancelRequestBuilder<M, Box<str>> {
+        CancelRequestBuilder {
+            merchant_account: self.merchant_account,
+            original_reference: reference.as_ref().into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
     /// Set the cancel reference.
@@ -186,20 +290,19 @@ impl CancelRequestBuilder {
         self.application_info = Some(info);
         self
     }
+}
 
+impl CancelRequestBuilder<Box<str>, Box<str>> {
     /// Build the cancel request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are missing.
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<CancelRequest, Box<str>> {
         Ok(CancelRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or("merchant_account is required")?,
-            original_reference: self
-                .original_reference
-                .ok_or("original_reference is required")?,
+            merchant_account: self.merchant_account,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
             reference: self.reference,
             additional_data: self.additional_data,
             application_info: self.application_info,
@@ -209,6 +312,11 @@ impl CancelRequestBuilder {
 
 /// Request to refund a captured payment.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RefundRequest {
     /// The merchant account identifier.
@@ -218,7 +326,7 @@ pub struct RefundRequest {
     pub modification_amount: Amount,
 
     /// The PSP reference of the payment to refund.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 
     /// Your reference for this refund request.
     pub reference: Option<Box<str>>,
@@ -228,6 +336,10 @@ pub struct RefundRequest {
 
     /// Application information.
     pub application_info: Option<ApplicationInfo>,
+
+    /// How to split the refunded amount between accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
 }
 
 impl RefundRequest {
@@ -239,34 +351,68 @@ impl RefundRequest {
 }
 
 /// Builder for refund requests.
+///
+/// `build` is only available once the required merchant account, modification
+/// amount and original reference are set.
 #[derive(Debug, Default)]
-pub struct RefundRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    modification_amount: Option<Amount>,
-    original_reference: Option<Box<str>>,
+pub struct RefundRequestBuilder<M = Missing, A = Missing, R = Missing> {
+    merchant_account: M,
+    modification_amount: A,
+    original_reference: R,
     reference: Option<Box<str>>,
     additional_data: Option<HashMap<Box<str>, Box<str>>>,
     application_info: Option<ApplicationInfo>,
+    splits: Option<Vec<Split>>,
 }
 
-impl RefundRequestBuilder {
+impl<M, A, R> RefundRequestBuilder<M, A, R> {
     /// Set the merchant account.
-    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
-        self.merchant_account = Some(account.into());
-        self
+    pub fn merchant_account<S: Into<Box<str>>>(
+        self,
+        account: S,
+    ) -> RefundRequestBuilder<Box<str>, A, R> {
+        RefundRequestBuilder {
+            merchant_account: account.into(),
+            modification_amount: self.modification_amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+        }
     }
 
     /// Set the modification amount.
     #[must_use]
-    pub fn modification_amount(mut self, amount: Amount) -> Self {
-        self.modification_amount = Some(amount);
-        self
+    pub fn modification_amount(self, amount: Amount) -> RefundRequestBuilder<M, Amount, R> {
+        RefundRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+        }
     }
 
-    /// Set the original payment reference.
-    pub fn original_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the original payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> RefundRequestBuilder<M, A, Box<str>> {
+        RefundRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: reference.as_ref().into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+            splits: self.splits,
+        }
     }
 
     /// Set the refund reference.
@@ -289,38 +435,48 @@ impl RefundRequestBuilder {
         self
     }
 
+    /// Set how to split the refunded amount between accounts.
+    #[must_use]
+    pub fn splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+}
+
+impl RefundRequestBuilder<Box<str>, Amount, Box<str>> {
     /// Build the refund request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are missing.
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<RefundRequest, Box<str>> {
         Ok(RefundRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or("merchant_account is required")?,
-            modification_amount: self
-                .modification_amount
-                .ok_or("modification_amount is required")?,
-            original_reference: self
-                .original_reference
-                .ok_or("original_reference is required")?,
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
             reference: self.reference,
             additional_data: self.additional_data,
             application_info: self.application_info,
+            splits: self.splits,
         })
     }
 }
 
 /// Request to cancel or refund a payment automatically.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrRefundRequest {
     /// The merchant account identifier.
     pub merchant_account: Box<str>,
 
     /// The PSP reference of the payment to cancel or refund.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 
     /// Your reference for this request.
     pub reference: Option<Box<str>>,
@@ -341,26 +497,48 @@ impl CancelOrRefundRequest {
 }
 
 /// Builder for cancel or refund requests.
+///
+/// `build` is only available once the required merchant account and original
+/// reference are set.
 #[derive(Debug, Default)]
-pub struct CancelOrRefundRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    original_reference: Option<Box<str>>,
+pub struct CancelOrRefundRequestBuilder<M = Missing, R = Missing> {
+    merchant_account: M,
+    original_reference: R,
     reference: Option<Box<str>>,
     additional_data: Option<HashMap<Box<str>, Box<str>>>,
     application_info: Option<ApplicationInfo>,
 }
 
-impl CancelOrRefundRequestBuilder {
+impl<M, R> CancelOrRefundRequestBuilder<M, R> {
     /// Set the merchant account.
-    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
-        self.merchant_account = Some(account.into());
-        self
+    pub fn merchant_account<S: Into<Box<str>>>(
+        self,
+        account: S,
+    ) -> CancelOrRefundRequestBuilder<Box<str>, R> {
+        CancelOrRefundRequestBuilder {
+            merchant_account: account.into(),
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
-    /// Set the original payment reference.
-    pub fn original_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the original payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> CancelOrRefundRequestBuilder<M, Box<str>> {
+        CancelOrRefundRequestBuilder {
+            merchant_account: self.merchant_account,
+            original_reference: reference.as_ref().into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
     /// Set the request reference.
@@ -382,20 +560,19 @@ impl CancelOrRefundRequestBuilder {
         self.application_info = Some(info);
         self
     }
+}
 
+impl CancelOrRefundRequestBuilder<Box<str>, Box<str>> {
     /// Build the cancel or refund request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are missing.
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<CancelOrRefundRequest, Box<str>> {
         Ok(CancelOrRefundRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or("merchant_account is required")?,
-            original_reference: self
-                .original_reference
-                .ok_or("original_reference is required")?,
+            merchant_account: self.merchant_account,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
             reference: self.reference,
             additional_data: self.additional_data,
             application_info: self.application_info,
@@ -405,6 +582,11 @@ impl CancelOrRefundRequestBuilder {
 
 /// Response codes for modification operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "kebab-case")]
 pub enum ModificationResponse {
     /// The modification was received successfully.
@@ -419,17 +601,25 @@ pub enum ModificationResponse {
     /// The modification was received successfully.
     #[serde(rename = "[cancelOrRefund-received]")]
     CancelOrRefundReceived,
+    /// A modification response not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Result of a payment modification operation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ModificationResult {
     /// Additional data returned by Adyen.
     pub additional_data: Option<HashMap<Box<str>, Box<str>>>,
 
     /// The PSP reference for this modification.
-    pub psp_reference: Box<str>,
+    pub psp_reference: PspReference,
 
     /// The response indicating the result of the modification.
     pub response: ModificationResponse,
@@ -437,6 +627,11 @@ pub struct ModificationResult {
 
 /// Request to adjust an authorized amount.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AdjustAuthorizationRequest {
     /// The merchant account identifier.
@@ -446,7 +641,7 @@ pub struct AdjustAuthorizationRequest {
     pub modification_amount: Amount,
 
     /// The PSP reference of the payment to adjust.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 
     /// Your reference for this adjustment request.
     pub reference: Option<Box<str>>,
@@ -467,34 +662,67 @@ impl AdjustAuthorizationRequest {
 }
 
 /// Builder for adjust authorization requests.
+///
+/// `build` is only available once the required merchant account, modification
+/// amount and original reference are set.
 #[derive(Debug, Default)]
-pub struct AdjustAuthorizationRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    modification_amount: Option<Amount>,
-    original_reference: Option<Box<str>>,
+pub struct AdjustAuthorizationRequestBuilder<M = Missing, A = Missing, R = Missing> {
+    merchant_account: M,
+    modification_amount: A,
+    original_reference: R,
     reference: Option<Box<str>>,
     additional_data: Option<HashMap<Box<str>, Box<str>>>,
     application_info: Option<ApplicationInfo>,
 }
 
-impl AdjustAuthorizationRequestBuilder {
+impl<M, A, R> AdjustAuthorizationRequestBuilder<M, A, R> {
     /// Set the merchant account.
-    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
-        self.merchant_account = Some(account.into());
-        self
+    pub fn merchant_account<S: Into<Box<str>>>(
+        self,
+        account: S,
+    ) -> AdjustAuthorizationRequestBuilder<Box<str>, A, R> {
+        AdjustAuthorizationRequestBuilder {
+            merchant_account: account.into(),
+            modification_amount: self.modification_amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
     /// Set the modification amount.
     #[must_use]
-    pub fn modification_amount(mut self, amount: Amount) -> Self {
-        self.modification_amount = Some(amount);
-        self
+    pub fn modification_amount(
+        self,
+        amount: Amount,
+    ) -> AdjustAuthorizationRequestBuilder<M, Amount, R> {
+        AdjustAuthorizationRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: amount,
+            original_reference: self.original_reference,
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
-    /// Set the original payment reference.
-    pub fn original_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the original payment.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference<S: AsRef<str>>(
+        self,
+        reference: S,
+    ) -> AdjustAuthorizationRequestBuilder<M, A, Box<str>> {
+        AdjustAuthorizationRequestBuilder {
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: reference.as_ref().into(),
+            reference: self.reference,
+            additional_data: self.additional_data,
+            application_info: self.application_info,
+        }
     }
 
     /// Set the adjustment reference.
@@ -516,23 +744,20 @@ impl AdjustAuthorizationRequestBuilder {
         self.application_info = Some(info);
         self
     }
+}
 
+impl AdjustAuthorizationRequestBuilder<Box<str>, Amount, Box<str>> {
     /// Build the adjust authorization request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are missing.
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<AdjustAuthorizationRequest, Box<str>> {
         Ok(AdjustAuthorizationRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or("merchant_account is required")?,
-            modification_amount: self
-                .modification_amount
-                .ok_or("modification_amount is required")?,
-            original_reference: self
-                .original_reference
-                .ok_or("original_reference is required")?,
+            merchant_account: self.merchant_account,
+            modification_amount: self.modification_amount,
+            original_reference: PspReference::new(&self.original_reference)
+                .map_err(|_| "original_reference is not a valid PSP reference")?,
             reference: self.reference,
             additional_data: self.additional_data,
             application_info: self.application_info,
@@ -558,10 +783,28 @@ mod tests {
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchant");
         assert_eq!(request.modification_amount.minor_units(), 10000);
-        assert_eq!(request.original_reference.as_ref(), "8515131751004933");
+        assert_eq!(request.original_reference, "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("capture-123"));
     }
 
+    #[test]
+    fn test_capture_request_with_splits() {
+        let request = CaptureRequest::builder()
+            .merchant_account("TestMerchant")
+            .modification_amount(Amount::from_major_units(100, Currency::EUR))
+            .original_reference("8515131751004933")
+            .splits(vec![
+                Split::balance_account("BA00000000000000000000001", 9000),
+                Split::commission(1000),
+            ])
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["splits"][0]["type"], "BalanceAccount");
+        assert_eq!(json["splits"][1]["amount"]["value"], 1000);
+    }
+
     #[test]
     fn test_cancel_request_builder() {
         let request = CancelRequest::builder()
@@ -572,7 +815,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchant");
-        assert_eq!(request.original_reference.as_ref(), "8515131751004933");
+        assert_eq!(request.original_reference, "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("cancel-123"));
     }
 
@@ -588,8 +831,8 @@ mod tests {
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchant");
         assert_eq!(request.modification_amount.minor_units(), 5000);
-        assert_eq!(request.modification_amount.currency(), Currency::USD);
-        assert_eq!(request.original_reference.as_ref(), "8515131751004933");
+        assert_eq!(request.modification_amount.currency(), &Currency::USD);
+        assert_eq!(request.original_reference, "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("refund-123"));
     }
 
@@ -603,7 +846,7 @@ mod tests {
             .unwrap();
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchant");
-        assert_eq!(request.original_reference.as_ref(), "8515131751004933");
+        assert_eq!(request.original_reference, "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("cancel-or-refund-123"));
     }
 
@@ -619,8 +862,8 @@ mod tests {
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchant");
         assert_eq!(request.modification_amount.minor_units(), 15000);
-        assert_eq!(request.modification_amount.currency(), Currency::GBP);
-        assert_eq!(request.original_reference.as_ref(), "8515131751004933");
+        assert_eq!(request.modification_amount.currency(), &Currency::GBP);
+        assert_eq!(request.original_reference, "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("adjust-123"));
     }
 
@@ -631,7 +874,7 @@ mod tests {
 
         let result = ModificationResult {
             additional_data: Some(additional_data),
-            psp_reference: "8515131751004934".into(),
+            psp_reference: PspReference::new("8515131751004934").unwrap(),
             response: ModificationResponse::CaptureReceived,
         };
 
@@ -662,32 +905,14 @@ mod tests {
 
     #[test]
     fn test_builder_validation() {
-        // Test missing merchant account
-        let result = CaptureRequest::builder()
-            .modification_amount(Amount::from_major_units(100, Currency::EUR))
-            .original_reference("8515131751004933")
-            .build();
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("merchant_account is required"));
-
-        // Test missing amount
-        let result = CaptureRequest::builder()
-            .merchant_account("TestMerchant")
-            .original_reference("8515131751004933")
-            .build();
-        assert!(result.is_err());
-        assert!(result
-            .unwrap_err()
-            .contains("modification_amount is required"));
-
-        // Test missing original reference
+        // Test merchant reference passed as the original reference
         let result = CaptureRequest::builder()
             .merchant_account("TestMerchant")
             .modification_amount(Amount::from_major_units(100, Currency::EUR))
+            .original_reference("Order-12345")
             .build();
-        assert!(result.is_err());
         assert!(result
             .unwrap_err()
-            .contains("original_reference is required"));
+            .contains("original_reference is not a valid PSP reference"));
     }
 }
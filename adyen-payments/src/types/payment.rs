@@ -1,11 +1,22 @@
 //! Classic payment request and response types.
 
-use adyen_core::{AdyenError, Amount, Result};
+use crate::types::fraud::RiskData;
+use crate::types::line_items::{open_invoice_data, LineItem};
+use crate::types::splits::Split;
+use adyen_core::additional_data::{AirlineData, EnhancedSchemeData, LodgingData};
+use adyen_core::builder::Missing;
+use adyen_core::validation::RequestValidator;
+use adyen_core::{Amount, CountryCode, Locale, PspReference, RequestId, Result, SecretString};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Request to create a payment authorization.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentRequest {
     /// The payment amount and currency.
@@ -27,11 +38,11 @@ pub struct PaymentRequest {
 
     /// The shopper's country code.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
 
     /// The shopper's locale.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shopper_locale: Option<String>,
+    pub shopper_locale: Option<Locale>,
 
     /// The shopper reference for recurring payments.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,10 +91,31 @@ pub struct PaymentRequest {
     /// Installment configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub installments: Option<Installments>,
+
+    /// Offset added to the fraud score; negative values lower the risk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraud_offset: Option<i32>,
+
+    /// Device fingerprint collected on the client for risk checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_fingerprint: Option<String>,
+
+    /// How to split the payment between accounts (Adyen for Platforms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splits: Option<Vec<Split>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
 }
 
 /// Payment method details for different payment types.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(untagged)]
 pub enum PaymentMethod {
     /// Credit/debit card payment.
@@ -102,16 +134,22 @@ pub enum PaymentMethod {
     Alternative {
         /// Additional payment method data.
         #[serde(flatten)]
+        #[cfg_attr(feature = "rkyv", with(adyen_core::archive::AsJson))]
         data: HashMap<String, serde_json::Value>,
     },
 }
 
 /// Credit/debit card details.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
     /// The card number.
-    pub number: String,
+    pub number: SecretString,
 
     /// The expiry month (MM).
     pub expiry_month: String,
@@ -120,7 +158,7 @@ pub struct Card {
     pub expiry_year: String,
 
     /// The card security code.
-    pub cvc: String,
+    pub cvc: SecretString,
 
     /// The cardholder name.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,6 +167,11 @@ pub struct Card {
 
 /// Recurring payment configuration.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct RecurringType {
     /// The type of recurring contract.
@@ -141,6 +184,11 @@ pub struct RecurringType {
 
 /// Recurring contract types.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RecurringContract {
     /// One-click payments.
@@ -149,10 +197,18 @@ pub enum RecurringContract {
     Recurring,
     /// Both one-click and recurring.
     OneclickRecurring,
+    /// A recurring contract not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Browser information for 3D Secure authentication.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserInfo {
     /// The accept header value.
@@ -183,47 +239,15 @@ pub struct BrowserInfo {
     pub user_agent: String,
 }
 
-/// Application information.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ApplicationInfo {
-    /// Information about the merchant application.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub merchant_application: Option<MerchantApplication>,
-
-    /// Information about the external platform.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub external_platform: Option<ExternalPlatform>,
-}
-
-/// Merchant application information.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct MerchantApplication {
-    /// The application name.
-    pub name: String,
-
-    /// The application version.
-    pub version: String,
-}
-
-/// External platform information.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ExternalPlatform {
-    /// The platform name.
-    pub name: String,
-
-    /// The platform version.
-    pub version: String,
-
-    /// The platform integrator.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub integrator: Option<String>,
-}
+pub use adyen_core::{ApplicationInfo, ExternalPlatform, MerchantApplication};
 
 /// Address information.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// The street address.
@@ -253,14 +277,96 @@ pub struct Address {
 
 /// Installment configuration.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Installments {
     /// The number of installments.
     pub value: u32,
+
+    /// The installment plan, required for some plans in Brazil and Mexico.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<InstallmentPlan>,
+
+    /// Extra number of installments or bonus payments, used in Japan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<u32>,
+}
+
+impl Installments {
+    /// Create installments with the given number of installments.
+    #[must_use]
+    pub fn new(value: u32) -> Self {
+        Self {
+            value,
+            plan: None,
+            extra: None,
+        }
+    }
+
+    /// Set the installment plan.
+    #[must_use]
+    pub fn with_plan(mut self, plan: InstallmentPlan) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    /// Set the extra number of installments.
+    #[must_use]
+    pub fn with_extra(mut self, extra: u32) -> Self {
+        self.extra = Some(extra);
+        self
+    }
+}
+
+/// Installment plan type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallmentPlan {
+    /// Fixed installments without interest.
+    Regular,
+    /// Revolving credit: the shopper chooses how much to pay each month.
+    Revolving,
+    /// Installments with interest charged by the issuer (Brazil).
+    WithInterest,
+    /// Buy now, pay later: the first installment is deferred (Mexico).
+    #[serde(rename = "buynow_paylater")]
+    BuyNowPayLater,
+    /// Bonus payments (Japan).
+    Bonus,
+    /// Installments with interest and bonus payments (Mexico).
+    InterestBonus,
+    /// Installments without interest, with bonus payments (Mexico).
+    NointerestBonus,
+    /// Percentage refund on interest-bearing installments (Mexico).
+    #[serde(rename = "interes_refund_prctg")]
+    InterestRefundPercentage,
+    /// Percentage refund on interest-free installments (Mexico).
+    #[serde(rename = "nointeres_refund_prctg")]
+    NointerestRefundPercentage,
+    /// Percentage refund on installments (Mexico).
+    #[serde(rename = "refund_prctg")]
+    RefundPercentage,
+    /// An installment plan not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Response from a payment authorization request.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentResult {
     /// The result of the payment request.
@@ -268,7 +374,7 @@ pub struct PaymentResult {
 
     /// Adyen's 16-character string reference associated with the transaction.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub psp_reference: Option<String>,
+    pub psp_reference: Option<PspReference>,
 
     /// The merchant reference for the payment.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -305,6 +411,11 @@ pub struct PaymentResult {
 
 /// The result code of a payment request.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "PascalCase")]
 pub enum PaymentResultCode {
     /// The payment was successful.
@@ -321,27 +432,58 @@ pub enum PaymentResultCode {
     Received,
     /// The payment is pending.
     Pending,
+    /// A payment result code not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Fraud detection results.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct FraudResult {
-    /// The fraud score.
-    pub account_score: u32,
+    /// The fraud score, the sum of the scores of the individual checks.
+    pub account_score: i32,
 
     /// The fraud check results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<crate::types::fraud::FraudCheckResult>>,
 }
 
+impl FraudResult {
+    /// Get the account score of each check, summed per check name.
+    ///
+    /// Use this to aggregate scores per check across payments.
+    #[must_use]
+    pub fn scores_by_check(&self) -> HashMap<crate::types::fraud::FraudCheckName, i32> {
+        crate::types::fraud::scores_by_check(self.results.as_deref().unwrap_or_default())
+    }
+
+    /// Get the checks that changed the account score.
+    pub fn triggered_checks(
+        &self,
+    ) -> impl Iterator<Item = &crate::types::fraud::FraudCheckResult> + '_ {
+        self.results
+            .iter()
+            .flatten()
+            .filter(|result| result.account_score != 0)
+    }
+}
+
 /// Builder for creating payment requests.
+///
+/// `build` is only available once the required amount, merchant account,
+/// reference and payment method are set.
 #[derive(Debug, Clone, Default)]
-pub struct PaymentRequestBuilder {
-    amount: Option<Amount>,
-    merchant_account: Option<String>,
-    reference: Option<String>,
-    payment_method: Option<PaymentMethod>,
+pub struct PaymentRequestBuilder<A = Missing, M = Missing, R = Missing, P = Missing> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    payment_method: P,
     channel: Option<String>,
     country_code: Option<String>,
     shopper_locale: Option<String>,
@@ -357,6 +499,10 @@ pub struct PaymentRequestBuilder {
     billing_address: Option<Address>,
     delivery_address: Option<Address>,
     installments: Option<Installments>,
+    line_items: Option<Vec<LineItem>>,
+    risk_data: Option<RiskData>,
+    splits: Option<Vec<Split>>,
+    idempotency_key: Option<RequestId>,
 }
 
 impl PaymentRequestBuilder {
@@ -365,42 +511,165 @@ impl PaymentRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<A, M, R, P> PaymentRequestBuilder<A, M, R, P> {
     /// Set the payment amount.
     #[must_use]
-    pub fn amount(mut self, amount: Amount) -> Self {
-        self.amount = Some(amount);
-        self
+    pub fn amount(self, amount: Amount) -> PaymentRequestBuilder<Amount, M, R, P> {
+        PaymentRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            payment_method: self.payment_method,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            shopper_ip: self.shopper_ip,
+            recurring: self.recurring,
+            return_url: self.return_url,
+            browser_info: self.browser_info,
+            application_info: self.application_info,
+            additional_data: self.additional_data,
+            session_validity: self.session_validity,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            installments: self.installments,
+            line_items: self.line_items,
+            risk_data: self.risk_data,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the merchant account.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentRequestBuilder<A, String, R, P> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            payment_method: self.payment_method,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            shopper_ip: self.shopper_ip,
+            recurring: self.recurring,
+            return_url: self.return_url,
+            browser_info: self.browser_info,
+            application_info: self.application_info,
+            additional_data: self.additional_data,
+            session_validity: self.session_validity,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            installments: self.installments,
+            line_items: self.line_items,
+            risk_data: self.risk_data,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the payment reference.
     #[must_use]
-    pub fn reference(mut self, reference: impl Into<String>) -> Self {
-        self.reference = Some(reference.into());
-        self
+    pub fn reference(self, reference: impl Into<String>) -> PaymentRequestBuilder<A, M, String, P> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            payment_method: self.payment_method,
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            shopper_ip: self.shopper_ip,
+            recurring: self.recurring,
+            return_url: self.return_url,
+            browser_info: self.browser_info,
+            application_info: self.application_info,
+            additional_data: self.additional_data,
+            session_validity: self.session_validity,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            installments: self.installments,
+            line_items: self.line_items,
+            risk_data: self.risk_data,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set card payment method.
     #[must_use]
-    pub fn card(mut self, card: Card) -> Self {
-        self.payment_method = Some(PaymentMethod::Card { card });
-        self
+    pub fn card(self, card: Card) -> PaymentRequestBuilder<A, M, R, PaymentMethod> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            payment_method: PaymentMethod::Card { card },
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            shopper_ip: self.shopper_ip,
+            recurring: self.recurring,
+            return_url: self.return_url,
+            browser_info: self.browser_info,
+            application_info: self.application_info,
+            additional_data: self.additional_data,
+            session_validity: self.session_validity,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            installments: self.installments,
+            line_items: self.line_items,
+            risk_data: self.risk_data,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set stored payment method.
     #[must_use]
-    pub fn stored_payment_method(mut self, reference: impl Into<String>) -> Self {
-        self.payment_method = Some(PaymentMethod::Stored {
-            selected_recurring_detail_reference: reference.into(),
-        });
-        self
+    pub fn stored_payment_method(
+        self,
+        reference: impl Into<String>,
+    ) -> PaymentRequestBuilder<A, M, R, PaymentMethod> {
+        PaymentRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            payment_method: PaymentMethod::Stored {
+                selected_recurring_detail_reference: reference.into(),
+            },
+            channel: self.channel,
+            country_code: self.country_code,
+            shopper_locale: self.shopper_locale,
+            shopper_reference: self.shopper_reference,
+            shopper_email: self.shopper_email,
+            shopper_ip: self.shopper_ip,
+            recurring: self.recurring,
+            return_url: self.return_url,
+            browser_info: self.browser_info,
+            application_info: self.application_info,
+            additional_data: self.additional_data,
+            session_validity: self.session_validity,
+            billing_address: self.billing_address,
+            delivery_address: self.delivery_address,
+            installments: self.installments,
+            line_items: self.line_items,
+            risk_data: self.risk_data,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the sales channel.
@@ -411,16 +680,22 @@ impl PaymentRequestBuilder {
     }
 
     /// Set the shopper's country code.
+    ///
+    /// Accepts a [`CountryCode`] or a two-letter code, which is validated by
+    /// `build`.
     #[must_use]
-    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
-        self.country_code = Some(country_code.into());
+    pub fn country_code(mut self, country_code: impl AsRef<str>) -> Self {
+        self.country_code = Some(country_code.as_ref().to_owned());
         self
     }
 
     /// Set the shopper's locale.
+    ///
+    /// Accepts a [`Locale`] or a string such as `nl-NL`, which is validated
+    /// by `build`.
     #[must_use]
-    pub fn shopper_locale(mut self, locale: impl Into<String>) -> Self {
-        self.shopper_locale = Some(locale.into());
+    pub fn shopper_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.shopper_locale = Some(locale.as_ref().to_owned());
         self
     }
 
@@ -501,6 +776,55 @@ impl PaymentRequestBuilder {
         self
     }
 
+    /// Add a line item for open invoice payment methods such as Klarna.
+    #[must_use]
+    pub fn line_item(mut self, item: LineItem) -> Self {
+        self.line_items.get_or_insert_with(Vec::new).push(item);
+        self
+    }
+
+    /// Set line items for open invoice payment methods such as Klarna.
+    ///
+    /// The items are sent as `openinvoicedata.*` additional data.
+    #[must_use]
+    pub fn line_items(mut self, items: Vec<LineItem>) -> Self {
+        self.line_items = Some(items);
+        self
+    }
+
+    /// Set risk data for Adyen's risk engine.
+    ///
+    /// The fraud offset and client data are sent as `fraudOffset` and
+    /// `deviceFingerprint`; the remaining fields as `riskdata.*` additional data.
+    #[must_use]
+    pub fn risk_data(mut self, risk_data: RiskData) -> Self {
+        self.risk_data = Some(risk_data);
+        self
+    }
+
+    /// Add a split.
+    #[must_use]
+    pub fn split(mut self, split: Split) -> Self {
+        self.splits.get_or_insert_with(Vec::new).push(split);
+        self
+    }
+
+    /// Set how to split the payment between accounts.
+    #[must_use]
+    pub fn splits(mut self, splits: Vec<Split>) -> Self {
+        self.splits = Some(splits);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this payment.
+    ///
+    /// Use [`RequestId::new`] to generate a random UUID v4 key.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
     /// Add additional data.
     #[must_use]
     pub fn additional_data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
@@ -510,33 +834,77 @@ impl PaymentRequestBuilder {
         self
     }
 
+    /// Add airline passenger data as `airline.*` additional data.
+    #[must_use]
+    pub fn airline_data(mut self, airline: &AirlineData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(airline.to_additional_data());
+        self
+    }
+
+    /// Add lodging data as `lodging.*` additional data.
+    #[must_use]
+    pub fn lodging_data(mut self, lodging: &LodgingData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(lodging.to_additional_data());
+        self
+    }
+
+    /// Add Level 2/3 data as `enhancedSchemeData.*` additional data.
+    #[must_use]
+    pub fn enhanced_scheme_data(mut self, enhanced: &EnhancedSchemeData) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .extend(enhanced.to_additional_data());
+        self
+    }
+}
+
+impl PaymentRequestBuilder<Amount, String, String, PaymentMethod> {
     /// Build the payment request.
     ///
     /// # Errors
     ///
-    /// Returns an error if required fields are not set.
+    /// Returns an error if the country code or shopper locale is invalid.
     pub fn build(self) -> Result<PaymentRequest> {
-        let amount = self
-            .amount
-            .ok_or_else(|| AdyenError::config("amount is required"))?;
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-        let reference = self
-            .reference
-            .ok_or_else(|| AdyenError::config("reference is required"))?;
-        let payment_method = self
-            .payment_method
-            .ok_or_else(|| AdyenError::config("payment_method is required"))?;
+        let mut additional_data = self.additional_data;
+        if let Some(items) = &self.line_items {
+            additional_data
+                .get_or_insert_with(HashMap::new)
+                .extend(open_invoice_data(items, self.amount.currency()));
+        }
+        if let Some(risk_data) = &self.risk_data {
+            let risk_additional_data = risk_data.to_additional_data();
+            if !risk_additional_data.is_empty() {
+                additional_data
+                    .get_or_insert_with(HashMap::new)
+                    .extend(risk_additional_data);
+            }
+        }
+        let fraud_offset = self.risk_data.as_ref().and_then(|risk| risk.fraud_offset);
+        let device_fingerprint = self
+            .risk_data
+            .and_then(|risk| risk.client_data)
+            .map(String::from);
 
         Ok(PaymentRequest {
-            amount,
-            merchant_account,
-            reference,
-            payment_method,
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            payment_method: self.payment_method,
             channel: self.channel,
-            country_code: self.country_code,
-            shopper_locale: self.shopper_locale,
+            country_code: self
+                .country_code
+                .as_deref()
+                .map(CountryCode::new)
+                .transpose()?,
+            shopper_locale: self
+                .shopper_locale
+                .as_deref()
+                .map(Locale::new)
+                .transpose()?,
             shopper_reference: self.shopper_reference,
             shopper_email: self.shopper_email,
             shopper_ip: self.shopper_ip,
@@ -544,13 +912,31 @@ impl PaymentRequestBuilder {
             return_url: self.return_url,
             browser_info: self.browser_info,
             application_info: self.application_info,
-            additional_data: self.additional_data,
+            additional_data,
             session_validity: self.session_validity,
             billing_address: self.billing_address,
             delivery_address: self.delivery_address,
             installments: self.installments,
+            fraud_offset,
+            device_fingerprint,
+            splits: self.splits,
+            idempotency_key: self.idempotency_key,
         })
     }
+
+    /// Build the payment request and check the formats of its fields, see
+    /// [`PaymentRequest::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`build`](Self::build), or
+    /// [`AdyenError::Validation`](adyen_core::AdyenError::Validation) listing
+    /// every field with an invalid format.
+    pub fn build_validated(self) -> Result<PaymentRequest> {
+        let request = self.build()?;
+        request.validate()?;
+        Ok(request)
+    }
 }
 
 impl PaymentRequest {
@@ -559,16 +945,57 @@ impl PaymentRequest {
     pub fn builder() -> PaymentRequestBuilder {
         PaymentRequestBuilder::new()
     }
+
+    /// Check the formats of the request's fields.
+    ///
+    /// Checks that the reference is at most 80 characters, the currency and
+    /// address countries are ISO codes, split currencies match the amount,
+    /// the shopper email is valid, the card expiry month is from 1 to 12,
+    /// and the return URL, if set, has a scheme shoppers can be redirected
+    /// to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdyenError::Validation`](adyen_core::AdyenError::Validation)
+    /// listing every invalid field.
+    pub fn validate(&self) -> Result<()> {
+        let mut validator = RequestValidator::new();
+        validator
+            .reference("reference", &self.reference)
+            .currency("amount", &self.amount);
+        if let Some(return_url) = &self.return_url {
+            validator.return_url("return_url", return_url);
+        }
+        if let Some(email) = &self.shopper_email {
+            validator.email("shopper_email", email);
+        }
+        if let PaymentMethod::Card { card } = &self.payment_method {
+            validator.expiry_month("payment_method.card.expiry_month", &card.expiry_month);
+        }
+        for (field, address) in [
+            ("billing_address.country", &self.billing_address),
+            ("delivery_address.country", &self.delivery_address),
+        ] {
+            if let Some(country) = address.as_ref().and_then(|a| a.country.as_deref()) {
+                validator.country_code(field, country);
+            }
+        }
+        for split in self.splits.iter().flatten() {
+            let currency = split.amount.as_ref().and_then(|a| a.currency.as_ref());
+            validator.same_currency("splits.amount.currency", &self.amount, currency);
+        }
+        validator.finish()
+    }
 }
 
 impl Card {
     /// Create a new card with the basic required information.
     #[must_use]
     pub fn new(
-        number: impl Into<String>,
+        number: impl Into<SecretString>,
         expiry_month: impl Into<String>,
         expiry_year: impl Into<String>,
-        cvc: impl Into<String>,
+        cvc: impl Into<SecretString>,
     ) -> Self {
         Self {
             number: number.into(),
@@ -626,7 +1053,93 @@ impl RecurringType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use adyen_core::{Amount, Currency};
+    use crate::types::splits::SplitType;
+    use adyen_core::{AdyenError, Amount, Currency};
+
+    #[test]
+    fn test_payment_request_build_validated() {
+        let builder = || {
+            PaymentRequest::builder()
+                .amount(Amount::from_major_units(100, Currency::EUR))
+                .merchant_account("TestMerchant")
+                .reference("Order-12345")
+        };
+
+        let request = builder()
+            .card(Card::new("4111111111111111", "03", "2030", "737"))
+            .shopper_email("shopper@example.com")
+            .build_validated()
+            .unwrap();
+        assert!(request.validate().is_ok());
+
+        let error = builder()
+            .card(Card::new("4111111111111111", "00", "2030", "737"))
+            .shopper_email("shopper@")
+            .return_url("data:text/html,checkout")
+            .build_validated()
+            .unwrap_err();
+        let AdyenError::Validation(errors) = error else {
+            panic!("expected validation errors, got {error:?}");
+        };
+        let mut fields: Vec<_> = errors.field_errors().into_keys().collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            [
+                "payment_method.card.expiry_month",
+                "return_url",
+                "shopper_email"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_installments_serialization() {
+        let installments = Installments::new(6)
+            .with_plan(InstallmentPlan::BuyNowPayLater)
+            .with_extra(1);
+        assert_eq!(
+            serde_json::to_value(installments).unwrap(),
+            serde_json::json!({"value": 6, "plan": "buynow_paylater", "extra": 1})
+        );
+        assert_eq!(
+            serde_json::to_value(Installments::new(3)).unwrap(),
+            serde_json::json!({"value": 3})
+        );
+
+        let parsed: Installments =
+            serde_json::from_str(r#"{"value": 3, "plan": "interes_refund_prctg"}"#).unwrap();
+        assert_eq!(parsed.plan, Some(InstallmentPlan::InterestRefundPercentage));
+    }
+
+    #[test]
+    fn test_payment_request_with_enhanced_data() {
+        use adyen_core::additional_data::{AirlineLeg, EnhancedSchemeItem, LodgingRoom};
+
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(45000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Trip-1")
+            .card(Card::new("4111111111111111", "03", "2030", "737"))
+            .airline_data(
+                &AirlineData::new("JOHN/DOE").with_leg(AirlineLeg::new("KL", "AMS", "JFK")),
+            )
+            .lodging_data(&LodgingData::new().with_room(LodgingRoom::new(15000, 2)))
+            .enhanced_scheme_data(
+                &EnhancedSchemeData::new().with_item(EnhancedSchemeItem::new("Taxi", 1, 3000)),
+            )
+            .build()
+            .unwrap();
+
+        let additional_data = request.additional_data.unwrap();
+        assert_eq!(additional_data["airline.passenger_name"], "JOHN/DOE");
+        assert_eq!(additional_data["airline.leg1.destination_code"], "JFK");
+        assert_eq!(additional_data["lodging.room1.numberOfNights"], "2");
+        assert_eq!(
+            additional_data["enhancedSchemeData.itemDetailLine1.totalAmount"],
+            "3000"
+        );
+    }
 
     #[test]
     fn test_payment_request_builder() {
@@ -645,7 +1158,10 @@ mod tests {
         assert_eq!(request.amount, amount);
         assert_eq!(request.merchant_account, "TestMerchant");
         assert_eq!(request.reference, "Order-12345");
-        assert_eq!(request.country_code, Some("NL".to_string()));
+        assert_eq!(
+            request.country_code.as_ref().map(CountryCode::as_str),
+            Some("NL")
+        );
 
         if let PaymentMethod::Card { card: request_card } = request.payment_method {
             assert_eq!(request_card, card);
@@ -654,15 +1170,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_idempotency_key_not_serialized() {
+        let key = adyen_core::RequestId::from_string("order-12345-auth").unwrap();
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_major_units(100, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .card(Card::new("4111111111111111", "12", "2025", "123"))
+            .idempotency_key(key.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(request.idempotency_key, Some(key));
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("idempotencyKey").is_none());
+    }
+
+    #[test]
+    fn test_line_items_sent_as_open_invoice_data() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(14100, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .card(Card::new("4111111111111111", "12", "2025", "123"))
+            .additional_data("customKey", "customValue")
+            .line_item(LineItem::new("Shoes", 1, 12100).with_tax(2100, 2100))
+            .line_item(LineItem::new("Socks", 2, 1000))
+            .build()
+            .unwrap();
+
+        let data = request.additional_data.unwrap();
+        assert_eq!(data["customKey"], "customValue");
+        assert_eq!(data["openinvoicedata.numberOfLines"], "2");
+        assert_eq!(data["openinvoicedata.line1.itemAmount"], "10000");
+        assert_eq!(data["openinvoicedata.line2.numberOfItems"], "2");
+    }
+
+    #[test]
+    fn test_payment_request_with_splits() {
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_minor_units(10000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .card(Card::new("4111111111111111", "12", "2025", "123"))
+            .split(Split::new(SplitType::MarketPlace, 9000).with_account("151272963"))
+            .split(Split::commission(1000))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["splits"][0]["type"], "MarketPlace");
+        assert_eq!(json["splits"][0]["account"], "151272963");
+        assert_eq!(json["splits"][1]["amount"]["value"], 1000);
+    }
+
+    #[test]
+    fn test_payment_request_with_risk_data() {
+        let risk_data = RiskData::builder()
+            .client_data("DpqwU4zEdN0050000000000000")
+            .custom_field("loyaltyTier", "gold")
+            .fraud_offset(-20)
+            .profile_reference("RiskProfile-1")
+            .build();
+
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_major_units(100, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("Order-12345")
+            .card(Card::new("4111111111111111", "12", "2025", "123"))
+            .risk_data(risk_data)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["fraudOffset"], -20);
+        assert_eq!(json["deviceFingerprint"], "DpqwU4zEdN0050000000000000");
+        assert_eq!(json["additionalData"]["riskdata.loyaltyTier"], "gold");
+        assert_eq!(
+            json["additionalData"]["riskdata.riskProfileReferenceId"],
+            "RiskProfile-1"
+        );
+    }
+
     #[test]
     fn test_card_creation() {
         let card = Card::new("4111111111111111", "12", "2025", "123").with_holder_name("John Doe");
 
-        assert_eq!(card.number, "4111111111111111");
+        assert_eq!(card.number.expose_secret(), "4111111111111111");
         assert_eq!(card.expiry_month, "12");
         assert_eq!(card.expiry_year, "2025");
-        assert_eq!(card.cvc, "123");
+        assert_eq!(card.cvc.expose_secret(), "123");
         assert_eq!(card.holder_name, Some("John Doe".to_string()));
+
+        let debug = format!("{card:?}");
+        assert!(!debug.contains("4111111111111111"));
+        assert!(!debug.contains("123"));
     }
 
     #[test]
@@ -692,10 +1295,29 @@ mod tests {
     }
 
     #[test]
-    fn test_payment_request_missing_required_fields() {
-        assert!(PaymentRequest::builder().build().is_err());
-
-        let amount = Amount::from_major_units(100, Currency::EUR);
-        assert!(PaymentRequest::builder().amount(amount).build().is_err());
+    fn test_payment_result_fraud_breakdown() {
+        let result: PaymentResult = serde_json::from_str(
+            r#"{
+                "resultCode": "Authorised",
+                "pspReference": "8515131751004933",
+                "fraudResult": {
+                    "accountScore": 50,
+                    "results": [
+                        {"FraudCheckResult": {"accountScore": 0, "checkId": 2, "name": "CardChunkUsage"}},
+                        {"FraudCheckResult": {"accountScore": 50, "checkId": 20, "name": "AVSAuthResultCheck"}}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let fraud_result = result.fraud_result.unwrap();
+        let triggered: Vec<_> = fraud_result.triggered_checks().collect();
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(
+            triggered[0].name,
+            crate::types::fraud::FraudCheckName::AvsAuthResultCheck
+        );
+        assert_eq!(fraud_result.scores_by_check().len(), 2);
     }
 }
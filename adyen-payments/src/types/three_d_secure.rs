@@ -1,10 +1,16 @@
 //! 3D Secure authentication types for Classic Payments API.
 
-use adyen_core::{AdyenError, Amount, Result};
+use adyen_core::builder::Missing;
+use adyen_core::{Amount, PspReference};
 use serde::{Deserialize, Serialize};
 
 /// Request for 3D Secure 1.0 authentication.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentRequest3d {
     /// The merchant account identifier.
@@ -27,12 +33,18 @@ pub struct PaymentRequest3d {
 
 /// Request for 3D Secure 2.0 authentication.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentRequest3ds2 {
     /// The merchant account identifier.
     pub merchant_account: String,
 
     /// The 3D Secure 2.0 result data.
+    #[serde(rename = "threeDS2Result")]
     pub three_ds2_result: ThreeDS2Result,
 
     /// Browser information from the initial payment request.
@@ -46,6 +58,11 @@ pub struct PaymentRequest3ds2 {
 
 /// 3D Secure data for payment requests.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDSecureData {
     /// The authentication method.
@@ -79,6 +96,11 @@ pub struct ThreeDSecureData {
 
 /// 3D Secure 2.0 request data.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDS2RequestData {
     /// The device channel (browser, app, 3ri).
@@ -114,6 +136,11 @@ pub struct ThreeDS2RequestData {
 
 /// Device channel for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "lowercase")]
 pub enum DeviceChannel {
     /// Browser-based authentication.
@@ -123,10 +150,18 @@ pub enum DeviceChannel {
     /// 3-RI (3DS Requestor Initiated) authentication.
     #[serde(rename = "3RI")]
     ThreeRi,
+    /// A device channel not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Account information for 3D Secure 2.0 risk analysis.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AcctInfo {
     /// Account age indicator.
@@ -168,6 +203,11 @@ pub struct AcctInfo {
 
 /// Account age indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum AccountAgeIndicator {
     /// No account (guest checkout).
@@ -180,10 +220,18 @@ pub enum AccountAgeIndicator {
     From30To60Days,
     /// More than 60 days.
     MoreThan60Days,
+    /// An account age indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Account change indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum AccountChangeIndicator {
     /// Changed during this transaction.
@@ -194,10 +242,18 @@ pub enum AccountChangeIndicator {
     From30To60Days,
     /// More than 60 days.
     MoreThan60Days,
+    /// An account change indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Password change indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PasswordChangeIndicator {
     /// No change.
@@ -210,10 +266,18 @@ pub enum PasswordChangeIndicator {
     From30To60Days,
     /// More than 60 days.
     MoreThan60Days,
+    /// A password change indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Payment account age indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PaymentAccountAgeIndicator {
     /// No account.
@@ -226,10 +290,18 @@ pub enum PaymentAccountAgeIndicator {
     From30To60Days,
     /// More than 60 days.
     MoreThan60Days,
+    /// A payment account age indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Shipping address usage indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum ShippingAddressUsageIndicator {
     /// First time used.
@@ -240,20 +312,36 @@ pub enum ShippingAddressUsageIndicator {
     From30To60Days,
     /// More than 60 days.
     MoreThan60Days,
+    /// A shipping address usage indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Suspicious account activity indicators.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum SuspiciousAccountActivity {
     /// No suspicious activity.
     NoSuspiciousActivity,
     /// Suspicious activity observed.
     SuspiciousActivityObserved,
+    /// A suspicious account activity value not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Merchant risk indicator for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct MerchantRiskIndicator {
     /// Shipping indicator.
@@ -287,6 +375,11 @@ pub struct MerchantRiskIndicator {
 
 /// Shipping indicators for 3D Secure 2.0.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum ShippingIndicator {
     /// Ship to cardholder billing address.
@@ -307,6 +400,11 @@ pub enum ShippingIndicator {
 
 /// Delivery timeframe indicators.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum DeliveryTimeframe {
     /// Electronic delivery.
@@ -317,30 +415,54 @@ pub enum DeliveryTimeframe {
     OvernightShipping,
     /// Two day or more shipping.
     TwoDayOrMoreShipping,
+    /// A delivery timeframe not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Reorder items indicators.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum ReorderItemsIndicator {
     /// First time ordered.
     FirstTimeOrdered,
     /// Reordered.
     Reordered,
+    /// A reorder indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Pre-order purchase indicators.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PreOrderPurchaseIndicator {
     /// Merchandise available.
     MerchandiseAvailable,
     /// Future availability.
     FutureAvailability,
+    /// A pre-order indicator not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// 3D Secure 2.0 result data.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDS2Result {
     /// The challenge result.
@@ -348,7 +470,7 @@ pub struct ThreeDS2Result {
     pub cres: Option<String>,
 
     /// The directory server transaction ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dsTransID", skip_serializing_if = "Option::is_none")]
     pub ds_trans_id: Option<String>,
 
     /// The message version.
@@ -356,7 +478,10 @@ pub struct ThreeDS2Result {
     pub message_version: Option<String>,
 
     /// The three DS server transaction ID.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "threeDSServerTransID",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub three_ds_server_trans_id: Option<String>,
 
     /// The transaction status.
@@ -378,30 +503,93 @@ pub struct ThreeDS2Result {
 
 /// Authentication result request for 3D Secure.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthenticationResultRequest {
     /// The merchant account identifier.
     pub merchant_account: String,
 
     /// The PSP reference of the initial payment.
-    pub psp_reference: String,
+    pub psp_reference: PspReference,
+}
+
+impl AuthenticationResultRequest {
+    /// Create a request for the authentication result of a payment.
+    #[must_use]
+    pub fn new(merchant_account: impl Into<String>, psp_reference: PspReference) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            psp_reference,
+        }
+    }
 }
 
 /// Authentication result response from 3D Secure.
+///
+/// Holds the result of whichever 3D Secure version authenticated the
+/// payment; [`cavv`](Self::cavv) and [`eci`](Self::eci) read the values
+/// from either.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthenticationResultResponse {
     /// The 3D Secure 1.0 result.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "threeDS1Result", skip_serializing_if = "Option::is_none")]
     pub three_ds1_result: Option<ThreeDS1Result>,
 
     /// The 3D Secure 2.0 result.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "threeDS2Result", skip_serializing_if = "Option::is_none")]
     pub three_ds2_result: Option<ThreeDS2Result>,
 }
 
+impl AuthenticationResultResponse {
+    /// Get the cardholder authentication verification value.
+    ///
+    /// For 3D Secure 2.0 this is the authentication value.
+    #[must_use]
+    pub fn cavv(&self) -> Option<&str> {
+        self.three_ds1_result
+            .as_ref()
+            .and_then(|result| result.cavv.as_deref())
+            .or_else(|| {
+                self.three_ds2_result.as_ref().and_then(|result| {
+                    result
+                        .authentication_value
+                        .as_deref()
+                        .or(result.cavv.as_deref())
+                })
+            })
+    }
+
+    /// Get the electronic commerce indicator.
+    #[must_use]
+    pub fn eci(&self) -> Option<&str> {
+        self.three_ds1_result
+            .as_ref()
+            .and_then(|result| result.eci.as_deref())
+            .or_else(|| {
+                self.three_ds2_result
+                    .as_ref()
+                    .and_then(|result| result.eci.as_deref())
+            })
+    }
+}
+
 /// 3D Secure 1.0 result data.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ThreeDS1Result {
     /// The cavv (Cardholder Authentication Verification Value).
@@ -430,12 +618,15 @@ pub struct ThreeDS1Result {
 }
 
 /// Builder for 3D Secure 1.0 requests.
+///
+/// `build` is only available once the required merchant account, MD and PA
+/// response are set.
 #[derive(Debug, Clone, Default)]
-pub struct PaymentRequest3dBuilder {
-    merchant_account: Option<String>,
+pub struct PaymentRequest3dBuilder<M = Missing, D = Missing, P = Missing> {
+    merchant_account: M,
     browser_info: Option<super::payment::BrowserInfo>,
-    md: Option<String>,
-    pa_response: Option<String>,
+    md: D,
+    pa_response: P,
     shopper_ip: Option<String>,
 }
 
@@ -445,12 +636,22 @@ impl PaymentRequest3dBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M, D, P> PaymentRequest3dBuilder<M, D, P> {
     /// Set the merchant account.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentRequest3dBuilder<String, D, P> {
+        PaymentRequest3dBuilder {
+            merchant_account: merchant_account.into(),
+            browser_info: self.browser_info,
+            md: self.md,
+            pa_response: self.pa_response,
+            shopper_ip: self.shopper_ip,
+        }
     }
 
     /// Set browser information.
@@ -462,16 +663,29 @@ impl PaymentRequest3dBuilder {
 
     /// Set the MD parameter.
     #[must_use]
-    pub fn md(mut self, md: impl Into<String>) -> Self {
-        self.md = Some(md.into());
-        self
+    pub fn md(self, md: impl Into<String>) -> PaymentRequest3dBuilder<M, String, P> {
+        PaymentRequest3dBuilder {
+            merchant_account: self.merchant_account,
+            browser_info: self.browser_info,
+            md: md.into(),
+            pa_response: self.pa_response,
+            shopper_ip: self.shopper_ip,
+        }
     }
 
     /// Set the `PaRes` parameter.
     #[must_use]
-    pub fn pa_response(mut self, pa_response: impl Into<String>) -> Self {
-        self.pa_response = Some(pa_response.into());
-        self
+    pub fn pa_response(
+        self,
+        pa_response: impl Into<String>,
+    ) -> PaymentRequest3dBuilder<M, D, String> {
+        PaymentRequest3dBuilder {
+            merchant_account: self.merchant_account,
+            browser_info: self.browser_info,
+            md: self.md,
+            pa_response: pa_response.into(),
+            shopper_ip: self.shopper_ip,
+        }
     }
 
     /// Set the shopper IP.
@@ -480,30 +694,19 @@ impl PaymentRequest3dBuilder {
         self.shopper_ip = Some(shopper_ip.into());
         self
     }
+}
 
+impl PaymentRequest3dBuilder<String, String, String> {
     /// Build the 3D Secure 1.0 request.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if required fields are not set.
-    pub fn build(self) -> Result<PaymentRequest3d> {
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-        let md = self
-            .md
-            .ok_or_else(|| AdyenError::config("md is required"))?;
-        let pa_response = self
-            .pa_response
-            .ok_or_else(|| AdyenError::config("pa_response is required"))?;
-
-        Ok(PaymentRequest3d {
-            merchant_account,
+    #[must_use]
+    pub fn build(self) -> PaymentRequest3d {
+        PaymentRequest3d {
+            merchant_account: self.merchant_account,
             browser_info: self.browser_info,
-            md,
-            pa_response,
+            md: self.md,
+            pa_response: self.pa_response,
             shopper_ip: self.shopper_ip,
-        })
+        }
     }
 }
 
@@ -516,10 +719,13 @@ impl PaymentRequest3d {
 }
 
 /// Builder for 3D Secure 2.0 requests.
+///
+/// `build` is only available once the required merchant account and 3DS2 result
+/// are set.
 #[derive(Debug, Clone, Default)]
-pub struct PaymentRequest3ds2Builder {
-    merchant_account: Option<String>,
-    three_ds2_result: Option<ThreeDS2Result>,
+pub struct PaymentRequest3ds2Builder<M = Missing, R = Missing> {
+    merchant_account: M,
+    three_ds2_result: R,
     browser_info: Option<super::payment::BrowserInfo>,
     shopper_ip: Option<String>,
 }
@@ -530,19 +736,35 @@ impl PaymentRequest3ds2Builder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M, R> PaymentRequest3ds2Builder<M, R> {
     /// Set the merchant account.
     #[must_use]
-    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<String>,
+    ) -> PaymentRequest3ds2Builder<String, R> {
+        PaymentRequest3ds2Builder {
+            merchant_account: merchant_account.into(),
+            three_ds2_result: self.three_ds2_result,
+            browser_info: self.browser_info,
+            shopper_ip: self.shopper_ip,
+        }
     }
 
     /// Set the 3D Secure 2.0 result.
     #[must_use]
-    pub fn three_ds2_result(mut self, result: ThreeDS2Result) -> Self {
-        self.three_ds2_result = Some(result);
-        self
+    pub fn three_ds2_result(
+        self,
+        result: ThreeDS2Result,
+    ) -> PaymentRequest3ds2Builder<M, ThreeDS2Result> {
+        PaymentRequest3ds2Builder {
+            merchant_account: self.merchant_account,
+            three_ds2_result: result,
+            browser_info: self.browser_info,
+            shopper_ip: self.shopper_ip,
+        }
     }
 
     /// Set browser information.
@@ -558,26 +780,18 @@ impl PaymentRequest3ds2Builder {
         self.shopper_ip = Some(shopper_ip.into());
         self
     }
+}
 
+impl PaymentRequest3ds2Builder<String, ThreeDS2Result> {
     /// Build the 3D Secure 2.0 request.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if required fields are not set.
-    pub fn build(self) -> Result<PaymentRequest3ds2> {
-        let merchant_account = self
-            .merchant_account
-            .ok_or_else(|| AdyenError::config("merchant_account is required"))?;
-        let three_ds2_result = self
-            .three_ds2_result
-            .ok_or_else(|| AdyenError::config("three_ds2_result is required"))?;
-
-        Ok(PaymentRequest3ds2 {
-            merchant_account,
-            three_ds2_result,
+    #[must_use]
+    pub fn build(self) -> PaymentRequest3ds2 {
+        PaymentRequest3ds2 {
+            merchant_account: self.merchant_account,
+            three_ds2_result: self.three_ds2_result,
             browser_info: self.browser_info,
             shopper_ip: self.shopper_ip,
-        })
+        }
     }
 }
 
@@ -600,8 +814,7 @@ mod tests {
             .md("test_md_value")
             .pa_response("test_pares")
             .shopper_ip("192.168.1.1")
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(request.merchant_account, "TestMerchant");
         assert_eq!(request.md, "test_md_value");
@@ -626,12 +839,36 @@ mod tests {
             .merchant_account("TestMerchant")
             .three_ds2_result(result.clone())
             .shopper_ip("192.168.1.1")
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(request.merchant_account, "TestMerchant");
         assert_eq!(request.three_ds2_result, result);
         assert_eq!(request.shopper_ip, Some("192.168.1.1".to_string()));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["threeDS2Result"]["dsTransID"], "test_ds_trans_id");
+        assert_eq!(
+            json["threeDS2Result"]["threeDSServerTransID"],
+            "test_server_trans_id"
+        );
+    }
+
+    #[test]
+    fn test_authentication_result_response() {
+        let response: AuthenticationResultResponse = serde_json::from_str(
+            r#"{"threeDS2Result":{"authenticationValue":"AQIDBAUGBwgJCgsMDQ4PEBESExQ=","eci":"05","transStatus":"Y"}}"#,
+        )
+        .unwrap();
+        assert!(response.three_ds1_result.is_none());
+        assert_eq!(response.cavv(), Some("AQIDBAUGBwgJCgsMDQ4PEBESExQ="));
+        assert_eq!(response.eci(), Some("05"));
+
+        let response: AuthenticationResultResponse = serde_json::from_str(
+            r#"{"threeDS1Result":{"cavv":"AAABBBCCC=","cavvAlgorithm":"3","eci":"02","xid":"ODgxNDc2MDg2MDExODk5MAAAAAA="}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.cavv(), Some("AAABBBCCC="));
+        assert_eq!(response.eci(), Some("02"));
     }
 
     #[test]
@@ -1,13 +1,14 @@
 //! Integration tests for Classic Payments API flows.
 
-use adyen_core::{Amount, Config, ConfigBuilder, Currency, Environment};
+use adyen_core::{Amount, Config, ConfigBuilder, CountryCode, Currency, Environment, Locale};
 use adyen_payments::types::fraud::{
     DeviceFingerprint, FraudAction, FraudCheckResult, FraudResult, RiskData, RiskLevel,
 };
 use adyen_payments::types::BrowserInfo;
 use adyen_payments::{
-    CancelOrRefundRequest, CancelRequest, CaptureRequest, Card, ModificationsApi, PaymentRequest,
-    PaymentRequest3d, PaymentsApi, RefundRequest,
+    AuthenticationResultRequest, CancelOrRefundRequest, CancelRequest, CaptureRequest, Card,
+    ModificationsApi, PaymentRequest, PaymentRequest3d, PaymentRequest3ds2, PaymentResultCode,
+    PaymentsApi, RefundRequest, ThreeDS2Result, ThreeDSResultRequest,
 };
 use std::collections::HashMap;
 
@@ -24,10 +25,10 @@ fn create_test_config() -> Config {
 /// Create a test card for payment requests.
 fn create_test_card() -> Card {
     Card {
-        number: "4111111111111111".to_string(),
+        number: "4111111111111111".into(),
         expiry_month: "12".to_string(),
         expiry_year: "2025".to_string(),
-        cvc: "123".to_string(),
+        cvc: "123".into(),
         holder_name: None,
     }
 }
@@ -66,11 +67,20 @@ mod payment_tests {
             .unwrap();
 
         assert_eq!(payment_request.amount.minor_units(), 25000);
-        assert_eq!(payment_request.amount.currency(), Currency::USD);
+        assert_eq!(payment_request.amount.currency(), &Currency::USD);
         assert_eq!(payment_request.merchant_account, "TestMerchantAccount");
         assert_eq!(payment_request.reference, "complete-payment-001");
-        assert_eq!(payment_request.country_code.as_deref(), Some("US"));
-        assert_eq!(payment_request.shopper_locale.as_deref(), Some("en_US"));
+        assert_eq!(
+            payment_request
+                .country_code
+                .as_ref()
+                .map(CountryCode::as_str),
+            Some("US")
+        );
+        assert_eq!(
+            payment_request.shopper_locale.as_ref().map(Locale::as_str),
+            Some("en_US")
+        );
         assert_eq!(
             payment_request.shopper_email.as_deref(),
             Some("test@example.com")
@@ -94,17 +104,17 @@ mod payment_tests {
     #[test]
     fn test_card_creation_and_validation() {
         let card = create_test_card();
-        assert_eq!(card.number, "4111111111111111");
+        assert_eq!(card.number.expose_secret(), "4111111111111111");
         assert_eq!(card.expiry_month, "12");
         assert_eq!(card.expiry_year, "2025");
-        assert_eq!(card.cvc, "123");
+        assert_eq!(card.cvc.expose_secret(), "123");
         assert_eq!(card.holder_name, None);
 
         let card_with_holder = Card {
-            number: "4111111111111111".to_string(),
+            number: "4111111111111111".into(),
             expiry_month: "12".to_string(),
             expiry_year: "2025".to_string(),
-            cvc: "123".to_string(),
+            cvc: "123".into(),
             holder_name: Some("John Doe".to_string()),
         };
         assert_eq!(card_with_holder.holder_name.as_deref(), Some("John Doe"));
@@ -149,8 +159,7 @@ mod three_d_secure_tests {
             .browser_info(browser_info)
             .md("md_value_from_issuer")
             .pa_response("pa_response_from_issuer")
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(request.merchant_account, "TestMerchantAccount");
         assert_eq!(request.md, "md_value_from_issuer");
@@ -179,7 +188,7 @@ mod modification_tests {
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchantAccount");
         assert_eq!(request.modification_amount.minor_units(), 7500);
-        assert_eq!(request.modification_amount.currency(), Currency::EUR);
+        assert_eq!(request.modification_amount.currency(), &Currency::EUR);
         assert_eq!(request.original_reference.as_ref(), "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("capture-001"));
 
@@ -231,7 +240,7 @@ mod modification_tests {
 
         assert_eq!(request.merchant_account.as_ref(), "TestMerchantAccount");
         assert_eq!(request.modification_amount.minor_units(), 5000);
-        assert_eq!(request.modification_amount.currency(), Currency::USD);
+        assert_eq!(request.modification_amount.currency(), &Currency::USD);
         assert_eq!(request.original_reference.as_ref(), "8515131751004933");
         assert_eq!(request.reference.as_deref(), Some("refund-001"));
 
@@ -264,24 +273,12 @@ mod modification_tests {
 
     #[test]
     fn test_modification_request_validation() {
-        // Test missing merchant account
-        let result = CaptureRequest::builder()
-            .modification_amount(Amount::from_major_units(100, Currency::EUR))
-            .original_reference("8515131751004933")
-            .build();
-        assert!(result.is_err());
-
-        // Test missing amount
-        let result = CaptureRequest::builder()
-            .merchant_account("TestMerchantAccount")
-            .original_reference("8515131751004933")
-            .build();
-        assert!(result.is_err());
-
-        // Test missing original reference
+        // Missing required fields are compile errors; the PSP reference
+        // format is still checked by `build`.
         let result = CaptureRequest::builder()
             .merchant_account("TestMerchantAccount")
             .modification_amount(Amount::from_major_units(100, Currency::EUR))
+            .original_reference("not a PSP reference")
             .build();
         assert!(result.is_err());
     }
@@ -342,16 +339,16 @@ mod fraud_detection_tests {
             name: "CyberSourceFraud".into(),
             check_id: 67890,
             account_score: 60,
-            risk_level: RiskLevel::Medium,
-            action: FraudAction::Review,
+            risk_level: Some(RiskLevel::Medium),
+            action: Some(FraudAction::Review),
             metadata: Some(metadata),
         };
 
         assert_eq!(fraud_check.name.as_ref(), "CyberSourceFraud");
         assert_eq!(fraud_check.check_id, 67890);
         assert_eq!(fraud_check.account_score, 60);
-        assert_eq!(fraud_check.risk_level, RiskLevel::Medium);
-        assert_eq!(fraud_check.action, FraudAction::Review);
+        assert_eq!(fraud_check.risk_level, Some(RiskLevel::Medium));
+        assert_eq!(fraud_check.action, Some(FraudAction::Review));
 
         let metadata = fraud_check.metadata.unwrap();
         assert_eq!(
@@ -511,8 +508,7 @@ mod end_to_end_flow_tests {
             .browser_info(browser_info)
             .md("md_from_initial_auth")
             .pa_response("pa_response_from_issuer")
-            .build()
-            .unwrap();
+            .build();
 
         assert_eq!(three_ds_request.merchant_account, "TestMerchantAccount");
         assert_eq!(three_ds_request.md, "md_from_initial_auth");
@@ -540,3 +536,345 @@ mod end_to_end_flow_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_core::{ApplicationInfo, RequestId};
+
+    fn mock_config(mock: &MockTransport) -> Config {
+        ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_authorise_then_capture_against_mock() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise")
+                .header("Idempotency-Key", "order-001-auth")
+                .body_includes(serde_json::json!({"reference": "test-payment-001"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "resultCode": "Authorised",
+                        "pspReference": "8515131751004933"
+                    }),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/capture")
+                .body_includes(serde_json::json!({"originalReference": "8515131751004933"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "pspReference": "8825408195409505",
+                        "response": "[capture-received]"
+                    }),
+                ))
+                .times(1),
+        );
+
+        let config = mock_config(&mock);
+        let payments = PaymentsApi::new(config.clone()).unwrap();
+        let modifications = ModificationsApi::new(config).unwrap();
+
+        let mut payment_request = create_basic_payment_request();
+        payment_request.idempotency_key = Some(RequestId::from_string("order-001-auth").unwrap());
+        let result = payments.authorise(&payment_request).await.unwrap();
+        let psp_reference = result.psp_reference.unwrap();
+
+        let capture = CaptureRequest::builder()
+            .merchant_account("TestMerchantAccount")
+            .modification_amount(Amount::from_major_units(100, Currency::EUR))
+            .original_reference(psp_reference.as_str())
+            .build()
+            .unwrap();
+        let capture_result = modifications.capture(&capture).await.unwrap();
+
+        assert_eq!(capture_result.psp_reference.as_ref(), "8825408195409505");
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_configured_application_info_sent_with_payments() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise")
+                .body_includes(serde_json::json!({
+                    "applicationInfo": {
+                        "merchantApplication": {"name": "pos", "version": "1.0"},
+                        "externalPlatform": {"name": "Magento", "version": "2.4.6"}
+                    }
+                }))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({"resultCode": "Authorised"}),
+                ))
+                .times(1),
+        );
+
+        let config = ConfigBuilder::new()
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .application_info(
+                ApplicationInfo::default()
+                    .with_merchant_application("my-shop", "2.1.0")
+                    .with_external_platform("Magento", "2.4.6", None),
+            )
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        let payments = PaymentsApi::new(config).unwrap();
+
+        // The merchant application set on the request wins.
+        let mut request = create_basic_payment_request();
+        request.application_info =
+            Some(ApplicationInfo::default().with_merchant_application("pos", "1.0"));
+        payments.authorise(&request).await.unwrap();
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_authorise_surfaces_api_errors() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise").respond_with(
+                MockResponse::json(
+                    422,
+                    serde_json::json!({
+                        "status": 422,
+                        "errorCode": "14_012",
+                        "message": "The provided SDK token could not be parsed.",
+                        "errorType": "validation"
+                    }),
+                ),
+            ),
+        );
+
+        let payments = PaymentsApi::new(mock_config(&mock)).unwrap();
+        let error = payments
+            .authorise(&create_basic_payment_request())
+            .await
+            .unwrap_err();
+
+        assert!(error.is_client_error());
+        assert_eq!(error.status_code(), Some(422));
+    }
+
+    #[tokio::test]
+    async fn test_3ds_endpoints_against_mock() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise3d")
+                .body_includes(serde_json::json!({
+                    "md": "md_value_from_issuer",
+                    "paResponse": "pa_response_from_issuer"
+                }))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "resultCode": "Authorised",
+                        "pspReference": "8515131751004933"
+                    }),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise3ds2")
+                .body_includes(serde_json::json!({"threeDS2Result": {"transStatus": "Y"}}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "resultCode": "Authorised",
+                        "pspReference": "8515131751004934"
+                    }),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/retrieve3ds2Result")
+                .body_includes(serde_json::json!({"pspReference": "8515131751004934"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "pspReference": "8515131751004934",
+                        "threeDS2Result": {"transStatus": "Y", "eci": "05"}
+                    }),
+                ))
+                .times(1),
+        );
+
+        let payments = PaymentsApi::new(mock_config(&mock)).unwrap();
+
+        let request_3d = PaymentRequest3d::builder()
+            .merchant_account("TestMerchantAccount")
+            .md("md_value_from_issuer")
+            .pa_response("pa_response_from_issuer")
+            .build();
+        let result = payments.authorise_3d(&request_3d).await.unwrap();
+        assert_eq!(result.result_code, PaymentResultCode::Authorised);
+
+        let request_3ds2 = PaymentRequest3ds2::builder()
+            .merchant_account("TestMerchantAccount")
+            .three_ds2_result(ThreeDS2Result {
+                trans_status: Some("Y".to_string()),
+                ..ThreeDS2Result::default()
+            })
+            .build();
+        let result = payments.authorise_3ds2(&request_3ds2).await.unwrap();
+        let psp_reference = result.psp_reference.unwrap();
+
+        let three_ds_result = payments
+            .retrieve_3ds2_result(&ThreeDSResultRequest::new(
+                "TestMerchantAccount",
+                psp_reference,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            three_ds_result.three_ds2_result.unwrap()["eci"],
+            serde_json::json!("05")
+        );
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_get_authentication_result_against_mock() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/getAuthenticationResult")
+                .body_includes(serde_json::json!({
+                    "merchantAccount": "TestMerchantAccount",
+                    "pspReference": "8515131751004933"
+                }))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "threeDS2Result": {
+                            "authenticationValue": "AQIDBAUGBwgJCgsMDQ4PEBESExQ=",
+                            "eci": "05",
+                            "transStatus": "Y"
+                        }
+                    }),
+                ))
+                .times(1),
+        );
+
+        let payments = PaymentsApi::new(mock_config(&mock)).unwrap();
+        let request = AuthenticationResultRequest::new(
+            "TestMerchantAccount",
+            adyen_core::PspReference::new("8515131751004933").unwrap(),
+        );
+        let result = payments.get_authentication_result(&request).await.unwrap();
+
+        assert_eq!(result.cavv(), Some("AQIDBAUGBwgJCgsMDQ4PEBESExQ="));
+        assert_eq!(result.eci(), Some("05"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_live_environment_uses_url_prefix() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise").respond_with(
+                MockResponse::json(200, serde_json::json!({"resultCode": "Authorised"})),
+            ),
+        );
+
+        let config = ConfigBuilder::new()
+            .environment(Environment::live_with_prefix("1797a841fbb37ca7-AdyenDemo").unwrap())
+            .api_key("live_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        PaymentsApi::new(config)
+            .unwrap()
+            .authorise(&create_basic_payment_request())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            mock.received_requests()[0].url,
+            "https://1797a841fbb37ca7-AdyenDemo-pal-live.adyenpayments.com/pal/servlet/Payment/v68/authorise"
+        );
+        mock.verify();
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+
+    #[test]
+    fn test_authorise_blocking() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "resultCode": "Authorised",
+                        "pspReference": "8515131751004933"
+                    }),
+                ),
+            ),
+        );
+
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        let payments = PaymentsApi::new(config).unwrap();
+
+        let result = payments
+            .authorise_blocking(&create_basic_payment_request())
+            .unwrap();
+        assert_eq!(result.psp_reference.unwrap(), "8515131751004933");
+        mock.verify();
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_tests {
+    use adyen_payments::types::payment::PaymentResult;
+    use adyen_payments::PaymentResultCode;
+
+    #[test]
+    fn test_payment_result_archive_round_trip() {
+        let result: PaymentResult = serde_json::from_value(serde_json::json!({
+            "resultCode": "Authorised",
+            "pspReference": "8515131751004933",
+            "merchantReference": "Order-12345",
+            "fraudResult": {"accountScore": 12},
+            "additionalData": {"cardSummary": "1111"}
+        }))
+        .unwrap();
+
+        let bytes = rkyv::to_bytes::<_, 1024>(&result).unwrap();
+        let archived = rkyv::check_archived_root::<PaymentResult>(&bytes).unwrap();
+        assert_eq!(
+            archived.merchant_reference.as_ref().map(|r| r.as_str()),
+            Some("Order-12345")
+        );
+
+        let restored: PaymentResult =
+            rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).unwrap();
+        assert_eq!(restored.result_code, PaymentResultCode::Authorised);
+        assert_eq!(restored.psp_reference, result.psp_reference);
+        assert_eq!(restored.additional_data, result.additional_data);
+        assert_eq!(restored.fraud_result.unwrap().account_score, 12);
+    }
+}
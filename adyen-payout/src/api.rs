@@ -1,7 +1,7 @@
 //! Payout API client implementation.
 
 use crate::types::*;
-use adyen_core::{Client, Config, Result};
+use adyen_core::{AdyenError, Api, Client, Config, Result};
 
 /// Adyen Payout API client.
 ///
@@ -9,6 +9,17 @@ use adyen_core::{Client, Config, Result};
 /// This includes instant payouts, batch processing, review workflows, and comprehensive
 /// status tracking.
 ///
+/// Adyen requires separate API credentials for third-party payouts. Clients
+/// created with [`with_credentials`](Self::with_credentials) send each
+/// request with the credential of its role:
+///
+/// | Role   | Endpoints |
+/// |--------|-----------|
+/// | Store  | `storeDetail`, `storeDetailAndSubmitThirdParty`, `submitThirdParty`, `payout` |
+/// | Review | `confirmThirdParty`, `declineThirdParty` |
+///
+/// [`new`](Self::new) uses the same credential for both roles.
+///
 /// # Example
 ///
 /// ```rust
@@ -28,6 +39,7 @@ use adyen_core::{Client, Config, Result};
 #[derive(Debug, Clone)]
 pub struct PayoutApi {
     client: Client,
+    review_client: Client,
 }
 
 impl PayoutApi {
@@ -37,15 +49,77 @@ impl PayoutApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
-        Ok(Self { client })
+        let client = Client::for_api(config, Api::Payout)?;
+        Ok(Self {
+            review_client: client.clone(),
+            client,
+        })
+    }
+
+    /// Create a new Payout API client from an existing client.
+    ///
+    /// All endpoints use the credentials of `client`; use
+    /// [`with_credentials`](Self::with_credentials) to separate the store
+    /// and review roles.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        let client = client.with_api(Api::Payout);
+        Self {
+            review_client: client.clone(),
+            client,
+        }
     }
 
-    /// Submit a payout request.
+    /// Create a new Payout API client with separate store and review
+    /// credentials.
     ///
-    /// Submits a payout to a shopper's bank account or card. For instant payouts,
-    /// this request needs to be followed by a `/confirmThirdParty` request to finalize the payout.
-    /// For regular payouts, the payout is processed automatically.
+    /// `store_config` holds the "Payout store" credential, used to store
+    /// details and submit payouts. `review_config` holds the "Payout review"
+    /// credential, used to confirm and decline submitted payouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configurations are for different environments,
+    /// or if either underlying HTTP client cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{ConfigBuilder, Environment};
+    /// use adyen_payout::PayoutApi;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let store_config = ConfigBuilder::new()
+    ///     .environment(Environment::test())
+    ///     .api_key("payout_store_api_key")?
+    ///     .build()?;
+    /// let review_config = ConfigBuilder::new()
+    ///     .environment(Environment::test())
+    ///     .api_key("payout_review_api_key")?
+    ///     .build()?;
+    ///
+    /// let payout = PayoutApi::with_credentials(store_config, review_config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_credentials(store_config: Config, review_config: Config) -> Result<Self> {
+        if store_config.environment() != review_config.environment() {
+            return Err(AdyenError::config(
+                "Payout store and review credentials must be for the same environment",
+            ));
+        }
+        Ok(Self {
+            client: Client::for_api(store_config, Api::Payout)?,
+            review_client: Client::for_api(review_config, Api::Payout)?,
+        })
+    }
+
+    /// Submit a payout to stored details.
+    ///
+    /// Submits a payout to the bank account or card stored with
+    /// [`store_detail`](Self::store_detail). The payout must then be confirmed
+    /// with [`confirm`](Self::confirm) or declined with
+    /// [`decline_payout`](Self::decline_payout) using the review credential.
     ///
     /// # Errors
     ///
@@ -54,39 +128,33 @@ impl PayoutApi {
     /// # Example
     ///
     /// ```rust
-    /// use adyen_payout::{PayoutApi, SubmitRequest, PayoutMethodDetails, BankAccount};
+    /// use adyen_payout::{PayoutApi, SubmitThirdPartyRequest};
     /// use adyen_core::{Amount, Currency};
     ///
     /// # async fn example(payout: PayoutApi) -> Result<(), Box<dyn std::error::Error>> {
-    /// let bank_account = BankAccount {
-    ///     account_number: "1234567890".into(),
-    ///     bic: Some("ABNANL2A".into()),
-    ///     country_code: "NL".into(),
-    ///     owner_name: "John Doe".into(),
-    ///     iban: Some("NL91ABNA0417164300".into()),
-    ///     bank_account_type: Some(adyen_payout::BankAccountType::Checking),
-    /// };
-    ///
-    /// let request = SubmitRequest::builder()
+    /// let request = SubmitThirdPartyRequest::builder()
     ///     .amount(Amount::from_minor_units(1000, Currency::EUR))
     ///     .merchant_account("YourMerchantAccount")
     ///     .reference("payout-001")
     ///     .shopper_email("shopper@example.com")
     ///     .shopper_reference("shopper_123")
-    ///     .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
-    ///     .build()?;
+    ///     .selected_recurring_detail_reference("LATEST")
+    ///     .build();
     ///
     /// let response = payout.submit(&request).await?;
     /// println!("Payout submitted with PSP reference: {}", response.psp_reference);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn submit(&self, request: &SubmitRequest) -> Result<SubmitResponse> {
+    pub async fn submit(&self, request: &SubmitThirdPartyRequest) -> Result<SubmitResponse> {
         let url = format!(
             "{}/pal/servlet/Payout/v68/submitThirdParty",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
@@ -94,6 +162,7 @@ impl PayoutApi {
     ///
     /// Confirms (and finalizes) a previously submitted payout. This is required for instant payouts
     /// after the initial `/submitThirdParty` request. Once confirmed, the payout cannot be cancelled.
+    /// Sent with the review credential.
     ///
     /// # Errors
     ///
@@ -118,16 +187,16 @@ impl PayoutApi {
     pub async fn confirm(&self, request: &ConfirmRequest) -> Result<ConfirmResponse> {
         let url = format!(
             "{}/pal/servlet/Payout/v68/confirmThirdParty",
-            self.client.config().environment().classic_api_url()
+            self.review_client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.review_client.post(&url, request).await?;
         Ok(response.data)
     }
 
     /// Decline a payout that requires manual review.
     ///
     /// Declines a payout that was flagged for manual review. This permanently rejects the payout
-    /// and prevents it from being processed.
+    /// and prevents it from being processed. Sent with the review credential.
     ///
     /// # Errors
     ///
@@ -152,9 +221,9 @@ impl PayoutApi {
     pub async fn decline_payout(&self, request: &DeclinePayoutRequest) -> Result<PayoutResponse> {
         let url = format!(
             "{}/pal/servlet/Payout/v68/declineThirdParty",
-            self.client.config().environment().classic_api_url()
+            self.review_client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self.review_client.post(&url, request).await?;
         Ok(response.data)
     }
 
@@ -200,14 +269,18 @@ impl PayoutApi {
             "{}/pal/servlet/Payout/v68/payout",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
-    /// Store payout details and submit for future use.
+    /// Store payout details and submit a payout to them.
     ///
-    /// Stores the payout details and submits the payout. This endpoint combines storing
-    /// shopper details for future payouts and immediate payout processing.
+    /// Combines [`store_detail`](Self::store_detail) and
+    /// [`submit`](Self::submit) in one request. The payout must then be
+    /// confirmed or declined using the review credential.
     ///
     /// # Errors
     ///
@@ -216,7 +289,7 @@ impl PayoutApi {
     /// # Example
     ///
     /// ```rust
-    /// use adyen_payout::{PayoutApi, SubmitRequest, PayoutMethodDetails, BankAccount};
+    /// use adyen_payout::{PayoutApi, StoreDetailRequest, PayoutMethodDetails, BankAccount};
     /// use adyen_core::{Amount, Currency};
     ///
     /// # async fn example(payout: PayoutApi) -> Result<(), Box<dyn std::error::Error>> {
@@ -229,33 +302,39 @@ impl PayoutApi {
     ///     bank_account_type: Some(adyen_payout::BankAccountType::Checking),
     /// };
     ///
-    /// let request = SubmitRequest::builder()
-    ///     .amount(Amount::from_minor_units(1000, Currency::EUR))
+    /// let request = StoreDetailRequest::builder()
     ///     .merchant_account("YourMerchantAccount")
-    ///     .reference("payout-001")
     ///     .shopper_email("shopper@example.com")
     ///     .shopper_reference("shopper_123")
     ///     .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
-    ///     .build()?;
+    ///     .build()
+    ///     .and_submit(Amount::from_minor_units(1000, Currency::EUR), "payout-001");
     ///
     /// let response = payout.store_detail_and_submit(&request).await?;
     /// println!("Payout stored and submitted: {}", response.psp_reference);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn store_detail_and_submit(&self, request: &SubmitRequest) -> Result<SubmitResponse> {
+    pub async fn store_detail_and_submit(
+        &self,
+        request: &StoreDetailAndSubmitRequest,
+    ) -> Result<SubmitResponse> {
         let url = format!(
             "{}/pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
             self.client.config().environment().classic_api_url()
         );
-        let response = self.client.post(&url, request).await?;
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
         Ok(response.data)
     }
 
     /// Store payout details for future use.
     ///
-    /// Stores shopper details for future payout requests without immediately submitting a payout.
-    /// This is useful for setting up payout methods that will be used later.
+    /// Stores a bank account or card for future payouts without submitting a
+    /// payout. Submit payouts to the returned `recurring_detail_reference`
+    /// with [`submit`](Self::submit).
     ///
     /// # Errors
     ///
@@ -264,8 +343,7 @@ impl PayoutApi {
     /// # Example
     ///
     /// ```rust
-    /// use adyen_payout::{PayoutApi, SubmitRequest, PayoutMethodDetails, BankAccount};
-    /// use adyen_core::{Amount, Currency};
+    /// use adyen_payout::{PayoutApi, StoreDetailRequest, PayoutMethodDetails, BankAccount};
     ///
     /// # async fn example(payout: PayoutApi) -> Result<(), Box<dyn std::error::Error>> {
     /// let bank_account = BankAccount {
@@ -277,21 +355,19 @@ impl PayoutApi {
     ///     bank_account_type: Some(adyen_payout::BankAccountType::Checking),
     /// };
     ///
-    /// let request = SubmitRequest::builder()
-    ///     .amount(Amount::from_minor_units(0, Currency::EUR))  // Amount 0 for storing only
+    /// let request = StoreDetailRequest::builder()
     ///     .merchant_account("YourMerchantAccount")
-    ///     .reference("store-only-001")
     ///     .shopper_email("shopper@example.com")
     ///     .shopper_reference("shopper_123")
     ///     .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
-    ///     .build()?;
+    ///     .build();
     ///
     /// let response = payout.store_detail(&request).await?;
-    /// println!("Payout details stored: {}", response.psp_reference);
+    /// println!("Payout details stored: {}", response.recurring_detail_reference);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn store_detail(&self, request: &SubmitRequest) -> Result<SubmitResponse> {
+    pub async fn store_detail(&self, request: &StoreDetailRequest) -> Result<StoreDetailResponse> {
         let url = format!(
             "{}/pal/servlet/Payout/v68/storeDetail",
             self.client.config().environment().classic_api_url()
@@ -301,10 +377,23 @@ impl PayoutApi {
     }
 }
 
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl PayoutApi {
+        fn submit_blocking => submit(request: &SubmitThirdPartyRequest) -> Result<SubmitResponse>;
+        fn confirm_blocking => confirm(request: &ConfirmRequest) -> Result<ConfirmResponse>;
+        fn decline_payout_blocking => decline_payout(request: &DeclinePayoutRequest) -> Result<PayoutResponse>;
+        fn instant_payout_blocking => instant_payout(request: &SubmitRequest) -> Result<SubmitResponse>;
+        fn store_detail_and_submit_blocking => store_detail_and_submit(request: &StoreDetailAndSubmitRequest) -> Result<SubmitResponse>;
+        fn store_detail_blocking => store_detail(request: &StoreDetailRequest) -> Result<StoreDetailResponse>;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use adyen_core::{ConfigBuilder, Environment};
+    use adyen_core::http::{FetchTransport, HeaderMap, HttpRequest, HttpResponse};
+    use adyen_core::{Amount, ConfigBuilder, Currency, Environment};
 
     #[test]
     fn test_payout_api_creation() {
@@ -335,4 +424,102 @@ mod tests {
 
         // Verify that the API instance was created successfully with test configuration
     }
+    /// Config whose transport answers with the API key and endpoint it was
+    /// called with.
+    fn echo_config(api_key: &str) -> Config {
+        let transport = FetchTransport::new(|request: HttpRequest| async move {
+            let api_key = request.headers["X-API-Key"].to_str().unwrap().to_string();
+            let endpoint = request.url.rsplit('/').next().unwrap().to_string();
+            Ok(HttpResponse {
+                status: 200,
+                headers: HeaderMap::new(),
+                body: format!(
+                    r#"{{"pspReference":"8815131768219992","response":"{api_key} {endpoint}","recurringDetailReference":"{api_key} {endpoint}","resultCode":"[payout-submit-received]"}}"#
+                )
+                .into_bytes(),
+            })
+        });
+        ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key(api_key)
+            .unwrap()
+            .transport(transport)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_separate_review_credentials() {
+        let api = PayoutApi::with_credentials(
+            echo_config("store_key_1234567890123456"),
+            echo_config("review_key_1234567890123456"),
+        )
+        .unwrap();
+
+        let confirm = ConfirmRequest::builder()
+            .merchant_account("TestMerchant")
+            .original_reference("8515131751004933")
+            .build()
+            .unwrap();
+        let response = api.confirm(&confirm).await.unwrap();
+        assert_eq!(
+            &*response.response,
+            "review_key_1234567890123456 confirmThirdParty"
+        );
+
+        let decline = DeclinePayoutRequest::builder()
+            .merchant_account("TestMerchant")
+            .psp_reference("8515131751004933")
+            .build()
+            .unwrap();
+        let response = api.decline_payout(&decline).await.unwrap();
+        assert_eq!(
+            &*response.response,
+            "review_key_1234567890123456 declineThirdParty"
+        );
+
+        let submit = SubmitThirdPartyRequest::builder()
+            .amount(Amount::from_minor_units(1000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("payout-001")
+            .shopper_email("shopper@example.com")
+            .shopper_reference("shopper_123")
+            .selected_recurring_detail_reference("LATEST")
+            .build();
+        let response = api.submit(&submit).await.unwrap();
+        assert!(matches!(response.result_code, PayoutResultCode::Received));
+
+        let store = StoreDetailRequest::builder()
+            .merchant_account("TestMerchant")
+            .shopper_email("shopper@example.com")
+            .shopper_reference("shopper_123")
+            .payout_method_details(PayoutMethodDetails::BankAccount(BankAccount {
+                account_number: "1234567890".into(),
+                bic: None,
+                country_code: "NL".into(),
+                owner_name: "John Doe".into(),
+                iban: Some("NL91ABNA0417164300".into()),
+                bank_account_type: None,
+            }))
+            .build();
+        let response = api.store_detail(&store).await.unwrap();
+        assert_eq!(
+            &*response.recurring_detail_reference,
+            "store_key_1234567890123456 storeDetail"
+        );
+    }
+
+    #[test]
+    fn test_credentials_for_different_environments() {
+        let live = ConfigBuilder::new()
+            .environment(Environment::live("1797a841fbb37ca7-AdyenDemo").unwrap())
+            .api_key("review_key_1234567890123456")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert!(
+            PayoutApi::with_credentials(echo_config("store_key_1234567890123456"), live).is_err()
+        );
+    }
 }
@@ -9,6 +9,8 @@
 //! ## Features
 //!
 //! - **Instant Payouts**: Submit and confirm immediate fund transfers
+//! - **Third-Party Payouts**: Store payout details and submit payouts to them, with
+//!   separate store and review credentials
 //! - **Batch Processing**: Handle multiple payouts efficiently
 //! - **Review System**: Approve or decline payouts requiring manual review
 //! - **Status Tracking**: Monitor payout status and handle notifications
@@ -39,6 +41,7 @@ pub mod types;
 pub use api::PayoutApi;
 pub use types::{
     Address, BankAccount, BankAccountType, Card, ConfirmRequest, ConfirmResponse,
-    DeclinePayoutRequest, EntityType, Name, PayoutMethodDetails, PayoutResponse,
-    ReviewPayoutRequest, SubmitRequest, SubmitResponse,
+    DeclinePayoutRequest, EntityType, Name, PayoutMethodDetails, PayoutResponse, Recurring,
+    ReviewPayoutRequest, StoreDetailAndSubmitRequest, StoreDetailRequest, StoreDetailResponse,
+    SubmitRequest, SubmitResponse, SubmitThirdPartyRequest,
 };
@@ -3,24 +3,38 @@
 //! This module contains all request and response types for payout operations,
 //! including instant payouts, batch processing, and review workflows.
 
-use adyen_core::{AdyenError, Amount};
+use adyen_core::builder::Missing;
+use adyen_core::{AdyenError, Amount, Metadata, PspReference, RequestId, SecretString};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Builder for creating submit payout requests.
+///
+/// `build` is only available once the required amount, merchant account,
+/// reference, shopper email, shopper reference and payout method details are
+/// set.
 #[derive(Debug, Clone, Default)]
-pub struct SubmitRequestBuilder {
-    amount: Option<Amount>,
-    merchant_account: Option<Box<str>>,
-    reference: Option<Box<str>>,
-    shopper_email: Option<Box<str>>,
-    shopper_reference: Option<Box<str>>,
-    payout_method_details: Option<PayoutMethodDetails>,
+pub struct SubmitRequestBuilder<
+    A = Missing,
+    M = Missing,
+    R = Missing,
+    E = Missing,
+    H = Missing,
+    P = Missing,
+> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    shopper_email: E,
+    shopper_reference: H,
+    payout_method_details: P,
     billing_address: Option<Address>,
     date_of_birth: Option<Box<str>>,
     entity_type: Option<EntityType>,
     nationality: Option<Box<str>>,
     shopper_name: Option<Name>,
+    metadata: Option<HashMap<String, String>>,
+    idempotency_key: Option<RequestId>,
 }
 
 impl SubmitRequestBuilder {
@@ -28,41 +42,136 @@ impl SubmitRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<A, M, R, E, H, P> SubmitRequestBuilder<A, M, R, E, H, P> {
     /// Set the payout amount.
-    pub fn amount(mut self, amount: Amount) -> Self {
-        self.amount = Some(amount);
-        self
+    pub fn amount(self, amount: Amount) -> SubmitRequestBuilder<Amount, M, R, E, H, P> {
+        SubmitRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the merchant account identifier.
-    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> SubmitRequestBuilder<A, Box<str>, R, E, H, P> {
+        SubmitRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the payout reference.
-    pub fn reference(mut self, reference: impl Into<Box<str>>) -> Self {
-        self.reference = Some(reference.into());
-        self
+    pub fn reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> SubmitRequestBuilder<A, M, Box<str>, E, H, P> {
+        SubmitRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the shopper email address.
-    pub fn shopper_email(mut self, email: impl Into<Box<str>>) -> Self {
-        self.shopper_email = Some(email.into());
-        self
+    pub fn shopper_email(
+        self,
+        email: impl Into<Box<str>>,
+    ) -> SubmitRequestBuilder<A, M, R, Box<str>, H, P> {
+        SubmitRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: email.into(),
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the shopper reference.
-    pub fn shopper_reference(mut self, reference: impl Into<Box<str>>) -> Self {
-        self.shopper_reference = Some(reference.into());
-        self
+    pub fn shopper_reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> SubmitRequestBuilder<A, M, R, E, Box<str>, P> {
+        SubmitRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: reference.into(),
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the payout method details.
-    pub fn payout_method_details(mut self, details: PayoutMethodDetails) -> Self {
-        self.payout_method_details = Some(details);
-        self
+    pub fn payout_method_details(
+        self,
+        details: PayoutMethodDetails,
+    ) -> SubmitRequestBuilder<A, M, R, E, H, PayoutMethodDetails> {
+        SubmitRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            metadata: self.metadata,
+            idempotency_key: self.idempotency_key,
+        }
     }
 
     /// Set the billing address.
@@ -95,32 +204,44 @@ impl SubmitRequestBuilder {
         self
     }
 
+    /// Add a metadata entry.
+    ///
+    /// The [`Metadata`] limits are checked by `build`.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this payout.
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
+
+impl SubmitRequestBuilder<Amount, Box<str>, Box<str>, Box<str>, Box<str>, PayoutMethodDetails> {
     /// Build the submit request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata exceeds the [`Metadata`] limits.
     pub fn build(self) -> Result<SubmitRequest, AdyenError> {
         Ok(SubmitRequest {
-            amount: self
-                .amount
-                .ok_or_else(|| AdyenError::config("Missing required field: amount"))?,
-            merchant_account: self
-                .merchant_account
-                .ok_or_else(|| AdyenError::config("Missing required field: merchant_account"))?,
-            reference: self
-                .reference
-                .ok_or_else(|| AdyenError::config("Missing required field: reference"))?,
-            shopper_email: self
-                .shopper_email
-                .ok_or_else(|| AdyenError::config("Missing required field: shopper_email"))?,
-            shopper_reference: self
-                .shopper_reference
-                .ok_or_else(|| AdyenError::config("Missing required field: shopper_reference"))?,
-            payout_method_details: self.payout_method_details.ok_or_else(|| {
-                AdyenError::config("Missing required field: payout_method_details")
-            })?,
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
             billing_address: self.billing_address,
             date_of_birth: self.date_of_birth,
             entity_type: self.entity_type,
             nationality: self.nationality,
             shopper_name: self.shopper_name,
+            metadata: self.metadata.map(Metadata::try_from).transpose()?,
+            idempotency_key: self.idempotency_key,
         })
     }
 }
@@ -156,6 +277,12 @@ pub struct SubmitRequest {
     /// The shopper's name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shopper_name: Option<Name>,
+    /// Your own key-value data, returned in the webhooks of the payout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
 }
 
 impl SubmitRequest {
@@ -170,7 +297,7 @@ impl SubmitRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SubmitResponse {
     /// A unique reference for the payout.
-    pub psp_reference: Box<str>,
+    pub psp_reference: PspReference,
     /// The result code indicating the outcome of the payout submission.
     pub result_code: PayoutResultCode,
     /// Additional information about the payout (if available).
@@ -185,10 +312,22 @@ pub struct SubmitResponse {
 }
 
 /// Builder for creating confirm payout requests.
+///
+/// `build` is only available once the required merchant account and original
+/// reference are set.
+///
+/// ```compile_fail
+/// use adyen_payout::types::ConfirmRequest;
+///
+/// // No original reference, so there is no `build` method.
+/// let request = ConfirmRequest::builder()
+///     .merchant_account("TestMerchant")
+///     .build();
+/// ```
 #[derive(Debug, Clone, Default)]
-pub struct ConfirmRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    original_reference: Option<Box<str>>,
+pub struct ConfirmRequestBuilder<M = Missing, R = Missing> {
+    merchant_account: M,
+    original_reference: R,
 }
 
 impl ConfirmRequestBuilder {
@@ -196,28 +335,45 @@ impl ConfirmRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M, R> ConfirmRequestBuilder<M, R> {
     /// Set the merchant account identifier.
-    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> ConfirmRequestBuilder<Box<str>, R> {
+        ConfirmRequestBuilder {
+            merchant_account: merchant_account.into(),
+            original_reference: self.original_reference,
+        }
     }
 
-    /// Set the original payout reference.
-    pub fn original_reference(mut self, reference: impl Into<Box<str>>) -> Self {
-        self.original_reference = Some(reference.into());
-        self
+    /// Set the PSP reference of the payout to confirm.
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn original_reference(
+        self,
+        reference: impl AsRef<str>,
+    ) -> ConfirmRequestBuilder<M, Box<str>> {
+        ConfirmRequestBuilder {
+            merchant_account: self.merchant_account,
+            original_reference: reference.as_ref().into(),
+        }
     }
+}
 
+impl ConfirmRequestBuilder<Box<str>, Box<str>> {
     /// Build the confirm request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the original reference is not a valid PSP reference.
     pub fn build(self) -> Result<ConfirmRequest, AdyenError> {
         Ok(ConfirmRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or_else(|| AdyenError::config("Missing required field: merchant_account"))?,
-            original_reference: self
-                .original_reference
-                .ok_or_else(|| AdyenError::config("Missing required field: original_reference"))?,
+            merchant_account: self.merchant_account,
+            original_reference: PspReference::new(&self.original_reference)?,
         })
     }
 }
@@ -229,7 +385,7 @@ pub struct ConfirmRequest {
     /// The merchant account identifier.
     pub merchant_account: Box<str>,
     /// The PSP reference of the payout to confirm.
-    pub original_reference: Box<str>,
+    pub original_reference: PspReference,
 }
 
 impl ConfirmRequest {
@@ -244,16 +400,19 @@ impl ConfirmRequest {
 #[serde(rename_all = "camelCase")]
 pub struct ConfirmResponse {
     /// A unique reference for the confirmation.
-    pub psp_reference: Box<str>,
+    pub psp_reference: PspReference,
     /// The result code indicating the outcome of the confirmation.
     pub response: Box<str>,
 }
 
 /// Builder for creating review payout requests.
+///
+/// `build` is only available once the required merchant account and PSP
+/// reference are set.
 #[derive(Debug, Clone, Default)]
-pub struct ReviewPayoutRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    psp_reference: Option<Box<str>>,
+pub struct ReviewPayoutRequestBuilder<M = Missing, P = Missing> {
+    merchant_account: M,
+    psp_reference: P,
 }
 
 impl ReviewPayoutRequestBuilder {
@@ -261,28 +420,45 @@ impl ReviewPayoutRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M, P> ReviewPayoutRequestBuilder<M, P> {
     /// Set the merchant account identifier.
-    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> ReviewPayoutRequestBuilder<Box<str>, P> {
+        ReviewPayoutRequestBuilder {
+            merchant_account: merchant_account.into(),
+            psp_reference: self.psp_reference,
+        }
     }
 
     /// Set the PSP reference of the payout to review.
-    pub fn psp_reference(mut self, reference: impl Into<Box<str>>) -> Self {
-        self.psp_reference = Some(reference.into());
-        self
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn psp_reference(
+        self,
+        reference: impl AsRef<str>,
+    ) -> ReviewPayoutRequestBuilder<M, Box<str>> {
+        ReviewPayoutRequestBuilder {
+            merchant_account: self.merchant_account,
+            psp_reference: reference.as_ref().into(),
+        }
     }
+}
 
+impl ReviewPayoutRequestBuilder<Box<str>, Box<str>> {
     /// Build the review request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PSP reference is not valid.
     pub fn build(self) -> Result<ReviewPayoutRequest, AdyenError> {
         Ok(ReviewPayoutRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or_else(|| AdyenError::config("Missing required field: merchant_account"))?,
-            psp_reference: self
-                .psp_reference
-                .ok_or_else(|| AdyenError::config("Missing required field: psp_reference"))?,
+            merchant_account: self.merchant_account,
+            psp_reference: PspReference::new(&self.psp_reference)?,
         })
     }
 }
@@ -294,7 +470,7 @@ pub struct ReviewPayoutRequest {
     /// The merchant account identifier.
     pub merchant_account: Box<str>,
     /// The PSP reference of the payout to review.
-    pub psp_reference: Box<str>,
+    pub psp_reference: PspReference,
 }
 
 impl ReviewPayoutRequest {
@@ -305,10 +481,13 @@ impl ReviewPayoutRequest {
 }
 
 /// Builder for creating decline payout requests.
+///
+/// `build` is only available once the required merchant account and PSP
+/// reference are set.
 #[derive(Debug, Clone, Default)]
-pub struct DeclinePayoutRequestBuilder {
-    merchant_account: Option<Box<str>>,
-    psp_reference: Option<Box<str>>,
+pub struct DeclinePayoutRequestBuilder<M = Missing, P = Missing> {
+    merchant_account: M,
+    psp_reference: P,
 }
 
 impl DeclinePayoutRequestBuilder {
@@ -316,28 +495,45 @@ impl DeclinePayoutRequestBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+}
 
+impl<M, P> DeclinePayoutRequestBuilder<M, P> {
     /// Set the merchant account identifier.
-    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
-        self.merchant_account = Some(merchant_account.into());
-        self
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> DeclinePayoutRequestBuilder<Box<str>, P> {
+        DeclinePayoutRequestBuilder {
+            merchant_account: merchant_account.into(),
+            psp_reference: self.psp_reference,
+        }
     }
 
     /// Set the PSP reference of the payout to decline.
-    pub fn psp_reference(mut self, reference: impl Into<Box<str>>) -> Self {
-        self.psp_reference = Some(reference.into());
-        self
+    ///
+    /// Accepts a [`PspReference`] or its string form, which is validated by
+    /// `build`.
+    pub fn psp_reference(
+        self,
+        reference: impl AsRef<str>,
+    ) -> DeclinePayoutRequestBuilder<M, Box<str>> {
+        DeclinePayoutRequestBuilder {
+            merchant_account: self.merchant_account,
+            psp_reference: reference.as_ref().into(),
+        }
     }
+}
 
+impl DeclinePayoutRequestBuilder<Box<str>, Box<str>> {
     /// Build the decline request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PSP reference is not valid.
     pub fn build(self) -> Result<DeclinePayoutRequest, AdyenError> {
         Ok(DeclinePayoutRequest {
-            merchant_account: self
-                .merchant_account
-                .ok_or_else(|| AdyenError::config("Missing required field: merchant_account"))?,
-            psp_reference: self
-                .psp_reference
-                .ok_or_else(|| AdyenError::config("Missing required field: psp_reference"))?,
+            merchant_account: self.merchant_account,
+            psp_reference: PspReference::new(&self.psp_reference)?,
         })
     }
 }
@@ -348,8 +544,10 @@ impl DeclinePayoutRequestBuilder {
 pub struct DeclinePayoutRequest {
     /// The merchant account identifier.
     pub merchant_account: Box<str>,
-    /// The PSP reference of the payout to decline.
-    pub psp_reference: Box<str>,
+    /// The PSP reference of the payout to decline, sent as
+    /// `originalReference`.
+    #[serde(rename = "originalReference", alias = "pspReference")]
+    pub psp_reference: PspReference,
 }
 
 impl DeclinePayoutRequest {
@@ -364,11 +562,546 @@ impl DeclinePayoutRequest {
 #[serde(rename_all = "camelCase")]
 pub struct PayoutResponse {
     /// A unique reference for the operation.
-    pub psp_reference: Box<str>,
+    pub psp_reference: PspReference,
     /// The result code indicating the outcome.
     pub response: Box<str>,
 }
 
+/// Recurring contract under which payout details are stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recurring {
+    /// The contract type, `PAYOUT` for payout details.
+    pub contract: Box<str>,
+}
+
+impl Default for Recurring {
+    fn default() -> Self {
+        Self {
+            contract: "PAYOUT".into(),
+        }
+    }
+}
+
+/// Builder for creating store detail requests.
+///
+/// `build` is only available once the required merchant account, shopper email,
+/// shopper reference and payout method details are set.
+#[derive(Debug, Clone, Default)]
+pub struct StoreDetailRequestBuilder<M = Missing, E = Missing, H = Missing, P = Missing> {
+    merchant_account: M,
+    shopper_email: E,
+    shopper_reference: H,
+    payout_method_details: P,
+    billing_address: Option<Address>,
+    date_of_birth: Option<Box<str>>,
+    entity_type: Option<EntityType>,
+    nationality: Option<Box<str>>,
+    shopper_name: Option<Name>,
+    additional_data: Option<HashMap<Box<str>, Box<str>>>,
+}
+
+impl StoreDetailRequestBuilder {
+    /// Create a new store detail request builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<M, E, H, P> StoreDetailRequestBuilder<M, E, H, P> {
+    /// Set the merchant account identifier.
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> StoreDetailRequestBuilder<Box<str>, E, H, P> {
+        StoreDetailRequestBuilder {
+            merchant_account: merchant_account.into(),
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the shopper email address.
+    pub fn shopper_email(
+        self,
+        email: impl Into<Box<str>>,
+    ) -> StoreDetailRequestBuilder<M, Box<str>, H, P> {
+        StoreDetailRequestBuilder {
+            merchant_account: self.merchant_account,
+            shopper_email: email.into(),
+            shopper_reference: self.shopper_reference,
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the shopper reference.
+    pub fn shopper_reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> StoreDetailRequestBuilder<M, E, Box<str>, P> {
+        StoreDetailRequestBuilder {
+            merchant_account: self.merchant_account,
+            shopper_email: self.shopper_email,
+            shopper_reference: reference.into(),
+            payout_method_details: self.payout_method_details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the bank account or card to store.
+    pub fn payout_method_details(
+        self,
+        details: PayoutMethodDetails,
+    ) -> StoreDetailRequestBuilder<M, E, H, PayoutMethodDetails> {
+        StoreDetailRequestBuilder {
+            merchant_account: self.merchant_account,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            payout_method_details: details,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Set the billing address.
+    pub fn billing_address(mut self, address: Address) -> Self {
+        self.billing_address = Some(address);
+        self
+    }
+
+    /// Set the date of birth (YYYY-MM-DD format).
+    pub fn date_of_birth(mut self, date: impl Into<Box<str>>) -> Self {
+        self.date_of_birth = Some(date.into());
+        self
+    }
+
+    /// Set the entity type.
+    pub fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.entity_type = Some(entity_type);
+        self
+    }
+
+    /// Set the nationality (ISO 3166-1 alpha-2 country code).
+    pub fn nationality(mut self, nationality: impl Into<Box<str>>) -> Self {
+        self.nationality = Some(nationality.into());
+        self
+    }
+
+    /// Set the shopper name.
+    pub fn shopper_name(mut self, name: Name) -> Self {
+        self.shopper_name = Some(name);
+        self
+    }
+
+    /// Add an additional data entry.
+    pub fn additional_data(mut self, key: impl Into<Box<str>>, value: impl Into<Box<str>>) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+impl StoreDetailRequestBuilder<Box<str>, Box<str>, Box<str>, PayoutMethodDetails> {
+    /// Build the store detail request.
+    pub fn build(self) -> StoreDetailRequest {
+        let (bank, card) = match self.payout_method_details {
+            PayoutMethodDetails::BankAccount(bank) => (Some(bank), None),
+            PayoutMethodDetails::Card(card) => (None, Some(card)),
+        };
+
+        StoreDetailRequest {
+            merchant_account: self.merchant_account,
+            recurring: Recurring::default(),
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            bank,
+            card,
+            billing_address: self.billing_address,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            additional_data: self.additional_data,
+        }
+    }
+}
+
+/// Request to store payout details for later payouts (`/storeDetail`).
+///
+/// The response contains the `recurringDetailReference` to submit payouts
+/// with [`SubmitThirdPartyRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreDetailRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+    /// The recurring contract, always `PAYOUT`.
+    #[serde(default)]
+    pub recurring: Recurring,
+    /// The shopper's email address.
+    pub shopper_email: Box<str>,
+    /// Your reference for the shopper.
+    pub shopper_reference: Box<str>,
+    /// The bank account to store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<BankAccount>,
+    /// The card to store.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<Card>,
+    /// The billing address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    /// The shopper's date of birth in YYYY-MM-DD format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<Box<str>>,
+    /// The type of entity for the payout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<EntityType>,
+    /// The shopper's nationality as an ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<Box<str>>,
+    /// The shopper's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_name: Option<Name>,
+    /// Additional data for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<HashMap<Box<str>, Box<str>>>,
+}
+
+impl StoreDetailRequest {
+    /// Create a new store detail request builder.
+    pub fn builder() -> StoreDetailRequestBuilder {
+        StoreDetailRequestBuilder::new()
+    }
+
+    /// Turn this into a request that also submits a payout of `amount` to
+    /// the stored details.
+    pub fn and_submit(
+        self,
+        amount: Amount,
+        reference: impl Into<Box<str>>,
+    ) -> StoreDetailAndSubmitRequest {
+        StoreDetailAndSubmitRequest {
+            amount,
+            reference: reference.into(),
+            details: self,
+            idempotency_key: None,
+        }
+    }
+}
+
+/// Response from storing payout details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreDetailResponse {
+    /// A unique reference for the request.
+    pub psp_reference: PspReference,
+    /// The reference of the stored details, used to submit payouts.
+    pub recurring_detail_reference: Box<str>,
+    /// `Success` if the details were stored.
+    pub result_code: Box<str>,
+    /// Additional information about the request (if available).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<HashMap<Box<str>, Box<str>>>,
+}
+
+/// Request to store payout details and submit a payout to them in one call
+/// (`/storeDetailAndSubmitThirdParty`).
+///
+/// Create it with [`StoreDetailRequest::and_submit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreDetailAndSubmitRequest {
+    /// The payout amount.
+    pub amount: Amount,
+    /// Your reference for the payout.
+    pub reference: Box<str>,
+    /// The details to store.
+    #[serde(flatten)]
+    pub details: StoreDetailRequest,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoreDetailAndSubmitRequest {
+    /// Set the idempotency key used to safely retry this payout.
+    #[must_use]
+    pub fn with_idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
+
+/// Builder for creating requests to submit payouts to stored details.
+///
+/// `build` is only available once the required amount, merchant account,
+/// reference, selected recurring detail reference, shopper email and shopper
+/// reference are set.
+#[derive(Debug, Clone, Default)]
+pub struct SubmitThirdPartyRequestBuilder<
+    A = Missing,
+    M = Missing,
+    R = Missing,
+    D = Missing,
+    E = Missing,
+    H = Missing,
+> {
+    amount: A,
+    merchant_account: M,
+    reference: R,
+    shopper_email: E,
+    shopper_reference: H,
+    selected_recurring_detail_reference: D,
+    date_of_birth: Option<Box<str>>,
+    entity_type: Option<EntityType>,
+    nationality: Option<Box<str>>,
+    shopper_name: Option<Name>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl SubmitThirdPartyRequestBuilder {
+    /// Create a new submit request builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A, M, R, D, E, H> SubmitThirdPartyRequestBuilder<A, M, R, D, E, H> {
+    /// Set the payout amount.
+    pub fn amount(self, amount: Amount) -> SubmitThirdPartyRequestBuilder<Amount, M, R, D, E, H> {
+        SubmitThirdPartyRequestBuilder {
+            amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the merchant account identifier.
+    pub fn merchant_account(
+        self,
+        merchant_account: impl Into<Box<str>>,
+    ) -> SubmitThirdPartyRequestBuilder<A, Box<str>, R, D, E, H> {
+        SubmitThirdPartyRequestBuilder {
+            amount: self.amount,
+            merchant_account: merchant_account.into(),
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the payout reference.
+    pub fn reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> SubmitThirdPartyRequestBuilder<A, M, Box<str>, D, E, H> {
+        SubmitThirdPartyRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: reference.into(),
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the shopper email address.
+    pub fn shopper_email(
+        self,
+        email: impl Into<Box<str>>,
+    ) -> SubmitThirdPartyRequestBuilder<A, M, R, D, Box<str>, H> {
+        SubmitThirdPartyRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: email.into(),
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the shopper reference.
+    pub fn shopper_reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> SubmitThirdPartyRequestBuilder<A, M, R, D, E, Box<str>> {
+        SubmitThirdPartyRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: reference.into(),
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the stored details to pay out to, or `LATEST` for the most
+    /// recently stored details of the shopper.
+    pub fn selected_recurring_detail_reference(
+        self,
+        reference: impl Into<Box<str>>,
+    ) -> SubmitThirdPartyRequestBuilder<A, M, R, Box<str>, E, H> {
+        SubmitThirdPartyRequestBuilder {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: reference.into(),
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+
+    /// Set the date of birth (YYYY-MM-DD format).
+    pub fn date_of_birth(mut self, date: impl Into<Box<str>>) -> Self {
+        self.date_of_birth = Some(date.into());
+        self
+    }
+
+    /// Set the entity type.
+    pub fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.entity_type = Some(entity_type);
+        self
+    }
+
+    /// Set the nationality (ISO 3166-1 alpha-2 country code).
+    pub fn nationality(mut self, nationality: impl Into<Box<str>>) -> Self {
+        self.nationality = Some(nationality.into());
+        self
+    }
+
+    /// Set the shopper name.
+    pub fn shopper_name(mut self, name: Name) -> Self {
+        self.shopper_name = Some(name);
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this payout.
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
+
+impl SubmitThirdPartyRequestBuilder<Amount, Box<str>, Box<str>, Box<str>, Box<str>, Box<str>> {
+    /// Build the submit request.
+    pub fn build(self) -> SubmitThirdPartyRequest {
+        SubmitThirdPartyRequest {
+            amount: self.amount,
+            merchant_account: self.merchant_account,
+            reference: self.reference,
+            recurring: Recurring::default(),
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            shopper_email: self.shopper_email,
+            shopper_reference: self.shopper_reference,
+            date_of_birth: self.date_of_birth,
+            entity_type: self.entity_type,
+            nationality: self.nationality,
+            shopper_name: self.shopper_name,
+            idempotency_key: self.idempotency_key,
+        }
+    }
+}
+
+/// Request to submit a payout to stored details (`/submitThirdParty`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitThirdPartyRequest {
+    /// The payout amount.
+    pub amount: Amount,
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+    /// Your reference for the payout.
+    pub reference: Box<str>,
+    /// The recurring contract, always `PAYOUT`.
+    #[serde(default)]
+    pub recurring: Recurring,
+    /// The stored details to pay out to, or `LATEST`.
+    pub selected_recurring_detail_reference: Box<str>,
+    /// The shopper's email address.
+    pub shopper_email: Box<str>,
+    /// Your reference for the shopper.
+    pub shopper_reference: Box<str>,
+    /// The shopper's date of birth in YYYY-MM-DD format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<Box<str>>,
+    /// The type of entity for the payout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<EntityType>,
+    /// The shopper's nationality as an ISO 3166-1 alpha-2 country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<Box<str>>,
+    /// The shopper's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_name: Option<Name>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl SubmitThirdPartyRequest {
+    /// Create a new submit request builder.
+    pub fn builder() -> SubmitThirdPartyRequestBuilder {
+        SubmitThirdPartyRequestBuilder::new()
+    }
+}
+
 /// Details about the payout method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
@@ -407,7 +1140,7 @@ pub struct BankAccount {
 #[serde(rename_all = "camelCase")]
 pub struct Card {
     /// The card number.
-    pub number: Box<str>,
+    pub number: SecretString,
     /// The card expiry month.
     pub expiry_month: Box<str>,
     /// The card expiry year.
@@ -450,9 +1183,13 @@ pub struct Name {
 #[serde(rename_all = "PascalCase")]
 pub enum PayoutResultCode {
     /// The payout was received and will be processed.
+    #[serde(alias = "[payout-submit-received]")]
     Received,
     /// The payout was refused.
     Refused,
+    /// A payout result code not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// The type of entity making the payout.
@@ -463,6 +1200,9 @@ pub enum EntityType {
     NaturalPerson,
     /// Company.
     Company,
+    /// An entity type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// The type of bank account.
@@ -473,6 +1213,9 @@ pub enum BankAccountType {
     Checking,
     /// Savings account.
     Savings,
+    /// A bank account type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 #[cfg(test)]
@@ -499,61 +1242,58 @@ mod tests {
             .shopper_email("john@example.com")
             .shopper_reference("shopper-123")
             .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
+            .metadata("batchId", "batch-7")
             .build()
             .unwrap();
 
         assert_eq!(request.amount.minor_units(), 1000);
-        assert_eq!(request.amount.currency(), Currency::EUR);
+        assert_eq!(request.amount.currency(), &Currency::EUR);
         assert_eq!(&*request.merchant_account, "TestMerchant");
         assert_eq!(&*request.reference, "payout-123");
+        assert_eq!(
+            request.metadata.as_ref().and_then(|m| m.get("batchId")),
+            Some("batch-7")
+        );
     }
 
     #[test]
     fn test_confirm_request_builder() {
         let request = ConfirmRequest::builder()
             .merchant_account("TestMerchant")
-            .original_reference("psp-123")
+            .original_reference("8515131751004933")
             .build()
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.original_reference, "psp-123");
+        assert_eq!(request.original_reference, "8515131751004933");
     }
 
     #[test]
     fn test_review_payout_request_builder() {
         let request = ReviewPayoutRequest::builder()
             .merchant_account("TestMerchant")
-            .psp_reference("psp-123")
+            .psp_reference("8515131751004933")
             .build()
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.psp_reference, "psp-123");
+        assert_eq!(request.psp_reference, "8515131751004933");
     }
 
     #[test]
     fn test_decline_payout_request_builder() {
         let request = DeclinePayoutRequest::builder()
             .merchant_account("TestMerchant")
-            .psp_reference("psp-123")
+            .psp_reference("8515131751004933")
             .build()
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.psp_reference, "psp-123");
-    }
+        assert_eq!(request.psp_reference, "8515131751004933");
 
-    #[test]
-    fn test_submit_request_builder_missing_fields() {
-        let result = SubmitRequest::builder().build();
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_confirm_request_builder_missing_fields() {
-        let result = ConfirmRequest::builder().build();
-        assert!(result.is_err());
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["originalReference"], "8515131751004933");
+        assert!(json.get("pspReference").is_none());
     }
 
     #[test]
@@ -3,10 +3,12 @@
 //! These tests verify the complete payout workflows including instant payouts,
 //! batch processing, review workflows, and error handling scenarios.
 
-use adyen_core::{Amount, ConfigBuilder, Currency, Environment};
+use adyen_core::{Amount, ConfigBuilder, Currency, Environment, RequestId};
+use adyen_payout::types::PayoutResultCode;
 use adyen_payout::{
     Address, BankAccount, BankAccountType, Card, ConfirmRequest, DeclinePayoutRequest, EntityType,
-    Name, PayoutApi, PayoutMethodDetails, ReviewPayoutRequest, SubmitRequest,
+    Name, PayoutApi, PayoutMethodDetails, ReviewPayoutRequest, StoreDetailRequest,
+    StoreDetailResponse, SubmitRequest, SubmitResponse, SubmitThirdPartyRequest,
 };
 
 /// Helper function to create a test configuration.
@@ -93,7 +95,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(request.amount.minor_units(), 10000);
-        assert_eq!(request.amount.currency(), Currency::EUR);
+        assert_eq!(request.amount.currency(), &Currency::EUR);
         assert_eq!(&*request.merchant_account, "TestMerchant");
         assert_eq!(&*request.reference, "payout-test-001");
         assert_eq!(&*request.shopper_email, "test@example.com");
@@ -121,7 +123,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(request.amount.minor_units(), 5000);
-        assert_eq!(request.amount.currency(), Currency::USD);
+        assert_eq!(request.amount.currency(), &Currency::USD);
         assert_eq!(&*request.reference, "payout-card-001");
         assert!(matches!(
             request.payout_method_details,
@@ -145,7 +147,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(request.amount.minor_units(), 2500);
-        assert_eq!(request.amount.currency(), Currency::EUR);
+        assert_eq!(request.amount.currency(), &Currency::EUR);
         assert_eq!(&*request.reference, "instant-payout-001");
         assert!(matches!(
             request.payout_method_details,
@@ -162,7 +164,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.original_reference, "8515131751004933");
+        assert_eq!(request.original_reference, "8515131751004933");
     }
 
     #[test]
@@ -174,7 +176,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.psp_reference, "8515131751004933");
+        assert_eq!(request.psp_reference, "8515131751004933");
     }
 
     #[test]
@@ -186,48 +188,7 @@ mod payout_api_tests {
             .unwrap();
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
-        assert_eq!(&*request.psp_reference, "8515131751004933");
-    }
-
-    #[test]
-    fn test_submit_request_missing_required_fields() {
-        let result = SubmitRequest::builder()
-            .amount(Amount::from_minor_units(1000, Currency::EUR))
-            .merchant_account("TestMerchant")
-            // Missing required fields
-            .build();
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_confirm_request_missing_required_fields() {
-        let result = ConfirmRequest::builder()
-            .merchant_account("TestMerchant")
-            // Missing original_reference
-            .build();
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_review_request_missing_required_fields() {
-        let result = ReviewPayoutRequest::builder()
-            .merchant_account("TestMerchant")
-            // Missing psp_reference
-            .build();
-
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_decline_request_missing_required_fields() {
-        let result = DeclinePayoutRequest::builder()
-            .merchant_account("TestMerchant")
-            // Missing psp_reference
-            .build();
-
-        assert!(result.is_err());
+        assert_eq!(request.psp_reference, "8515131751004933");
     }
 }
 
@@ -261,6 +222,25 @@ mod serialization_tests {
         assert_eq!(request.reference, deserialized.reference);
     }
 
+    #[test]
+    fn test_submit_request_idempotency_key_is_header_only() {
+        let key = RequestId::new();
+        let request = SubmitRequest::builder()
+            .amount(Amount::from_minor_units(10000, Currency::EUR))
+            .merchant_account("TestMerchant")
+            .reference("payout-001")
+            .shopper_email("test@example.com")
+            .shopper_reference("shopper-001")
+            .payout_method_details(PayoutMethodDetails::BankAccount(create_test_bank_account()))
+            .idempotency_key(key.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(request.idempotency_key, Some(key));
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("idempotencyKey").is_none());
+    }
+
     #[test]
     fn test_bank_account_serialization() {
         let bank_account = create_test_bank_account();
@@ -395,7 +375,7 @@ mod workflow_tests {
 
         // Verify confirm request
         assert_eq!(&*confirm_request.merchant_account, "TestMerchant");
-        assert_eq!(&*confirm_request.original_reference, "8515131751004933");
+        assert_eq!(confirm_request.original_reference, "8515131751004933");
     }
 
     /// Test the review workflow for payouts requiring manual approval:
@@ -426,7 +406,7 @@ mod workflow_tests {
             .build()
             .unwrap();
 
-        assert_eq!(&*review_request.psp_reference, "8515131751004933");
+        assert_eq!(review_request.psp_reference, "8515131751004933");
 
         // Step 2b: Create decline request (alternative to approval)
         let decline_request = DeclinePayoutRequest::builder()
@@ -435,7 +415,7 @@ mod workflow_tests {
             .build()
             .unwrap();
 
-        assert_eq!(&*decline_request.psp_reference, "8515131751004933");
+        assert_eq!(decline_request.psp_reference, "8515131751004933");
     }
 
     /// Test the store detail workflow for setting up future payouts:
@@ -443,40 +423,71 @@ mod workflow_tests {
     /// 2. Later submit payout using stored details
     #[test]
     fn test_store_detail_workflow_types() {
-        // Step 1: Store details without payout (amount = 0)
-        let bank_account = create_test_bank_account();
-        let store_request = SubmitRequest::builder()
-            .amount(Amount::from_minor_units(0, Currency::EUR)) // Amount 0 for storing only
+        // Step 1: Store details without payout
+        let store_request = StoreDetailRequest::builder()
             .merchant_account("TestMerchant")
-            .reference("store-detail-001")
             .shopper_email("store@example.com")
             .shopper_reference("store-shopper-001")
-            .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
-            .build()
-            .unwrap();
+            .payout_method_details(PayoutMethodDetails::BankAccount(create_test_bank_account()))
+            .build();
 
-        assert_eq!(store_request.amount.minor_units(), 0);
-        assert_eq!(&*store_request.reference, "store-detail-001");
+        let json = serde_json::to_value(&store_request).unwrap();
+        assert_eq!(json["recurring"]["contract"], "PAYOUT");
+        assert_eq!(json["bank"]["ownerName"], "John Doe");
+        assert!(json.get("card").is_none());
+
+        let store_response: StoreDetailResponse = serde_json::from_str(
+            r#"{"pspReference":"8515131751004933","recurringDetailReference":"8315131751004944","resultCode":"Success"}"#,
+        )
+        .unwrap();
 
         // Step 2: Later use stored details for actual payout
-        let bank_account = create_test_bank_account();
-        let payout_request = SubmitRequest::builder()
+        let payout_request = SubmitThirdPartyRequest::builder()
             .amount(Amount::from_minor_units(5000, Currency::EUR))
             .merchant_account("TestMerchant")
             .reference("stored-payout-001")
             .shopper_email("store@example.com")
             .shopper_reference("store-shopper-001") // Same shopper reference
-            .payout_method_details(PayoutMethodDetails::BankAccount(bank_account))
-            .build()
-            .unwrap();
+            .selected_recurring_detail_reference(store_response.recurring_detail_reference)
+            .build();
 
-        assert_eq!(payout_request.amount.minor_units(), 5000);
+        let json = serde_json::to_value(&payout_request).unwrap();
+        assert_eq!(json["selectedRecurringDetailReference"], "8315131751004944");
+        assert_eq!(json["recurring"]["contract"], "PAYOUT");
         assert_eq!(
             payout_request.shopper_reference,
             store_request.shopper_reference
         );
     }
 
+    /// Test storing details and submitting a payout in one request.
+    #[test]
+    fn test_store_detail_and_submit_types() {
+        let request = StoreDetailRequest::builder()
+            .merchant_account("TestMerchant")
+            .shopper_email("store@example.com")
+            .shopper_reference("store-shopper-001")
+            .payout_method_details(PayoutMethodDetails::Card(create_test_card()))
+            .build()
+            .and_submit(
+                Amount::from_minor_units(2500, Currency::EUR),
+                "store-submit-001",
+            );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["amount"]["currency"], "EUR");
+        assert_eq!(json["reference"], "store-submit-001");
+        assert_eq!(json["merchantAccount"], "TestMerchant");
+        assert_eq!(json["recurring"]["contract"], "PAYOUT");
+        assert!(json["card"]["number"].is_string());
+
+        let response: SubmitResponse = serde_json::from_str(
+            r#"{"pspReference":"8515131751004933","resultCode":"[payout-submit-received]"}"#,
+        )
+        .unwrap();
+        assert!(matches!(response.result_code, PayoutResultCode::Received));
+    }
+
     /// Test the instant card payout workflow for immediate card payouts:
     /// 1. Create instant payout request for card
     /// 2. Verify request structure for instant processing
@@ -498,7 +509,7 @@ mod workflow_tests {
 
         // Verify instant payout request structure
         assert_eq!(instant_request.amount.minor_units(), 7500);
-        assert_eq!(instant_request.amount.currency(), Currency::GBP);
+        assert_eq!(instant_request.amount.currency(), &Currency::GBP);
         assert_eq!(&*instant_request.reference, "instant-card-payout-001");
         assert!(matches!(
             instant_request.payout_method_details,
@@ -508,75 +519,7 @@ mod workflow_tests {
         // Instant payouts are typically for cards and don't require confirmation workflow
         if let PayoutMethodDetails::Card(ref card_details) = instant_request.payout_method_details {
             assert_eq!(&*card_details.holder_name, "John Doe");
-            assert_eq!(&*card_details.number, "4111111111111111");
+            assert_eq!(card_details.number.expose_secret(), "4111111111111111");
         }
     }
 }
-
-#[cfg(test)]
-mod error_handling_tests {
-    use super::*;
-    use adyen_core::AdyenError;
-
-    #[test]
-    fn test_submit_request_validation() {
-        // Test missing amount
-        let result = SubmitRequest::builder()
-            .merchant_account("TestMerchant")
-            .reference("test-ref")
-            .shopper_email("test@example.com")
-            .shopper_reference("shopper-ref")
-            .payout_method_details(PayoutMethodDetails::BankAccount(create_test_bank_account()))
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-
-        // Test missing merchant account
-        let result = SubmitRequest::builder()
-            .amount(Amount::from_minor_units(1000, Currency::EUR))
-            .reference("test-ref")
-            .shopper_email("test@example.com")
-            .shopper_reference("shopper-ref")
-            .payout_method_details(PayoutMethodDetails::BankAccount(create_test_bank_account()))
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-    }
-
-    #[test]
-    fn test_confirm_request_validation() {
-        // Test missing merchant account
-        let result = ConfirmRequest::builder()
-            .original_reference("8515131751004933")
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-
-        // Test missing original reference
-        let result = ConfirmRequest::builder()
-            .merchant_account("TestMerchant")
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-    }
-
-    #[test]
-    fn test_review_request_validation() {
-        // Test missing PSP reference
-        let result = ReviewPayoutRequest::builder()
-            .merchant_account("TestMerchant")
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-    }
-
-    #[test]
-    fn test_decline_request_validation() {
-        // Test missing PSP reference
-        let result = DeclinePayoutRequest::builder()
-            .merchant_account("TestMerchant")
-            .build();
-
-        assert!(matches!(result, Err(AdyenError::Config(_))));
-    }
-}
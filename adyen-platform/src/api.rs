@@ -1,7 +1,7 @@
 //! Balance Platform API client implementation.
 
 use crate::types::*;
-use adyen_core::{Client, Config, Result};
+use adyen_core::{http::Method, AdyenError, Api, Client, Config, Request, Result};
 
 /// Adyen Balance Platform API client.
 ///
@@ -36,10 +36,21 @@ impl BalancePlatformApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::BalancePlatform)?;
         Ok(Self { client })
     }
 
+    /// Create a new Balance Platform API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::BalancePlatform),
+        }
+    }
+
     // ============================================================================
     // Balance Account Management
     // ============================================================================
@@ -104,6 +115,23 @@ impl BalancePlatformApi {
         Ok(response.data)
     }
 
+    /// Get the current balances of a balance account.
+    ///
+    /// Returns one balance per currency held on the balance account. To list
+    /// the transactions behind the balances, use the `list_transactions_stream`
+    /// method of the Transfers API client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_balance_account_balances(
+        &self,
+        balance_account_id: &str,
+    ) -> Result<Vec<Balance>> {
+        let balance_account = self.get_balance_account(balance_account_id).await?;
+        Ok(balance_account.balances.unwrap_or_default())
+    }
+
     /// Update a balance account.
     ///
     /// Updates the balance account with the provided information.
@@ -220,15 +248,36 @@ impl BalancePlatformApi {
 
     /// Update an account holder.
     ///
-    /// Updates the account holder with the provided information.
+    /// Updates only the fields set in the request, including capability
+    /// requests and status changes.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_platform::{
+    ///     AccountHolderCapabilityRequest, BalancePlatformApi, CapabilityLevel,
+    ///     UpdateAccountHolderRequest,
+    /// };
+    ///
+    /// # async fn example(platform: BalancePlatformApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = UpdateAccountHolderRequest::default()
+    ///     .capability(
+    ///         "issueCard",
+    ///         AccountHolderCapabilityRequest::request(CapabilityLevel::Medium),
+    ///     )
+    ///     .capability("sendToTransferInstrument", AccountHolderCapabilityRequest::disable());
+    /// platform.update_account_holder("AH12345", &request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn update_account_holder(
         &self,
         account_holder_id: &str,
-        request: &CreateAccountHolderRequest,
+        request: &UpdateAccountHolderRequest,
     ) -> Result<AccountHolder> {
         let url = format!(
             "{}/v2/accountHolders/{}",
@@ -242,6 +291,34 @@ impl BalancePlatformApi {
         Ok(response.data)
     }
 
+    /// Suspend an account holder.
+    ///
+    /// The account holder cannot use its balance accounts and payment
+    /// instruments until it is reactivated with [`update_account_holder`].
+    ///
+    /// [`update_account_holder`]: Self::update_account_holder
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn suspend_account_holder(&self, account_holder_id: &str) -> Result<AccountHolder> {
+        self.update_account_holder(account_holder_id, &UpdateAccountHolderRequest::suspend())
+            .await
+    }
+
+    /// Close an account holder.
+    ///
+    /// Closing is permanent. The balance accounts of the account holder must
+    /// have a zero balance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn close_account_holder(&self, account_holder_id: &str) -> Result<AccountHolder> {
+        self.update_account_holder(account_holder_id, &UpdateAccountHolderRequest::close())
+            .await
+    }
+
     /// Get all account holders.
     ///
     /// Returns a list of account holders associated with your balance platform.
@@ -359,6 +436,169 @@ impl BalancePlatformApi {
         Ok(response.data.data)
     }
 
+    // ============================================================================
+    // Payment Instrument Groups
+    // ============================================================================
+
+    /// Create a new payment instrument group.
+    ///
+    /// Creates a group of payment instruments that share transaction rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn create_payment_instrument_group(
+        &self,
+        request: &CreatePaymentInstrumentGroupRequest,
+    ) -> Result<PaymentInstrumentGroup> {
+        let url = format!(
+            "{}/v2/paymentInstrumentGroups",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Get a payment instrument group by ID.
+    ///
+    /// Returns the payment instrument group details for the specified ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_payment_instrument_group(
+        &self,
+        payment_instrument_group_id: &str,
+    ) -> Result<PaymentInstrumentGroup> {
+        let url = format!(
+            "{}/v2/paymentInstrumentGroups/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            payment_instrument_group_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Get all transaction rules for a payment instrument group.
+    ///
+    /// Returns the transaction rules that apply to the payment instruments in
+    /// the specified group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_payment_instrument_group_transaction_rules(
+        &self,
+        payment_instrument_group_id: &str,
+    ) -> Result<Vec<TransactionRule>> {
+        self.list_transaction_rules(
+            EntityType::PaymentInstrumentGroup,
+            payment_instrument_group_id,
+        )
+        .await
+    }
+
+    // ============================================================================
+    // Network Token Management
+    // ============================================================================
+
+    /// Get all network tokens for a payment instrument.
+    ///
+    /// Returns the network tokens provisioned for the specified card, e.g. in
+    /// digital wallets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_network_tokens(
+        &self,
+        payment_instrument_id: &str,
+    ) -> Result<Vec<NetworkToken>> {
+        let url = format!(
+            "{}/v2/paymentInstruments/{}/networkTokens",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            payment_instrument_id
+        );
+        let response: adyen_core::ApiResponse<NetworkTokensResponse> =
+            self.client.get(&url).await?;
+        Ok(response.data.network_tokens)
+    }
+
+    /// Get a network token by ID.
+    ///
+    /// Returns the network token details for the specified ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_network_token(&self, network_token_id: &str) -> Result<NetworkToken> {
+        let url = format!(
+            "{}/v2/networkTokens/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            network_token_id
+        );
+        let response: adyen_core::ApiResponse<NetworkTokenResponse> = self.client.get(&url).await?;
+        Ok(response.data.token)
+    }
+
+    /// Update the status of a network token.
+    ///
+    /// Suspends, reactivates or closes the specified network token. Closing a
+    /// token is permanent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_platform::{BalancePlatformApi, NetworkTokenStatus};
+    ///
+    /// # async fn example(platform: BalancePlatformApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Suspend all wallet tokens of a card reported lost.
+    /// for token in platform.list_network_tokens("PI12345").await? {
+    ///     if token.status.can_transition_to(NetworkTokenStatus::Suspended) {
+    ///         platform
+    ///             .update_network_token(&token.id, NetworkTokenStatus::Suspended)
+    ///             .await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_network_token(
+        &self,
+        network_token_id: &str,
+        status: NetworkTokenStatus,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/v2/networkTokens/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            network_token_id
+        );
+        let request = UpdateNetworkTokenRequest { status };
+        // Adyen accepts the update with an empty 202 response.
+        let request = Request::new(Method::Patch, url).with_body(serde_json::to_value(&request)?);
+        self.client.execute_raw(request).await?;
+        Ok(())
+    }
+
     // ============================================================================
     // Transaction Rules Management
     // ============================================================================
@@ -369,11 +609,45 @@ impl BalancePlatformApi {
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response cannot be parsed.
+    /// Returns an error if the request is missing fields required by the rule
+    /// type or outcome, the request fails, or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_platform::{
+    ///     BalancePlatformApi, CreateTransactionRuleRequest, EntityKey, EntityType, ListRestriction,
+    ///     OutcomeType, TransactionRuleInterval, IntervalType, TransactionRuleRestrictions,
+    ///     TransactionRuleType,
+    /// };
+    ///
+    /// # async fn example(platform: BalancePlatformApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Block gambling merchants for all cards of a balance account.
+    /// let request = CreateTransactionRuleRequest {
+    ///     description: Some("Block gambling".into()),
+    ///     reference: Some("TR_GAMBLING".into()),
+    ///     r#type: TransactionRuleType::BlockList,
+    ///     rule_restrictions: TransactionRuleRestrictions {
+    ///         mccs: Some(ListRestriction::any_of(["7995"])),
+    ///         ..TransactionRuleRestrictions::default()
+    ///     },
+    ///     entity_key: EntityKey {
+    ///         entity_type: EntityType::BalanceAccount,
+    ///         entity_reference: "BA12345".into(),
+    ///     },
+    ///     outcome_type: OutcomeType::HardBlock,
+    ///     interval: Some(TransactionRuleInterval::new(IntervalType::PerTransaction)),
+    ///     score: None,
+    /// };
+    /// platform.create_transaction_rule(&request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn create_transaction_rule(
         &self,
         request: &CreateTransactionRuleRequest,
     ) -> Result<TransactionRule> {
+        request.validate().map_err(invalid_transaction_rule)?;
         let url = format!(
             "{}/v2/transactionRules",
             self.client
@@ -407,16 +681,20 @@ impl BalancePlatformApi {
 
     /// Update a transaction rule.
     ///
-    /// Updates the transaction rule with the provided information.
+    /// Replaces the transaction rule with the provided information. Use
+    /// [`update_transaction_rule_status`](Self::update_transaction_rule_status)
+    /// to only activate or deactivate a rule.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response cannot be parsed.
+    /// Returns an error if the request is missing fields required by the rule
+    /// type or outcome, the request fails, or the response cannot be parsed.
     pub async fn update_transaction_rule(
         &self,
         transaction_rule_id: &str,
         request: &CreateTransactionRuleRequest,
     ) -> Result<TransactionRule> {
+        request.validate().map_err(invalid_transaction_rule)?;
         let url = format!(
             "{}/v2/transactionRules/{}",
             self.client
@@ -429,6 +707,29 @@ impl BalancePlatformApi {
         Ok(response.data)
     }
 
+    /// Activate or deactivate a transaction rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn update_transaction_rule_status(
+        &self,
+        transaction_rule_id: &str,
+        status: TransactionRuleStatus,
+    ) -> Result<TransactionRule> {
+        let url = format!(
+            "{}/v2/transactionRules/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            transaction_rule_id
+        );
+        let request = UpdateTransactionRuleStatusRequest { status };
+        let response = self.client.patch(&url, &request).await?;
+        Ok(response.data)
+    }
+
     /// Delete a transaction rule.
     ///
     /// Deletes the transaction rule for the specified ID.
@@ -451,28 +752,337 @@ impl BalancePlatformApi {
 
     /// Get all transaction rules for an entity.
     ///
-    /// Returns a list of transaction rules associated with the specified entity.
+    /// Returns a list of transaction rules associated with the specified
+    /// balance account, payment instrument, account holder or payment
+    /// instrument group.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn list_transaction_rules(
         &self,
-        entity_type: &str,
+        entity_type: EntityType,
         entity_id: &str,
     ) -> Result<Vec<TransactionRule>> {
         let url = format!(
-            "{}/v2/transactionRules?entityType={}&entityId={}",
+            "{}/v2/{}/{}/transactionRules",
             self.client
                 .config()
                 .environment()
                 .balance_platform_api_url(),
-            entity_type,
+            entity_type.path_segment(),
             entity_id
         );
-        let response: adyen_core::ApiResponse<PaginatedResponse<TransactionRule>> =
+        let response: adyen_core::ApiResponse<TransactionRulesResponse> =
             self.client.get(&url).await?;
-        Ok(response.data.data)
+        Ok(response.data.transaction_rules)
+    }
+
+    // ============================================================================
+    // Capital (Grant Offers and Grant Accounts)
+    // ============================================================================
+
+    /// Get all grant offers for an account holder.
+    ///
+    /// Returns the grant offers currently available to the specified account holder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_platform::BalancePlatformApi;
+    ///
+    /// # async fn example(platform: BalancePlatformApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// for offer in platform.list_grant_offers("AH12345").await? {
+    ///     if let (Some(total), Some(repayment)) = (offer.total_repayment(), &offer.repayment) {
+    ///         println!(
+    ///             "{}: repay {} {} at {}% of incoming funds",
+    ///             offer.id,
+    ///             total.value,
+    ///             total.currency,
+    ///             repayment.percentage()
+    ///         );
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_grant_offers(&self, account_holder_id: &str) -> Result<Vec<GrantOffer>> {
+        let mut url = url::Url::parse(&format!(
+            "{}/v2/grantOffers",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url()
+        ))?;
+        url.query_pairs_mut()
+            .append_pair("accountHolderId", account_holder_id);
+        let response: adyen_core::ApiResponse<GrantOffersResponse> =
+            self.client.get(url.as_str()).await?;
+        Ok(response.data.grant_offers)
+    }
+
+    /// Get a grant offer by ID.
+    ///
+    /// Returns the grant offer details for the specified ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_grant_offer(&self, grant_offer_id: &str) -> Result<GrantOffer> {
+        let url = format!(
+            "{}/v2/grantOffers/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            grant_offer_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Get a grant account by ID.
+    ///
+    /// Returns the outstanding balances and limits of the specified grant account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_grant_account(&self, grant_account_id: &str) -> Result<GrantAccount> {
+        let url = format!(
+            "{}/v2/grantAccounts/{}",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            grant_account_id
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    // ============================================================================
+    // PIN and Card Reveal
+    // ============================================================================
+
+    /// Get the Adyen public key for encrypting session keys.
+    ///
+    /// Returns the PEM-encoded RSA key for the specified purpose.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_public_key(&self, purpose: PublicKeyPurpose) -> Result<PublicKeyResponse> {
+        let url = format!(
+            "{}/v2/publicKey?purpose={}&format=pem",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url(),
+            purpose.as_str()
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Reveal the PIN of a card.
+    ///
+    /// Returns the PIN as a PIN block encrypted with the session key in the
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn reveal_pin(&self, request: &PinRevealRequest) -> Result<PinRevealResponse> {
+        let url = format!(
+            "{}/v2/pins/reveal",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Change the PIN of a card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn change_pin(&self, request: &PinChangeRequest) -> Result<PinChangeResponse> {
+        let url = format!(
+            "{}/v2/pins/change",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Reveal the card number, expiry date and CVC of a card.
+    ///
+    /// Returns the card details encrypted with the session key in the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn reveal_payment_instrument(
+        &self,
+        request: &PaymentInstrumentRevealRequest,
+    ) -> Result<PaymentInstrumentRevealResponse> {
+        let url = format!(
+            "{}/v2/paymentInstruments/reveal",
+            self.client
+                .config()
+                .environment()
+                .balance_platform_api_url()
+        );
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Reveal and decrypt the PIN of a card.
+    ///
+    /// Fetches the public key, encrypts a fresh session key with it, and
+    /// decrypts the returned PIN block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the random number generator or a request fails, or
+    /// the public key or PIN block is invalid.
+    #[cfg(feature = "encryption")]
+    pub async fn reveal_pin_decrypted(&self, payment_instrument_id: &str) -> Result<String> {
+        let public_key = self.get_public_key(PublicKeyPurpose::PinReveal).await?;
+        let session_key = crate::SessionKey::generate()?;
+        let request = PinRevealRequest {
+            encrypted_key: session_key
+                .encrypt_with_public_key(&public_key.public_key)?
+                .into(),
+            payment_instrument_id: payment_instrument_id.into(),
+        };
+        let response = self.reveal_pin(&request).await?;
+        Ok(session_key.decrypt_pin_block(&response.encrypted_pin_block, &response.token)?)
+    }
+
+    /// Encrypt and set a new PIN for a card.
+    ///
+    /// Fetches the public key, encrypts a fresh session key with it, and sends
+    /// the PIN as an encrypted PIN block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PIN is not 4 to 12 digits, the random number
+    /// generator or a request fails, or the public key is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_platform::{BalancePlatformApi, PinChangeStatus};
+    ///
+    /// # async fn example(platform: BalancePlatformApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let response = platform.change_pin_encrypted("PI12345", "4321").await?;
+    /// if response.status == PinChangeStatus::Completed {
+    ///     println!("PIN changed");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub async fn change_pin_encrypted(
+        &self,
+        payment_instrument_id: &str,
+        pin: &str,
+    ) -> Result<PinChangeResponse> {
+        let public_key = self.get_public_key(PublicKeyPurpose::PinChange).await?;
+        let session_key = crate::SessionKey::generate()?;
+        let token = crate::generate_pin_token()?;
+        let request = PinChangeRequest {
+            encrypted_key: session_key
+                .encrypt_with_public_key(&public_key.public_key)?
+                .into(),
+            encrypted_pin_block: session_key.encrypt_pin_block(pin, &token)?.into(),
+            payment_instrument_id: payment_instrument_id.into(),
+            token: token.into(),
+        };
+        self.change_pin(&request).await
+    }
+
+    /// Reveal and decrypt the card number, expiry date and CVC of a card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the random number generator or a request fails, or
+    /// the public key or card data is invalid.
+    #[cfg(feature = "encryption")]
+    pub async fn reveal_card_decrypted(
+        &self,
+        payment_instrument_id: &str,
+    ) -> Result<crate::RevealedCard> {
+        let public_key = self.get_public_key(PublicKeyPurpose::PanReveal).await?;
+        let session_key = crate::SessionKey::generate()?;
+        let request = PaymentInstrumentRevealRequest {
+            encrypted_key: session_key
+                .encrypt_with_public_key(&public_key.public_key)?
+                .into(),
+            payment_instrument_id: payment_instrument_id.into(),
+        };
+        let response = self.reveal_payment_instrument(&request).await?;
+        Ok(session_key.decrypt_card_data(&response.encrypted_data)?)
+    }
+}
+
+fn invalid_transaction_rule(message: Box<str>) -> AdyenError {
+    AdyenError::generic(format!("Invalid transaction rule: {message}"))
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl BalancePlatformApi {
+        fn create_balance_account_blocking => create_balance_account(request: &CreateBalanceAccountRequest) -> Result<BalanceAccount>;
+        fn get_balance_account_blocking => get_balance_account(balance_account_id: &str) -> Result<BalanceAccount>;
+        fn get_balance_account_balances_blocking => get_balance_account_balances(balance_account_id: &str) -> Result<Vec<Balance>>;
+        fn update_balance_account_blocking => update_balance_account(balance_account_id: &str, request: &CreateBalanceAccountRequest) -> Result<BalanceAccount>;
+        fn close_balance_account_blocking => close_balance_account(balance_account_id: &str) -> Result<BalanceAccount>;
+        fn list_balance_accounts_blocking => list_balance_accounts(account_holder_id: &str) -> Result<Vec<BalanceAccount>>;
+        fn create_account_holder_blocking => create_account_holder(request: &CreateAccountHolderRequest) -> Result<AccountHolder>;
+        fn get_account_holder_blocking => get_account_holder(account_holder_id: &str) -> Result<AccountHolder>;
+        fn update_account_holder_blocking => update_account_holder(account_holder_id: &str, request: &UpdateAccountHolderRequest) -> Result<AccountHolder>;
+        fn suspend_account_holder_blocking => suspend_account_holder(account_holder_id: &str) -> Result<AccountHolder>;
+        fn close_account_holder_blocking => close_account_holder(account_holder_id: &str) -> Result<AccountHolder>;
+        fn list_account_holders_blocking => list_account_holders() -> Result<Vec<AccountHolder>>;
+        fn create_payment_instrument_blocking => create_payment_instrument(request: &CreatePaymentInstrumentRequest) -> Result<PaymentInstrument>;
+        fn get_payment_instrument_blocking => get_payment_instrument(payment_instrument_id: &str) -> Result<PaymentInstrument>;
+        fn update_payment_instrument_blocking => update_payment_instrument(payment_instrument_id: &str, request: &CreatePaymentInstrumentRequest) -> Result<PaymentInstrument>;
+        fn list_payment_instruments_blocking => list_payment_instruments(balance_account_id: &str) -> Result<Vec<PaymentInstrument>>;
+        fn create_payment_instrument_group_blocking => create_payment_instrument_group(request: &CreatePaymentInstrumentGroupRequest) -> Result<PaymentInstrumentGroup>;
+        fn get_payment_instrument_group_blocking => get_payment_instrument_group(payment_instrument_group_id: &str) -> Result<PaymentInstrumentGroup>;
+        fn list_payment_instrument_group_transaction_rules_blocking => list_payment_instrument_group_transaction_rules(payment_instrument_group_id: &str) -> Result<Vec<TransactionRule>>;
+        fn list_network_tokens_blocking => list_network_tokens(payment_instrument_id: &str) -> Result<Vec<NetworkToken>>;
+        fn get_network_token_blocking => get_network_token(network_token_id: &str) -> Result<NetworkToken>;
+        fn update_network_token_blocking => update_network_token(network_token_id: &str, status: NetworkTokenStatus) -> Result<()>;
+        fn create_transaction_rule_blocking => create_transaction_rule(request: &CreateTransactionRuleRequest) -> Result<TransactionRule>;
+        fn get_transaction_rule_blocking => get_transaction_rule(transaction_rule_id: &str) -> Result<TransactionRule>;
+        fn update_transaction_rule_blocking => update_transaction_rule(transaction_rule_id: &str, request: &CreateTransactionRuleRequest) -> Result<TransactionRule>;
+        fn update_transaction_rule_status_blocking => update_transaction_rule_status(transaction_rule_id: &str, status: TransactionRuleStatus) -> Result<TransactionRule>;
+        fn delete_transaction_rule_blocking => delete_transaction_rule(transaction_rule_id: &str) -> Result<()>;
+        fn list_transaction_rules_blocking => list_transaction_rules(entity_type: EntityType, entity_id: &str) -> Result<Vec<TransactionRule>>;
+        fn list_grant_offers_blocking => list_grant_offers(account_holder_id: &str) -> Result<Vec<GrantOffer>>;
+        fn get_grant_offer_blocking => get_grant_offer(grant_offer_id: &str) -> Result<GrantOffer>;
+        fn get_grant_account_blocking => get_grant_account(grant_account_id: &str) -> Result<GrantAccount>;
+        fn get_public_key_blocking => get_public_key(purpose: PublicKeyPurpose) -> Result<PublicKeyResponse>;
+        fn reveal_pin_blocking => reveal_pin(request: &PinRevealRequest) -> Result<PinRevealResponse>;
+        fn change_pin_blocking => change_pin(request: &PinChangeRequest) -> Result<PinChangeResponse>;
+        fn reveal_payment_instrument_blocking => reveal_payment_instrument(request: &PaymentInstrumentRevealRequest) -> Result<PaymentInstrumentRevealResponse>;
+        fn reveal_pin_decrypted_blocking => reveal_pin_decrypted(payment_instrument_id: &str) -> Result<String>;
+        fn change_pin_encrypted_blocking => change_pin_encrypted(payment_instrument_id: &str, pin: &str) -> Result<PinChangeResponse>;
+        fn reveal_card_decrypted_blocking => reveal_card_decrypted(payment_instrument_id: &str) -> Result<crate::RevealedCard>;
     }
 }
 
@@ -0,0 +1,429 @@
+//! Encryption of PIN blocks and decryption of revealed card details.
+//!
+//! Adyen never sends or accepts card PINs or full card numbers in the clear.
+//! Instead, each request uses a fresh session key:
+//!
+//! 1. Fetch Adyen's RSA public key for the purpose with
+//!    [`get_public_key`](crate::BalancePlatformApi::get_public_key).
+//! 2. Generate a random AES-256 [`SessionKey`] and send it encrypted with the
+//!    public key (RSA-OAEP with SHA-512) as the `encryptedKey`.
+//! 3. PINs travel as ISO 9564 format 4 PIN blocks, encrypted with the session
+//!    key (AES-256) and bound to a 16-digit token. Revealed card details are
+//!    encrypted with the session key using AES-256-CBC.
+//!
+//! All binary values are exchanged as uppercase hexadecimal strings. Session
+//! keys, cipher state and the plain text PIN and card data buffers are
+//! zeroized when dropped.
+
+use adyen_core::AdyenError;
+use aes::cipher::{
+    block_padding::Pkcs7, BlockDecrypt, BlockDecryptMut, BlockEncrypt, KeyInit, KeyIvInit,
+};
+use aes::{Aes256, Block};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::rand_core::OsRng;
+use rsa::{Oaep, RsaPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use std::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+type Aes256CbcDec = cbc::Decryptor<Aes256>;
+
+/// Shortest PIN accepted by ISO 9564 format 4.
+const MIN_PIN_LENGTH: usize = 4;
+/// Longest PIN accepted by ISO 9564 format 4.
+const MAX_PIN_LENGTH: usize = 12;
+/// Number of digits in the tokens generated by [`generate_pin_token`].
+const PIN_TOKEN_LENGTH: usize = 16;
+
+/// Error types for PIN and card data encryption.
+#[derive(Debug, thiserror::Error)]
+pub enum PinCryptoError {
+    /// Adyen's public key could not be parsed.
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(String),
+    /// The PIN is not 4 to 12 digits.
+    #[error("PIN must be {MIN_PIN_LENGTH} to {MAX_PIN_LENGTH} digits")]
+    InvalidPin,
+    /// The token is not 12 to 19 digits.
+    #[error("Token must be 12 to 19 digits")]
+    InvalidToken,
+    /// The encrypted data is malformed or was encrypted with another key.
+    #[error("Invalid encrypted data: {0}")]
+    InvalidData(String),
+    /// Encrypting the session key failed.
+    #[error("Encryption failed: {0}")]
+    Encryption(String),
+    /// The operating system random number generator failed.
+    #[error("Random number generator failed: {0}")]
+    Random(String),
+    /// The decrypted card details are not valid JSON.
+    #[error("Invalid decrypted card details: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl From<PinCryptoError> for AdyenError {
+    fn from(error: PinCryptoError) -> Self {
+        Self::generic_with_source("Failed to process encrypted card data", Box::new(error))
+    }
+}
+
+/// Random AES-256 key used for a single PIN or card reveal request.
+///
+/// The key is zeroized when dropped.
+pub struct SessionKey([u8; 32]);
+
+impl SessionKey {
+    /// Generate a random session key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operating system random number generator fails.
+    pub fn generate() -> Result<Self, PinCryptoError> {
+        let mut key = Self([0u8; 32]);
+        getrandom::getrandom(&mut key.0).map_err(random_error)?;
+        Ok(key)
+    }
+
+    /// Use the given bytes as session key.
+    #[must_use]
+    pub const fn from_bytes(key: [u8; 32]) -> Self {
+        Self(key)
+    }
+
+    /// Encrypt this key with Adyen's public key, for the `encryptedKey` field.
+    ///
+    /// `public_key_pem` is the key returned by
+    /// [`get_public_key`](crate::BalancePlatformApi::get_public_key).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the public key is not a PEM-encoded RSA key.
+    pub fn encrypt_with_public_key(&self, public_key_pem: &str) -> Result<String, PinCryptoError> {
+        let public_key = RsaPublicKey::from_public_key_pem(public_key_pem.trim())
+            .map_err(|e| PinCryptoError::InvalidPublicKey(e.to_string()))?;
+        let encrypted = public_key
+            .encrypt(&mut OsRng, Oaep::new::<Sha512>(), &self.0)
+            .map_err(|e| PinCryptoError::Encryption(e.to_string()))?;
+        Ok(encode_hex(&encrypted))
+    }
+
+    /// Encrypt a PIN as an ISO 9564 format 4 PIN block bound to `token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PIN is not 4 to 12 digits, the token is not
+    /// 12 to 19 digits, or the random number generator fails.
+    pub fn encrypt_pin_block(&self, pin: &str, token: &str) -> Result<String, PinCryptoError> {
+        let pin_field = Zeroizing::new(pin_field(pin)?);
+        let pan_field = pan_field(token)?;
+
+        let cipher = Aes256::new(&self.0.into());
+        let mut block = Block::from(*pin_field);
+        cipher.encrypt_block(&mut block);
+        xor_in_place(&mut block, &pan_field);
+        cipher.encrypt_block(&mut block);
+        Ok(encode_hex(&block))
+    }
+
+    /// Decrypt an ISO 9564 format 4 PIN block bound to `token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PIN block is malformed, or was not encrypted
+    /// with this key and token.
+    pub fn decrypt_pin_block(
+        &self,
+        encrypted_pin_block: &str,
+        token: &str,
+    ) -> Result<String, PinCryptoError> {
+        let pan_field = pan_field(token)?;
+        let bytes = decode_hex(encrypted_pin_block)?;
+        let bytes: [u8; 16] = bytes
+            .try_into()
+            .map_err(|_| PinCryptoError::InvalidData("PIN block must be 16 bytes".into()))?;
+
+        let cipher = Aes256::new(&self.0.into());
+        let mut block = Block::from(bytes);
+        cipher.decrypt_block(&mut block);
+        xor_in_place(&mut block, &pan_field);
+        cipher.decrypt_block(&mut block);
+        let pin = parse_pin_field(&block);
+        block.zeroize();
+        pin
+    }
+
+    /// Decrypt the `encryptedData` of a card reveal response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is malformed, or was not encrypted with
+    /// this key.
+    pub fn decrypt_card_data(&self, encrypted_data: &str) -> Result<RevealedCard, PinCryptoError> {
+        let bytes = decode_hex(encrypted_data)?;
+        let plaintext = Aes256CbcDec::new(&self.0.into(), &[0u8; 16].into())
+            .decrypt_padded_vec_mut::<Pkcs7>(&bytes)
+            .map(Zeroizing::new)
+            .map_err(|_| PinCryptoError::InvalidData("invalid padding".into()))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+impl Drop for SessionKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SessionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SessionKey(..)")
+    }
+}
+
+/// Card details decrypted from a card reveal response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevealedCard {
+    /// Full card number.
+    pub pan: String,
+    /// Card verification code.
+    pub cvc: String,
+    /// Expiry date of the card.
+    pub expiration: RevealedCardExpiration,
+}
+
+impl fmt::Debug for RevealedCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevealedCard")
+            .field("pan", &"..")
+            .field("cvc", &"..")
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+/// Expiry date of a revealed card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevealedCardExpiration {
+    /// Two-digit month.
+    pub month: String,
+    /// Four-digit year.
+    pub year: String,
+}
+
+/// Generate a random 16-digit token to bind a new PIN block to.
+///
+/// # Errors
+///
+/// Returns an error if the operating system random number generator fails.
+pub fn generate_pin_token() -> Result<String, PinCryptoError> {
+    let mut token = String::with_capacity(PIN_TOKEN_LENGTH);
+    let mut bytes = [0u8; PIN_TOKEN_LENGTH];
+    while token.len() < PIN_TOKEN_LENGTH {
+        getrandom::getrandom(&mut bytes).map_err(random_error)?;
+        // 250 is the largest multiple of 10 below 256; bytes above it would
+        // make the digits 0-5 more likely than 6-9.
+        let digits = bytes
+            .iter()
+            .filter(|&&b| b < 250)
+            .map(|b| char::from(b'0' + b % 10));
+        token.extend(digits.take(PIN_TOKEN_LENGTH - token.len()));
+    }
+    Ok(token)
+}
+
+fn random_error(error: getrandom::Error) -> PinCryptoError {
+    PinCryptoError::Random(error.to_string())
+}
+
+/// Build the plain text PIN field: format `4`, the PIN length, the PIN
+/// digits, `A` fill up to 16 nibbles, then 8 random bytes.
+fn pin_field(pin: &str) -> Result<[u8; 16], PinCryptoError> {
+    if !(MIN_PIN_LENGTH..=MAX_PIN_LENGTH).contains(&pin.len())
+        || !pin.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(PinCryptoError::InvalidPin);
+    }
+
+    let mut nibbles = [0xAu8; 16];
+    nibbles[0] = 4;
+    // The length is at most 12, so it fits in a nibble.
+    #[allow(clippy::cast_possible_truncation)]
+    {
+        nibbles[1] = pin.len() as u8;
+    }
+    for (nibble, digit) in nibbles[2..].iter_mut().zip(pin.bytes()) {
+        *nibble = digit - b'0';
+    }
+
+    let mut field = [0u8; 16];
+    for (byte, pair) in field.iter_mut().zip(nibbles.chunks(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    nibbles.zeroize();
+    getrandom::getrandom(&mut field[8..]).map_err(random_error)?;
+    Ok(field)
+}
+
+/// Build the plain text PAN field from a token: the token length minus 12,
+/// the token digits, then zero fill.
+fn pan_field(token: &str) -> Result<[u8; 16], PinCryptoError> {
+    if !(12..=19).contains(&token.len()) || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(PinCryptoError::InvalidToken);
+    }
+
+    let mut nibbles = [0u8; 32];
+    // The length is at most 19, so the difference fits in a nibble.
+    #[allow(clippy::cast_possible_truncation)]
+    {
+        nibbles[0] = (token.len() - 12) as u8;
+    }
+    for (nibble, digit) in nibbles[1..].iter_mut().zip(token.bytes()) {
+        *nibble = digit - b'0';
+    }
+
+    let mut field = [0u8; 16];
+    for (byte, pair) in field.iter_mut().zip(nibbles.chunks(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    Ok(field)
+}
+
+fn parse_pin_field(field: &[u8]) -> Result<String, PinCryptoError> {
+    let nibble = |index: usize| {
+        let byte = field[index / 2];
+        if index % 2 == 0 {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        }
+    };
+
+    let length = usize::from(nibble(1));
+    if nibble(0) != 4 || !(MIN_PIN_LENGTH..=MAX_PIN_LENGTH).contains(&length) {
+        return Err(PinCryptoError::InvalidData(
+            "not an ISO 9564 format 4 PIN block for this key and token".into(),
+        ));
+    }
+
+    (2..2 + length)
+        .map(|index| match nibble(index) {
+            digit @ 0..=9 => Ok(char::from(b'0' + digit)),
+            _ => Err(PinCryptoError::InvalidData(
+                "PIN block contains non-digit characters".into(),
+            )),
+        })
+        .collect()
+}
+
+fn xor_in_place(block: &mut [u8], other: &[u8; 16]) {
+    for (byte, mask) in block.iter_mut().zip(other) {
+        *byte ^= mask;
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02X}");
+        hex
+    })
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, PinCryptoError> {
+    if hex.len() % 2 != 0 {
+        return Err(PinCryptoError::InvalidData("odd-length hex string".into()));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| PinCryptoError::InvalidData("invalid hex string".into()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+    use rsa::pkcs8::EncodePublicKey;
+    use rsa::RsaPrivateKey;
+
+    type Aes256CbcEnc = cbc::Encryptor<Aes256>;
+
+    const KEY: SessionKey = SessionKey::from_bytes([7u8; 32]);
+
+    #[test]
+    fn test_pin_block_round_trip() {
+        let token = generate_pin_token().unwrap();
+        assert_eq!(token.len(), 16);
+        assert!(token.bytes().all(|b| b.is_ascii_digit()));
+
+        let encrypted = KEY.encrypt_pin_block("1234", &token).unwrap();
+        assert_eq!(encrypted.len(), 32);
+        assert_eq!(KEY.decrypt_pin_block(&encrypted, &token).unwrap(), "1234");
+
+        // The random fill makes every PIN block different.
+        assert_ne!(encrypted, KEY.encrypt_pin_block("1234", &token).unwrap());
+
+        let other_key = SessionKey::from_bytes([8u8; 32]);
+        assert!(other_key.decrypt_pin_block(&encrypted, &token).is_err());
+    }
+
+    #[test]
+    fn test_pin_field_layout() {
+        let field = pin_field("123456").unwrap();
+        assert_eq!(encode_hex(&field[..8]), "46123456AAAAAAAA");
+        assert_eq!(
+            encode_hex(&pan_field("4111111111111111").unwrap()),
+            "44111111111111111000000000000000"
+        );
+        assert!(matches!(pin_field("123"), Err(PinCryptoError::InvalidPin)));
+        assert!(matches!(pin_field("12a4"), Err(PinCryptoError::InvalidPin)));
+        assert!(matches!(
+            KEY.encrypt_pin_block("1234", "123"),
+            Err(PinCryptoError::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn test_session_key_encryption() {
+        // OAEP with SHA-512 needs a key of at least 1536 bits for a 32-byte message.
+        let private_key = RsaPrivateKey::new(&mut OsRng, 1536).unwrap();
+        let pem = private_key
+            .to_public_key()
+            .to_public_key_pem(rsa::pkcs8::LineEnding::LF)
+            .unwrap();
+
+        let session_key = SessionKey::generate().unwrap();
+        let encrypted = session_key.encrypt_with_public_key(&pem).unwrap();
+        let decrypted = private_key
+            .decrypt(Oaep::new::<Sha512>(), &decode_hex(&encrypted).unwrap())
+            .unwrap();
+        assert_eq!(decrypted, session_key.0);
+
+        assert!(matches!(
+            session_key.encrypt_with_public_key("not a key"),
+            Err(PinCryptoError::InvalidPublicKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_card_data() {
+        let plaintext =
+            br#"{"pan":"5555444433331111","cvc":"737","expiration":{"month":"03","year":"2030"}}"#;
+        let encrypted = Aes256CbcEnc::new(&KEY.0.into(), &[0u8; 16].into())
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+
+        let card = KEY.decrypt_card_data(&encode_hex(&encrypted)).unwrap();
+        assert_eq!(card.pan, "5555444433331111");
+        assert_eq!(card.expiration.year, "2030");
+        assert!(!format!("{card:?}").contains("5555"));
+    }
+}
@@ -6,10 +6,16 @@
 //!
 //! # Features
 //!
-//! - **Balance Account Management**: Create and manage balance accounts that hold funds
-//! - **Account Holder Management**: Manage account holders linked to legal entities
+//! - **Balance Account Management**: Create and manage balance accounts that hold funds, and read their balances
+//! - **Account Holder Management**: Manage account holders linked to legal entities, request
+//!   capabilities, and suspend or close account holders
 //! - **Payment Instruments**: Create cards and bank accounts for payment processing
+//! - **Payment Instrument Groups**: Group payment instruments that share transaction rules
+//! - **Network Tokens**: Suspend, reactivate, or close the network tokens of issued cards
 //! - **Transaction Rules**: Configure rules to control transaction processing
+//! - **Capital**: Retrieve grant offers and grant account balances
+//! - **PIN and Card Reveal**: Change and reveal card PINs, and reveal card numbers and CVCs,
+//!   with the required encryption behind the default `encryption` feature
 //! - **Comprehensive Type Safety**: Full type definitions for all API endpoints
 //! - **Builder Patterns**: Ergonomic request builders with validation
 //!
@@ -40,7 +46,13 @@
 //! ```
 
 pub mod api;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod types;
 
 pub use api::BalancePlatformApi;
+#[cfg(feature = "encryption")]
+pub use encryption::{
+    generate_pin_token, PinCryptoError, RevealedCard, RevealedCardExpiration, SessionKey,
+};
 pub use types::*;
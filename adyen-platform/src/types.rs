@@ -1,5 +1,6 @@
 //! Types for the Adyen Balance Platform API v2.
 
+use adyen_core::Metadata;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,6 +10,11 @@ use std::collections::HashMap;
 
 /// Balance account configuration for marketplace operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BalanceAccount {
     /// Unique identifier of the balance account.
@@ -22,7 +28,7 @@ pub struct BalanceAccount {
     /// Current balances by currency.
     pub balances: Option<Vec<Balance>>,
     /// Metadata about the balance account.
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
     /// Status of the balance account.
     pub status: BalanceAccountStatus,
     /// Time zone for the balance account.
@@ -31,6 +37,11 @@ pub struct BalanceAccount {
 
 /// Request to create a new balance account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBalanceAccountRequest {
     /// Account holder identifier.
@@ -42,13 +53,18 @@ pub struct CreateBalanceAccountRequest {
     /// Default currency code.
     pub default_currency_code: Option<Box<str>>,
     /// Metadata about the balance account.
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
     /// Time zone for the balance account.
     pub time_zone: Option<Box<str>>,
 }
 
 /// Current balance information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
     /// Three-character ISO currency code.
@@ -63,12 +79,20 @@ pub struct Balance {
 
 /// Status of a balance account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum BalanceAccountStatus {
     Active,
     Closed,
     Inactive,
     Suspended,
+    /// A balance account status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 // ============================================================================
@@ -77,6 +101,11 @@ pub enum BalanceAccountStatus {
 
 /// Account holder details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountHolder {
     /// Unique identifier of the account holder.
@@ -96,11 +125,16 @@ pub struct AccountHolder {
     /// Time zone for the account holder.
     pub time_zone: Option<Box<str>>,
     /// Metadata about the account holder.
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
 }
 
 /// Request to create a new account holder.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateAccountHolderRequest {
     /// Legal entity identifier.
@@ -114,21 +148,34 @@ pub struct CreateAccountHolderRequest {
     /// Time zone for the account holder.
     pub time_zone: Option<Box<str>>,
     /// Metadata about the account holder.
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
 }
 
 /// Status of an account holder.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum AccountHolderStatus {
     Active,
     Inactive,
     Suspended,
     Closed,
+    /// An account holder status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Account holder capability configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountHolderCapability {
     /// Whether the capability is allowed.
@@ -141,16 +188,29 @@ pub struct AccountHolderCapability {
 
 /// Verification status for capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum VerificationStatus {
     Pending,
     Valid,
     Invalid,
     Rejected,
+    /// A verification status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Settings for account holder capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CapabilitySettings {
     /// Whether the capability requires additional documentation.
@@ -161,12 +221,165 @@ pub struct CapabilitySettings {
     pub enabled_card_types: Option<Vec<Box<str>>>,
 }
 
+/// Request to update an account holder.
+///
+/// Only the fields that are set are sent, so the other fields of the account
+/// holder are left unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAccountHolderRequest {
+    /// Description of the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+    /// Reference for the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+    /// New status of the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AccountHolderStatus>,
+    /// Capabilities to request or change, keyed by capability name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<HashMap<String, AccountHolderCapabilityRequest>>,
+    /// Balance account that receives the account holder's payouts by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_balance_account: Option<Box<str>>,
+    /// Contact details for the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_details: Option<ContactDetails>,
+    /// Time zone for the account holder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Box<str>>,
+    /// Metadata about the account holder. Replaces the existing metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl UpdateAccountHolderRequest {
+    /// Request to suspend the account holder.
+    ///
+    /// A suspended account holder cannot use its balance accounts and payment
+    /// instruments until it is reactivated.
+    pub fn suspend() -> Self {
+        Self::with_status(AccountHolderStatus::Suspended)
+    }
+
+    /// Request to reactivate a suspended account holder.
+    pub fn reactivate() -> Self {
+        Self::with_status(AccountHolderStatus::Active)
+    }
+
+    /// Request to close the account holder. Closing is permanent.
+    pub fn close() -> Self {
+        Self::with_status(AccountHolderStatus::Closed)
+    }
+
+    fn with_status(status: AccountHolderStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..Self::default()
+        }
+    }
+
+    /// Add a change to a capability, e.g. `receivePayments` or `issueCard`.
+    pub fn capability(
+        mut self,
+        name: impl Into<String>,
+        capability: AccountHolderCapabilityRequest,
+    ) -> Self {
+        self.capabilities
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), capability);
+        self
+    }
+}
+
+/// Change to a capability of an account holder.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHolderCapabilityRequest {
+    /// Whether to request the capability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
+    /// Level of the capability to request, which determines the verification
+    /// requirements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_level: Option<CapabilityLevel>,
+    /// Whether the capability is enabled. Disabling a capability stops its
+    /// use without affecting its verification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl AccountHolderCapabilityRequest {
+    /// Request the capability at the given level.
+    pub fn request(level: CapabilityLevel) -> Self {
+        Self {
+            requested: Some(true),
+            requested_level: Some(level),
+            enabled: None,
+        }
+    }
+
+    /// Enable a capability that was disabled.
+    pub fn enable() -> Self {
+        Self {
+            enabled: Some(true),
+            ..Self::default()
+        }
+    }
+
+    /// Disable the capability.
+    pub fn disable() -> Self {
+        Self {
+            enabled: Some(false),
+            ..Self::default()
+        }
+    }
+}
+
+/// Level of a capability, which determines the verification requirements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum CapabilityLevel {
+    /// Lowest volumes, with the fewest verification checks.
+    Low,
+    /// Medium volumes.
+    Medium,
+    /// Highest volumes, with the most verification checks.
+    High,
+    /// The capability has no levels.
+    NotApplicable,
+    /// A capability level not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
 // ============================================================================
 // Payment Instrument Management
 // ============================================================================
 
 /// Payment instrument configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentInstrument {
     /// Unique identifier of the payment instrument.
@@ -189,6 +402,11 @@ pub struct PaymentInstrument {
 
 /// Request to create a new payment instrument.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePaymentInstrumentRequest {
     /// Balance account ID to associate with.
@@ -207,24 +425,45 @@ pub struct CreatePaymentInstrumentRequest {
 
 /// Type of payment instrument.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PaymentInstrumentType {
     BankAccount,
     Card,
+    /// A payment instrument type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Status of a payment instrument.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PaymentInstrumentStatus {
     Active,
     Closed,
     Inactive,
     Suspended,
+    /// A payment instrument status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Card details for payment instruments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
     /// Card brand (e.g., visa, mastercard).
@@ -245,6 +484,11 @@ pub struct Card {
 
 /// Request to create a new card.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCardRequest {
     /// Card brand to create.
@@ -259,14 +503,27 @@ pub struct CreateCardRequest {
 
 /// Form factor options for cards.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum CardFormFactor {
     Physical,
     Virtual,
+    /// A card form factor not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Bank account details for payment instruments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct BankAccount {
     /// Account number.
@@ -289,6 +546,11 @@ pub struct BankAccount {
 
 /// Request to create a new bank account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBankAccountRequest {
     /// Account number.
@@ -309,12 +571,186 @@ pub struct CreateBankAccountRequest {
     pub currency: Box<str>,
 }
 
+// ============================================================================
+// Payment Instrument Groups
+// ============================================================================
+
+/// Group of payment instruments that share transaction rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInstrumentGroup {
+    /// Unique identifier of the payment instrument group.
+    pub id: Box<str>,
+    /// Balance platform the group belongs to.
+    pub balance_platform: Box<str>,
+    /// Payment method variant of the instruments in the group, e.g. `mc`.
+    pub tx_variant: Box<str>,
+    /// Description of the payment instrument group.
+    pub description: Option<Box<str>>,
+    /// Reference provided during creation.
+    pub reference: Option<Box<str>>,
+    /// Properties of the payment instrument group.
+    pub properties: Option<HashMap<String, String>>,
+}
+
+/// Request to create a new payment instrument group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePaymentInstrumentGroupRequest {
+    /// Balance platform the group belongs to.
+    pub balance_platform: Box<str>,
+    /// Payment method variant of the instruments in the group, e.g. `mc`.
+    pub tx_variant: Box<str>,
+    /// Description of the payment instrument group.
+    pub description: Option<Box<str>>,
+    /// Reference for the payment instrument group.
+    pub reference: Option<Box<str>>,
+    /// Properties of the payment instrument group.
+    pub properties: Option<HashMap<String, String>>,
+}
+
+impl CreatePaymentInstrumentGroupRequest {
+    /// Create a request for a group of `tx_variant` instruments.
+    pub fn new(balance_platform: impl Into<Box<str>>, tx_variant: impl Into<Box<str>>) -> Self {
+        Self {
+            balance_platform: balance_platform.into(),
+            tx_variant: tx_variant.into(),
+            description: None,
+            reference: None,
+            properties: None,
+        }
+    }
+
+    /// Set the description.
+    pub fn description(mut self, description: impl Into<Box<str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the reference.
+    pub fn reference(mut self, reference: impl Into<Box<str>>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+}
+
+// ============================================================================
+// Network Token Management
+// ============================================================================
+
+/// Network token provisioned for a card, e.g. in a digital wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkToken {
+    /// Unique identifier of the network token.
+    pub id: Box<str>,
+    /// Payment instrument (card) the token was provisioned for.
+    pub payment_instrument_id: Option<Box<str>>,
+    /// Status of the network token.
+    pub status: NetworkTokenStatus,
+    /// Wallet or merchant the token was provisioned for, e.g. `applePay`.
+    pub r#type: Option<Box<str>>,
+    /// Card brand variant of the token.
+    pub brand_variant: Option<Box<str>>,
+    /// Date and time the token was created.
+    pub creation_date: Option<Box<str>>,
+    /// Last four digits of the token number.
+    pub token_last_four: Option<Box<str>>,
+    /// Device the token is stored on.
+    pub device: Option<NetworkTokenDevice>,
+}
+
+/// Status of a network token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum NetworkTokenStatus {
+    /// The token can be used for payments.
+    Active,
+    /// The token is not yet activated.
+    Inactive,
+    /// The token is temporarily blocked; it can be reactivated.
+    Suspended,
+    /// The token is permanently closed.
+    Closed,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl NetworkTokenStatus {
+    /// Whether a token can be moved from this status to `target` with
+    /// [`update_network_token`](crate::BalancePlatformApi::update_network_token).
+    ///
+    /// Closed tokens cannot be changed, and only active, suspended and
+    /// closed can be requested.
+    pub fn can_transition_to(self, target: NetworkTokenStatus) -> bool {
+        match (self, target) {
+            (Self::Closed | Self::Unknown, _) => false,
+            (_, Self::Active | Self::Suspended | Self::Closed) => self != target,
+            _ => false,
+        }
+    }
+}
+
+/// Device a network token is stored on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenDevice {
+    /// Form factor of the device, e.g. `mobile_phone`.
+    pub form_factor: Option<Box<str>>,
+    /// Operating system of the device.
+    pub os_name: Option<Box<str>>,
+}
+
+/// Request to update the status of a network token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNetworkTokenRequest {
+    /// New status of the network token.
+    pub status: NetworkTokenStatus,
+}
+
 // ============================================================================
 // Transaction Rules Management
 // ============================================================================
 
 /// Transaction rule configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionRule {
     /// Unique identifier of the transaction rule.
@@ -333,10 +769,23 @@ pub struct TransactionRule {
     pub entity_key: EntityKey,
     /// Outcome when rule is triggered.
     pub outcome_type: OutcomeType,
+    /// Period over which velocity and maximum usage rules are evaluated.
+    pub interval: Option<TransactionRuleInterval>,
+    /// Score added to the transaction when a score-based rule is triggered.
+    pub score: Option<i32>,
 }
 
 /// Request to create a new transaction rule.
+///
+/// Also used to update a rule with
+/// [`update_transaction_rule`](crate::BalancePlatformApi::update_transaction_rule),
+/// which replaces all its fields.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateTransactionRuleRequest {
     /// Description of the transaction rule.
@@ -351,41 +800,371 @@ pub struct CreateTransactionRuleRequest {
     pub entity_key: EntityKey,
     /// Outcome when rule is triggered.
     pub outcome_type: OutcomeType,
+    /// Period over which velocity and maximum usage rules are evaluated.
+    pub interval: Option<TransactionRuleInterval>,
+    /// Score added to the transaction when a score-based rule is triggered.
+    pub score: Option<i32>,
+}
+
+impl CreateTransactionRuleRequest {
+    /// Check that the fields required by the rule type and outcome are set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a velocity or maximum usage rule has no interval,
+    /// or a score-based rule has no score.
+    pub fn validate(&self) -> Result<(), Box<str>> {
+        if matches!(
+            self.r#type,
+            TransactionRuleType::Velocity | TransactionRuleType::MaxUsage
+        ) && self.interval.is_none()
+        {
+            return Err("interval is required for velocity and maxUsage rules".into());
+        }
+        match (&self.outcome_type, self.score) {
+            (OutcomeType::ScoreBased, None) => {
+                Err("score is required for scoreBased outcomes".into())
+            }
+            (OutcomeType::ScoreBased, Some(_)) | (_, None) => Ok(()),
+            (_, Some(_)) => Err("score is only allowed for scoreBased outcomes".into()),
+        }
+    }
+}
+
+/// Request to update only the status of a transaction rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTransactionRuleStatusRequest {
+    /// New status of the transaction rule.
+    pub status: TransactionRuleStatus,
 }
 
 /// Status of a transaction rule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionRuleStatus {
     Active,
     Inactive,
+    /// A transaction rule status not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Type of transaction rule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionRuleType {
     Velocity,
     BlockList,
     AllowList,
+    /// Limits the number of times a payment instrument can be used.
+    MaxUsage,
+    /// A transaction rule type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Transaction rule restrictions configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionRuleRestrictions {
     /// Maximum transaction amount restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_amount: Option<Amount>,
     /// Velocity restrictions (limits over time periods).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub velocity: Option<VelocityRestriction>,
     /// Geographic restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub processing_types: Option<ProcessingTypesRestriction>,
     /// Time-based restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time_period: Option<TimePeriodRestriction>,
+    /// Merchant category codes of the transaction, e.g. `7995`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mccs: Option<ListRestriction<Box<str>>>,
+    /// Countries of the merchant, as ISO 3166-1 alpha-2 codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countries: Option<ListRestriction<Box<str>>>,
+    /// How the card details were entered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_modes: Option<ListRestriction<EntryMode>>,
+    /// Whether the transaction currency differs from the balance account
+    /// currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub different_currencies: Option<ComparisonRestriction<bool>>,
+    /// Number of transactions that match the other restrictions within the
+    /// rule interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_transactions: Option<ComparisonRestriction<u32>>,
+}
+
+/// Restriction that matches a transaction value against a list of values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRestriction<T> {
+    /// Whether the transaction value must or must not be in the list.
+    pub operation: MatchOperation,
+    /// Values to match.
+    pub value: Vec<T>,
+}
+
+impl<T> ListRestriction<T> {
+    /// Match transactions whose value is one of `values`.
+    pub fn any_of(values: impl IntoIterator<Item = impl Into<T>>) -> Self {
+        Self {
+            operation: MatchOperation::AnyMatch,
+            value: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Match transactions whose value is none of `values`.
+    pub fn none_of(values: impl IntoIterator<Item = impl Into<T>>) -> Self {
+        Self {
+            operation: MatchOperation::NoneMatch,
+            value: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// How a list restriction matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum MatchOperation {
+    /// The value is in the list.
+    AnyMatch,
+    /// The value is not in the list.
+    NoneMatch,
+    /// A match operation not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Restriction that compares a transaction value to a single value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonRestriction<T> {
+    /// How the transaction value is compared.
+    pub operation: ComparisonOperation,
+    /// Value to compare with.
+    pub value: T,
+}
+
+impl<T> ComparisonRestriction<T> {
+    /// Match transactions whose value equals `value`.
+    pub fn equals(value: T) -> Self {
+        Self {
+            operation: ComparisonOperation::Equals,
+            value,
+        }
+    }
+
+    /// Match transactions whose value is greater than `value`.
+    pub fn greater_than(value: T) -> Self {
+        Self {
+            operation: ComparisonOperation::GreaterThan,
+            value,
+        }
+    }
+
+    /// Match transactions whose value is less than `value`.
+    pub fn less_than(value: T) -> Self {
+        Self {
+            operation: ComparisonOperation::LessThan,
+            value,
+        }
+    }
+}
+
+/// How a comparison restriction compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ComparisonOperation {
+    Equals,
+    NotEquals,
+    GreaterThan,
+    GreaterThanOrEqualTo,
+    LessThan,
+    LessThanOrEqualTo,
+    /// A comparison operation not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How the card details of a transaction were entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum EntryMode {
+    Barcode,
+    CardOnFile,
+    Chip,
+    Contactless,
+    Ecommerce,
+    Magstripe,
+    Manual,
+    Ocr,
+    Server,
+    /// An entry mode not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Period over which a transaction rule is evaluated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRuleInterval {
+    /// Type of interval.
+    pub r#type: IntervalType,
+    /// Day the weekly interval starts, e.g. `monday`.
+    pub day_of_week: Option<Box<str>>,
+    /// Day of the month the monthly interval starts.
+    pub day_of_month: Option<u8>,
+    /// Time of day the interval starts, in `hh:mm:ss` format.
+    pub time_of_day: Option<Box<str>>,
+    /// Time zone of the interval start, e.g. `Europe/Amsterdam`.
+    pub time_zone: Option<Box<str>>,
+    /// Length of rolling and sliding intervals.
+    pub duration: Option<IntervalDuration>,
+}
+
+impl TransactionRuleInterval {
+    /// Create an interval of the given type.
+    pub fn new(r#type: IntervalType) -> Self {
+        Self {
+            r#type,
+            day_of_week: None,
+            day_of_month: None,
+            time_of_day: None,
+            time_zone: None,
+            duration: None,
+        }
+    }
+
+    /// Create a sliding interval covering the last `value` `unit`s.
+    pub fn sliding(value: u32, unit: DurationUnit) -> Self {
+        Self {
+            duration: Some(IntervalDuration { unit, value }),
+            ..Self::new(IntervalType::Sliding)
+        }
+    }
+}
+
+/// Type of transaction rule interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum IntervalType {
+    /// Each transaction is evaluated on its own.
+    PerTransaction,
+    Daily,
+    Weekly,
+    Monthly,
+    /// Since the payment instrument was created.
+    Lifetime,
+    /// Fixed-length periods starting at a given time.
+    Rolling,
+    /// The period of the given duration before each transaction.
+    Sliding,
+    /// An interval type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Length of a rolling or sliding interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct IntervalDuration {
+    /// Unit of the duration.
+    pub unit: DurationUnit,
+    /// Number of units.
+    pub value: u32,
+}
+
+/// Unit of an interval duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DurationUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    /// A duration unit not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Entity key for transaction rule application.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityKey {
     /// Type of entity.
@@ -396,24 +1175,62 @@ pub struct EntityKey {
 
 /// Type of entity for transaction rules.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum EntityType {
     BalanceAccount,
     PaymentInstrument,
     AccountHolder,
+    PaymentInstrumentGroup,
+}
+
+impl EntityType {
+    /// Path segment of the resource, e.g. `balanceAccounts`.
+    pub const fn path_segment(&self) -> &'static str {
+        match self {
+            Self::BalanceAccount => "balanceAccounts",
+            Self::PaymentInstrument => "paymentInstruments",
+            Self::AccountHolder => "accountHolders",
+            Self::PaymentInstrumentGroup => "paymentInstrumentGroups",
+        }
+    }
 }
 
 /// Outcome type when transaction rule is triggered.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum OutcomeType {
     HardBlock,
     AdviseOnly,
     AskAcquirer,
+    /// The transaction is allowed; used by allow list rules.
+    Enabled,
+    /// The rule's score is added to the transaction, which is declined once
+    /// the total score reaches 100.
+    ScoreBased,
+    /// Transactions are declined for the rest of the rule interval.
+    TimedBlock,
+    /// An outcome type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Amount restriction configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Amount {
     /// Currency code.
@@ -424,6 +1241,11 @@ pub struct Amount {
 
 /// Velocity restriction configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct VelocityRestriction {
     /// Maximum number of transactions allowed.
@@ -434,6 +1256,11 @@ pub struct VelocityRestriction {
 
 /// Processing types restriction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessingTypesRestriction {
     /// Processing types to include or exclude.
@@ -444,6 +1271,11 @@ pub struct ProcessingTypesRestriction {
 
 /// Time period restriction configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct TimePeriodRestriction {
     /// Start time for restrictions.
@@ -456,30 +1288,398 @@ pub struct TimePeriodRestriction {
 
 /// Time period options for velocity restrictions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TimePeriod {
     Daily,
     Weekly,
     Monthly,
     Lifetime,
+    /// A time period not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Processing type categories.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum ProcessingType {
     Pos,
     Ecommerce,
     ContAuth,
     Moto,
+    /// A processing type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Restriction operation type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum RestrictionOperation {
     Include,
     Exclude,
+    /// A restriction operation not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+// ============================================================================
+// Capital (Grant Offers and Grant Accounts)
+// ============================================================================
+
+/// Offer of a grant (business financing) to an account holder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantOffer {
+    /// Unique identifier of the grant offer.
+    pub id: Box<str>,
+    /// Account holder the offer is made to.
+    pub account_holder_id: Box<str>,
+    /// Principal amount of the grant.
+    pub amount: Option<Amount>,
+    /// Type of contract of the grant.
+    pub contract_type: Option<GrantContractType>,
+    /// Fee charged for the grant.
+    pub fee: Option<GrantFee>,
+    /// How the grant is repaid.
+    pub repayment: Option<GrantRepayment>,
+    /// Date and time from which the offer can be accepted.
+    pub starts_at: Option<Box<str>>,
+    /// Date and time after which the offer can no longer be accepted.
+    pub expires_at: Option<Box<str>>,
+}
+
+impl GrantOffer {
+    /// Total amount to repay, the principal plus the fee, in minor units.
+    ///
+    /// Returns `None` if the offer has no amount, or the fee is in another
+    /// currency.
+    pub fn total_repayment(&self) -> Option<Amount> {
+        let amount = self.amount.as_ref()?;
+        let fee = match &self.fee {
+            None => 0,
+            Some(fee) if fee.amount.currency == amount.currency => fee.amount.value,
+            Some(_) => return None,
+        };
+        Some(Amount {
+            currency: amount.currency.clone(),
+            value: amount.value + fee,
+        })
+    }
+}
+
+/// Type of contract of a grant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum GrantContractType {
+    /// Repaid as a percentage of incoming funds; the fee is fixed.
+    CashAdvance,
+    /// Repaid in fixed instalments with interest.
+    Loan,
+    /// A contract type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fee charged for a grant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantFee {
+    /// Fee amount.
+    pub amount: Amount,
+}
+
+/// Repayment conditions of a grant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantRepayment {
+    /// Share of incoming funds used for repayment, in basis points
+    /// (`1000` is 10%).
+    pub basis_points: u32,
+    /// Expected and maximum repayment term.
+    pub term: Option<RepaymentTerm>,
+    /// Minimum amount repaid per repayment period.
+    pub threshold: Option<RepaymentThreshold>,
+}
+
+impl GrantRepayment {
+    /// Share of incoming funds used for repayment, as a percentage.
+    pub fn percentage(&self) -> f64 {
+        f64::from(self.basis_points) / 100.0
+    }
+}
+
+/// Expected and maximum repayment term of a grant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RepaymentTerm {
+    /// Expected number of days to repay the grant.
+    pub estimated_days: u32,
+    /// Maximum number of days to repay the grant.
+    pub maximum_days: Option<u32>,
+}
+
+/// Minimum amount repaid per repayment period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RepaymentThreshold {
+    /// Minimum repayment amount.
+    pub amount: Amount,
+}
+
+/// Account that tracks the grants of an account holder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantAccount {
+    /// Unique identifier of the grant account.
+    pub id: Box<str>,
+    /// Balance account grants are paid out to and repaid from.
+    pub funding_balance_account_id: Option<Box<str>>,
+    /// Outstanding balances by currency.
+    #[serde(default)]
+    pub balances: Vec<GrantAccountBalance>,
+    /// Limits on the total amount of grants.
+    #[serde(default)]
+    pub limits: Vec<GrantLimit>,
+}
+
+/// Outstanding balance of a grant account, in minor units.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantAccountBalance {
+    /// Three-character ISO currency code.
+    pub currency: Box<str>,
+    /// Outstanding fee.
+    pub fee: i64,
+    /// Outstanding principal.
+    pub principal: i64,
+    /// Total outstanding amount, the principal plus the fee.
+    pub total: i64,
+}
+
+/// Limit on the total amount of grants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantLimit {
+    /// Maximum amount.
+    pub amount: Amount,
+}
+
+// ============================================================================
+// PIN and Card Reveal
+// ============================================================================
+
+/// What an Adyen public key is used to encrypt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PublicKeyPurpose {
+    /// Encrypting the session key of a PIN change.
+    PinChange,
+    /// Encrypting the session key of a PIN reveal.
+    PinReveal,
+    /// Encrypting the session key of a card number and CVC reveal.
+    PanReveal,
+}
+
+impl PublicKeyPurpose {
+    /// Value of the `purpose` query parameter.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PinChange => "pinChange",
+            Self::PinReveal => "pinReveal",
+            Self::PanReveal => "panReveal",
+        }
+    }
+}
+
+/// Adyen public key for encrypting session keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyResponse {
+    /// PEM-encoded RSA public key.
+    pub public_key: Box<str>,
+    /// Date after which the key can no longer be used.
+    pub public_key_expiry_date: Option<Box<str>>,
+}
+
+/// Request to reveal the PIN of a card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PinRevealRequest {
+    /// Session key encrypted with the `pinReveal` public key, in hex.
+    pub encrypted_key: Box<str>,
+    /// Payment instrument of the card.
+    pub payment_instrument_id: Box<str>,
+}
+
+/// Encrypted PIN of a card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PinRevealResponse {
+    /// ISO 9564 format 4 PIN block encrypted with the session key, in hex.
+    pub encrypted_pin_block: Box<str>,
+    /// Token the PIN block is bound to.
+    pub token: Box<str>,
+}
+
+/// Request to change the PIN of a card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PinChangeRequest {
+    /// Session key encrypted with the `pinChange` public key, in hex.
+    pub encrypted_key: Box<str>,
+    /// ISO 9564 format 4 PIN block encrypted with the session key, in hex.
+    pub encrypted_pin_block: Box<str>,
+    /// Payment instrument of the card.
+    pub payment_instrument_id: Box<str>,
+    /// 16-digit token the PIN block is bound to.
+    pub token: Box<str>,
+}
+
+/// Result of a PIN change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PinChangeResponse {
+    /// Status of the PIN change.
+    pub status: PinChangeStatus,
+}
+
+/// Status of a PIN change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub enum PinChangeStatus {
+    /// The new PIN is active.
+    Completed,
+    /// The new PIN is being processed.
+    Pending,
+    /// PIN changes are not available for the card.
+    Unavailable,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request to reveal the card number, expiry date and CVC of a card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInstrumentRevealRequest {
+    /// Session key encrypted with the `panReveal` public key, in hex.
+    pub encrypted_key: Box<str>,
+    /// Payment instrument of the card.
+    pub payment_instrument_id: Box<str>,
+}
+
+/// Encrypted card details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInstrumentRevealResponse {
+    /// Card details encrypted with the session key (AES-256-CBC), in hex.
+    pub encrypted_data: Box<str>,
 }
 
 // ============================================================================
@@ -488,6 +1688,11 @@ pub enum RestrictionOperation {
 
 /// Contact details for account holders.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct ContactDetails {
     /// Email address.
@@ -502,6 +1707,11 @@ pub struct ContactDetails {
 
 /// Phone number details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Phone {
     /// Phone number.
@@ -512,15 +1722,28 @@ pub struct Phone {
 
 /// Type of phone number.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub enum PhoneType {
     Mobile,
     Landline,
     Fax,
+    /// A phone type not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Address details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// Country code (ISO 3166-1 alpha-2).
@@ -541,8 +1764,68 @@ pub struct Address {
 // Response Types
 // ============================================================================
 
+/// Response wrapper for transaction rule lists.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRulesResponse {
+    /// Transaction rules.
+    #[serde(default)]
+    pub transaction_rules: Vec<TransactionRule>,
+}
+
+/// Response wrapper for network token listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokensResponse {
+    /// Network tokens of the payment instrument.
+    #[serde(default)]
+    pub network_tokens: Vec<NetworkToken>,
+}
+
+/// Response wrapper for a single network token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenResponse {
+    /// The network token.
+    pub token: NetworkToken,
+}
+
+/// Response wrapper for grant offer listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrantOffersResponse {
+    /// Grant offers for the account holder.
+    #[serde(default)]
+    pub grant_offers: Vec<GrantOffer>,
+}
+
 /// Response wrapper for paginated results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginatedResponse<T> {
     /// Array of result items.
@@ -572,7 +1855,7 @@ pub struct CreateBalanceAccountRequestBuilder {
     description: Option<Box<str>>,
     reference: Option<Box<str>>,
     default_currency_code: Option<Box<str>>,
-    metadata: Option<HashMap<String, String>>,
+    metadata: Option<Metadata>,
     time_zone: Option<Box<str>>,
 }
 
@@ -607,7 +1890,7 @@ impl CreateBalanceAccountRequestBuilder {
 
     /// Set metadata.
     #[must_use]
-    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
         self.metadata = Some(metadata);
         self
     }
@@ -1,12 +1,12 @@
 //! Integration tests for the Adyen Balance Platform API v2.
 
-use adyen_core::{ConfigBuilder, Environment};
+use adyen_core::{ConfigBuilder, Environment, Metadata};
 use adyen_platform::types::*;
 use adyen_platform::{
-    BalancePlatformApi, CreateAccountHolderRequest, CreateBalanceAccountRequest,
-    CreatePaymentInstrumentRequest, CreateTransactionRuleRequest,
+    generate_pin_token, BalancePlatformApi, CreateAccountHolderRequest,
+    CreateBalanceAccountRequest, CreatePaymentInstrumentRequest, CreateTransactionRuleRequest,
+    SessionKey,
 };
-use std::collections::HashMap;
 
 fn create_test_config() -> adyen_core::Config {
     ConfigBuilder::new()
@@ -23,8 +23,8 @@ mod request_building_tests {
 
     #[test]
     fn test_create_balance_account_request_builder() {
-        let mut metadata = HashMap::new();
-        metadata.insert("purpose".to_string(), "marketplace".to_string());
+        let mut metadata = Metadata::new();
+        metadata.insert("purpose", "marketplace").unwrap();
 
         let request = CreateBalanceAccountRequest::builder()
             .account_holder_id("AH12345")
@@ -46,7 +46,10 @@ mod request_building_tests {
             request.default_currency_code.as_ref().unwrap().as_ref(),
             "EUR"
         );
-        assert!(request.metadata.is_some());
+        assert_eq!(
+            request.metadata.as_ref().unwrap().get("purpose"),
+            Some("marketplace")
+        );
         assert_eq!(
             request.time_zone.as_ref().unwrap().as_ref(),
             "Europe/Amsterdam"
@@ -126,6 +129,7 @@ mod request_building_tests {
             }),
             processing_types: None,
             time_period: None,
+            ..TransactionRuleRestrictions::default()
         };
 
         let entity_key = EntityKey {
@@ -140,6 +144,8 @@ mod request_building_tests {
             rule_restrictions: restrictions,
             entity_key,
             outcome_type: OutcomeType::HardBlock,
+            interval: Some(TransactionRuleInterval::new(IntervalType::Daily)),
+            score: None,
         };
 
         assert_eq!(
@@ -229,6 +235,207 @@ mod serialization_tests {
         let _deserialized: PaymentInstrument = serde_json::from_str(&json).unwrap();
     }
 
+    #[test]
+    fn test_grant_offer_deserialization() {
+        let json = r#"{
+            "id": "GO00000000000000000000001",
+            "accountHolderId": "AH00000000000000000000001",
+            "amount": {"currency": "EUR", "value": 1000000},
+            "contractType": "cashAdvance",
+            "fee": {"amount": {"currency": "EUR", "value": 120000}},
+            "repayment": {
+                "basisPoints": 1500,
+                "term": {"estimatedDays": 180, "maximumDays": 540},
+                "threshold": {"amount": {"currency": "EUR", "value": 15000}}
+            },
+            "startsAt": "2026-10-01T00:00:00+02:00",
+            "expiresAt": "2026-10-31T00:00:00+01:00"
+        }"#;
+
+        let offer: GrantOffer = serde_json::from_str(json).unwrap();
+        assert_eq!(offer.contract_type, Some(GrantContractType::CashAdvance));
+        assert_eq!(offer.total_repayment().unwrap().value, 1120000);
+
+        let repayment = offer.repayment.unwrap();
+        assert!((repayment.percentage() - 15.0).abs() < f64::EPSILON);
+        assert_eq!(repayment.term.unwrap().maximum_days, Some(540));
+
+        let account: GrantAccount = serde_json::from_str(
+            r#"{
+                "id": "CG00000000000000000000001",
+                "fundingBalanceAccountId": "BA00000000000000000000001",
+                "balances": [{"currency": "EUR", "fee": 60000, "principal": 500000, "total": 560000}],
+                "limits": [{"amount": {"currency": "EUR", "value": 2000000}}]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(account.balances[0].total, 560000);
+        assert_eq!(account.limits[0].amount.value, 2000000);
+    }
+
+    #[test]
+    fn test_network_token_serialization() {
+        let json = r#"{
+            "id": "NT00000000000000000000001",
+            "paymentInstrumentId": "PI00000000000000000000001",
+            "status": "active",
+            "type": "applePay",
+            "brandVariant": "mcdebit",
+            "creationDate": "2026-10-01T12:00:00+02:00",
+            "tokenLastFour": "1234",
+            "device": {"formFactor": "mobile_phone", "osName": "iOS"}
+        }"#;
+
+        let token: NetworkToken = serde_json::from_str(json).unwrap();
+        assert_eq!(token.status, NetworkTokenStatus::Active);
+        assert!(token
+            .status
+            .can_transition_to(NetworkTokenStatus::Suspended));
+        assert!(!token.status.can_transition_to(NetworkTokenStatus::Active));
+        assert!(!NetworkTokenStatus::Closed.can_transition_to(NetworkTokenStatus::Active));
+
+        let request = UpdateNetworkTokenRequest {
+            status: NetworkTokenStatus::Closed,
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"status": "closed"})
+        );
+    }
+
+    #[test]
+    fn test_transaction_rule_restrictions_serialization() {
+        let mut request = CreateTransactionRuleRequest {
+            description: Some("Score foreign contactless payments".into()),
+            reference: None,
+            r#type: TransactionRuleType::Velocity,
+            rule_restrictions: TransactionRuleRestrictions {
+                mccs: Some(ListRestriction::none_of(["5411"])),
+                countries: Some(ListRestriction::any_of(["NL", "BE"])),
+                entry_modes: Some(ListRestriction::any_of([EntryMode::Contactless])),
+                different_currencies: Some(ComparisonRestriction::equals(true)),
+                matching_transactions: Some(ComparisonRestriction::greater_than(5)),
+                ..TransactionRuleRestrictions::default()
+            },
+            entity_key: EntityKey {
+                entity_type: EntityType::PaymentInstrumentGroup,
+                entity_reference: "PG12345".into(),
+            },
+            outcome_type: OutcomeType::ScoreBased,
+            interval: Some(TransactionRuleInterval::sliding(24, DurationUnit::Hours)),
+            score: Some(40),
+        };
+        assert!(request.validate().is_ok());
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["ruleRestrictions"],
+            serde_json::json!({
+                "mccs": {"operation": "noneMatch", "value": ["5411"]},
+                "countries": {"operation": "anyMatch", "value": ["NL", "BE"]},
+                "entryModes": {"operation": "anyMatch", "value": ["contactless"]},
+                "differentCurrencies": {"operation": "equals", "value": true},
+                "matchingTransactions": {"operation": "greaterThan", "value": 5}
+            })
+        );
+        assert_eq!(
+            json["interval"],
+            serde_json::json!({
+                "type": "sliding",
+                "dayOfWeek": null,
+                "dayOfMonth": null,
+                "timeOfDay": null,
+                "timeZone": null,
+                "duration": {"unit": "hours", "value": 24}
+            })
+        );
+        assert_eq!(json["outcomeType"], "scoreBased");
+        assert_eq!(json["entityKey"]["entityType"], "paymentInstrumentGroup");
+
+        request.score = None;
+        assert!(request.validate().is_err());
+        request.outcome_type = OutcomeType::HardBlock;
+        request.interval = None;
+        assert_eq!(
+            request.validate().unwrap_err().as_ref(),
+            "interval is required for velocity and maxUsage rules"
+        );
+    }
+
+    #[test]
+    fn test_update_account_holder_request_serialization() {
+        let request = UpdateAccountHolderRequest::default()
+            .capability(
+                "issueCard",
+                AccountHolderCapabilityRequest::request(CapabilityLevel::Medium),
+            )
+            .capability(
+                "sendToTransferInstrument",
+                AccountHolderCapabilityRequest::disable(),
+            );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "capabilities": {
+                    "issueCard": {"requested": true, "requestedLevel": "medium"},
+                    "sendToTransferInstrument": {"enabled": false}
+                }
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(UpdateAccountHolderRequest::suspend()).unwrap(),
+            serde_json::json!({"status": "suspended"})
+        );
+
+        let group: PaymentInstrumentGroup = serde_json::from_str(
+            r#"{
+                "id": "PG00000000000000000000001",
+                "balancePlatform": "YOUR_BALANCE_PLATFORM",
+                "txVariant": "mc",
+                "description": "Travel cards"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(group.tx_variant.as_ref(), "mc");
+        let request = CreatePaymentInstrumentGroupRequest::new("YOUR_BALANCE_PLATFORM", "mc")
+            .description("Travel cards");
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["txVariant"],
+            serde_json::json!("mc")
+        );
+    }
+
+    #[test]
+    fn test_pin_change_serialization() {
+        let session_key = SessionKey::generate().unwrap();
+        let token = generate_pin_token().unwrap();
+        let request = PinChangeRequest {
+            encrypted_key: "A1B2C3".into(),
+            encrypted_pin_block: session_key
+                .encrypt_pin_block("4321", &token)
+                .unwrap()
+                .into(),
+            payment_instrument_id: "PI00000000000000000000001".into(),
+            token: token.clone().into(),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["encryptedKey"], "A1B2C3");
+        assert_eq!(json["token"], token.as_str());
+        assert_eq!(
+            session_key
+                .decrypt_pin_block(json["encryptedPinBlock"].as_str().unwrap(), &token)
+                .unwrap(),
+            "4321"
+        );
+
+        let response: PinChangeResponse =
+            serde_json::from_str(r#"{"status": "completed"}"#).unwrap();
+        assert_eq!(response.status, PinChangeStatus::Completed);
+        assert_eq!(PublicKeyPurpose::PanReveal.as_str(), "panReveal");
+    }
+
     #[test]
     fn test_enum_serialization() {
         assert_eq!(
@@ -374,12 +581,15 @@ mod workflow_tests {
                 }),
                 processing_types: None,
                 time_period: None,
+                ..TransactionRuleRestrictions::default()
             },
             entity_key: EntityKey {
                 entity_type: EntityType::BalanceAccount,
                 entity_reference: balance_account.id.clone(),
             },
             outcome_type: OutcomeType::HardBlock,
+            interval: Some(TransactionRuleInterval::new(IntervalType::Daily)),
+            score: None,
         };
 
         // Verify the workflow relationships
@@ -478,12 +688,15 @@ mod workflow_tests {
                     operation: RestrictionOperation::Include,
                 }),
                 time_period: None,
+                ..TransactionRuleRestrictions::default()
             },
             entity_key: EntityKey {
                 entity_type: EntityType::BalanceAccount,
                 entity_reference: balance_account.id.clone(),
             },
             outcome_type: OutcomeType::HardBlock,
+            interval: None,
+            score: None,
         };
 
         // Verify multi-currency and geographic controls
@@ -523,12 +736,15 @@ mod workflow_tests {
                     end_time: Some("17:00".into()),
                     time_zone: Some("Europe/Amsterdam".into()),
                 }),
+                ..TransactionRuleRestrictions::default()
             },
             entity_key: EntityKey {
                 entity_type: EntityType::PaymentInstrument,
                 entity_reference: "PI12345".into(),
             },
             outcome_type: OutcomeType::AdviseOnly,
+            interval: Some(TransactionRuleInterval::new(IntervalType::Daily)),
+            score: None,
         };
 
         // Verify comprehensive restrictions
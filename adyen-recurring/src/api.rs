@@ -1,7 +1,7 @@
 //! Recurring API client implementation.
 
 use crate::types::*;
-use adyen_core::{Client, Config, Result};
+use adyen_core::{Api, Client, Config, Result};
 
 /// Adyen Recurring API client.
 ///
@@ -36,10 +36,21 @@ impl RecurringApi {
     ///
     /// Returns an error if the underlying HTTP client cannot be created.
     pub fn new(config: Config) -> Result<Self> {
-        let client = Client::new(config)?;
+        let client = Client::for_api(config, Api::Recurring)?;
         Ok(Self { client })
     }
 
+    /// Create a new Recurring API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Recurring),
+        }
+    }
+
     /// Retrieve stored payment methods for a shopper.
     ///
     /// Returns a list of the shopper's stored payment methods available for recurring payments.
@@ -191,6 +202,18 @@ impl RecurringApi {
     }
 }
 
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl RecurringApi {
+        fn list_recurring_details_blocking => list_recurring_details(request: &RecurringDetailsRequest) -> Result<RecurringDetailsResult>;
+        fn disable_blocking => disable(request: &DisableRequest) -> Result<DisableResult>;
+        fn notify_shopper_blocking => notify_shopper(request: &NotifyShopperRequest) -> Result<NotifyShopperResult>;
+        fn schedule_account_updater_blocking => schedule_account_updater(request: &ScheduleAccountUpdaterRequest) -> Result<ScheduleAccountUpdaterResult>;
+        fn create_permit_blocking => create_permit(request: &CreatePermitRequest) -> Result<CreatePermitResult>;
+        fn disable_permit_blocking => disable_permit(request: &DisablePermitRequest) -> Result<DisablePermitResult>;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,6 +37,8 @@ pub mod types;
 // Re-export main types for convenience
 pub use api::RecurringApi;
 pub use types::{
+    AccountUpdaterResultCode,
+    AccountUpdaterUpdate,
     BankAccount,
     Card,
     CreatePermitRequest,
@@ -59,5 +61,8 @@ pub use types::{
     RecurringDetailsRequestBuilder,
     RecurringDetailsResult,
     ScheduleAccountUpdaterRequest,
+    ScheduleAccountUpdaterRequestBuilder,
     ScheduleAccountUpdaterResult,
+    StoredPaymentMethod,
+    TokenDetails,
 };
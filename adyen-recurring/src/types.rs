@@ -2,7 +2,8 @@
 //!
 //! This module contains all request and response types for recurring payment operations.
 
-use adyen_core::{AdyenError, Amount, Result};
+use adyen_core::{AdyenError, Amount, PspReference, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -23,10 +24,13 @@ pub struct RecurringDetailsRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RecurringDetailsResult {
+    /// The date the first recurring contract of the shopper was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<DateTime<Utc>>,
     /// Details of the recurring payments.
     #[serde(default)]
     pub details: Vec<RecurringDetail>,
-    /// The date when the recurring details were last updated.
+    /// The most recent email address of the shopper.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_known_shopper_email: Option<Box<str>>,
     /// The shopper reference.
@@ -92,6 +96,11 @@ pub struct NotifyShopperResult {
 }
 
 /// Request to schedule the Account Updater for stored payment methods.
+///
+/// The card to check is either given directly in `card`, or as a stored
+/// payment method through `selected_recurring_detail_reference` and
+/// `shopper_reference`. The result is reported in an `ACCOUNT_UPDATER`
+/// webhook; see [`AccountUpdaterUpdate`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ScheduleAccountUpdaterRequest {
@@ -99,11 +108,19 @@ pub struct ScheduleAccountUpdaterRequest {
     pub merchant_account: Box<str>,
     /// Reference for this request.
     pub reference: Box<str>,
-    /// The shopper's reference.
-    pub shopper_reference: Box<str>,
-    /// Specific card details to update.
+    /// The shopper's reference, required with
+    /// `selected_recurring_detail_reference`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<Box<str>>,
+    /// The stored payment method to check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_recurring_detail_reference: Option<Box<str>>,
+    /// The card to check, with its full card number.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<Card>,
+    /// Additional data for this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<HashMap<String, String>>,
 }
 
 /// Result of scheduling the Account Updater.
@@ -113,6 +130,10 @@ pub struct ScheduleAccountUpdaterResult {
     /// The PSP reference for this request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub psp_reference: Option<Box<str>>,
+    /// `Success` if the Account Updater was scheduled, otherwise an error
+    /// message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Box<str>>,
     /// Response message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response: Option<Box<str>>,
@@ -121,35 +142,372 @@ pub struct ScheduleAccountUpdaterResult {
     pub result_code: Option<Box<str>>,
 }
 
+impl ScheduleAccountUpdaterResult {
+    /// Check if the Account Updater was scheduled.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.result
+            .as_deref()
+            .is_some_and(|result| result.eq_ignore_ascii_case("success"))
+    }
+}
+
+/// Result of an Account Updater check.
+///
+/// Both the codes of scheduled checks and the `realtimeAccountUpdaterStatus`
+/// values of real-time checks (e.g. `CardChanged`) are recognized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccountUpdaterResultCode {
+    /// The card has a new expiry date.
+    NewExpiryDate,
+    /// The card was replaced by a new card number.
+    NewCard,
+    /// The card account was closed; stop using the stored details.
+    CloseAccount,
+    /// The issuer has no update; ask the cardholder for new details.
+    ContactCardHolder,
+    /// A result code not known to this library.
+    Unknown(Box<str>),
+}
+
+impl AccountUpdaterResultCode {
+    /// Get the result code as sent by Adyen for scheduled checks.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NewExpiryDate => "NewExpiryDate",
+            Self::NewCard => "NewCard",
+            Self::CloseAccount => "CloseAccount",
+            Self::ContactCardHolder => "ContactCardHolder",
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Check if the stored card details must be updated.
+    #[must_use]
+    pub const fn has_new_details(&self) -> bool {
+        matches!(self, Self::NewExpiryDate | Self::NewCard)
+    }
+
+    /// Check if the stored card can no longer be used as it is.
+    #[must_use]
+    pub const fn requires_action(&self) -> bool {
+        matches!(self, Self::CloseAccount | Self::ContactCardHolder)
+    }
+}
+
+impl From<&str> for AccountUpdaterResultCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "NewExpiryDate" | "CardExpiryChanged" => Self::NewExpiryDate,
+            "NewCard" | "CardChanged" => Self::NewCard,
+            "CloseAccount" => Self::CloseAccount,
+            "ContactCardHolder" | "ContactCardAccountHolder" => Self::ContactCardHolder,
+            other => Self::Unknown(other.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for AccountUpdaterResultCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for AccountUpdaterResultCode {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountUpdaterResultCode {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(code.as_ref()))
+    }
+}
+
+/// Outcome of a scheduled Account Updater check, read from an
+/// `ACCOUNT_UPDATER` webhook.
+///
+/// Build it from the `reason` and `additionalData` of the webhook's
+/// notification item, e.g. with `adyen-webhooks`:
+///
+/// ```rust
+/// use adyen_recurring::{AccountUpdaterResultCode, AccountUpdaterUpdate};
+/// use std::collections::HashMap;
+///
+/// # let reason = "NewExpiryDate";
+/// # let additional_data: HashMap<String, serde_json::Value> = HashMap::from([
+/// #     ("expiryDate".to_string(), "8/2031".into()),
+/// # ]);
+/// // With `item: &adyen_webhooks::NotificationRequestItem`:
+/// // let update = AccountUpdaterUpdate::from_webhook(&item.reason, item.additional_data.as_ref());
+/// let update = AccountUpdaterUpdate::from_webhook(reason, Some(&additional_data));
+/// if update.result_code == AccountUpdaterResultCode::NewExpiryDate {
+///     assert_eq!(update.expiry_date(), Some(("8", "2031")));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdaterUpdate {
+    /// The result of the check.
+    pub result_code: AccountUpdaterResultCode,
+    /// The checked stored payment method.
+    pub recurring_detail_reference: Option<Box<str>>,
+    /// The shopper the stored payment method belongs to.
+    pub shopper_reference: Option<Box<str>>,
+    /// The new expiry date, in `M/YYYY` form.
+    pub expiry_date: Option<Box<str>>,
+    /// The last four digits of the new card number.
+    pub card_summary: Option<Box<str>>,
+}
+
+impl AccountUpdaterUpdate {
+    /// Interpret the `reason` and `additionalData` of an `ACCOUNT_UPDATER`
+    /// webhook.
+    #[must_use]
+    pub fn from_webhook(
+        reason: &str,
+        additional_data: Option<&HashMap<String, serde_json::Value>>,
+    ) -> Self {
+        let string = |key: &str| {
+            additional_data?
+                .get(key)?
+                .as_str()
+                .filter(|value| !value.is_empty())
+                .map(Box::from)
+        };
+
+        Self {
+            result_code: AccountUpdaterResultCode::from(reason),
+            recurring_detail_reference: string("recurring.recurringDetailReference"),
+            shopper_reference: string("recurring.shopperReference"),
+            expiry_date: string("expiryDate"),
+            card_summary: string("cardSummary"),
+        }
+    }
+
+    /// Get the new expiry month and year.
+    #[must_use]
+    pub fn expiry_date(&self) -> Option<(&str, &str)> {
+        self.expiry_date.as_deref()?.split_once('/')
+    }
+}
+
 /// Stored payment method details.
+///
+/// The card or bank account data is available in typed form through
+/// [`payment_method`](Self::payment_method). Like in API responses, the
+/// serialized form is wrapped in a `RecurringDetail` object.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(from = "RecurringDetailEntry", into = "RecurringDetailEntry")]
 pub struct RecurringDetail {
     /// Reference for this recurring detail.
     pub recurring_detail_reference: Box<str>,
     /// Payment method variant (e.g., "visa", "mc").
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub variant: Option<Box<str>>,
+    /// Detailed payment method variant (e.g., "visagold").
+    pub payment_method_variant: Option<Box<str>>,
     /// Supported contract types for this payment method.
-    #[serde(default)]
-    pub contract_types: Vec<Box<str>>,
-    /// Card details if this is a card payment method.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub card: Option<Card>,
-    /// Bank account details if this is a bank payment method.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bank: Option<BankAccount>,
+    pub contract_types: Vec<RecurringContract>,
+    /// The stored card or bank account.
+    pub payment_method: StoredPaymentMethod,
+    /// Details of the network token stored for this payment method.
+    pub token_details: Option<TokenDetails>,
+    /// Alias of the card number, which is the same for all stored details of
+    /// a card.
+    pub alias: Option<Box<str>>,
+    /// Type of the alias.
+    pub alias_type: Option<Box<str>>,
+    /// PSP reference of the payment that stored this payment method.
+    pub first_psp_reference: Option<PspReference>,
+    /// Transaction reference of the card scheme, used for subsequent
+    /// payments.
+    pub network_tx_reference: Option<Box<str>>,
     /// Name associated with this payment method.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<Box<str>>,
     /// The date this payment method was created.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<Box<str>>,
+    pub creation_date: Option<DateTime<Utc>>,
     /// Additional details about the payment method.
-    #[serde(flatten)]
     pub additional_data: HashMap<String, serde_json::Value>,
 }
 
+/// The stored payment method of a [`RecurringDetail`].
+#[derive(Debug, Clone)]
+pub enum StoredPaymentMethod {
+    /// A card.
+    Card(Card),
+    /// A bank account for SEPA Direct Debit.
+    SepaDirectDebit(BankAccount),
+    /// Any other bank account.
+    BankAccount(BankAccount),
+    /// A payment method without card or bank account details, e.g. a
+    /// wallet. See [`RecurringDetail::variant`] for its type.
+    Other,
+}
+
+impl StoredPaymentMethod {
+    /// Get the card, if this is a card.
+    #[must_use]
+    pub const fn card(&self) -> Option<&Card> {
+        match self {
+            Self::Card(card) => Some(card),
+            _ => None,
+        }
+    }
+
+    /// Get the bank account, if this is a SEPA Direct Debit or other bank
+    /// account.
+    #[must_use]
+    pub const fn bank_account(&self) -> Option<&BankAccount> {
+        match self {
+            Self::SepaDirectDebit(bank) | Self::BankAccount(bank) => Some(bank),
+            _ => None,
+        }
+    }
+}
+
+/// Network token stored for a payment method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenDetails {
+    /// Details of the token, such as its expiry date.
+    #[serde(default)]
+    pub token_data: HashMap<String, String>,
+    /// Type of the token data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_data_type: Option<Box<str>>,
+}
+
+/// Wire format of [`RecurringDetail`], with the card and bank account as
+/// separate fields.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecurringDetailData {
+    recurring_detail_reference: Box<str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_method_variant: Option<Box<str>>,
+    #[serde(default)]
+    contract_types: Vec<RecurringContract>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<Card>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank: Option<BankAccount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_details: Option<TokenDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alias: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alias_type: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_psp_reference: Option<PspReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_tx_reference: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creation_date: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    additional_data: HashMap<String, serde_json::Value>,
+}
+
+impl From<RecurringDetailData> for RecurringDetail {
+    fn from(data: RecurringDetailData) -> Self {
+        let is_sepa = data
+            .variant
+            .as_deref()
+            .is_some_and(|variant| variant.starts_with("sepadirectdebit"));
+        let payment_method = match (data.card, data.bank) {
+            (Some(card), _) => StoredPaymentMethod::Card(card),
+            (None, Some(bank)) if is_sepa => StoredPaymentMethod::SepaDirectDebit(bank),
+            (None, Some(bank)) => StoredPaymentMethod::BankAccount(bank),
+            (None, None) => StoredPaymentMethod::Other,
+        };
+
+        Self {
+            recurring_detail_reference: data.recurring_detail_reference,
+            variant: data.variant,
+            payment_method_variant: data.payment_method_variant,
+            contract_types: data.contract_types,
+            payment_method,
+            token_details: data.token_details,
+            alias: data.alias,
+            alias_type: data.alias_type,
+            first_psp_reference: data.first_psp_reference,
+            network_tx_reference: data.network_tx_reference,
+            name: data.name,
+            creation_date: data.creation_date,
+            additional_data: data.additional_data,
+        }
+    }
+}
+
+impl From<RecurringDetail> for RecurringDetailData {
+    fn from(detail: RecurringDetail) -> Self {
+        let (card, bank) = match detail.payment_method {
+            StoredPaymentMethod::Card(card) => (Some(card), None),
+            StoredPaymentMethod::SepaDirectDebit(bank) | StoredPaymentMethod::BankAccount(bank) => {
+                (None, Some(bank))
+            }
+            StoredPaymentMethod::Other => (None, None),
+        };
+
+        Self {
+            recurring_detail_reference: detail.recurring_detail_reference,
+            variant: detail.variant,
+            payment_method_variant: detail.payment_method_variant,
+            contract_types: detail.contract_types,
+            card,
+            bank,
+            token_details: detail.token_details,
+            alias: detail.alias,
+            alias_type: detail.alias_type,
+            first_psp_reference: detail.first_psp_reference,
+            network_tx_reference: detail.network_tx_reference,
+            name: detail.name,
+            creation_date: detail.creation_date,
+            additional_data: detail.additional_data,
+        }
+    }
+}
+
+/// Entry of the `details` list, which the API wraps in a `RecurringDetail`
+/// object. Unwrapped entries are accepted as well.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RecurringDetailEntry {
+    Wrapped {
+        #[serde(rename = "RecurringDetail")]
+        detail: RecurringDetailData,
+    },
+    Plain(RecurringDetailData),
+}
+
+impl From<RecurringDetailEntry> for RecurringDetail {
+    fn from(entry: RecurringDetailEntry) -> Self {
+        match entry {
+            RecurringDetailEntry::Wrapped { detail } | RecurringDetailEntry::Plain(detail) => {
+                detail.into()
+            }
+        }
+    }
+}
+
+impl From<RecurringDetail> for RecurringDetailEntry {
+    fn from(detail: RecurringDetail) -> Self {
+        Self::Wrapped {
+            detail: detail.into(),
+        }
+    }
+}
+
 /// Configuration for recurring payments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -170,16 +528,44 @@ pub struct Recurring {
     pub token_service: Option<Box<str>>,
 }
 
+impl Recurring {
+    /// Create a recurring configuration for the given contract.
+    #[must_use]
+    pub const fn new(contract: RecurringContract) -> Self {
+        Self {
+            contract,
+            recurring_detail_name: None,
+            recurring_expiry: None,
+            recurring_frequency: None,
+            token_service: None,
+        }
+    }
+}
+
 /// Types of recurring contracts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+///
+/// In a [`RecurringDetailsRequest`] the contract filters the stored payment
+/// methods returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RecurringContract {
     /// One-click payments - shopper needs to confirm each payment.
+    #[serde(rename = "ONECLICK")]
     Oneclick,
     /// Fully recurring payments - no shopper interaction required.
+    #[serde(rename = "RECURRING")]
     Recurring,
     /// Both one-click and recurring contract types.
+    #[serde(rename = "ONECLICK,RECURRING")]
     OneclickRecurring,
+    /// Payouts to the stored payment method.
+    #[serde(rename = "PAYOUT")]
+    Payout,
+    /// Payment methods tokenized outside Adyen.
+    #[serde(rename = "EXTERNAL")]
+    External,
+    /// A recurring contract not known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
 /// Card details for stored payment methods.
@@ -259,6 +645,15 @@ impl RecurringDetailsRequestBuilder {
         self
     }
 
+    /// Only list stored payment methods with the given contract.
+    pub fn contract(mut self, contract: RecurringContract) -> Self {
+        match &mut self.recurring {
+            Some(recurring) => recurring.contract = contract,
+            None => self.recurring = Some(Recurring::new(contract)),
+        }
+        self
+    }
+
     /// Build the RecurringDetailsRequest.
     pub fn build(self) -> Result<RecurringDetailsRequest> {
         let merchant_account = self
@@ -327,6 +722,97 @@ impl DisableRequestBuilder {
     }
 }
 
+impl ScheduleAccountUpdaterRequest {
+    /// Create a new builder for ScheduleAccountUpdaterRequest.
+    pub fn builder() -> ScheduleAccountUpdaterRequestBuilder {
+        ScheduleAccountUpdaterRequestBuilder::default()
+    }
+}
+
+/// Builder for ScheduleAccountUpdaterRequest.
+#[derive(Default)]
+pub struct ScheduleAccountUpdaterRequestBuilder {
+    merchant_account: Option<Box<str>>,
+    reference: Option<Box<str>>,
+    shopper_reference: Option<Box<str>>,
+    selected_recurring_detail_reference: Option<Box<str>>,
+    card: Option<Card>,
+    additional_data: Option<HashMap<String, String>>,
+}
+
+impl ScheduleAccountUpdaterRequestBuilder {
+    /// Set the merchant account.
+    pub fn merchant_account(mut self, merchant_account: impl Into<Box<str>>) -> Self {
+        self.merchant_account = Some(merchant_account.into());
+        self
+    }
+
+    /// Set the reference for this request.
+    pub fn reference(mut self, reference: impl Into<Box<str>>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Check the card with its full card number.
+    pub fn card(mut self, card: Card) -> Self {
+        self.card = Some(card);
+        self
+    }
+
+    /// Check a stored payment method of a shopper.
+    pub fn stored_payment_method(
+        mut self,
+        shopper_reference: impl Into<Box<str>>,
+        recurring_detail_reference: impl Into<Box<str>>,
+    ) -> Self {
+        self.shopper_reference = Some(shopper_reference.into());
+        self.selected_recurring_detail_reference = Some(recurring_detail_reference.into());
+        self
+    }
+
+    /// Add an additional data entry.
+    pub fn additional_data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.additional_data
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the ScheduleAccountUpdaterRequest.
+    ///
+    /// Exactly one of a card or a stored payment method must be set.
+    pub fn build(self) -> Result<ScheduleAccountUpdaterRequest> {
+        let merchant_account = self
+            .merchant_account
+            .ok_or_else(|| AdyenError::generic("merchant_account is required"))?;
+        let reference = self
+            .reference
+            .ok_or_else(|| AdyenError::generic("reference is required"))?;
+        match (&self.card, &self.selected_recurring_detail_reference) {
+            (Some(_), Some(_)) => {
+                return Err(AdyenError::generic(
+                    "card and stored_payment_method cannot both be set",
+                ))
+            }
+            (None, None) => {
+                return Err(AdyenError::generic(
+                    "either card or stored_payment_method is required",
+                ))
+            }
+            _ => {}
+        }
+
+        Ok(ScheduleAccountUpdaterRequest {
+            merchant_account,
+            reference,
+            shopper_reference: self.shopper_reference,
+            selected_recurring_detail_reference: self.selected_recurring_detail_reference,
+            card: self.card,
+            additional_data: self.additional_data,
+        })
+    }
+}
+
 // ============================================================================
 // Permit Management Types
 // ============================================================================
@@ -3,7 +3,8 @@
 use adyen_core::{Amount, ConfigBuilder, Currency, Environment};
 use adyen_recurring::{
     Card, DisableRequest, NotifyShopperRequest, Recurring, RecurringApi, RecurringContract,
-    RecurringDetailsRequest, ScheduleAccountUpdaterRequest,
+    RecurringDetailsRequest, RecurringDetailsResult, ScheduleAccountUpdaterRequest,
+    StoredPaymentMethod,
 };
 
 fn create_test_config() -> adyen_core::Config {
@@ -55,6 +56,20 @@ mod request_building_tests {
         assert_eq!(recurring.recurring_detail_name.as_deref(), Some("My Card"));
     }
 
+    #[test]
+    fn test_recurring_details_request_contract_filter() {
+        let request = RecurringDetailsRequest::builder()
+            .merchant_account("TestMerchant")
+            .shopper_reference("shopper_12345")
+            .contract(RecurringContract::OneclickRecurring)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["recurring"]["contract"], "ONECLICK,RECURRING");
+        assert_eq!(json["recurring"].as_object().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_disable_request_builder() {
         let request = DisableRequest::builder()
@@ -86,7 +101,7 @@ mod request_building_tests {
         };
 
         assert_eq!(request.amount.minor_units(), 1000);
-        assert_eq!(request.amount.currency(), Currency::EUR);
+        assert_eq!(request.amount.currency(), &Currency::EUR);
         assert_eq!(&*request.merchant_account, "TestMerchant");
         assert_eq!(&*request.reference, "notify_001");
     }
@@ -104,8 +119,10 @@ mod request_building_tests {
         let request = ScheduleAccountUpdaterRequest {
             merchant_account: "TestMerchant".into(),
             reference: "updater_001".into(),
-            shopper_reference: "shopper_12345".into(),
+            shopper_reference: None,
+            selected_recurring_detail_reference: None,
             card: Some(card),
+            additional_data: None,
         };
 
         assert_eq!(&*request.merchant_account, "TestMerchant");
@@ -125,7 +142,7 @@ mod request_building_tests {
 
         assert_eq!(oneclick_json, "\"ONECLICK\"");
         assert_eq!(recurring_json, "\"RECURRING\"");
-        assert_eq!(both_json, "\"ONECLICK_RECURRING\"");
+        assert_eq!(both_json, "\"ONECLICK,RECURRING\"");
     }
 }
 
@@ -220,8 +237,10 @@ mod serialization_tests {
         let request = ScheduleAccountUpdaterRequest {
             merchant_account: "TestMerchant".into(),
             reference: "updater_001".into(),
-            shopper_reference: "shopper_12345".into(),
+            shopper_reference: Some("shopper_12345".into()),
+            selected_recurring_detail_reference: Some("8415736344864224".into()),
             card: None,
+            additional_data: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -233,6 +252,243 @@ mod serialization_tests {
     }
 }
 
+#[cfg(test)]
+mod response_tests {
+    use super::*;
+
+    #[test]
+    fn test_recurring_details_result_deserialization() {
+        let json = r#"{
+            "creationDate": "2017-03-01T11:53:11+01:00",
+            "details": [
+                {
+                    "RecurringDetail": {
+                        "additionalData": {"cardBin": "411111"},
+                        "alias": "cardAlias",
+                        "aliasType": "Default",
+                        "card": {
+                            "expiryMonth": "8",
+                            "expiryYear": "2030",
+                            "holderName": "John Smith",
+                            "number": "1111"
+                        },
+                        "contractTypes": ["ONECLICK", "RECURRING"],
+                        "creationDate": "2017-03-07T09:43:33+01:00",
+                        "firstPspReference": "8524888762135795",
+                        "networkTxReference": "MCC123456789",
+                        "paymentMethodVariant": "visa",
+                        "recurringDetailReference": "8315288762130001",
+                        "tokenDetails": {
+                            "tokenData": {"expiryMonth": "8", "expiryYear": "2030"},
+                            "tokenDataType": "NetworkToken"
+                        },
+                        "variant": "visa"
+                    }
+                },
+                {
+                    "RecurringDetail": {
+                        "bank": {
+                            "countryCode": "NL",
+                            "iban": "NL13TEST0123456789",
+                            "ownerName": "A. Schneider"
+                        },
+                        "contractTypes": ["RECURRING"],
+                        "recurringDetailReference": "8415288762130002",
+                        "variant": "sepadirectdebit"
+                    }
+                },
+                {
+                    "RecurringDetail": {
+                        "contractTypes": ["RECURRING"],
+                        "recurringDetailReference": "8415288762130003",
+                        "variant": "paypal"
+                    }
+                }
+            ],
+            "lastKnownShopperEmail": "s.hopper@example.com",
+            "shopperReference": "shopper_12345"
+        }"#;
+
+        let result: RecurringDetailsResult = serde_json::from_str(json).unwrap();
+        assert!(result.creation_date.is_some());
+        assert_eq!(result.details.len(), 3);
+
+        let card_detail = &result.details[0];
+        let card = card_detail.payment_method.card().unwrap();
+        assert_eq!(card.number.as_deref(), Some("1111"));
+        assert_eq!(card.expiry_year.as_deref(), Some("2030"));
+        assert_eq!(
+            card_detail.contract_types,
+            vec![RecurringContract::Oneclick, RecurringContract::Recurring]
+        );
+        assert_eq!(
+            card_detail.first_psp_reference.as_ref().unwrap().as_str(),
+            "8524888762135795"
+        );
+        assert_eq!(
+            card_detail.creation_date.unwrap().to_rfc3339(),
+            "2017-03-07T08:43:33+00:00"
+        );
+        let token = card_detail.token_details.as_ref().unwrap();
+        assert_eq!(token.token_data_type.as_deref(), Some("NetworkToken"));
+        assert_eq!(token.token_data["expiryYear"], "2030");
+        assert_eq!(card_detail.additional_data["cardBin"], "411111");
+
+        let StoredPaymentMethod::SepaDirectDebit(bank) = &result.details[1].payment_method else {
+            panic!("expected a SEPA Direct Debit account");
+        };
+        assert_eq!(bank.iban.as_deref(), Some("NL13TEST0123456789"));
+
+        assert!(matches!(
+            result.details[2].payment_method,
+            StoredPaymentMethod::Other
+        ));
+    }
+
+    #[test]
+    fn test_recurring_details_result_round_trip() {
+        let json = r#"{"details":[{"RecurringDetail":{"recurringDetailReference":"8415288762130002","variant":"mc","contractTypes":["ONECLICK"],"card":{"number":"4444"}}}]}"#;
+
+        let result: RecurringDetailsResult = serde_json::from_str(json).unwrap();
+        let value = serde_json::to_value(&result).unwrap();
+        let detail = &value["details"][0]["RecurringDetail"];
+        assert_eq!(detail["card"]["number"], "4444");
+        assert_eq!(detail["contractTypes"][0], "ONECLICK");
+        assert!(detail.get("bank").is_none());
+
+        // Unwrapped entries are accepted as well.
+        let unwrapped =
+            r#"{"details":[{"recurringDetailReference":"8415288762130002","variant":"mc"}]}"#;
+        let result: RecurringDetailsResult = serde_json::from_str(unwrapped).unwrap();
+        assert_eq!(
+            &*result.details[0].recurring_detail_reference,
+            "8415288762130002"
+        );
+    }
+}
+
+#[cfg(test)]
+mod account_updater_tests {
+    use super::*;
+    use adyen_recurring::{
+        AccountUpdaterResultCode, AccountUpdaterUpdate, ScheduleAccountUpdaterResult,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_schedule_account_updater_builder() {
+        let request = ScheduleAccountUpdaterRequest::builder()
+            .merchant_account("TestMerchant")
+            .reference("updater_001")
+            .stored_payment_method("shopper_12345", "8415736344864224")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["shopperReference"], "shopper_12345");
+        assert_eq!(json["selectedRecurringDetailReference"], "8415736344864224");
+        assert!(json.get("card").is_none());
+
+        let card = Card {
+            number: Some("4111111111111111".into()),
+            expiry_month: Some("03".into()),
+            expiry_year: Some("2030".into()),
+            holder_name: Some("John Doe".into()),
+            summary: None,
+        };
+        let request = ScheduleAccountUpdaterRequest::builder()
+            .merchant_account("TestMerchant")
+            .reference("updater_002")
+            .card(card.clone())
+            .build()
+            .unwrap();
+        assert!(request.shopper_reference.is_none());
+        assert!(request.card.is_some());
+
+        let both = ScheduleAccountUpdaterRequest::builder()
+            .merchant_account("TestMerchant")
+            .reference("updater_003")
+            .card(card)
+            .stored_payment_method("shopper_12345", "8415736344864224")
+            .build();
+        assert!(both.is_err());
+
+        let neither = ScheduleAccountUpdaterRequest::builder()
+            .merchant_account("TestMerchant")
+            .reference("updater_004")
+            .build();
+        assert!(neither.is_err());
+    }
+
+    #[test]
+    fn test_schedule_account_updater_result() {
+        let result: ScheduleAccountUpdaterResult =
+            serde_json::from_str(r#"{"pspReference":"8815131768219992","result":"Success"}"#)
+                .unwrap();
+        assert!(result.is_success());
+
+        let result: ScheduleAccountUpdaterResult =
+            serde_json::from_str(r#"{"pspReference":"8815131768219993","result":"Invalid card"}"#)
+                .unwrap();
+        assert!(!result.is_success());
+    }
+
+    #[test]
+    fn test_account_updater_result_codes() {
+        for (code, expected) in [
+            ("NewExpiryDate", AccountUpdaterResultCode::NewExpiryDate),
+            ("NewCard", AccountUpdaterResultCode::NewCard),
+            ("CloseAccount", AccountUpdaterResultCode::CloseAccount),
+            (
+                "ContactCardHolder",
+                AccountUpdaterResultCode::ContactCardHolder,
+            ),
+            ("CardChanged", AccountUpdaterResultCode::NewCard),
+            ("CardExpiryChanged", AccountUpdaterResultCode::NewExpiryDate),
+        ] {
+            let parsed: AccountUpdaterResultCode =
+                serde_json::from_value(serde_json::json!(code)).unwrap();
+            assert_eq!(parsed, expected);
+        }
+
+        assert!(AccountUpdaterResultCode::NewCard.has_new_details());
+        assert!(AccountUpdaterResultCode::CloseAccount.requires_action());
+        let unknown = AccountUpdaterResultCode::from("NoChange");
+        assert_eq!(
+            unknown,
+            AccountUpdaterResultCode::Unknown("NoChange".into())
+        );
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""NoChange""#);
+    }
+
+    #[test]
+    fn test_account_updater_update_from_webhook() {
+        let additional_data: HashMap<String, serde_json::Value> = serde_json::from_str(
+            r#"{
+                "recurring.recurringDetailReference": "8415736344864224",
+                "recurring.shopperReference": "shopper_12345",
+                "expiryDate": "8/2031",
+                "cardSummary": "4321"
+            }"#,
+        )
+        .unwrap();
+
+        let update = AccountUpdaterUpdate::from_webhook("NewCard", Some(&additional_data));
+        assert_eq!(update.result_code, AccountUpdaterResultCode::NewCard);
+        assert_eq!(
+            update.recurring_detail_reference.as_deref(),
+            Some("8415736344864224")
+        );
+        assert_eq!(update.shopper_reference.as_deref(), Some("shopper_12345"));
+        assert_eq!(update.expiry_date(), Some(("8", "2031")));
+        assert_eq!(update.card_summary.as_deref(), Some("4321"));
+
+        let update = AccountUpdaterUpdate::from_webhook("CloseAccount", None);
+        assert!(update.result_code.requires_action());
+        assert!(update.expiry_date().is_none());
+    }
+}
+
 #[cfg(test)]
 mod validation_tests {
     use super::*;
@@ -301,18 +557,12 @@ mod workflow_tests {
             .unwrap();
 
         // Schedule account updater for automatic card updates
-        let updater_request = ScheduleAccountUpdaterRequest {
-            merchant_account: "TestMerchant".into(),
-            reference: "updater_001".into(),
-            shopper_reference: "shopper_12345".into(),
-            card: Some(Card {
-                number: Some("1111".into()),
-                expiry_month: Some("12".into()),
-                expiry_year: Some("2030".into()),
-                holder_name: Some("John Doe".into()),
-                summary: Some("1111".into()),
-            }),
-        };
+        let updater_request = ScheduleAccountUpdaterRequest::builder()
+            .merchant_account("TestMerchant")
+            .reference("updater_001")
+            .stored_payment_method("shopper_12345", "8415736344864225")
+            .build()
+            .unwrap();
 
         // Verify all requests are properly constructed
         assert_eq!(
@@ -320,7 +570,7 @@ mod workflow_tests {
             disable_request.shopper_reference
         );
         assert_eq!(
-            disable_request.shopper_reference,
+            Some(disable_request.shopper_reference),
             updater_request.shopper_reference
         );
         assert!(matches!(
@@ -347,7 +597,7 @@ mod workflow_tests {
 
         // Verify notification request structure
         assert_eq!(notification_request.amount.minor_units(), 2500);
-        assert_eq!(notification_request.amount.currency(), Currency::GBP);
+        assert_eq!(notification_request.amount.currency(), &Currency::GBP);
         assert_eq!(&*notification_request.reference, "recurring_payment_001");
         assert_eq!(
             notification_request.stored_payment_method_id.as_deref(),
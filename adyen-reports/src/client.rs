@@ -0,0 +1,144 @@
+//! Report download client implementation.
+
+use crate::types::Report;
+use adyen_core::http::{Bytes, HeaderName, HeaderValue, Method};
+use adyen_core::{AdyenError, Api, Client, Config, Request, Result};
+use adyen_webhooks::{EventCode, NotificationRequestItem};
+use futures::Stream;
+
+/// Client for downloading reports generated by Adyen.
+///
+/// Reports are downloaded with the credentials of a report user (`report_…@Company.…`)
+/// or an API key with the report download role, set on the [`Config`].
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_reports::{Report, ReportsClient};
+/// use adyen_webhooks::NotificationRequestItem;
+///
+/// # async fn example(item: &NotificationRequestItem) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .basic_auth("report_123456@Company.YourCompany", "report_password")?
+///     .build()?;
+///
+/// let reports = ReportsClient::new(config)?;
+///
+/// // `item` is a validated REPORT_AVAILABLE notification.
+/// if let Report::SettlementDetail(rows) = reports.download_report(item).await? {
+///     for row in rows.iter().filter(|row| row.is_payout()) {
+///         println!("Payout of {} {:?}", row.net_amount(), row.net_currency);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReportsClient {
+    client: Client,
+}
+
+impl ReportsClient {
+    /// Create a new reports client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::Reports)?;
+        Ok(Self { client })
+    }
+
+    /// Create a new reports client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Reports),
+        }
+    }
+
+    /// Download the report a `REPORT_AVAILABLE` notification refers to and parse it.
+    ///
+    /// The notification's PSP reference is the report's file name and its reason is
+    /// the download URL. Validate the notification's HMAC signature before calling
+    /// this, as the report credentials are sent to that URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notification is not a successful `REPORT_AVAILABLE`
+    /// notification, the download fails or the report cannot be parsed.
+    pub async fn download_report(&self, item: &NotificationRequestItem) -> Result<Report> {
+        if item.event_code != EventCode::ReportAvailable || !item.is_success() {
+            return Err(AdyenError::generic(format!(
+                "Expected a successful REPORT_AVAILABLE notification, got {} for {}",
+                item.event_code, item.psp_reference
+            )));
+        }
+
+        let data = self.download(&item.reason).await?;
+        Report::parse(&item.psp_reference, data)
+    }
+
+    /// Download a report from its URL.
+    ///
+    /// Only HTTPS URLs on `adyen.com` are accepted, so the report credentials are
+    /// never sent elsewhere.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not an Adyen URL or the download fails.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        check_report_url(url)?;
+
+        // Reports are CSV, XLSX or PDF files rather than JSON.
+        let request = Request::new(Method::Get, url).with_header(
+            HeaderName::from_static("accept"),
+            HeaderValue::from_static("*/*"),
+        );
+        let response = self.client.execute_raw(request).await?;
+        Ok(response.data)
+    }
+
+    /// Download a report from its URL without holding it in memory.
+    ///
+    /// Use this for large reports, e.g. to write them to a file chunk by chunk. Only
+    /// HTTPS URLs on `adyen.com` are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not an Adyen URL or the download fails to
+    /// start. Failures while reading the report are returned by the stream.
+    pub async fn download_stream(
+        &self,
+        url: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin> {
+        check_report_url(url)?;
+        self.client.get_stream(url).await
+    }
+}
+
+fn check_report_url(url: &str) -> Result<()> {
+    let parsed = url::Url::parse(url)?;
+    let is_adyen_host = parsed
+        .host_str()
+        .is_some_and(|host| host == "adyen.com" || host.ends_with(".adyen.com"));
+    if parsed.scheme() != "https" || !is_adyen_host {
+        return Err(AdyenError::config(format!(
+            "Refusing to download a report from non-Adyen URL '{url}'"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl ReportsClient {
+        fn download_report_blocking => download_report(item: &NotificationRequestItem) -> Result<Report>;
+        fn download_blocking => download(url: &str) -> Result<Vec<u8>>;
+    }
+}
@@ -0,0 +1,35 @@
+//! # Adyen Reports
+//!
+//! This crate downloads reports generated by Adyen and parses them into typed rows.
+//!
+//! ## Features
+//!
+//! - **Report Downloads**: Download the report announced by a `REPORT_AVAILABLE` webhook
+//! - **Settlement Detail Reports**: Typed rows for every payment, fee and payout in a settlement batch
+//! - **Payment Accounting Reports**: Typed rows for every status change of a payment
+//!
+//! ## Example
+//!
+//! ```rust
+//! use adyen_reports::{Report, SettlementDetailRow};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let csv = "\
+//! Company Account,Merchant Account,Psp Reference,Merchant Reference,Type,Net Currency,Net Credit (NC),Batch Number
+//! YourCompany,YourMerchant,8815329842815468,order-1,Settled,EUR,99.70,42
+//! ";
+//!
+//! let rows = SettlementDetailRow::parse_csv(csv.as_bytes())?;
+//! assert_eq!(rows[0].net_amount().to_string(), "99.70");
+//!
+//! let report = Report::parse("settlement_detail_report_batch_42.csv", csv.as_bytes().to_vec())?;
+//! assert!(matches!(report, Report::SettlementDetail(_)));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod client;
+pub mod types;
+
+pub use client::ReportsClient;
+pub use types::*;
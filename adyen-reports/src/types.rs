@@ -0,0 +1,300 @@
+//! Typed rows of Adyen reports.
+
+use adyen_core::{AdyenError, Result};
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// Reports with typed rows, identified by their file name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportType {
+    /// Settlement Detail report, e.g. `settlement_detail_report_batch_42.csv`.
+    SettlementDetail,
+    /// Payment Accounting report, e.g. `payments_accounting_report_2024_01_15.csv`.
+    PaymentAccounting,
+}
+
+impl ReportType {
+    /// Identify a report from its file name.
+    ///
+    /// For `REPORT_AVAILABLE` webhooks the file name is sent as the PSP reference.
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let file_name = file_name.rsplit('/').next().unwrap_or(file_name);
+        if !file_name.ends_with(".csv") {
+            return None;
+        }
+        if file_name.starts_with("settlement_detail_report_") {
+            Some(Self::SettlementDetail)
+        } else if file_name.starts_with("payments_accounting_report_") {
+            Some(Self::PaymentAccounting)
+        } else {
+            None
+        }
+    }
+}
+
+/// A downloaded report.
+#[derive(Debug, Clone)]
+pub enum Report {
+    /// Parsed Settlement Detail report.
+    SettlementDetail(Vec<SettlementDetailRow>),
+    /// Parsed Payment Accounting report.
+    PaymentAccounting(Vec<PaymentAccountingRow>),
+    /// Report without typed rows, e.g. a PDF or an XLSX report.
+    Other(Vec<u8>),
+}
+
+impl Report {
+    /// Parse report contents according to the report's file name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a Settlement Detail or Payment Accounting report is not
+    /// valid CSV or has invalid values.
+    pub fn parse(file_name: &str, data: Vec<u8>) -> Result<Self> {
+        Ok(match ReportType::from_file_name(file_name) {
+            Some(ReportType::SettlementDetail) => {
+                Self::SettlementDetail(SettlementDetailRow::parse_csv(&data)?)
+            }
+            Some(ReportType::PaymentAccounting) => {
+                Self::PaymentAccounting(PaymentAccountingRow::parse_csv(&data)?)
+            }
+            None => Self::Other(data),
+        })
+    }
+}
+
+/// Row of a Settlement Detail report.
+///
+/// Each row is a payment, modification or fee booked in a settlement batch. The
+/// batch ends with a `MerchantPayout` row for the amount paid out.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SettlementDetailRow {
+    /// Company account.
+    #[serde(rename = "Company Account")]
+    pub company_account: String,
+    /// Merchant account.
+    #[serde(rename = "Merchant Account")]
+    pub merchant_account: String,
+    /// PSP reference of the payment; empty for fees and payouts.
+    #[serde(rename = "Psp Reference")]
+    pub psp_reference: Option<String>,
+    /// Merchant reference of the payment.
+    #[serde(rename = "Merchant Reference")]
+    pub merchant_reference: Option<String>,
+    /// Payment method, e.g. `visa`.
+    #[serde(rename = "Payment Method")]
+    pub payment_method: Option<String>,
+    /// When the record was created, in [`time_zone`](Self::time_zone).
+    #[serde(rename = "Creation Date", default, deserialize_with = "datetime")]
+    pub creation_date: Option<NaiveDateTime>,
+    /// Time zone of the creation date, e.g. `CET`.
+    #[serde(rename = "TimeZone")]
+    pub time_zone: Option<String>,
+    /// Record type, e.g. `Settled`, `Refunded`, `Chargeback` or `MerchantPayout`.
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    /// PSP reference of the modification.
+    #[serde(rename = "Modification Reference")]
+    pub modification_reference: Option<String>,
+    /// Currency of the gross amounts.
+    #[serde(rename = "Gross Currency")]
+    pub gross_currency: Option<String>,
+    /// Gross amount debited, in the gross currency.
+    #[serde(rename = "Gross Debit (GC)", default, deserialize_with = "decimal")]
+    pub gross_debit: Option<Decimal>,
+    /// Gross amount credited, in the gross currency.
+    #[serde(rename = "Gross Credit (GC)", default, deserialize_with = "decimal")]
+    pub gross_credit: Option<Decimal>,
+    /// Exchange rate from the gross to the net currency.
+    #[serde(rename = "Exchange Rate", default, deserialize_with = "decimal")]
+    pub exchange_rate: Option<Decimal>,
+    /// Currency of the net amounts and fees.
+    #[serde(rename = "Net Currency")]
+    pub net_currency: Option<String>,
+    /// Net amount debited, in the net currency.
+    #[serde(rename = "Net Debit (NC)", default, deserialize_with = "decimal")]
+    pub net_debit: Option<Decimal>,
+    /// Net amount credited, in the net currency.
+    #[serde(rename = "Net Credit (NC)", default, deserialize_with = "decimal")]
+    pub net_credit: Option<Decimal>,
+    /// Adyen commission, in the net currency.
+    #[serde(rename = "Commission (NC)", default, deserialize_with = "decimal")]
+    pub commission: Option<Decimal>,
+    /// Adyen markup, in the net currency.
+    #[serde(rename = "Markup (NC)", default, deserialize_with = "decimal")]
+    pub markup: Option<Decimal>,
+    /// Scheme fees, in the net currency.
+    #[serde(rename = "Scheme Fees (NC)", default, deserialize_with = "decimal")]
+    pub scheme_fees: Option<Decimal>,
+    /// Interchange, in the net currency.
+    #[serde(rename = "Interchange (NC)", default, deserialize_with = "decimal")]
+    pub interchange: Option<Decimal>,
+    /// Payment method variant, e.g. `visagold`.
+    #[serde(rename = "Payment Method Variant")]
+    pub payment_method_variant: Option<String>,
+    /// Merchant reference of the modification.
+    #[serde(rename = "Modification Merchant Reference")]
+    pub modification_merchant_reference: Option<String>,
+    /// Settlement batch number.
+    #[serde(rename = "Batch Number")]
+    pub batch_number: Option<u32>,
+}
+
+impl SettlementDetailRow {
+    /// Parse the rows of a Settlement Detail report.
+    ///
+    /// Columns are matched by header name, so reports with additional or reordered
+    /// columns are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not valid CSV or has invalid values.
+    pub fn parse_csv(data: &[u8]) -> Result<Vec<Self>> {
+        parse_csv(data)
+    }
+
+    /// Net amount credited minus the net amount debited.
+    #[must_use]
+    pub fn net_amount(&self) -> Decimal {
+        self.net_credit.unwrap_or_default() - self.net_debit.unwrap_or_default()
+    }
+
+    /// Check if this row is the payout of the settlement batch.
+    #[must_use]
+    pub fn is_payout(&self) -> bool {
+        self.record_type == "MerchantPayout"
+    }
+}
+
+/// Row of a Payment Accounting report.
+///
+/// Each row is a status change of a payment, e.g. `Received`, `Authorised`,
+/// `SentForSettle` or `Settled`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PaymentAccountingRow {
+    /// Company account.
+    #[serde(rename = "Company Account")]
+    pub company_account: String,
+    /// Merchant account.
+    #[serde(rename = "Merchant Account")]
+    pub merchant_account: String,
+    /// PSP reference of the payment.
+    #[serde(rename = "Psp Reference")]
+    pub psp_reference: Option<String>,
+    /// Merchant reference of the payment.
+    #[serde(rename = "Merchant Reference")]
+    pub merchant_reference: Option<String>,
+    /// Payment method, e.g. `visa`.
+    #[serde(rename = "Payment Method")]
+    pub payment_method: Option<String>,
+    /// When the record was booked, in [`time_zone`](Self::time_zone).
+    #[serde(rename = "Booking Date", default, deserialize_with = "datetime")]
+    pub booking_date: Option<NaiveDateTime>,
+    /// Time zone of the booking date, e.g. `CET`.
+    #[serde(rename = "TimeZone")]
+    pub time_zone: Option<String>,
+    /// Currency of the main amount.
+    #[serde(rename = "Main Currency")]
+    pub main_currency: Option<String>,
+    /// Amount of the record.
+    #[serde(rename = "Main Amount", default, deserialize_with = "decimal")]
+    pub main_amount: Option<Decimal>,
+    /// Record type, e.g. `Authorised` or `Settled`.
+    #[serde(rename = "Record Type")]
+    pub record_type: String,
+    /// Currency of the payment amounts.
+    #[serde(rename = "Payment Currency")]
+    pub payment_currency: Option<String>,
+    /// Amount received, in the payment currency.
+    #[serde(rename = "Received (PC)", default, deserialize_with = "decimal")]
+    pub received: Option<Decimal>,
+    /// Amount authorised, in the payment currency.
+    #[serde(rename = "Authorised (PC)", default, deserialize_with = "decimal")]
+    pub authorised: Option<Decimal>,
+    /// Amount captured, in the payment currency.
+    #[serde(rename = "Captured (PC)", default, deserialize_with = "decimal")]
+    pub captured: Option<Decimal>,
+    /// Currency of the settlement amounts and fees.
+    #[serde(rename = "Settlement Currency")]
+    pub settlement_currency: Option<String>,
+    /// Amount payable to the merchant, in the settlement currency.
+    #[serde(rename = "Payable (SC)", default, deserialize_with = "decimal")]
+    pub payable: Option<Decimal>,
+    /// Adyen commission, in the settlement currency.
+    #[serde(rename = "Commission (SC)", default, deserialize_with = "decimal")]
+    pub commission: Option<Decimal>,
+    /// Adyen markup, in the settlement currency.
+    #[serde(rename = "Markup (SC)", default, deserialize_with = "decimal")]
+    pub markup: Option<Decimal>,
+    /// Scheme fees, in the settlement currency.
+    #[serde(rename = "Scheme Fees (SC)", default, deserialize_with = "decimal")]
+    pub scheme_fees: Option<Decimal>,
+    /// Interchange, in the settlement currency.
+    #[serde(rename = "Interchange (SC)", default, deserialize_with = "decimal")]
+    pub interchange: Option<Decimal>,
+    /// Currency of the processing fee.
+    #[serde(rename = "Processing Fee Currency")]
+    pub processing_fee_currency: Option<String>,
+    /// Processing fee, in the processing fee currency.
+    #[serde(rename = "Processing Fee (FC)", default, deserialize_with = "decimal")]
+    pub processing_fee: Option<Decimal>,
+    /// User who triggered the record, e.g. for manual captures.
+    #[serde(rename = "User Name")]
+    pub user_name: Option<String>,
+    /// Payment method variant, e.g. `visagold`.
+    #[serde(rename = "Payment Method Variant")]
+    pub payment_method_variant: Option<String>,
+    /// Merchant reference of the modification.
+    #[serde(rename = "Modification Merchant Reference")]
+    pub modification_merchant_reference: Option<String>,
+}
+
+impl PaymentAccountingRow {
+    /// Parse the rows of a Payment Accounting report.
+    ///
+    /// Columns are matched by header name, so reports with additional or reordered
+    /// columns are supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not valid CSV or has invalid values.
+    pub fn parse_csv(data: &[u8]) -> Result<Vec<Self>> {
+        parse_csv(data)
+    }
+}
+
+fn parse_csv<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(data)
+        .deserialize()
+        .collect::<std::result::Result<_, _>>()
+        .map_err(|e| AdyenError::generic_with_source("Failed to parse report", Box::new(e)))
+}
+
+/// Date format used in reports, e.g. `2024-01-15 10:30:00`.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+fn datetime<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<NaiveDateTime>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| NaiveDateTime::parse_from_str(&value, DATE_FORMAT))
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+// Amounts are parsed from the text, as going through `f64` would lose precision.
+fn decimal<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Decimal>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<Decimal>())
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,211 @@
+//! Integration tests for the Adyen Reports crate.
+
+use adyen_reports::*;
+use rust_decimal::Decimal;
+
+const SETTLEMENT_DETAIL_REPORT: &str = "\
+Company Account,Merchant Account,Psp Reference,Merchant Reference,Payment Method,Creation Date,TimeZone,Type,Modification Reference,Gross Currency,Gross Debit (GC),Gross Credit (GC),Exchange Rate,Net Currency,Net Debit (NC),Net Credit (NC),Commission (NC),Markup (NC),Scheme Fees (NC),Interchange (NC),Payment Method Variant,Modification Merchant Reference,Batch Number,Reserved4
+TestCompany,TestMerchant,8815329842815468,order-1,visa,2024-01-15 10:30:00,CET,Settled,8815329842815468,EUR,,100.00,1,EUR,,99.70,0.10,,0.05,0.15,visagold,,42,
+TestCompany,TestMerchant,8815329842815469,\"order, with comma\",mc,2024-01-15 11:00:00,CET,Refunded,8825329842815470,EUR,25.00,,1,EUR,25.00,,,,,,mccredit,refund-1,42,
+TestCompany,TestMerchant,,,,2024-01-16 06:00:00,CET,MerchantPayout,,,,,,EUR,74.70,,,,,,,,42,
+";
+
+const PAYMENT_ACCOUNTING_REPORT: &str = "\
+Company Account,Merchant Account,Psp Reference,Merchant Reference,Payment Method,Booking Date,TimeZone,Main Currency,Main Amount,Record Type,Payment Currency,Received (PC),Authorised (PC),Captured (PC),Settlement Currency,Payable (SC),Commission (SC),Markup (SC),Scheme Fees (SC),Interchange (SC),Processing Fee Currency,Processing Fee (FC),User Name,Payment Method Variant,Modification Merchant Reference
+TestCompany,TestMerchant,8815329842815468,order-1,visa,2024-01-15 10:29:58,CET,EUR,100.00,Authorised,EUR,,100.00,,,,,,,,,,,visagold,
+TestCompany,TestMerchant,8815329842815468,order-1,visa,2024-01-16 02:00:00,CET,EUR,100.00,Settled,EUR,,,100.00,EUR,99.70,0.10,,0.05,0.15,EUR,0.12,ws@Company.TestCompany,visagold,
+";
+
+mod parsing_tests {
+    use super::*;
+
+    #[test]
+    fn test_report_type_from_file_name() {
+        assert_eq!(
+            ReportType::from_file_name("settlement_detail_report_batch_42.csv"),
+            Some(ReportType::SettlementDetail)
+        );
+        assert_eq!(
+            ReportType::from_file_name("payments_accounting_report_2024_01_15.csv"),
+            Some(ReportType::PaymentAccounting)
+        );
+        assert_eq!(
+            ReportType::from_file_name("settlement_detail_report_batch_42.xlsx"),
+            None
+        );
+        assert_eq!(
+            ReportType::from_file_name("dispute_report_2024_01.csv"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_settlement_detail_report() {
+        let rows = SettlementDetailRow::parse_csv(SETTLEMENT_DETAIL_REPORT.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let settled = &rows[0];
+        assert_eq!(settled.psp_reference.as_deref(), Some("8815329842815468"));
+        assert_eq!(settled.record_type, "Settled");
+        assert_eq!(settled.gross_debit, None);
+        assert_eq!(settled.gross_credit, Some(Decimal::new(10000, 2)));
+        assert_eq!(settled.net_amount(), Decimal::new(9970, 2));
+        assert_eq!(settled.interchange, Some(Decimal::new(15, 2)));
+        assert_eq!(settled.batch_number, Some(42));
+        assert_eq!(
+            settled.creation_date.unwrap().to_string(),
+            "2024-01-15 10:30:00"
+        );
+
+        let refund = &rows[1];
+        assert_eq!(
+            refund.merchant_reference.as_deref(),
+            Some("order, with comma")
+        );
+        assert_eq!(refund.net_amount(), Decimal::new(-2500, 2));
+
+        let payout = &rows[2];
+        assert!(payout.is_payout());
+        assert_eq!(payout.psp_reference, None);
+        assert_eq!(payout.net_debit, Some(Decimal::new(7470, 2)));
+    }
+
+    #[test]
+    fn test_parse_payment_accounting_report() {
+        let rows = PaymentAccountingRow::parse_csv(PAYMENT_ACCOUNTING_REPORT.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].record_type, "Authorised");
+        assert_eq!(rows[0].authorised, Some(Decimal::new(10000, 2)));
+        assert_eq!(rows[0].payable, None);
+
+        let settled = &rows[1];
+        assert_eq!(settled.record_type, "Settled");
+        assert_eq!(settled.payable, Some(Decimal::new(9970, 2)));
+        assert_eq!(settled.processing_fee, Some(Decimal::new(12, 2)));
+        assert_eq!(settled.user_name.as_deref(), Some("ws@Company.TestCompany"));
+    }
+
+    #[test]
+    fn test_parse_invalid_report() {
+        let invalid = "Company Account,Merchant Account,Type,Net Credit (NC)\nA,B,Settled,abc\n";
+        assert!(SettlementDetailRow::parse_csv(invalid.as_bytes()).is_err());
+
+        let report = Report::parse("dispute_report.pdf", b"%PDF".to_vec()).unwrap();
+        assert!(matches!(report, Report::Other(data) if data == b"%PDF"));
+    }
+}
+
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_core::{ConfigBuilder, Environment};
+    use adyen_webhooks::types::Amount;
+    use adyen_webhooks::{EventCode, NotificationRequestItem};
+
+    const REPORT_URL: &str = "https://ca-test.adyen.com/reports/download/MerchantAccount/TestMerchant/settlement_detail_report_batch_42.csv";
+
+    fn mock_client(mock: &MockTransport) -> ReportsClient {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .basic_auth("report_123456@Company.TestCompany", "password")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        ReportsClient::new(config).unwrap()
+    }
+
+    fn report_available(file_name: &str, url: &str) -> NotificationRequestItem {
+        NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(0, "EUR"),
+            event_code: EventCode::ReportAvailable,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: String::new(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: String::new(),
+            psp_reference: file_name.to_string(),
+            reason: url.to_string(),
+            success: "true".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_report_from_notification() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get(
+                "/reports/download/MerchantAccount/TestMerchant/settlement_detail_report_batch_42.csv",
+            )
+            .respond_with(MockResponse::raw(200, SETTLEMENT_DETAIL_REPORT)),
+        );
+
+        let item = report_available("settlement_detail_report_batch_42.csv", REPORT_URL);
+        let report = mock_client(&mock).download_report(&item).await.unwrap();
+        let Report::SettlementDetail(rows) = report else {
+            panic!("expected a settlement detail report");
+        };
+        assert_eq!(rows.len(), 3);
+        assert!(rows[2].is_payout());
+
+        let requests = mock.received_requests();
+        assert!(requests[0].headers.contains_key("authorization"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_download_rejects_other_notifications_and_hosts() {
+        let mock = MockTransport::new();
+        let client = mock_client(&mock);
+
+        let mut item = report_available("settlement_detail_report_batch_42.csv", REPORT_URL);
+        item.event_code = EventCode::Authorisation;
+        assert!(client.download_report(&item).await.is_err());
+
+        let item = report_available(
+            "settlement_detail_report_batch_42.csv",
+            "https://example.com/settlement_detail_report_batch_42.csv",
+        );
+        assert!(client.download_report(&item).await.is_err());
+        assert!(client
+            .download("http://ca-test.adyen.com/reports/download/report.csv")
+            .await
+            .is_err());
+        assert!(client
+            .download("https://ca-test.adyen.com.example.com/report.csv")
+            .await
+            .is_err());
+        assert!(client
+            .download_stream("https://example.com/report.csv")
+            .await
+            .is_err());
+        assert!(mock.received_requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_stream() {
+        use futures::TryStreamExt;
+
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get(
+                "/reports/download/MerchantAccount/TestMerchant/settlement_detail_report_batch_42.csv",
+            )
+            .respond_with(MockResponse::raw(200, SETTLEMENT_DETAIL_REPORT)),
+        );
+
+        let chunks: Vec<_> = mock_client(&mock)
+            .download_stream(REPORT_URL)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let rows = SettlementDetailRow::parse_csv(&chunks.concat()).unwrap();
+        assert_eq!(rows.len(), 3);
+        mock.verify();
+    }
+}
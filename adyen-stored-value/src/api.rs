@@ -0,0 +1,197 @@
+//! Stored Value API client implementation.
+
+use crate::types::{
+    StoredValueBalanceCheckRequest, StoredValueBalanceMergeRequest, StoredValueIssueRequest,
+    StoredValueLoadRequest, StoredValueResponse, StoredValueStatusChangeRequest,
+    StoredValueVoidRequest,
+};
+use adyen_core::{Api, Client, Config, Result};
+
+/// Adyen Stored Value API client.
+///
+/// Provides access to Adyen's Stored Value API v46 for gift card and prepaid
+/// programs: issuing and activating cards, loading funds, checking and merging
+/// balances, and voiding operations.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_stored_value::StoredValueApi;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let stored_value = StoredValueApi::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StoredValueApi {
+    client: Client,
+}
+
+impl StoredValueApi {
+    /// Create a new Stored Value API client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::StoredValue)?;
+        Ok(Self { client })
+    }
+
+    /// Create a new Stored Value API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::StoredValue),
+        }
+    }
+
+    fn endpoint(&self, operation: &str) -> String {
+        format!(
+            "{}/pal/servlet/StoredValue/v46/{}",
+            self.client.config().environment().classic_api_url(),
+            operation
+        )
+    }
+
+    /// Issue a new gift card.
+    ///
+    /// The issued card number and security code are returned in the
+    /// response's `payment_method`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn issue(&self, request: &StoredValueIssueRequest) -> Result<StoredValueResponse> {
+        let url = self.endpoint("issue");
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Activate or deactivate a gift card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn change_status(
+        &self,
+        request: &StoredValueStatusChangeRequest,
+    ) -> Result<StoredValueResponse> {
+        let url = self.endpoint("changeStatus");
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Load funds onto a gift card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_stored_value::{
+    ///     Amount, LoadType, StoredValueApi, StoredValueLoadRequest, StoredValuePaymentMethod,
+    /// };
+    ///
+    /// # async fn example(api: StoredValueApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = StoredValueLoadRequest::builder()
+    ///     .amount(Amount::new(2500, "EUR"))
+    ///     .merchant_account("YourMerchantAccount")
+    ///     .payment_method(StoredValuePaymentMethod::card("givex", "6036280000000000000", "123"))
+    ///     .reference("load-001")
+    ///     .load_type(LoadType::MerchandiseReturn)
+    ///     .build()
+    ///     .map_err(|e| format!("Builder error: {}", e))?;
+    ///
+    /// let response = api.load(&request).await?;
+    /// println!("New balance: {:?}", response.current_balance);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load(&self, request: &StoredValueLoadRequest) -> Result<StoredValueResponse> {
+        let url = self.endpoint("load");
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Check the balance of a gift card.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn check_balance(
+        &self,
+        request: &StoredValueBalanceCheckRequest,
+    ) -> Result<StoredValueResponse> {
+        let url = self.endpoint("checkBalance");
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    /// Move the full balance of one gift card onto another.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn merge_balance(
+        &self,
+        request: &StoredValueBalanceMergeRequest,
+    ) -> Result<StoredValueResponse> {
+        let url = self.endpoint("mergeBalance");
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Void a previous stored value operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn void_transaction(
+        &self,
+        request: &StoredValueVoidRequest,
+    ) -> Result<StoredValueResponse> {
+        let url = self.endpoint("voidTransaction");
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl StoredValueApi {
+        fn issue_blocking => issue(request: &StoredValueIssueRequest) -> Result<StoredValueResponse>;
+        fn change_status_blocking => change_status(request: &StoredValueStatusChangeRequest) -> Result<StoredValueResponse>;
+        fn load_blocking => load(request: &StoredValueLoadRequest) -> Result<StoredValueResponse>;
+        fn check_balance_blocking => check_balance(request: &StoredValueBalanceCheckRequest) -> Result<StoredValueResponse>;
+        fn merge_balance_blocking => merge_balance(request: &StoredValueBalanceMergeRequest) -> Result<StoredValueResponse>;
+        fn void_transaction_blocking => void_transaction(request: &StoredValueVoidRequest) -> Result<StoredValueResponse>;
+    }
+}
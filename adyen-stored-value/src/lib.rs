@@ -1 +1,47 @@
-//! Placeholder for adyen-stored-value crate
+//! # Adyen Stored Value API
+//!
+//! This crate provides access to Adyen's Stored Value API (v46) for gift card
+//! and prepaid programs.
+//!
+//! ## Features
+//!
+//! - **Issue and Activate**: Issue new cards and change their status
+//! - **Load Funds**: Load funds bought by the shopper or refunded for returns
+//! - **Balances**: Check a card's balance or merge it onto another card
+//! - **Void**: Cancel a previous operation
+//!
+//! ## Example
+//!
+//! ```rust
+//! use adyen_core::{ConfigBuilder, Environment};
+//! use adyen_stored_value::{
+//!     StoredValueApi, StoredValueBalanceCheckRequest, StoredValuePaymentMethod,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let api = StoredValueApi::new(config)?;
+//!
+//! let request = StoredValueBalanceCheckRequest::new(
+//!     "YourMerchantAccount",
+//!     StoredValuePaymentMethod::card("givex", "6036280000000000000", "123"),
+//!     "balance-001",
+//! );
+//!
+//! let response = api.check_balance(&request).await?;
+//! if response.is_success() {
+//!     println!("Balance: {:?}", response.current_balance);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+pub mod types;
+
+pub use api::StoredValueApi;
+pub use types::*;
@@ -0,0 +1,879 @@
+//! Types for the Adyen Stored Value API v46.
+
+use adyen_core::RequestId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// ============================================================================
+// Common Types
+// ============================================================================
+
+/// Amount in minor units with its currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amount {
+    /// Three-character ISO currency code.
+    pub currency: Box<str>,
+    /// Amount value in minor units.
+    pub value: i64,
+}
+
+impl Amount {
+    /// Create a new amount from minor units and a currency code.
+    #[must_use]
+    pub fn new(value: i64, currency: &str) -> Self {
+        Self {
+            currency: currency.into(),
+            value,
+        }
+    }
+}
+
+impl From<&adyen_core::Amount> for Amount {
+    fn from(amount: &adyen_core::Amount) -> Self {
+        Self {
+            currency: amount.currency().to_string().into(),
+            value: i64::try_from(amount.minor_units()).unwrap_or(i64::MAX),
+        }
+    }
+}
+
+/// Gift card or prepaid card the operation applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValuePaymentMethod {
+    /// Payment method type, e.g. `givex`, `svs` or `valuelink`.
+    #[serde(rename = "type")]
+    pub payment_method_type: Box<str>,
+
+    /// Card number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<str>>,
+
+    /// Card security code (PIN).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_code: Option<Box<str>>,
+}
+
+impl StoredValuePaymentMethod {
+    /// Create a payment method of the given type without card details.
+    ///
+    /// Used to issue a new card, where Adyen returns the card number.
+    #[must_use]
+    pub fn new(payment_method_type: &str) -> Self {
+        Self {
+            payment_method_type: payment_method_type.into(),
+            number: None,
+            security_code: None,
+        }
+    }
+
+    /// Create a payment method for an existing card.
+    #[must_use]
+    pub fn card(payment_method_type: &str, number: &str, security_code: &str) -> Self {
+        Self {
+            payment_method_type: payment_method_type.into(),
+            number: Some(number.into()),
+            security_code: Some(security_code.into()),
+        }
+    }
+}
+
+/// Sales channel of the operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShopperInteraction {
+    /// Online operation.
+    Ecommerce,
+    /// Operation with stored details, without the shopper present.
+    ContAuth,
+    /// Mail or telephone order.
+    Moto,
+    /// Point-of-sale operation.
+    #[serde(rename = "POS")]
+    Pos,
+    /// A shopper interaction not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Result of a stored value operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResultCode {
+    /// The operation succeeded.
+    Success,
+    /// The operation was refused.
+    Refused,
+    /// The operation failed because of an error.
+    Error,
+    /// The card balance is too low for the operation.
+    NotEnoughBalance,
+    /// A result code not known to this library.
+    Unknown(String),
+}
+
+impl ResultCode {
+    /// Get the result code as sent by Adyen.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Success => "Success",
+            Self::Refused => "Refused",
+            Self::Error => "Error",
+            Self::NotEnoughBalance => "NotEnoughBalance",
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ResultCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "Success" => Self::Success,
+            "Refused" => Self::Refused,
+            "Error" => Self::Error,
+            "NotEnoughBalance" => Self::NotEnoughBalance,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ResultCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ResultCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ResultCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(code.as_ref()))
+    }
+}
+
+/// Response of a stored value operation.
+///
+/// All operations share this shape; fields not relevant to an operation are
+/// left out by Adyen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueResponse {
+    /// Result of the operation.
+    pub result_code: ResultCode,
+
+    /// PSP reference of the operation.
+    pub psp_reference: Option<Box<str>>,
+
+    /// Authorisation code of the operation.
+    pub auth_code: Option<Box<str>>,
+
+    /// Balance of the card after the operation.
+    pub current_balance: Option<Amount>,
+
+    /// The issued card, returned when issuing a card.
+    pub payment_method: Option<StoredValuePaymentMethod>,
+
+    /// Reason the operation was refused.
+    pub refusal_reason: Option<Box<str>>,
+
+    /// Refusal reason given by the card provider.
+    pub third_party_refusal_reason: Option<Box<str>>,
+}
+
+impl StoredValueResponse {
+    /// Check if the operation succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.result_code == ResultCode::Success
+    }
+}
+
+// ============================================================================
+// Issue
+// ============================================================================
+
+/// Request to issue a new gift card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueIssueRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Type of card to issue.
+    pub payment_method: StoredValuePaymentMethod,
+
+    /// Your reference for this operation.
+    pub reference: Box<str>,
+
+    /// Initial balance of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+
+    /// Sales channel of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Your reference for the shopper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_reference: Option<Box<str>>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueIssueRequest {
+    /// Create a new issue request builder.
+    #[must_use]
+    pub fn builder() -> StoredValueIssueRequestBuilder {
+        StoredValueIssueRequestBuilder::default()
+    }
+}
+
+/// Builder for issue requests.
+#[derive(Debug, Default)]
+pub struct StoredValueIssueRequestBuilder {
+    merchant_account: Option<Box<str>>,
+    payment_method: Option<StoredValuePaymentMethod>,
+    reference: Option<Box<str>>,
+    amount: Option<Amount>,
+    shopper_interaction: Option<ShopperInteraction>,
+    shopper_reference: Option<Box<str>>,
+    store: Option<Box<str>>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueIssueRequestBuilder {
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the type of card to issue.
+    #[must_use]
+    pub fn payment_method(mut self, payment_method: StoredValuePaymentMethod) -> Self {
+        self.payment_method = Some(payment_method);
+        self
+    }
+
+    /// Set the reference.
+    pub fn reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the initial balance of the card.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the shopper reference.
+    pub fn shopper_reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
+        self.shopper_reference = Some(reference.into());
+        self
+    }
+
+    /// Set the store.
+    pub fn store<S: Into<Box<str>>>(mut self, store: S) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this request.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Build the issue request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<StoredValueIssueRequest, Box<str>> {
+        Ok(StoredValueIssueRequest {
+            merchant_account: self
+                .merchant_account
+                .ok_or("merchant_account is required")?,
+            payment_method: self.payment_method.ok_or("payment_method is required")?,
+            reference: self.reference.ok_or("reference is required")?,
+            amount: self.amount,
+            shopper_interaction: self.shopper_interaction,
+            shopper_reference: self.shopper_reference,
+            store: self.store,
+            idempotency_key: self.idempotency_key,
+        })
+    }
+}
+
+// ============================================================================
+// Status Change
+// ============================================================================
+
+/// Status of a gift card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StoredValueStatus {
+    /// The card can be used.
+    Active,
+    /// The card is blocked.
+    Inactive,
+    /// A stored value status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request to activate or deactivate a gift card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueStatusChangeRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card to change the status of.
+    pub payment_method: StoredValuePaymentMethod,
+
+    /// Your reference for this operation.
+    pub reference: Box<str>,
+
+    /// New status of the card.
+    pub status: StoredValueStatus,
+
+    /// Balance to load when activating the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+
+    /// Sales channel of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueStatusChangeRequest {
+    /// Create a new status change request builder.
+    #[must_use]
+    pub fn builder() -> StoredValueStatusChangeRequestBuilder {
+        StoredValueStatusChangeRequestBuilder::default()
+    }
+}
+
+/// Builder for status change requests.
+#[derive(Debug, Default)]
+pub struct StoredValueStatusChangeRequestBuilder {
+    merchant_account: Option<Box<str>>,
+    payment_method: Option<StoredValuePaymentMethod>,
+    reference: Option<Box<str>>,
+    status: Option<StoredValueStatus>,
+    amount: Option<Amount>,
+    shopper_interaction: Option<ShopperInteraction>,
+    store: Option<Box<str>>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueStatusChangeRequestBuilder {
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the card to change the status of.
+    #[must_use]
+    pub fn payment_method(mut self, payment_method: StoredValuePaymentMethod) -> Self {
+        self.payment_method = Some(payment_method);
+        self
+    }
+
+    /// Set the reference.
+    pub fn reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the new status of the card.
+    #[must_use]
+    pub fn status(mut self, status: StoredValueStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the balance to load when activating the card.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the store.
+    pub fn store<S: Into<Box<str>>>(mut self, store: S) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this request.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Build the status change request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<StoredValueStatusChangeRequest, Box<str>> {
+        Ok(StoredValueStatusChangeRequest {
+            merchant_account: self
+                .merchant_account
+                .ok_or("merchant_account is required")?,
+            payment_method: self.payment_method.ok_or("payment_method is required")?,
+            reference: self.reference.ok_or("reference is required")?,
+            status: self.status.ok_or("status is required")?,
+            amount: self.amount,
+            shopper_interaction: self.shopper_interaction,
+            store: self.store,
+            idempotency_key: self.idempotency_key,
+        })
+    }
+}
+
+// ============================================================================
+// Load
+// ============================================================================
+
+/// Reason for loading funds onto a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LoadType {
+    /// Funds bought by the shopper.
+    Load,
+    /// Funds refunded for returned merchandise.
+    MerchandiseReturn,
+    /// A load type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request to load funds onto a gift card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueLoadRequest {
+    /// Amount to load.
+    pub amount: Amount,
+
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card to load funds onto.
+    pub payment_method: StoredValuePaymentMethod,
+
+    /// Your reference for this operation.
+    pub reference: Box<str>,
+
+    /// Reason for loading the funds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_type: Option<LoadType>,
+
+    /// Sales channel of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueLoadRequest {
+    /// Create a new load request builder.
+    #[must_use]
+    pub fn builder() -> StoredValueLoadRequestBuilder {
+        StoredValueLoadRequestBuilder::default()
+    }
+}
+
+/// Builder for load requests.
+#[derive(Debug, Default)]
+pub struct StoredValueLoadRequestBuilder {
+    amount: Option<Amount>,
+    merchant_account: Option<Box<str>>,
+    payment_method: Option<StoredValuePaymentMethod>,
+    reference: Option<Box<str>>,
+    load_type: Option<LoadType>,
+    shopper_interaction: Option<ShopperInteraction>,
+    store: Option<Box<str>>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueLoadRequestBuilder {
+    /// Set the amount to load.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the card to load funds onto.
+    #[must_use]
+    pub fn payment_method(mut self, payment_method: StoredValuePaymentMethod) -> Self {
+        self.payment_method = Some(payment_method);
+        self
+    }
+
+    /// Set the reference.
+    pub fn reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the reason for loading the funds.
+    #[must_use]
+    pub fn load_type(mut self, load_type: LoadType) -> Self {
+        self.load_type = Some(load_type);
+        self
+    }
+
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the store.
+    pub fn store<S: Into<Box<str>>>(mut self, store: S) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this request.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Build the load request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<StoredValueLoadRequest, Box<str>> {
+        Ok(StoredValueLoadRequest {
+            amount: self.amount.ok_or("amount is required")?,
+            merchant_account: self
+                .merchant_account
+                .ok_or("merchant_account is required")?,
+            payment_method: self.payment_method.ok_or("payment_method is required")?,
+            reference: self.reference.ok_or("reference is required")?,
+            load_type: self.load_type,
+            shopper_interaction: self.shopper_interaction,
+            store: self.store,
+            idempotency_key: self.idempotency_key,
+        })
+    }
+}
+
+// ============================================================================
+// Balance Check
+// ============================================================================
+
+/// Request to check the balance of a gift card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueBalanceCheckRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card to check the balance of.
+    pub payment_method: StoredValuePaymentMethod,
+
+    /// Your reference for this operation.
+    pub reference: Box<str>,
+
+    /// Sales channel of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+}
+
+impl StoredValueBalanceCheckRequest {
+    /// Create a balance check for the given card.
+    #[must_use]
+    pub fn new(
+        merchant_account: &str,
+        payment_method: StoredValuePaymentMethod,
+        reference: &str,
+    ) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            payment_method,
+            reference: reference.into(),
+            shopper_interaction: None,
+            store: None,
+        }
+    }
+
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the store.
+    #[must_use]
+    pub fn store(mut self, store: &str) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+}
+
+// ============================================================================
+// Balance Merge
+// ============================================================================
+
+/// Request to move the full balance of one gift card onto another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueBalanceMergeRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// Card that receives the balance.
+    pub payment_method: StoredValuePaymentMethod,
+
+    /// Card whose balance is moved.
+    pub source_payment_method: StoredValuePaymentMethod,
+
+    /// Your reference for this operation.
+    pub reference: Box<str>,
+
+    /// Sales channel of the operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shopper_interaction: Option<ShopperInteraction>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueBalanceMergeRequest {
+    /// Create a new balance merge request builder.
+    #[must_use]
+    pub fn builder() -> StoredValueBalanceMergeRequestBuilder {
+        StoredValueBalanceMergeRequestBuilder::default()
+    }
+}
+
+/// Builder for balance merge requests.
+#[derive(Debug, Default)]
+pub struct StoredValueBalanceMergeRequestBuilder {
+    merchant_account: Option<Box<str>>,
+    payment_method: Option<StoredValuePaymentMethod>,
+    source_payment_method: Option<StoredValuePaymentMethod>,
+    reference: Option<Box<str>>,
+    shopper_interaction: Option<ShopperInteraction>,
+    store: Option<Box<str>>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueBalanceMergeRequestBuilder {
+    /// Set the merchant account.
+    pub fn merchant_account<S: Into<Box<str>>>(mut self, account: S) -> Self {
+        self.merchant_account = Some(account.into());
+        self
+    }
+
+    /// Set the card that receives the balance.
+    #[must_use]
+    pub fn payment_method(mut self, payment_method: StoredValuePaymentMethod) -> Self {
+        self.payment_method = Some(payment_method);
+        self
+    }
+
+    /// Set the card whose balance is moved.
+    #[must_use]
+    pub fn source_payment_method(mut self, payment_method: StoredValuePaymentMethod) -> Self {
+        self.source_payment_method = Some(payment_method);
+        self
+    }
+
+    /// Set the reference.
+    pub fn reference<S: Into<Box<str>>>(mut self, reference: S) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the sales channel.
+    #[must_use]
+    pub fn shopper_interaction(mut self, interaction: ShopperInteraction) -> Self {
+        self.shopper_interaction = Some(interaction);
+        self
+    }
+
+    /// Set the store.
+    pub fn store<S: Into<Box<str>>>(mut self, store: S) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this request.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Build the balance merge request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<StoredValueBalanceMergeRequest, Box<str>> {
+        Ok(StoredValueBalanceMergeRequest {
+            merchant_account: self
+                .merchant_account
+                .ok_or("merchant_account is required")?,
+            payment_method: self.payment_method.ok_or("payment_method is required")?,
+            source_payment_method: self
+                .source_payment_method
+                .ok_or("source_payment_method is required")?,
+            reference: self.reference.ok_or("reference is required")?,
+            shopper_interaction: self.shopper_interaction,
+            store: self.store,
+            idempotency_key: self.idempotency_key,
+        })
+    }
+}
+
+// ============================================================================
+// Void
+// ============================================================================
+
+/// Request to void a previous stored value operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredValueVoidRequest {
+    /// The merchant account identifier.
+    pub merchant_account: Box<str>,
+
+    /// PSP reference of the operation to void.
+    pub original_reference: Box<str>,
+
+    /// Your reference for this operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+
+    /// Store the operation is processed for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<Box<str>>,
+
+    /// Reference of the tender of the operation to void.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tender_reference: Option<Box<str>>,
+
+    /// Terminal the operation to void was made on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_terminal_id: Option<Box<str>>,
+
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl StoredValueVoidRequest {
+    /// Create a request voiding the operation with the given PSP reference.
+    #[must_use]
+    pub fn new(merchant_account: &str, original_reference: &str) -> Self {
+        Self {
+            merchant_account: merchant_account.into(),
+            original_reference: original_reference.into(),
+            reference: None,
+            store: None,
+            tender_reference: None,
+            unique_terminal_id: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the reference.
+    #[must_use]
+    pub fn reference(mut self, reference: &str) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the store.
+    #[must_use]
+    pub fn store(mut self, store: &str) -> Self {
+        self.store = Some(store.into());
+        self
+    }
+
+    /// Set the tender and terminal of an in-person operation to void.
+    #[must_use]
+    pub fn terminal_operation(mut self, tender_reference: &str, unique_terminal_id: &str) -> Self {
+        self.tender_reference = Some(tender_reference.into());
+        self.unique_terminal_id = Some(unique_terminal_id.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this request.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+}
@@ -0,0 +1,230 @@
+//! Integration tests for the Adyen Stored Value API v46.
+
+use adyen_core::{ConfigBuilder, Environment};
+use adyen_stored_value::types::*;
+use adyen_stored_value::StoredValueApi;
+
+fn create_test_config() -> adyen_core::Config {
+    ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn gift_card() -> StoredValuePaymentMethod {
+    StoredValuePaymentMethod::card("givex", "6036280000000000000", "123")
+}
+
+#[cfg(test)]
+mod request_building_tests {
+    use super::*;
+
+    #[test]
+    fn test_api_creation() {
+        let api = StoredValueApi::new(create_test_config());
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_load_request_requires_amount() {
+        let result = StoredValueLoadRequest::builder()
+            .merchant_account("TestMerchant")
+            .payment_method(gift_card())
+            .reference("load-001")
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "amount is required");
+    }
+
+    #[test]
+    fn test_merge_request_requires_source() {
+        let result = StoredValueBalanceMergeRequest::builder()
+            .merchant_account("TestMerchant")
+            .payment_method(gift_card())
+            .reference("merge-001")
+            .build();
+        assert_eq!(
+            result.unwrap_err().as_ref(),
+            "source_payment_method is required"
+        );
+    }
+
+    #[test]
+    fn test_result_code_unknown_fallback() {
+        assert_eq!(
+            ResultCode::from("NotEnoughBalance"),
+            ResultCode::NotEnoughBalance
+        );
+        assert_eq!(
+            ResultCode::from("Pending"),
+            ResultCode::Unknown("Pending".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_status_change_request_serialization() {
+        let request = StoredValueStatusChangeRequest::builder()
+            .merchant_account("TestMerchant")
+            .payment_method(gift_card())
+            .reference("activate-001")
+            .status(StoredValueStatus::Active)
+            .amount(Amount::new(1000, "EUR"))
+            .shopper_interaction(ShopperInteraction::Pos)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "merchantAccount": "TestMerchant",
+                "paymentMethod": {
+                    "type": "givex",
+                    "number": "6036280000000000000",
+                    "securityCode": "123"
+                },
+                "reference": "activate-001",
+                "status": "active",
+                "amount": {"currency": "EUR", "value": 1000},
+                "shopperInteraction": "POS"
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_request_serialization() {
+        let request = StoredValueLoadRequest::builder()
+            .amount(Amount::new(2500, "EUR"))
+            .merchant_account("TestMerchant")
+            .payment_method(gift_card())
+            .reference("load-001")
+            .load_type(LoadType::MerchandiseReturn)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["loadType"], "merchandiseReturn");
+        assert!(json.get("idempotencyKey").is_none());
+    }
+
+    #[test]
+    fn test_issue_response_deserialization() {
+        let json = r#"{
+            "authCode": "1305",
+            "currentBalance": {"currency": "EUR", "value": 1000},
+            "paymentMethod": {
+                "number": "7219627091701347",
+                "securityCode": "0140",
+                "type": "givex"
+            },
+            "pspReference": "851564651069192J",
+            "resultCode": "Success"
+        }"#;
+
+        let response: StoredValueResponse = serde_json::from_str(json).unwrap();
+        assert!(response.is_success());
+        assert_eq!(response.current_balance, Some(Amount::new(1000, "EUR")));
+        assert_eq!(
+            response.payment_method.unwrap().number.as_deref(),
+            Some("7219627091701347")
+        );
+    }
+}
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_core::RequestId;
+
+    fn mock_api(mock: &MockTransport) -> StoredValueApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        StoredValueApi::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_load_then_void() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/StoredValue/v46/load")
+                .header("Idempotency-Key", "load-001")
+                .body_includes(serde_json::json!({"amount": {"currency": "EUR", "value": 2500}}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "currentBalance": {"currency": "EUR", "value": 3500},
+                        "pspReference": "881564657480267D",
+                        "resultCode": "Success"
+                    }),
+                )),
+        );
+        mock.expect(
+            Expectation::post("/pal/servlet/StoredValue/v46/voidTransaction")
+                .body_includes(serde_json::json!({"originalReference": "881564657480267D"}))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "currentBalance": {"currency": "EUR", "value": 1000},
+                        "pspReference": "851564673300692A",
+                        "resultCode": "Success"
+                    }),
+                )),
+        );
+
+        let api = mock_api(&mock);
+        let load = StoredValueLoadRequest::builder()
+            .amount(Amount::new(2500, "EUR"))
+            .merchant_account("TestMerchant")
+            .payment_method(gift_card())
+            .reference("load-001")
+            .idempotency_key(RequestId::from_string("load-001").unwrap())
+            .build()
+            .unwrap();
+        let loaded = api.load(&load).await.unwrap();
+        assert_eq!(loaded.current_balance, Some(Amount::new(3500, "EUR")));
+
+        let void =
+            StoredValueVoidRequest::new("TestMerchant", loaded.psp_reference.as_deref().unwrap());
+        let voided = api.void_transaction(&void).await.unwrap();
+        assert!(voided.is_success());
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_check_balance_not_enough_balance() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/pal/servlet/StoredValue/v46/checkBalance").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "currentBalance": {"currency": "EUR", "value": 0},
+                        "refusalReason": "Not enough balance",
+                        "resultCode": "NotEnoughBalance"
+                    }),
+                ),
+            ),
+        );
+
+        let api = mock_api(&mock);
+        let request =
+            StoredValueBalanceCheckRequest::new("TestMerchant", gift_card(), "balance-001");
+        let response = api.check_balance(&request).await.unwrap();
+
+        assert_eq!(response.result_code, ResultCode::NotEnoughBalance);
+        assert!(!response.is_success());
+        mock.verify();
+    }
+}
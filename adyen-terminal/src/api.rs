@@ -0,0 +1,169 @@
+//! Terminal API (cloud) client implementation.
+
+use crate::types::{TerminalApiRequest, TerminalApiResponse};
+use adyen_core::{http::Method, AdyenError, Api, Client, Config, Request, Result};
+use std::time::Duration;
+
+/// Default timeout for synchronous calls.
+///
+/// A synchronous call only returns once the shopper finished the transaction
+/// on the terminal, so Adyen recommends a timeout of at least 150 seconds.
+pub const DEFAULT_SYNC_TIMEOUT: Duration = Duration::from_secs(150);
+
+/// Adyen Terminal API client for cloud-connected terminals.
+///
+/// Sends nexo messages to payment terminals through Adyen's cloud endpoints.
+/// Use [`sync_request`](Self::sync_request) to wait for the terminal's
+/// response, or [`async_request`](Self::async_request) to receive the result
+/// as a display or event notification instead.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_terminal::TerminalApi;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let terminal_api = TerminalApi::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TerminalApi {
+    client: Client,
+    sync_timeout: Duration,
+}
+
+impl TerminalApi {
+    /// Create a new Terminal API client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::Terminal)?;
+        Ok(Self {
+            client,
+            sync_timeout: DEFAULT_SYNC_TIMEOUT,
+        })
+    }
+
+    /// Create a new Terminal API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Terminal),
+            sync_timeout: DEFAULT_SYNC_TIMEOUT,
+        }
+    }
+
+    /// Set the timeout for synchronous calls.
+    #[must_use]
+    pub const fn with_sync_timeout(mut self, timeout: Duration) -> Self {
+        self.sync_timeout = timeout;
+        self
+    }
+
+    /// Send a request and wait for the terminal's response.
+    ///
+    /// If Adyen cannot deliver the request, the returned response holds an
+    /// [`EventNotification`](crate::EventNotification) instead of a
+    /// `SaleToPOIResponse`. Requests without a response, such as aborts,
+    /// return an empty [`TerminalApiResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_core::{Amount, Currency};
+    /// use adyen_terminal::{PaymentRequest, RequestBody, SaleToPOIRequest, TerminalApi};
+    ///
+    /// # async fn example(api: TerminalApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let payment = PaymentRequest::builder()
+    ///     .transaction_id("order-001")
+    ///     .time_stamp("2024-01-01T12:00:00Z")
+    ///     .amount(&Amount::from_minor_units(1099, Currency::EUR))
+    ///     .build()
+    ///     .map_err(|e| format!("Builder error: {}", e))?;
+    ///
+    /// let request = SaleToPOIRequest::new(
+    ///     "POSSystemID12345",
+    ///     "V400m-324688179",
+    ///     "0207111104",
+    ///     RequestBody::PaymentRequest(payment),
+    /// );
+    ///
+    /// let response = api.sync_request(&request.into()).await?;
+    /// if let Some(payment) = response.payment_response() {
+    ///     println!("Payment result: {:?}", payment.response.result);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync_request(&self, request: &TerminalApiRequest) -> Result<TerminalApiResponse> {
+        let url = format!(
+            "{}/sync",
+            self.client.config().environment().terminal_api_url()
+        );
+        let request = Request::new(Method::Post, url)
+            .with_body(serde_json::to_value(request)?)
+            .with_timeout(self.sync_timeout);
+        let response = self.client.execute_raw(request).await?;
+
+        if is_empty_reply(&response.data) {
+            return Ok(TerminalApiResponse::default());
+        }
+        Ok(serde_json::from_slice(&response.data)?)
+    }
+
+    /// Send a request without waiting for the terminal's response.
+    ///
+    /// The outcome is delivered to the event notification URL configured for
+    /// the terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or Adyen does not accept it.
+    pub async fn async_request(&self, request: &TerminalApiRequest) -> Result<()> {
+        let url = format!(
+            "{}/async",
+            self.client.config().environment().terminal_api_url()
+        );
+        let request = Request::new(Method::Post, url).with_body(serde_json::to_value(request)?);
+        let response = self.client.execute_raw(request).await?;
+
+        if is_empty_reply(&response.data) {
+            return Ok(());
+        }
+        Err(AdyenError::generic(format!(
+            "Terminal API rejected the request: {}",
+            String::from_utf8_lossy(&response.data)
+        )))
+    }
+}
+
+/// Check if a response body carries no message, which is how the Terminal
+/// API acknowledges asynchronous requests and messages without a response.
+fn is_empty_reply(body: &[u8]) -> bool {
+    let body = body.trim_ascii();
+    body.is_empty() || body.eq_ignore_ascii_case(b"ok")
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl TerminalApi {
+        fn sync_request_blocking => sync_request(request: &TerminalApiRequest) -> Result<TerminalApiResponse>;
+        fn async_request_blocking => async_request(request: &TerminalApiRequest) -> Result<()>;
+    }
+}
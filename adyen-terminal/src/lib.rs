@@ -0,0 +1,60 @@
+//! Adyen Terminal API (cloud) for in-person payments.
+//!
+//! This crate provides access to Adyen's cloud Terminal API, enabling sale
+//! systems to drive payment terminals through Adyen using nexo messages.
+//!
+//! # Features
+//!
+//! - **Sync and Async Calls**: Wait for the terminal's response or receive it as a notification
+//! - **Payments and Refunds**: `PaymentRequest` with typed amounts and payment types
+//! - **Reversals**: Full and partial reversals of previous transactions
+//! - **Transaction Status**: Recover the outcome of transactions after timeouts
+//! - **Abort**: Cancel transactions in progress
+//! - **nexo Envelopes**: Typed `SaleToPOIRequest`/`SaleToPOIResponse` messages
+//! - **Local Terminal API**: Encrypted messages sent straight to terminals (`local` feature)
+//!
+//! # Quick Start
+//!
+//! ```rust
+//! use adyen_core::{Amount, ConfigBuilder, Currency, Environment};
+//! use adyen_terminal::{PaymentRequest, RequestBody, SaleToPOIRequest, TerminalApi};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let api = TerminalApi::new(config)?;
+//!
+//! let payment = PaymentRequest::builder()
+//!     .transaction_id("order-001")
+//!     .time_stamp("2024-01-01T12:00:00Z")
+//!     .amount(&Amount::from_minor_units(1099, Currency::EUR))
+//!     .build()
+//!     .map_err(|e| format!("Builder error: {}", e))?;
+//!
+//! let request = SaleToPOIRequest::new(
+//!     "POSSystemID12345",
+//!     "V400m-324688179",
+//!     "0207111104",
+//!     RequestBody::PaymentRequest(payment),
+//! );
+//!
+//! let response = api.sync_request(&request.into()).await?;
+//! match response.payment_response() {
+//!     Some(payment) if payment.response.is_success() => println!("Approved"),
+//!     Some(payment) => println!("Declined: {:?}", payment.response.error_condition),
+//!     None => println!("No payment response: {:?}", response.event_notification()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+#[cfg(feature = "local")]
+pub mod local;
+pub mod types;
+
+pub use api::{TerminalApi, DEFAULT_SYNC_TIMEOUT};
+pub use types::*;
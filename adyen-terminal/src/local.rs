@@ -0,0 +1,548 @@
+//! Local Terminal API with nexo payload encryption.
+//!
+//! On a local network the sale system talks to the terminal directly at
+//! `https://<terminal-ip>:8443/nexo`. Messages are encrypted with a key derived
+//! from the key identifier, passphrase and version configured for the terminal
+//! in the Customer Area:
+//!
+//! 1. PBKDF2-HMAC-SHA1 (salt `AdyenNexoV1Salt`, 4000 rounds) derives 80 bytes:
+//!    a 32-byte HMAC key, a 32-byte AES key and a 16-byte IV.
+//! 2. The serialized message is encrypted with AES-256-CBC, using the derived
+//!    IV XOR-ed with a random 16-byte nonce.
+//! 3. An HMAC-SHA256 of the plaintext is sent in the `SecurityTrailer` next to
+//!    the nonce, so the receiver can authenticate the decrypted message.
+//!
+//! The message header stays in the clear so the terminal can route the
+//! message.
+//!
+//! The terminal presents a certificate signed by Adyen's terminal root CA.
+//! Configure a transport that trusts it, for example with
+//! [`ConfigBuilder::transport`](adyen_core::ConfigBuilder::transport).
+
+use crate::types::{MessageHeader, TerminalApiRequest, TerminalApiResponse};
+use adyen_core::{http::Method, AdyenError, Api, Client, Config, Request, Result, SecretString};
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fmt;
+use zeroize::Zeroize;
+
+type HmacSha256 = Hmac<Sha256>;
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+/// Salt used to derive nexo encryption keys.
+const KEY_DERIVATION_SALT: &[u8] = b"AdyenNexoV1Salt";
+/// PBKDF2 rounds used to derive nexo encryption keys.
+const KEY_DERIVATION_ROUNDS: u32 = 4000;
+/// Crypto version written to and expected in security trailers.
+pub const ADYEN_CRYPTO_VERSION: u32 = 1;
+
+/// Error types for nexo payload encryption.
+#[derive(Debug, thiserror::Error)]
+pub enum NexoCryptoError {
+    /// The encrypted payload or nonce is malformed.
+    #[error("Invalid encrypted payload: {0}")]
+    InvalidPayload(String),
+    /// The HMAC in the security trailer does not match the payload.
+    #[error("HMAC validation of the decrypted payload failed")]
+    InvalidHmac,
+    /// The message was encrypted with a different key.
+    #[error("Message was encrypted with key {key_identifier} version {key_version}")]
+    KeyMismatch {
+        /// Key identifier from the security trailer.
+        key_identifier: String,
+        /// Key version from the security trailer.
+        key_version: u32,
+    },
+    /// The security trailer uses an unsupported crypto version.
+    #[error("Unsupported Adyen crypto version: {0}")]
+    UnsupportedVersion(u32),
+    /// The decrypted payload is not a valid message.
+    #[error("Invalid decrypted message: {0}")]
+    Serialization(#[from] serde_json::Error),
+    /// The operating system random number generator failed.
+    #[error("Random number generator failed: {0}")]
+    Random(String),
+}
+
+impl From<NexoCryptoError> for AdyenError {
+    fn from(error: NexoCryptoError) -> Self {
+        match error {
+            NexoCryptoError::InvalidHmac | NexoCryptoError::KeyMismatch { .. } => {
+                Self::auth(error.to_string())
+            }
+            _ => Self::generic_with_source("Failed to process encrypted message", Box::new(error)),
+        }
+    }
+}
+
+/// Key identifier, passphrase and version configured for a terminal.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionCredentials {
+    key_identifier: Box<str>,
+    passphrase: SecretString,
+    version: u32,
+}
+
+impl EncryptionCredentials {
+    /// Create encryption credentials.
+    #[must_use]
+    pub fn new(key_identifier: &str, passphrase: &str, version: u32) -> Self {
+        Self {
+            key_identifier: key_identifier.into(),
+            passphrase: SecretString::new(passphrase),
+            version,
+        }
+    }
+
+    /// Get the key identifier.
+    #[must_use]
+    pub fn key_identifier(&self) -> &str {
+        &self.key_identifier
+    }
+
+    /// Get the key version.
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl fmt::Debug for EncryptionCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionCredentials")
+            .field("key_identifier", &self.key_identifier)
+            .field("passphrase", &self.passphrase)
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+/// Trailer authenticating an encrypted nexo message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecurityTrailer {
+    /// Version of the encryption scheme.
+    #[serde(rename = "AdyenCryptoVersion")]
+    pub adyen_crypto_version: u32,
+    /// Identifier of the key used.
+    #[serde(rename = "KeyIdentifier")]
+    pub key_identifier: Box<str>,
+    /// Version of the key used.
+    #[serde(rename = "KeyVersion")]
+    pub key_version: u32,
+    /// Base64-encoded nonce mixed into the IV.
+    #[serde(rename = "Nonce")]
+    pub nonce: Box<str>,
+    /// Base64-encoded HMAC-SHA256 of the plaintext message.
+    #[serde(rename = "Hmac")]
+    pub hmac: Box<str>,
+}
+
+/// Encrypted nexo message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecuredMessage {
+    /// Message header, sent in the clear.
+    #[serde(rename = "MessageHeader")]
+    pub message_header: MessageHeader,
+    /// Base64-encoded encrypted message.
+    #[serde(rename = "NexoBlob")]
+    pub nexo_blob: Box<str>,
+    /// Trailer authenticating the message.
+    #[serde(rename = "SecurityTrailer")]
+    pub security_trailer: SecurityTrailer,
+}
+
+/// Encrypted Terminal API request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecuredTerminalApiRequest {
+    /// The encrypted request envelope.
+    #[serde(rename = "SaleToPOIRequest")]
+    pub sale_to_poi_request: SecuredMessage,
+}
+
+/// Encrypted Terminal API response.
+///
+/// Terminals send event notifications as encrypted `SaleToPOIRequest`
+/// messages, so either field may be set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecuredTerminalApiResponse {
+    /// The encrypted response envelope.
+    #[serde(rename = "SaleToPOIResponse", skip_serializing_if = "Option::is_none")]
+    pub sale_to_poi_response: Option<SecuredMessage>,
+    /// The encrypted event notification.
+    #[serde(rename = "SaleToPOIRequest", skip_serializing_if = "Option::is_none")]
+    pub sale_to_poi_request: Option<SecuredMessage>,
+}
+
+/// Keys derived from [`EncryptionCredentials`], zeroized when dropped.
+struct DerivedKey {
+    hmac_key: [u8; 32],
+    cipher_key: [u8; 32],
+    iv: [u8; 16],
+}
+
+impl DerivedKey {
+    fn derive(passphrase: &str) -> Self {
+        let mut material = [0u8; 80];
+        pbkdf2::pbkdf2_hmac::<sha1::Sha1>(
+            passphrase.as_bytes(),
+            KEY_DERIVATION_SALT,
+            KEY_DERIVATION_ROUNDS,
+            &mut material,
+        );
+
+        let mut key = Self {
+            hmac_key: [0; 32],
+            cipher_key: [0; 32],
+            iv: [0; 16],
+        };
+        key.hmac_key.copy_from_slice(&material[..32]);
+        key.cipher_key.copy_from_slice(&material[32..64]);
+        key.iv.copy_from_slice(&material[64..]);
+        material.zeroize();
+        key
+    }
+
+    fn iv_with_nonce(&self, nonce: &[u8; 16]) -> [u8; 16] {
+        let mut iv = self.iv;
+        for (byte, nonce) in iv.iter_mut().zip(nonce) {
+            *byte ^= nonce;
+        }
+        iv
+    }
+
+    fn hmac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.hmac_key).expect("HMAC accepts any key length")
+    }
+}
+
+impl Drop for DerivedKey {
+    fn drop(&mut self) {
+        self.hmac_key.zeroize();
+        self.cipher_key.zeroize();
+        self.iv.zeroize();
+    }
+}
+
+/// Encrypts and decrypts nexo messages for the local Terminal API.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_terminal::local::{EncryptionCredentials, NexoCrypto};
+/// use adyen_terminal::{MessageCategory, MessageHeader};
+///
+/// let crypto = NexoCrypto::new(EncryptionCredentials::new("mykey", "mypassphrase", 1));
+/// let header = MessageHeader::request(MessageCategory::Payment, "POS-1", "V400m-324688179", "1");
+///
+/// let secured = crypto.encrypt(header, b"{}", [0; 16]);
+/// assert_eq!(crypto.decrypt(&secured).unwrap(), b"{}");
+/// ```
+pub struct NexoCrypto {
+    credentials: EncryptionCredentials,
+    key: DerivedKey,
+}
+
+impl NexoCrypto {
+    /// Derive the encryption keys for the given credentials.
+    #[must_use]
+    pub fn new(credentials: EncryptionCredentials) -> Self {
+        let key = DerivedKey::derive(credentials.passphrase.expose_secret());
+        Self { credentials, key }
+    }
+
+    /// Get the credentials the keys were derived from.
+    #[must_use]
+    pub const fn credentials(&self) -> &EncryptionCredentials {
+        &self.credentials
+    }
+
+    /// Encrypt a Terminal API request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be serialized, or the random
+    /// number generator fails.
+    pub fn encrypt_request(
+        &self,
+        request: &TerminalApiRequest,
+    ) -> std::result::Result<SecuredTerminalApiRequest, NexoCryptoError> {
+        let plaintext = serde_json::to_vec(request)?;
+        let message_header = request.sale_to_poi_request.message_header.clone();
+        Ok(SecuredTerminalApiRequest {
+            sale_to_poi_request: self.encrypt(message_header, &plaintext, random_nonce()?),
+        })
+    }
+
+    /// Decrypt a Terminal API response and validate its security trailer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trailer does not match these credentials, the
+    /// payload cannot be decrypted, or the HMAC does not match.
+    pub fn decrypt_response(
+        &self,
+        response: &SecuredTerminalApiResponse,
+    ) -> std::result::Result<TerminalApiResponse, NexoCryptoError> {
+        let message = response
+            .sale_to_poi_response
+            .as_ref()
+            .or(response.sale_to_poi_request.as_ref());
+        match message {
+            Some(message) => Ok(serde_json::from_slice(&self.decrypt(message)?)?),
+            None => Ok(TerminalApiResponse::default()),
+        }
+    }
+
+    /// Encrypt a serialized message using the given nonce.
+    ///
+    /// The nonce must never be reused with the same key; prefer
+    /// [`encrypt_request`](Self::encrypt_request), which picks a random one.
+    #[must_use]
+    pub fn encrypt(
+        &self,
+        message_header: MessageHeader,
+        plaintext: &[u8],
+        nonce: [u8; 16],
+    ) -> SecuredMessage {
+        let iv = self.key.iv_with_nonce(&nonce);
+        let ciphertext = Aes256CbcEnc::new(&self.key.cipher_key.into(), &iv.into())
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+
+        let mut mac = self.key.hmac();
+        mac.update(plaintext);
+
+        SecuredMessage {
+            message_header,
+            nexo_blob: BASE64.encode(ciphertext).into(),
+            security_trailer: SecurityTrailer {
+                adyen_crypto_version: ADYEN_CRYPTO_VERSION,
+                key_identifier: self.credentials.key_identifier.clone(),
+                key_version: self.credentials.version,
+                nonce: BASE64.encode(nonce).into(),
+                hmac: BASE64.encode(mac.finalize().into_bytes()).into(),
+            },
+        }
+    }
+
+    /// Validate the security trailer of a message and return its decrypted
+    /// payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trailer does not match these credentials, the
+    /// payload cannot be decrypted, or the HMAC does not match.
+    pub fn decrypt(
+        &self,
+        message: &SecuredMessage,
+    ) -> std::result::Result<Vec<u8>, NexoCryptoError> {
+        let trailer = &message.security_trailer;
+        if trailer.adyen_crypto_version != ADYEN_CRYPTO_VERSION {
+            return Err(NexoCryptoError::UnsupportedVersion(
+                trailer.adyen_crypto_version,
+            ));
+        }
+        if trailer.key_identifier != self.credentials.key_identifier
+            || trailer.key_version != self.credentials.version
+        {
+            return Err(NexoCryptoError::KeyMismatch {
+                key_identifier: trailer.key_identifier.to_string(),
+                key_version: trailer.key_version,
+            });
+        }
+
+        let nonce: [u8; 16] = decode_base64(&trailer.nonce, "nonce")?
+            .try_into()
+            .map_err(|_| NexoCryptoError::InvalidPayload("nonce must be 16 bytes".into()))?;
+        let expected_hmac = decode_base64(&trailer.hmac, "HMAC")?;
+        let ciphertext = decode_base64(&message.nexo_blob, "NexoBlob")?;
+
+        let iv = self.key.iv_with_nonce(&nonce);
+        let plaintext = Aes256CbcDec::new(&self.key.cipher_key.into(), &iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(&ciphertext)
+            .map_err(|_| NexoCryptoError::InvalidPayload("invalid padding".into()))?;
+
+        let mut mac = self.key.hmac();
+        mac.update(&plaintext);
+        mac.verify_slice(&expected_hmac)
+            .map_err(|_| NexoCryptoError::InvalidHmac)?;
+
+        Ok(plaintext)
+    }
+}
+
+impl fmt::Debug for NexoCrypto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NexoCrypto")
+            .field("credentials", &self.credentials)
+            .finish_non_exhaustive()
+    }
+}
+
+fn decode_base64(value: &str, field: &str) -> std::result::Result<Vec<u8>, NexoCryptoError> {
+    BASE64
+        .decode(value)
+        .map_err(|e| NexoCryptoError::InvalidPayload(format!("invalid base64 in {field}: {e}")))
+}
+
+fn random_nonce() -> std::result::Result<[u8; 16], NexoCryptoError> {
+    let mut nonce = [0u8; 16];
+    getrandom::getrandom(&mut nonce).map_err(|e| NexoCryptoError::Random(e.to_string()))?;
+    Ok(nonce)
+}
+
+/// Client for the local Terminal API.
+///
+/// Sends encrypted nexo messages straight to a terminal on the local network
+/// and decrypts its responses.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_terminal::local::{EncryptionCredentials, LocalTerminalApi};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let terminal = LocalTerminalApi::new(
+///     config,
+///     "https://192.168.1.20:8443/nexo",
+///     EncryptionCredentials::new("mykey", "mypassphrase", 1),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalTerminalApi {
+    client: Client,
+    endpoint: Box<str>,
+    crypto: std::sync::Arc<NexoCrypto>,
+}
+
+impl LocalTerminalApi {
+    /// Create a client for the terminal at `endpoint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config, endpoint: &str, credentials: EncryptionCredentials) -> Result<Self> {
+        let client = Client::for_api(config, Api::Terminal)?;
+        Ok(Self {
+            client,
+            endpoint: endpoint.into(),
+            crypto: std::sync::Arc::new(NexoCrypto::new(credentials)),
+        })
+    }
+
+    /// Get the terminal endpoint.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Encrypt and send a request, then decrypt the terminal's response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or the response cannot be
+    /// decrypted or fails security trailer validation.
+    pub async fn request(&self, request: &TerminalApiRequest) -> Result<TerminalApiResponse> {
+        let secured = self.crypto.encrypt_request(request)?;
+        let request = Request::new(Method::Post, self.endpoint.as_ref())
+            .with_body(serde_json::to_value(&secured)?)
+            .with_timeout(crate::DEFAULT_SYNC_TIMEOUT);
+        let response = self.client.execute_raw(request).await?;
+
+        if response.data.trim_ascii().is_empty() {
+            return Ok(TerminalApiResponse::default());
+        }
+        let secured: SecuredTerminalApiResponse = serde_json::from_slice(&response.data)?;
+        Ok(self.crypto.decrypt_response(&secured)?)
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl LocalTerminalApi {
+        fn request_blocking => request(request: &TerminalApiRequest) -> Result<TerminalApiResponse>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageCategory;
+
+    fn crypto() -> NexoCrypto {
+        NexoCrypto::new(EncryptionCredentials::new("mykey", "mypassphrase", 1))
+    }
+
+    fn header() -> MessageHeader {
+        MessageHeader::request(
+            MessageCategory::Payment,
+            "POSSystemID12345",
+            "V400m-324688179",
+            "0207111104",
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let crypto = crypto();
+        let plaintext = br#"{"SaleToPOIResponse":{}}"#;
+        let message = crypto.encrypt(header(), plaintext, [7; 16]);
+
+        assert_eq!(message.security_trailer.key_identifier.as_ref(), "mykey");
+        assert_eq!(crypto.decrypt(&message).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_nonce_changes_ciphertext() {
+        let crypto = crypto();
+        let first = crypto.encrypt(header(), b"payload", [1; 16]);
+        let second = crypto.encrypt(header(), b"payload", [2; 16]);
+
+        assert_ne!(first.nexo_blob, second.nexo_blob);
+        assert_eq!(first.security_trailer.hmac, second.security_trailer.hmac);
+    }
+
+    #[test]
+    fn test_tampered_message_rejected() {
+        let crypto = crypto();
+        let mut message = crypto.encrypt(header(), b"payload", [3; 16]);
+        message.security_trailer.hmac = BASE64.encode([0u8; 32]).into();
+
+        assert!(matches!(
+            crypto.decrypt(&message),
+            Err(NexoCryptoError::InvalidHmac)
+        ));
+    }
+
+    #[test]
+    fn test_wrong_key_rejected() {
+        let message = crypto().encrypt(header(), b"payload", [4; 16]);
+
+        let other = NexoCrypto::new(EncryptionCredentials::new("mykey", "mypassphrase", 2));
+        assert!(matches!(
+            other.decrypt(&message),
+            Err(NexoCryptoError::KeyMismatch { key_version: 1, .. })
+        ));
+
+        let other = NexoCrypto::new(EncryptionCredentials::new("mykey", "otherphrase", 1));
+        assert!(other.decrypt(&message).is_err());
+    }
+
+    #[test]
+    fn test_credentials_debug_redacts_passphrase() {
+        let debug = format!("{:?}", crypto());
+        assert!(debug.contains("mykey"));
+        assert!(!debug.contains("mypassphrase"));
+    }
+}
@@ -0,0 +1,951 @@
+//! nexo message types for the Adyen Terminal API.
+//!
+//! The Terminal API wraps every message in a `SaleToPOIRequest` or
+//! `SaleToPOIResponse` envelope made of a [`MessageHeader`] and exactly one
+//! message body. Field names follow the nexo specification, so JSON keys are
+//! `PascalCase` and amounts are decimal values in major units.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// nexo protocol version sent in message headers.
+pub const PROTOCOL_VERSION: &str = "3.0";
+
+// ============================================================================
+// Envelopes
+// ============================================================================
+
+/// Top-level Terminal API request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TerminalApiRequest {
+    /// The request envelope.
+    #[serde(rename = "SaleToPOIRequest")]
+    pub sale_to_poi_request: SaleToPOIRequest,
+}
+
+impl TerminalApiRequest {
+    /// Wrap a request envelope.
+    #[must_use]
+    pub const fn new(sale_to_poi_request: SaleToPOIRequest) -> Self {
+        Self {
+            sale_to_poi_request,
+        }
+    }
+}
+
+impl From<SaleToPOIRequest> for TerminalApiRequest {
+    fn from(sale_to_poi_request: SaleToPOIRequest) -> Self {
+        Self::new(sale_to_poi_request)
+    }
+}
+
+/// Top-level Terminal API response.
+///
+/// A successful call carries a [`SaleToPOIResponse`]. When the request could
+/// not be delivered to the terminal, Adyen answers with a `SaleToPOIRequest`
+/// holding an [`EventNotification`] instead. Both are empty for messages
+/// without a response, such as [`AbortRequest`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TerminalApiResponse {
+    /// The response envelope.
+    #[serde(rename = "SaleToPOIResponse", skip_serializing_if = "Option::is_none")]
+    pub sale_to_poi_response: Option<SaleToPOIResponse>,
+    /// Event sent back in place of a response.
+    #[serde(rename = "SaleToPOIRequest", skip_serializing_if = "Option::is_none")]
+    pub sale_to_poi_request: Option<SaleToPOIRequest>,
+}
+
+impl TerminalApiResponse {
+    /// Get the payment response, if any.
+    #[must_use]
+    pub fn payment_response(&self) -> Option<&PaymentResponse> {
+        match &self.sale_to_poi_response.as_ref()?.body {
+            ResponseBody::PaymentResponse(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Get the reversal response, if any.
+    #[must_use]
+    pub fn reversal_response(&self) -> Option<&ReversalResponse> {
+        match &self.sale_to_poi_response.as_ref()?.body {
+            ResponseBody::ReversalResponse(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Get the transaction status response, if any.
+    #[must_use]
+    pub fn transaction_status_response(&self) -> Option<&TransactionStatusResponse> {
+        match &self.sale_to_poi_response.as_ref()?.body {
+            ResponseBody::TransactionStatusResponse(response) => Some(&**response),
+            _ => None,
+        }
+    }
+
+    /// Get the event notification returned instead of a response, if any.
+    #[must_use]
+    pub fn event_notification(&self) -> Option<&EventNotification> {
+        match &self.sale_to_poi_request.as_ref()?.body {
+            RequestBody::EventNotification(event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+/// Request envelope sent to a terminal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaleToPOIRequest {
+    /// Message header identifying the sale system, terminal and service.
+    #[serde(rename = "MessageHeader")]
+    pub message_header: MessageHeader,
+    /// The message body.
+    #[serde(flatten)]
+    pub body: RequestBody,
+}
+
+impl SaleToPOIRequest {
+    /// Create a request envelope with a service header matching `body`.
+    ///
+    /// `service_id` identifies the transaction on the terminal and must be
+    /// unique for the terminal; nexo limits it to 10 characters.
+    #[must_use]
+    pub fn new(sale_id: &str, poi_id: &str, service_id: &str, body: RequestBody) -> Self {
+        Self {
+            message_header: MessageHeader::request(
+                body.message_category(),
+                sale_id,
+                poi_id,
+                service_id,
+            ),
+            body,
+        }
+    }
+}
+
+/// Response envelope returned by a terminal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaleToPOIResponse {
+    /// Message header echoing the request.
+    #[serde(rename = "MessageHeader")]
+    pub message_header: MessageHeader,
+    /// The message body.
+    #[serde(flatten)]
+    pub body: ResponseBody,
+}
+
+/// Body of a [`SaleToPOIRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RequestBody {
+    /// Start a payment or refund.
+    PaymentRequest(PaymentRequest),
+    /// Reverse a previous transaction.
+    ReversalRequest(ReversalRequest),
+    /// Query the outcome of a previous transaction.
+    TransactionStatusRequest(TransactionStatusRequest),
+    /// Abort a transaction in progress.
+    AbortRequest(AbortRequest),
+    /// Event sent by the terminal or Adyen.
+    EventNotification(EventNotification),
+}
+
+impl RequestBody {
+    /// Get the message category for this body.
+    #[must_use]
+    pub const fn message_category(&self) -> MessageCategory {
+        match self {
+            Self::PaymentRequest(_) => MessageCategory::Payment,
+            Self::ReversalRequest(_) => MessageCategory::Reversal,
+            Self::TransactionStatusRequest(_) => MessageCategory::TransactionStatus,
+            Self::AbortRequest(_) => MessageCategory::Abort,
+            Self::EventNotification(_) => MessageCategory::Event,
+        }
+    }
+}
+
+/// Body of a [`SaleToPOIResponse`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ResponseBody {
+    /// Outcome of a payment or refund.
+    PaymentResponse(PaymentResponse),
+    /// Outcome of a reversal.
+    ReversalResponse(ReversalResponse),
+    /// Outcome of a transaction status query.
+    TransactionStatusResponse(Box<TransactionStatusResponse>),
+}
+
+// ============================================================================
+// Message Header
+// ============================================================================
+
+/// Class of a nexo message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageClass {
+    /// Service request, such as a payment.
+    Service,
+    /// Device request, such as display or input.
+    Device,
+    /// Event notification.
+    Event,
+    /// A message class not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Category of a nexo message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageCategory {
+    /// Abort a transaction in progress.
+    Abort,
+    /// Display a message on the terminal.
+    Display,
+    /// Event notification.
+    Event,
+    /// Request input from the shopper.
+    Input,
+    /// Payment or refund.
+    Payment,
+    /// Reversal of a transaction.
+    Reversal,
+    /// Transaction status query.
+    TransactionStatus,
+    /// A message category not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Type of a nexo message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageType {
+    /// Request message.
+    Request,
+    /// Response message.
+    Response,
+    /// Notification message.
+    Notification,
+    /// A message type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Header of every nexo message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageHeader {
+    /// nexo protocol version.
+    #[serde(rename = "ProtocolVersion", skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<Box<str>>,
+    /// Class of the message.
+    #[serde(rename = "MessageClass")]
+    pub message_class: MessageClass,
+    /// Category of the message.
+    #[serde(rename = "MessageCategory")]
+    pub message_category: MessageCategory,
+    /// Type of the message.
+    #[serde(rename = "MessageType")]
+    pub message_type: MessageType,
+    /// Identifier of the transaction on the terminal.
+    #[serde(rename = "ServiceID", skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<Box<str>>,
+    /// Identifier of the sale system (cash register).
+    #[serde(rename = "SaleID")]
+    pub sale_id: Box<str>,
+    /// Identifier of the terminal, in the format `[model]-[serial number]`.
+    #[serde(rename = "POIID")]
+    pub poi_id: Box<str>,
+}
+
+impl MessageHeader {
+    /// Create a header for a service request.
+    #[must_use]
+    pub fn request(
+        message_category: MessageCategory,
+        sale_id: &str,
+        poi_id: &str,
+        service_id: &str,
+    ) -> Self {
+        Self {
+            protocol_version: Some(PROTOCOL_VERSION.into()),
+            message_class: MessageClass::Service,
+            message_category,
+            message_type: MessageType::Request,
+            service_id: Some(service_id.into()),
+            sale_id: sale_id.into(),
+            poi_id: poi_id.into(),
+        }
+    }
+}
+
+// ============================================================================
+// Common Types
+// ============================================================================
+
+/// Identification of a transaction by the sale system or the terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionIdentification {
+    /// Identifier of the transaction.
+    #[serde(rename = "TransactionID")]
+    pub transaction_id: Box<str>,
+    /// Date and time of the transaction in ISO 8601 format.
+    #[serde(rename = "TimeStamp")]
+    pub time_stamp: Box<str>,
+}
+
+impl TransactionIdentification {
+    /// Create a transaction identification.
+    #[must_use]
+    pub fn new(transaction_id: &str, time_stamp: &str) -> Self {
+        Self {
+            transaction_id: transaction_id.into(),
+            time_stamp: time_stamp.into(),
+        }
+    }
+}
+
+/// Data from the sale system about a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaleData {
+    /// Identification of the transaction by the sale system.
+    #[serde(rename = "SaleTransactionID")]
+    pub sale_transaction_id: TransactionIdentification,
+    /// Additional data for Adyen, e.g. base64-encoded JSON or URL-encoded key-value pairs.
+    #[serde(rename = "SaleToAcquirerData", skip_serializing_if = "Option::is_none")]
+    pub sale_to_acquirer_data: Option<Box<str>>,
+    /// Identifier of a group of transactions, e.g. for split tenders.
+    #[serde(rename = "SaleReferenceID", skip_serializing_if = "Option::is_none")]
+    pub sale_reference_id: Option<Box<str>>,
+}
+
+/// Data from the terminal about a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct POIData {
+    /// Identification of the transaction by the terminal.
+    #[serde(rename = "POITransactionID")]
+    pub poi_transaction_id: TransactionIdentification,
+    /// Reconciliation period of the transaction.
+    #[serde(
+        rename = "POIReconciliationID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub poi_reconciliation_id: Option<Box<str>>,
+}
+
+/// Reference to a previous message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageReference {
+    /// Category of the referenced message.
+    #[serde(rename = "MessageCategory", skip_serializing_if = "Option::is_none")]
+    pub message_category: Option<MessageCategory>,
+    /// Service identifier of the referenced message.
+    #[serde(rename = "ServiceID", skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<Box<str>>,
+    /// Sale system of the referenced message.
+    #[serde(rename = "SaleID", skip_serializing_if = "Option::is_none")]
+    pub sale_id: Option<Box<str>>,
+    /// Terminal of the referenced message.
+    #[serde(rename = "POIID", skip_serializing_if = "Option::is_none")]
+    pub poi_id: Option<Box<str>>,
+}
+
+impl MessageReference {
+    /// Reference a message by category and service identifier.
+    #[must_use]
+    pub fn new(message_category: MessageCategory, service_id: &str) -> Self {
+        Self {
+            message_category: Some(message_category),
+            service_id: Some(service_id.into()),
+            sale_id: None,
+            poi_id: None,
+        }
+    }
+
+    /// Set the sale system of the referenced message.
+    #[must_use]
+    pub fn sale_id(mut self, sale_id: &str) -> Self {
+        self.sale_id = Some(sale_id.into());
+        self
+    }
+
+    /// Set the terminal of the referenced message.
+    #[must_use]
+    pub fn poi_id(mut self, poi_id: &str) -> Self {
+        self.poi_id = Some(poi_id.into());
+        self
+    }
+}
+
+/// Result of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResultCode {
+    /// The request succeeded.
+    Success,
+    /// The request failed.
+    Failure,
+    /// The request partially succeeded, e.g. a partial approval.
+    Partial,
+    /// A result code not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Reason a request failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorCondition {
+    /// The transaction was aborted.
+    Aborted,
+    /// The terminal is busy with another transaction.
+    Busy,
+    /// The shopper or cashier cancelled the transaction.
+    Cancel,
+    /// The device is out of order.
+    DeviceOut,
+    /// The transaction is still in progress.
+    InProgress,
+    /// The card was inserted instead of tapped or swiped.
+    InsertedCard,
+    /// The card is invalid.
+    InvalidCard,
+    /// The terminal is logged out.
+    LoggedOut,
+    /// The message is malformed.
+    MessageFormat,
+    /// The request is not allowed.
+    NotAllowed,
+    /// The referenced transaction was not found.
+    NotFound,
+    /// The payment is restricted.
+    PaymentRestriction,
+    /// The transaction was refused.
+    Refusal,
+    /// The device is unavailable.
+    UnavailableDevice,
+    /// The service is unavailable.
+    UnavailableService,
+    /// The host could not be reached.
+    UnreachableHost,
+    /// The shopper entered a wrong PIN.
+    #[serde(rename = "WrongPIN")]
+    WrongPin,
+    /// A condition not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Outcome of a request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Response {
+    /// Result of the request.
+    #[serde(rename = "Result")]
+    pub result: ResultCode,
+    /// Reason the request failed.
+    #[serde(rename = "ErrorCondition", skip_serializing_if = "Option::is_none")]
+    pub error_condition: Option<ErrorCondition>,
+    /// Additional data from Adyen, URL-encoded or base64-encoded JSON.
+    #[serde(rename = "AdditionalResponse", skip_serializing_if = "Option::is_none")]
+    pub additional_response: Option<Box<str>>,
+}
+
+impl Response {
+    /// Check if the request succeeded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.result == ResultCode::Success
+    }
+
+    /// Decode a URL-encoded additional response into key-value pairs.
+    ///
+    /// Returns an empty map if there is no additional response. Entries that
+    /// are not valid `key=value` pairs are skipped.
+    #[must_use]
+    pub fn additional_data(&self) -> HashMap<String, String> {
+        self.additional_response
+            .as_deref()
+            .unwrap_or_default()
+            .split('&')
+            .filter_map(|pair| {
+                let (key, value) = pair.split_once('=')?;
+                let key = urlencoding::decode(key).ok()?;
+                let value = urlencoding::decode(value).ok()?;
+                Some((key.into_owned(), value.into_owned()))
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// Payment
+// ============================================================================
+
+/// Type of a payment transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentType {
+    /// Regular payment.
+    Normal,
+    /// Refund to the shopper's card.
+    Refund,
+    /// A payment type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Amounts requested for a payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountsReq {
+    /// Three-character ISO currency code.
+    #[serde(rename = "Currency")]
+    pub currency: Box<str>,
+    /// Amount to pay in major units.
+    #[serde(rename = "RequestedAmount", with = "rust_decimal::serde::float")]
+    pub requested_amount: Decimal,
+    /// Tip amount in major units.
+    #[serde(
+        rename = "TipAmount",
+        with = "rust_decimal::serde::float_option",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub tip_amount: Option<Decimal>,
+}
+
+impl From<&adyen_core::Amount> for AmountsReq {
+    fn from(amount: &adyen_core::Amount) -> Self {
+        Self {
+            currency: amount.currency().to_string().into(),
+            requested_amount: amount.major_units(),
+            tip_amount: None,
+        }
+    }
+}
+
+/// Transaction details of a payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentTransaction {
+    /// Requested amounts.
+    #[serde(rename = "AmountsReq")]
+    pub amounts_req: AmountsReq,
+}
+
+/// Payment data of a payment request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentData {
+    /// Type of the payment.
+    #[serde(rename = "PaymentType")]
+    pub payment_type: PaymentType,
+}
+
+/// Request to start a payment or refund on a terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentRequest {
+    /// Sale system data.
+    #[serde(rename = "SaleData")]
+    pub sale_data: SaleData,
+    /// Transaction details.
+    #[serde(rename = "PaymentTransaction")]
+    pub payment_transaction: PaymentTransaction,
+    /// Payment data, e.g. to request a refund.
+    #[serde(rename = "PaymentData", skip_serializing_if = "Option::is_none")]
+    pub payment_data: Option<PaymentData>,
+}
+
+impl PaymentRequest {
+    /// Create a new builder for `PaymentRequest`.
+    #[must_use]
+    pub fn builder() -> PaymentRequestBuilder {
+        PaymentRequestBuilder::default()
+    }
+}
+
+/// Builder for `PaymentRequest`.
+#[derive(Debug, Default)]
+pub struct PaymentRequestBuilder {
+    transaction_id: Option<Box<str>>,
+    time_stamp: Option<Box<str>>,
+    amounts_req: Option<AmountsReq>,
+    payment_type: Option<PaymentType>,
+    sale_to_acquirer_data: Option<Box<str>>,
+    sale_reference_id: Option<Box<str>>,
+}
+
+impl PaymentRequestBuilder {
+    /// Set the sale system's transaction identifier.
+    #[must_use]
+    pub fn transaction_id(mut self, transaction_id: &str) -> Self {
+        self.transaction_id = Some(transaction_id.into());
+        self
+    }
+
+    /// Set the transaction time stamp in ISO 8601 format.
+    #[must_use]
+    pub fn time_stamp(mut self, time_stamp: &str) -> Self {
+        self.time_stamp = Some(time_stamp.into());
+        self
+    }
+
+    /// Set the amount to pay.
+    #[must_use]
+    pub fn amount(mut self, amount: &adyen_core::Amount) -> Self {
+        self.amounts_req = Some(amount.into());
+        self
+    }
+
+    /// Set the requested amounts directly.
+    #[must_use]
+    pub fn amounts_req(mut self, amounts_req: AmountsReq) -> Self {
+        self.amounts_req = Some(amounts_req);
+        self
+    }
+
+    /// Set the payment type.
+    #[must_use]
+    pub fn payment_type(mut self, payment_type: PaymentType) -> Self {
+        self.payment_type = Some(payment_type);
+        self
+    }
+
+    /// Set additional data for Adyen.
+    #[must_use]
+    pub fn sale_to_acquirer_data(mut self, data: &str) -> Self {
+        self.sale_to_acquirer_data = Some(data.into());
+        self
+    }
+
+    /// Set the sale reference identifier.
+    #[must_use]
+    pub fn sale_reference_id(mut self, sale_reference_id: &str) -> Self {
+        self.sale_reference_id = Some(sale_reference_id.into());
+        self
+    }
+
+    /// Build the `PaymentRequest`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<PaymentRequest, Box<str>> {
+        Ok(PaymentRequest {
+            sale_data: SaleData {
+                sale_transaction_id: TransactionIdentification {
+                    transaction_id: self.transaction_id.ok_or("transaction_id is required")?,
+                    time_stamp: self.time_stamp.ok_or("time_stamp is required")?,
+                },
+                sale_to_acquirer_data: self.sale_to_acquirer_data,
+                sale_reference_id: self.sale_reference_id,
+            },
+            payment_transaction: PaymentTransaction {
+                amounts_req: self.amounts_req.ok_or("amount is required")?,
+            },
+            payment_data: self
+                .payment_type
+                .map(|payment_type| PaymentData { payment_type }),
+        })
+    }
+}
+
+/// Amounts of a completed payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountsResp {
+    /// Three-character ISO currency code.
+    #[serde(rename = "Currency", skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Box<str>>,
+    /// Authorised amount in major units.
+    #[serde(rename = "AuthorizedAmount", with = "rust_decimal::serde::float")]
+    pub authorized_amount: Decimal,
+}
+
+/// Result of a payment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentResult {
+    /// Type of the payment.
+    #[serde(rename = "PaymentType", skip_serializing_if = "Option::is_none")]
+    pub payment_type: Option<PaymentType>,
+    /// Data about the payment instrument, such as the masked card number.
+    #[serde(
+        rename = "PaymentInstrumentData",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payment_instrument_data: Option<serde_json::Value>,
+    /// Authorised amounts.
+    #[serde(rename = "AmountsResp", skip_serializing_if = "Option::is_none")]
+    pub amounts_resp: Option<AmountsResp>,
+    /// Whether the payment was authorised online.
+    #[serde(rename = "OnlineFlag", skip_serializing_if = "Option::is_none")]
+    pub online_flag: Option<bool>,
+}
+
+/// Response to a [`PaymentRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentResponse {
+    /// Outcome of the payment.
+    #[serde(rename = "Response")]
+    pub response: Response,
+    /// Sale system data echoed from the request.
+    #[serde(rename = "SaleData")]
+    pub sale_data: SaleData,
+    /// Terminal data, including the terminal's transaction identifier.
+    #[serde(rename = "POIData")]
+    pub poi_data: POIData,
+    /// Result of the payment.
+    #[serde(rename = "PaymentResult", skip_serializing_if = "Option::is_none")]
+    pub payment_result: Option<PaymentResult>,
+    /// Receipts to print, as returned by the terminal.
+    #[serde(rename = "PaymentReceipt", skip_serializing_if = "Option::is_none")]
+    pub payment_receipt: Option<Vec<serde_json::Value>>,
+}
+
+// ============================================================================
+// Reversal
+// ============================================================================
+
+/// Reason for a reversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReversalReason {
+    /// The shopper cancelled.
+    CustCancel,
+    /// The merchant cancelled.
+    MerchantCancel,
+    /// A device malfunctioned.
+    Malfunction,
+    /// The transaction could not be completed.
+    Unable2Compl,
+    /// A reversal reason not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Original transaction to reverse.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginalPOITransaction {
+    /// Identification of the transaction by the terminal.
+    #[serde(rename = "POITransactionID")]
+    pub poi_transaction_id: TransactionIdentification,
+    /// Sale system of the original transaction.
+    #[serde(rename = "SaleID", skip_serializing_if = "Option::is_none")]
+    pub sale_id: Option<Box<str>>,
+    /// Terminal of the original transaction.
+    #[serde(rename = "POIID", skip_serializing_if = "Option::is_none")]
+    pub poi_id: Option<Box<str>>,
+}
+
+/// Request to reverse (refund or cancel) a previous transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReversalRequest {
+    /// Transaction to reverse.
+    #[serde(rename = "OriginalPOITransaction")]
+    pub original_poi_transaction: OriginalPOITransaction,
+    /// Reason for the reversal.
+    #[serde(rename = "ReversalReason")]
+    pub reversal_reason: ReversalReason,
+    /// Amount to reverse in major units, for partial reversals.
+    #[serde(
+        rename = "ReversedAmount",
+        with = "rust_decimal::serde::float_option",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub reversed_amount: Option<Decimal>,
+    /// Sale system data of the reversal.
+    #[serde(rename = "SaleData", skip_serializing_if = "Option::is_none")]
+    pub sale_data: Option<SaleData>,
+}
+
+impl ReversalRequest {
+    /// Create a request to fully reverse the transaction identified by the
+    /// terminal's `POITransactionID`.
+    #[must_use]
+    pub fn new(poi_transaction_id: TransactionIdentification, reason: ReversalReason) -> Self {
+        Self {
+            original_poi_transaction: OriginalPOITransaction {
+                poi_transaction_id,
+                sale_id: None,
+                poi_id: None,
+            },
+            reversal_reason: reason,
+            reversed_amount: None,
+            sale_data: None,
+        }
+    }
+
+    /// Only reverse part of the original amount.
+    #[must_use]
+    pub const fn reversed_amount(mut self, amount: Decimal) -> Self {
+        self.reversed_amount = Some(amount);
+        self
+    }
+
+    /// Set sale system data for the reversal.
+    #[must_use]
+    pub fn sale_data(mut self, sale_data: SaleData) -> Self {
+        self.sale_data = Some(sale_data);
+        self
+    }
+}
+
+/// Response to a [`ReversalRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReversalResponse {
+    /// Outcome of the reversal.
+    #[serde(rename = "Response")]
+    pub response: Response,
+    /// Terminal data of the reversal.
+    #[serde(rename = "POIData", skip_serializing_if = "Option::is_none")]
+    pub poi_data: Option<POIData>,
+    /// The reversed transaction.
+    #[serde(
+        rename = "OriginalPOITransaction",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub original_poi_transaction: Option<OriginalPOITransaction>,
+    /// Reversed amount in major units.
+    #[serde(
+        rename = "ReversedAmount",
+        with = "rust_decimal::serde::float_option",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub reversed_amount: Option<Decimal>,
+}
+
+// ============================================================================
+// Transaction Status
+// ============================================================================
+
+/// Kind of document to return with a transaction status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentQualifier {
+    /// Receipt for the cashier.
+    CashierReceipt,
+    /// Receipt for the shopper.
+    CustomerReceipt,
+    /// Generic document.
+    Document,
+    /// Journal entry.
+    Journal,
+    /// Receipt for the sale system.
+    SaleReceipt,
+    /// Voucher.
+    Voucher,
+    /// A document qualifier not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Request for the outcome of a previous transaction.
+///
+/// Without a message reference, the status of the last transaction on the
+/// terminal is returned.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionStatusRequest {
+    /// The transaction to query.
+    #[serde(rename = "MessageReference", skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+    /// Documents to return.
+    #[serde(rename = "DocumentQualifier", skip_serializing_if = "Option::is_none")]
+    pub document_qualifier: Option<Vec<DocumentQualifier>>,
+    /// Whether to return the receipts again.
+    #[serde(rename = "ReceiptReprintFlag", skip_serializing_if = "Option::is_none")]
+    pub receipt_reprint_flag: Option<bool>,
+}
+
+impl TransactionStatusRequest {
+    /// Query the transaction with the given message reference.
+    #[must_use]
+    pub fn new(message_reference: MessageReference) -> Self {
+        Self {
+            message_reference: Some(message_reference),
+            ..Self::default()
+        }
+    }
+}
+
+/// Body of a repeated response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepeatedResponseMessageBody {
+    /// The original payment response.
+    #[serde(rename = "PaymentResponse", skip_serializing_if = "Option::is_none")]
+    pub payment_response: Option<PaymentResponse>,
+    /// The original reversal response.
+    #[serde(rename = "ReversalResponse", skip_serializing_if = "Option::is_none")]
+    pub reversal_response: Option<ReversalResponse>,
+}
+
+/// Copy of the response to the queried transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepeatedMessageResponse {
+    /// Header of the original response.
+    #[serde(rename = "MessageHeader")]
+    pub message_header: MessageHeader,
+    /// Body of the original response.
+    #[serde(rename = "RepeatedResponseMessageBody")]
+    pub repeated_response_message_body: RepeatedResponseMessageBody,
+}
+
+/// Response to a [`TransactionStatusRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionStatusResponse {
+    /// Outcome of the query.
+    ///
+    /// A failure with [`ErrorCondition::InProgress`] means the transaction
+    /// has not finished yet.
+    #[serde(rename = "Response")]
+    pub response: Response,
+    /// The queried transaction.
+    #[serde(rename = "MessageReference", skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+    /// The response of the queried transaction.
+    #[serde(
+        rename = "RepeatedMessageResponse",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub repeated_message_response: Option<RepeatedMessageResponse>,
+}
+
+// ============================================================================
+// Abort
+// ============================================================================
+
+/// Request to abort a transaction in progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbortRequest {
+    /// The transaction to abort.
+    #[serde(rename = "MessageReference")]
+    pub message_reference: MessageReference,
+    /// Reason for aborting, e.g. `MerchantAbort`.
+    #[serde(rename = "AbortReason")]
+    pub abort_reason: Box<str>,
+}
+
+impl AbortRequest {
+    /// Abort the referenced transaction on behalf of the merchant.
+    #[must_use]
+    pub fn new(message_reference: MessageReference) -> Self {
+        Self {
+            message_reference,
+            abort_reason: "MerchantAbort".into(),
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Event sent by the terminal or Adyen.
+///
+/// Adyen also returns an event notification instead of a response when a
+/// request could not be processed, for example because the terminal is
+/// offline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventNotification {
+    /// Date and time of the event in ISO 8601 format.
+    #[serde(rename = "TimeStamp")]
+    pub time_stamp: Box<str>,
+    /// Kind of event, e.g. `Reject` or `Shutdown`.
+    #[serde(rename = "EventToNotify")]
+    pub event_to_notify: Box<str>,
+    /// Details about the event, often URL-encoded.
+    #[serde(rename = "EventDetails", skip_serializing_if = "Option::is_none")]
+    pub event_details: Option<Box<str>>,
+    /// The rejected message, base64-encoded.
+    #[serde(rename = "RejectedMessage", skip_serializing_if = "Option::is_none")]
+    pub rejected_message: Option<Box<str>>,
+}
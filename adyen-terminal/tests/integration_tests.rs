@@ -0,0 +1,453 @@
+//! Integration tests for the Adyen Terminal API.
+
+use adyen_core::{Amount, ConfigBuilder, Currency, Environment};
+use adyen_terminal::types::*;
+use adyen_terminal::TerminalApi;
+use rust_decimal::Decimal;
+
+const SALE_ID: &str = "POSSystemID12345";
+const POI_ID: &str = "V400m-324688179";
+
+fn create_test_config() -> adyen_core::Config {
+    ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn create_payment_request() -> TerminalApiRequest {
+    let payment = PaymentRequest::builder()
+        .transaction_id("order-001")
+        .time_stamp("2024-01-01T12:00:00Z")
+        .amount(&Amount::from_minor_units(1099, Currency::EUR))
+        .build()
+        .unwrap();
+
+    SaleToPOIRequest::new(
+        SALE_ID,
+        POI_ID,
+        "0207111104",
+        RequestBody::PaymentRequest(payment),
+    )
+    .into()
+}
+
+fn payment_response_json() -> serde_json::Value {
+    serde_json::json!({
+        "SaleToPOIResponse": {
+            "MessageHeader": {
+                "ProtocolVersion": "3.0",
+                "MessageClass": "Service",
+                "MessageCategory": "Payment",
+                "MessageType": "Response",
+                "ServiceID": "0207111104",
+                "SaleID": SALE_ID,
+                "POIID": POI_ID
+            },
+            "PaymentResponse": {
+                "Response": {
+                    "Result": "Success",
+                    "AdditionalResponse": "pspReference=8835511210681234&cardType=mc&expiryDate=03%2F2030"
+                },
+                "SaleData": {
+                    "SaleTransactionID": {
+                        "TransactionID": "order-001",
+                        "TimeStamp": "2024-01-01T12:00:00Z"
+                    }
+                },
+                "POIData": {
+                    "POITransactionID": {
+                        "TransactionID": "BV0q001575635389003.8835511210681234",
+                        "TimeStamp": "2024-01-01T12:00:05Z"
+                    },
+                    "POIReconciliationID": "1000"
+                },
+                "PaymentResult": {
+                    "AmountsResp": {"AuthorizedAmount": 10.99, "Currency": "EUR"},
+                    "OnlineFlag": true
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod request_building_tests {
+    use super::*;
+
+    #[test]
+    fn test_api_creation() {
+        let api = TerminalApi::new(create_test_config());
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_message_header_matches_body() {
+        let request = create_payment_request();
+        let header = &request.sale_to_poi_request.message_header;
+
+        assert_eq!(header.message_category, MessageCategory::Payment);
+        assert_eq!(header.message_class, MessageClass::Service);
+        assert_eq!(header.message_type, MessageType::Request);
+        assert_eq!(header.protocol_version.as_deref(), Some(PROTOCOL_VERSION));
+
+        let abort = SaleToPOIRequest::new(
+            SALE_ID,
+            POI_ID,
+            "0207111105",
+            RequestBody::AbortRequest(AbortRequest::new(
+                MessageReference::new(MessageCategory::Payment, "0207111104")
+                    .sale_id(SALE_ID)
+                    .poi_id(POI_ID),
+            )),
+        );
+        assert_eq!(
+            abort.message_header.message_category,
+            MessageCategory::Abort
+        );
+    }
+
+    #[test]
+    fn test_payment_request_builder_missing_fields() {
+        let result = PaymentRequest::builder()
+            .transaction_id("order-001")
+            .time_stamp("2024-01-01T12:00:00Z")
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "amount is required");
+
+        let result = PaymentRequest::builder()
+            .amount(&Amount::from_minor_units(100, Currency::EUR))
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "transaction_id is required");
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_request_serialization() {
+        let json = serde_json::to_value(create_payment_request()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "SaleToPOIRequest": {
+                    "MessageHeader": {
+                        "ProtocolVersion": "3.0",
+                        "MessageClass": "Service",
+                        "MessageCategory": "Payment",
+                        "MessageType": "Request",
+                        "ServiceID": "0207111104",
+                        "SaleID": SALE_ID,
+                        "POIID": POI_ID
+                    },
+                    "PaymentRequest": {
+                        "SaleData": {
+                            "SaleTransactionID": {
+                                "TransactionID": "order-001",
+                                "TimeStamp": "2024-01-01T12:00:00Z"
+                            }
+                        },
+                        "PaymentTransaction": {
+                            "AmountsReq": {"Currency": "EUR", "RequestedAmount": 10.99}
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_refund_and_reversal_serialization() {
+        let refund = PaymentRequest::builder()
+            .transaction_id("refund-001")
+            .time_stamp("2024-01-02T12:00:00Z")
+            .amount(&Amount::from_minor_units(500, Currency::USD))
+            .payment_type(PaymentType::Refund)
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&refund).unwrap();
+        assert_eq!(json["PaymentData"]["PaymentType"], "Refund");
+
+        let reversal = ReversalRequest::new(
+            TransactionIdentification::new(
+                "BV0q001575635389003.8835511210681234",
+                "2024-01-01T12:00:05Z",
+            ),
+            ReversalReason::MerchantCancel,
+        )
+        .reversed_amount(Decimal::new(250, 2));
+        let json = serde_json::to_value(&reversal).unwrap();
+        assert_eq!(json["ReversalReason"], "MerchantCancel");
+        assert_eq!(json["ReversedAmount"], 2.5);
+        assert_eq!(
+            json["OriginalPOITransaction"]["POITransactionID"]["TransactionID"],
+            "BV0q001575635389003.8835511210681234"
+        );
+    }
+
+    #[test]
+    fn test_payment_response_deserialization() {
+        let response: TerminalApiResponse =
+            serde_json::from_value(payment_response_json()).unwrap();
+
+        let payment = response.payment_response().unwrap();
+        assert!(payment.response.is_success());
+        assert_eq!(
+            payment
+                .response
+                .additional_data()
+                .get("expiryDate")
+                .unwrap(),
+            "03/2030"
+        );
+        assert_eq!(
+            payment
+                .payment_result
+                .as_ref()
+                .unwrap()
+                .amounts_resp
+                .as_ref()
+                .unwrap()
+                .authorized_amount,
+            Decimal::new(1099, 2)
+        );
+        assert!(response.event_notification().is_none());
+    }
+
+    #[test]
+    fn test_transaction_status_response_deserialization() {
+        let mut repeated = payment_response_json()["SaleToPOIResponse"].clone();
+        let body = repeated
+            .as_object_mut()
+            .unwrap()
+            .remove("PaymentResponse")
+            .unwrap();
+
+        let json = serde_json::json!({
+            "SaleToPOIResponse": {
+                "MessageHeader": {
+                    "MessageClass": "Service",
+                    "MessageCategory": "TransactionStatus",
+                    "MessageType": "Response",
+                    "ServiceID": "0207111106",
+                    "SaleID": SALE_ID,
+                    "POIID": POI_ID
+                },
+                "TransactionStatusResponse": {
+                    "Response": {"Result": "Success"},
+                    "RepeatedMessageResponse": {
+                        "MessageHeader": repeated["MessageHeader"],
+                        "RepeatedResponseMessageBody": {"PaymentResponse": body}
+                    }
+                }
+            }
+        });
+
+        let response: TerminalApiResponse = serde_json::from_value(json).unwrap();
+        let status = response.transaction_status_response().unwrap();
+        let original = status
+            .repeated_message_response
+            .as_ref()
+            .unwrap()
+            .repeated_response_message_body
+            .payment_response
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            original.poi_data.poi_reconciliation_id.as_deref(),
+            Some("1000")
+        );
+    }
+
+    #[test]
+    fn test_failure_and_unknown_error_condition() {
+        let response: Response =
+            serde_json::from_str(r#"{"Result": "Failure", "ErrorCondition": "WrongPIN"}"#).unwrap();
+        assert!(!response.is_success());
+        assert_eq!(response.error_condition, Some(ErrorCondition::WrongPin));
+
+        let response: Response =
+            serde_json::from_str(r#"{"Result": "Failure", "ErrorCondition": "SomethingNew"}"#)
+                .unwrap();
+        assert_eq!(response.error_condition, Some(ErrorCondition::Unknown));
+    }
+
+    #[test]
+    fn test_event_notification_deserialization() {
+        let json = r#"{
+            "SaleToPOIRequest": {
+                "MessageHeader": {
+                    "ProtocolVersion": "3.0",
+                    "MessageClass": "Event",
+                    "MessageCategory": "Event",
+                    "MessageType": "Notification",
+                    "DeviceID": "1517998561",
+                    "SaleID": "POSSystemID12345",
+                    "POIID": "V400m-324688179"
+                },
+                "EventNotification": {
+                    "EventToNotify": "Reject",
+                    "EventDetails": "message=Did+not+receive+a+response+from+the+POI.",
+                    "TimeStamp": "2024-01-01T12:02:30Z"
+                }
+            }
+        }"#;
+
+        let response: TerminalApiResponse = serde_json::from_str(json).unwrap();
+        assert!(response.payment_response().is_none());
+        assert_eq!(
+            response
+                .event_notification()
+                .unwrap()
+                .event_to_notify
+                .as_ref(),
+            "Reject"
+        );
+    }
+}
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+
+    fn mock_api(mock: &MockTransport) -> TerminalApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        TerminalApi::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sync_payment() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/sync")
+                .body_includes(serde_json::json!({
+                    "SaleToPOIRequest": {"MessageHeader": {"POIID": POI_ID}}
+                }))
+                .respond_with(MockResponse::json(200, payment_response_json())),
+        );
+
+        let api = mock_api(&mock);
+        let response = api.sync_request(&create_payment_request()).await.unwrap();
+
+        assert!(response.payment_response().unwrap().response.is_success());
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_async_request_and_abort() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/async")
+                .respond_with(MockResponse::raw(200, "ok"))
+                .times(1),
+        );
+        mock.expect(Expectation::post("/sync").respond_with(MockResponse::raw(200, "")));
+
+        let api = mock_api(&mock);
+        api.async_request(&create_payment_request()).await.unwrap();
+
+        let abort: TerminalApiRequest = SaleToPOIRequest::new(
+            SALE_ID,
+            POI_ID,
+            "0207111105",
+            RequestBody::AbortRequest(AbortRequest::new(MessageReference::new(
+                MessageCategory::Payment,
+                "0207111104",
+            ))),
+        )
+        .into();
+        let response = api.sync_request(&abort).await.unwrap();
+        assert_eq!(response, TerminalApiResponse::default());
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_async_request_rejected() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/async")
+                .respond_with(MockResponse::raw(200, r#"{"SaleToPOIRequest": {}}"#)),
+        );
+
+        let api = mock_api(&mock);
+        let result = api.async_request(&create_payment_request()).await;
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(feature = "local")]
+mod local_terminal_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_terminal::local::{
+        EncryptionCredentials, LocalTerminalApi, NexoCrypto, SecuredTerminalApiResponse,
+    };
+
+    fn credentials() -> EncryptionCredentials {
+        EncryptionCredentials::new("mykey", "mypassphrase", 1)
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_payment_round_trip() {
+        // Play the terminal: encrypt the canned response with the shared key.
+        let terminal = NexoCrypto::new(credentials());
+        let response = payment_response_json();
+        let header: MessageHeader =
+            serde_json::from_value(response["SaleToPOIResponse"]["MessageHeader"].clone()).unwrap();
+        let secured = SecuredTerminalApiResponse {
+            sale_to_poi_response: Some(terminal.encrypt(
+                header,
+                response.to_string().as_bytes(),
+                [9; 16],
+            )),
+            sale_to_poi_request: None,
+        };
+
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/nexo")
+                .body_includes(serde_json::json!({
+                    "SaleToPOIRequest": {"SecurityTrailer": {"KeyIdentifier": "mykey", "KeyVersion": 1}}
+                }))
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::to_value(&secured).unwrap(),
+                )),
+        );
+
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        let api =
+            LocalTerminalApi::new(config, "https://192.168.1.20:8443/nexo", credentials()).unwrap();
+
+        let result = api.request(&create_payment_request()).await.unwrap();
+        assert!(result.payment_response().unwrap().response.is_success());
+
+        // The request body must not leak the payment in the clear.
+        let sent = mock.received_requests()[0].body.clone().unwrap();
+        assert!(sent["SaleToPOIRequest"].get("PaymentRequest").is_none());
+        let decrypted = terminal
+            .decrypt(&serde_json::from_value(sent["SaleToPOIRequest"].clone()).unwrap())
+            .unwrap();
+        let request: TerminalApiRequest = serde_json::from_slice(&decrypted).unwrap();
+        assert_eq!(request, create_payment_request());
+        mock.verify();
+    }
+}
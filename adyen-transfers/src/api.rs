@@ -0,0 +1,250 @@
+//! Transfers API client implementation.
+
+use crate::types::*;
+use adyen_core::{AdyenError, Api, Client, Config, Result};
+use futures::stream::{self, Stream, TryStreamExt};
+
+/// Adyen Transfers API client.
+///
+/// Provides access to Adyen's Transfers API v4 for moving funds on a balance
+/// platform: internal bookings between balance accounts, payouts to transfer
+/// instruments and bank accounts, and the transactions they result in.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_core::{ConfigBuilder, Environment};
+/// use adyen_transfers::TransfersApi;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let transfers_api = TransfersApi::new(config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransfersApi {
+    client: Client,
+}
+
+impl TransfersApi {
+    /// Create a new Transfers API client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        let client = Client::for_api(config, Api::Transfers)?;
+        Ok(Self { client })
+    }
+
+    /// Create a new Transfers API client from an existing client.
+    ///
+    /// Pass a clone of one [`Client`] to several API structs to share its
+    /// connection pool.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client: client.with_api(Api::Transfers),
+        }
+    }
+
+    fn base_url(&self) -> String {
+        format!(
+            "{}/btl/v4",
+            self.client.config().environment().transfers_api_url()
+        )
+    }
+
+    // ============================================================================
+    // Transfers
+    // ============================================================================
+
+    /// Transfer funds.
+    ///
+    /// Starts a transfer from a balance account to another balance account, a
+    /// transfer instrument or a bank account. The returned transfer is usually
+    /// not final yet; use [`get_transfer`](Self::get_transfer) or webhooks to
+    /// track its status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_transfers::{Amount, Counterparty, TransferCategory, TransferRequest, TransfersApi};
+    ///
+    /// # async fn example(api: TransfersApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let request = TransferRequest::builder()
+    ///     .amount(Amount::new(10_000, "EUR"))
+    ///     .balance_account_id("BA00000000000000000000001")
+    ///     .category(TransferCategory::Bank)
+    ///     .counterparty(Counterparty::transfer_instrument("SE00000000000000000000001"))
+    ///     .reference("payout_001")
+    ///     .build()
+    ///     .map_err(|e| format!("Builder error: {}", e))?;
+    ///
+    /// let transfer = api.transfer_funds(&request).await?;
+    /// println!("Transfer {:?} is {:?}", transfer.id, transfer.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer_funds(&self, request: &TransferRequest) -> Result<Transfer> {
+        let url = format!("{}/transfers", self.base_url());
+        let response = self
+            .client
+            .post_idempotent(&url, request, request.idempotency_key.as_ref())
+            .await?;
+        Ok(response.data)
+    }
+
+    /// Get a transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_transfer(&self, transfer_id: &str) -> Result<Transfer> {
+        let url = format!("{}/transfers/{}", self.base_url(), transfer_id);
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// List transfers matching the given filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_transfers(&self, params: &SearchParams) -> Result<TransferSearchResponse> {
+        let url = format!("{}/transfers?{}", self.base_url(), params.to_query_string());
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// Return an incoming transfer to its sender.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn return_transfer(
+        &self,
+        transfer_id: &str,
+        request: &ReturnTransferRequest,
+    ) -> Result<ReturnTransferResponse> {
+        let url = format!("{}/transfers/{}/returns", self.base_url(), transfer_id);
+        let response = self.client.post(&url, request).await?;
+        Ok(response.data)
+    }
+
+    // ============================================================================
+    // Transactions
+    // ============================================================================
+
+    /// Get a transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_transaction(&self, transaction_id: &str) -> Result<Transaction> {
+        let url = format!("{}/transactions/{}", self.base_url(), transaction_id);
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// List transactions matching the given filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn list_transactions(
+        &self,
+        params: &SearchParams,
+    ) -> Result<TransactionSearchResponse> {
+        let url = format!(
+            "{}/transactions?{}",
+            self.base_url(),
+            params.to_query_string()
+        );
+        let response = self.client.get(&url).await?;
+        Ok(response.data)
+    }
+
+    /// List all transactions matching the given filters as a stream.
+    ///
+    /// Follows the `next` links of the result pages, fetching each page when
+    /// the transactions of the previous one are consumed. The stream ends
+    /// after the last page or the first error. Use
+    /// [`SearchParams::limit`] to set the page size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_transfers::{SearchParams, TransfersApi};
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn example(api: TransfersApi) -> Result<(), Box<dyn std::error::Error>> {
+    /// let params = SearchParams::new()
+    ///     .balance_account_id("BA00000000000000000000001")
+    ///     .created_since("2024-01-01T00:00:00Z")
+    ///     .created_until("2024-02-01T00:00:00Z")
+    ///     .limit(100);
+    ///
+    /// let transactions = api.list_transactions_stream(&params);
+    /// futures::pin_mut!(transactions);
+    /// let mut total = 0;
+    /// while let Some(transaction) = transactions.try_next().await? {
+    ///     total += transaction.amount.value;
+    /// }
+    /// println!("Net movement: {total}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_transactions_stream(
+        &self,
+        params: &SearchParams,
+    ) -> impl Stream<Item = Result<Transaction>> {
+        let client = self.client.clone();
+        let url = format!(
+            "{}/transactions?{}",
+            self.base_url(),
+            params.to_query_string()
+        );
+        stream::try_unfold(Some(url), move |url| {
+            let client = client.clone();
+            async move {
+                let Some(url) = url else {
+                    return Ok(None);
+                };
+                let page: TransactionSearchResponse = client.get(&url).await?.data;
+                // Stop on an empty page even if a next link is returned, so
+                // a misbehaving cursor cannot loop forever.
+                let next = match page.links {
+                    Some(PageLinks {
+                        next: Some(next), ..
+                    }) if !page.data.is_empty() => Some(next.href.into_string()),
+                    _ => None,
+                };
+                let transactions = page.data.into_iter().map(Ok::<_, AdyenError>);
+                Ok::<_, AdyenError>(Some((stream::iter(transactions), next)))
+            }
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(feature = "blocking")]
+adyen_core::blocking_api! {
+    impl TransfersApi {
+        fn transfer_funds_blocking => transfer_funds(request: &TransferRequest) -> Result<Transfer>;
+        fn get_transfer_blocking => get_transfer(transfer_id: &str) -> Result<Transfer>;
+        fn list_transfers_blocking => list_transfers(params: &SearchParams) -> Result<TransferSearchResponse>;
+        fn return_transfer_blocking => return_transfer(transfer_id: &str, request: &ReturnTransferRequest) -> Result<ReturnTransferResponse>;
+        fn get_transaction_blocking => get_transaction(transaction_id: &str) -> Result<Transaction>;
+        fn list_transactions_blocking => list_transactions(params: &SearchParams) -> Result<TransactionSearchResponse>;
+    }
+}
@@ -1 +1,51 @@
-//! Placeholder for adyen-transfers crate
+//! Adyen Transfers API v4 for advanced fund movement.
+//!
+//! This crate provides access to Adyen's Transfers API v4, enabling balance
+//! platforms to move funds between balance accounts, pay out to transfer
+//! instruments and bank accounts, and inspect the resulting transactions.
+//!
+//! # Features
+//!
+//! - **Fund Transfers**: Internal bookings and payouts to transfer instruments or bank accounts
+//! - **Status Tracking**: Typed transfer statuses with helpers for final and successful states
+//! - **Returns**: Return incoming transfers to their sender
+//! - **Transactions**: Look up and list bookings on balance accounts, or stream all pages of them
+//! - **Idempotency**: Safe retries of transfers with an `Idempotency-Key`
+//! - **Builder Patterns**: Ergonomic request builders with validation
+//!
+//! # Quick Start
+//!
+//! ```rust
+//! use adyen_core::{ConfigBuilder, Environment};
+//! use adyen_transfers::{Amount, Counterparty, TransferCategory, TransferRequest, TransfersApi};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let api = TransfersApi::new(config)?;
+//!
+//! let request = TransferRequest::builder()
+//!     .amount(Amount::new(2_500, "EUR"))
+//!     .balance_account_id("BA00000000000000000000001")
+//!     .category(TransferCategory::Internal)
+//!     .counterparty(Counterparty::balance_account("BA00000000000000000000002"))
+//!     .reference("booking_001")
+//!     .build()
+//!     .map_err(|e| format!("Builder error: {}", e))?;
+//!
+//! let transfer = api.transfer_funds(&request).await?;
+//! if transfer.is_final() {
+//!     println!("Transfer settled with status {:?}", transfer.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+pub mod types;
+
+pub use api::TransfersApi;
+pub use types::*;
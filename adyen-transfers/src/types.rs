@@ -0,0 +1,661 @@
+//! Types for the Adyen Transfers API v4.
+
+use adyen_core::RequestId;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Common Types
+// ============================================================================
+
+/// Amount in minor units with its currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amount {
+    /// Three-character ISO currency code.
+    pub currency: Box<str>,
+    /// Amount value in minor units.
+    pub value: i64,
+}
+
+impl Amount {
+    /// Create a new amount from minor units and a currency code.
+    #[must_use]
+    pub fn new(value: i64, currency: &str) -> Self {
+        Self {
+            currency: currency.into(),
+            value,
+        }
+    }
+}
+
+/// Reference to a resource by its identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceReference {
+    /// Unique identifier of the resource.
+    pub id: Box<str>,
+    /// Description of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+    /// Your reference for the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+}
+
+/// Link to another page of results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    /// URL of the page.
+    pub href: Box<str>,
+}
+
+/// Links to the previous and next pages of results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageLinks {
+    /// Link to the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Link>,
+    /// Link to the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<Link>,
+}
+
+// ============================================================================
+// Transfers
+// ============================================================================
+
+/// Category of a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferCategory {
+    /// Payout to a bank account or transfer instrument.
+    Bank,
+    /// Transfer to a card.
+    Card,
+    /// Internal booking between balance accounts.
+    Internal,
+    /// Transfer initiated by an issued card.
+    IssuedCard,
+    /// Transfer related to a payment processed on the platform.
+    PlatformPayment,
+    /// Transfer to a top-up account.
+    TopUp,
+    /// A transfer category not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Priority of a bank transfer, determining speed and cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferPriority {
+    /// Cross-border transfer.
+    CrossBorder,
+    /// Fast transfer, typically same-day.
+    Fast,
+    /// Instant transfer.
+    Instant,
+    /// Internal transfer.
+    Internal,
+    /// Regular transfer at the lowest cost.
+    Regular,
+    /// Wire transfer for high-value payments.
+    Wire,
+    /// A transfer priority not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Direction of a transfer relative to the balance account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferDirection {
+    /// Funds coming into the balance account.
+    Incoming,
+    /// Funds leaving the balance account.
+    Outgoing,
+    /// A transfer direction not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferStatus {
+    /// The transfer request was received.
+    Received,
+    /// The transfer was authorised.
+    Authorised,
+    /// The transfer is pending approval.
+    PendingApproval,
+    /// The transfer was approved.
+    Approved,
+    /// The transfer is being processed by the bank.
+    BankPending,
+    /// Funds are booked on the balance account.
+    Booked,
+    /// The transfer was credited to the counterparty.
+    Credited,
+    /// The transfer was refused.
+    Refused,
+    /// The transfer failed.
+    Failed,
+    /// The transfer was cancelled.
+    Cancelled,
+    /// The transfer was returned by the counterparty.
+    Returned,
+    /// The transfer expired.
+    Expired,
+    /// The transfer was reversed.
+    Reversed,
+    /// A status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TransferStatus {
+    /// Check if the transfer reached a final state and will not change anymore.
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Credited
+                | Self::Refused
+                | Self::Failed
+                | Self::Cancelled
+                | Self::Returned
+                | Self::Expired
+                | Self::Reversed
+        )
+    }
+
+    /// Check if the transfer completed successfully.
+    #[must_use]
+    pub const fn is_successful(&self) -> bool {
+        matches!(self, Self::Booked | Self::Credited)
+    }
+
+    /// Check if the transfer did not go through.
+    #[must_use]
+    pub const fn is_failed(&self) -> bool {
+        matches!(
+            self,
+            Self::Refused | Self::Failed | Self::Cancelled | Self::Returned | Self::Expired
+        )
+    }
+}
+
+/// Bank account details of a counterparty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CounterpartyBankAccount {
+    /// The account holder of the bank account.
+    pub account_holder: PartyIdentification,
+    /// Account identification, e.g. `{"type": "iban", "iban": "..."}`.
+    pub account_identification: serde_json::Value,
+}
+
+/// Identification of a party in a transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyIdentification {
+    /// Full name of the individual or organization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_name: Option<Box<str>>,
+    /// First name of the individual.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<Box<str>>,
+    /// Last name of the individual.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<Box<str>>,
+    /// Type of party, `individual` or `organization`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub party_type: Option<Box<str>>,
+}
+
+/// The other party in a transfer.
+///
+/// Provide exactly one of the fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Counterparty {
+    /// Balance account to book funds to, for internal transfers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_account_id: Option<Box<str>>,
+    /// Transfer instrument to pay out to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_instrument_id: Option<Box<str>>,
+    /// Bank account to pay out to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account: Option<CounterpartyBankAccount>,
+}
+
+impl Counterparty {
+    /// Counterparty for an internal transfer to another balance account.
+    #[must_use]
+    pub fn balance_account(balance_account_id: &str) -> Self {
+        Self {
+            balance_account_id: Some(balance_account_id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Counterparty for a payout to a transfer instrument.
+    #[must_use]
+    pub fn transfer_instrument(transfer_instrument_id: &str) -> Self {
+        Self {
+            transfer_instrument_id: Some(transfer_instrument_id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Request to transfer funds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRequest {
+    /// The amount to transfer.
+    pub amount: Amount,
+    /// The balance account the funds are taken from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_account_id: Option<Box<str>>,
+    /// The type of transfer.
+    pub category: TransferCategory,
+    /// The receiver of the funds.
+    pub counterparty: Counterparty,
+    /// Description of the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+    /// The payment instrument used, for transfers from issued cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_instrument_id: Option<Box<str>>,
+    /// Priority of the bank transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<TransferPriority>,
+    /// Your reference for the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+    /// Reference sent to the counterparty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_for_beneficiary: Option<Box<str>>,
+    /// Key sent as the `Idempotency-Key` header; not part of the request body.
+    #[serde(skip)]
+    pub idempotency_key: Option<RequestId>,
+}
+
+impl TransferRequest {
+    /// Create a new builder for `TransferRequest`.
+    #[must_use]
+    pub fn builder() -> TransferRequestBuilder {
+        TransferRequestBuilder::default()
+    }
+}
+
+/// Builder for `TransferRequest`.
+#[derive(Debug, Default)]
+pub struct TransferRequestBuilder {
+    amount: Option<Amount>,
+    balance_account_id: Option<Box<str>>,
+    category: Option<TransferCategory>,
+    counterparty: Option<Counterparty>,
+    description: Option<Box<str>>,
+    payment_instrument_id: Option<Box<str>>,
+    priority: Option<TransferPriority>,
+    reference: Option<Box<str>>,
+    reference_for_beneficiary: Option<Box<str>>,
+    idempotency_key: Option<RequestId>,
+}
+
+impl TransferRequestBuilder {
+    /// Set the amount.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set the source balance account.
+    #[must_use]
+    pub fn balance_account_id(mut self, balance_account_id: &str) -> Self {
+        self.balance_account_id = Some(balance_account_id.into());
+        self
+    }
+
+    /// Set the transfer category.
+    #[must_use]
+    pub fn category(mut self, category: TransferCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Set the counterparty.
+    #[must_use]
+    pub fn counterparty(mut self, counterparty: Counterparty) -> Self {
+        self.counterparty = Some(counterparty);
+        self
+    }
+
+    /// Set the description.
+    #[must_use]
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the payment instrument.
+    #[must_use]
+    pub fn payment_instrument_id(mut self, payment_instrument_id: &str) -> Self {
+        self.payment_instrument_id = Some(payment_instrument_id.into());
+        self
+    }
+
+    /// Set the priority.
+    #[must_use]
+    pub fn priority(mut self, priority: TransferPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Set the reference.
+    #[must_use]
+    pub fn reference(mut self, reference: &str) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the reference sent to the counterparty.
+    #[must_use]
+    pub fn reference_for_beneficiary(mut self, reference: &str) -> Self {
+        self.reference_for_beneficiary = Some(reference.into());
+        self
+    }
+
+    /// Set the idempotency key used to safely retry this transfer.
+    #[must_use]
+    pub fn idempotency_key(mut self, key: RequestId) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Build the `TransferRequest`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if required fields are missing.
+    pub fn build(self) -> Result<TransferRequest, Box<str>> {
+        Ok(TransferRequest {
+            amount: self.amount.ok_or("amount is required")?,
+            balance_account_id: self.balance_account_id,
+            category: self.category.ok_or("category is required")?,
+            counterparty: self.counterparty.ok_or("counterparty is required")?,
+            description: self.description,
+            payment_instrument_id: self.payment_instrument_id,
+            priority: self.priority,
+            reference: self.reference,
+            reference_for_beneficiary: self.reference_for_beneficiary,
+            idempotency_key: self.idempotency_key,
+        })
+    }
+}
+
+/// A transfer of funds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    /// Unique identifier of the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<str>>,
+    /// The account holder of the balance account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder: Option<ResourceReference>,
+    /// The transferred amount.
+    pub amount: Amount,
+    /// The balance account of the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_account: Option<ResourceReference>,
+    /// The type of transfer.
+    pub category: TransferCategory,
+    /// The other party in the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<Counterparty>,
+    /// Creation date and time in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<Box<str>>,
+    /// Description of the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<str>>,
+    /// Direction of the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<TransferDirection>,
+    /// Reason for the current status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Box<str>>,
+    /// Your reference for the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+    /// Reference sent to the counterparty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_for_beneficiary: Option<Box<str>>,
+    /// Current status of the transfer.
+    pub status: TransferStatus,
+}
+
+impl Transfer {
+    /// Check if the transfer reached a final state.
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
+        self.status.is_final()
+    }
+
+    /// Check if the transfer completed successfully.
+    #[must_use]
+    pub const fn is_successful(&self) -> bool {
+        self.status.is_successful()
+    }
+}
+
+/// Request to return a transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnTransferRequest {
+    /// The amount to return.
+    pub amount: Amount,
+    /// Your reference for the return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+}
+
+/// Response to a transfer return request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnTransferResponse {
+    /// Unique identifier of the return.
+    pub id: Box<str>,
+    /// Your reference for the return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<Box<str>>,
+    /// Status of the return, `Authorised` or `Declined`.
+    pub status: Box<str>,
+    /// Identifier of the returned transfer.
+    pub transfer_id: Box<str>,
+}
+
+/// Request to approve or cancel transfers pending approval.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferIdsRequest {
+    /// Identifiers of the transfers.
+    pub transfer_ids: Vec<Box<str>>,
+}
+
+/// A page of transfers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferSearchResponse {
+    /// Transfers on this page.
+    #[serde(default)]
+    pub data: Vec<Transfer>,
+    /// Links to adjacent pages.
+    #[serde(rename = "_links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
+
+// ============================================================================
+// Transactions
+// ============================================================================
+
+/// Status of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionStatus {
+    /// Funds are booked on the balance account.
+    Booked,
+    /// Funds are reserved but not yet booked.
+    Pending,
+    /// The transaction was reversed.
+    Reversed,
+    /// A transaction status not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A booking on a balance account resulting from a transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    /// Unique identifier of the transaction.
+    pub id: Box<str>,
+    /// The account holder of the balance account.
+    pub account_holder: ResourceReference,
+    /// The booked amount.
+    pub amount: Amount,
+    /// The balance account of the transaction.
+    pub balance_account: ResourceReference,
+    /// Unique identifier of the balance platform.
+    pub balance_platform: Box<str>,
+    /// Booking date and time in ISO 8601 format.
+    pub booking_date: Box<str>,
+    /// Creation date and time in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<Box<str>>,
+    /// Status of the transaction.
+    pub status: TransactionStatus,
+    /// The transfer the transaction belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<ResourceReference>,
+    /// Date and time the funds become available, in ISO 8601 format.
+    pub value_date: Box<str>,
+}
+
+/// A page of transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSearchResponse {
+    /// Transactions on this page.
+    #[serde(default)]
+    pub data: Vec<Transaction>,
+    /// Links to adjacent pages.
+    #[serde(rename = "_links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
+
+/// Filters for listing transfers and transactions.
+///
+/// One of `balance_platform`, `account_holder_id` or `balance_account_id` is
+/// required, as well as `created_since` and `created_until`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchParams {
+    /// Unique identifier of the balance platform.
+    pub balance_platform: Option<Box<str>>,
+    /// Unique identifier of the account holder.
+    pub account_holder_id: Option<Box<str>>,
+    /// Unique identifier of the balance account.
+    pub balance_account_id: Option<Box<str>>,
+    /// Only include results created at or after this ISO 8601 date-time.
+    pub created_since: Option<Box<str>>,
+    /// Only include results created before this ISO 8601 date-time.
+    pub created_until: Option<Box<str>>,
+    /// Cursor of the page to fetch.
+    pub cursor: Option<Box<str>>,
+    /// Maximum number of results per page.
+    pub limit: Option<u32>,
+}
+
+impl SearchParams {
+    /// Create empty search parameters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by balance platform.
+    #[must_use]
+    pub fn balance_platform(mut self, balance_platform: &str) -> Self {
+        self.balance_platform = Some(balance_platform.into());
+        self
+    }
+
+    /// Filter by account holder.
+    #[must_use]
+    pub fn account_holder_id(mut self, account_holder_id: &str) -> Self {
+        self.account_holder_id = Some(account_holder_id.into());
+        self
+    }
+
+    /// Filter by balance account.
+    #[must_use]
+    pub fn balance_account_id(mut self, balance_account_id: &str) -> Self {
+        self.balance_account_id = Some(balance_account_id.into());
+        self
+    }
+
+    /// Set the start of the creation date range.
+    #[must_use]
+    pub fn created_since(mut self, created_since: &str) -> Self {
+        self.created_since = Some(created_since.into());
+        self
+    }
+
+    /// Set the end of the creation date range.
+    #[must_use]
+    pub fn created_until(mut self, created_until: &str) -> Self {
+        self.created_until = Some(created_until.into());
+        self
+    }
+
+    /// Set the page cursor.
+    #[must_use]
+    pub fn cursor(mut self, cursor: &str) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Set the page size.
+    #[must_use]
+    pub const fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Encode the parameters as a URL query string, without the leading `?`.
+    #[must_use]
+    pub fn to_query_string(&self) -> String {
+        let limit = self.limit.map(|limit| limit.to_string());
+        [
+            ("balancePlatform", self.balance_platform.as_deref()),
+            ("accountHolderId", self.account_holder_id.as_deref()),
+            ("balanceAccountId", self.balance_account_id.as_deref()),
+            ("createdSince", self.created_since.as_deref()),
+            ("createdUntil", self.created_until.as_deref()),
+            ("cursor", self.cursor.as_deref()),
+            ("limit", limit.as_deref()),
+        ]
+        .iter()
+        .filter_map(|(name, value)| {
+            value.map(|value| format!("{name}={}", urlencoding::encode(value)))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+    }
+}
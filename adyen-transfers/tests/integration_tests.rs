@@ -0,0 +1,318 @@
+//! Integration tests for the Adyen Transfers API v4.
+
+use adyen_core::{ConfigBuilder, Environment};
+use adyen_transfers::types::*;
+use adyen_transfers::TransfersApi;
+
+fn create_test_config() -> adyen_core::Config {
+    ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+fn create_payout_request() -> TransferRequest {
+    TransferRequest::builder()
+        .amount(Amount::new(10_000, "EUR"))
+        .balance_account_id("BA00000000000000000000001")
+        .category(TransferCategory::Bank)
+        .counterparty(Counterparty::transfer_instrument(
+            "SE00000000000000000000001",
+        ))
+        .priority(TransferPriority::Regular)
+        .reference("payout_001")
+        .build()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod request_building_tests {
+    use super::*;
+
+    #[test]
+    fn test_api_creation() {
+        let api = TransfersApi::new(create_test_config());
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_transfer_request_builder() {
+        let request = create_payout_request();
+
+        assert_eq!(request.amount.value, 10_000);
+        assert_eq!(request.category, TransferCategory::Bank);
+        assert_eq!(
+            request.counterparty.transfer_instrument_id.as_deref(),
+            Some("SE00000000000000000000001")
+        );
+        assert!(request.idempotency_key.is_none());
+    }
+
+    #[test]
+    fn test_transfer_request_builder_missing_fields() {
+        let result = TransferRequest::builder()
+            .amount(Amount::new(100, "EUR"))
+            .category(TransferCategory::Internal)
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "counterparty is required");
+
+        let result = TransferRequest::builder()
+            .counterparty(Counterparty::balance_account("BA1"))
+            .category(TransferCategory::Internal)
+            .build();
+        assert_eq!(result.unwrap_err().as_ref(), "amount is required");
+    }
+
+    #[test]
+    fn test_search_params_query_string() {
+        let params = SearchParams::new()
+            .balance_account_id("BA00000000000000000000001")
+            .created_since("2024-01-01T00:00:00+01:00")
+            .created_until("2024-02-01T00:00:00Z")
+            .limit(50);
+
+        assert_eq!(
+            params.to_query_string(),
+            "balanceAccountId=BA00000000000000000000001\
+             &createdSince=2024-01-01T00%3A00%3A00%2B01%3A00\
+             &createdUntil=2024-02-01T00%3A00%3A00Z&limit=50"
+        );
+        assert_eq!(SearchParams::new().to_query_string(), "");
+    }
+}
+
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_request_serialization() {
+        let mut request = create_payout_request();
+        request.idempotency_key = Some(adyen_core::RequestId::new());
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "amount": {"currency": "EUR", "value": 10000},
+                "balanceAccountId": "BA00000000000000000000001",
+                "category": "bank",
+                "counterparty": {"transferInstrumentId": "SE00000000000000000000001"},
+                "priority": "regular",
+                "reference": "payout_001"
+            })
+        );
+    }
+
+    #[test]
+    fn test_transfer_deserialization() {
+        let json = r#"{
+            "id": "1W1UG35U8A9J5ZLG",
+            "accountHolder": {"id": "AH00000000000000000000001"},
+            "amount": {"currency": "EUR", "value": 2500},
+            "balanceAccount": {"id": "BA00000000000000000000001"},
+            "category": "internal",
+            "counterparty": {"balanceAccountId": "BA00000000000000000000002"},
+            "direction": "outgoing",
+            "reference": "booking_001",
+            "status": "authorised"
+        }"#;
+
+        let transfer: Transfer = serde_json::from_str(json).unwrap();
+        assert_eq!(transfer.id.as_deref(), Some("1W1UG35U8A9J5ZLG"));
+        assert_eq!(transfer.category, TransferCategory::Internal);
+        assert_eq!(transfer.direction, Some(TransferDirection::Outgoing));
+        assert_eq!(transfer.status, TransferStatus::Authorised);
+        assert!(!transfer.is_final());
+    }
+
+    #[test]
+    fn test_unknown_transfer_status() {
+        let status: TransferStatus = serde_json::from_str(r#""atmWithdrawal""#).unwrap();
+        assert_eq!(status, TransferStatus::Unknown);
+        assert!(!status.is_final());
+    }
+
+    #[test]
+    fn test_transfer_status_tracking() {
+        assert!(TransferStatus::Credited.is_final());
+        assert!(TransferStatus::Credited.is_successful());
+        assert!(TransferStatus::Booked.is_successful());
+        assert!(!TransferStatus::Booked.is_final());
+        assert!(TransferStatus::Returned.is_final());
+        assert!(TransferStatus::Returned.is_failed());
+        assert!(!TransferStatus::Received.is_failed());
+    }
+
+    #[test]
+    fn test_transaction_search_response_deserialization() {
+        let json = r#"{
+            "data": [{
+                "id": "IZK7C25U7DYVX03Y",
+                "accountHolder": {"id": "AH00000000000000000000001", "description": "Main"},
+                "amount": {"currency": "EUR", "value": -9000},
+                "balanceAccount": {"id": "BA00000000000000000000001"},
+                "balancePlatform": "YOUR_BALANCE_PLATFORM",
+                "bookingDate": "2024-01-02T10:00:00+01:00",
+                "status": "booked",
+                "transfer": {"id": "1W1UG35U8A9J5ZLG", "reference": "payout_001"},
+                "valueDate": "2024-01-02T10:00:00+01:00"
+            }],
+            "_links": {"next": {"href": "https://balanceplatform-api-test.adyen.com/btl/v4/transactions?cursor=abc"}}
+        }"#;
+
+        let response: TransactionSearchResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].status, TransactionStatus::Booked);
+        assert_eq!(response.data[0].amount.value, -9000);
+        assert!(response.links.unwrap().next.is_some());
+    }
+}
+
+#[cfg(test)]
+mod mock_transport_tests {
+    use super::*;
+    use adyen_core::testing::{Expectation, MockResponse, MockTransport};
+    use adyen_core::RequestId;
+    use futures::TryStreamExt;
+
+    fn mock_api(mock: &MockTransport) -> TransfersApi {
+        let config = ConfigBuilder::new()
+            .environment(Environment::test())
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        TransfersApi::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_transfer_funds_and_track_status() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::post("/btl/v4/transfers")
+                .header("Idempotency-Key", "payout-001")
+                .body_includes(serde_json::json!({"category": "bank"}))
+                .respond_with(MockResponse::json(
+                    202,
+                    serde_json::json!({
+                        "id": "1W1UG35U8A9J5ZLG",
+                        "amount": {"currency": "EUR", "value": 10000},
+                        "category": "bank",
+                        "status": "authorised"
+                    }),
+                )),
+        );
+        mock.expect(
+            Expectation::get("/btl/v4/transfers/1W1UG35U8A9J5ZLG").respond_with(
+                MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "id": "1W1UG35U8A9J5ZLG",
+                        "amount": {"currency": "EUR", "value": 10000},
+                        "category": "bank",
+                        "status": "credited"
+                    }),
+                ),
+            ),
+        );
+
+        let api = mock_api(&mock);
+        let mut request = create_payout_request();
+        request.idempotency_key = Some(RequestId::from_string("payout-001").unwrap());
+
+        let transfer = api.transfer_funds(&request).await.unwrap();
+        assert_eq!(transfer.status, TransferStatus::Authorised);
+
+        let transfer = api
+            .get_transfer(transfer.id.as_deref().unwrap())
+            .await
+            .unwrap();
+        assert!(transfer.is_successful());
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions_sends_query() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/btl/v4/transactions")
+                .respond_with(MockResponse::json(200, serde_json::json!({"data": []}))),
+        );
+
+        let api = mock_api(&mock);
+        let params = SearchParams::new()
+            .balance_platform("YOUR_BALANCE_PLATFORM")
+            .created_since("2024-01-01T00:00:00Z")
+            .created_until("2024-01-31T00:00:00Z");
+        let response = api.list_transactions(&params).await.unwrap();
+
+        assert!(response.data.is_empty());
+        assert!(mock.received_requests()[0]
+            .url
+            .ends_with("/btl/v4/transactions?balancePlatform=YOUR_BALANCE_PLATFORM&createdSince=2024-01-01T00%3A00%3A00Z&createdUntil=2024-01-31T00%3A00%3A00Z"));
+        mock.verify();
+    }
+
+    #[tokio::test]
+    async fn test_list_transactions_stream_follows_next_links() {
+        fn transaction(id: &str, value: i64) -> serde_json::Value {
+            serde_json::json!({
+                "id": id,
+                "accountHolder": {"id": "AH00000000000000000000001"},
+                "amount": {"currency": "EUR", "value": value},
+                "balanceAccount": {"id": "BA00000000000000000000001"},
+                "balancePlatform": "YOUR_BALANCE_PLATFORM",
+                "bookingDate": "2024-01-02T10:00:00+01:00",
+                "status": "booked",
+                "valueDate": "2024-01-02T10:00:00+01:00"
+            })
+        }
+
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/btl/v4/transactions")
+                .times(1)
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [transaction("T1", 1000), transaction("T2", -250)],
+                        "_links": {"next": {"href": "https://balanceplatform-api-test.adyen.com/btl/v4/transactions?cursor=page2"}}
+                    }),
+                )),
+        );
+        mock.expect(
+            Expectation::get("/btl/v4/transactions")
+                .times(1)
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "data": [transaction("T3", 500)],
+                        "_links": {"prev": {"href": "https://balanceplatform-api-test.adyen.com/btl/v4/transactions?cursor=page1"}}
+                    }),
+                )),
+        );
+
+        let api = mock_api(&mock);
+        let params = SearchParams::new()
+            .balance_account_id("BA00000000000000000000001")
+            .limit(2);
+        let transactions: Vec<Transaction> = api
+            .list_transactions_stream(&params)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = transactions.iter().map(|t| t.id.as_ref()).collect();
+        assert_eq!(ids, ["T1", "T2", "T3"]);
+        let requests = mock.received_requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1]
+            .url
+            .ends_with("/btl/v4/transactions?cursor=page2"));
+        mock.verify();
+    }
+}
@@ -0,0 +1,94 @@
+//! Compares parsing and validating webhooks into owned and borrowed types,
+//! and with simd-json when the `simd-json` feature is enabled.
+
+use adyen_webhooks::{HmacValidator, Webhook, WebhookRef};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+const HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+/// Build a webhook body with `count` notification items.
+fn webhook_body(count: usize) -> String {
+    let items: Vec<String> = (0..count)
+        .map(|i| {
+            format!(
+                r#"{{"NotificationRequestItem": {{
+                    "additionalData": {{
+                        "hmacSignature": "coqCmt/IZ4E3CzPvMY8zTjQVL5hYJUiBRg8UU+iCWo0=",
+                        "authCode": "123456",
+                        "cardSummary": "1111",
+                        "expiryDate": "03/2030",
+                        "paymentMethodVariant": "visa"
+                    }},
+                    "amount": {{"currency": "EUR", "value": {value}}},
+                    "eventCode": "AUTHORISATION",
+                    "eventDate": "2024-01-15T10:30:00+01:00",
+                    "merchantAccountCode": "TestMerchant",
+                    "merchantReference": "order-{i}",
+                    "operations": ["CANCEL", "CAPTURE", "REFUND"],
+                    "paymentMethod": "visa",
+                    "pspReference": "85151317510{i:05}",
+                    "reason": "123456:1111:03/2030",
+                    "success": "true"
+                }}}}"#,
+                value = 1000 + i
+            )
+        })
+        .collect();
+    format!(
+        r#"{{"live": "false", "notificationItems": [{}]}}"#,
+        items.join(",")
+    )
+}
+
+fn bench_parsing(c: &mut Criterion) {
+    let validator = HmacValidator::new(HMAC_KEY).unwrap();
+
+    for count in [1, 100] {
+        let body = webhook_body(count);
+        let mut group = c.benchmark_group(format!("webhook_{count}_items"));
+        group.throughput(Throughput::Bytes(body.len() as u64));
+
+        group.bench_function("owned", |b| {
+            b.iter(|| {
+                let webhook: Webhook = serde_json::from_str(black_box(&body)).unwrap();
+                webhook
+                    .get_notification_items()
+                    .into_iter()
+                    .filter(|item| validator.validate_notification(item))
+                    .count()
+            });
+        });
+
+        group.bench_function("borrowed", |b| {
+            b.iter(|| {
+                let webhook: WebhookRef<'_> = serde_json::from_str(black_box(&body)).unwrap();
+                webhook
+                    .get_notification_items()
+                    .into_iter()
+                    .filter(|item| validator.validate_notification_ref(item))
+                    .count()
+            });
+        });
+
+        #[cfg(feature = "simd-json")]
+        group.bench_function("simd_json", |b| {
+            b.iter_batched_ref(
+                || body.clone().into_bytes(),
+                |body| {
+                    let webhook = adyen_webhooks::handle_webhook_simd(body).unwrap();
+                    webhook
+                        .get_notification_items()
+                        .into_iter()
+                        .filter(|item| validator.validate_notification(item))
+                        .count()
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_parsing);
+criterion_main!(benches);
@@ -0,0 +1,364 @@
+//! Balance Platform webhook types.
+//!
+//! Balance Platform webhooks (account holders, balance accounts, payment instruments
+//! and transfers) are sent as a single JSON object instead of a list of notification
+//! items, and are signed over the raw request body. Validate and parse them with
+//! [`HmacValidator::parse_signed_payload`](crate::HmacValidator::parse_signed_payload),
+//! using the signature from the [`HMAC_SIGNATURE_HEADER`](crate::HMAC_SIGNATURE_HEADER)
+//! header.
+
+use crate::types::Amount;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Balance Platform webhook payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalancePlatformNotification {
+    /// Type of webhook, e.g. `balancePlatform.accountHolder.updated`.
+    pub r#type: BalancePlatformEventType,
+    /// Environment the webhook was sent from, `test` or `live`.
+    pub environment: String,
+    /// When the event happened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Event data, whose shape depends on the type.
+    pub data: serde_json::Value,
+}
+
+impl BalancePlatformNotification {
+    /// Check if this webhook is from the live environment.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.environment == "live"
+    }
+
+    /// Parse the event data according to the webhook type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data does not match the shape expected for the type.
+    pub fn event(&self) -> Result<BalancePlatformEvent, serde_json::Error> {
+        use BalancePlatformEventType as Type;
+
+        let data = self.data.clone();
+        Ok(match self.r#type {
+            Type::AccountHolderCreated | Type::AccountHolderUpdated => {
+                BalancePlatformEvent::AccountHolder(serde_json::from_value(data)?)
+            }
+            Type::BalanceAccountCreated | Type::BalanceAccountUpdated => {
+                BalancePlatformEvent::BalanceAccount(serde_json::from_value(data)?)
+            }
+            Type::PaymentInstrumentCreated | Type::PaymentInstrumentUpdated => {
+                BalancePlatformEvent::PaymentInstrument(serde_json::from_value(data)?)
+            }
+            Type::TransferCreated | Type::TransferUpdated => {
+                BalancePlatformEvent::Transfer(serde_json::from_value(data)?)
+            }
+            Type::Unknown(_) => BalancePlatformEvent::Other(data),
+        })
+    }
+}
+
+/// Types of Balance Platform webhooks.
+///
+/// Types not known to this library deserialize into
+/// [`BalancePlatformEventType::Unknown`], which keeps the original string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BalancePlatformEventType {
+    /// An account holder was created.
+    AccountHolderCreated,
+    /// An account holder was updated, e.g. its status or capabilities changed.
+    AccountHolderUpdated,
+    /// A balance account was created.
+    BalanceAccountCreated,
+    /// A balance account was updated.
+    BalanceAccountUpdated,
+    /// A payment instrument was created.
+    PaymentInstrumentCreated,
+    /// A payment instrument was updated.
+    PaymentInstrumentUpdated,
+    /// A transfer was created.
+    TransferCreated,
+    /// The status of a transfer changed.
+    TransferUpdated,
+    /// A webhook type not known to this library.
+    Unknown(String),
+}
+
+impl BalancePlatformEventType {
+    /// Get the type as sent by Adyen, e.g. `balancePlatform.transfer.updated`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccountHolderCreated => "balancePlatform.accountHolder.created",
+            Self::AccountHolderUpdated => "balancePlatform.accountHolder.updated",
+            Self::BalanceAccountCreated => "balancePlatform.balanceAccount.created",
+            Self::BalanceAccountUpdated => "balancePlatform.balanceAccount.updated",
+            Self::PaymentInstrumentCreated => "balancePlatform.paymentInstrument.created",
+            Self::PaymentInstrumentUpdated => "balancePlatform.paymentInstrument.updated",
+            Self::TransferCreated => "balancePlatform.transfer.created",
+            Self::TransferUpdated => "balancePlatform.transfer.updated",
+            Self::Unknown(r#type) => r#type,
+        }
+    }
+}
+
+impl From<&str> for BalancePlatformEventType {
+    fn from(r#type: &str) -> Self {
+        match r#type {
+            "balancePlatform.accountHolder.created" => Self::AccountHolderCreated,
+            "balancePlatform.accountHolder.updated" => Self::AccountHolderUpdated,
+            "balancePlatform.balanceAccount.created" => Self::BalanceAccountCreated,
+            "balancePlatform.balanceAccount.updated" => Self::BalanceAccountUpdated,
+            "balancePlatform.paymentInstrument.created" => Self::PaymentInstrumentCreated,
+            "balancePlatform.paymentInstrument.updated" => Self::PaymentInstrumentUpdated,
+            "balancePlatform.transfer.created" => Self::TransferCreated,
+            "balancePlatform.transfer.updated" => Self::TransferUpdated,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for BalancePlatformEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for BalancePlatformEventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BalancePlatformEventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let r#type = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(r#type.as_ref()))
+    }
+}
+
+/// Typed data of a Balance Platform webhook.
+#[derive(Debug, Clone)]
+pub enum BalancePlatformEvent {
+    /// Account holder created or updated.
+    AccountHolder(AccountHolderNotificationData),
+    /// Balance account created or updated.
+    BalanceAccount(BalanceAccountNotificationData),
+    /// Payment instrument created or updated.
+    PaymentInstrument(PaymentInstrumentNotificationData),
+    /// Transfer created or updated.
+    Transfer(TransferNotificationData),
+    /// Data of a webhook type not known to this library.
+    Other(serde_json::Value),
+}
+
+/// Data of `balancePlatform.accountHolder.*` webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHolderNotificationData {
+    /// Balance platform the account holder belongs to.
+    pub balance_platform: Option<String>,
+    /// The account holder.
+    pub account_holder: AccountHolder,
+}
+
+/// Account holder in a Balance Platform webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHolder {
+    /// Account holder ID.
+    pub id: String,
+    /// Legal entity the account holder represents.
+    pub legal_entity_id: Option<String>,
+    /// Status, e.g. `active` or `suspended`.
+    pub status: Option<String>,
+    /// Your reference for the account holder.
+    pub reference: Option<String>,
+    /// Description of the account holder.
+    pub description: Option<String>,
+    /// Capabilities and their status, keyed by capability name.
+    pub capabilities: Option<serde_json::Value>,
+}
+
+/// Data of `balancePlatform.balanceAccount.*` webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceAccountNotificationData {
+    /// Balance platform the balance account belongs to.
+    pub balance_platform: Option<String>,
+    /// The balance account.
+    pub balance_account: BalanceAccount,
+}
+
+/// Balance account in a Balance Platform webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceAccount {
+    /// Balance account ID.
+    pub id: String,
+    /// Account holder owning the balance account.
+    pub account_holder_id: Option<String>,
+    /// Default currency of the balance account.
+    pub default_currency_code: Option<String>,
+    /// Status, e.g. `active` or `closed`.
+    pub status: Option<String>,
+    /// Your reference for the balance account.
+    pub reference: Option<String>,
+    /// Description of the balance account.
+    pub description: Option<String>,
+}
+
+/// Data of `balancePlatform.paymentInstrument.*` webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInstrumentNotificationData {
+    /// Balance platform the payment instrument belongs to.
+    pub balance_platform: Option<String>,
+    /// The payment instrument.
+    pub payment_instrument: PaymentInstrument,
+}
+
+/// Payment instrument in a Balance Platform webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInstrument {
+    /// Payment instrument ID.
+    pub id: String,
+    /// Balance account the payment instrument is linked to.
+    pub balance_account_id: Option<String>,
+    /// Type, e.g. `card` or `bankAccount`.
+    pub r#type: Option<String>,
+    /// Status, e.g. `active`, `inactive` or `blocked`.
+    pub status: Option<String>,
+    /// Country where the payment instrument is issued.
+    pub issuing_country_code: Option<String>,
+    /// Your reference for the payment instrument.
+    pub reference: Option<String>,
+    /// Description of the payment instrument.
+    pub description: Option<String>,
+}
+
+/// Data of `balancePlatform.transfer.*` webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferNotificationData {
+    /// Transfer ID.
+    pub id: String,
+    /// Amount transferred.
+    pub amount: Amount,
+    /// Balance platform the transfer belongs to.
+    pub balance_platform: Option<String>,
+    /// Account holder involved in the transfer.
+    pub account_holder: Option<ResourceReference>,
+    /// Balance account involved in the transfer.
+    pub balance_account: Option<ResourceReference>,
+    /// Category, e.g. `bank`, `internal` or `issuedCard`.
+    pub category: Option<String>,
+    /// Direction, `incoming` or `outgoing`.
+    pub direction: Option<String>,
+    /// Status, e.g. `authorised`, `booked` or `failed`.
+    pub status: Option<String>,
+    /// Reason for the status, e.g. `approved`.
+    pub reason: Option<String>,
+    /// Your reference for the transfer.
+    pub reference: Option<String>,
+    /// Increments with each webhook for the transfer, to order updates.
+    pub sequence_number: Option<u32>,
+}
+
+/// Reference to a resource in a Balance Platform webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceReference {
+    /// Resource ID.
+    pub id: String,
+    /// Description of the resource.
+    pub description: Option<String>,
+    /// Your reference for the resource.
+    pub reference: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::HmacValidator;
+
+    const TEST_HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+    #[test]
+    fn test_account_holder_webhook() {
+        let payload = r#"{
+            "data": {
+                "balancePlatform": "YOUR_BALANCE_PLATFORM",
+                "accountHolder": {
+                    "id": "AH3227C223222B5CMD2SXFKGT",
+                    "legalEntityId": "LE4567",
+                    "status": "active",
+                    "reference": "merchant-1"
+                }
+            },
+            "environment": "test",
+            "timestamp": "2024-03-05T11:30:00+01:00",
+            "type": "balancePlatform.accountHolder.updated"
+        }"#;
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let signature = validator.calculate_payload_signature(payload).unwrap();
+
+        let notification: BalancePlatformNotification =
+            validator.parse_signed_payload(payload, &signature).unwrap();
+        assert_eq!(
+            notification.r#type,
+            BalancePlatformEventType::AccountHolderUpdated
+        );
+        assert!(!notification.is_live());
+        assert_eq!(
+            notification.timestamp.unwrap().to_rfc3339(),
+            "2024-03-05T10:30:00+00:00"
+        );
+        let BalancePlatformEvent::AccountHolder(data) = notification.event().unwrap() else {
+            panic!("expected an account holder event");
+        };
+        assert_eq!(data.account_holder.id, "AH3227C223222B5CMD2SXFKGT");
+        assert_eq!(data.account_holder.status.as_deref(), Some("active"));
+    }
+
+    #[test]
+    fn test_transfer_and_unknown_webhooks() {
+        let notification: BalancePlatformNotification = serde_json::from_str(
+            r#"{
+                "data": {
+                    "id": "1W1UG35U8A9J5ZLG",
+                    "amount": {"currency": "EUR", "value": 10000},
+                    "balanceAccount": {"id": "BA3227C223222B5CTBLR8BWJB"},
+                    "category": "bank",
+                    "direction": "outgoing",
+                    "status": "booked",
+                    "sequenceNumber": 3
+                },
+                "environment": "live",
+                "type": "balancePlatform.transfer.updated"
+            }"#,
+        )
+        .unwrap();
+        let BalancePlatformEvent::Transfer(transfer) = notification.event().unwrap() else {
+            panic!("expected a transfer event");
+        };
+        assert_eq!(transfer.amount.minor_units(), 10000);
+        assert_eq!(transfer.sequence_number, Some(3));
+
+        let notification: BalancePlatformNotification = serde_json::from_str(
+            r#"{"data": {}, "environment": "test", "type": "balancePlatform.future.created"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            notification.r#type.as_str(),
+            "balancePlatform.future.created"
+        );
+        assert!(matches!(
+            notification.event().unwrap(),
+            BalancePlatformEvent::Other(_)
+        ));
+    }
+}
@@ -0,0 +1,292 @@
+//! Webhook types that borrow from the request body.
+//!
+//! [`Webhook`](crate::Webhook) copies every string of a notification into its
+//! own allocation. Endpoints that receive many notifications can parse the
+//! body into a [`WebhookRef`] instead, whose string fields borrow from the
+//! body wherever the JSON text contains no escape sequences, and whose
+//! additional data values stay unparsed until they are looked up.
+//!
+//! ```rust
+//! use adyen_webhooks::{EventCode, WebhookRef};
+//!
+//! # fn example(body: &str) -> Result<(), serde_json::Error> {
+//! let webhook: WebhookRef<'_> = serde_json::from_str(body)?;
+//! for item in webhook.get_notification_items() {
+//!     if item.event_code == EventCode::Authorisation && item.is_success() {
+//!         println!("Authorised: {}", item.psp_reference);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The borrowed types must be deserialized from a `&str` or `&[u8]` that
+//! outlives them, e.g. with [`serde_json::from_str`]. Use
+//! [`NotificationRequestItemRef::into_owned`] to keep an item after the body
+//! is dropped.
+
+use crate::types::{Amount, EventCode, NotificationItem, NotificationRequestItem, Webhook};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Additional data of a [`NotificationRequestItemRef`], with values kept as
+/// raw JSON.
+pub type RawAdditionalData<'a> = HashMap<Cow<'a, str>, &'a RawValue>;
+
+/// Amount of a [`NotificationRequestItemRef`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmountRef<'a> {
+    /// The payment amount in minor units (e.g., cents).
+    pub value: i64,
+    /// The three-character ISO currency code.
+    #[serde(borrow)]
+    pub currency: Cow<'a, str>,
+}
+
+/// Webhook payload that borrows from the request body.
+///
+/// The borrowed counterpart of [`Webhook`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookRef<'a> {
+    /// Indicates whether this webhook was sent from the live environment.
+    /// "true" for live, "false" for test environment.
+    #[serde(borrow)]
+    pub live: Cow<'a, str>,
+    /// Array of notification items containing the actual webhook events.
+    #[serde(borrow, default)]
+    pub notification_items: Vec<NotificationItemRef<'a>>,
+}
+
+/// Container for a single borrowed notification request item.
+///
+/// The borrowed counterpart of [`NotificationItem`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationItemRef<'a> {
+    /// The actual notification request item containing event data.
+    #[serde(rename = "NotificationRequestItem", borrow)]
+    pub notification_request_item: NotificationRequestItemRef<'a>,
+}
+
+/// Notification request item that borrows from the request body.
+///
+/// The borrowed counterpart of [`NotificationRequestItem`]. Additional data
+/// values are kept as raw JSON and parsed when looked up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRequestItemRef<'a> {
+    /// Additional data provided with the webhook event, as raw JSON values.
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<RawAdditionalData<'a>>,
+    /// The payment amount associated with this event.
+    #[serde(borrow)]
+    pub amount: AmountRef<'a>,
+    /// The type of event that triggered this webhook.
+    pub event_code: EventCode,
+    /// The date and time when the event occurred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_date: Option<DateTime<Utc>>,
+    /// The merchant account identifier.
+    #[serde(borrow)]
+    pub merchant_account_code: Cow<'a, str>,
+    /// The merchant's reference for this payment/transaction.
+    #[serde(borrow)]
+    pub merchant_reference: Cow<'a, str>,
+    /// Array of operations that can be performed on this payment.
+    #[serde(borrow, default)]
+    pub operations: Vec<Cow<'a, str>>,
+    /// Reference to the original request (for modifications).
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub original_reference: Option<Cow<'a, str>>,
+    /// The payment method used.
+    #[serde(borrow)]
+    pub payment_method: Cow<'a, str>,
+    /// Adyen's unique reference for this payment/transaction.
+    #[serde(borrow)]
+    pub psp_reference: Cow<'a, str>,
+    /// Reason for the event (e.g., fraud check results).
+    #[serde(borrow)]
+    pub reason: Cow<'a, str>,
+    /// Indicates whether the operation was successful.
+    /// "true" for successful, "false" for failed operations.
+    #[serde(borrow)]
+    pub success: Cow<'a, str>,
+}
+
+impl<'a> WebhookRef<'a> {
+    /// Get all notification request items from this webhook.
+    #[must_use]
+    pub fn get_notification_items(&self) -> Vec<&NotificationRequestItemRef<'a>> {
+        self.notification_items
+            .iter()
+            .map(|item| &item.notification_request_item)
+            .collect()
+    }
+
+    /// Check if this webhook is from the live environment.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.live == "true"
+    }
+
+    /// Check if this webhook is from the test environment.
+    #[must_use]
+    pub fn is_test(&self) -> bool {
+        self.live == "false"
+    }
+
+    /// Copy the webhook into a [`Webhook`] that owns its data.
+    #[must_use]
+    pub fn into_owned(self) -> Webhook {
+        Webhook {
+            live: self.live.into_owned(),
+            notification_items: self
+                .notification_items
+                .into_iter()
+                .map(|item| NotificationItem {
+                    notification_request_item: item.notification_request_item.into_owned(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> NotificationRequestItemRef<'a> {
+    /// Check if this notification represents a successful operation.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.success == "true"
+    }
+
+    /// Check if this notification represents a failed operation.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.success == "false"
+    }
+
+    /// Get the HMAC signature from additional data, if present.
+    ///
+    /// The signature borrows from the request body unless it contains escape
+    /// sequences.
+    #[must_use]
+    pub fn hmac_signature(&self) -> Option<Cow<'a, str>> {
+        let raw: &'a RawValue = self.get_raw_additional_data("hmacSignature")?;
+        serde_json::from_str::<&'a str>(raw.get())
+            .map(Cow::Borrowed)
+            .or_else(|_| serde_json::from_str::<String>(raw.get()).map(Cow::Owned))
+            .ok()
+    }
+
+    /// Get a specific value from additional data as raw JSON.
+    #[must_use]
+    pub fn get_raw_additional_data(&self, key: &str) -> Option<&'a RawValue> {
+        self.additional_data.as_ref()?.get(key).copied()
+    }
+
+    /// Get a specific value from additional data.
+    #[must_use]
+    pub fn get_additional_data(&self, key: &str) -> Option<serde_json::Value> {
+        serde_json::from_str(self.get_raw_additional_data(key)?.get()).ok()
+    }
+
+    /// Copy the item into a [`NotificationRequestItem`] that owns its data.
+    ///
+    /// Additional data values that are not valid JSON are dropped, which
+    /// cannot happen for items deserialized from JSON.
+    #[must_use]
+    pub fn into_owned(self) -> NotificationRequestItem {
+        NotificationRequestItem {
+            additional_data: self.additional_data.map(|data| {
+                data.into_iter()
+                    .filter_map(|(key, value)| {
+                        Some((key.into_owned(), serde_json::from_str(value.get()).ok()?))
+                    })
+                    .collect()
+            }),
+            amount: Amount::new(self.amount.value, self.amount.currency),
+            event_code: self.event_code,
+            event_date: self.event_date,
+            merchant_account_code: self.merchant_account_code.into_owned(),
+            merchant_reference: self.merchant_reference.into_owned(),
+            operations: self.operations.into_iter().map(Cow::into_owned).collect(),
+            original_reference: self.original_reference.map(Cow::into_owned),
+            payment_method: self.payment_method.into_owned(),
+            psp_reference: self.psp_reference.into_owned(),
+            reason: self.reason.into_owned(),
+            success: self.success.into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEBHOOK: &str = r#"{
+        "live": "false",
+        "notificationItems": [
+            {
+                "NotificationRequestItem": {
+                    "additionalData": {
+                        "hmacSignature": "coqCmt\/IZ4E3CzPvMY8zTjQVL5hYJUiBRg8UU+iCWo0=",
+                        "authCode": "123456",
+                        "totalFraudScore": 10
+                    },
+                    "amount": {"currency": "EUR", "value": 1000},
+                    "eventCode": "AUTHORISATION",
+                    "eventDate": "2024-01-15T10:30:00+01:00",
+                    "merchantAccountCode": "TestMerchant",
+                    "merchantReference": "test-payment-123",
+                    "operations": ["CAPTURE", "REFUND"],
+                    "paymentMethod": "visa",
+                    "pspReference": "8515131751004933",
+                    "reason": "Approved",
+                    "success": "true"
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_borrowed_webhook_parsing() {
+        let webhook: WebhookRef<'_> = serde_json::from_str(WEBHOOK).unwrap();
+        assert!(webhook.is_test());
+
+        let item = webhook.get_notification_items()[0];
+        assert!(item.is_success());
+        assert_eq!(item.event_code, EventCode::Authorisation);
+        assert!(matches!(
+            item.psp_reference,
+            Cow::Borrowed("8515131751004933")
+        ));
+        assert!(matches!(item.amount.currency, Cow::Borrowed("EUR")));
+        assert_eq!(item.operations, ["CAPTURE", "REFUND"]);
+
+        // The escaped slash in the signature has to be unescaped into a copy.
+        let signature = item.hmac_signature().unwrap();
+        assert!(matches!(signature, Cow::Owned(_)));
+        assert_eq!(signature, "coqCmt/IZ4E3CzPvMY8zTjQVL5hYJUiBRg8UU+iCWo0=");
+        assert_eq!(item.get_additional_data("totalFraudScore").unwrap(), 10);
+        assert!(item.get_additional_data("cardSummary").is_none());
+    }
+
+    #[test]
+    fn test_borrowed_webhook_into_owned() {
+        let borrowed: WebhookRef<'_> = serde_json::from_str(WEBHOOK).unwrap();
+        let owned: Webhook = serde_json::from_str(WEBHOOK).unwrap();
+
+        let converted = borrowed.into_owned();
+        assert_eq!(
+            serde_json::to_value(&converted).unwrap(),
+            serde_json::to_value(&owned).unwrap()
+        );
+        let item = &converted.notification_items[0].notification_request_item;
+        assert_eq!(
+            item.hmac_signature().as_deref(),
+            Some("coqCmt/IZ4E3CzPvMY8zTjQVL5hYJUiBRg8UU+iCWo0=")
+        );
+    }
+}
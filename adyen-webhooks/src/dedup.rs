@@ -0,0 +1,191 @@
+//! Detection of duplicate webhook notifications.
+//!
+//! Adyen retries a webhook until it is accepted, so the same notification can arrive
+//! several times. A [`NotificationDeduplicator`] remembers notifications that were
+//! already processed, keyed on PSP reference, event code and success, so handlers can
+//! skip duplicates. [`InMemoryDeduplicator`] is a bounded in-process default; implement
+//! the trait over a shared store such as a database when running several instances.
+
+use crate::types::{EventCode, NotificationRequestItem};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Key identifying a notification across retries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotificationKey {
+    /// PSP reference of the payment or modification.
+    pub psp_reference: String,
+    /// Event code of the notification.
+    pub event_code: EventCode,
+    /// Whether the notification reports a successful operation.
+    pub success: bool,
+}
+
+impl From<&NotificationRequestItem> for NotificationKey {
+    fn from(item: &NotificationRequestItem) -> Self {
+        Self {
+            psp_reference: item.psp_reference.clone(),
+            event_code: item.event_code.clone(),
+            success: item.is_success(),
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.psp_reference, self.event_code, self.success
+        )
+    }
+}
+
+/// Store of notifications that were already processed.
+pub trait NotificationDeduplicator: Send + Sync {
+    /// Record the key and return `true` if it was not seen before.
+    ///
+    /// Implementations must check and record atomically, so concurrent deliveries of
+    /// the same notification are processed only once.
+    fn first_seen(&self, key: &NotificationKey) -> bool;
+
+    /// Forget a key, so the next delivery is processed again.
+    ///
+    /// Called when processing a notification fails.
+    fn forget(&self, key: &NotificationKey);
+
+    /// Record a notification item and return `true` if it was seen before.
+    fn is_duplicate(&self, item: &NotificationRequestItem) -> bool {
+        !self.first_seen(&NotificationKey::from(item))
+    }
+}
+
+/// In-memory deduplicator remembering the most recently seen notifications.
+///
+/// Once `capacity` keys are stored, the least recently seen key is forgotten.
+#[derive(Debug)]
+pub struct InMemoryDeduplicator {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    /// Generation at which each key was last seen.
+    seen: HashMap<NotificationKey, u64>,
+    /// Keys in the order they were seen; entries with an older generation are stale.
+    order: VecDeque<(NotificationKey, u64)>,
+    generation: u64,
+}
+
+impl InMemoryDeduplicator {
+    /// Create a deduplicator remembering up to `capacity` notifications.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Number of notifications currently remembered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().seen.len()
+    }
+
+    /// Check if no notifications are remembered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for InMemoryDeduplicator {
+    /// Remembers the last 10,000 notifications.
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl NotificationDeduplicator for InMemoryDeduplicator {
+    fn first_seen(&self, key: &NotificationKey) -> bool {
+        let mut state = self.lock();
+        state.generation += 1;
+        let generation = state.generation;
+        let first = state.seen.insert(key.clone(), generation).is_none();
+        state.order.push_back((key.clone(), generation));
+
+        while state.seen.len() > self.capacity || state.order.len() > 2 * self.capacity {
+            let Some((oldest, generation)) = state.order.pop_front() else {
+                break;
+            };
+            if state.seen.get(&oldest) == Some(&generation) {
+                state.seen.remove(&oldest);
+            }
+        }
+        first
+    }
+
+    fn forget(&self, key: &NotificationKey) {
+        self.lock().seen.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(psp_reference: &str, event_code: EventCode, success: bool) -> NotificationKey {
+        NotificationKey {
+            psp_reference: psp_reference.to_string(),
+            event_code,
+            success,
+        }
+    }
+
+    #[test]
+    fn test_detects_duplicates() {
+        let dedup = InMemoryDeduplicator::default();
+        let authorised = key("PSP1", EventCode::Authorisation, true);
+
+        assert!(dedup.first_seen(&authorised));
+        assert!(!dedup.first_seen(&authorised));
+        assert!(dedup.first_seen(&key("PSP1", EventCode::Authorisation, false)));
+        assert!(dedup.first_seen(&key("PSP1", EventCode::Capture, true)));
+        assert_eq!(dedup.len(), 3);
+
+        dedup.forget(&authorised);
+        assert!(dedup.first_seen(&authorised));
+        assert_eq!(authorised.to_string(), "PSP1:AUTHORISATION:true");
+    }
+
+    #[test]
+    fn test_evicts_least_recently_seen() {
+        let dedup = InMemoryDeduplicator::new(2);
+        let first = key("PSP1", EventCode::Authorisation, true);
+        let second = key("PSP2", EventCode::Authorisation, true);
+        let third = key("PSP3", EventCode::Authorisation, true);
+
+        assert!(dedup.first_seen(&first));
+        assert!(dedup.first_seen(&second));
+        // Seeing the first key again makes the second the least recently seen.
+        assert!(!dedup.first_seen(&first));
+        assert!(dedup.first_seen(&third));
+
+        assert_eq!(dedup.len(), 2);
+        assert!(!dedup.first_seen(&first));
+        assert!(dedup.first_seen(&second));
+    }
+}
@@ -0,0 +1,459 @@
+//! Routing of webhook notifications to typed per-event handlers.
+//!
+//! [`WebhookDispatcher`] parses an incoming webhook, validates the HMAC signature of
+//! every notification item, and calls the async handler registered for each item's
+//! [`EventCode`]. On success it returns the `[accepted]` body Adyen expects in the
+//! response.
+
+use crate::dedup::{NotificationDeduplicator, NotificationKey};
+use crate::types::{EventCode, NotificationRequestItem, Webhook};
+use crate::validation::HmacValidator;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Response body Adyen expects once a webhook has been accepted.
+pub const ACCEPTED_RESPONSE: &str = "[accepted]";
+
+/// Result returned by webhook handlers.
+pub type HandlerResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+type BoxedHandler = Box<
+    dyn Fn(NotificationRequestItem) -> Pin<Box<dyn Future<Output = HandlerResult> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Error types for webhook dispatching.
+#[derive(Debug, thiserror::Error)]
+pub enum DispatchError {
+    /// The payload is not a valid webhook.
+    #[error("Invalid webhook payload: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// A notification item has a missing or invalid HMAC signature.
+    #[error("Invalid HMAC signature for notification {psp_reference}")]
+    InvalidSignature {
+        /// PSP reference of the rejected notification item.
+        psp_reference: String,
+    },
+    /// A handler failed to process a notification item.
+    #[error("Handler for {event_code} failed for notification {psp_reference}: {source}")]
+    Handler {
+        /// Event code of the notification item.
+        event_code: EventCode,
+        /// PSP reference of the notification item.
+        psp_reference: String,
+        /// Error returned by the handler.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Dispatcher routing webhook notifications to handlers registered per event code.
+///
+/// All notification items are validated before any handler runs, so a webhook with a
+/// forged item is rejected as a whole. Items without a registered handler go to the
+/// fallback handler if one is set, and are otherwise accepted without processing.
+/// With a [`NotificationDeduplicator`], items that were already handled are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_webhooks::{HmacValidator, WebhookDispatcher};
+///
+/// # async fn example(payload: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let dispatcher = WebhookDispatcher::new(HmacValidator::new("44782DEF547AAA06")?)
+///     .on_authorisation(|item| async move {
+///         println!("Payment {} authorised: {}", item.psp_reference, item.is_success());
+///         Ok(())
+///     })
+///     .on_refund(|item| async move {
+///         println!("Refund for {}", item.merchant_reference);
+///         Ok(())
+///     });
+///
+/// let response_body = dispatcher.dispatch(payload).await?;
+/// assert_eq!(response_body, "[accepted]");
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebhookDispatcher {
+    validator: Option<HmacValidator>,
+    handlers: HashMap<EventCode, BoxedHandler>,
+    fallback: Option<BoxedHandler>,
+    deduplicator: Option<Box<dyn NotificationDeduplicator>>,
+}
+
+impl WebhookDispatcher {
+    /// Create a dispatcher that validates notifications with the given validator.
+    #[must_use]
+    pub fn new(validator: HmacValidator) -> Self {
+        Self {
+            validator: Some(validator),
+            handlers: HashMap::new(),
+            fallback: None,
+            deduplicator: None,
+        }
+    }
+
+    /// Create a dispatcher that skips HMAC validation.
+    ///
+    /// Only use this when signatures are verified elsewhere, e.g. by a proxy.
+    #[must_use]
+    pub fn without_validation() -> Self {
+        Self {
+            validator: None,
+            handlers: HashMap::new(),
+            fallback: None,
+            deduplicator: None,
+        }
+    }
+
+    /// Skip notification items the deduplicator has already seen.
+    ///
+    /// Items whose handler fails are forgotten again, so Adyen's retry is processed.
+    #[must_use]
+    pub fn with_deduplicator(
+        mut self,
+        deduplicator: impl NotificationDeduplicator + 'static,
+    ) -> Self {
+        self.deduplicator = Some(Box::new(deduplicator));
+        self
+    }
+
+    /// Register a handler for an event code, replacing any previous handler for it.
+    #[must_use]
+    pub fn on<F, Fut>(mut self, event_code: EventCode, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.handlers.insert(event_code, box_handler(handler));
+        self
+    }
+
+    /// Register a handler for items without an event-specific handler.
+    #[must_use]
+    pub fn on_unhandled<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.fallback = Some(box_handler(handler));
+        self
+    }
+
+    /// Register a handler for `AUTHORISATION` events.
+    #[must_use]
+    pub fn on_authorisation<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::Authorisation, handler)
+    }
+
+    /// Register a handler for `CAPTURE` events.
+    #[must_use]
+    pub fn on_capture<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::Capture, handler)
+    }
+
+    /// Register a handler for `CANCELLATION` events.
+    #[must_use]
+    pub fn on_cancellation<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::Cancellation, handler)
+    }
+
+    /// Register a handler for `REFUND` events.
+    #[must_use]
+    pub fn on_refund<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::Refund, handler)
+    }
+
+    /// Register a handler for `CHARGEBACK` events.
+    #[must_use]
+    pub fn on_chargeback<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::Chargeback, handler)
+    }
+
+    /// Register a handler for `REPORT_AVAILABLE` events.
+    #[must_use]
+    pub fn on_report_available<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.on(EventCode::ReportAvailable, handler)
+    }
+
+    /// Parse, validate and dispatch a raw webhook payload.
+    ///
+    /// Returns the `[accepted]` response body once every item has been handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be parsed, a signature is invalid, or a
+    /// handler fails. Respond with an error status so Adyen retries the webhook.
+    pub async fn dispatch(&self, payload: &str) -> Result<&'static str, DispatchError> {
+        let webhook: Webhook = serde_json::from_str(payload)?;
+        self.dispatch_webhook(webhook).await
+    }
+
+    /// Validate and dispatch an already parsed webhook.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signature is invalid or a handler fails.
+    pub async fn dispatch_webhook(&self, webhook: Webhook) -> Result<&'static str, DispatchError> {
+        if let Some(validator) = &self.validator {
+            if let Some(item) = webhook
+                .get_notification_items()
+                .into_iter()
+                .find(|item| !validator.validate_notification(item))
+            {
+                return Err(DispatchError::InvalidSignature {
+                    psp_reference: item.psp_reference.clone(),
+                });
+            }
+        }
+
+        for item in webhook.notification_items {
+            let item = item.notification_request_item;
+            let Some(handler) = self
+                .handlers
+                .get(&item.event_code)
+                .or(self.fallback.as_ref())
+            else {
+                continue;
+            };
+
+            let key = NotificationKey::from(&item);
+            if let Some(deduplicator) = &self.deduplicator {
+                if !deduplicator.first_seen(&key) {
+                    continue;
+                }
+            }
+
+            if let Err(source) = handler(item).await {
+                if let Some(deduplicator) = &self.deduplicator {
+                    deduplicator.forget(&key);
+                }
+                return Err(DispatchError::Handler {
+                    event_code: key.event_code,
+                    psp_reference: key.psp_reference,
+                    source,
+                });
+            }
+        }
+
+        Ok(ACCEPTED_RESPONSE)
+    }
+}
+
+impl std::fmt::Debug for WebhookDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookDispatcher")
+            .field("validator", &self.validator)
+            .field("handlers", &self.handlers.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .field("deduplicator", &self.deduplicator.is_some())
+            .finish()
+    }
+}
+
+fn box_handler<F, Fut>(handler: F) -> BoxedHandler
+where
+    F: Fn(NotificationRequestItem) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = HandlerResult> + Send + 'static,
+{
+    Box::new(move |item| Box::pin(handler(item)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    const TEST_HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+    type Seen = Arc<Mutex<Vec<String>>>;
+
+    fn notification(event_code: &str, psp_reference: &str) -> NotificationRequestItem {
+        NotificationRequestItem {
+            additional_data: None,
+            amount: crate::types::Amount::new(1000, "EUR"),
+            event_code: EventCode::from(event_code),
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "order-123".to_string(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: psp_reference.to_string(),
+            reason: String::new(),
+            success: "true".to_string(),
+        }
+    }
+
+    fn signed_payload(items: Vec<NotificationRequestItem>) -> String {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let notification_items = items
+            .into_iter()
+            .map(|mut item| {
+                let signature = validator.calculate_notification_signature(&item).unwrap();
+                item.additional_data = Some(HashMap::from([(
+                    "hmacSignature".to_string(),
+                    serde_json::Value::String(signature),
+                )]));
+                crate::types::NotificationItem {
+                    notification_request_item: item,
+                }
+            })
+            .collect();
+        serde_json::to_string(&Webhook {
+            live: "false".to_string(),
+            notification_items,
+        })
+        .unwrap()
+    }
+
+    fn recording_dispatcher(seen: &Seen) -> WebhookDispatcher {
+        let authorisations = Arc::clone(seen);
+        let fallback = Arc::clone(seen);
+        WebhookDispatcher::new(HmacValidator::new(TEST_HMAC_KEY).unwrap())
+            .on_authorisation(move |item| {
+                let seen = Arc::clone(&authorisations);
+                async move {
+                    seen.lock()
+                        .unwrap()
+                        .push(format!("auth:{}", item.psp_reference));
+                    Ok(())
+                }
+            })
+            .on_unhandled(move |item| {
+                let seen = Arc::clone(&fallback);
+                async move {
+                    seen.lock()
+                        .unwrap()
+                        .push(format!("other:{}", item.event_code));
+                    Ok(())
+                }
+            })
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_routes_by_event_code() {
+        let seen = Seen::default();
+        let dispatcher = recording_dispatcher(&seen);
+        let payload = signed_payload(vec![
+            notification("AUTHORISATION", "PSP1"),
+            notification("SOME_FUTURE_EVENT", "PSP2"),
+        ]);
+
+        assert_eq!(dispatcher.dispatch(&payload).await.unwrap(), "[accepted]");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            ["auth:PSP1", "other:SOME_FUTURE_EVENT"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_rejects_invalid_signature_before_handling() {
+        let seen = Seen::default();
+        let dispatcher = recording_dispatcher(&seen);
+        let mut payload: Webhook =
+            serde_json::from_str(&signed_payload(vec![notification("AUTHORISATION", "PSP1")]))
+                .unwrap();
+        let mut forged = notification("AUTHORISATION", "PSP2");
+        forged.additional_data = payload.notification_items[0]
+            .notification_request_item
+            .additional_data
+            .clone();
+        payload
+            .notification_items
+            .push(crate::types::NotificationItem {
+                notification_request_item: forged,
+            });
+
+        let error = dispatcher.dispatch_webhook(payload).await.unwrap_err();
+        assert!(matches!(
+            error,
+            DispatchError::InvalidSignature { ref psp_reference } if psp_reference == "PSP2"
+        ));
+        assert!(seen.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_duplicates() {
+        let seen = Seen::default();
+        let dispatcher = recording_dispatcher(&seen)
+            .with_deduplicator(crate::dedup::InMemoryDeduplicator::default());
+        let payload = signed_payload(vec![notification("AUTHORISATION", "PSP1")]);
+
+        dispatcher.dispatch(&payload).await.unwrap();
+        assert_eq!(dispatcher.dispatch(&payload).await.unwrap(), "[accepted]");
+        assert_eq!(*seen.lock().unwrap(), ["auth:PSP1"]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retries_failed_items_after_deduplication() {
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&attempts);
+        let dispatcher = WebhookDispatcher::without_validation()
+            .with_deduplicator(crate::dedup::InMemoryDeduplicator::default())
+            .on_capture(move |_| {
+                let attempt = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async move {
+                    if attempt == 0 {
+                        Err("temporarily unavailable".into())
+                    } else {
+                        Ok(())
+                    }
+                }
+            });
+        let payload = signed_payload(vec![notification("CAPTURE", "PSP1")]);
+
+        assert!(dispatcher.dispatch(&payload).await.is_err());
+        dispatcher.dispatch(&payload).await.unwrap();
+        dispatcher.dispatch(&payload).await.unwrap();
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_surfaces_handler_errors() {
+        let dispatcher = WebhookDispatcher::without_validation()
+            .on_refund(|_| async { Err("ledger unavailable".into()) });
+        let payload = signed_payload(vec![
+            notification("CAPTURE", "PSP1"),
+            notification("REFUND", "PSP2"),
+        ]);
+
+        let error = dispatcher.dispatch(&payload).await.unwrap_err();
+        assert!(matches!(
+            error,
+            DispatchError::Handler { event_code: EventCode::Refund, ref psp_reference, .. }
+                if psp_reference == "PSP2"
+        ));
+        assert!(error.to_string().contains("ledger unavailable"));
+        assert!(matches!(
+            dispatcher.dispatch("not json").await,
+            Err(DispatchError::Parse(_))
+        ));
+    }
+}
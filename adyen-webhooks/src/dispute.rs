@@ -0,0 +1,288 @@
+//! Lifecycle of a chargeback, driven by dispute webhooks.
+//!
+//! A dispute moves through several notifications: an optional
+//! `NOTIFICATION_OF_CHARGEBACK`, the `CHARGEBACK` itself, a `CHARGEBACK_REVERSED` when
+//! the defense succeeds, and for some schemes a `SECOND_CHARGEBACK` and a
+//! pre-arbitration outcome. [`DisputeLifecycle`] tracks where a dispute is from
+//! these notifications and which actions are open to the merchant, such as accepting
+//! or defending it through the Disputes API.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_webhooks::dispute::{DisputeAction, DisputeLifecycle, DisputeState};
+//! use adyen_webhooks::EventCode;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut dispute = DisputeLifecycle::new();
+//! dispute.apply(&EventCode::NotificationOfChargeback)?;
+//! dispute.apply(&EventCode::Chargeback)?;
+//! assert_eq!(dispute.state(), Some(DisputeState::Chargeback));
+//! assert!(dispute.allowed_actions().contains(&DisputeAction::Defend));
+//!
+//! dispute.apply(&EventCode::ChargebackReversed)?;
+//! assert!(dispute.allowed_actions().is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{EventCode, NotificationRequestItem};
+
+/// Stage of a dispute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisputeState {
+    /// The issuer announced a chargeback (`NOTIFICATION_OF_CHARGEBACK`); the funds
+    /// have not been debited yet.
+    Notified,
+    /// The funds were debited from the merchant (`CHARGEBACK`).
+    Chargeback,
+    /// The defense succeeded and the funds were returned (`CHARGEBACK_REVERSED`).
+    Reversed,
+    /// The issuer disputed the reversal and debited the funds again
+    /// (`SECOND_CHARGEBACK`).
+    SecondChargeback,
+    /// The merchant won the pre-arbitration case (`PREARBITRATION_WON`).
+    PrearbitrationWon,
+    /// The merchant lost the pre-arbitration case (`PREARBITRATION_LOST`).
+    PrearbitrationLost,
+}
+
+impl DisputeState {
+    /// Get the state an event code moves a dispute to, or `None` if the event is not
+    /// part of the chargeback lifecycle.
+    #[must_use]
+    pub fn from_event_code(event_code: &EventCode) -> Option<Self> {
+        match event_code {
+            EventCode::NotificationOfChargeback => Some(Self::Notified),
+            EventCode::Chargeback => Some(Self::Chargeback),
+            EventCode::ChargebackReversed => Some(Self::Reversed),
+            EventCode::SecondChargeback => Some(Self::SecondChargeback),
+            EventCode::PrearbitrationWon => Some(Self::PrearbitrationWon),
+            EventCode::PrearbitrationLost => Some(Self::PrearbitrationLost),
+            _ => None,
+        }
+    }
+
+    /// Get the actions open to the merchant in this state.
+    #[must_use]
+    pub const fn allowed_actions(self) -> &'static [DisputeAction] {
+        match self {
+            Self::Notified | Self::Chargeback | Self::SecondChargeback => {
+                &[DisputeAction::Accept, DisputeAction::Defend]
+            }
+            Self::Reversed | Self::PrearbitrationWon | Self::PrearbitrationLost => &[],
+        }
+    }
+
+    /// Check if the dispute can no longer change after this state.
+    #[must_use]
+    pub const fn is_final(self) -> bool {
+        matches!(self, Self::PrearbitrationWon | Self::PrearbitrationLost)
+    }
+
+    /// Check if the disputed funds are currently debited from the merchant.
+    #[must_use]
+    pub const fn funds_debited(self) -> bool {
+        matches!(
+            self,
+            Self::Chargeback | Self::SecondChargeback | Self::PrearbitrationLost
+        )
+    }
+
+    /// Check if the dispute can move from this state to `next`.
+    #[must_use]
+    pub const fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Notified, Self::Chargeback)
+                | (
+                    Self::Chargeback | Self::SecondChargeback,
+                    Self::Reversed | Self::PrearbitrationWon | Self::PrearbitrationLost
+                )
+                | (
+                    Self::Reversed,
+                    Self::SecondChargeback | Self::PrearbitrationWon | Self::PrearbitrationLost
+                )
+        )
+    }
+}
+
+/// Action a merchant can take on a dispute through the Disputes API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisputeAction {
+    /// Accept the dispute and its loss.
+    Accept,
+    /// Supply defense documents and defend the dispute.
+    Defend,
+}
+
+/// Error applying a notification to a [`DisputeLifecycle`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DisputeError {
+    /// The event code is not part of the chargeback lifecycle.
+    #[error("{0} is not a chargeback lifecycle event")]
+    NotDisputeEvent(EventCode),
+    /// The event cannot follow the current state of the dispute.
+    #[error("Dispute cannot move from {from:?} to {to:?}")]
+    InvalidTransition {
+        /// Current state of the dispute, `None` if no event was applied yet.
+        from: Option<DisputeState>,
+        /// State the event would move the dispute to.
+        to: DisputeState,
+    },
+}
+
+/// State machine tracking one dispute.
+///
+/// Apply the dispute notifications of a payment in the order they arrive. Adyen
+/// retries webhooks and does not guarantee their order, so an event repeating the
+/// current state, or a `NOTIFICATION_OF_CHARGEBACK` arriving after the chargeback,
+/// leaves the state unchanged instead of failing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DisputeLifecycle {
+    state: Option<DisputeState>,
+}
+
+impl DisputeLifecycle {
+    /// Create a lifecycle for a payment without dispute events yet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { state: None }
+    }
+
+    /// Resume a lifecycle from a stored state.
+    #[must_use]
+    pub const fn from_state(state: DisputeState) -> Self {
+        Self { state: Some(state) }
+    }
+
+    /// Get the current state, or `None` if no event was applied yet.
+    #[must_use]
+    pub const fn state(&self) -> Option<DisputeState> {
+        self.state
+    }
+
+    /// Get the actions open to the merchant in the current state.
+    #[must_use]
+    pub const fn allowed_actions(&self) -> &'static [DisputeAction] {
+        match self.state {
+            Some(state) => state.allowed_actions(),
+            None => &[],
+        }
+    }
+
+    /// Check if the dispute reached a final state.
+    #[must_use]
+    pub const fn is_final(&self) -> bool {
+        match self.state {
+            Some(state) => state.is_final(),
+            None => false,
+        }
+    }
+
+    /// Apply a dispute event and return the new state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DisputeError::NotDisputeEvent`] if the event code is not a chargeback
+    /// lifecycle event, or [`DisputeError::InvalidTransition`] if it cannot follow the
+    /// current state. The state is unchanged on error.
+    pub fn apply(&mut self, event_code: &EventCode) -> Result<DisputeState, DisputeError> {
+        let to = DisputeState::from_event_code(event_code)
+            .ok_or_else(|| DisputeError::NotDisputeEvent(event_code.clone()))?;
+
+        let next = match self.state {
+            None if matches!(to, DisputeState::Notified | DisputeState::Chargeback) => to,
+            Some(from) if from == to || to == DisputeState::Notified => from,
+            Some(from) if from.can_transition_to(to) => to,
+            from => return Err(DisputeError::InvalidTransition { from, to }),
+        };
+        self.state = Some(next);
+        Ok(next)
+    }
+
+    /// Apply a dispute notification and return the new state.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`apply`](Self::apply).
+    pub fn apply_notification(
+        &mut self,
+        item: &NotificationRequestItem,
+    ) -> Result<DisputeState, DisputeError> {
+        self.apply(&item.event_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_lifecycle() {
+        let mut dispute = DisputeLifecycle::new();
+        assert!(dispute.allowed_actions().is_empty());
+
+        let steps = [
+            (EventCode::NotificationOfChargeback, DisputeState::Notified),
+            (EventCode::Chargeback, DisputeState::Chargeback),
+            (EventCode::ChargebackReversed, DisputeState::Reversed),
+            (EventCode::SecondChargeback, DisputeState::SecondChargeback),
+            (
+                EventCode::PrearbitrationLost,
+                DisputeState::PrearbitrationLost,
+            ),
+        ];
+        for (event_code, state) in steps {
+            assert_eq!(dispute.apply(&event_code), Ok(state));
+        }
+        assert!(dispute.is_final());
+        assert!(dispute.allowed_actions().is_empty());
+        assert!(DisputeState::PrearbitrationLost.funds_debited());
+    }
+
+    #[test]
+    fn test_allowed_actions() {
+        let dispute = DisputeLifecycle::from_state(DisputeState::Chargeback);
+        assert_eq!(
+            dispute.allowed_actions(),
+            [DisputeAction::Accept, DisputeAction::Defend]
+        );
+        assert!(DisputeState::Reversed.allowed_actions().is_empty());
+        assert!(!DisputeState::Reversed.funds_debited());
+    }
+
+    #[test]
+    fn test_retried_and_late_events_keep_state() {
+        let mut dispute = DisputeLifecycle::new();
+        dispute.apply(&EventCode::Chargeback).unwrap();
+        assert_eq!(
+            dispute.apply(&EventCode::Chargeback),
+            Ok(DisputeState::Chargeback)
+        );
+        assert_eq!(
+            dispute.apply(&EventCode::NotificationOfChargeback),
+            Ok(DisputeState::Chargeback)
+        );
+    }
+
+    #[test]
+    fn test_invalid_events() {
+        let mut dispute = DisputeLifecycle::new();
+        assert_eq!(
+            dispute.apply(&EventCode::Capture),
+            Err(DisputeError::NotDisputeEvent(EventCode::Capture))
+        );
+        assert_eq!(
+            dispute.apply(&EventCode::ChargebackReversed),
+            Err(DisputeError::InvalidTransition {
+                from: None,
+                to: DisputeState::Reversed,
+            })
+        );
+
+        let mut dispute = DisputeLifecycle::from_state(DisputeState::PrearbitrationWon);
+        assert!(dispute.apply(&EventCode::SecondChargeback).is_err());
+        assert_eq!(dispute.state(), Some(DisputeState::PrearbitrationWon));
+    }
+}
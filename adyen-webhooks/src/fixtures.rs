@@ -0,0 +1,315 @@
+//! Signed webhook fixtures for testing.
+//!
+//! This module is available with the `testing` feature. [`WebhookFixtures`] builds
+//! realistic notification items for the common events of a card payment, signed with
+//! a given HMAC key, so integration tests of downstream systems can post webhooks
+//! that pass validation without waiting for Adyen to send them.
+//!
+//! Modification events refer to the payment they modify through their
+//! `originalReference`, so build them from the authorisation they belong to.
+//!
+//! # Example
+//!
+//! ```rust
+//! use adyen_webhooks::fixtures::WebhookFixtures;
+//! use adyen_webhooks::types::Amount;
+//! use adyen_webhooks::HmacValidator;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+//! let fixtures = WebhookFixtures::new(key)?.merchant_account("TestMerchant");
+//!
+//! let authorisation = fixtures.authorisation("order-1", Amount::new(1000, "EUR"));
+//! let capture = fixtures.capture(&authorisation);
+//! let webhook = fixtures.webhook([authorisation, capture]);
+//!
+//! assert!(webhook.validate_all(&HmacValidator::new(key)?).all_valid());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{Amount, EventCode, NotificationItem, NotificationRequestItem, Webhook};
+use crate::validation::{HmacValidator, ValidationError};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// First PSP reference handed out by [`WebhookFixtures`].
+const FIRST_PSP_REFERENCE: u64 = 8_815_000_000_000_001;
+
+/// Builder of signed notification items.
+///
+/// Every item gets a new 16-digit PSP reference and is signed with the first key of
+/// the validator. The `eventDate` is the time the item was built; it is not part of
+/// the signature, so tests can change it freely. Changing any other field requires
+/// signing the item again with [`HmacValidator::sign_notification`].
+#[derive(Debug)]
+pub struct WebhookFixtures {
+    validator: HmacValidator,
+    merchant_account: String,
+    next_psp_reference: AtomicU64,
+}
+
+impl WebhookFixtures {
+    /// Create fixtures signed with the given hex-encoded HMAC key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not valid hex.
+    pub fn new(hmac_key: &str) -> Result<Self, ValidationError> {
+        Ok(Self::with_validator(HmacValidator::new(hmac_key)?))
+    }
+
+    /// Create fixtures signed with the first key of `validator`.
+    #[must_use]
+    pub fn with_validator(validator: HmacValidator) -> Self {
+        Self {
+            validator,
+            merchant_account: "TestMerchant".to_string(),
+            next_psp_reference: AtomicU64::new(FIRST_PSP_REFERENCE),
+        }
+    }
+
+    /// Set the merchant account of the items, `TestMerchant` by default.
+    #[must_use]
+    pub fn merchant_account(mut self, merchant_account: impl Into<String>) -> Self {
+        self.merchant_account = merchant_account.into();
+        self
+    }
+
+    /// Build a successful `AUTHORISATION` of a Visa card payment.
+    #[must_use]
+    pub fn authorisation(
+        &self,
+        merchant_reference: &str,
+        amount: Amount,
+    ) -> NotificationRequestItem {
+        let additional_data = [
+            ("authCode", "054321"),
+            ("cardSummary", "1111"),
+            ("cardBin", "411111"),
+            ("expiryDate", "03/2030"),
+            ("issuerCountry", "NL"),
+            ("paymentMethodVariant", "visa"),
+        ];
+        let item = NotificationRequestItem {
+            additional_data: Some(string_map(additional_data)),
+            amount,
+            event_code: EventCode::Authorisation,
+            event_date: Some(Utc::now()),
+            merchant_account_code: self.merchant_account.clone(),
+            merchant_reference: merchant_reference.to_string(),
+            operations: vec![
+                "CANCEL".to_string(),
+                "CAPTURE".to_string(),
+                "REFUND".to_string(),
+            ],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: self.psp_reference(),
+            reason: "054321:1111:03/2030".to_string(),
+            success: "true".to_string(),
+        };
+        self.sign(&item)
+    }
+
+    /// Build a successful `CAPTURE` of the full amount of an authorisation.
+    #[must_use]
+    pub fn capture(&self, authorisation: &NotificationRequestItem) -> NotificationRequestItem {
+        let item = self.modification(
+            authorisation,
+            EventCode::Capture,
+            authorisation.amount.clone(),
+        );
+        self.sign(&item)
+    }
+
+    /// Build a successful `REFUND` of `amount` of an authorisation.
+    #[must_use]
+    pub fn refund(
+        &self,
+        authorisation: &NotificationRequestItem,
+        amount: Amount,
+    ) -> NotificationRequestItem {
+        let item = self.modification(authorisation, EventCode::Refund, amount);
+        self.sign(&item)
+    }
+
+    /// Build a `CHARGEBACK` of the full amount of an authorisation, for a Visa
+    /// "merchandise not received" dispute.
+    #[must_use]
+    pub fn chargeback(&self, authorisation: &NotificationRequestItem) -> NotificationRequestItem {
+        let mut item = self.modification(
+            authorisation,
+            EventCode::Chargeback,
+            authorisation.amount.clone(),
+        );
+        item.additional_data = Some(string_map([
+            ("chargebackReasonCode", "13.1"),
+            ("chargebackSchemeCode", "visa"),
+            ("defendable", "true"),
+            ("disputeStatus", "Undefended"),
+        ]));
+        item.reason = "Merchandise/Services Not Received".to_string();
+        self.sign(&item)
+    }
+
+    /// Build a `RECURRING_CONTRACT` storing the card of an authorisation for
+    /// `shopper_reference`.
+    ///
+    /// As sent by Adyen, the PSP reference of the item is the reference of the stored
+    /// payment details.
+    #[must_use]
+    pub fn recurring_contract(
+        &self,
+        authorisation: &NotificationRequestItem,
+        shopper_reference: &str,
+    ) -> NotificationRequestItem {
+        let mut item = self.modification(
+            authorisation,
+            EventCode::RecurringContract,
+            authorisation.amount.clone(),
+        );
+        let mut additional_data = string_map([
+            (
+                "recurring.recurringDetailReference",
+                item.psp_reference.as_str(),
+            ),
+            ("recurring.shopperReference", shopper_reference),
+        ]);
+        additional_data.insert(
+            "shopperReference".to_string(),
+            serde_json::Value::String(shopper_reference.to_string()),
+        );
+        item.additional_data = Some(additional_data);
+        self.sign(&item)
+    }
+
+    /// Wrap items in a test-environment webhook request.
+    #[must_use]
+    pub fn webhook(&self, items: impl IntoIterator<Item = NotificationRequestItem>) -> Webhook {
+        Webhook {
+            live: "false".to_string(),
+            notification_items: items
+                .into_iter()
+                .map(|notification_request_item| NotificationItem {
+                    notification_request_item,
+                })
+                .collect(),
+        }
+    }
+
+    /// Build an unsigned, successful event modifying `authorisation`.
+    fn modification(
+        &self,
+        authorisation: &NotificationRequestItem,
+        event_code: EventCode,
+        amount: Amount,
+    ) -> NotificationRequestItem {
+        NotificationRequestItem {
+            additional_data: None,
+            amount,
+            event_code,
+            event_date: Some(Utc::now()),
+            merchant_account_code: authorisation.merchant_account_code.clone(),
+            merchant_reference: authorisation.merchant_reference.clone(),
+            operations: vec![],
+            original_reference: Some(authorisation.psp_reference.clone()),
+            payment_method: authorisation.payment_method.clone(),
+            psp_reference: self.psp_reference(),
+            reason: String::new(),
+            success: "true".to_string(),
+        }
+    }
+
+    fn psp_reference(&self) -> String {
+        self.next_psp_reference
+            .fetch_add(1, Ordering::Relaxed)
+            .to_string()
+    }
+
+    fn sign(&self, item: &NotificationRequestItem) -> NotificationRequestItem {
+        self.validator
+            .sign_notification(item)
+            .expect("HMAC-SHA256 accepts keys of any length")
+    }
+}
+
+fn string_map<'a>(
+    entries: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> HashMap<String, serde_json::Value> {
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            (
+                key.to_string(),
+                serde_json::Value::String(value.to_string()),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+    #[test]
+    fn test_payment_flow_is_signed_and_linked() {
+        let fixtures = WebhookFixtures::new(TEST_HMAC_KEY)
+            .unwrap()
+            .merchant_account("ShopMerchant");
+        let authorisation = fixtures.authorisation("order-1", Amount::new(2500, "EUR"));
+        let items = [
+            fixtures.capture(&authorisation),
+            fixtures.refund(&authorisation, Amount::new(500, "EUR")),
+            fixtures.chargeback(&authorisation),
+            fixtures.recurring_contract(&authorisation, "shopper-1"),
+        ];
+
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        assert!(validator.validate_notification(&authorisation));
+        assert_eq!(authorisation.merchant_account_code, "ShopMerchant");
+        assert_eq!(authorisation.psp_reference.len(), 16);
+        for item in &items {
+            assert!(validator.validate_notification(item));
+            assert_eq!(
+                item.original_reference.as_deref(),
+                Some(&*authorisation.psp_reference)
+            );
+            assert_eq!(item.merchant_reference, "order-1");
+            assert_ne!(item.psp_reference, authorisation.psp_reference);
+        }
+
+        let [capture, refund, chargeback, recurring] = &items;
+        assert_eq!(capture.event_code, EventCode::Capture);
+        assert_eq!(capture.amount, authorisation.amount);
+        assert_eq!(refund.amount.value, 500);
+        let dispute = chargeback.additional_data_typed();
+        assert_eq!(dispute.chargeback_reason_code.as_deref(), Some("13.1"));
+        let stored = recurring.additional_data_typed();
+        assert_eq!(
+            stored.recurring_detail_reference.as_deref(),
+            Some(&*recurring.psp_reference)
+        );
+        assert_eq!(
+            stored.recurring_shopper_reference.as_deref(),
+            Some("shopper-1")
+        );
+    }
+
+    #[test]
+    fn test_webhook_round_trips() {
+        let fixtures = WebhookFixtures::new(TEST_HMAC_KEY).unwrap();
+        let authorisation = fixtures.authorisation("order-2", Amount::new(1000, "USD"));
+        let webhook = fixtures.webhook([authorisation.clone(), fixtures.capture(&authorisation)]);
+
+        let body = serde_json::to_string(&webhook).unwrap();
+        let parsed = crate::handle_webhook(&body).unwrap();
+        assert!(parsed.is_test());
+        assert_eq!(parsed.notification_items.len(), 2);
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        assert!(parsed.validate_all(&validator).all_valid());
+    }
+}
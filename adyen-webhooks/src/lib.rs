@@ -9,7 +9,18 @@
 //! - **HMAC Signature Validation**: Verify webhook authenticity using SHA-256 HMAC
 //! - **Type-Safe Event Handling**: Strongly typed webhook events and data structures
 //! - **Multiple Validation Methods**: Support for both payload and additional-data signatures
+//! - **Signing for Tests**: Generate signed webhooks to send to your own endpoints
+//! - **Fixtures**: Build signed payment, modification and dispute events with the `testing` feature
+//! - **Basic Authentication**: Constant-time checks of webhook endpoint credentials
 //! - **Complete Event Coverage**: All Adyen webhook event types supported
+//! - **Balance Platform and Management Webhooks**: Typed payloads signed over the raw body
+//! - **Event Dispatching**: Route notifications to async handlers per event code
+//! - **Responses**: The exact `[accepted]` body, and the status to reply with on errors
+//! - **Chargeback Lifecycle**: Track dispute state and the actions still open from dispute events
+//! - **Duplicate Detection**: Skip notifications Adyen delivers again on retry
+//! - **Borrowed Parsing**: Parse notifications without copying their strings
+//! - **SIMD Parsing**: Optional simd-json parsing of large notification batches
+//! - **Batch Validation**: Validate all items of a webhook at once, optionally in parallel
 //! - **Zero-Copy Processing**: Optional rkyv serialization for performance
 //!
 //! ## Quick Start
@@ -68,17 +79,53 @@
 //! }
 //! # }
 //! ```
+//!
+//! Balance Platform and Management API webhooks can be validated and parsed in one step:
+//!
+//! ```rust
+//! # use adyen_webhooks::{BalancePlatformEvent, BalancePlatformNotification, HmacValidator};
+//! # fn example(validator: HmacValidator, payload: &str, signature: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let notification: BalancePlatformNotification =
+//!     validator.parse_signed_payload(payload, signature)?;
+//! if let BalancePlatformEvent::Transfer(transfer) = notification.event()? {
+//!     println!("Transfer {} is {:?}", transfer.id, transfer.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 #![deny(missing_docs)]
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod balance_platform;
+pub mod borrowed;
+pub mod dedup;
+pub mod dispatcher;
+pub mod dispute;
+#[cfg(feature = "testing")]
+pub mod fixtures;
+pub mod management;
+pub mod response;
+pub mod security;
 pub mod types;
 pub mod validation;
 
 // Re-export main types for convenience
-pub use types::{EventCode, NotificationItem, NotificationRequestItem, Webhook};
-pub use validation::{HmacValidator, ValidationError};
+pub use balance_platform::{BalancePlatformEvent, BalancePlatformNotification};
+pub use borrowed::{AmountRef, NotificationItemRef, NotificationRequestItemRef, WebhookRef};
+pub use dedup::{InMemoryDeduplicator, NotificationDeduplicator, NotificationKey};
+pub use dispatcher::{DispatchError, HandlerResult, WebhookDispatcher, ACCEPTED_RESPONSE};
+pub use dispute::{DisputeAction, DisputeError, DisputeLifecycle, DisputeState};
+pub use management::{ManagementEvent, ManagementNotification};
+pub use response::{AcceptedResponse, WebhookResponse};
+pub use security::{BasicAuthValidator, WebhookSecurity};
+pub use types::{
+    AdditionalData, EventCode, NotificationItem, NotificationRequestItem, ValidatedItems, Webhook,
+};
+pub use validation::{
+    HmacKey, HmacValidator, SignedPayload, ValidationError, HMAC_SIGNATURE_HEADER,
+};
 
 /// Handle and parse a webhook request from JSON.
 ///
@@ -129,6 +176,30 @@ pub fn handle_webhook(json_payload: &str) -> Result<Webhook, serde_json::Error>
     serde_json::from_str(json_payload)
 }
 
+/// Parse a webhook request with SIMD-accelerated JSON parsing.
+///
+/// Like [`handle_webhook`], but faster for large notification batches. The
+/// parser rewrites `json_payload` in place, so pass a copy if the raw body is
+/// still needed, e.g. to validate a payload signature.
+///
+/// # Errors
+///
+/// Returns an error if the JSON payload cannot be parsed as a valid webhook.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_webhooks::handle_webhook_simd;
+///
+/// let mut body = br#"{"live": "false", "notificationItems": []}"#.to_vec();
+/// let webhook = handle_webhook_simd(&mut body).unwrap();
+/// assert!(webhook.is_test());
+/// ```
+#[cfg(feature = "simd-json")]
+pub fn handle_webhook_simd(json_payload: &mut [u8]) -> Result<Webhook, simd_json::Error> {
+    simd_json::serde::from_slice(json_payload)
+}
+
 /// Current version of the Adyen Webhooks library.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -165,7 +236,7 @@ mod tests {
         assert_eq!(webhook.notification_items.len(), 1);
 
         let item = &webhook.notification_items[0].notification_request_item;
-        assert_eq!(item.event_code, "AUTHORISATION");
+        assert_eq!(item.event_code, EventCode::Authorisation);
         assert!(item.is_success());
     }
 
@@ -175,6 +246,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_handle_webhook_simd() {
+        let webhook_json = r#"{
+            "live": "true",
+            "notificationItems": [
+                {
+                    "NotificationRequestItem": {
+                        "additionalData": {"hmacSignature": "c2lnbmF0dXJl", "totalFraudScore": 10},
+                        "amount": {"currency": "EUR", "value": 1000},
+                        "eventCode": "SOME_FUTURE_EVENT",
+                        "eventDate": "2024-01-15T10:30:00+01:00",
+                        "merchantAccountCode": "TestMerchant",
+                        "merchantReference": "test-payment-123",
+                        "paymentMethod": "visa",
+                        "pspReference": "8515131751004933",
+                        "reason": "Approved",
+                        "success": "true"
+                    }
+                }
+            ]
+        }"#;
+
+        let webhook = handle_webhook_simd(&mut webhook_json.as_bytes().to_vec()).unwrap();
+        let expected = handle_webhook(webhook_json).unwrap();
+        assert!(webhook.is_live());
+        assert_eq!(
+            serde_json::to_value(&webhook).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+        assert!(handle_webhook_simd(&mut b"invalid json".to_vec()).is_err());
+    }
+
     #[test]
     #[allow(clippy::const_is_empty)]
     fn test_library_version() {
@@ -0,0 +1,226 @@
+//! Management API webhook types.
+//!
+//! Management API webhooks report changes to merchant accounts and payment method
+//! settings. Like Balance Platform webhooks they are signed over the raw request body;
+//! validate and parse them with
+//! [`HmacValidator::parse_signed_payload`](crate::HmacValidator::parse_signed_payload).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Management API webhook payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagementNotification {
+    /// Type of webhook, e.g. `merchant.updated`.
+    pub r#type: ManagementEventType,
+    /// Environment the webhook was sent from, `test` or `live`.
+    pub environment: String,
+    /// When the event happened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// Event data, whose shape depends on the type.
+    pub data: serde_json::Value,
+}
+
+impl ManagementNotification {
+    /// Check if this webhook is from the live environment.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.environment == "live"
+    }
+
+    /// Parse the event data according to the webhook type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data does not match the shape expected for the type.
+    pub fn event(&self) -> Result<ManagementEvent, serde_json::Error> {
+        use ManagementEventType as Type;
+
+        let data = self.data.clone();
+        Ok(match self.r#type {
+            Type::MerchantCreated | Type::MerchantUpdated => {
+                ManagementEvent::Merchant(serde_json::from_value(data)?)
+            }
+            Type::PaymentMethodCreated
+            | Type::PaymentMethodRequestRemoved
+            | Type::PaymentMethodRequestScheduledForRemoval => {
+                ManagementEvent::PaymentMethod(serde_json::from_value(data)?)
+            }
+            Type::Unknown(_) => ManagementEvent::Other(data),
+        })
+    }
+}
+
+/// Types of Management API webhooks.
+///
+/// Types not known to this library deserialize into [`ManagementEventType::Unknown`],
+/// which keeps the original string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ManagementEventType {
+    /// A merchant account was created.
+    MerchantCreated,
+    /// A merchant account was updated, e.g. its status changed.
+    MerchantUpdated,
+    /// A payment method request was processed.
+    PaymentMethodCreated,
+    /// A payment method was removed.
+    PaymentMethodRequestRemoved,
+    /// A payment method is scheduled to be removed.
+    PaymentMethodRequestScheduledForRemoval,
+    /// A webhook type not known to this library.
+    Unknown(String),
+}
+
+impl ManagementEventType {
+    /// Get the type as sent by Adyen, e.g. `merchant.updated`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::MerchantCreated => "merchant.created",
+            Self::MerchantUpdated => "merchant.updated",
+            Self::PaymentMethodCreated => "paymentMethodCreated",
+            Self::PaymentMethodRequestRemoved => "paymentMethodRequestRemoved",
+            Self::PaymentMethodRequestScheduledForRemoval => {
+                "paymentMethodRequestScheduledForRemoval"
+            }
+            Self::Unknown(r#type) => r#type,
+        }
+    }
+}
+
+impl From<&str> for ManagementEventType {
+    fn from(r#type: &str) -> Self {
+        match r#type {
+            "merchant.created" => Self::MerchantCreated,
+            "merchant.updated" => Self::MerchantUpdated,
+            "paymentMethodCreated" => Self::PaymentMethodCreated,
+            "paymentMethodRequestRemoved" => Self::PaymentMethodRequestRemoved,
+            "paymentMethodRequestScheduledForRemoval" => {
+                Self::PaymentMethodRequestScheduledForRemoval
+            }
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ManagementEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ManagementEventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ManagementEventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let r#type = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(r#type.as_ref()))
+    }
+}
+
+/// Typed data of a Management API webhook.
+#[derive(Debug, Clone)]
+pub enum ManagementEvent {
+    /// Merchant account created or updated.
+    Merchant(MerchantNotificationData),
+    /// Payment method created, removed or scheduled for removal.
+    PaymentMethod(PaymentMethodNotificationData),
+    /// Data of a webhook type not known to this library.
+    Other(serde_json::Value),
+}
+
+/// Data of `merchant.*` webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantNotificationData {
+    /// Merchant account ID.
+    pub merchant_id: String,
+    /// Company account the merchant account belongs to.
+    pub company_id: Option<String>,
+    /// Legal entity linked to the merchant account.
+    pub legal_entity_id: Option<String>,
+    /// Status, e.g. `PreActive` or `Active`.
+    pub status: Option<String>,
+    /// Capabilities and their status, keyed by capability name.
+    pub capabilities: Option<serde_json::Value>,
+}
+
+/// Data of payment method webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodNotificationData {
+    /// Payment method ID.
+    pub id: String,
+    /// Merchant account the payment method belongs to.
+    pub merchant_id: String,
+    /// Store the payment method belongs to, if any.
+    pub store_id: Option<String>,
+    /// Payment method type, e.g. `visa`.
+    pub r#type: Option<String>,
+    /// Result of the request, e.g. `SUCCESS` or `FAILURE`.
+    pub result: Option<String>,
+    /// Status of the payment method.
+    pub status: Option<String>,
+    /// Whether the payment method is enabled.
+    pub enabled: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merchant_webhook() {
+        let notification: ManagementNotification = serde_json::from_str(
+            r#"{
+                "createdAt": "2024-03-05T10:30:00Z",
+                "data": {
+                    "merchantId": "MC3224X22322535GH8D537TJR",
+                    "companyId": "CA00000000000000000000001",
+                    "status": "Active"
+                },
+                "environment": "live",
+                "type": "merchant.updated"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(notification.r#type, ManagementEventType::MerchantUpdated);
+        assert!(notification.is_live());
+        let ManagementEvent::Merchant(merchant) = notification.event().unwrap() else {
+            panic!("expected a merchant event");
+        };
+        assert_eq!(merchant.merchant_id, "MC3224X22322535GH8D537TJR");
+        assert_eq!(merchant.status.as_deref(), Some("Active"));
+    }
+
+    #[test]
+    fn test_payment_method_webhook() {
+        let notification: ManagementNotification = serde_json::from_str(
+            r#"{
+                "data": {
+                    "id": "PM3224R223224K5FH4M2K9B86",
+                    "merchantId": "MERCHANT_ACCOUNT",
+                    "result": "SUCCESS",
+                    "type": "visa"
+                },
+                "environment": "test",
+                "type": "paymentMethodCreated"
+            }"#,
+        )
+        .unwrap();
+        let ManagementEvent::PaymentMethod(method) = notification.event().unwrap() else {
+            panic!("expected a payment method event");
+        };
+        assert_eq!(method.result.as_deref(), Some("SUCCESS"));
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap()["type"],
+            "paymentMethodCreated"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Responses to send back to Adyen for a webhook request.
+//!
+//! Adyen marks a webhook as delivered only when the endpoint answers with a 2xx status
+//! and the `[accepted]` body; anything else is retried. [`AcceptedResponse`] gives the
+//! exact body in plain-text or JSON form, and [`WebhookResponse`] maps validation and
+//! dispatch errors to the status to reply with, so the response can be written with
+//! any HTTP framework.
+
+use crate::dispatcher::{DispatchError, ACCEPTED_RESPONSE};
+use crate::types::Webhook;
+use crate::validation::ValidationError;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+/// Body of the response acknowledging a webhook.
+///
+/// Serializes to the `"[accepted]"` string or to
+/// `{"notificationResponse":"[accepted]"}`, so it can also be returned through a
+/// framework's JSON response type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AcceptedResponse {
+    /// The plain-text body `[accepted]`.
+    #[default]
+    Text,
+    /// The JSON body `{"notificationResponse":"[accepted]"}`.
+    Json,
+}
+
+impl AcceptedResponse {
+    /// The HTTP status code of the response.
+    #[must_use]
+    pub const fn status(self) -> u16 {
+        200
+    }
+
+    /// The response body.
+    #[must_use]
+    pub const fn body(self) -> &'static str {
+        match self {
+            Self::Text => ACCEPTED_RESPONSE,
+            Self::Json => r#"{"notificationResponse":"[accepted]"}"#,
+        }
+    }
+
+    /// The value of the `Content-Type` header of the response.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Text => "text/plain",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+impl std::fmt::Display for AcceptedResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.body())
+    }
+}
+
+impl Serialize for AcceptedResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Text => serializer.serialize_str(ACCEPTED_RESPONSE),
+            Self::Json => {
+                let mut state = serializer.serialize_struct("AcceptedResponse", 1)?;
+                state.serialize_field("notificationResponse", ACCEPTED_RESPONSE)?;
+                state.end()
+            }
+        }
+    }
+}
+
+impl Webhook {
+    /// Get the response acknowledging a webhook, the plain-text `[accepted]` body.
+    ///
+    /// Send it only after the notification items were stored or processed; Adyen
+    /// does not deliver an accepted webhook again.
+    #[must_use]
+    pub const fn accepted_response() -> AcceptedResponse {
+        AcceptedResponse::Text
+    }
+}
+
+/// Response to send for a webhook request, including rejections.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_webhooks::{WebhookDispatcher, WebhookResponse};
+///
+/// # async fn example(dispatcher: WebhookDispatcher, body: &str) {
+/// let response = match dispatcher.dispatch(body).await {
+///     Ok(_) => WebhookResponse::accepted(),
+///     Err(error) => WebhookResponse::from(&error),
+/// };
+/// // Write `response.status()`, `response.content_type()` and `response.body()`
+/// // with your HTTP framework.
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookResponse {
+    /// The webhook was accepted; Adyen will not send it again.
+    Accepted(AcceptedResponse),
+    /// Basic auth credentials or an HMAC signature are missing or invalid (401).
+    ///
+    /// Adyen retries the webhook, so a misconfigured key shows up as failed
+    /// deliveries in the Customer Area instead of lost events.
+    Unauthorized,
+    /// The payload is not a valid webhook (400).
+    BadRequest,
+    /// The webhook could not be processed, e.g. a handler failed (500). Adyen
+    /// retries the webhook.
+    ServerError,
+}
+
+impl WebhookResponse {
+    /// The plain-text `[accepted]` response.
+    #[must_use]
+    pub const fn accepted() -> Self {
+        Self::Accepted(AcceptedResponse::Text)
+    }
+
+    /// The HTTP status code of the response.
+    #[must_use]
+    pub const fn status(self) -> u16 {
+        match self {
+            Self::Accepted(accepted) => accepted.status(),
+            Self::Unauthorized => 401,
+            Self::BadRequest => 400,
+            Self::ServerError => 500,
+        }
+    }
+
+    /// The response body.
+    #[must_use]
+    pub const fn body(self) -> &'static str {
+        match self {
+            Self::Accepted(accepted) => accepted.body(),
+            Self::Unauthorized => "Unauthorized",
+            Self::BadRequest => "Bad Request",
+            Self::ServerError => "Internal Server Error",
+        }
+    }
+
+    /// The value of the `Content-Type` header of the response.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Accepted(accepted) => accepted.content_type(),
+            Self::Unauthorized | Self::BadRequest | Self::ServerError => "text/plain",
+        }
+    }
+
+    /// Check if the response acknowledges the webhook.
+    #[must_use]
+    pub const fn is_accepted(self) -> bool {
+        matches!(self, Self::Accepted(_))
+    }
+}
+
+impl From<AcceptedResponse> for WebhookResponse {
+    fn from(accepted: AcceptedResponse) -> Self {
+        Self::Accepted(accepted)
+    }
+}
+
+impl From<&ValidationError> for WebhookResponse {
+    fn from(error: &ValidationError) -> Self {
+        match error {
+            ValidationError::InvalidCredentials
+            | ValidationError::MissingSignature
+            | ValidationError::MalformedSignature(_)
+            | ValidationError::SignatureLength { .. }
+            | ValidationError::InvalidSignature => Self::Unauthorized,
+            ValidationError::InvalidPayload(_) => Self::BadRequest,
+            ValidationError::InvalidKey(_) | ValidationError::HmacError(_) => Self::ServerError,
+        }
+    }
+}
+
+impl From<&DispatchError> for WebhookResponse {
+    fn from(error: &DispatchError) -> Self {
+        match error {
+            DispatchError::Parse(_) => Self::BadRequest,
+            DispatchError::InvalidSignature { .. } => Self::Unauthorized,
+            DispatchError::Handler { .. } => Self::ServerError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepted_response_bodies() {
+        let text = Webhook::accepted_response();
+        assert_eq!(text.body(), "[accepted]");
+        assert_eq!(text.to_string(), "[accepted]");
+        assert_eq!(text.content_type(), "text/plain");
+        assert_eq!(serde_json::to_string(&text).unwrap(), r#""[accepted]""#);
+
+        let json = AcceptedResponse::Json;
+        assert_eq!(serde_json::to_string(&json).unwrap(), json.body());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json.body()).unwrap(),
+            serde_json::json!({"notificationResponse": "[accepted]"})
+        );
+        assert_eq!(json.content_type(), "application/json");
+    }
+
+    #[test]
+    fn test_error_responses() {
+        let response = WebhookResponse::from(&ValidationError::InvalidSignature);
+        assert_eq!(response.status(), 401);
+        assert!(!response.is_accepted());
+        assert_eq!(
+            WebhookResponse::from(&ValidationError::InvalidCredentials),
+            WebhookResponse::Unauthorized
+        );
+
+        let parse_error = serde_json::from_str::<Webhook>("not json").unwrap_err();
+        assert_eq!(
+            WebhookResponse::from(&DispatchError::Parse(parse_error)).status(),
+            400
+        );
+        let handler_error = DispatchError::Handler {
+            event_code: crate::EventCode::Capture,
+            psp_reference: "8515131751004933".to_string(),
+            source: "database unavailable".into(),
+        };
+        assert_eq!(WebhookResponse::from(&handler_error).status(), 500);
+
+        let accepted = WebhookResponse::from(AcceptedResponse::Json);
+        assert_eq!(accepted.status(), 200);
+        assert!(accepted.is_accepted());
+        assert_eq!(WebhookResponse::accepted().body(), "[accepted]");
+    }
+}
@@ -0,0 +1,279 @@
+//! Basic authentication for webhook endpoints.
+//!
+//! Webhooks can be configured in the Customer Area to send basic auth credentials in
+//! addition to the HMAC signature. [`BasicAuthValidator`] checks the `Authorization`
+//! header, and [`WebhookSecurity`] checks both the header and the HMAC signatures of
+//! a webhook in one call.
+
+use crate::types::Webhook;
+use crate::validation::{constant_time_eq, HmacValidator, ValidationError};
+use adyen_core::SecretString;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+/// Validator for the basic auth credentials sent with webhooks.
+///
+/// Credentials are compared in constant time, and the password is redacted in
+/// `Debug` output.
+#[derive(Debug, Clone)]
+pub struct BasicAuthValidator {
+    username: String,
+    password: SecretString,
+}
+
+impl BasicAuthValidator {
+    /// Create a validator expecting the given username and password.
+    #[must_use]
+    pub fn new(username: impl Into<String>, password: impl Into<SecretString>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Validate the value of an `Authorization` header, e.g. `Basic dXNlcjpwYXNz`.
+    #[must_use]
+    pub fn validate_header(&self, authorization: &str) -> bool {
+        let Some((scheme, encoded)) = authorization.trim().split_once(' ') else {
+            return false;
+        };
+        if !scheme.eq_ignore_ascii_case("basic") {
+            return false;
+        }
+        let Ok(decoded) = BASE64.decode(encoded.trim()) else {
+            return false;
+        };
+        let Ok(decoded) = String::from_utf8(decoded) else {
+            return false;
+        };
+        let Some((username, password)) = decoded.split_once(':') else {
+            return false;
+        };
+        self.validate_credentials(username, password)
+    }
+
+    /// Validate a username and password.
+    #[must_use]
+    pub fn validate_credentials(&self, username: &str, password: &str) -> bool {
+        // Compare both parts so the time taken does not reveal which one is wrong.
+        let username_matches = constant_time_eq(username.as_bytes(), self.username.as_bytes());
+        let password_matches = constant_time_eq(
+            password.as_bytes(),
+            self.password.expose_secret().as_bytes(),
+        );
+        username_matches & password_matches
+    }
+}
+
+/// Combined basic auth and HMAC validation for webhook endpoints.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen_webhooks::{BasicAuthValidator, HmacValidator, WebhookSecurity};
+///
+/// # fn example(authorization: Option<&str>, body: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let security = WebhookSecurity::new(
+///     BasicAuthValidator::new("adyen", "webhook-password"),
+///     HmacValidator::new("44782DEF547AAA06")?,
+/// );
+///
+/// // Reject the request unless both the credentials and every signature are valid.
+/// let webhook = security.validate(authorization, body)?;
+/// for item in webhook.get_notification_items() {
+///     println!("{} for {}", item.event_code, item.psp_reference);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookSecurity {
+    basic_auth: BasicAuthValidator,
+    hmac: HmacValidator,
+}
+
+impl WebhookSecurity {
+    /// Create a validator checking both basic auth and HMAC signatures.
+    #[must_use]
+    pub fn new(basic_auth: BasicAuthValidator, hmac: HmacValidator) -> Self {
+        Self { basic_auth, hmac }
+    }
+
+    /// Get the HMAC validator.
+    #[must_use]
+    pub fn hmac(&self) -> &HmacValidator {
+        &self.hmac
+    }
+
+    /// Validate the `Authorization` header and the signature of every notification
+    /// item, and return the parsed webhook.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidCredentials`] if the header is missing or
+    /// wrong, [`ValidationError::InvalidPayload`] if the payload is not a webhook, or
+    /// the errors of [`HmacValidator::validate_notification_strict`] for the first
+    /// item with an invalid signature.
+    pub fn validate(
+        &self,
+        authorization: Option<&str>,
+        payload: &str,
+    ) -> Result<Webhook, ValidationError> {
+        self.validate_authorization(authorization)?;
+
+        let webhook: Webhook = serde_json::from_str(payload)?;
+        for item in webhook.get_notification_items() {
+            self.hmac.validate_notification_strict(item)?;
+        }
+        Ok(webhook)
+    }
+
+    /// Validate the `Authorization` header and the signature of a raw payload, and
+    /// parse it.
+    ///
+    /// Used for Balance Platform and Management API webhooks, which are signed in the
+    /// [`HMAC_SIGNATURE_HEADER`](crate::HMAC_SIGNATURE_HEADER) header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidCredentials`] if the header is missing or
+    /// wrong, and otherwise the errors of
+    /// [`HmacValidator::parse_signed_payload`].
+    pub fn validate_signed_payload<T: serde::de::DeserializeOwned>(
+        &self,
+        authorization: Option<&str>,
+        payload: &str,
+        signature: &str,
+    ) -> Result<T, ValidationError> {
+        self.validate_authorization(authorization)?;
+        self.hmac.parse_signed_payload(payload, signature)
+    }
+
+    fn validate_authorization(&self, authorization: Option<&str>) -> Result<(), ValidationError> {
+        if authorization.is_some_and(|header| self.basic_auth.validate_header(header)) {
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidCredentials)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Amount, EventCode, NotificationItem, NotificationRequestItem};
+    use std::collections::HashMap;
+
+    const TEST_HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+    fn authorization(credentials: &str) -> String {
+        format!("Basic {}", BASE64.encode(credentials))
+    }
+
+    fn security() -> WebhookSecurity {
+        WebhookSecurity::new(
+            BasicAuthValidator::new("adyen", "s3cret:pass"),
+            HmacValidator::new(TEST_HMAC_KEY).unwrap(),
+        )
+    }
+
+    fn webhook_payload(signature: Option<String>) -> String {
+        let mut item = NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::Authorisation,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "order-123".to_string(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: "PSP1".to_string(),
+            reason: String::new(),
+            success: "true".to_string(),
+        };
+        let signature = signature.unwrap_or_else(|| {
+            HmacValidator::new(TEST_HMAC_KEY)
+                .unwrap()
+                .calculate_notification_signature(&item)
+                .unwrap()
+        });
+        item.additional_data = Some(HashMap::from([(
+            "hmacSignature".to_string(),
+            serde_json::Value::String(signature),
+        )]));
+        serde_json::to_string(&Webhook {
+            live: "false".to_string(),
+            notification_items: vec![NotificationItem {
+                notification_request_item: item,
+            }],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_basic_auth_header() {
+        let validator = BasicAuthValidator::new("adyen", "s3cret:pass");
+
+        // Passwords may contain colons; only the first one separates the username.
+        assert!(validator.validate_header(&authorization("adyen:s3cret:pass")));
+        assert!(
+            validator.validate_header(&format!("basic  {}", BASE64.encode("adyen:s3cret:pass")))
+        );
+        assert!(!validator.validate_header(&authorization("adyen:wrong")));
+        assert!(!validator.validate_header(&authorization("other:s3cret:pass")));
+        assert!(!validator.validate_header("Bearer token"));
+        assert!(!validator.validate_header("Basic not-base64!"));
+        assert!(!format!("{validator:?}").contains("s3cret"));
+    }
+
+    #[test]
+    fn test_webhook_security_validates_both() {
+        let security = security();
+        let header = authorization("adyen:s3cret:pass");
+
+        let webhook = security
+            .validate(Some(&header), &webhook_payload(None))
+            .unwrap();
+        assert_eq!(webhook.notification_items.len(), 1);
+
+        assert!(matches!(
+            security.validate(None, &webhook_payload(None)),
+            Err(ValidationError::InvalidCredentials)
+        ));
+        assert!(matches!(
+            security.validate(
+                Some(&header),
+                &webhook_payload(Some(BASE64.encode([7u8; 32])))
+            ),
+            Err(ValidationError::InvalidSignature)
+        ));
+        assert!(matches!(
+            security.validate(Some(&header), "not json"),
+            Err(ValidationError::InvalidPayload(_))
+        ));
+    }
+
+    #[test]
+    fn test_webhook_security_signed_payload() {
+        let security = security();
+        let header = authorization("adyen:s3cret:pass");
+        let payload = r#"{"environment": "test"}"#;
+        let signature = security
+            .hmac()
+            .calculate_payload_signature(payload)
+            .unwrap();
+
+        let parsed: serde_json::Value = security
+            .validate_signed_payload(Some(&header), payload, &signature)
+            .unwrap();
+        assert_eq!(parsed["environment"], "test");
+        assert!(matches!(
+            security.validate_signed_payload::<serde_json::Value>(
+                Some(&authorization("adyen:wrong")),
+                payload,
+                &signature
+            ),
+            Err(ValidationError::InvalidCredentials)
+        ));
+    }
+}
@@ -3,6 +3,10 @@
 //! This module contains the fundamental types used across all Adyen webhook implementations,
 //! including the base webhook structure, notification items, and common event types.
 
+// rkyv's derive output for `EventCode::Unknown` binds its field as `_0`.
+#![cfg_attr(feature = "rkyv", allow(clippy::used_underscore_binding))]
+
+use crate::validation::HmacValidator;
 use adyen_core::Currency;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -15,7 +19,8 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
 pub struct Amount {
     /// The payment amount in minor units (e.g., cents).
@@ -35,7 +40,7 @@ impl Amount {
 
     /// Create an amount from major units (e.g., dollars).
     #[must_use]
-    pub fn from_major_units(major_units: i64, currency: Currency) -> Self {
+    pub fn from_major_units(major_units: i64, currency: &Currency) -> Self {
         Self {
             #[allow(clippy::cast_possible_wrap)]
             value: major_units * currency.minor_unit_multiplier() as i64,
@@ -113,7 +118,7 @@ pub struct NotificationRequestItem {
     /// The payment amount associated with this event.
     pub amount: Amount,
     /// The type of event that triggered this webhook.
-    pub event_code: String,
+    pub event_code: EventCode,
     /// The date and time when the event occurred.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_date: Option<DateTime<Utc>>,
@@ -142,13 +147,19 @@ pub struct NotificationRequestItem {
 ///
 /// This enum contains all the possible event types that Adyen can send,
 /// covering payments, modifications, disputes, and administrative events.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Codes not known to this library deserialize into [`EventCode::Unknown`],
+/// which keeps the original string. Unlike `#[serde(other)]`, this preserves
+/// the code exactly as sent, which HMAC validation depends on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
 )]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventCode {
+    /// Result of a scheduled Account Updater check of stored card details.
+    AccountUpdater,
     /// ACH notification of change event.
     AchNotificationOfChange,
     /// Initial payment authorization.
@@ -227,6 +238,159 @@ pub enum EventCode {
     OrderClosed,
     /// Order opened.
     OrderOpened,
+    /// Dispute defense period ended.
+    DisputeDefensePeriodEnded,
+    /// Donation processed.
+    Donation,
+    /// Additional information supplied for a dispute.
+    InformationSupplied,
+    /// Issuer response timeframe expired.
+    IssuerResponseTimeframeExpired,
+    /// Payment pending.
+    Pending,
+    /// An event code not known to this library.
+    Unknown(String),
+}
+
+impl EventCode {
+    /// Get the event code as sent by Adyen, e.g. `AUTHORISATION`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccountUpdater => "ACCOUNT_UPDATER",
+            Self::AchNotificationOfChange => "ACH_NOTIFICATION_OF_CHANGE",
+            Self::Authorisation => "AUTHORISATION",
+            Self::AuthorisationAdjustment => "AUTHORISATION_ADJUSTMENT",
+            Self::Autorescue => "AUTORESCUE",
+            Self::AutorescueNextAttempt => "AUTORESCUE_NEXT_ATTEMPT",
+            Self::Cancellation => "CANCELLATION",
+            Self::CancelAutorescue => "CANCEL_AUTORESCUE",
+            Self::CancelOrRefund => "CANCEL_OR_REFUND",
+            Self::Capture => "CAPTURE",
+            Self::CaptureFailed => "CAPTURE_FAILED",
+            Self::Chargeback => "CHARGEBACK",
+            Self::ChargebackReversed => "CHARGEBACK_REVERSED",
+            Self::Expire => "EXPIRE",
+            Self::IssuerComments => "ISSUER_COMMENTS",
+            Self::HandledExternally => "HANDLED_EXTERNALLY",
+            Self::ManualReviewAccept => "MANUAL_REVIEW_ACCEPT",
+            Self::ManualReviewReject => "MANUAL_REVIEW_REJECT",
+            Self::NotificationOfChargeback => "NOTIFICATION_OF_CHARGEBACK",
+            Self::NotificationOfFraud => "NOTIFICATION_OF_FRAUD",
+            Self::OfferClosed => "OFFER_CLOSED",
+            Self::PaidoutReversed => "PAIDOUT_REVERSED",
+            Self::PayoutDecline => "PAYOUT_DECLINE",
+            Self::PayoutExpire => "PAYOUT_EXPIRE",
+            Self::PayoutThirdparty => "PAYOUT_THIRDPARTY",
+            Self::PostponedRefund => "POSTPONED_REFUND",
+            Self::PrearbitrationLost => "PREARBITRATION_LOST",
+            Self::PrearbitrationWon => "PREARBITRATION_WON",
+            Self::RecurringContract => "RECURRING_CONTRACT",
+            Self::Refund => "REFUND",
+            Self::RefundFailed => "REFUND_FAILED",
+            Self::RefundWithData => "REFUND_WITH_DATA",
+            Self::RefundedReversed => "REFUNDED_REVERSED",
+            Self::ReportAvailable => "REPORT_AVAILABLE",
+            Self::RequestForInformation => "REQUEST_FOR_INFORMATION",
+            Self::SecondChargeback => "SECOND_CHARGEBACK",
+            Self::TechnicalCancel => "TECHNICAL_CANCEL",
+            Self::VoidPendingRefund => "VOID_PENDING_REFUND",
+            Self::OrderClosed => "ORDER_CLOSED",
+            Self::OrderOpened => "ORDER_OPENED",
+            Self::DisputeDefensePeriodEnded => "DISPUTE_DEFENSE_PERIOD_ENDED",
+            Self::Donation => "DONATION",
+            Self::InformationSupplied => "INFORMATION_SUPPLIED",
+            Self::IssuerResponseTimeframeExpired => "ISSUER_RESPONSE_TIMEFRAME_EXPIRED",
+            Self::Pending => "PENDING",
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Check if this is an event code not known to this library.
+    #[must_use]
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+}
+
+impl From<&str> for EventCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "ACCOUNT_UPDATER" => Self::AccountUpdater,
+            "ACH_NOTIFICATION_OF_CHANGE" => Self::AchNotificationOfChange,
+            "AUTHORISATION" => Self::Authorisation,
+            "AUTHORISATION_ADJUSTMENT" => Self::AuthorisationAdjustment,
+            "AUTORESCUE" => Self::Autorescue,
+            "AUTORESCUE_NEXT_ATTEMPT" => Self::AutorescueNextAttempt,
+            "CANCELLATION" => Self::Cancellation,
+            "CANCEL_AUTORESCUE" => Self::CancelAutorescue,
+            "CANCEL_OR_REFUND" => Self::CancelOrRefund,
+            "CAPTURE" => Self::Capture,
+            "CAPTURE_FAILED" => Self::CaptureFailed,
+            "CHARGEBACK" => Self::Chargeback,
+            "CHARGEBACK_REVERSED" => Self::ChargebackReversed,
+            "EXPIRE" => Self::Expire,
+            "ISSUER_COMMENTS" => Self::IssuerComments,
+            "HANDLED_EXTERNALLY" => Self::HandledExternally,
+            "MANUAL_REVIEW_ACCEPT" => Self::ManualReviewAccept,
+            "MANUAL_REVIEW_REJECT" => Self::ManualReviewReject,
+            "NOTIFICATION_OF_CHARGEBACK" => Self::NotificationOfChargeback,
+            "NOTIFICATION_OF_FRAUD" => Self::NotificationOfFraud,
+            "OFFER_CLOSED" => Self::OfferClosed,
+            "PAIDOUT_REVERSED" => Self::PaidoutReversed,
+            "PAYOUT_DECLINE" => Self::PayoutDecline,
+            "PAYOUT_EXPIRE" => Self::PayoutExpire,
+            "PAYOUT_THIRDPARTY" => Self::PayoutThirdparty,
+            "POSTPONED_REFUND" => Self::PostponedRefund,
+            "PREARBITRATION_LOST" => Self::PrearbitrationLost,
+            "PREARBITRATION_WON" => Self::PrearbitrationWon,
+            "RECURRING_CONTRACT" => Self::RecurringContract,
+            "REFUND" => Self::Refund,
+            "REFUND_FAILED" => Self::RefundFailed,
+            "REFUND_WITH_DATA" => Self::RefundWithData,
+            "REFUNDED_REVERSED" => Self::RefundedReversed,
+            "REPORT_AVAILABLE" => Self::ReportAvailable,
+            "REQUEST_FOR_INFORMATION" => Self::RequestForInformation,
+            "SECOND_CHARGEBACK" => Self::SecondChargeback,
+            "TECHNICAL_CANCEL" => Self::TechnicalCancel,
+            "VOID_PENDING_REFUND" => Self::VoidPendingRefund,
+            "ORDER_CLOSED" => Self::OrderClosed,
+            "ORDER_OPENED" => Self::OrderOpened,
+            "DISPUTE_DEFENSE_PERIOD_ENDED" => Self::DisputeDefensePeriodEnded,
+            "DONATION" => Self::Donation,
+            "INFORMATION_SUPPLIED" => Self::InformationSupplied,
+            "ISSUER_RESPONSE_TIMEFRAME_EXPIRED" => Self::IssuerResponseTimeframeExpired,
+            "PENDING" => Self::Pending,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::str::FromStr for EventCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl std::fmt::Display for EventCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for EventCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::from(code.as_ref()))
+    }
 }
 
 impl Webhook {
@@ -253,6 +417,65 @@ impl Webhook {
     pub fn is_test(&self) -> bool {
         self.live == "false"
     }
+
+    /// Validate the HMAC signatures of all notification items.
+    ///
+    /// Adyen batches many notification items into one webhook request. With
+    /// the `rayon` feature, the items are validated in parallel. Either way,
+    /// both lists keep the order of the items in the webhook.
+    #[must_use]
+    pub fn validate_all(&self, validator: &HmacValidator) -> ValidatedItems<'_> {
+        #[cfg(feature = "rayon")]
+        let results: Vec<bool> = {
+            use rayon::prelude::*;
+            self.notification_items
+                .par_iter()
+                .map(|item| validator.validate_notification(&item.notification_request_item))
+                .collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let results: Vec<bool> = self
+            .notification_items
+            .iter()
+            .map(|item| validator.validate_notification(&item.notification_request_item))
+            .collect();
+
+        let (valid, invalid) = self
+            .notification_items
+            .iter()
+            .zip(results)
+            .partition::<Vec<_>, _>(|(_, is_valid)| *is_valid);
+        ValidatedItems {
+            valid: valid
+                .into_iter()
+                .map(|(item, _)| &item.notification_request_item)
+                .collect(),
+            invalid: invalid
+                .into_iter()
+                .map(|(item, _)| &item.notification_request_item)
+                .collect(),
+        }
+    }
+}
+
+/// Notification items of a webhook, partitioned by whether their HMAC
+/// signature is valid.
+///
+/// Created with [`Webhook::validate_all`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidatedItems<'a> {
+    /// Items with a valid signature.
+    pub valid: Vec<&'a NotificationRequestItem>,
+    /// Items with a missing or invalid signature.
+    pub invalid: Vec<&'a NotificationRequestItem>,
+}
+
+impl ValidatedItems<'_> {
+    /// Check if every item has a valid signature.
+    #[must_use]
+    pub fn all_valid(&self) -> bool {
+        self.invalid.is_empty()
+    }
 }
 
 impl NotificationRequestItem {
@@ -282,6 +505,81 @@ impl NotificationRequestItem {
     pub fn get_additional_data(&self, key: &str) -> Option<&serde_json::Value> {
         self.additional_data.as_ref()?.get(key)
     }
+
+    /// Get a typed view of the common fields in additional data.
+    ///
+    /// Adyen sends additional data values as strings; numeric fields are parsed, and
+    /// fields that are missing or cannot be parsed are `None`.
+    #[must_use]
+    pub fn additional_data_typed(&self) -> AdditionalData {
+        let string = |key: &str| {
+            self.get_additional_data(key).and_then(|value| match value {
+                serde_json::Value::String(s) => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                serde_json::Value::Bool(b) => Some(b.to_string()),
+                _ => None,
+            })
+        };
+
+        AdditionalData {
+            hmac_signature: string("hmacSignature"),
+            auth_code: string("authCode"),
+            card_summary: string("cardSummary"),
+            card_bin: string("cardBin"),
+            expiry_date: string("expiryDate"),
+            issuer_country: string("issuerCountry"),
+            payment_method_variant: string("paymentMethodVariant"),
+            recurring_detail_reference: string("recurring.recurringDetailReference"),
+            recurring_shopper_reference: string("recurring.shopperReference"),
+            total_fraud_score: string("totalFraudScore").and_then(|s| s.parse().ok()),
+            chargeback_reason_code: string("chargebackReasonCode"),
+            chargeback_scheme_code: string("chargebackSchemeCode"),
+        }
+    }
+}
+
+/// Typed view of common `additionalData` fields on a notification item.
+///
+/// Created with [`NotificationRequestItem::additional_data_typed`]. Which fields are
+/// present depends on the event code and the additional data settings in the
+/// Customer Area.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdditionalData {
+    /// HMAC signature of the notification item (`hmacSignature`).
+    pub hmac_signature: Option<String>,
+    /// Authorisation code from the issuer (`authCode`).
+    pub auth_code: Option<String>,
+    /// Last four digits of the card number (`cardSummary`).
+    pub card_summary: Option<String>,
+    /// First six digits of the card number (`cardBin`).
+    pub card_bin: Option<String>,
+    /// Card expiry date in `MM/YYYY` format (`expiryDate`).
+    pub expiry_date: Option<String>,
+    /// Country of the card issuer (`issuerCountry`).
+    pub issuer_country: Option<String>,
+    /// Payment method variant, e.g. `visacorporate` (`paymentMethodVariant`).
+    pub payment_method_variant: Option<String>,
+    /// Stored payment details created with the payment
+    /// (`recurring.recurringDetailReference`).
+    pub recurring_detail_reference: Option<String>,
+    /// Shopper the stored payment details belong to (`recurring.shopperReference`).
+    pub recurring_shopper_reference: Option<String>,
+    /// Total risk score of the payment (`totalFraudScore`).
+    pub total_fraud_score: Option<i32>,
+    /// Reason code of a dispute (`chargebackReasonCode`).
+    pub chargeback_reason_code: Option<String>,
+    /// Card scheme the dispute came from (`chargebackSchemeCode`).
+    pub chargeback_scheme_code: Option<String>,
+}
+
+impl AdditionalData {
+    /// Parse the card expiry date into `(month, year)`.
+    #[must_use]
+    pub fn expiry(&self) -> Option<(u8, u16)> {
+        let (month, year) = self.expiry_date.as_deref()?.split_once('/')?;
+        let month = month.parse().ok().filter(|m| (1..=12).contains(m))?;
+        Some((month, year.parse().ok()?))
+    }
 }
 
 #[cfg(test)]
@@ -320,7 +618,7 @@ mod tests {
         let item = &webhook.notification_items[0].notification_request_item;
         assert!(item.is_success());
         assert!(!item.is_failure());
-        assert_eq!(item.event_code, "AUTHORISATION");
+        assert_eq!(item.event_code, EventCode::Authorisation);
         assert_eq!(item.merchant_reference, "test-payment-123");
         assert_eq!(item.amount.minor_units(), 1000);
         assert_eq!(item.amount.currency_string(), "EUR");
@@ -336,6 +634,38 @@ mod tests {
         assert_eq!(parsed, EventCode::Authorisation);
     }
 
+    #[test]
+    fn test_event_code_round_trips_all_known_codes() {
+        for code in [
+            "AUTHORISATION",
+            "CAPTURE",
+            "REFUND",
+            "CHARGEBACK",
+            "REPORT_AVAILABLE",
+            "RECURRING_CONTRACT",
+            "ACH_NOTIFICATION_OF_CHANGE",
+            "ACCOUNT_UPDATER",
+            "PAYOUT_THIRDPARTY",
+            "CANCEL_OR_REFUND",
+        ] {
+            let event = EventCode::from(code);
+            assert!(!event.is_unknown(), "{code} should be a known event code");
+            assert_eq!(event.as_str(), code);
+        }
+    }
+
+    #[test]
+    fn test_event_code_unknown_fallback() {
+        let parsed: EventCode = serde_json::from_str(r#""SOME_FUTURE_EVENT""#).unwrap();
+        assert_eq!(parsed, EventCode::Unknown("SOME_FUTURE_EVENT".to_string()));
+        assert!(parsed.is_unknown());
+        assert_eq!(parsed.to_string(), "SOME_FUTURE_EVENT");
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#""SOME_FUTURE_EVENT""#
+        );
+    }
+
     #[test]
     fn test_notification_item_additional_data() {
         let mut additional_data = HashMap::new();
@@ -351,7 +681,7 @@ mod tests {
         let item = NotificationRequestItem {
             additional_data: Some(additional_data),
             amount: Amount::new(1000, "EUR"),
-            event_code: "AUTHORISATION".to_string(),
+            event_code: EventCode::Authorisation,
             event_date: None,
             merchant_account_code: "TestMerchant".to_string(),
             merchant_reference: "test-ref".to_string(),
@@ -370,4 +700,100 @@ mod tests {
         );
         assert_eq!(item.get_additional_data("nonexistent"), None);
     }
+
+    #[test]
+    fn test_additional_data_typed() {
+        let item: NotificationRequestItem = serde_json::from_str(
+            r#"{
+                "additionalData": {
+                    "hmacSignature": "sig",
+                    "authCode": "065696",
+                    "cardSummary": "1111",
+                    "expiryDate": "03/2030",
+                    "recurring.recurringDetailReference": "8415736344108917",
+                    "recurring.shopperReference": "shopper-1",
+                    "totalFraudScore": "10",
+                    "chargebackReasonCode": "10.4"
+                },
+                "amount": {"currency": "EUR", "value": 1000},
+                "eventCode": "AUTHORISATION",
+                "merchantAccountCode": "TestMerchant",
+                "merchantReference": "test-ref",
+                "paymentMethod": "visa",
+                "pspReference": "12345",
+                "reason": "065696:1111:03/2030",
+                "success": "true"
+            }"#,
+        )
+        .unwrap();
+
+        let data = item.additional_data_typed();
+        assert_eq!(data.hmac_signature.as_deref(), Some("sig"));
+        assert_eq!(data.auth_code.as_deref(), Some("065696"));
+        assert_eq!(data.card_summary.as_deref(), Some("1111"));
+        assert_eq!(data.expiry(), Some((3, 2030)));
+        assert_eq!(
+            data.recurring_detail_reference.as_deref(),
+            Some("8415736344108917")
+        );
+        assert_eq!(
+            data.recurring_shopper_reference.as_deref(),
+            Some("shopper-1")
+        );
+        assert_eq!(data.total_fraud_score, Some(10));
+        assert_eq!(data.chargeback_reason_code.as_deref(), Some("10.4"));
+        assert_eq!(data.card_bin, None);
+
+        let mut item = item;
+        item.additional_data = None;
+        assert_eq!(item.additional_data_typed(), AdditionalData::default());
+    }
+
+    #[test]
+    fn test_validate_all_partitions_items() {
+        let validator =
+            HmacValidator::new("44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056")
+                .unwrap();
+        let notification_items = (0..5)
+            .map(|i| {
+                let mut item: NotificationRequestItem = serde_json::from_value(serde_json::json!({
+                    "amount": {"currency": "EUR", "value": 1000},
+                    "eventCode": "AUTHORISATION",
+                    "merchantAccountCode": "TestMerchant",
+                    "merchantReference": format!("order-{i}"),
+                    "paymentMethod": "visa",
+                    "pspReference": format!("851513175100493{i}"),
+                    "reason": "Approved",
+                    "success": "true"
+                }))
+                .unwrap();
+                // Every other item is left unsigned.
+                if i % 2 == 0 {
+                    let signature = validator.calculate_notification_signature(&item).unwrap();
+                    item.additional_data = Some(HashMap::from([(
+                        "hmacSignature".to_string(),
+                        serde_json::Value::String(signature),
+                    )]));
+                }
+                NotificationItem {
+                    notification_request_item: item,
+                }
+            })
+            .collect();
+        let webhook = Webhook {
+            live: "false".to_string(),
+            notification_items,
+        };
+
+        let items = webhook.validate_all(&validator);
+        assert!(!items.all_valid());
+        let references = |items: &[&NotificationRequestItem]| {
+            items
+                .iter()
+                .map(|item| item.merchant_reference.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(references(&items.valid), ["order-0", "order-2", "order-4"]);
+        assert_eq!(references(&items.invalid), ["order-1", "order-3"]);
+    }
 }
@@ -3,11 +3,13 @@
 //! This module provides utilities to validate webhook authenticity using HMAC-SHA256 signatures.
 //! Adyen uses HMAC signatures to ensure webhooks are genuine and haven't been tampered with.
 
-use crate::types::NotificationRequestItem;
+use crate::borrowed::NotificationRequestItemRef;
+use crate::types::{NotificationItem, NotificationRequestItem, Webhook};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use std::collections::HashMap;
+use zeroize::Zeroizing;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -20,9 +22,105 @@ pub enum ValidationError {
     /// HMAC calculation failed.
     #[error("HMAC calculation failed: {0}")]
     HmacError(String),
+    /// Basic auth credentials are missing or do not match.
+    #[error("Basic auth credentials missing or invalid")]
+    InvalidCredentials,
     /// Missing HMAC signature in webhook data.
     #[error("HMAC signature not found in additional data")]
     MissingSignature,
+    /// HMAC signature is not valid base64.
+    #[error("HMAC signature is not valid base64: {0}")]
+    MalformedSignature(base64::DecodeError),
+    /// HMAC signature does not have the length of an HMAC-SHA256 digest.
+    #[error("HMAC signature is {actual} bytes, expected {expected}")]
+    SignatureLength {
+        /// Length of an HMAC-SHA256 digest.
+        expected: usize,
+        /// Length of the decoded signature.
+        actual: usize,
+    },
+    /// HMAC signature does not match the payload.
+    #[error("HMAC signature does not match the payload")]
+    InvalidSignature,
+    /// Payload could not be parsed after its signature was validated.
+    #[error("Invalid webhook payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// Compare two byte strings in constant time.
+///
+/// Both inputs are hashed first, so the time taken reveals neither their contents nor
+/// their lengths.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    use sha2::Digest;
+    use subtle::ConstantTimeEq;
+
+    Sha256::digest(a).ct_eq(&Sha256::digest(b)).into()
+}
+
+/// HTTP header carrying the HMAC signature of Balance Platform and Management API
+/// webhooks.
+pub const HMAC_SIGNATURE_HEADER: &str = "HmacSignature";
+
+/// HMAC key used to validate webhook signatures.
+///
+/// The key is zeroized when dropped.
+#[derive(Clone)]
+pub struct HmacKey {
+    id: Option<String>,
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl HmacKey {
+    /// Decode a hex-encoded HMAC key, with an optional identifier such as the key's
+    /// creation date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key is not valid hex.
+    pub fn new(id: Option<&str>, secret_key: &str) -> Result<Self, ValidationError> {
+        let key = hex::decode(secret_key.trim())
+            .map(Zeroizing::new)
+            .map_err(|e| ValidationError::InvalidKey(format!("Invalid hex key: {e}")))?;
+
+        Ok(Self {
+            id: id.map(String::from),
+            key,
+        })
+    }
+
+    /// Get the identifier of this key, if it has one.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+impl std::fmt::Debug for HmacKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HmacKey")
+            .field("id", &self.id)
+            .field("key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// A webhook body with its HMAC signature, created with
+/// [`HmacValidator::sign_payload`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedPayload {
+    /// The serialized JSON body.
+    pub body: String,
+    /// The base64-encoded HMAC signature of the body.
+    pub signature: String,
+}
+
+impl SignedPayload {
+    /// Get the name and value of the HTTP header carrying the signature.
+    #[must_use]
+    pub fn header(&self) -> (&'static str, &str) {
+        (HMAC_SIGNATURE_HEADER, &self.signature)
+    }
 }
 
 /// HMAC validator for Adyen webhooks.
@@ -30,9 +128,13 @@ pub enum ValidationError {
 /// This validator provides methods to verify webhook authenticity using HMAC-SHA256 signatures.
 /// It supports both payload-based validation (for HTTP header signatures) and additional-data
 /// based validation (for signatures embedded in the webhook payload).
-#[derive(Debug)]
+///
+/// A validator can hold several keys so webhooks keep validating while a key is rotated:
+/// a signature is valid if it matches any of them. Signatures are calculated with the
+/// first key.
+#[derive(Debug, Clone)]
 pub struct HmacValidator {
-    secret_key: Vec<u8>,
+    keys: Vec<HmacKey>,
 }
 
 impl HmacValidator {
@@ -44,10 +146,111 @@ impl HmacValidator {
     ///
     /// Returns an error if the secret key is not valid hex.
     pub fn new(secret_key: &str) -> Result<Self, ValidationError> {
-        let key = hex::decode(secret_key)
-            .map_err(|e| ValidationError::InvalidKey(format!("Invalid hex key: {e}")))?;
+        Ok(Self {
+            keys: vec![HmacKey::new(None, secret_key)?],
+        })
+    }
 
-        Ok(Self { secret_key: key })
+    /// Create a validator accepting signatures from any of the given keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no keys are given or a key is not valid hex.
+    pub fn from_keys(keys: impl IntoIterator<Item = HmacKey>) -> Result<Self, ValidationError> {
+        let keys: Vec<_> = keys.into_iter().collect();
+        if keys.is_empty() {
+            return Err(ValidationError::InvalidKey(
+                "No HMAC keys given".to_string(),
+            ));
+        }
+        Ok(Self { keys })
+    }
+
+    /// Create a validator from keys in an environment variable, read at runtime.
+    ///
+    /// The variable holds a comma-separated list of hex-encoded keys, each optionally
+    /// prefixed with an identifier and `=`, e.g. `2024-06=44782DEF...,2024-01=A1B2C3...`.
+    /// List the current key first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is not set, is empty, or holds an invalid key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_webhooks::HmacValidator;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// // ADYEN_HMAC_KEYS="2024-06=44782DEF547AAA06,2024-01=C910C43932B1EB0C"
+    /// let validator = HmacValidator::from_env("ADYEN_HMAC_KEYS")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env(var: &str) -> Result<Self, ValidationError> {
+        let value = std::env::var(var)
+            .map_err(|e| ValidationError::InvalidKey(format!("Cannot read {var}: {e}")))?;
+
+        let keys = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((id, key)) => HmacKey::new(Some(id.trim()), key),
+                None => HmacKey::new(None, entry),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_keys(keys)
+    }
+
+    /// Create a validator from the HMAC keys of a client configuration, e.g. one
+    /// read with [`Config::from_env`](adyen_core::Config::from_env).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration has no HMAC keys or a key is not valid hex.
+    pub fn from_config(config: &adyen_core::Config) -> Result<Self, ValidationError> {
+        let keys = config
+            .hmac_keys()
+            .iter()
+            .map(|key| HmacKey::new(key.id(), key.key().expose_secret()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_keys(keys)
+    }
+
+    /// Add a key accepted for validation, e.g. the new key during a rotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret key is not valid hex.
+    pub fn with_key(mut self, id: Option<&str>, secret_key: &str) -> Result<Self, ValidationError> {
+        self.keys.push(HmacKey::new(id, secret_key)?);
+        Ok(self)
+    }
+
+    /// Get the keys accepted by this validator, in order.
+    #[must_use]
+    pub fn keys(&self) -> &[HmacKey] {
+        &self.keys
+    }
+
+    /// Find the key that signed a notification request item.
+    ///
+    /// Returns `None` if the signature is missing or matches none of the keys. Useful
+    /// to check whether webhooks are still signed with a key that is being retired.
+    #[must_use]
+    pub fn matching_notification_key(&self, item: &NotificationRequestItem) -> Option<&HmacKey> {
+        let signature = item.hmac_signature()?;
+        let data_to_sign = self.get_notification_data_to_sign(item);
+        self.matching_key(&data_to_sign, &signature)
+    }
+
+    /// Find the key that signed a raw payload.
+    #[must_use]
+    pub fn matching_payload_key(&self, payload: &str, signature: &str) -> Option<&HmacKey> {
+        self.matching_key(payload, signature)
     }
 
     /// Validate HMAC signature for a notification request item.
@@ -64,18 +267,29 @@ impl HmacValidator {
     /// Returns `true` if the signature is valid, `false` otherwise.
     #[must_use]
     pub fn validate_notification(&self, item: &NotificationRequestItem) -> bool {
-        // Get the HMAC signature from additional data
-        let Some(signature) = item.hmac_signature() else {
-            return false;
-        };
-
-        // Calculate expected signature
-        let Ok(expected_signature) = self.calculate_notification_signature(item) else {
-            return false;
-        };
+        self.matching_notification_key(item).is_some()
+    }
 
-        // Compare signatures
-        signature == expected_signature
+    /// Validate HMAC signature for a borrowed notification request item.
+    ///
+    /// Like [`validate_notification`](Self::validate_notification), for items
+    /// parsed as a [`WebhookRef`](crate::WebhookRef).
+    #[must_use]
+    pub fn validate_notification_ref(&self, item: &NotificationRequestItemRef<'_>) -> bool {
+        item.hmac_signature().is_some_and(|signature| {
+            let data_to_sign = format!(
+                "{}:{}:{}:{}:{}:{}:{}:{}",
+                item.psp_reference,
+                item.original_reference.as_deref().unwrap_or(""),
+                item.merchant_account_code,
+                item.merchant_reference,
+                item.amount.value,
+                item.amount.currency,
+                item.event_code,
+                item.success
+            );
+            self.matching_key(&data_to_sign, &signature).is_some()
+        })
     }
 
     /// Validate HMAC signature for a raw payload.
@@ -93,10 +307,64 @@ impl HmacValidator {
     /// Returns `true` if the signature is valid, `false` otherwise.
     #[must_use]
     pub fn validate_payload(&self, payload: &str, signature: &str) -> bool {
-        match self.calculate_payload_signature(payload) {
-            Ok(expected_signature) => signature == expected_signature,
-            Err(_) => false,
-        }
+        self.matching_payload_key(payload, signature).is_some()
+    }
+
+    /// Validate the signature of a raw payload and parse it.
+    ///
+    /// Used for webhooks signed in the [`HMAC_SIGNATURE_HEADER`] header, such as
+    /// [`BalancePlatformNotification`](crate::balance_platform::BalancePlatformNotification)
+    /// and [`ManagementNotification`](crate::management::ManagementNotification). The
+    /// payload must be the request body exactly as received.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`validate_payload_strict`](Self::validate_payload_strict),
+    /// or [`ValidationError::InvalidPayload`] if the payload cannot be parsed as `T`.
+    pub fn parse_signed_payload<T: serde::de::DeserializeOwned>(
+        &self,
+        payload: &str,
+        signature: &str,
+    ) -> Result<T, ValidationError> {
+        self.validate_payload_strict(payload, signature)?;
+        Ok(serde_json::from_str(payload)?)
+    }
+
+    /// Validate HMAC signature for a notification request item, explaining failures.
+    ///
+    /// Unlike [`validate_notification`](Self::validate_notification), this reports why
+    /// validation failed, so a missing or malformed signature can be logged
+    /// differently from a forged one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::MissingSignature`] if the item has no signature,
+    /// [`ValidationError::MalformedSignature`] or [`ValidationError::SignatureLength`]
+    /// if the signature is not a base64-encoded HMAC-SHA256 digest, or
+    /// [`ValidationError::InvalidSignature`] if it matches none of the keys.
+    pub fn validate_notification_strict(
+        &self,
+        item: &NotificationRequestItem,
+    ) -> Result<(), ValidationError> {
+        let signature = item
+            .hmac_signature()
+            .ok_or(ValidationError::MissingSignature)?;
+        let data_to_sign = self.get_notification_data_to_sign(item);
+        self.verify(&data_to_sign, &signature).map(|_| ())
+    }
+
+    /// Validate HMAC signature for a raw payload, explaining failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`validate_notification_strict`](Self::validate_notification_strict).
+    pub fn validate_payload_strict(
+        &self,
+        payload: &str,
+        signature: &str,
+    ) -> Result<(), ValidationError> {
+        self.verify(payload, signature).map(|_| ())
     }
 
     /// Calculate HMAC signature for a notification request item.
@@ -124,6 +392,108 @@ impl HmacValidator {
         self.calculate_hmac(payload)
     }
 
+    /// Sign a notification request item, e.g. to send test webhooks to your own
+    /// endpoint.
+    ///
+    /// Returns a copy of `item` with the `hmacSignature` additional data set to the
+    /// signature calculated with the first key, replacing any existing signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HMAC calculation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use adyen_webhooks::{HmacValidator, NotificationRequestItem};
+    /// # fn example(validator: HmacValidator, item: NotificationRequestItem) -> Result<(), Box<dyn std::error::Error>> {
+    /// let signed = validator.sign_notification(&item)?;
+    /// assert!(validator.validate_notification(&signed));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_notification(
+        &self,
+        item: &NotificationRequestItem,
+    ) -> Result<NotificationRequestItem, ValidationError> {
+        let signature = self.calculate_notification_signature(item)?;
+        let mut signed = item.clone();
+        signed
+            .additional_data
+            .get_or_insert_with(HashMap::new)
+            .insert(
+                "hmacSignature".to_string(),
+                serde_json::Value::String(signature),
+            );
+        Ok(signed)
+    }
+
+    /// Sign every notification item of a webhook.
+    ///
+    /// Like [`sign_notification`](Self::sign_notification), for a whole webhook
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HMAC calculation fails.
+    pub fn sign_webhook(&self, webhook: &Webhook) -> Result<Webhook, ValidationError> {
+        let notification_items = webhook
+            .notification_items
+            .iter()
+            .map(|item| {
+                Ok(NotificationItem {
+                    notification_request_item: self
+                        .sign_notification(&item.notification_request_item)?,
+                })
+            })
+            .collect::<Result<_, ValidationError>>()?;
+        Ok(Webhook {
+            live: webhook.live.clone(),
+            notification_items,
+        })
+    }
+
+    /// Serialize and sign a webhook payload signed in the [`HMAC_SIGNATURE_HEADER`]
+    /// header, such as a
+    /// [`BalancePlatformNotification`](crate::balance_platform::BalancePlatformNotification)
+    /// or [`ManagementNotification`](crate::management::ManagementNotification).
+    ///
+    /// The signature is calculated over the serialized body with the first key, so
+    /// send the body exactly as returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidPayload`] if the payload cannot be serialized,
+    /// or an error if HMAC calculation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use adyen_webhooks::HmacValidator;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let validator = HmacValidator::new("44782DEF547AAA06C910C43932B1EB0C")?;
+    /// let signed = validator.sign_payload(&serde_json::json!({
+    ///     "type": "merchant.updated",
+    ///     "environment": "test",
+    ///     "data": {"merchantId": "TestMerchant"}
+    /// }))?;
+    ///
+    /// let (header, signature) = signed.header();
+    /// assert_eq!(header, "HmacSignature");
+    /// assert!(validator.validate_payload(&signed.body, signature));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sign_payload<T: serde::Serialize>(
+        &self,
+        payload: &T,
+    ) -> Result<SignedPayload, ValidationError> {
+        let body = serde_json::to_string(payload)?;
+        let signature = self.calculate_payload_signature(&body)?;
+        Ok(SignedPayload { body, signature })
+    }
+
     /// Get the data-to-sign string for a notification request item.
     ///
     /// This method constructs the canonical string that Adyen uses for HMAC calculation,
@@ -146,20 +516,58 @@ impl HmacValidator {
         )
     }
 
-    /// Calculate HMAC-SHA256 signature for the given data.
+    /// Find the key whose signature of `data` equals `signature`.
+    fn matching_key(&self, data: &str, signature: &str) -> Option<&HmacKey> {
+        self.verify(data, signature).ok()
+    }
+
+    /// Find the key whose signature of `data` equals `signature`, explaining failures.
+    ///
+    /// Signatures are compared in constant time.
+    fn verify(&self, data: &str, signature: &str) -> Result<&HmacKey, ValidationError> {
+        if signature.is_empty() {
+            return Err(ValidationError::MissingSignature);
+        }
+        let signature = BASE64
+            .decode(signature)
+            .map_err(ValidationError::MalformedSignature)?;
+        let expected = <Sha256 as sha2::Digest>::output_size();
+        if signature.len() != expected {
+            return Err(ValidationError::SignatureLength {
+                expected,
+                actual: signature.len(),
+            });
+        }
+
+        self.keys
+            .iter()
+            .find(|key| {
+                HmacSha256::new_from_slice(&key.key).is_ok_and(|mut mac| {
+                    mac.update(data.as_bytes());
+                    mac.verify_slice(&signature).is_ok()
+                })
+            })
+            .ok_or(ValidationError::InvalidSignature)
+    }
+
+    /// Calculate HMAC-SHA256 signature for the given data with the first key.
     fn calculate_hmac(&self, data: &str) -> Result<String, ValidationError> {
-        let mut mac = HmacSha256::new_from_slice(&self.secret_key)
+        Self::calculate_hmac_with(&self.keys[0], data)
+    }
+
+    /// Calculate HMAC-SHA256 signature for the given data with `key`.
+    fn calculate_hmac_with(key: &HmacKey, data: &str) -> Result<String, ValidationError> {
+        let mut mac = HmacSha256::new_from_slice(&key.key)
             .map_err(|e| ValidationError::HmacError(format!("Failed to create HMAC: {e}")))?;
 
-        // Apply escaping for backslashes and colons as per Adyen specification
-        let escaped_data = self.escape_data(data);
-        mac.update(escaped_data.as_bytes());
+        mac.update(data.as_bytes());
 
         let result = mac.finalize();
         Ok(BASE64.encode(result.into_bytes()))
     }
 
-    /// Escape backslashes and colons in data as required by Adyen HMAC specification.
+    /// Escape backslashes and colons in a key or value of key-value data, as required
+    /// by Adyen HMAC specification.
     ///
     /// This matches the escaping logic from the Go library:
     /// - Backslashes are escaped as `\\`
@@ -179,10 +587,8 @@ impl HmacValidator {
         data: &HashMap<String, String>,
         signature: &str,
     ) -> bool {
-        match self.calculate_key_value_signature(data) {
-            Ok(expected_signature) => signature == expected_signature,
-            Err(_) => false,
-        }
+        let data_to_sign = self.get_key_value_data_to_sign(data);
+        self.matching_key(&data_to_sign, signature).is_some()
     }
 
     /// Calculate HMAC signature for key-value pairs.
@@ -191,10 +597,20 @@ impl HmacValidator {
     /// 1. Sort keys alphabetically
     /// 2. Escape keys and values
     /// 3. Create signature string: "key1:key2:...:value1:value2:..."
-    fn calculate_key_value_signature(
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HMAC calculation fails.
+    pub fn calculate_key_value_signature(
         &self,
         data: &HashMap<String, String>,
     ) -> Result<String, ValidationError> {
+        let data_to_sign = self.get_key_value_data_to_sign(data);
+        self.calculate_hmac(&data_to_sign)
+    }
+
+    /// Get the data-to-sign string for key-value pairs.
+    fn get_key_value_data_to_sign(&self, data: &HashMap<String, String>) -> String {
         let mut keys: Vec<&String> = data.keys().collect();
         keys.sort();
 
@@ -203,16 +619,14 @@ impl HmacValidator {
         let escaped_values: Vec<String> =
             keys.iter().map(|k| self.escape_data(&data[*k])).collect();
 
-        let data_to_sign = format!("{}:{}", escaped_keys.join(":"), escaped_values.join(":"));
-
-        self.calculate_hmac(&data_to_sign)
+        format!("{}:{}", escaped_keys.join(":"), escaped_values.join(":"))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Amount, NotificationRequestItem};
+    use crate::types::{Amount, EventCode, NotificationRequestItem};
     use std::collections::HashMap;
 
     const TEST_HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
@@ -220,7 +634,7 @@ mod tests {
     #[test]
     fn test_hmac_validator_creation() {
         let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
-        assert_eq!(validator.secret_key.len(), 32); // 256 bits = 32 bytes
+        assert_eq!(validator.keys[0].key.len(), 32); // 256 bits = 32 bytes
     }
 
     #[test]
@@ -240,7 +654,7 @@ mod tests {
         let item = NotificationRequestItem {
             additional_data: None,
             amount: Amount::new(1000, "EUR"),
-            event_code: "AUTHORISATION".to_string(),
+            event_code: EventCode::Authorisation,
             event_date: None,
             merchant_account_code: "TestMerchant".to_string(),
             merchant_reference: "test-payment-123".to_string(),
@@ -273,7 +687,7 @@ mod tests {
         let item = NotificationRequestItem {
             additional_data: None,
             amount: Amount::new(1000, "EUR"),
-            event_code: "AUTHORISATION".to_string(),
+            event_code: EventCode::Authorisation,
             event_date: None,
             merchant_account_code: "TestMerchant".to_string(),
             merchant_reference: "test-payment-123".to_string(),
@@ -304,7 +718,7 @@ mod tests {
             .calculate_notification_signature(&NotificationRequestItem {
                 additional_data: None,
                 amount: Amount::new(1000, "EUR"),
-                event_code: "AUTHORISATION".to_string(),
+                event_code: EventCode::Authorisation,
                 event_date: None,
                 merchant_account_code: "TestMerchant".to_string(),
                 merchant_reference: "test-payment-123".to_string(),
@@ -325,7 +739,7 @@ mod tests {
         let item = NotificationRequestItem {
             additional_data: Some(additional_data),
             amount: Amount::new(1000, "EUR"),
-            event_code: "AUTHORISATION".to_string(),
+            event_code: EventCode::Authorisation,
             event_date: None,
             merchant_account_code: "TestMerchant".to_string(),
             merchant_reference: "test-payment-123".to_string(),
@@ -340,6 +754,36 @@ mod tests {
         assert!(validator.validate_notification(&item));
     }
 
+    #[test]
+    fn test_borrowed_notification_validation() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let item = NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::Authorisation,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "test-payment-123".to_string(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: "8515131751004933".to_string(),
+            reason: "test".to_string(),
+            success: "true".to_string(),
+        };
+        let signature = validator.calculate_notification_signature(&item).unwrap();
+
+        let mut json = serde_json::to_value(&item).unwrap();
+        json["additionalData"] = serde_json::json!({ "hmacSignature": signature });
+        let json = json.to_string();
+        let item: crate::NotificationRequestItemRef<'_> = serde_json::from_str(&json).unwrap();
+        assert!(validator.validate_notification_ref(&item));
+
+        let tampered = json.replace("test-payment-123", "test-payment-456");
+        let item: crate::NotificationRequestItemRef<'_> = serde_json::from_str(&tampered).unwrap();
+        assert!(!validator.validate_notification_ref(&item));
+    }
+
     #[test]
     fn test_notification_validation_with_invalid_signature() {
         let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
@@ -353,7 +797,7 @@ mod tests {
         let item = NotificationRequestItem {
             additional_data: Some(additional_data),
             amount: Amount::new(1000, "EUR"),
-            event_code: "AUTHORISATION".to_string(),
+            event_code: EventCode::Authorisation,
             event_date: None,
             merchant_account_code: "TestMerchant".to_string(),
             merchant_reference: "test-payment-123".to_string(),
@@ -379,6 +823,281 @@ mod tests {
         assert!(!validator.validate_payload(payload, "invalid_signature"));
     }
 
+    #[test]
+    fn test_notification_signature_matches_reference_value() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+
+        let item = NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::from("EVENT"),
+            event_date: None,
+            merchant_account_code: "merchantAccount".to_string(),
+            merchant_reference: "reference".to_string(),
+            operations: vec![],
+            original_reference: Some("originalReference".to_string()),
+            payment_method: "visa".to_string(),
+            psp_reference: "pspReference".to_string(),
+            reason: String::new(),
+            success: "true".to_string(),
+        };
+
+        assert_eq!(
+            validator.calculate_notification_signature(&item).unwrap(),
+            "Z+0vw7Q8IVITRxBbatPzjgStFCBVmluJRqwx7ZA4pEw="
+        );
+    }
+
+    #[test]
+    fn test_payload_signature_is_computed_over_raw_payload() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+
+        // Colons and backslashes in the body must not be escaped before signing.
+        let payload = r#"{"type":"balancePlatform.accountHolder.created","path":"a\\b"}"#;
+        let mut mac = HmacSha256::new_from_slice(&hex::decode(TEST_HMAC_KEY).unwrap()).unwrap();
+        mac.update(payload.as_bytes());
+        let expected = BASE64.encode(mac.finalize().into_bytes());
+
+        assert_eq!(
+            validator.calculate_payload_signature(payload).unwrap(),
+            expected
+        );
+        assert!(validator.validate_payload(payload, &expected));
+    }
+
+    #[test]
+    fn test_strict_validation_errors() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let payload = r#"{"test": "data"}"#;
+        let signature = validator.calculate_payload_signature(payload).unwrap();
+
+        assert!(validator
+            .validate_payload_strict(payload, &signature)
+            .is_ok());
+        assert!(matches!(
+            validator.validate_payload_strict(payload, ""),
+            Err(ValidationError::MissingSignature)
+        ));
+        assert!(matches!(
+            validator.validate_payload_strict(payload, "not base64!"),
+            Err(ValidationError::MalformedSignature(_))
+        ));
+        assert!(matches!(
+            validator.validate_payload_strict(payload, &BASE64.encode([0u8; 20])),
+            Err(ValidationError::SignatureLength {
+                expected: 32,
+                actual: 20
+            })
+        ));
+        assert!(matches!(
+            validator.validate_payload_strict(payload, &BASE64.encode([0u8; 32])),
+            Err(ValidationError::InvalidSignature)
+        ));
+
+        let mut item = NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::Authorisation,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "test-payment-123".to_string(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: "8515131751004933".to_string(),
+            reason: "test".to_string(),
+            success: "true".to_string(),
+        };
+        assert!(matches!(
+            validator.validate_notification_strict(&item),
+            Err(ValidationError::MissingSignature)
+        ));
+        let signature = validator.calculate_notification_signature(&item).unwrap();
+        item.additional_data = Some(HashMap::from([(
+            "hmacSignature".to_string(),
+            serde_json::Value::String(signature),
+        )]));
+        assert!(validator.validate_notification_strict(&item).is_ok());
+    }
+
+    #[test]
+    fn test_parse_signed_payload() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let payload = r#"{"environment":"test"}"#;
+        let signature = validator.calculate_payload_signature(payload).unwrap();
+
+        let parsed: serde_json::Value =
+            validator.parse_signed_payload(payload, &signature).unwrap();
+        assert_eq!(parsed["environment"], "test");
+        let forged = BASE64.encode([7u8; 32]);
+        assert!(matches!(
+            validator.parse_signed_payload::<serde_json::Value>(payload, &forged),
+            Err(ValidationError::InvalidSignature)
+        ));
+        let signature = validator.calculate_payload_signature("not json").unwrap();
+        assert!(matches!(
+            validator.parse_signed_payload::<serde_json::Value>("not json", &signature),
+            Err(ValidationError::InvalidPayload(_))
+        ));
+    }
+
+    #[test]
+    fn test_sign_notification() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let mut additional_data = HashMap::new();
+        additional_data.insert(
+            "hmacSignature".to_string(),
+            serde_json::Value::String("stale".to_string()),
+        );
+        let item = NotificationRequestItem {
+            additional_data: Some(additional_data),
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::Refund,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "test-payment-123".to_string(),
+            operations: vec![],
+            original_reference: Some("8515131751004933".to_string()),
+            payment_method: "visa".to_string(),
+            psp_reference: "8515131751004934".to_string(),
+            reason: String::new(),
+            success: "true".to_string(),
+        };
+
+        let signed = validator.sign_notification(&item).unwrap();
+        assert!(!validator.validate_notification(&item));
+        assert!(validator.validate_notification(&signed));
+        assert_eq!(
+            signed.hmac_signature(),
+            validator.calculate_notification_signature(&item).ok()
+        );
+
+        let webhook = Webhook {
+            live: "false".to_string(),
+            notification_items: vec![NotificationItem {
+                notification_request_item: NotificationRequestItem {
+                    additional_data: None,
+                    ..item
+                },
+            }],
+        };
+        let signed = validator.sign_webhook(&webhook).unwrap();
+        assert!(signed.validate_all(&validator).all_valid());
+        assert!(!webhook.validate_all(&validator).all_valid());
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let notification = serde_json::json!({
+            "type": "merchant.updated",
+            "environment": "test",
+            "data": {"merchantId": "TestMerchant"}
+        });
+
+        let signed = validator.sign_payload(&notification).unwrap();
+        assert_eq!(signed.header(), (HMAC_SIGNATURE_HEADER, &*signed.signature));
+        let parsed: crate::management::ManagementNotification = validator
+            .parse_signed_payload(&signed.body, &signed.signature)
+            .unwrap();
+        assert_eq!(parsed.data["merchantId"], "TestMerchant");
+    }
+
+    #[test]
+    fn test_rotating_keys() {
+        const NEW_HMAC_KEY: &str =
+            "A1B2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F90";
+        let old = HmacValidator::new(TEST_HMAC_KEY).unwrap();
+        let new = HmacValidator::new(NEW_HMAC_KEY).unwrap();
+        let rotating = HmacValidator::new(NEW_HMAC_KEY)
+            .unwrap()
+            .with_key(Some("2024-01"), TEST_HMAC_KEY)
+            .unwrap();
+
+        let payload = r#"{"test": "data"}"#;
+        let old_signature = old.calculate_payload_signature(payload).unwrap();
+        let new_signature = new.calculate_payload_signature(payload).unwrap();
+        assert!(rotating.validate_payload(payload, &old_signature));
+        assert!(rotating.validate_payload(payload, &new_signature));
+        assert!(!new.validate_payload(payload, &old_signature));
+        assert_eq!(
+            rotating
+                .matching_payload_key(payload, &old_signature)
+                .and_then(HmacKey::id),
+            Some("2024-01")
+        );
+        assert_eq!(
+            rotating.calculate_payload_signature(payload).unwrap(),
+            new_signature
+        );
+
+        let mut item = NotificationRequestItem {
+            additional_data: None,
+            amount: Amount::new(1000, "EUR"),
+            event_code: EventCode::Authorisation,
+            event_date: None,
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "test-payment-123".to_string(),
+            operations: vec![],
+            original_reference: None,
+            payment_method: "visa".to_string(),
+            psp_reference: "8515131751004933".to_string(),
+            reason: "test".to_string(),
+            success: "true".to_string(),
+        };
+        let signature = old.calculate_notification_signature(&item).unwrap();
+        item.additional_data = Some(HashMap::from([(
+            "hmacSignature".to_string(),
+            serde_json::Value::String(signature),
+        )]));
+        assert!(rotating.validate_notification(&item));
+        assert!(!new.validate_notification(&item));
+
+        assert!(!format!("{rotating:?}").contains("44782"));
+        assert!(HmacValidator::from_keys(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_keys_from_env() {
+        let var = "ADYEN_WEBHOOKS_TEST_HMAC_KEYS";
+        std::env::set_var(var, format!("2024-06={TEST_HMAC_KEY}, {TEST_HMAC_KEY}"));
+        let validator = HmacValidator::from_env(var).unwrap();
+        assert_eq!(validator.keys().len(), 2);
+        assert_eq!(validator.keys()[0].id(), Some("2024-06"));
+        assert_eq!(validator.keys()[1].id(), None);
+
+        std::env::set_var(var, "2024-06=not-hex");
+        assert!(matches!(
+            HmacValidator::from_env(var),
+            Err(ValidationError::InvalidKey(_))
+        ));
+        std::env::remove_var(var);
+        assert!(HmacValidator::from_env(var).is_err());
+    }
+
+    #[test]
+    fn test_keys_from_config() {
+        let config = adyen_core::ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .hmac_key(adyen_core::WebhookHmacKey::new(
+                Some("2024-06"),
+                TEST_HMAC_KEY,
+            ))
+            .build()
+            .unwrap();
+        let validator = HmacValidator::from_config(&config).unwrap();
+        assert_eq!(validator.keys().len(), 1);
+        assert_eq!(validator.keys()[0].id(), Some("2024-06"));
+
+        let config = adyen_core::ConfigBuilder::new()
+            .api_key("test_key_12345")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(HmacValidator::from_config(&config).is_err());
+    }
+
     #[test]
     fn test_key_value_signature_calculation() {
         let validator = HmacValidator::new(TEST_HMAC_KEY).unwrap();
@@ -0,0 +1,201 @@
+//! # Adyen
+//!
+//! One crate for all of Adyen's APIs. Each API crate is re-exported as a
+//! module, e.g. [`checkout`] for `adyen-checkout`, and the core types such as
+//! [`ConfigBuilder`] and [`Amount`] are available at the crate root.
+//!
+//! [`AdyenClient`] creates the API clients from one configuration. They all
+//! share one HTTP client, and with it one connection pool, instead of each
+//! API struct creating its own.
+//!
+//! ## Quick Start
+//!
+//! ```rust
+//! use adyen::{AdyenClient, ConfigBuilder, Environment};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = ConfigBuilder::new()
+//!     .environment(Environment::test())
+//!     .api_key("your_api_key")?
+//!     .build()?;
+//!
+//! let adyen = AdyenClient::new(config)?;
+//!
+//! let checkout = adyen.checkout();
+//! let management = adyen.management();
+//! # let _ = (checkout, management);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Features
+//!
+//! - `blocking`: `_blocking` variants of all API methods, see
+//!   [`adyen_core::blocking`]
+//! - `config-file`: [`Config::from_file`] for TOML profile files
+//! - `encryption`: client-side card encryption in [`checkout::encryption`]
+//! - `rkyv`: zero-copy serialization of core and webhook types, and of the
+//!   Checkout, Payments, Platform, Management and Legal Entity API types
+//! - `rayon`: parallel validation in [`webhooks::Webhook::validate_all`]
+//! - `simd-json`: SIMD-accelerated parsing of API responses and webhooks
+
+#![deny(missing_docs)]
+#![warn(clippy::all, clippy::pedantic)]
+
+pub use adyen_bin_lookup as bin_lookup;
+pub use adyen_checkout as checkout;
+pub use adyen_core::*;
+pub use adyen_disputes as disputes;
+pub use adyen_legal_entity as legal_entity;
+pub use adyen_management as management;
+pub use adyen_payments as payments;
+pub use adyen_payout as payout;
+pub use adyen_platform as platform;
+pub use adyen_recurring as recurring;
+pub use adyen_reports as reports;
+pub use adyen_stored_value as stored_value;
+pub use adyen_terminal as terminal;
+pub use adyen_transfers as transfers;
+pub use adyen_webhooks as webhooks;
+
+/// Client for all Adyen APIs.
+///
+/// The accessors return API clients that share this client's HTTP client
+/// and configuration. They are cheap to create, so call them where needed
+/// rather than storing the results.
+///
+/// # Example
+///
+/// ```rust
+/// use adyen::{AdyenClient, ConfigBuilder, Environment};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = ConfigBuilder::new()
+///     .environment(Environment::test())
+///     .api_key("your_api_key")?
+///     .build()?;
+///
+/// let adyen = AdyenClient::new(config)?;
+/// let company = adyen.management().get_company("YOUR_COMPANY_ID").await?;
+/// println!("Company: {}", company.name);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdyenClient {
+    client: Client,
+}
+
+impl AdyenClient {
+    /// Create a new client with the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying HTTP client cannot be created.
+    pub fn new(config: Config) -> Result<Self> {
+        Ok(Self {
+            client: Client::new(config)?,
+        })
+    }
+
+    /// Get the HTTP client shared by the API clients.
+    #[must_use]
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub fn config(&self) -> &Config {
+        self.client.config()
+    }
+
+    /// Get a Checkout API client.
+    #[must_use]
+    pub fn checkout(&self) -> checkout::CheckoutApi {
+        checkout::CheckoutApi::with_client(self.client.clone())
+    }
+
+    /// Get a Classic Payments API client.
+    #[must_use]
+    pub fn payments(&self) -> payments::PaymentsApi {
+        payments::PaymentsApi::with_client(self.client.clone())
+    }
+
+    /// Get a Classic Payments modifications client.
+    #[must_use]
+    pub fn modifications(&self) -> payments::ModificationsApi {
+        payments::ModificationsApi::with_client(self.client.clone())
+    }
+
+    /// Get a Recurring API client.
+    #[must_use]
+    pub fn recurring(&self) -> recurring::RecurringApi {
+        recurring::RecurringApi::with_client(self.client.clone())
+    }
+
+    /// Get a Payout API client.
+    ///
+    /// All payout endpoints use this client's credentials; create a
+    /// [`PayoutApi`](payout::PayoutApi) with
+    /// [`with_credentials`](payout::PayoutApi::with_credentials) to separate
+    /// the store and review roles.
+    #[must_use]
+    pub fn payout(&self) -> payout::PayoutApi {
+        payout::PayoutApi::with_client(self.client.clone())
+    }
+
+    /// Get a Management API client.
+    #[must_use]
+    pub fn management(&self) -> management::ManagementApi {
+        management::ManagementApi::with_client(self.client.clone())
+    }
+
+    /// Get a Balance Platform Configuration API client.
+    #[must_use]
+    pub fn platform(&self) -> platform::BalancePlatformApi {
+        platform::BalancePlatformApi::with_client(self.client.clone())
+    }
+
+    /// Get a Legal Entity Management API client.
+    #[must_use]
+    pub fn legal_entity(&self) -> legal_entity::LegalEntityApi {
+        legal_entity::LegalEntityApi::with_client(self.client.clone())
+    }
+
+    /// Get a Transfers API client.
+    #[must_use]
+    pub fn transfers(&self) -> transfers::TransfersApi {
+        transfers::TransfersApi::with_client(self.client.clone())
+    }
+
+    /// Get a cloud Terminal API client.
+    #[must_use]
+    pub fn terminal(&self) -> terminal::TerminalApi {
+        terminal::TerminalApi::with_client(self.client.clone())
+    }
+
+    /// Get a Disputes API client.
+    #[must_use]
+    pub fn disputes(&self) -> disputes::DisputesApi {
+        disputes::DisputesApi::with_client(self.client.clone())
+    }
+
+    /// Get a BIN Lookup API client.
+    #[must_use]
+    pub fn bin_lookup(&self) -> bin_lookup::BinLookupApi {
+        bin_lookup::BinLookupApi::with_client(self.client.clone())
+    }
+
+    /// Get a Stored Value API client.
+    #[must_use]
+    pub fn stored_value(&self) -> stored_value::StoredValueApi {
+        stored_value::StoredValueApi::with_client(self.client.clone())
+    }
+
+    /// Get a client for downloading reports.
+    #[must_use]
+    pub fn reports(&self) -> reports::ReportsClient {
+        reports::ReportsClient::with_client(self.client.clone())
+    }
+}
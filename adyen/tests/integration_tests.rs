@@ -0,0 +1,121 @@
+//! Integration tests for the `AdyenClient` facade.
+
+use adyen::payments::{Card, PaymentRequest};
+use adyen::testing::{Expectation, MockResponse, MockTransport};
+use adyen::{AdyenClient, Amount, ApplicationInfo, ConfigBuilder, Currency, Environment};
+
+fn mock_client(mock: &MockTransport) -> AdyenClient {
+    let config = ConfigBuilder::new()
+        .environment(Environment::test())
+        .api_key("test_key_1234567890123456")
+        .unwrap()
+        .transport(mock.clone())
+        .build()
+        .unwrap();
+    AdyenClient::new(config).unwrap()
+}
+
+#[cfg(test)]
+mod facade_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_api_clients_share_one_client() {
+        let mock = MockTransport::new();
+        mock.expect(
+            Expectation::get("/v3/companies/YOUR_COMPANY_ID")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({"id": "YOUR_COMPANY_ID", "name": "Test Company"}),
+                ))
+                .times(1),
+        );
+        mock.expect(
+            Expectation::post("/pal/servlet/Payment/v68/authorise")
+                .respond_with(MockResponse::json(
+                    200,
+                    serde_json::json!({
+                        "resultCode": "Authorised",
+                        "pspReference": "8515131751004933"
+                    }),
+                ))
+                .times(1),
+        );
+
+        let adyen = mock_client(&mock);
+        let company = adyen
+            .management()
+            .get_company("YOUR_COMPANY_ID")
+            .await
+            .unwrap();
+        assert_eq!(company.name.as_ref(), "Test Company");
+
+        let request = PaymentRequest::builder()
+            .amount(Amount::from_major_units(10, Currency::EUR))
+            .merchant_account("TestMerchantAccount")
+            .reference("Order-1")
+            .card(Card::new("4111111111111111", "03", "2030", "737"))
+            .build()
+            .unwrap();
+        adyen.payments().authorise(&request).await.unwrap();
+
+        let requests = mock.received_requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0]
+            .url
+            .starts_with("https://management-test.adyen.com"));
+        assert!(requests[1].url.starts_with("https://pal-test.adyen.com"));
+        mock.verify();
+    }
+
+    #[test]
+    fn test_client_is_not_scoped_to_an_api() {
+        let adyen = mock_client(&MockTransport::new());
+        assert!(adyen.client().api().is_none());
+        assert_eq!(adyen.config().environment(), &Environment::test());
+    }
+
+    #[tokio::test]
+    async fn test_application_info_added_to_payment_requests() {
+        let mock = MockTransport::new();
+        mock.expect(Expectation::post("/v71/sessions").times(1));
+        mock.expect(Expectation::post("/v71/paymentMethods").times(1));
+
+        let config = ConfigBuilder::new()
+            .api_key("test_key_1234567890123456")
+            .unwrap()
+            .application_info(
+                ApplicationInfo::default()
+                    .with_merchant_application("my-shop", "2.1.0")
+                    .with_external_platform("Magento", "2.4.6", Some("Acme Integrations")),
+            )
+            .transport(mock.clone())
+            .build()
+            .unwrap();
+        let checkout = AdyenClient::new(config).unwrap().checkout();
+
+        let session = adyen::checkout::CreateCheckoutSessionRequest::builder()
+            .amount(Amount::from_major_units(10, Currency::EUR))
+            .merchant_account("TestMerchantAccount")
+            .reference("Order-1")
+            .return_url("https://your-company.com/checkout/return")
+            .build()
+            .unwrap();
+        let _ = checkout.sessions(&session).await;
+        let methods = adyen::checkout::PaymentMethodsRequest::builder()
+            .merchant_account("TestMerchantAccount")
+            .build()
+            .unwrap();
+        let _ = checkout.payment_methods(&methods).await;
+
+        let requests = mock.received_requests();
+        let application_info = &requests[0].body.as_ref().unwrap()["applicationInfo"];
+        assert_eq!(application_info["merchantApplication"]["name"], "my-shop");
+        assert_eq!(
+            application_info["externalPlatform"]["integrator"],
+            "Acme Integrations"
+        );
+        assert!(requests[1].body.as_ref().unwrap()["applicationInfo"].is_null());
+        mock.verify();
+    }
+}